@@ -2,6 +2,7 @@ use crate::HashMap;
 use crate::analyzer::resource_table::PathId;
 use crate::analyzer_error::AnalyzerError;
 use crate::attribute_table;
+use crate::definition_table;
 use crate::handlers::check_expression::CheckExpression;
 use crate::handlers::*;
 use crate::instance_history;
@@ -15,6 +16,7 @@ use crate::symbol::{
 use crate::symbol_path::SymbolPathNamespace;
 use crate::symbol_table;
 use crate::type_dag;
+use crate::unsafe_table;
 use crate::var_ref::{
     AssignPosition, AssignPositionTree, AssignPositionType, ExpressionTargetType, VarRef,
     VarRefAffiliation, VarRefPath, VarRefType,
@@ -23,6 +25,7 @@ use itertools::Itertools;
 use std::path::Path;
 use veryl_metadata::{Build, EnvVar, Lint, Metadata};
 use veryl_parser::resource_table;
+use veryl_parser::text_table;
 use veryl_parser::token_range::TokenRange;
 use veryl_parser::veryl_grammar_trait::*;
 use veryl_parser::veryl_token::{Token, TokenSource};
@@ -101,12 +104,17 @@ impl AnalyzerPass3 {
 
         for symbol in symbols {
             if symbol.token.source == self.path {
-                if let SymbolKind::Variable(_) = symbol.kind {
+                if let SymbolKind::Variable(ref x) = symbol.kind {
                     if symbol.references.is_empty() && !symbol.allow_unused {
                         let name = symbol.token.to_string();
                         if !name.starts_with('_') {
+                            // Declarations under conditional compilation can't be removed safely
+                            let declaration = x.declaration.as_ref().filter(|x| {
+                                !attribute_table::get(&x.beg).iter().any(|x| x.is_ifdef())
+                            });
                             ret.push(AnalyzerError::unused_variable(
                                 &symbol.token.to_string(),
+                                declaration,
                                 &symbol.token.into(),
                             ));
                         }
@@ -410,6 +418,18 @@ impl Analyzer {
         ret
     }
 
+    /// Drops analysis results of the specified file before re-analyzing it
+    pub fn drop_file(path: PathId) {
+        symbol_table::drop(path);
+        namespace_table::drop(path);
+        text_table::drop(path);
+        attribute_table::drop(path);
+        unsafe_table::drop(path);
+        definition_table::drop(path);
+        msb_table::drop(path);
+        type_dag::drop(path);
+    }
+
     pub fn clear(&self) {
         attribute_table::clear();
         msb_table::clear();
@@ -1,12 +1,18 @@
 use crate::evaluator::EvaluatedError;
 use crate::multi_sources::{MultiSources, Source};
+use crate::suggestion::Suggestion;
 use miette::{self, Diagnostic, SourceSpan};
 use thiserror::Error;
 use veryl_parser::token_range::TokenRange;
 
 #[derive(Error, Diagnostic, Debug)]
 pub enum AnalyzerError {
-    #[diagnostic(severity(Error), code(anonymous_identifier_usage), help(""), url(""))]
+    #[diagnostic(
+        severity(Error),
+        code(anonymous_identifier_usage),
+        help("replace `_` with a named identifier"),
+        url("")
+    )]
     #[error("Anonymous identifier can't be placed at here")]
     AnonymousIdentifierUsage {
         #[source_code]
@@ -38,7 +44,7 @@ pub enum AnalyzerError {
     #[diagnostic(
         severity(Error),
         code(cyclic_type_dependency),
-        help(""),
+        help("break the cycle by changing the type of a member"),
         url(
             "https://doc.veryl-lang.org/book/07_appendix/02_semantic_error.html#cyclic_type_dependency"
         )
@@ -56,7 +62,7 @@ pub enum AnalyzerError {
     #[diagnostic(
         severity(Error),
         code(duplicated_identifier),
-        help(""),
+        help("rename one of the declarations"),
         url(
             "https://doc.veryl-lang.org/book/07_appendix/02_semantic_error.html#duplicated_identifier"
         )
@@ -73,7 +79,7 @@ pub enum AnalyzerError {
     #[diagnostic(
         severity(Error),
         code(multiple_assignment),
-        help(""),
+        help("assign the variable in a single block or assignment"),
         url(
             "https://doc.veryl-lang.org/book/07_appendix/02_semantic_error.html#multiple_assignment"
         )
@@ -127,7 +133,12 @@ pub enum AnalyzerError {
         error_location: SourceSpan,
     },
 
-    #[diagnostic(severity(Error), code(invalid_connect_operand), help(""), url(""))]
+    #[diagnostic(
+        severity(Error),
+        code(invalid_connect_operand),
+        help("use a single interface instance with modport or a modport port"),
+        url("")
+    )]
     #[error("{identifier} can't be used as a connect operand because {reason}")]
     InvalidConnectOperand {
         identifier: String,
@@ -190,7 +201,7 @@ pub enum AnalyzerError {
     #[diagnostic(
         severity(Warning),
         code(invalid_select),
-        help(""),
+        help("fix the select to be in the range of the variable"),
         url("https://doc.veryl-lang.org/book/07_appendix/02_semantic_error.html#invalid_select")
     )]
     #[error("invalid select caused by {kind}")]
@@ -216,6 +227,7 @@ pub enum AnalyzerError {
     InvalidIdentifier {
         identifier: String,
         rule: String,
+        suggestion: Option<Suggestion>,
         #[source_code]
         input: MultiSources,
         #[label("Error location")]
@@ -267,7 +279,7 @@ pub enum AnalyzerError {
     #[diagnostic(
         severity(Error),
         code(invalid_number_character),
-        help(""),
+        help("remove invalid characters or change the base"),
         url(
             "https://doc.veryl-lang.org/book/07_appendix/02_semantic_error.html#invalid_number_character"
         )
@@ -302,7 +314,7 @@ pub enum AnalyzerError {
     #[diagnostic(
         severity(Error),
         code(invalid_clock),
-        help(""),
+        help("change the type to `clock`"),
         url("https://doc.veryl-lang.org/book/07_appendix/02_semantic_error.html#invalid_clock")
     )]
     #[error(
@@ -316,7 +328,12 @@ pub enum AnalyzerError {
         error_location: SourceSpan,
     },
 
-    #[diagnostic(severity(Error), code(multiple_default_clock), help(""), url(""))]
+    #[diagnostic(
+        severity(Error),
+        code(multiple_default_clock),
+        help("remove `default` modifier from other clock ports"),
+        url("")
+    )]
     #[error(
         "{identifier} can't be used as the default clock because the default clock has already been specified."
     )]
@@ -331,7 +348,7 @@ pub enum AnalyzerError {
     #[diagnostic(
         severity(Error),
         code(invalid_modport_variable_item),
-        help(""),
+        help("use `import` direction for functions"),
         url(
             "https://doc.veryl-lang.org/book/07_appendix/02_semantic_error.html#invalid_modport_variable_item"
         )
@@ -348,7 +365,7 @@ pub enum AnalyzerError {
     #[diagnostic(
         severity(Error),
         code(invalid_modport_function_item),
-        help(""),
+        help("use `input`, `output` or `inout` direction for variables"),
         url(
             "https://doc.veryl-lang.org/book/07_appendix/02_semantic_error.html#invalid_modport_function_item"
         )
@@ -362,7 +379,12 @@ pub enum AnalyzerError {
         error_location: SourceSpan,
     },
 
-    #[diagnostic(severity(Error), code(unexpandable_modport), help(""), url(""))]
+    #[diagnostic(
+        severity(Error),
+        code(unexpandable_modport),
+        help("remove parameters of the interface or `#[expand(modport)]`"),
+        url("")
+    )]
     #[error("#{identifier} can't be expanded")]
     UnexpandableModport {
         identifier: String,
@@ -372,7 +394,28 @@ pub enum AnalyzerError {
         error_location: SourceSpan,
     },
 
-    #[diagnostic(severity(Error), code(invalid_port_default_value), help(""), url(""))]
+    #[diagnostic(
+        severity(Error),
+        code(unflattenable_module),
+        help("remove generic parameters or `#[flatten]`"),
+        url("")
+    )]
+    #[error("#{identifier} can't be flattened because {cause}")]
+    UnflattenableModule {
+        identifier: String,
+        cause: String,
+        #[source_code]
+        input: MultiSources,
+        #[label("Error location")]
+        error_location: SourceSpan,
+    },
+
+    #[diagnostic(
+        severity(Error),
+        code(invalid_port_default_value),
+        help("remove the port default value or use `_` for output port"),
+        url("")
+    )]
     #[error("#{direction} port #{identifier} cannot have a port default value")]
     InvalidPortDefaultValue {
         identifier: String,
@@ -386,7 +429,7 @@ pub enum AnalyzerError {
     #[diagnostic(
         severity(Error),
         code(invalid_reset),
-        help(""),
+        help("change the type to `reset`"),
         url("https://doc.veryl-lang.org/book/07_appendix/02_semantic_error.html#invalid_reset")
     )]
     #[error(
@@ -400,7 +443,12 @@ pub enum AnalyzerError {
         error_location: SourceSpan,
     },
 
-    #[diagnostic(severity(Error), code(multiple_default_reset), help(""), url(""))]
+    #[diagnostic(
+        severity(Error),
+        code(multiple_default_reset),
+        help("remove `default` modifier from other reset ports"),
+        url("")
+    )]
     #[error(
         "{identifier} can't be used as the default reset because the default reset has already been specified."
     )]
@@ -415,7 +463,7 @@ pub enum AnalyzerError {
     #[diagnostic(
         severity(Error),
         code(invalid_reset_non_elaborative),
-        help(""),
+        help("use a constant value as reset value"),
         url(
             "https://doc.veryl-lang.org/book/07_appendix/02_semantic_error.html#invalid_reset_non_elaborative"
         )
@@ -431,7 +479,7 @@ pub enum AnalyzerError {
     #[diagnostic(
         severity(Error),
         code(invalid_case_condition_non_elaborative),
-        help(""),
+        help("use a constant value or `switch` statement"),
         url("")
     )]
     #[error("Case condition value cannot be used because it is not evaluable at elaboration time")]
@@ -442,7 +490,12 @@ pub enum AnalyzerError {
         error_location: SourceSpan,
     },
 
-    #[diagnostic(severity(Error), code(invalid_cast), help(""), url(""))]
+    #[diagnostic(
+        severity(Error),
+        code(invalid_cast),
+        help("cast from a value which has the same kind"),
+        url("")
+    )]
     #[error("Casting from {from} to {to} is incompatible")]
     InvalidCast {
         from: String,
@@ -456,7 +509,7 @@ pub enum AnalyzerError {
     #[diagnostic(
         severity(Error),
         code(invalid_test),
-        help(""),
+        help("fix arguments of `#[test]` attribute"),
         url("https://doc.veryl-lang.org/book/07_appendix/02_semantic_error.html#invalid_test")
     )]
     #[error("test is invalid because {cause}")]
@@ -468,7 +521,12 @@ pub enum AnalyzerError {
         error_location: SourceSpan,
     },
 
-    #[diagnostic(severity(Error), code(invalid_type_declaration), help(""), url(""))]
+    #[diagnostic(
+        severity(Error),
+        code(invalid_type_declaration),
+        help("move the declaration into a package"),
+        url("")
+    )]
     #[error("{kind} can't be declared in interface declaration")]
     InvalidTypeDeclaration {
         kind: String,
@@ -480,7 +538,7 @@ pub enum AnalyzerError {
     #[diagnostic(
         severity(Error),
         code(incompat_proto),
-        help(""),
+        help("make the declaration match the proto"),
         url("https://doc.veryl-lang.org/book/07_appendix/02_semantic_error.html#incompat_proto")
     )]
     #[error("{identifier} is incompatible with {proto} because {cause}")]
@@ -552,7 +610,7 @@ pub enum AnalyzerError {
     #[diagnostic(
         severity(Error),
         code(mismatch_attribute_args),
-        help(""),
+        help("fix attribute arguments"),
         url(
             "https://doc.veryl-lang.org/book/07_appendix/02_semantic_error.html#mismatch_attribute_args"
         )
@@ -570,7 +628,7 @@ pub enum AnalyzerError {
     #[diagnostic(
         severity(Error),
         code(mismatch_type),
-        help(""),
+        help("use a symbol which has the expected type"),
         url("https://doc.veryl-lang.org/book/07_appendix/02_semantic_error.html#mismatch_type")
     )]
     #[error("\"{name}\" is expected to \"{expected}\", but it is \"{actual}\"")]
@@ -587,7 +645,7 @@ pub enum AnalyzerError {
     #[diagnostic(
         severity(Error),
         code(mismatch_clock_domain),
-        help(""),
+        help("add a synchronizer in `unsafe (cdc)` block"),
         url(
             "https://doc.veryl-lang.org/book/07_appendix/02_semantic_error.html#mismatch_clock_domain"
         )
@@ -607,7 +665,7 @@ pub enum AnalyzerError {
     #[diagnostic(
         severity(Warning),
         code(mismatch_assignment),
-        help(""),
+        help("fix the type of the source or the destination"),
         url(
             "https://doc.veryl-lang.org/book/07_appendix/02_semantic_error.html#mismatch_assignment"
         )
@@ -648,6 +706,7 @@ pub enum AnalyzerError {
     MissingPort {
         name: String,
         port: String,
+        suggestion: Option<Suggestion>,
         #[source_code]
         input: MultiSources,
         #[label("Error location")]
@@ -762,6 +821,7 @@ pub enum AnalyzerError {
     #[error("SystemVerilog keyword may not be used as identifier")]
     SvKeywordUsage {
         identifier: String,
+        suggestion: Option<Suggestion>,
         #[source_code]
         input: MultiSources,
         #[label("Error location")]
@@ -786,6 +846,51 @@ pub enum AnalyzerError {
         error_location: SourceSpan,
     },
 
+    #[diagnostic(
+        severity(Error),
+        code(unsupported_by_verilog2005),
+        help("Rewrite it without the construct, or emit SystemVerilog"),
+        url("")
+    )]
+    #[error("{kind} can't be lowered to Verilog-2005")]
+    UnsupportedByVerilog2005 {
+        kind: String,
+        #[source_code]
+        input: MultiSources,
+        #[label("Error location")]
+        error_location: SourceSpan,
+    },
+
+    #[diagnostic(
+        severity(Error),
+        code(unsupported_by_vhdl2008),
+        help("Rewrite it without the construct, or emit SystemVerilog"),
+        url("")
+    )]
+    #[error("{kind} can't be translated to VHDL-2008")]
+    UnsupportedByVhdl2008 {
+        kind: String,
+        #[source_code]
+        input: MultiSources,
+        #[label("Error location")]
+        error_location: SourceSpan,
+    },
+
+    #[diagnostic(
+        severity(Error),
+        code(unsupported_by_circt),
+        help("Rewrite it without the construct, or emit SystemVerilog"),
+        url("")
+    )]
+    #[error("{kind} can't be elaborated into CIRCT IR")]
+    UnsupportedByCirct {
+        kind: String,
+        #[source_code]
+        input: MultiSources,
+        #[label("Error location")]
+        error_location: SourceSpan,
+    },
+
     #[diagnostic(
         severity(Error),
         code(invalid_clock_domain),
@@ -803,7 +908,7 @@ pub enum AnalyzerError {
     #[diagnostic(
         severity(Error),
         code(too_large_enum_variant),
-        help(""),
+        help("increase the width of the enum or decrease the value"),
         url(
             "https://doc.veryl-lang.org/book/07_appendix/02_semantic_error.html#too_large_enum_variant"
         )
@@ -824,7 +929,7 @@ pub enum AnalyzerError {
     #[diagnostic(
         severity(Error),
         code(unevaluatable_enum_variant_value),
-        help(""),
+        help("specify the value of the variant explicitly"),
         url("")
     )]
     #[error("The value of enum variant {identifier} cannot be evaluated")]
@@ -836,7 +941,12 @@ pub enum AnalyzerError {
         error_location: SourceSpan,
     },
 
-    #[diagnostic(severity(Error), code(invalid_enum_variant_value), help(""), url(""))]
+    #[diagnostic(
+        severity(Error),
+        code(invalid_enum_variant_value),
+        help("change the value to follow the encoding"),
+        url("")
+    )]
     #[error("The value of enum variant {identifier} is not encoded value by {encoding}")]
     InvalidEnumVariant {
         identifier: String,
@@ -865,7 +975,7 @@ pub enum AnalyzerError {
     #[diagnostic(
         severity(Error),
         code(too_much_enum_variant),
-        help(""),
+        help("increase the width of the enum"),
         url(
             "https://doc.veryl-lang.org/book/07_appendix/02_semantic_error.html#too_much_enum_variant"
         )
@@ -883,7 +993,12 @@ pub enum AnalyzerError {
         error_location: SourceSpan,
     },
 
-    #[diagnostic(severity(Error), code(invisible_identifier), help(""), url(""))]
+    #[diagnostic(
+        severity(Error),
+        code(invisible_identifier),
+        help("move the declaration into a package"),
+        url("")
+    )]
     #[error("cannot refer indentifier {identifier} because it is invisible at here")]
     InvisibleIndentifier {
         identifier: String,
@@ -896,7 +1011,7 @@ pub enum AnalyzerError {
     #[diagnostic(
         severity(Error),
         code(undefined_identifier),
-        help(""),
+        help("declare the identifier or fix the typo"),
         url(
             "https://doc.veryl-lang.org/book/07_appendix/02_semantic_error.html#undefined_identifier"
         )
@@ -928,7 +1043,7 @@ pub enum AnalyzerError {
     #[diagnostic(
         severity(Error),
         code(unresolvable_generic_argument),
-        help(""),
+        help("use a value which is visible from the definition of generics"),
         url(
             "https://doc.veryl-lang.org/book/07_appendix/02_semantic_error.html#unresolvable_generic_argument"
         )
@@ -947,7 +1062,7 @@ pub enum AnalyzerError {
     #[diagnostic(
         severity(Error),
         code(unknown_attribute),
-        help(""),
+        help("remove the attribute or fix the typo"),
         url(
             "https://doc.veryl-lang.org/book/07_appendix/02_semantic_error.html#unknown_attribute"
         )
@@ -961,7 +1076,12 @@ pub enum AnalyzerError {
         error_location: SourceSpan,
     },
 
-    #[diagnostic(severity(Error), code(invalid_enbed), help(""), url(""))]
+    #[diagnostic(
+        severity(Error),
+        code(invalid_enbed),
+        help("fix the way or the language of embed"),
+        url("")
+    )]
     #[error("embed (way: {way}/lang: {lang}) can't be used at here")]
     InvalidEmbed {
         way: String,
@@ -975,7 +1095,7 @@ pub enum AnalyzerError {
     #[diagnostic(
         severity(Error),
         code(unknown_embed_lang),
-        help(""),
+        help("use `sv` or `py`"),
         url(
             "https://doc.veryl-lang.org/book/07_appendix/02_semantic_error.html#unknown_embed_lang"
         )
@@ -992,7 +1112,7 @@ pub enum AnalyzerError {
     #[diagnostic(
         severity(Error),
         code(unknown_embed_way),
-        help(""),
+        help("use `inline` or `cocotb`"),
         url(
             "https://doc.veryl-lang.org/book/07_appendix/02_semantic_error.html#unknown_embed_way"
         )
@@ -1009,7 +1129,7 @@ pub enum AnalyzerError {
     #[diagnostic(
         severity(Error),
         code(unknown_include_way),
-        help(""),
+        help("use `inline` or `cocotb`"),
         url(
             "https://doc.veryl-lang.org/book/07_appendix/02_semantic_error.html#unknown_include_way"
         )
@@ -1026,7 +1146,7 @@ pub enum AnalyzerError {
     #[diagnostic(
         severity(Error),
         code(unknown_member),
-        help(""),
+        help("fix the member name"),
         url("https://doc.veryl-lang.org/book/07_appendix/02_semantic_error.html#unknown_member")
     )]
     #[error("\"{name}\" doesn't have member \"{member}\"")]
@@ -1042,7 +1162,7 @@ pub enum AnalyzerError {
     #[diagnostic(
         severity(Error),
         code(unknown_unsafe),
-        help(""),
+        help("use `cdc`"),
         url("https://doc.veryl-lang.org/book/07_appendix/02_semantic_error.html#unknown_unsafe")
     )]
     #[error("\"{name}\" is not valid unsafe identifier")]
@@ -1057,7 +1177,7 @@ pub enum AnalyzerError {
     #[diagnostic(
         severity(Error),
         code(private_member),
-        help(""),
+        help("add `pub` to the declaration"),
         url("https://doc.veryl-lang.org/book/07_appendix/02_semantic_error.html#private_member")
     )]
     #[error("\"{name}\" is private member")]
@@ -1072,7 +1192,7 @@ pub enum AnalyzerError {
     #[diagnostic(
         severity(Error),
         code(unknown_msb),
-        help(""),
+        help("use the explicit index instead of `msb`"),
         url("https://doc.veryl-lang.org/book/07_appendix/02_semantic_error.html#unknown_msb")
     )]
     #[error("resolving msb is failed")]
@@ -1138,6 +1258,7 @@ pub enum AnalyzerError {
     #[error("{identifier} is unused")]
     UnusedVariable {
         identifier: String,
+        suggestion: Option<Suggestion>,
         #[source_code]
         input: MultiSources,
         #[label("Error location")]
@@ -1162,7 +1283,7 @@ pub enum AnalyzerError {
     #[diagnostic(
         severity(Warning),
         code(unassign_variable),
-        help(""),
+        help("assign the variable or remove it"),
         url(
             "https://doc.veryl-lang.org/book/07_appendix/02_semantic_error.html#unassign_variable"
         )
@@ -1179,7 +1300,7 @@ pub enum AnalyzerError {
     #[diagnostic(
         severity(Error),
         code(unassignable_output),
-        help(""),
+        help("connect a variable or `_` to the output port"),
         url(
             "https://doc.veryl-lang.org/book/07_appendix/02_semantic_error.html#unassignable_output"
         )
@@ -1195,7 +1316,7 @@ pub enum AnalyzerError {
     #[diagnostic(
         severity(Warning),
         code(uncovered_branch),
-        help(""),
+        help("assign the variable in all branches or before the branches"),
         url("https://doc.veryl-lang.org/book/07_appendix/02_semantic_error.html#uncovered_branch")
     )]
     #[error("{identifier} is not covered by all branches, it causes latch generation")]
@@ -1229,7 +1350,7 @@ pub enum AnalyzerError {
     #[diagnostic(
         severity(Error),
         code(include_failure),
-        help(""),
+        help("check the path of the file"),
         url("https://doc.veryl-lang.org/book/07_appendix/02_semantic_error.html#include_failure")
     )]
     #[error("\"{name}\" can't be read because \"{cause}\"")]
@@ -1252,13 +1373,19 @@ pub enum AnalyzerError {
     WrongSeparator {
         separator: String,
         valid_separator: String,
+        suggestion: Option<Suggestion>,
         #[source_code]
         input: MultiSources,
         #[label("Error location")]
         error_location: SourceSpan,
     },
 
-    #[diagnostic(severity(Error), code(infinite_recursion), help(""), url(""))]
+    #[diagnostic(
+        severity(Error),
+        code(infinite_recursion),
+        help("add a condition to stop the recursion"),
+        url("")
+    )]
     #[error("infinite instance recursion is detected")]
     InfiniteRecursion {
         #[source_code]
@@ -1267,7 +1394,12 @@ pub enum AnalyzerError {
         error_location: SourceSpan,
     },
 
-    #[diagnostic(severity(Error), code(exceed_limit), help(""), url(""))]
+    #[diagnostic(
+        severity(Error),
+        code(exceed_limit),
+        help("add a condition to stop the recursion or increase the limit"),
+        url("")
+    )]
     #[error("exceed {kind} limit")]
     ExceedLimit {
         kind: String,
@@ -1280,7 +1412,7 @@ pub enum AnalyzerError {
     #[diagnostic(
         severity(Error),
         code(ambiguous_elsif),
-        help(""),
+        help("place a single `#[ifdef]` or `#[ifndef]` before `#[elsif]` or `#[else]`"),
         url("https://doc.veryl-lang.org/book/07_appendix/02_semantic_error.html#ambiguous_elsif")
     )]
     #[error("elsif/else attribute is ambiguous because {cause}")]
@@ -1295,7 +1427,7 @@ pub enum AnalyzerError {
     #[diagnostic(
         severity(Error),
         code(last_item_with_define),
-        help(""),
+        help("move the item so that it isn't the last"),
         url(
             "https://doc.veryl-lang.org/book/07_appendix/02_semantic_error.html#last_item_with_define"
         )
@@ -1350,6 +1482,18 @@ fn source_with_context(
 }
 
 impl AnalyzerError {
+    /// Machine-applicable fix which can be applied by `veryl fix`
+    pub fn suggestion(&self) -> Option<&Suggestion> {
+        match self {
+            AnalyzerError::InvalidIdentifier { suggestion, .. }
+            | AnalyzerError::MissingPort { suggestion, .. }
+            | AnalyzerError::SvKeywordUsage { suggestion, .. }
+            | AnalyzerError::UnusedVariable { suggestion, .. }
+            | AnalyzerError::WrongSeparator { suggestion, .. } => suggestion.as_ref(),
+            _ => None,
+        }
+    }
+
     pub fn anonymous_identifier_usage(token: &TokenRange) -> Self {
         AnalyzerError::AnonymousIdentifierUsage {
             input: source(token),
@@ -1449,10 +1593,16 @@ impl AnalyzerError {
         }
     }
 
-    pub fn invalid_identifier(identifier: &str, rule: &str, token: &TokenRange) -> Self {
+    pub fn invalid_identifier(
+        identifier: &str,
+        rule: &str,
+        fixed: Option<&str>,
+        token: &TokenRange,
+    ) -> Self {
         AnalyzerError::InvalidIdentifier {
             identifier: identifier.to_string(),
             rule: rule.to_string(),
+            suggestion: fixed.map(|x| Suggestion::Rename(token.beg, x.to_string())),
             input: source(token),
             error_location: token.into(),
         }
@@ -1591,6 +1741,15 @@ impl AnalyzerError {
         }
     }
 
+    pub fn unflattenable_module(identifier: &str, cause: &str, token: &TokenRange) -> Self {
+        AnalyzerError::UnflattenableModule {
+            identifier: identifier.into(),
+            cause: cause.into(),
+            input: source(token),
+            error_location: token.into(),
+        }
+    }
+
     pub fn invalid_port_default_value(
         identifier: &str,
         direction: &str,
@@ -1746,10 +1905,16 @@ impl AnalyzerError {
         }
     }
 
-    pub fn missing_port(name: &str, port: &str, token: &TokenRange) -> Self {
+    pub fn missing_port(
+        name: &str,
+        port: &str,
+        suggestion: Option<Suggestion>,
+        token: &TokenRange,
+    ) -> Self {
         AnalyzerError::MissingPort {
             name: name.to_string(),
             port: port.to_string(),
+            suggestion,
             input: source(token),
             error_location: token.into(),
         }
@@ -1763,8 +1928,10 @@ impl AnalyzerError {
     }
 
     pub fn sv_keyword_usage(identifier: &str, token: &TokenRange) -> Self {
+        let fixed = format!("{}_", identifier.strip_prefix("r#").unwrap_or(identifier));
         AnalyzerError::SvKeywordUsage {
             identifier: identifier.to_string(),
+            suggestion: Some(Suggestion::Rename(token.beg, fixed)),
             input: source(token),
             error_location: token.into(),
         }
@@ -1777,6 +1944,30 @@ impl AnalyzerError {
         }
     }
 
+    pub fn unsupported_by_verilog2005(kind: &str, token: &TokenRange) -> Self {
+        AnalyzerError::UnsupportedByVerilog2005 {
+            kind: kind.to_string(),
+            input: source(token),
+            error_location: token.into(),
+        }
+    }
+
+    pub fn unsupported_by_vhdl2008(kind: &str, token: &TokenRange) -> Self {
+        AnalyzerError::UnsupportedByVhdl2008 {
+            kind: kind.to_string(),
+            input: source(token),
+            error_location: token.into(),
+        }
+    }
+
+    pub fn unsupported_by_circt(kind: &str, token: &TokenRange) -> Self {
+        AnalyzerError::UnsupportedByCirct {
+            kind: kind.to_string(),
+            input: source(token),
+            error_location: token.into(),
+        }
+    }
+
     pub fn invalid_clock_domain(token: &TokenRange) -> Self {
         AnalyzerError::InvalidClockDomain {
             input: source(token),
@@ -1978,9 +2169,14 @@ impl AnalyzerError {
         }
     }
 
-    pub fn unused_variable(identifier: &str, token: &TokenRange) -> Self {
+    pub fn unused_variable(
+        identifier: &str,
+        declaration: Option<&TokenRange>,
+        token: &TokenRange,
+    ) -> Self {
         AnalyzerError::UnusedVariable {
             identifier: identifier.to_string(),
+            suggestion: declaration.map(|x| Suggestion::Replace(*x, String::new())),
             input: source(token),
             error_location: token.into(),
         }
@@ -2040,6 +2236,7 @@ impl AnalyzerError {
         AnalyzerError::WrongSeparator {
             separator: separator.to_string(),
             valid_separator: valid_separator.to_string(),
+            suggestion: Some(Suggestion::Replace(*token, valid_separator.to_string())),
             input: source(token),
             error_location: token.into(),
         }
@@ -1,6 +1,7 @@
 use std::cell::RefCell;
 use std::fmt;
 use veryl_parser::resource_table::{self, StrId};
+use veryl_parser::token_range::TokenRange;
 use veryl_parser::veryl_token::Token;
 
 #[derive(Clone, Debug, PartialEq, Eq)]
@@ -13,11 +14,12 @@ pub enum Attribute {
     Allow(AllowItem),
     EnumEncoding(EnumEncodingItem),
     EnumMemberPrefix(StrId),
-    Test(Token, Option<StrId>),
+    Test(Token, Option<StrId>, TestItem),
     CondType(CondTypeItem),
     Align(Vec<AlignItem>),
     Format(Vec<FormatItem>),
     Expand(Vec<ExpandItem>),
+    Flatten(Option<StrId>, Option<StrId>),
 }
 
 impl Attribute {
@@ -67,7 +69,7 @@ impl fmt::Display for Attribute {
             Attribute::Allow(x) => format!("allow({x})"),
             Attribute::EnumEncoding(x) => format!("enum_encoding({x})"),
             Attribute::EnumMemberPrefix(x) => format!("enum_member_prefix({x})"),
-            Attribute::Test(x, _) => format!("test({})", x.text),
+            Attribute::Test(x, _, _) => format!("test({})", x.text),
             Attribute::CondType(x) => format!("cond_type({x})"),
             Attribute::Align(x) => {
                 let mut arg = String::new();
@@ -90,6 +92,16 @@ impl fmt::Display for Attribute {
                 }
                 format!("expand({arg})")
             }
+            Attribute::Flatten(name, separator) => {
+                let mut arg = String::new();
+                if let Some(x) = name {
+                    arg.push_str(&format!("{x}, "));
+                }
+                if let Some(x) = separator {
+                    arg.push_str(&format!("\"{x}\", "));
+                }
+                format!("flatten({arg})")
+            }
         };
         text.fmt(f)
     }
@@ -141,6 +153,47 @@ fn get_arg_string(
     }
 }
 
+fn get_args_len(args: &Option<veryl_parser::veryl_grammar_trait::AttributeOpt>) -> usize {
+    use veryl_parser::veryl_grammar_trait as g;
+
+    if let Some(x) = args {
+        let args: Vec<g::AttributeItem> = x.attribute_list.as_ref().into();
+        args.len()
+    } else {
+        0
+    }
+}
+
+fn get_args(
+    args: &Option<veryl_parser::veryl_grammar_trait::AttributeOpt>,
+) -> Vec<veryl_parser::veryl_grammar_trait::AttributeItem> {
+    if let Some(x) = args {
+        x.attribute_list.as_ref().into()
+    } else {
+        Vec::new()
+    }
+}
+
+fn get_values(value: &veryl_parser::veryl_grammar_trait::AttributeValue) -> Vec<Token> {
+    use veryl_parser::veryl_grammar_trait as g;
+
+    match value {
+        g::AttributeValue::IntegralNumber(x) => {
+            vec![TokenRange::from(x.integral_number.as_ref()).beg]
+        }
+        g::AttributeValue::StringLiteral(x) => vec![x.string_literal.string_literal_token.token],
+        g::AttributeValue::Identifier(x) => vec![x.identifier.identifier_token.token],
+        g::AttributeValue::AttributeArray(x) => {
+            let list = &x.attribute_array.attribute_value_list;
+            let mut ret = get_values(&list.attribute_value);
+            for x in &list.attribute_value_list_list {
+                ret.append(&mut get_values(&x.attribute_value));
+            }
+            ret
+        }
+    }
+}
+
 fn get_args_ident(args: &Option<veryl_parser::veryl_grammar_trait::AttributeOpt>) -> Vec<Token> {
     use veryl_parser::veryl_grammar_trait as g;
 
@@ -185,6 +238,9 @@ struct Pattern {
     pub compact: StrId,
     pub expand: StrId,
     pub modport: StrId,
+    pub flatten: StrId,
+    pub seeds: StrId,
+    pub params: StrId,
 }
 
 impl Pattern {
@@ -217,6 +273,9 @@ impl Pattern {
             compact: resource_table::insert_str("compact"),
             expand: resource_table::insert_str("expand"),
             modport: resource_table::insert_str("modport"),
+            flatten: resource_table::insert_str("flatten"),
+            seeds: resource_table::insert_str("seeds"),
+            params: resource_table::insert_str("params"),
         }
     }
 }
@@ -227,6 +286,8 @@ impl TryFrom<&veryl_parser::veryl_grammar_trait::Attribute> for Attribute {
     type Error = AttributeError;
 
     fn try_from(value: &veryl_parser::veryl_grammar_trait::Attribute) -> Result<Self, Self::Error> {
+        use veryl_parser::veryl_grammar_trait as g;
+
         PAT.with_borrow(|pat| match value.identifier.identifier_token.token.text {
             x if x == pat.ifdef || x == pat.ifndef || x == pat.elsif || x == pat.r#else => {
                 let arg = get_arg_ident(&value.attribute_opt, 0);
@@ -311,10 +372,59 @@ impl TryFrom<&veryl_parser::veryl_grammar_trait::Attribute> for Attribute {
                 let arg = get_arg_ident(&value.attribute_opt, 0);
                 let top = get_arg_ident(&value.attribute_opt, 1);
 
+                let err = AttributeError::MismatchArgs(
+                    "test name, optional top module, seeds = number and params(NAME = values)",
+                );
+
+                let mut item = TestItem::default();
+                for (i, x) in get_args(&value.attribute_opt).iter().enumerate() {
+                    match x {
+                        g::AttributeItem::Identifier(_) if i <= 1 => (),
+                        g::AttributeItem::AttributeAssignment(x)
+                            if x.attribute_assignment
+                                .identifier
+                                .identifier_token
+                                .token
+                                .text
+                                == pat.seeds =>
+                        {
+                            let seeds = get_values(&x.attribute_assignment.attribute_value);
+                            let seeds = match seeds.as_slice() {
+                                [x] => x.to_string().replace('_', "").parse::<usize>().ok(),
+                                _ => None,
+                            };
+                            if seeds.is_none() {
+                                return Err(err);
+                            }
+                            item.seeds = seeds;
+                        }
+                        g::AttributeItem::AttributeCall(x)
+                            if x.attribute_call.identifier.identifier_token.token.text
+                                == pat.params =>
+                        {
+                            let args: Vec<g::AttributeItem> =
+                                x.attribute_call.attribute_list.as_ref().into();
+                            for x in &args {
+                                let g::AttributeItem::AttributeAssignment(x) = x else {
+                                    return Err(err);
+                                };
+                                let x = &x.attribute_assignment;
+                                let values = get_values(&x.attribute_value)
+                                    .iter()
+                                    .map(|x| x.text)
+                                    .collect();
+                                item.params
+                                    .push((x.identifier.identifier_token.token.text, values));
+                            }
+                        }
+                        _ => return Err(err),
+                    }
+                }
+
                 if let Some(arg) = arg {
-                    Ok(Attribute::Test(arg, top.map(|x| x.text)))
+                    Ok(Attribute::Test(arg, top.map(|x| x.text), item))
                 } else {
-                    Err(AttributeError::MismatchArgs("single identifier"))
+                    Err(err)
                 }
             }
             x if x == pat.cond_type => {
@@ -393,11 +503,36 @@ impl TryFrom<&veryl_parser::veryl_grammar_trait::Attribute> for Attribute {
                     Ok(Attribute::Expand(items))
                 }
             }
+            x if x == pat.flatten => {
+                let name = get_arg_ident(&value.attribute_opt, 0);
+                let pos = if name.is_some() { 1 } else { 0 };
+                let separator = get_arg_string(&value.attribute_opt, pos);
+                let len = pos + if separator.is_some() { 1 } else { 0 };
+
+                if get_args_len(&value.attribute_opt) == len {
+                    let separator = separator.map(|x| {
+                        let text = x.to_string();
+                        resource_table::insert_str(&text[1..text.len() - 1])
+                    });
+                    Ok(Attribute::Flatten(name.map(|x| x.text), separator))
+                } else {
+                    Err(AttributeError::MismatchArgs(
+                        "optional wrapper name identifier and separator string",
+                    ))
+                }
+            }
             _ => Err(AttributeError::UnknownAttribute),
         })
     }
 }
 
+/// Seeds and parameter sets to expand a test into multiple runs
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct TestItem {
+    pub seeds: Option<usize>,
+    pub params: Vec<(StrId, Vec<StrId>)>,
+}
+
 #[derive(Copy, Clone, Debug, PartialEq, Eq)]
 pub enum AllowItem {
     MissingPort,
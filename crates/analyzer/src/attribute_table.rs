@@ -1,7 +1,7 @@
 use crate::attribute::{AlignItem, Attribute, ExpandItem, FormatItem};
 use crate::range_table::RangeTable;
 use std::cell::RefCell;
-use veryl_parser::resource_table::PathId;
+use veryl_parser::resource_table::{PathId, StrId};
 use veryl_parser::token_range::TokenRange;
 use veryl_parser::veryl_token::Token;
 
@@ -38,6 +38,17 @@ pub fn is_expand(token: &Token, item: ExpandItem) -> bool {
     attrs.iter().any(|x| x.is_expand(item))
 }
 
+pub fn get_flatten(token: &Token) -> Option<(Option<StrId>, Option<StrId>)> {
+    let attrs = ATTRIBUTE_TABLE.with(|f| f.borrow().get(token));
+    attrs.iter().find_map(|x| {
+        if let Attribute::Flatten(name, separator) = x {
+            Some((*name, *separator))
+        } else {
+            None
+        }
+    })
+}
+
 pub fn contains(token: &Token, value: Attribute) -> bool {
     ATTRIBUTE_TABLE.with(|f| f.borrow().contains(token, &value))
 }
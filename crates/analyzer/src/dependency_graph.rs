@@ -0,0 +1,178 @@
+use crate::SVec;
+use crate::symbol::{ParameterKind, Symbol, SymbolKind};
+use crate::symbol_table;
+use crate::{HashMap, HashSet};
+use std::hash::{DefaultHasher, Hash, Hasher};
+use veryl_parser::resource_table::{PathId, StrId};
+use veryl_parser::veryl_token::{Token, TokenSource};
+
+/// File-level dependency graph built from references between symbols.
+///
+/// A file depends on another file if it refers (including through `import`) to a symbol
+/// which is defined in the other file.
+#[derive(Default)]
+pub struct DependencyGraph {
+    dependencies: HashMap<PathId, HashSet<PathId>>,
+}
+
+impl DependencyGraph {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Rebuilds the whole graph from the current symbol table
+    pub fn rebuild(&mut self) {
+        self.dependencies.clear();
+        for symbol in symbol_table::get_all() {
+            let Some(defined) = source_path(&symbol.token) else {
+                continue;
+            };
+            for reference in &symbol.references {
+                if let Some(path) = source_path(reference)
+                    && path != defined
+                {
+                    self.dependencies.entry(path).or_default().insert(defined);
+                }
+            }
+        }
+    }
+
+    /// Updates dependencies of the specified file only
+    pub fn update(&mut self, path: PathId) {
+        let mut dependencies = HashSet::default();
+        for symbol in symbol_table::get_all() {
+            let Some(defined) = source_path(&symbol.token) else {
+                continue;
+            };
+            if defined != path && symbol.references.iter().any(|x| x.source == path) {
+                dependencies.insert(defined);
+            }
+        }
+        self.dependencies.insert(path, dependencies);
+    }
+
+    pub fn remove(&mut self, path: PathId) {
+        self.dependencies.remove(&path);
+    }
+
+    /// Returns files which depend on the specified file
+    pub fn dependents(&self, path: PathId) -> Vec<PathId> {
+        let mut ret: Vec<_> = self
+            .dependencies
+            .iter()
+            .filter(|(_, x)| x.contains(&path))
+            .map(|(x, _)| *x)
+            .collect();
+        ret.sort();
+        ret
+    }
+}
+
+/// Hash of symbols defined in the specified file which can be referred from other files.
+///
+/// Members like struct members, enum variants and modport members are included,
+/// but local declarations in modules and functions are not because other files can't refer to them.
+/// Dependent files need re-analysis only if the hash is changed.
+pub fn file_signature(path: PathId) -> u64 {
+    let symbols: Vec<_> = symbol_table::get_all()
+        .into_iter()
+        .filter(|x| x.token.source == path)
+        .collect();
+    let table: HashMap<_, _> = symbols
+        .iter()
+        .map(|x| ((x.namespace.paths.clone(), x.token.text), x))
+        .collect();
+
+    let mut symbols: Vec<_> = symbols
+        .iter()
+        .filter(|x| is_public(x, &table))
+        .map(|x| format!("{}::{} {}", x.namespace, x.token.text, x.kind))
+        .collect();
+    symbols.sort();
+
+    let mut hasher = DefaultHasher::new();
+    symbols.hash(&mut hasher);
+    hasher.finish()
+}
+
+/// Whether the symbol can be referred from other files through its parents
+fn is_public(symbol: &Symbol, table: &HashMap<(SVec<StrId>, StrId), &Symbol>) -> bool {
+    let paths = &symbol.namespace.paths;
+    if paths.len() <= 1 {
+        return true;
+    }
+
+    let key = (paths[..paths.len() - 1].into(), paths[paths.len() - 1]);
+    let Some(parent) = table.get(&key) else {
+        return false;
+    };
+
+    let visible = match parent.kind {
+        SymbolKind::Package(_)
+        | SymbolKind::Interface(_)
+        | SymbolKind::Struct(_)
+        | SymbolKind::Union(_)
+        | SymbolKind::Enum(_)
+        | SymbolKind::Modport(_) => true,
+        SymbolKind::Module(_) | SymbolKind::Function(_) => match &symbol.kind {
+            SymbolKind::Port(_) | SymbolKind::GenericParameter(_) => true,
+            SymbolKind::Parameter(x) => x.kind == ParameterKind::Param,
+            _ => false,
+        },
+        _ => false,
+    };
+    visible && is_public(parent, table)
+}
+
+/// References from other files to symbols defined in the specified file.
+///
+/// Re-analysis of a file renews all symbols defined in it,
+/// so the references should be restored by `restore_references` after that.
+pub fn external_references(path: PathId) -> Vec<(String, Vec<Token>)> {
+    symbol_table::get_all()
+        .into_iter()
+        .filter(|x| x.token.source == path)
+        .filter_map(|x| {
+            let references: Vec<_> = x
+                .references
+                .iter()
+                .filter(|x| x.source != path)
+                .copied()
+                .collect();
+            if references.is_empty() {
+                None
+            } else {
+                Some((symbol_key(&x), references))
+            }
+        })
+        .collect()
+}
+
+pub fn restore_references(path: PathId, references: Vec<(String, Vec<Token>)>) {
+    let mut references: HashMap<_, _> = references.into_iter().collect();
+    for mut symbol in symbol_table::get_all() {
+        if symbol.token.source != path {
+            continue;
+        }
+        if let Some(tokens) = references.remove(&symbol_key(&symbol)) {
+            for token in tokens {
+                if !symbol.references.contains(&token) {
+                    symbol.references.push(token);
+                }
+            }
+            symbol_table::update(symbol);
+        }
+    }
+}
+
+fn symbol_key(symbol: &Symbol) -> String {
+    format!("{}::{}", symbol.namespace, symbol.token.text)
+}
+
+fn source_path(token: &Token) -> Option<PathId> {
+    if let TokenSource::File { path, .. } = token.source {
+        Some(path)
+    } else {
+        None
+    }
+}
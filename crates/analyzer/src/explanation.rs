@@ -0,0 +1,116 @@
+// Long-form explanations of diagnostics keyed by the diagnostic code.
+// Each explanation is a Markdown document which describes what the rule checks,
+// a failing example, the corrected example and how to suppress it.
+macro_rules! explanations {
+    ($($code:ident),* $(,)?) => {
+        const EXPLANATIONS: &[(&str, &str)] = &[
+            $((
+                stringify!($code),
+                include_str!(concat!("explanations/", stringify!($code), ".md")),
+            ),)*
+        ];
+    };
+}
+
+explanations!(
+    anonymous_identifier_usage,
+    call_non_function,
+    cyclic_type_dependency,
+    duplicated_identifier,
+    multiple_assignment,
+    invalid_assignment,
+    invalid_assignment_to_const,
+    invalid_connect_operand,
+    invalid_modifier,
+    invalid_direction,
+    invalid_factor,
+    invalid_select,
+    invalid_identifier,
+    invalid_import,
+    invalid_lsb,
+    invalid_msb,
+    invalid_number_character,
+    invalid_statement,
+    invalid_clock,
+    multiple_default_clock,
+    invalid_modport_variable_item,
+    invalid_modport_function_item,
+    unexpandable_modport,
+    unflattenable_module,
+    invalid_port_default_value,
+    invalid_reset,
+    multiple_default_reset,
+    invalid_reset_non_elaborative,
+    invalid_case_condition_non_elaborative,
+    invalid_cast,
+    invalid_test,
+    invalid_type_declaration,
+    incompat_proto,
+    missing_default_argument,
+    mismatch_function_arity,
+    mismatch_generics_arity,
+    mismatch_attribute_args,
+    mismatch_type,
+    mismatch_clock_domain,
+    mismatch_assignment,
+    missing_if_reset,
+    missing_port,
+    missing_clock_signal,
+    missing_reset_signal,
+    missing_reset_statement,
+    missing_tri,
+    missing_clock_domain,
+    mixed_function_argument,
+    sv_keyword_usage,
+    sv_with_implicit_reset,
+    unsupported_by_verilog2005,
+    unsupported_by_vhdl2008,
+    unsupported_by_circt,
+    invalid_clock_domain,
+    too_large_enum_variant,
+    unevaluatable_enum_variant_value,
+    invalid_enum_variant_value,
+    too_large_number,
+    too_much_enum_variant,
+    invisible_identifier,
+    undefined_identifier,
+    referring_package_before_definition,
+    unresolvable_generic_argument,
+    unknown_attribute,
+    invalid_enbed,
+    unknown_embed_lang,
+    unknown_embed_way,
+    unknown_include_way,
+    unknown_member,
+    unknown_unsafe,
+    private_member,
+    unknown_msb,
+    unknown_port,
+    unknown_param,
+    unenclosed_inner_if_expression,
+    unused_variable,
+    unused_return,
+    unassign_variable,
+    unassignable_output,
+    uncovered_branch,
+    reserved_identifier,
+    include_failure,
+    wrong_seperator,
+    infinite_recursion,
+    exceed_limit,
+    ambiguous_elsif,
+    last_item_with_define,
+);
+
+/// Returns the explanation of the diagnostic code
+pub fn get(code: &str) -> Option<&'static str> {
+    EXPLANATIONS
+        .iter()
+        .find(|(x, _)| *x == code)
+        .map(|(_, text)| *text)
+}
+
+/// Returns all diagnostic codes which have explanations
+pub fn codes() -> impl Iterator<Item = &'static str> {
+    EXPLANATIONS.iter().map(|(code, _)| *code)
+}
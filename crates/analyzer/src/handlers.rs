@@ -1,5 +1,6 @@
 pub mod check_anonymous;
 pub mod check_attribute;
+pub mod check_circt;
 pub mod check_clock_domain;
 pub mod check_clock_reset;
 pub mod check_connect_operation;
@@ -18,9 +19,12 @@ pub mod check_statement;
 pub mod check_type;
 pub mod check_unsafe;
 pub mod check_var_ref;
+pub mod check_verilog2005;
+pub mod check_vhdl2008;
 pub mod create_symbol_table;
 use check_anonymous::*;
 use check_attribute::*;
+use check_circt::*;
 use check_clock_domain::*;
 use check_clock_reset::*;
 use check_connect_operation::*;
@@ -39,6 +43,8 @@ use check_statement::*;
 use check_type::*;
 use check_unsafe::*;
 use check_var_ref::*;
+use check_verilog2005::*;
+use check_vhdl2008::*;
 use create_symbol_table::*;
 
 use crate::analyzer_error::AnalyzerError;
@@ -114,11 +120,14 @@ pub struct Pass2Handlers {
     check_clock_domain: CheckClockDomain,
     check_proto: CheckProto,
     check_type: CheckType,
-    enables: [bool; 13],
+    check_verilog2005: CheckVerilog2005,
+    check_vhdl2008: CheckVhdl2008,
+    check_circt: CheckCirct,
+    enables: [bool; 16],
 }
 
 impl Pass2Handlers {
-    pub fn new(_build_opt: &Build, _lint_opt: &Lint, env_var: &EnvVar) -> Self {
+    pub fn new(build_opt: &Build, _lint_opt: &Lint, env_var: &EnvVar) -> Self {
         Self {
             check_separator: CheckSeparator::new(),
             check_enum: CheckEnum::new(),
@@ -133,12 +142,18 @@ impl Pass2Handlers {
             check_clock_domain: CheckClockDomain::new(),
             check_proto: CheckProto::new(),
             check_type: CheckType::new(),
+            check_verilog2005: CheckVerilog2005::new(build_opt),
+            check_vhdl2008: CheckVhdl2008::new(build_opt),
+            check_circt: CheckCirct::new(build_opt),
             enables: env_var.analyzer_pass2_enables,
         }
     }
 
     pub fn get_handlers(&mut self) -> Vec<(bool, &mut dyn Handler)> {
         let en = &self.enables;
+        let verilog2005 = self.check_verilog2005.enabled;
+        let vhdl2008 = self.check_vhdl2008.enabled;
+        let circt = self.check_circt.enabled;
         vec![
             (en[0], &mut self.check_separator as &mut dyn Handler),
             (en[1], &mut self.check_enum as &mut dyn Handler),
@@ -153,6 +168,15 @@ impl Pass2Handlers {
             (en[10], &mut self.check_clock_domain as &mut dyn Handler),
             (en[11], &mut self.check_proto as &mut dyn Handler),
             (en[12], &mut self.check_type as &mut dyn Handler),
+            (
+                en[13] && verilog2005,
+                &mut self.check_verilog2005 as &mut dyn Handler,
+            ),
+            (
+                en[14] && vhdl2008,
+                &mut self.check_vhdl2008 as &mut dyn Handler,
+            ),
+            (en[15] && circt, &mut self.check_circt as &mut dyn Handler),
         ]
     }
 
@@ -171,6 +195,9 @@ impl Pass2Handlers {
         ret.append(&mut self.check_clock_domain.errors);
         ret.append(&mut self.check_proto.errors);
         ret.append(&mut self.check_type.errors);
+        ret.append(&mut self.check_verilog2005.errors);
+        ret.append(&mut self.check_vhdl2008.errors);
+        ret.append(&mut self.check_circt.errors);
         ret
     }
 }
@@ -0,0 +1,384 @@
+use crate::analyzer_error::AnalyzerError;
+use crate::evaluator::Evaluator;
+use crate::symbol::SymbolKind;
+use crate::symbol_table;
+use veryl_metadata::{Build, Language};
+use veryl_parser::ParolError;
+use veryl_parser::token_range::TokenRange;
+use veryl_parser::veryl_grammar_trait::*;
+use veryl_parser::veryl_walker::{Handler, HandlerPoint};
+
+const SUPPORTED_SYSTEM_FUNCTIONS: &[&str] = &["$clog2", "$signed", "$unsigned", "$bits", "$size"];
+
+/// Checks constructs which can't be elaborated into CIRCT `hw`/`comb`/`seq` dialects
+#[derive(Default)]
+pub struct CheckCirct {
+    pub errors: Vec<AnalyzerError>,
+    pub enabled: bool,
+    point: HandlerPoint,
+}
+
+impl CheckCirct {
+    pub fn new(build_opt: &Build) -> Self {
+        Self {
+            enabled: build_opt.language == Language::Circt,
+            ..Default::default()
+        }
+    }
+
+    fn error(&mut self, kind: &str, token: &TokenRange) {
+        self.errors
+            .push(AnalyzerError::unsupported_by_circt(kind, token));
+    }
+
+    /// Loops are unrolled, so the bounds should be known after elaboration
+    fn check_static(&mut self, kind: &str, exps: &[&Expression], token: &TokenRange) {
+        let mut evaluator = Evaluator::new(&[]);
+        if exps
+            .iter()
+            .any(|x| !evaluator.expression(x).is_known_static())
+        {
+            self.error(kind, token);
+        }
+    }
+}
+
+impl Handler for CheckCirct {
+    fn set_point(&mut self, p: HandlerPoint) {
+        self.point = p;
+    }
+}
+
+impl VerylGrammarTrait for CheckCirct {
+    fn attribute(&mut self, arg: &Attribute) -> Result<(), ParolError> {
+        if let HandlerPoint::Before = self.point {
+            let name = arg.identifier.identifier_token.to_string();
+            if matches!(name.as_str(), "ifdef" | "ifndef" | "elsif" | "else") {
+                self.error("conditional compilation", &arg.into());
+            }
+        }
+        Ok(())
+    }
+
+    fn based(&mut self, arg: &Based) -> Result<(), ParolError> {
+        if let HandlerPoint::Before = self.point {
+            let text = arg.based_token.to_string();
+            if let Some((_, value)) = text.split_once('\'')
+                && value.chars().any(|x| matches!(x, 'x' | 'X' | 'z' | 'Z'))
+            {
+                self.error("4-state literal", &arg.based_token.token.into());
+            }
+        }
+        Ok(())
+    }
+
+    fn all_bit(&mut self, arg: &AllBit) -> Result<(), ParolError> {
+        if let HandlerPoint::Before = self.point {
+            let text = arg.all_bit_token.to_string();
+            if !matches!(text.as_str(), "'0" | "'1") {
+                self.error("4-state literal", &arg.all_bit_token.token.into());
+            }
+        }
+        Ok(())
+    }
+
+    fn real_number(&mut self, arg: &RealNumber) -> Result<(), ParolError> {
+        if let HandlerPoint::Before = self.point {
+            self.error("real number", &arg.into());
+        }
+        Ok(())
+    }
+
+    fn factor(&mut self, arg: &Factor) -> Result<(), ParolError> {
+        if let HandlerPoint::Before = self.point {
+            let token: TokenRange = arg.into();
+            match arg {
+                Factor::QuoteLBraceArrayLiteralListRBrace(_) => self.error("array literal", &token),
+                Factor::StringLiteral(_) => self.error("string literal", &token),
+                Factor::TypeExpression(_) => self.error("type expression", &token),
+                Factor::FactorTypeFactor(_) => self.error("type as value", &token),
+                Factor::IdentifierFactor(x) => {
+                    let x = x.identifier_factor.as_ref();
+                    if let Some(opt) = &x.identifier_factor_opt {
+                        match opt.identifier_factor_opt_group.as_ref() {
+                            IdentifierFactorOptGroup::FunctionCall(_) => {
+                                let name = x.expression_identifier.identifier().to_string();
+                                if !name.starts_with('$') {
+                                    self.error("function call", &token);
+                                }
+                            }
+                            IdentifierFactorOptGroup::StructConstructor(_) => {
+                                self.error("struct constructor", &token);
+                            }
+                        }
+                    }
+                }
+                _ => (),
+            }
+        }
+        Ok(())
+    }
+
+    fn expression12(&mut self, arg: &Expression12) -> Result<(), ParolError> {
+        if let HandlerPoint::Before = self.point
+            && let Some(x) = &arg.expression12_opt
+            && matches!(
+                x.casting_type.as_ref(),
+                CastingType::F32(_) | CastingType::F64(_)
+            )
+        {
+            self.error("floating point casting", &x.casting_type.as_ref().into());
+        }
+        Ok(())
+    }
+
+    fn expression_identifier(&mut self, arg: &ExpressionIdentifier) -> Result<(), ParolError> {
+        if let HandlerPoint::Before = self.point
+            && let Ok(symbol) = symbol_table::resolve(arg.scoped_identifier.as_ref())
+            && matches!(symbol.found.kind, SymbolKind::Block)
+        {
+            self.error("reference into generate block", &arg.into());
+        }
+        Ok(())
+    }
+
+    fn scoped_identifier(&mut self, arg: &ScopedIdentifier) -> Result<(), ParolError> {
+        if let HandlerPoint::Before = self.point {
+            let name = arg.identifier().to_string();
+            if name == "$sv" {
+                self.error("SystemVerilog namespace", &arg.into());
+            } else if name.starts_with('$') && !SUPPORTED_SYSTEM_FUNCTIONS.contains(&name.as_str())
+            {
+                self.error("system function", &arg.into());
+            }
+        }
+        Ok(())
+    }
+
+    fn fixed_type(&mut self, arg: &FixedType) -> Result<(), ParolError> {
+        if let HandlerPoint::Before = self.point {
+            match arg {
+                FixedType::F32(_) | FixedType::F64(_) => {
+                    self.error("floating point type", &arg.into())
+                }
+                FixedType::Strin(_) => self.error("string type", &arg.into()),
+                _ => (),
+            }
+        }
+        Ok(())
+    }
+
+    fn identifier_statement(&mut self, arg: &IdentifierStatement) -> Result<(), ParolError> {
+        if let HandlerPoint::Before = self.point {
+            match arg.identifier_statement_group.as_ref() {
+                IdentifierStatementGroup::FunctionCall(_) => {
+                    let name = arg.expression_identifier.identifier().to_string();
+                    if name.starts_with('$') {
+                        self.error("system task", &arg.into());
+                    } else {
+                        self.error("function call", &arg.into());
+                    }
+                }
+                IdentifierStatementGroup::Assignment(x) => {
+                    if let AssignmentGroup::DiamondOperator(x) =
+                        x.assignment.assignment_group.as_ref()
+                    {
+                        self.error("connect operation", &x.diamond_operator.as_ref().into());
+                    }
+                }
+            }
+        }
+        Ok(())
+    }
+
+    fn break_statement(&mut self, arg: &BreakStatement) -> Result<(), ParolError> {
+        if let HandlerPoint::Before = self.point {
+            self.error("break statement", &arg.into());
+        }
+        Ok(())
+    }
+
+    fn for_statement(&mut self, arg: &ForStatement) -> Result<(), ParolError> {
+        if let HandlerPoint::Before = self.point {
+            let mut exps = vec![arg.range.expression.as_ref()];
+            if let Some(x) = &arg.range.range_opt {
+                exps.push(x.expression.as_ref());
+            }
+            if let Some(x) = &arg.for_statement_opt {
+                exps.push(x.expression.as_ref());
+            }
+            self.check_static("for statement with non-constant range", &exps, &arg.into());
+        }
+        Ok(())
+    }
+
+    fn var_declaration(&mut self, arg: &VarDeclaration) -> Result<(), ParolError> {
+        if let HandlerPoint::Before = self.point
+            && arg.array_type.array_type_opt.is_some()
+        {
+            self.error("array variable", &arg.into());
+        }
+        Ok(())
+    }
+
+    fn let_declaration(&mut self, arg: &LetDeclaration) -> Result<(), ParolError> {
+        if let HandlerPoint::Before = self.point
+            && arg.array_type.array_type_opt.is_some()
+        {
+            self.error("array variable", &arg.into());
+        }
+        Ok(())
+    }
+
+    fn let_statement(&mut self, arg: &LetStatement) -> Result<(), ParolError> {
+        if let HandlerPoint::Before = self.point
+            && arg.array_type.array_type_opt.is_some()
+        {
+            self.error("array variable", &arg.into());
+        }
+        Ok(())
+    }
+
+    fn const_declaration(&mut self, arg: &ConstDeclaration) -> Result<(), ParolError> {
+        if let HandlerPoint::Before = self.point
+            && let ConstDeclarationGroup::Type(_) = arg.const_declaration_group.as_ref()
+        {
+            self.error("type constant", &arg.into());
+        }
+        Ok(())
+    }
+
+    fn connect_declaration(&mut self, arg: &ConnectDeclaration) -> Result<(), ParolError> {
+        if let HandlerPoint::Before = self.point {
+            self.error("connect declaration", &arg.into());
+        }
+        Ok(())
+    }
+
+    fn initial_declaration(&mut self, arg: &InitialDeclaration) -> Result<(), ParolError> {
+        if let HandlerPoint::Before = self.point {
+            self.error("initial declaration", &arg.into());
+        }
+        Ok(())
+    }
+
+    fn assertion_statement(&mut self, arg: &AssertionStatement) -> Result<(), ParolError> {
+        if let HandlerPoint::Before = self.point {
+            self.error("assertion", &arg.into());
+        }
+        Ok(())
+    }
+
+    fn assertion_declaration(&mut self, arg: &AssertionDeclaration) -> Result<(), ParolError> {
+        if let HandlerPoint::Before = self.point {
+            self.error("assertion", &arg.into());
+        }
+        Ok(())
+    }
+
+    fn final_declaration(&mut self, arg: &FinalDeclaration) -> Result<(), ParolError> {
+        if let HandlerPoint::Before = self.point {
+            self.error("final declaration", &arg.into());
+        }
+        Ok(())
+    }
+
+    fn inst_declaration(&mut self, arg: &InstDeclaration) -> Result<(), ParolError> {
+        if let HandlerPoint::Before = self.point {
+            let is_interface = symbol_table::resolve(arg.scoped_identifier.as_ref())
+                .map(|x| matches!(x.found.kind, SymbolKind::Interface(_)))
+                .unwrap_or(false);
+            if is_interface {
+                self.error("interface instance", &arg.into());
+            } else if arg.inst_declaration_opt0.is_some() {
+                self.error("array of instance", &arg.into());
+            }
+        }
+        Ok(())
+    }
+
+    fn with_generic_parameter(&mut self, arg: &WithGenericParameter) -> Result<(), ParolError> {
+        if let HandlerPoint::Before = self.point {
+            self.error("generic parameter", &arg.into());
+        }
+        Ok(())
+    }
+
+    fn with_parameter_item(&mut self, arg: &WithParameterItem) -> Result<(), ParolError> {
+        if let HandlerPoint::Before = self.point {
+            match arg.with_parameter_item_group0.as_ref() {
+                WithParameterItemGroup0::ArrayType(x) => {
+                    if x.array_type.array_type_opt.is_some() {
+                        self.error("array parameter", &arg.into());
+                    }
+                }
+                WithParameterItemGroup0::Type(_) => self.error("type parameter", &arg.into()),
+            }
+        }
+        Ok(())
+    }
+
+    fn port_declaration_item(&mut self, arg: &PortDeclarationItem) -> Result<(), ParolError> {
+        if let HandlerPoint::Before = self.point {
+            match arg.port_declaration_item_group.as_ref() {
+                PortDeclarationItemGroup::PortTypeConcrete(x) => {
+                    let x = x.port_type_concrete.as_ref();
+                    match x.direction.as_ref() {
+                        Direction::Modport(_) => self.error("modport port", &arg.into()),
+                        Direction::Inout(_) => self.error("inout port", &arg.into()),
+                        _ if x.array_type.array_type_opt.is_some() => {
+                            self.error("array port", &arg.into())
+                        }
+                        _ => (),
+                    }
+                }
+                PortDeclarationItemGroup::PortTypeAbstract(_) => {
+                    self.error("interface port", &arg.into());
+                }
+            }
+        }
+        Ok(())
+    }
+
+    fn generate_for_declaration(&mut self, arg: &GenerateForDeclaration) -> Result<(), ParolError> {
+        if let HandlerPoint::Before = self.point {
+            let mut exps = vec![arg.range.expression.as_ref()];
+            if let Some(x) = &arg.range.range_opt {
+                exps.push(x.expression.as_ref());
+            }
+            if let Some(x) = &arg.generate_for_declaration_opt {
+                exps.push(x.expression.as_ref());
+            }
+            self.check_static("generate for with non-constant range", &exps, &arg.into());
+        }
+        Ok(())
+    }
+
+    fn interface_declaration(&mut self, arg: &InterfaceDeclaration) -> Result<(), ParolError> {
+        if let HandlerPoint::Before = self.point {
+            self.error("interface", &arg.identifier.as_ref().into());
+        }
+        Ok(())
+    }
+
+    fn alias_declaration(&mut self, arg: &AliasDeclaration) -> Result<(), ParolError> {
+        if let HandlerPoint::Before = self.point {
+            self.error("alias declaration", &arg.into());
+        }
+        Ok(())
+    }
+
+    fn embed_declaration(&mut self, arg: &EmbedDeclaration) -> Result<(), ParolError> {
+        if let HandlerPoint::Before = self.point {
+            self.error("embed declaration", &arg.into());
+        }
+        Ok(())
+    }
+
+    fn include_declaration(&mut self, arg: &IncludeDeclaration) -> Result<(), ParolError> {
+        if let HandlerPoint::Before = self.point {
+            self.error("include declaration", &arg.into());
+        }
+        Ok(())
+    }
+}
@@ -31,6 +31,41 @@ impl CheckClockDomain {
         self.always_ff_clock_domain = get_clock_domain(symbol, range);
     }
 
+    /// Sets the clock domain of `always_ff` or concurrent assertion from its clock signal
+    fn set_event_clock_domain(&mut self, clock: Option<HierarchicalIdentifier>, range: TokenRange) {
+        if let Some(clock) = clock {
+            // clock domain is assigned to base identifier
+            if let Ok(symbol) = symbol_table::resolve(clock.identifier.as_ref()) {
+                self.set_always_ff_clock_domain(&symbol.found, range);
+            }
+        } else if let Some(clock) = self.default_clock {
+            if let Some(symbol) = symbol_table::get(clock) {
+                self.set_always_ff_clock_domain(&symbol, range);
+            }
+        }
+    }
+
+    fn check_event_reset_domain(&mut self, reset: Option<HierarchicalIdentifier>) {
+        let Some(clock_domain) = self.always_ff_clock_domain else {
+            return;
+        };
+
+        let reset_domain = if let Some(reset) = reset {
+            // clock domain is assigned to base identifier
+            symbol_table::resolve(reset.identifier.as_ref())
+                .ok()
+                .and_then(|x| get_clock_domain(&x.found, x.found.token.into()))
+        } else {
+            self.default_reset
+                .and_then(symbol_table::get)
+                .and_then(|x| get_clock_domain(&x, x.token.into()))
+        };
+
+        if let Some(reset_domain) = reset_domain {
+            check_clock_domain(&reset_domain, &clock_domain, false, &mut self.errors);
+        }
+    }
+
     fn push_expr_clock_domain(&mut self, symbol: &Symbol, range: TokenRange) {
         if let Some(domain) = get_clock_domain(symbol, range) {
             self.expr_clock_domains.push(domain);
@@ -131,51 +166,12 @@ impl VerylGrammarTrait for CheckClockDomain {
         match self.point {
             HandlerPoint::Before => {
                 let range: TokenRange = arg.always_ff.always_ff_token.token.into();
-                if let Some(clock) = arg.get_explicit_clock() {
-                    // clock domain is assigned to base identifier
-                    if let Ok(symbol) = symbol_table::resolve(clock.identifier.as_ref()) {
-                        self.set_always_ff_clock_domain(&symbol.found, range);
-                    }
-                } else if let Some(clock) = self.default_clock {
-                    if let Some(symbol) = symbol_table::get(clock) {
-                        self.set_always_ff_clock_domain(&symbol, range);
-                    }
-                }
+                self.set_event_clock_domain(arg.get_explicit_clock(), range);
 
-                let check_reset = self.always_ff_clock_domain.is_some()
-                    && arg.has_if_reset()
-                    && (arg.has_explicit_clock() || arg.has_explicit_reset());
+                let check_reset =
+                    arg.has_if_reset() && (arg.has_explicit_clock() || arg.has_explicit_reset());
                 if check_reset {
-                    let clock_domain = self.always_ff_clock_domain.unwrap();
-
-                    if let Some(reset) = arg.get_explicit_reset() {
-                        // clock domain is assigned to base identifier
-                        if let Ok(symbol) = symbol_table::resolve(reset.identifier.as_ref()) {
-                            if let Some(reset_domain) =
-                                get_clock_domain(&symbol.found, symbol.found.token.into())
-                            {
-                                check_clock_domain(
-                                    &reset_domain,
-                                    &clock_domain,
-                                    false,
-                                    &mut self.errors,
-                                );
-                            }
-                        }
-                    } else if let Some(reset) = self.default_reset {
-                        if let Some(symbol) = symbol_table::get(reset) {
-                            if let Some(reset_domain) =
-                                get_clock_domain(&symbol, symbol.token.into())
-                            {
-                                check_clock_domain(
-                                    &reset_domain,
-                                    &clock_domain,
-                                    false,
-                                    &mut self.errors,
-                                );
-                            }
-                        }
-                    }
+                    self.check_event_reset_domain(arg.get_explicit_reset());
                 }
             }
             HandlerPoint::After => self.always_ff_clock_domain = None,
@@ -183,6 +179,34 @@ impl VerylGrammarTrait for CheckClockDomain {
         Ok(())
     }
 
+    fn assertion_declaration(&mut self, arg: &AssertionDeclaration) -> Result<(), ParolError> {
+        match self.point {
+            HandlerPoint::Before => {
+                let range: TokenRange = arg.assertion_keyword.as_ref().into();
+                self.set_event_clock_domain(arg.get_explicit_clock(), range);
+                if arg.has_explicit_reset() {
+                    self.check_event_reset_domain(arg.get_explicit_reset());
+                }
+                self.expr_clock_domains.clear();
+            }
+            HandlerPoint::After => {
+                self.check_expr_clock_domains(&arg.r_brace.r_brace_token.token);
+                self.always_ff_clock_domain = None;
+            }
+        }
+        Ok(())
+    }
+
+    fn assertion_statement(&mut self, arg: &AssertionStatement) -> Result<(), ParolError> {
+        match self.point {
+            HandlerPoint::Before => self.expr_clock_domains.clear(),
+            HandlerPoint::After => {
+                self.check_expr_clock_domains(&arg.semicolon.semicolon_token.token);
+            }
+        }
+        Ok(())
+    }
+
     fn assign_declaration(&mut self, arg: &AssignDeclaration) -> Result<(), ParolError> {
         match self.point {
             HandlerPoint::Before => self.expr_clock_domains.clear(),
@@ -3,6 +3,7 @@ use crate::evaluator::{EvaluatedValue, Evaluator};
 use crate::symbol::{SymbolId, SymbolKind, Type, TypeKind};
 use crate::symbol_table;
 use veryl_parser::ParolError;
+use veryl_parser::token_range::TokenExt;
 use veryl_parser::veryl_grammar_trait::*;
 use veryl_parser::veryl_walker::{Handler, HandlerPoint};
 
@@ -124,6 +125,27 @@ impl VerylGrammarTrait for CheckClockReset {
         Ok(())
     }
 
+    fn assertion_declaration(&mut self, arg: &AssertionDeclaration) -> Result<(), ParolError> {
+        if let HandlerPoint::Before = self.point {
+            let token = arg.assertion_keyword.first();
+
+            // clock and reset signals are inferred in the same manner as always_ff,
+            // but reset is optional because it is used as disable condition only
+            if !arg.has_explicit_clock() {
+                if let Some(id) = self.default_clock {
+                    symbol_table::add_reference(id, &token);
+                } else {
+                    self.errors
+                        .push(AnalyzerError::missing_clock_signal(&arg.into()))
+                }
+                if let Some(id) = self.default_reset {
+                    symbol_table::add_reference(id, &token);
+                }
+            }
+        }
+        Ok(())
+    }
+
     fn always_ff_clock(&mut self, arg: &AlwaysFfClock) -> Result<(), ParolError> {
         fn is_valid_clock(x: Type, n_of_selected: usize) -> bool {
             let n_of_selectable = x.width.len() + x.array.len();
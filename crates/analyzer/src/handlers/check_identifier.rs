@@ -206,6 +206,7 @@ impl CheckIdentifier {
                 self.errors.push(AnalyzerError::invalid_identifier(
                     &identifier,
                     &format!("prefix: {prefix}"),
+                    Some(&format!("{prefix}{identifier}")),
                     &token.into(),
                 ));
             }
@@ -215,6 +216,7 @@ impl CheckIdentifier {
                 self.errors.push(AnalyzerError::invalid_identifier(
                     &identifier,
                     &format!("suffix: {suffix}"),
+                    Some(&format!("{identifier}{suffix}")),
                     &token.into(),
                 ));
             }
@@ -227,9 +229,16 @@ impl CheckIdentifier {
                 Case::LowerCamel => is_lower_camel_case(&identifier),
             };
             if !pass {
+                let fixed = match case {
+                    Case::Snake => to_snake_case(&identifier),
+                    Case::ScreamingSnake => to_screaming_snake_case(&identifier),
+                    Case::UpperCamel => to_upper_camel_case(&identifier),
+                    Case::LowerCamel => to_lower_camel_case(&identifier),
+                };
                 self.errors.push(AnalyzerError::invalid_identifier(
                     &identifier,
                     &format!("case: {case}"),
+                    Some(&fixed),
                     &token.into(),
                 ));
             }
@@ -244,6 +253,7 @@ impl CheckIdentifier {
                 self.errors.push(AnalyzerError::invalid_identifier(
                     &identifier,
                     &format!("re_required: {re_required}"),
+                    None,
                     &token.into(),
                 ));
             }
@@ -258,6 +268,7 @@ impl CheckIdentifier {
                 self.errors.push(AnalyzerError::invalid_identifier(
                     &identifier,
                     &format!("re_forbidden: {re_forbidden}"),
+                    None,
                     &token.into(),
                 ));
             }
@@ -450,6 +461,69 @@ fn is_screaming_snake_case(text: &str) -> bool {
     text.to_ascii_uppercase() == text
 }
 
+/// Splits identifier into leading underscores and words.
+/// Words are separated by `_` and boundaries of case like `aB` and `ABc`.
+fn split_words(text: &str) -> (&str, Vec<String>) {
+    let body = text.trim_start_matches('_');
+    let head = &text[..text.len() - body.len()];
+
+    let mut words = Vec::new();
+    for part in body.split('_').filter(|x| !x.is_empty()) {
+        let chars: Vec<_> = part.chars().collect();
+        let mut word = String::new();
+        for (i, c) in chars.iter().enumerate() {
+            if i > 0 && c.is_ascii_uppercase() {
+                let prev = chars[i - 1];
+                let next_lower = chars.get(i + 1).is_some_and(|x| x.is_ascii_lowercase());
+                if !prev.is_ascii_uppercase() || next_lower {
+                    words.push(std::mem::take(&mut word));
+                }
+            }
+            word.push(*c);
+        }
+        words.push(word);
+    }
+    (head, words)
+}
+
+fn capitalize(word: &str) -> String {
+    let mut chars = word.chars();
+    match chars.next() {
+        Some(x) => x.to_ascii_uppercase().to_string() + &chars.as_str().to_ascii_lowercase(),
+        None => String::new(),
+    }
+}
+
+fn to_lower_camel_case(text: &str) -> String {
+    let (head, words) = split_words(text);
+    let mut ret = head.to_string();
+    for (i, word) in words.iter().enumerate() {
+        if i == 0 {
+            ret.push_str(&word.to_ascii_lowercase());
+        } else {
+            ret.push_str(&capitalize(word));
+        }
+    }
+    ret
+}
+
+fn to_upper_camel_case(text: &str) -> String {
+    let (head, words) = split_words(text);
+    let words: Vec<_> = words.iter().map(|x| capitalize(x)).collect();
+    format!("{head}{}", words.concat())
+}
+
+/// Converts identifier to snake_case keeping leading underscores
+pub fn to_snake_case(text: &str) -> String {
+    let (head, words) = split_words(text);
+    format!("{head}{}", words.join("_").to_ascii_lowercase())
+}
+
+fn to_screaming_snake_case(text: &str) -> String {
+    let (head, words) = split_words(text);
+    format!("{head}{}", words.join("_").to_ascii_uppercase())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -501,4 +575,20 @@ mod tests {
         assert_eq!(is_screaming_snake_case("a_B_c"), false);
         assert_eq!(is_screaming_snake_case("A_B_C"), true);
     }
+
+    #[test]
+    fn case_conversion() {
+        assert_eq!(to_lower_camel_case("ModuleA"), "moduleA");
+        assert_eq!(to_lower_camel_case("a_b_c"), "aBC");
+        assert_eq!(to_lower_camel_case("_abc_def"), "_abcDef");
+        assert_eq!(to_upper_camel_case("module_a"), "ModuleA");
+        assert_eq!(to_upper_camel_case("aBc"), "ABc");
+        assert_eq!(to_upper_camel_case("AXI_BUS"), "AxiBus");
+        assert_eq!(to_snake_case("ModuleA"), "module_a");
+        assert_eq!(to_snake_case("AXIBus"), "axi_bus");
+        assert_eq!(to_snake_case("A_B_C"), "a_b_c");
+        assert_eq!(to_snake_case("_aBc"), "_a_bc");
+        assert_eq!(to_screaming_snake_case("abcDef"), "ABC_DEF");
+        assert_eq!(to_screaming_snake_case("a_b_c"), "A_B_C");
+    }
 }
@@ -16,6 +16,7 @@ pub struct CheckModport {
     point: HandlerPoint,
     interface_namespace: Option<Namespace>,
     in_function: bool,
+    in_flatten_module: bool,
 }
 
 impl CheckModport {
@@ -95,6 +96,36 @@ impl VerylGrammarTrait for CheckModport {
                 }
             }
         }
+        if matches!(self.point, HandlerPoint::Before)
+            && self.in_flatten_module
+            && !self.in_function
+            && let Ok(symbol) = symbol_table::resolve(arg.identifier.as_ref())
+            && self.is_unexpandable_modport(&symbol.found)
+        {
+            self.errors.push(AnalyzerError::unflattenable_module(
+                &arg.identifier.identifier_token.token.to_string(),
+                "interface of the modport has parameters or is abstract",
+                &arg.identifier.as_ref().into(),
+            ));
+        }
+        Ok(())
+    }
+
+    fn module_declaration(&mut self, arg: &ModuleDeclaration) -> Result<(), ParolError> {
+        match self.point {
+            HandlerPoint::Before => {
+                let token = &arg.identifier.identifier_token.token;
+                self.in_flatten_module = attribute_table::get_flatten(token).is_some();
+                if self.in_flatten_module && arg.module_declaration_opt.is_some() {
+                    self.errors.push(AnalyzerError::unflattenable_module(
+                        &token.to_string(),
+                        "it has generic parameters",
+                        &arg.identifier.as_ref().into(),
+                    ));
+                }
+            }
+            HandlerPoint::After => self.in_flatten_module = false,
+        }
         Ok(())
     }
 
@@ -118,7 +118,7 @@ impl VerylGrammarTrait for CheckMsbLsb {
                         }
 
                         if let Some(demension_number) = demension_number {
-                            msb_table::insert(arg.msb_token.token.id, demension_number);
+                            msb_table::insert(&arg.msb_token.token, demension_number);
                             true
                         } else {
                             false
@@ -4,7 +4,9 @@ use crate::attribute::Attribute as Attr;
 use crate::attribute_table;
 use crate::namespace::Namespace;
 use crate::namespace_table;
-use crate::symbol::{GenericBoundKind, ProtoBound, Symbol, SymbolId, SymbolKind};
+use crate::suggestion::Suggestion;
+use crate::symbol::Direction as SymDirection;
+use crate::symbol::{GenericBoundKind, Port, ProtoBound, Symbol, SymbolId, SymbolKind};
 use crate::symbol_path::{GenericSymbolPath, GenericSymbolPathKind, SymbolPathNamespace};
 use crate::symbol_table;
 use veryl_parser::resource_table;
@@ -332,6 +334,35 @@ fn resolve_inst_type(arg: &InstTypeSource) -> Option<Symbol> {
     Some(symbol)
 }
 
+/// Connects missing output ports to `_` explicitly.
+/// Input and inout ports can't be fixed mechanically because they require a driver.
+fn missing_port_suggestion(arg: &InstDeclaration, ports: &[&Port]) -> Option<Suggestion> {
+    let items: Vec<_> = ports
+        .iter()
+        .filter(|x| x.property().direction == SymDirection::Output)
+        .map(|x| format!("{}: _", x.token.token))
+        .collect();
+    if items.is_empty() {
+        return None;
+    }
+    let items = items.join(", ");
+
+    if let Some(ref x) = arg.inst_declaration_opt2 {
+        let separator = match &x.inst_declaration_opt3 {
+            Some(x) if x.inst_port_list.inst_port_list_opt.is_none() => ", ",
+            _ => "",
+        };
+        let token = &x.r_paren.r_paren_token.token;
+        Some(Suggestion::Replace(
+            token.into(),
+            format!("{separator}{items})"),
+        ))
+    } else {
+        let token = &arg.semicolon.semicolon_token.token;
+        Some(Suggestion::Replace(token.into(), format!(" ({items});")))
+    }
+}
+
 impl VerylGrammarTrait for CheckType {
     fn user_defined_type(&mut self, _arg: &UserDefinedType) -> Result<(), ParolError> {
         match self.point {
@@ -709,21 +740,31 @@ impl VerylGrammarTrait for CheckType {
                 }
 
                 if check_port_connection {
-                    for port in &ports {
-                        if !connected_ports.contains(&port.name())
-                            && port.property().default_value.is_none()
-                            && !attribute_table::contains(
-                                &arg.inst.inst_token.token,
-                                Attr::Allow(AllowItem::MissingPort),
-                            )
-                        {
-                            let port = resource_table::get_str_value(port.name()).unwrap();
-                            self.errors.push(AnalyzerError::missing_port(
-                                name,
-                                &port,
-                                &arg.identifier.as_ref().into(),
-                            ));
-                        }
+                    let missing_ports: Vec<_> = ports
+                        .iter()
+                        .filter(|port| {
+                            !connected_ports.contains(&port.name())
+                                && port.property().default_value.is_none()
+                                && !attribute_table::contains(
+                                    &arg.inst.inst_token.token,
+                                    Attr::Allow(AllowItem::MissingPort),
+                                )
+                        })
+                        .collect();
+                    let suggestion = missing_port_suggestion(arg, &missing_ports);
+                    for port in &missing_ports {
+                        let suggestion = if port.property().direction == SymDirection::Output {
+                            suggestion.clone()
+                        } else {
+                            None
+                        };
+                        let port = resource_table::get_str_value(port.name()).unwrap();
+                        self.errors.push(AnalyzerError::missing_port(
+                            name,
+                            &port,
+                            suggestion,
+                            &arg.identifier.as_ref().into(),
+                        ));
                     }
                     for param in &connected_params {
                         if !params.iter().any(|x| &x.name == param) {
@@ -0,0 +1,375 @@
+use crate::analyzer_error::AnalyzerError;
+use crate::namespace_table;
+use crate::packed_layout;
+use crate::symbol::Type as SymType;
+use crate::symbol::{Direction, SymbolKind};
+use crate::symbol_table;
+use veryl_metadata::{Build, Language};
+use veryl_parser::ParolError;
+use veryl_parser::token_range::{TokenExt, TokenRange};
+use veryl_parser::veryl_grammar_trait::*;
+use veryl_parser::veryl_token::Token;
+use veryl_parser::veryl_walker::{Handler, HandlerPoint};
+
+/// Checks constructs which can't be lowered to Verilog-2005
+#[derive(Default)]
+pub struct CheckVerilog2005 {
+    pub errors: Vec<AnalyzerError>,
+    pub enabled: bool,
+    point: HandlerPoint,
+    in_interface: bool,
+    in_package: bool,
+    last_return: Option<Token>,
+}
+
+impl CheckVerilog2005 {
+    pub fn new(build_opt: &Build) -> Self {
+        Self {
+            enabled: build_opt.language == Language::Verilog2005,
+            ..Default::default()
+        }
+    }
+
+    fn error(&mut self, kind: &str, token: &TokenRange) {
+        self.errors
+            .push(AnalyzerError::unsupported_by_verilog2005(kind, token));
+    }
+}
+
+impl Handler for CheckVerilog2005 {
+    fn set_point(&mut self, p: HandlerPoint) {
+        self.point = p;
+    }
+}
+
+impl VerylGrammarTrait for CheckVerilog2005 {
+    fn struct_constructor(&mut self, arg: &StructConstructor) -> Result<(), ParolError> {
+        if let HandlerPoint::Before = self.point {
+            self.error("struct constructor", &arg.into());
+        }
+        Ok(())
+    }
+
+    fn factor(&mut self, arg: &Factor) -> Result<(), ParolError> {
+        if let HandlerPoint::Before = self.point
+            && let Factor::QuoteLBraceArrayLiteralListRBrace(_) = arg
+        {
+            self.error("array literal", &arg.into());
+        }
+        Ok(())
+    }
+
+    fn expression12(&mut self, arg: &Expression12) -> Result<(), ParolError> {
+        if let HandlerPoint::Before = self.point
+            && let Some(x) = &arg.expression12_opt
+        {
+            let supported = matches!(
+                x.casting_type.as_ref(),
+                CastingType::Bool(_)
+                    | CastingType::Clock(_)
+                    | CastingType::ClockPosedge(_)
+                    | CastingType::ClockNegedge(_)
+                    | CastingType::Reset(_)
+                    | CastingType::ResetAsyncHigh(_)
+                    | CastingType::ResetAsyncLow(_)
+                    | CastingType::ResetSyncHigh(_)
+                    | CastingType::ResetSyncLow(_)
+            );
+            if !supported {
+                self.error("type casting", &x.casting_type.as_ref().into());
+            }
+        }
+        Ok(())
+    }
+
+    fn operator07(&mut self, arg: &Operator07) -> Result<(), ParolError> {
+        if let HandlerPoint::Before = self.point {
+            let text = arg.operator07_token.to_string();
+            if text == "==?" || text == "!=?" {
+                self.error("wildcard equality", &arg.operator07_token.token.into());
+            }
+        }
+        Ok(())
+    }
+
+    fn msb(&mut self, arg: &Msb) -> Result<(), ParolError> {
+        if let HandlerPoint::Before = self.point {
+            self.error("`msb`", &arg.msb_token.token.into());
+        }
+        Ok(())
+    }
+
+    fn scoped_identifier(&mut self, arg: &ScopedIdentifier) -> Result<(), ParolError> {
+        if let HandlerPoint::Before = self.point
+            && arg.identifier().to_string() == "$sv"
+        {
+            self.error("SystemVerilog namespace", &arg.into());
+        }
+        Ok(())
+    }
+
+    fn scalar_type(&mut self, arg: &ScalarType) -> Result<(), ParolError> {
+        if let HandlerPoint::Before = self.point {
+            match arg.scalar_type_group.as_ref() {
+                ScalarTypeGroup::UserDefinedTypeScalarTypeOpt(x) => {
+                    let namespace = namespace_table::get(arg.first().id).unwrap();
+                    let r#type: SymType = arg.into();
+                    let is_data_type =
+                        symbol_table::resolve(x.user_defined_type.scoped_identifier.as_ref())
+                            .map(|x| {
+                                matches!(
+                                    x.found.kind,
+                                    SymbolKind::Enum(_)
+                                        | SymbolKind::Struct(_)
+                                        | SymbolKind::Union(_)
+                                        | SymbolKind::TypeDef(_)
+                                )
+                            })
+                            .unwrap_or(false);
+
+                    if x.scalar_type_opt.is_some() {
+                        self.error("packed array of user-defined type", &arg.into());
+                    } else if is_data_type
+                        && packed_layout::packed_width(&r#type, &namespace).is_none()
+                    {
+                        self.error("type without static width", &arg.into());
+                    }
+                }
+                ScalarTypeGroup::FactorType(x) => {
+                    if let FactorTypeGroup::VariableTypeFactorTypeOpt(x) =
+                        x.factor_type.factor_type_group.as_ref()
+                        && let Some(x) = &x.factor_type_opt
+                        && !x.width.width_list.is_empty()
+                    {
+                        self.error("multi-dimensional packed array", &arg.into());
+                    }
+                }
+            }
+        }
+        Ok(())
+    }
+
+    fn assignment(&mut self, arg: &Assignment) -> Result<(), ParolError> {
+        if let HandlerPoint::Before = self.point
+            && let AssignmentGroup::DiamondOperator(x) = arg.assignment_group.as_ref()
+        {
+            self.error("connect operation", &x.diamond_operator.as_ref().into());
+        }
+        Ok(())
+    }
+
+    fn connect_declaration(&mut self, arg: &ConnectDeclaration) -> Result<(), ParolError> {
+        if let HandlerPoint::Before = self.point {
+            self.error("connect declaration", &arg.into());
+        }
+        Ok(())
+    }
+
+    fn break_statement(&mut self, arg: &BreakStatement) -> Result<(), ParolError> {
+        if let HandlerPoint::Before = self.point {
+            self.error("break statement", &arg.into());
+        }
+        Ok(())
+    }
+
+    fn return_statement(&mut self, arg: &ReturnStatement) -> Result<(), ParolError> {
+        if let HandlerPoint::Before = self.point {
+            let is_last = self
+                .last_return
+                .is_some_and(|x| x.id == arg.r#return.return_token.token.id);
+            if !is_last {
+                self.error(
+                    "return statement except at the end of function",
+                    &arg.into(),
+                );
+            }
+        }
+        Ok(())
+    }
+
+    fn assertion_statement(&mut self, arg: &AssertionStatement) -> Result<(), ParolError> {
+        if let HandlerPoint::Before = self.point {
+            self.error("assertion", &arg.into());
+        }
+        Ok(())
+    }
+
+    fn assertion_declaration(&mut self, arg: &AssertionDeclaration) -> Result<(), ParolError> {
+        if let HandlerPoint::Before = self.point {
+            self.error("assertion", &arg.into());
+        }
+        Ok(())
+    }
+
+    fn final_declaration(&mut self, arg: &FinalDeclaration) -> Result<(), ParolError> {
+        if let HandlerPoint::Before = self.point {
+            self.error("final declaration", &arg.into());
+        }
+        Ok(())
+    }
+
+    fn with_generic_parameter(&mut self, arg: &WithGenericParameter) -> Result<(), ParolError> {
+        if let HandlerPoint::Before = self.point {
+            self.error("generic parameter", &arg.into());
+        }
+        Ok(())
+    }
+
+    fn with_parameter_item(&mut self, arg: &WithParameterItem) -> Result<(), ParolError> {
+        if let HandlerPoint::Before = self.point
+            && let WithParameterItemGroup0::Type(_) = arg.with_parameter_item_group0.as_ref()
+        {
+            self.error("type parameter", &arg.into());
+        }
+        Ok(())
+    }
+
+    fn const_declaration(&mut self, arg: &ConstDeclaration) -> Result<(), ParolError> {
+        if let HandlerPoint::Before = self.point
+            && let ConstDeclarationGroup::Type(_) = arg.const_declaration_group.as_ref()
+        {
+            self.error("type constant", &arg.into());
+        }
+        Ok(())
+    }
+
+    fn inst_declaration(&mut self, arg: &InstDeclaration) -> Result<(), ParolError> {
+        if let HandlerPoint::Before = self.point {
+            let is_interface = symbol_table::resolve(arg.scoped_identifier.as_ref())
+                .map(|x| matches!(x.found.kind, SymbolKind::Interface(_)))
+                .unwrap_or(false);
+            if is_interface && arg.inst_declaration_opt0.is_some() {
+                self.error("array of interface instance", &arg.into());
+            }
+        }
+        Ok(())
+    }
+
+    fn port_declaration_item(&mut self, arg: &PortDeclarationItem) -> Result<(), ParolError> {
+        if let HandlerPoint::Before = self.point {
+            match arg.port_declaration_item_group.as_ref() {
+                PortDeclarationItemGroup::PortTypeConcrete(x) => {
+                    let x = x.port_type_concrete.as_ref();
+                    let is_modport = matches!(
+                        x.direction.as_ref(),
+                        veryl_parser::veryl_grammar_trait::Direction::Modport(_)
+                    );
+                    if is_modport && x.array_type.array_type_opt.is_some() {
+                        self.error("array of modport port", &arg.into());
+                    }
+                }
+                PortDeclarationItemGroup::PortTypeAbstract(_) => {
+                    self.error("generic interface port", &arg.into());
+                }
+            }
+        }
+        Ok(())
+    }
+
+    fn function_declaration(&mut self, arg: &FunctionDeclaration) -> Result<(), ParolError> {
+        match self.point {
+            HandlerPoint::Before => {
+                if self.in_package || self.in_interface {
+                    self.error(
+                        "function in package or interface",
+                        &arg.identifier.as_ref().into(),
+                    );
+                }
+                if arg.function_declaration_opt1.is_none() {
+                    self.error("void function", &arg.identifier.as_ref().into());
+                }
+
+                if let Ok(symbol) = symbol_table::resolve(arg.identifier.as_ref())
+                    && let SymbolKind::Function(x) = symbol.found.kind
+                {
+                    for port in &x.ports {
+                        if port.property().direction != Direction::Input {
+                            self.error("non-input function argument", &port.token.token.into());
+                        }
+                    }
+                }
+
+                self.last_return = arg
+                    .statement_block
+                    .statement_block_list
+                    .last()
+                    .and_then(|x| {
+                        match x.statement_block_group.statement_block_group_group.as_ref() {
+                            StatementBlockGroupGroup::StatementBlockItem(x) => {
+                                match x.statement_block_item.as_ref() {
+                                    StatementBlockItem::Statement(x) => {
+                                        match x.statement.as_ref() {
+                                            Statement::ReturnStatement(x) => {
+                                                Some(x.return_statement.r#return.return_token.token)
+                                            }
+                                            _ => None,
+                                        }
+                                    }
+                                    _ => None,
+                                }
+                            }
+                            _ => None,
+                        }
+                    });
+            }
+            HandlerPoint::After => self.last_return = None,
+        }
+        Ok(())
+    }
+
+    fn interface_declaration(&mut self, arg: &InterfaceDeclaration) -> Result<(), ParolError> {
+        match self.point {
+            HandlerPoint::Before => {
+                if arg.interface_declaration_opt1.is_some() {
+                    self.error("parameterized interface", &arg.identifier.as_ref().into());
+                }
+                self.in_interface = true;
+            }
+            HandlerPoint::After => self.in_interface = false,
+        }
+        Ok(())
+    }
+
+    fn generate_item(&mut self, arg: &GenerateItem) -> Result<(), ParolError> {
+        if let HandlerPoint::Before = self.point
+            && self.in_interface
+        {
+            let kind = match arg {
+                GenerateItem::VarDeclaration(_)
+                | GenerateItem::ConstDeclaration(_)
+                | GenerateItem::TypeDefDeclaration(_)
+                | GenerateItem::EnumDeclaration(_)
+                | GenerateItem::StructUnionDeclaration(_)
+                | GenerateItem::ImportDeclaration(_)
+                | GenerateItem::AliasDeclaration(_)
+                | GenerateItem::FunctionDeclaration(_) => None,
+                GenerateItem::LetDeclaration(_) => Some("let declaration"),
+                GenerateItem::InstDeclaration(_) => Some("instance"),
+                GenerateItem::AlwaysFfDeclaration(_) => Some("always_ff declaration"),
+                GenerateItem::AlwaysCombDeclaration(_) => Some("always_comb declaration"),
+                GenerateItem::AssignDeclaration(_) => Some("assign declaration"),
+                GenerateItem::AssertionDeclaration(_) => None,
+                GenerateItem::ConnectDeclaration(_) => None,
+                GenerateItem::GenerateIfDeclaration(_) => Some("generate if"),
+                GenerateItem::GenerateForDeclaration(_) => Some("generate for"),
+                GenerateItem::GenerateBlockDeclaration(_) => Some("generate block"),
+                GenerateItem::InitialDeclaration(_) => Some("initial declaration"),
+                GenerateItem::FinalDeclaration(_) => None,
+                GenerateItem::UnsafeBlock(_) => Some("unsafe block"),
+                GenerateItem::EmbedDeclaration(_) => Some("embed declaration"),
+            };
+            if let Some(kind) = kind {
+                self.error(&format!("{kind} in interface"), &arg.into());
+            }
+        }
+        Ok(())
+    }
+
+    fn package_declaration(&mut self, _arg: &PackageDeclaration) -> Result<(), ParolError> {
+        match self.point {
+            HandlerPoint::Before => self.in_package = true,
+            HandlerPoint::After => self.in_package = false,
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,393 @@
+use crate::analyzer_error::AnalyzerError;
+use crate::symbol::{Direction, SymbolKind};
+use crate::symbol_table;
+use veryl_metadata::{Build, Language};
+use veryl_parser::ParolError;
+use veryl_parser::token_range::TokenRange;
+use veryl_parser::veryl_grammar_trait::*;
+use veryl_parser::veryl_walker::{Handler, HandlerPoint};
+
+const SUPPORTED_SYSTEM_FUNCTIONS: &[&str] = &[
+    "$clog2",
+    "$signed",
+    "$unsigned",
+    "$bits",
+    "$size",
+    "$display",
+    "$write",
+    "$info",
+    "$warning",
+    "$error",
+    "$fatal",
+    "$finish",
+    "$stop",
+    "$sin",
+    "$cos",
+    "$tan",
+    "$asin",
+    "$acos",
+    "$atan",
+    "$atan2",
+    "$sinh",
+    "$cosh",
+    "$tanh",
+    "$asinh",
+    "$acosh",
+    "$atanh",
+    "$exp",
+    "$ln",
+    "$log10",
+    "$pow",
+    "$sqrt",
+    "$floor",
+    "$ceil",
+];
+
+/// Checks constructs which can't be translated to VHDL-2008
+#[derive(Default)]
+pub struct CheckVhdl2008 {
+    pub errors: Vec<AnalyzerError>,
+    pub enabled: bool,
+    point: HandlerPoint,
+    conditional_expressions: Vec<TokenRange>,
+}
+
+impl CheckVhdl2008 {
+    pub fn new(build_opt: &Build) -> Self {
+        Self {
+            enabled: build_opt.language == Language::Vhdl2008,
+            ..Default::default()
+        }
+    }
+
+    fn error(&mut self, kind: &str, token: &TokenRange) {
+        self.errors
+            .push(AnalyzerError::unsupported_by_vhdl2008(kind, token));
+    }
+
+    /// Conditional expressions can be translated to conditional/selected assignments
+    /// only if they are the whole right-hand side of the assignment.
+    fn is_conditional_assignment(&self, token: &TokenRange) -> bool {
+        self.conditional_expressions
+            .iter()
+            .any(|x| x.beg.id == token.beg.id && x.end.id == token.end.id)
+    }
+}
+
+impl Handler for CheckVhdl2008 {
+    fn set_point(&mut self, p: HandlerPoint) {
+        self.point = p;
+    }
+}
+
+impl VerylGrammarTrait for CheckVhdl2008 {
+    fn attribute(&mut self, arg: &Attribute) -> Result<(), ParolError> {
+        if let HandlerPoint::Before = self.point {
+            let name = arg.identifier.identifier_token.to_string();
+            if matches!(name.as_str(), "ifdef" | "ifndef" | "elsif" | "else") {
+                self.error("conditional compilation", &arg.into());
+            }
+        }
+        Ok(())
+    }
+
+    fn if_expression(&mut self, arg: &IfExpression) -> Result<(), ParolError> {
+        if let HandlerPoint::Before = self.point
+            && !arg.if_expression_list.is_empty()
+        {
+            let token: TokenRange = arg.into();
+            if !self.is_conditional_assignment(&token) {
+                self.error("nested if expression", &token);
+            }
+        }
+        Ok(())
+    }
+
+    fn factor(&mut self, arg: &Factor) -> Result<(), ParolError> {
+        if let HandlerPoint::Before = self.point {
+            let token: TokenRange = arg.into();
+            match arg {
+                Factor::CaseExpression(_) if !self.is_conditional_assignment(&token) => {
+                    self.error("nested case expression", &token);
+                }
+                Factor::SwitchExpression(_) if !self.is_conditional_assignment(&token) => {
+                    self.error("nested switch expression", &token);
+                }
+                Factor::TypeExpression(_) => self.error("type expression", &token),
+                Factor::FactorTypeFactor(_) => self.error("type as value", &token),
+                _ => (),
+            }
+        }
+        Ok(())
+    }
+
+    fn expression12(&mut self, arg: &Expression12) -> Result<(), ParolError> {
+        if let HandlerPoint::Before = self.point
+            && let Some(x) = &arg.expression12_opt
+        {
+            let supported = matches!(
+                x.casting_type.as_ref(),
+                CastingType::Clock(_)
+                    | CastingType::ClockPosedge(_)
+                    | CastingType::ClockNegedge(_)
+                    | CastingType::Reset(_)
+                    | CastingType::ResetAsyncHigh(_)
+                    | CastingType::ResetAsyncLow(_)
+                    | CastingType::ResetSyncHigh(_)
+                    | CastingType::ResetSyncLow(_)
+                    | CastingType::UserDefinedType(_)
+                    | CastingType::Based(_)
+                    | CastingType::BaseLess(_)
+            );
+            if !supported {
+                self.error("type casting", &x.casting_type.as_ref().into());
+            }
+        }
+        Ok(())
+    }
+
+    fn scoped_identifier(&mut self, arg: &ScopedIdentifier) -> Result<(), ParolError> {
+        if let HandlerPoint::Before = self.point {
+            let name = arg.identifier().to_string();
+            if name == "$sv" {
+                self.error("SystemVerilog namespace", &arg.into());
+            } else if name.starts_with('$') && !SUPPORTED_SYSTEM_FUNCTIONS.contains(&name.as_str())
+            {
+                self.error("system function", &arg.into());
+            }
+        }
+        Ok(())
+    }
+
+    fn scalar_type(&mut self, arg: &ScalarType) -> Result<(), ParolError> {
+        if let HandlerPoint::Before = self.point {
+            match arg.scalar_type_group.as_ref() {
+                ScalarTypeGroup::UserDefinedTypeScalarTypeOpt(x) => {
+                    if x.scalar_type_opt.is_some() {
+                        self.error("packed array of user-defined type", &arg.into());
+                    }
+                }
+                ScalarTypeGroup::FactorType(x) => {
+                    if let FactorTypeGroup::VariableTypeFactorTypeOpt(x) =
+                        x.factor_type.factor_type_group.as_ref()
+                        && let Some(x) = &x.factor_type_opt
+                        && !x.width.width_list.is_empty()
+                    {
+                        self.error("multi-dimensional packed array", &arg.into());
+                    }
+                }
+            }
+        }
+        Ok(())
+    }
+
+    fn let_statement(&mut self, arg: &LetStatement) -> Result<(), ParolError> {
+        if let HandlerPoint::Before = self.point {
+            self.conditional_expressions
+                .push(arg.expression.as_ref().into());
+        }
+        Ok(())
+    }
+
+    fn assignment(&mut self, arg: &Assignment) -> Result<(), ParolError> {
+        if let HandlerPoint::Before = self.point {
+            match arg.assignment_group.as_ref() {
+                AssignmentGroup::Equ(_) => {
+                    self.conditional_expressions
+                        .push(arg.expression.as_ref().into());
+                }
+                AssignmentGroup::DiamondOperator(x) => {
+                    self.error("connect operation", &x.diamond_operator.as_ref().into());
+                }
+                AssignmentGroup::AssignmentOperator(_) => (),
+            }
+        }
+        Ok(())
+    }
+
+    fn for_statement(&mut self, arg: &ForStatement) -> Result<(), ParolError> {
+        if let HandlerPoint::Before = self.point
+            && arg.for_statement_opt.is_some()
+        {
+            self.error("for statement with step", &arg.into());
+        }
+        Ok(())
+    }
+
+    fn let_declaration(&mut self, arg: &LetDeclaration) -> Result<(), ParolError> {
+        if let HandlerPoint::Before = self.point {
+            self.conditional_expressions
+                .push(arg.expression.as_ref().into());
+            if arg.array_type.array_type_opt.is_some() {
+                self.error("let declaration of array", &arg.into());
+            }
+        }
+        Ok(())
+    }
+
+    fn const_declaration(&mut self, arg: &ConstDeclaration) -> Result<(), ParolError> {
+        if let HandlerPoint::Before = self.point
+            && let ConstDeclarationGroup::Type(_) = arg.const_declaration_group.as_ref()
+        {
+            self.error("type constant", &arg.into());
+        }
+        Ok(())
+    }
+
+    fn assign_declaration(&mut self, arg: &AssignDeclaration) -> Result<(), ParolError> {
+        if let HandlerPoint::Before = self.point {
+            self.conditional_expressions
+                .push(arg.expression.as_ref().into());
+        }
+        Ok(())
+    }
+
+    fn connect_declaration(&mut self, arg: &ConnectDeclaration) -> Result<(), ParolError> {
+        if let HandlerPoint::Before = self.point {
+            self.error("connect declaration", &arg.into());
+        }
+        Ok(())
+    }
+
+    fn struct_union(&mut self, arg: &StructUnion) -> Result<(), ParolError> {
+        if let HandlerPoint::Before = self.point
+            && let StructUnion::Union(x) = arg
+        {
+            self.error("union", &x.union.union_token.token.into());
+        }
+        Ok(())
+    }
+
+    fn assertion_statement(&mut self, arg: &AssertionStatement) -> Result<(), ParolError> {
+        if let HandlerPoint::Before = self.point {
+            self.error("assertion", &arg.into());
+        }
+        Ok(())
+    }
+
+    fn assertion_declaration(&mut self, arg: &AssertionDeclaration) -> Result<(), ParolError> {
+        if let HandlerPoint::Before = self.point {
+            self.error("assertion", &arg.into());
+        }
+        Ok(())
+    }
+
+    fn final_declaration(&mut self, arg: &FinalDeclaration) -> Result<(), ParolError> {
+        if let HandlerPoint::Before = self.point {
+            self.error("final declaration", &arg.into());
+        }
+        Ok(())
+    }
+
+    fn inst_declaration(&mut self, arg: &InstDeclaration) -> Result<(), ParolError> {
+        if let HandlerPoint::Before = self.point {
+            let is_interface = symbol_table::resolve(arg.scoped_identifier.as_ref())
+                .map(|x| matches!(x.found.kind, SymbolKind::Interface(_)))
+                .unwrap_or(false);
+            if is_interface {
+                self.error("interface instance", &arg.into());
+            } else if arg.inst_declaration_opt0.is_some() {
+                self.error("array of instance", &arg.into());
+            }
+        }
+        Ok(())
+    }
+
+    fn with_generic_parameter(&mut self, arg: &WithGenericParameter) -> Result<(), ParolError> {
+        if let HandlerPoint::Before = self.point {
+            self.error("generic parameter", &arg.into());
+        }
+        Ok(())
+    }
+
+    fn with_parameter_item(&mut self, arg: &WithParameterItem) -> Result<(), ParolError> {
+        if let HandlerPoint::Before = self.point {
+            match arg.with_parameter_item_group0.as_ref() {
+                WithParameterItemGroup0::ArrayType(x) => {
+                    if x.array_type.array_type_opt.is_some() {
+                        self.error("array parameter", &arg.into());
+                    }
+                }
+                WithParameterItemGroup0::Type(_) => self.error("type parameter", &arg.into()),
+            }
+        }
+        Ok(())
+    }
+
+    fn port_declaration_item(&mut self, arg: &PortDeclarationItem) -> Result<(), ParolError> {
+        if let HandlerPoint::Before = self.point {
+            match arg.port_declaration_item_group.as_ref() {
+                PortDeclarationItemGroup::PortTypeConcrete(x) => {
+                    let x = x.port_type_concrete.as_ref();
+                    if let veryl_parser::veryl_grammar_trait::Direction::Modport(_) =
+                        x.direction.as_ref()
+                    {
+                        self.error("modport port", &arg.into());
+                    } else if x.array_type.array_type_opt.is_some() {
+                        self.error("array port", &arg.into());
+                    }
+                }
+                PortDeclarationItemGroup::PortTypeAbstract(_) => {
+                    self.error("interface port", &arg.into());
+                }
+            }
+        }
+        Ok(())
+    }
+
+    fn function_declaration(&mut self, arg: &FunctionDeclaration) -> Result<(), ParolError> {
+        if let HandlerPoint::Before = self.point {
+            if arg.function_declaration_opt1.is_none() {
+                self.error("void function", &arg.identifier.as_ref().into());
+            }
+
+            if let Ok(symbol) = symbol_table::resolve(arg.identifier.as_ref())
+                && let SymbolKind::Function(x) = symbol.found.kind
+            {
+                for port in &x.ports {
+                    if port.property().direction != Direction::Input {
+                        self.error("non-input function argument", &port.token.token.into());
+                    }
+                }
+            }
+        }
+        Ok(())
+    }
+
+    fn generate_for_declaration(&mut self, arg: &GenerateForDeclaration) -> Result<(), ParolError> {
+        if let HandlerPoint::Before = self.point
+            && arg.generate_for_declaration_opt.is_some()
+        {
+            self.error("generate for with step", &arg.into());
+        }
+        Ok(())
+    }
+
+    fn interface_declaration(&mut self, arg: &InterfaceDeclaration) -> Result<(), ParolError> {
+        if let HandlerPoint::Before = self.point {
+            self.error("interface", &arg.identifier.as_ref().into());
+        }
+        Ok(())
+    }
+
+    fn alias_declaration(&mut self, arg: &AliasDeclaration) -> Result<(), ParolError> {
+        if let HandlerPoint::Before = self.point {
+            self.error("alias declaration", &arg.into());
+        }
+        Ok(())
+    }
+
+    fn embed_declaration(&mut self, arg: &EmbedDeclaration) -> Result<(), ParolError> {
+        if let HandlerPoint::Before = self.point {
+            self.error("embed declaration", &arg.into());
+        }
+        Ok(())
+    }
+
+    fn include_declaration(&mut self, arg: &IncludeDeclaration) -> Result<(), ParolError> {
+        if let HandlerPoint::Before = self.point {
+            self.error("include declaration", &arg.into());
+        }
+        Ok(())
+    }
+}
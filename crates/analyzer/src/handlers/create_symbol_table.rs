@@ -810,6 +810,7 @@ impl VerylGrammarTrait for CreateSymbolTable {
                 suffix,
                 clock_domain,
                 loop_variable: false,
+                declaration: Some(arg.into()),
             };
             let kind = SymbolKind::Variable(property);
 
@@ -856,6 +857,7 @@ impl VerylGrammarTrait for CreateSymbolTable {
                     suffix: None,
                     clock_domain: SymClockDomain::None,
                     loop_variable: true,
+                    declaration: None,
                 };
                 let kind = SymbolKind::Variable(property);
                 self.insert_symbol(&arg.identifier.identifier_token.token, kind, false);
@@ -892,6 +894,7 @@ impl VerylGrammarTrait for CreateSymbolTable {
                 suffix,
                 clock_domain,
                 loop_variable: false,
+                declaration: Some(arg.into()),
             };
             let kind = SymbolKind::Variable(property);
 
@@ -929,6 +932,7 @@ impl VerylGrammarTrait for CreateSymbolTable {
                 suffix,
                 clock_domain,
                 loop_variable: false,
+                declaration: Some(arg.into()),
             };
             let kind = SymbolKind::Variable(property);
 
@@ -1358,6 +1362,7 @@ impl VerylGrammarTrait for CreateSymbolTable {
                 } else {
                     vec![ConnectTargetIdentifier {
                         path: vec![(port.text, vec![])],
+                        tokens: vec![port],
                     }]
                 };
                 let expression = if let Some(x) = &arg.inst_port_item_opt {
@@ -2242,8 +2247,8 @@ impl VerylGrammarTrait for CreateSymbolTable {
 
             let attrs = attribute_table::get(&arg.embed.embed_token.token);
             for attr in attrs {
-                if let Attr::Test(x, y) = attr {
-                    test_attr = Some((x, y));
+                if let Attr::Test(x, y, z) = attr {
+                    test_attr = Some((x, y, z));
                 }
             }
 
@@ -2254,7 +2259,7 @@ impl VerylGrammarTrait for CreateSymbolTable {
                 _ => None,
             };
 
-            if let (Some((token, top)), Some(r#type)) = (test_attr, r#type) {
+            if let (Some((token, top, item)), Some(r#type)) = (test_attr, r#type) {
                 let path = if let TokenSource::File { path, .. } = content.source {
                     path
                 } else {
@@ -2266,7 +2271,13 @@ impl VerylGrammarTrait for CreateSymbolTable {
                         .push(AnalyzerError::invalid_test("`cocotb` test requires top module name at the second argument of `#[test]` attribute", &token.into()));
                 }
 
-                let property = TestProperty { r#type, path, top };
+                let property = TestProperty {
+                    r#type,
+                    path,
+                    top,
+                    seeds: item.seeds,
+                    params: item.params,
+                };
                 self.insert_symbol(&token, SymbolKind::Test(property), false);
             }
         }
@@ -2280,8 +2291,8 @@ impl VerylGrammarTrait for CreateSymbolTable {
 
             let attrs = attribute_table::get(&arg.include.include_token.token);
             for attr in attrs {
-                if let Attr::Test(x, y) = attr {
-                    test_attr = Some((x, y));
+                if let Attr::Test(x, y, z) = attr {
+                    test_attr = Some((x, y, z));
                 }
             }
 
@@ -2292,7 +2303,7 @@ impl VerylGrammarTrait for CreateSymbolTable {
                 _ => None,
             };
 
-            if let (Some((token, top)), Some(r#type)) = (test_attr, r#type) {
+            if let (Some((token, top, item)), Some(r#type)) = (test_attr, r#type) {
                 let path = if let TokenSource::File { path, .. } = content.source {
                     path
                 } else {
@@ -2304,7 +2315,13 @@ impl VerylGrammarTrait for CreateSymbolTable {
                         .push(AnalyzerError::invalid_test("`cocotb` test requires top module name at the second argument of `#[test]` attribute", &token.into()));
                 }
 
-                let property = TestProperty { r#type, path, top };
+                let property = TestProperty {
+                    r#type,
+                    path,
+                    top,
+                    seeds: item.seeds,
+                    params: item.params,
+                };
                 self.insert_symbol(&token, SymbolKind::Test(property), false);
             }
         }
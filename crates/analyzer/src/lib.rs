@@ -4,15 +4,19 @@ pub mod attribute;
 pub mod attribute_table;
 pub mod connect_operation_table;
 pub mod definition_table;
+pub mod dependency_graph;
 pub mod evaluator;
+pub mod explanation;
 pub mod handlers;
 pub mod instance_history;
 pub mod msb_table;
 pub mod multi_sources;
 pub mod namespace;
 pub mod namespace_table;
+pub mod packed_layout;
 pub mod range_table;
 pub mod reference_table;
+pub mod suggestion;
 pub mod sv_system_function;
 pub mod symbol;
 pub mod symbol_path;
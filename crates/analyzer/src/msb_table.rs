@@ -1,19 +1,25 @@
 use crate::HashMap;
 use std::cell::RefCell;
-use veryl_parser::resource_table::TokenId;
+use veryl_parser::resource_table::{PathId, TokenId};
+use veryl_parser::veryl_token::{Token, TokenSource};
 
 #[derive(Clone, Default, Debug)]
 pub struct MsbTable {
-    table: HashMap<TokenId, usize>,
+    table: HashMap<TokenId, (TokenSource, usize)>,
 }
 
 impl MsbTable {
-    pub fn insert(&mut self, id: TokenId, dimension_number: usize) {
-        self.table.insert(id, dimension_number);
+    pub fn insert(&mut self, token: &Token, dimension_number: usize) {
+        self.table
+            .insert(token.id, (token.source, dimension_number));
     }
 
-    pub fn get(&self, id: TokenId) -> Option<&usize> {
-        self.table.get(&id)
+    pub fn get(&self, id: TokenId) -> Option<usize> {
+        self.table.get(&id).map(|x| x.1)
+    }
+
+    pub fn drop(&mut self, path: PathId) {
+        self.table.retain(|_, x| x.0 != path);
     }
 
     pub fn clear(&mut self) {
@@ -23,12 +29,16 @@ impl MsbTable {
 
 thread_local!(static MSB_TABLE: RefCell<MsbTable> = RefCell::new(MsbTable::default()));
 
-pub fn insert(id: TokenId, dimension_number: usize) {
-    MSB_TABLE.with(|f| f.borrow_mut().insert(id, dimension_number))
+pub fn insert(token: &Token, dimension_number: usize) {
+    MSB_TABLE.with(|f| f.borrow_mut().insert(token, dimension_number))
 }
 
 pub fn get(id: TokenId) -> Option<usize> {
-    MSB_TABLE.with(|f| f.borrow().get(id).cloned())
+    MSB_TABLE.with(|f| f.borrow().get(id))
+}
+
+pub fn drop(path: PathId) {
+    MSB_TABLE.with(|f| f.borrow_mut().drop(path))
 }
 
 pub fn clear() {
@@ -0,0 +1,227 @@
+use crate::evaluator::Evaluator;
+use crate::namespace::Namespace;
+use crate::symbol::{Symbol, SymbolKind, Type, TypeKind};
+use crate::symbol_table;
+use veryl_parser::ParolError;
+use veryl_parser::resource_table::StrId;
+use veryl_parser::veryl_grammar_trait::*;
+use veryl_parser::veryl_walker::{Handler, HandlerPoint, VerylWalker};
+
+/// Layout of a member of packed struct/union
+#[derive(Clone, Debug)]
+pub struct MemberLayout {
+    pub lsb: usize,
+    pub width: usize,
+    pub r#type: Type,
+    pub namespace: Namespace,
+}
+
+/// Returns the packed width of the type.
+///
+/// `None` is returned if the width can't be determined statically,
+/// e.g. it depends on overridable parameters.
+pub fn packed_width(r#type: &Type, namespace: &Namespace) -> Option<usize> {
+    let base = match &r#type.kind {
+        TypeKind::UserDefined(_) => {
+            let symbol = resolve_type(r#type, namespace)?;
+            match &symbol.kind {
+                SymbolKind::TypeDef(x) => packed_width(&x.r#type, &symbol.namespace)?,
+                SymbolKind::Enum(x) => {
+                    if let Some(x) = &x.r#type {
+                        packed_width(x, &symbol.namespace)?
+                    } else {
+                        x.width
+                    }
+                }
+                SymbolKind::Struct(_) | SymbolKind::Union(_) => {
+                    let members = members(&symbol)?;
+                    let widths = members.iter().map(|(_, x)| x.width);
+                    if matches!(symbol.kind, SymbolKind::Struct(_)) {
+                        widths.sum()
+                    } else {
+                        widths.max().unwrap_or(0)
+                    }
+                }
+                _ => return None,
+            }
+        }
+        TypeKind::Clock
+        | TypeKind::ClockPosedge
+        | TypeKind::ClockNegedge
+        | TypeKind::Reset
+        | TypeKind::ResetAsyncHigh
+        | TypeKind::ResetAsyncLow
+        | TypeKind::ResetSyncHigh
+        | TypeKind::ResetSyncLow
+        | TypeKind::Bit
+        | TypeKind::Logic
+        | TypeKind::Bool => 1,
+        TypeKind::U8 | TypeKind::I8 => 8,
+        TypeKind::U16 | TypeKind::I16 => 16,
+        TypeKind::U32 | TypeKind::I32 | TypeKind::F32 => 32,
+        TypeKind::U64 | TypeKind::I64 | TypeKind::F64 => 64,
+        _ => return None,
+    };
+
+    let mut ret = base;
+    for x in &r#type.width {
+        ret *= static_value(x)?;
+    }
+    Some(ret)
+}
+
+/// Returns the layout of the specified member if the type is packed struct/union.
+pub fn member_layout(r#type: &Type, namespace: &Namespace, member: StrId) -> Option<MemberLayout> {
+    if !r#type.width.is_empty() {
+        return None;
+    }
+
+    let symbol = resolve_type(r#type, namespace)?;
+    match &symbol.kind {
+        SymbolKind::TypeDef(x) => member_layout(&x.r#type, &symbol.namespace, member),
+        SymbolKind::Struct(_) | SymbolKind::Union(_) => struct_layout(&symbol)?
+            .into_iter()
+            .find(|(name, _)| *name == member)
+            .map(|(_, x)| x),
+        _ => None,
+    }
+}
+
+/// Returns the layouts of all members of the packed struct/union symbol in declaration order.
+pub fn struct_layout(symbol: &Symbol) -> Option<Vec<(StrId, MemberLayout)>> {
+    let mut members = members(symbol)?;
+    let is_struct = matches!(symbol.kind, SymbolKind::Struct(_));
+
+    // The first member of struct is placed at MSB
+    if is_struct {
+        let mut lsb: usize = members.iter().map(|(_, x)| x.width).sum();
+        for (_, x) in &mut members {
+            lsb -= x.width;
+            x.lsb = lsb;
+        }
+    }
+    Some(members)
+}
+
+/// Returns whether the type is struct/union, including type aliases of them.
+pub fn is_struct_union(r#type: &Type, namespace: &Namespace) -> bool {
+    if let Some(symbol) = resolve_type(r#type, namespace) {
+        match &symbol.kind {
+            SymbolKind::TypeDef(x) => is_struct_union(&x.r#type, &symbol.namespace),
+            SymbolKind::Struct(_) | SymbolKind::Union(_) => true,
+            _ => false,
+        }
+    } else {
+        false
+    }
+}
+
+/// Evaluates the expression if it doesn't depend on overridable parameters.
+pub fn static_value(arg: &Expression) -> Option<usize> {
+    static_integer(arg)?.try_into().ok()
+}
+
+/// Evaluates the expression as a signed integer if it doesn't depend on overridable parameters.
+pub fn static_integer(arg: &Expression) -> Option<isize> {
+    let mut checker = StaticChecker::default();
+    VerylWalker::expression(&mut checker, arg);
+    if !checker.is_static {
+        return None;
+    }
+
+    let mut evaluator = Evaluator::new(&[]);
+    evaluator.expression(arg).get_value()
+}
+
+fn resolve_type(r#type: &Type, namespace: &Namespace) -> Option<Symbol> {
+    let TypeKind::UserDefined(x) = &r#type.kind else {
+        return None;
+    };
+    // Generic types are not supported
+    if x.path.paths.iter().any(|x| !x.arguments.is_empty()) {
+        return None;
+    }
+    symbol_table::resolve((&x.path.generic_path(), namespace))
+        .ok()
+        .map(|x| x.found)
+}
+
+fn members(symbol: &Symbol) -> Option<Vec<(StrId, MemberLayout)>> {
+    let members = match &symbol.kind {
+        SymbolKind::Struct(x) if x.generic_parameters.is_empty() => &x.members,
+        SymbolKind::Union(x) if x.generic_parameters.is_empty() => &x.members,
+        _ => return None,
+    };
+
+    let mut ret = Vec::new();
+    for id in members {
+        let member = symbol_table::get(*id)?;
+        let r#type = match &member.kind {
+            SymbolKind::StructMember(x) => x.r#type.clone(),
+            SymbolKind::UnionMember(x) => x.r#type.clone(),
+            _ => return None,
+        };
+        let width = packed_width(&r#type, &member.namespace)?;
+        ret.push((
+            member.token.text,
+            MemberLayout {
+                lsb: 0,
+                width,
+                r#type,
+                namespace: member.namespace.clone(),
+            },
+        ));
+    }
+    Some(ret)
+}
+
+/// Checks whether identifiers in an expression refer only constants
+struct StaticChecker {
+    is_static: bool,
+    point: HandlerPoint,
+}
+
+impl Default for StaticChecker {
+    fn default() -> Self {
+        Self {
+            is_static: true,
+            point: HandlerPoint::Before,
+        }
+    }
+}
+
+impl VerylWalker for StaticChecker {
+    fn get_handlers(&mut self) -> Option<Vec<(bool, &mut dyn Handler)>> {
+        Some(vec![(true, self)])
+    }
+}
+
+impl Handler for StaticChecker {
+    fn set_point(&mut self, p: HandlerPoint) {
+        self.point = p;
+    }
+}
+
+impl VerylGrammarTrait for StaticChecker {
+    fn expression_identifier(&mut self, arg: &ExpressionIdentifier) -> Result<(), ParolError> {
+        if let HandlerPoint::Before = self.point {
+            // System functions like `$clog2` are evaluated by the arguments
+            if arg.identifier().to_string().starts_with('$') {
+                return Ok(());
+            }
+
+            let is_static = match symbol_table::resolve(arg) {
+                Ok(x) => match &x.found.kind {
+                    SymbolKind::Parameter(x) => {
+                        x.kind.is_const() && static_value(&x.value).is_some()
+                    }
+                    SymbolKind::EnumMember(_) => true,
+                    _ => false,
+                },
+                Err(_) => false,
+            };
+            self.is_static &= is_static;
+        }
+        Ok(())
+    }
+}
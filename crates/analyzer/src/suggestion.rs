@@ -0,0 +1,82 @@
+use crate::namespace::Namespace;
+use crate::symbol::Symbol;
+use crate::symbol_path::SymbolPath;
+use crate::symbol_table;
+use veryl_parser::resource_table::{self, PathId};
+use veryl_parser::token_range::TokenRange;
+use veryl_parser::veryl_token::{Token, TokenSource};
+
+/// Machine-applicable fix of a diagnostic
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Suggestion {
+    /// Replace the source text of the range
+    Replace(TokenRange, String),
+    /// Rename the declared identifier and all references to it
+    Rename(Token, String),
+}
+
+/// Replacement of the source text at byte offsets `beg..end`
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Edit {
+    pub path: PathId,
+    pub beg: usize,
+    pub end: usize,
+    pub text: String,
+}
+
+impl Suggestion {
+    /// Resolves the suggestion into edits of source files.
+    /// References of renamed identifiers are gathered from the symbol table,
+    /// so it should be called after all passes of analysis.
+    /// Renaming to an identifier which already exists in the scope gives no edits.
+    pub fn edits(&self) -> Vec<Edit> {
+        let mut ret: Vec<_> = match self {
+            Suggestion::Replace(range, text) => {
+                edit(&range.beg, &range.end, text).into_iter().collect()
+            }
+            Suggestion::Rename(token, text) => {
+                let mut tokens = vec![*token];
+                if let Some(symbol) = renamed_symbol(token) {
+                    let path = SymbolPath::new(&[resource_table::insert_str(text)]);
+                    if symbol_table::resolve((&path, &symbol.namespace)).is_ok() {
+                        return Vec::new();
+                    }
+                    tokens.extend(symbol.references.iter().copied());
+                }
+                tokens.iter().filter_map(|x| edit(x, x, text)).collect()
+            }
+        };
+        ret.sort();
+        ret.dedup();
+        ret
+    }
+
+    /// Returns the scope and the new name if the suggestion renames a symbol
+    pub fn rename_target(&self) -> Option<(Namespace, String)> {
+        if let Suggestion::Rename(token, text) = self {
+            let symbol = renamed_symbol(token)?;
+            Some((symbol.namespace, text.clone()))
+        } else {
+            None
+        }
+    }
+}
+
+fn renamed_symbol(token: &Token) -> Option<Symbol> {
+    symbol_table::get_all()
+        .into_iter()
+        .find(|x| x.token.id == token.id)
+}
+
+fn edit(beg: &Token, end: &Token, text: &str) -> Option<Edit> {
+    if let TokenSource::File { path, .. } = beg.source {
+        Some(Edit {
+            path,
+            beg: beg.pos as usize,
+            end: (end.pos + end.length) as usize,
+            text: text.to_string(),
+        })
+    } else {
+        None
+    }
+}
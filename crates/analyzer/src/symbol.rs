@@ -1730,6 +1730,7 @@ pub struct VariableProperty {
     pub suffix: Option<String>,
     pub clock_domain: ClockDomain,
     pub loop_variable: bool,
+    pub declaration: Option<TokenRange>,
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -1904,6 +1905,8 @@ pub struct ConnectTarget {
 #[derive(Debug, Clone)]
 pub struct ConnectTargetIdentifier {
     pub path: Vec<(StrId, Vec<syntax_tree::Expression>)>,
+    /// Tokens of the identifiers in the source
+    pub tokens: Vec<Token>,
 }
 
 impl ConnectTargetIdentifier {
@@ -1947,7 +1950,16 @@ impl From<&syntax_tree::ExpressionIdentifier> for ConnectTargetIdentifier {
                 .collect();
             ret.push((text, select));
         }
-        Self { path: ret }
+
+        let mut tokens = vec![value.identifier().token];
+        for x in &value.scoped_identifier.scoped_identifier_list {
+            tokens.push(x.identifier.identifier_token.token);
+        }
+        for x in &value.expression_identifier_list0 {
+            tokens.push(x.identifier.identifier_token.token);
+        }
+
+        Self { path: ret, tokens }
     }
 }
 
@@ -2185,4 +2197,6 @@ pub struct TestProperty {
     pub r#type: TestType,
     pub path: PathId,
     pub top: Option<StrId>,
+    pub seeds: Option<usize>,
+    pub params: Vec<(StrId, Vec<StrId>)>,
 }
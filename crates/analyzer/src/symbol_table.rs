@@ -1046,6 +1046,10 @@ impl SymbolTable {
         self.var_ref_list.clone()
     }
 
+    pub fn clear_var_ref_list(&mut self) {
+        self.var_ref_list.clear();
+    }
+
     pub fn get_assign_list(&self) -> Vec<Assign> {
         self.var_ref_list
             .values()
@@ -1529,6 +1533,11 @@ pub fn get_var_ref_list() -> HashMap<VarRefAffiliation, Vec<VarRef>> {
     SYMBOL_TABLE.with(|f| f.borrow_mut().get_var_ref_list())
 }
 
+pub fn clear_var_ref_list() {
+    SYMBOL_CACHE.with(|f| f.borrow_mut().clear());
+    SYMBOL_TABLE.with(|f| f.borrow_mut().clear_var_ref_list())
+}
+
 pub fn get_assign_list() -> Vec<Assign> {
     SYMBOL_CACHE.with(|f| f.borrow_mut().clear());
     SYMBOL_TABLE.with(|f| f.borrow_mut().get_assign_list())
@@ -6,13 +6,18 @@ use veryl_parser::Parser;
 
 #[track_caller]
 fn analyze(code: &str) -> Vec<AnalyzerError> {
+    let metadata: Metadata =
+        toml::from_str(&Metadata::create_default_toml("prj").unwrap()).unwrap();
+    analyze_with_metadata(code, &metadata)
+}
+
+#[track_caller]
+fn analyze_with_metadata(code: &str, metadata: &Metadata) -> Vec<AnalyzerError> {
     symbol_table::clear();
     attribute_table::clear();
 
-    let metadata: Metadata =
-        toml::from_str(&Metadata::create_default_toml("prj").unwrap()).unwrap();
     let parser = Parser::parse(&code, &"").unwrap();
-    let analyzer = Analyzer::new(&metadata);
+    let analyzer = Analyzer::new(metadata);
 
     let mut errors = vec![];
     errors.append(&mut analyzer.analyze_pass1(&"prj", &"", &parser.veryl));
@@ -1305,6 +1310,45 @@ fn mismatch_attribute_args() {
         errors[0],
         AnalyzerError::MismatchAttributeArgs { .. }
     ));
+
+    let code = r#"
+    #[test(test1, seeds = 4, params(WIDTH = [8, 16], MODE = "a"))]
+    embed (inline) sv{{{
+    module test1;
+    endmodule
+    }}}
+    "#;
+
+    let errors = analyze(code);
+    assert!(errors.is_empty());
+
+    let code = r#"
+    #[test(test1, seeds = a)]
+    embed (inline) sv{{{
+    module test1;
+    endmodule
+    }}}
+    "#;
+
+    let errors = analyze(code);
+    assert!(matches!(
+        errors[0],
+        AnalyzerError::MismatchAttributeArgs { .. }
+    ));
+
+    let code = r#"
+    #[test(test1, params(WIDTH))]
+    embed (inline) sv{{{
+    module test1;
+    endmodule
+    }}}
+    "#;
+
+    let errors = analyze(code);
+    assert!(matches!(
+        errors[0],
+        AnalyzerError::MismatchAttributeArgs { .. }
+    ));
 }
 
 #[test]
@@ -6008,6 +6052,99 @@ fn clock_domain() {
     ));
 }
 
+#[test]
+fn assertion() {
+    let code = r#"
+    module ModuleA (
+        i_clk: input  clock,
+        i_rst: input  reset,
+        i_req: input  logic,
+        o_ack: output logic,
+    ) {
+        assign o_ack = i_req;
+
+        assert { i_req |-> o_ack }
+        assume req_known (i_clk, i_rst) { !$isunknown(i_req) }
+        cover (i_clk) { i_req |=> o_ack }
+
+        always_comb {
+            assert i_req == o_ack;
+        }
+    }
+    "#;
+
+    let errors = analyze(code);
+    assert!(errors.is_empty());
+
+    let code = r#"
+    module ModuleB (
+        i_req: input  logic,
+        o_ack: output logic,
+    ) {
+        assign o_ack = i_req;
+        assert { i_req |-> o_ack }
+    }
+    "#;
+
+    let errors = analyze(code);
+    assert!(matches!(
+        errors[0],
+        AnalyzerError::MissingClockSignal { .. }
+    ));
+
+    let code = r#"
+    module ModuleC (
+        i_clk: input  clock,
+        i_req: input  logic,
+    ) {
+        assert { i_req |-> o_ack }
+    }
+    "#;
+
+    let errors = analyze(code);
+    assert!(matches!(
+        errors[0],
+        AnalyzerError::UndefinedIdentifier { .. }
+    ));
+
+    let code = r#"
+    module ModuleD (
+        i_clk_a: input  'a clock,
+        i_clk_b: input  'b clock,
+        i_req  : input  'b logic,
+        o_ack  : output 'b logic,
+    ) {
+        assign o_ack = i_req;
+        assert (i_clk_a) { i_req |-> o_ack }
+    }
+    "#;
+
+    let errors = analyze(code);
+    assert!(matches!(
+        errors[0],
+        AnalyzerError::MismatchClockDomain { .. }
+    ));
+
+    let mut metadata: Metadata =
+        toml::from_str(&Metadata::create_default_toml("prj").unwrap()).unwrap();
+    metadata.build.language = veryl_metadata::Language::Verilog2005;
+
+    let code = r#"
+    module ModuleE (
+        i_clk: input clock,
+        i_req: input logic,
+    ) {
+        assert { i_req }
+    }
+    "#;
+
+    let errors = analyze_with_metadata(code, &metadata);
+    assert!(matches!(
+        errors[0],
+        AnalyzerError::UnsupportedByVerilog2005 { .. }
+    ));
+}
+
 #[test]
 fn r#unsafe() {
     let code = r#"
@@ -6926,6 +7063,56 @@ fn unexpandable_modport() {
     ));
 }
 
+#[test]
+fn unflattenable_module() {
+    let code = r#"
+    #[flatten]
+    module ModuleA::<W: u32> (
+        i_a: input logic<W>,
+    ) {}
+    "#;
+
+    let errors = analyze(code);
+    assert!(matches!(
+        errors[0],
+        AnalyzerError::UnflattenableModule { .. }
+    ));
+
+    let code = r#"
+    interface InterfaceA #(
+        param WIDTH: u32 = 1
+    ) {
+        var a: logic<WIDTH>;
+        modport mp {
+            a: input,
+        }
+    }
+    #[flatten]
+    module ModuleA (
+        if_a: modport InterfaceA::mp,
+    ) {}
+    "#;
+
+    let errors = analyze(code);
+    assert!(matches!(
+        errors[0],
+        AnalyzerError::UnflattenableModule { .. }
+    ));
+
+    let code = r#"
+    #[flatten(ModuleAWrapper, "__", x)]
+    module ModuleA (
+        i_a: input logic,
+    ) {}
+    "#;
+
+    let errors = analyze(code);
+    assert!(matches!(
+        errors[0],
+        AnalyzerError::MismatchAttributeArgs { .. }
+    ));
+}
+
 #[test]
 fn recursive_module_instance() {
     let code = r#"
@@ -7048,3 +7235,535 @@ fn recursive_module_instance() {
     let errors = analyze(code);
     assert!(errors.is_empty());
 }
+
+#[test]
+fn unsupported_by_verilog2005() {
+    let mut metadata: Metadata =
+        toml::from_str(&Metadata::create_default_toml("prj").unwrap()).unwrap();
+    metadata.build.language = veryl_metadata::Language::Verilog2005;
+
+    let code = r#"
+    interface InterfaceA {
+        var a: logic;
+        modport mp {
+            a: input,
+        }
+    }
+    module ModuleA (
+        p: modport InterfaceA::mp,
+    ) {
+        let _b: logic = p.a;
+    }
+    "#;
+
+    let errors = analyze_with_metadata(code, &metadata);
+    assert!(
+        !errors
+            .iter()
+            .any(|x| matches!(x, AnalyzerError::UnsupportedByVerilog2005 { .. }))
+    );
+
+    let code = r#"
+    module ModuleA {
+        inst u: InterfaceA [2];
+    }
+    interface InterfaceA {
+        var a: logic;
+    }
+    "#;
+
+    let errors = analyze_with_metadata(code, &metadata);
+    assert!(
+        errors
+            .iter()
+            .any(|x| matches!(x, AnalyzerError::UnsupportedByVerilog2005 { .. }))
+    );
+
+    let code = r#"
+    package PkgA {
+        function f (
+            a: input logic,
+        ) -> logic {
+            return a;
+        }
+    }
+    "#;
+
+    let errors = analyze_with_metadata(code, &metadata);
+    assert!(
+        errors
+            .iter()
+            .any(|x| matches!(x, AnalyzerError::UnsupportedByVerilog2005 { .. }))
+    );
+
+    let code = r#"
+    module ModuleA {
+        let _a: logic<2> = {1'b0, 1'b1} ==? 2'b0x;
+    }
+    "#;
+
+    let errors = analyze_with_metadata(code, &metadata);
+    assert!(
+        errors
+            .iter()
+            .any(|x| matches!(x, AnalyzerError::UnsupportedByVerilog2005 { .. }))
+    );
+
+    let code = r#"
+    module ModuleA {
+        let _a: logic<2> = {1'b0, 1'b1} ==? 2'b0x;
+    }
+    "#;
+
+    let errors = analyze(code);
+    assert!(errors.is_empty());
+}
+
+#[test]
+fn unsupported_by_vhdl2008() {
+    let mut metadata: Metadata =
+        toml::from_str(&Metadata::create_default_toml("prj").unwrap()).unwrap();
+    metadata.build.language = veryl_metadata::Language::Vhdl2008;
+
+    let code = r#"
+    module ModuleA (
+        i_clk: input  clock,
+        i_rst: input  reset,
+        o_a  : output logic<2>,
+    ) {
+        var a: logic<2>;
+        always_ff {
+            if_reset {
+                a = 0;
+            } else {
+                a = if a == 0 ? 1 : 2;
+            }
+        }
+        assign o_a = $clog2(a);
+    }
+    "#;
+
+    let errors = analyze_with_metadata(code, &metadata);
+    assert!(
+        !errors
+            .iter()
+            .any(|x| matches!(x, AnalyzerError::UnsupportedByVhdl2008 { .. }))
+    );
+
+    let code = r#"
+    module ModuleA {
+        let a: logic = 1;
+        let _b: logic = (if a ? 1 : 0) + 1;
+    }
+    "#;
+
+    let errors = analyze_with_metadata(code, &metadata);
+    assert!(
+        errors
+            .iter()
+            .any(|x| matches!(x, AnalyzerError::UnsupportedByVhdl2008 { .. }))
+    );
+
+    let code = r#"
+    interface InterfaceA {
+        var a: logic;
+    }
+    "#;
+
+    let errors = analyze_with_metadata(code, &metadata);
+    assert!(
+        errors
+            .iter()
+            .any(|x| matches!(x, AnalyzerError::UnsupportedByVhdl2008 { .. }))
+    );
+
+    let code = r#"
+    module ModuleA {
+        let _a: logic<32> = $urandom();
+    }
+    "#;
+
+    let errors = analyze_with_metadata(code, &metadata);
+    assert!(
+        errors
+            .iter()
+            .any(|x| matches!(x, AnalyzerError::UnsupportedByVhdl2008 { .. }))
+    );
+
+    let errors = analyze(code);
+    assert!(errors.is_empty());
+}
+
+#[test]
+fn unsupported_by_circt() {
+    let mut metadata: Metadata =
+        toml::from_str(&Metadata::create_default_toml("prj").unwrap()).unwrap();
+    metadata.build.language = veryl_metadata::Language::Circt;
+
+    let code = r#"
+    module ModuleA #(
+        param N: u32 = 2,
+    ) (
+        i_clk: input  clock,
+        i_rst: input  reset,
+        o_a  : output logic<N>,
+    ) {
+        var a: logic<N>;
+        always_ff {
+            if_reset {
+                a = 0;
+            } else {
+                for i: u32 in 0..N {
+                    a[i] = ~a[i];
+                }
+            }
+        }
+        assign o_a = $clog2(a);
+    }
+    "#;
+
+    let errors = analyze_with_metadata(code, &metadata);
+    assert!(
+        !errors
+            .iter()
+            .any(|x| matches!(x, AnalyzerError::UnsupportedByCirct { .. }))
+    );
+
+    let code = r#"
+    module ModuleA {
+        let _a: logic<2> = 2'bx0;
+    }
+    "#;
+
+    let errors = analyze_with_metadata(code, &metadata);
+    assert!(
+        errors
+            .iter()
+            .any(|x| matches!(x, AnalyzerError::UnsupportedByCirct { .. }))
+    );
+
+    let code = r#"
+    module ModuleA {
+        var a: logic<2> [4];
+        assign a = '{0, 1, 2, 3};
+    }
+    "#;
+
+    let errors = analyze_with_metadata(code, &metadata);
+    assert!(
+        errors
+            .iter()
+            .any(|x| matches!(x, AnalyzerError::UnsupportedByCirct { .. }))
+    );
+
+    let code = r#"
+    module ModuleA (
+        i_a: input logic,
+    ) {
+        var a: logic;
+        always_comb {
+            a = 0;
+            for i: u32 in 0..i_a {
+                a = 1;
+            }
+        }
+    }
+    "#;
+
+    let errors = analyze_with_metadata(code, &metadata);
+    assert!(
+        errors
+            .iter()
+            .any(|x| matches!(x, AnalyzerError::UnsupportedByCirct { .. }))
+    );
+
+    let errors = analyze(code);
+    assert!(
+        !errors
+            .iter()
+            .any(|x| matches!(x, AnalyzerError::UnsupportedByCirct { .. }))
+    );
+}
+
+#[test]
+fn explanation() {
+    use crate::explanation;
+    use miette::Diagnostic;
+
+    // All diagnostic codes should have explanations
+    let source = include_str!("analyzer_error.rs");
+    for line in source.lines() {
+        if let Some(x) = line.split("code(").nth(1) {
+            let code = x.split(')').next().unwrap();
+            assert!(explanation::get(code).is_some(), "{code}");
+        }
+    }
+
+    // Failing examples should cause the diagnostic, and other examples should be clean
+    for code in explanation::codes() {
+        let text = explanation::get(code).unwrap();
+        for block in text.split("```").skip(1).step_by(2) {
+            let (info, example) = block.split_once('\n').unwrap();
+            match info {
+                "veryl,fail" => assert!(
+                    analyze(example)
+                        .iter()
+                        .any(|x| x.code().is_some_and(|x| x.to_string() == code)),
+                    "{code}"
+                ),
+                "veryl" => assert!(analyze(example).is_empty(), "{code}"),
+                _ => (),
+            }
+        }
+    }
+}
+
+#[test]
+fn suggestion() {
+    fn fix(code: &str) -> String {
+        fix_with_errors(code, analyze(code))
+    }
+
+    fn fix_with_errors(code: &str, errors: Vec<AnalyzerError>) -> String {
+        let mut edits: Vec<_> = errors
+            .iter()
+            .filter_map(|x| x.suggestion())
+            .flat_map(|x| x.edits())
+            .collect();
+        edits.sort();
+        edits.dedup();
+
+        let mut ret = code.to_string();
+        for edit in edits.iter().rev() {
+            ret.replace_range(edit.beg..edit.end, &edit.text);
+        }
+        ret
+    }
+
+    let code = r#"
+    package A {
+        enum B {
+            C,
+        }
+    }
+    module ModuleA {
+        var _a: A::B;
+        always_comb {
+            _a = A.B.C;
+        }
+    }
+    "#;
+    assert!(fix(code).contains("_a = A::B::C;"));
+
+    let code = r#"
+    module ModuleA {
+        let a: logic = 1;
+        let _b: logic = 1;
+    }
+    "#;
+    assert!(!fix(code).contains("let a"));
+
+    let code = r#"
+    module ModuleA {
+        var begin: logic;
+        assign begin = 1;
+        let _a: logic = begin;
+    }
+    "#;
+    let fixed = fix(code);
+    assert!(fixed.contains("var begin_: logic;"));
+    assert!(fixed.contains("assign begin_ = 1;"));
+    assert!(fixed.contains("let _a: logic = begin_;"));
+
+    // the renamed identifier already exists
+    let code = r#"
+    module ModuleA {
+        var begin : logic;
+        var begin_: logic;
+        assign begin  = 1;
+        assign begin_ = begin;
+    }
+    "#;
+    assert_eq!(fix(code), code);
+
+    let code = r#"
+    module ModuleA {
+        inst u0: ModuleB (
+            i_a: 1,
+        );
+        inst u1: ModuleB (
+            i_a: 1
+        );
+        inst u2: ModuleB;
+    }
+    module ModuleB (
+        i_a: input logic,
+        o_b: output logic,
+        o_c: output logic,
+    ) {
+        assign o_b = 1;
+        assign o_c = 1;
+    }
+    "#;
+    let fixed = fix(code);
+    assert!(fixed.contains("i_a: 1,\n        o_b: _, o_c: _);"));
+    assert!(fixed.contains("i_a: 1\n        , o_b: _, o_c: _);"));
+    assert!(fixed.contains("inst u2: ModuleB (o_b: _, o_c: _);"));
+
+    let code = r#"
+    module ModuleA {
+        var varA: logic;
+        assign varA = 1;
+        let _b: logic = varA;
+    }
+    "#;
+    let mut metadata: Metadata =
+        toml::from_str(&Metadata::create_default_toml("prj").unwrap()).unwrap();
+    metadata.lint.naming.case_var = Some(veryl_metadata::Case::Snake);
+    let fixed = fix_with_errors(code, analyze_with_metadata(code, &metadata));
+    assert!(fixed.contains("var var_a: logic;"));
+    assert!(fixed.contains("assign var_a = 1;"));
+    assert!(fixed.contains("let _b: logic = var_a;"));
+}
+
+#[test]
+fn file_signature() {
+    let signature = |code: &str| {
+        analyze(code);
+        let path = veryl_parser::resource_table::insert_path(std::path::Path::new(""));
+        crate::dependency_graph::file_signature(path)
+    };
+
+    let code = r#"
+    package PackageA {
+        struct StructA {
+            a: logic,
+        }
+        enum EnumA {
+            A,
+        }
+    }
+    "#;
+    let base = signature(code);
+    assert_eq!(base, signature(code));
+
+    let code = r#"
+    package PackageA {
+        struct StructA {
+            a: logic<2>,
+        }
+        enum EnumA {
+            A,
+        }
+    }
+    "#;
+    assert_ne!(base, signature(code));
+
+    let code = r#"
+    package PackageA {
+        struct StructA {
+            a: logic,
+        }
+        enum EnumA {
+            A,
+            B,
+        }
+    }
+    "#;
+    assert_ne!(base, signature(code));
+
+    let code = r#"
+    module ModuleA (
+        i: input logic,
+    ) {
+        var a: logic;
+        always_comb {
+            a = i;
+        }
+        function FuncA () -> logic {
+            var b: logic;
+            b = 0;
+            return b;
+        }
+    }
+    "#;
+    let base = signature(code);
+
+    let code = r#"
+    module ModuleA (
+        i: input logic,
+    ) {
+        var a: logic<2>;
+        always_comb {
+            a = i;
+        }
+        function FuncA () -> logic {
+            var b: logic<2>;
+            b = 0;
+            return b[0];
+        }
+    }
+    "#;
+    assert_eq!(base, signature(code));
+
+    let code = r#"
+    module ModuleA (
+        i: input logic<2>,
+    ) {
+        var a: logic;
+        always_comb {
+            a = i[0];
+        }
+        function FuncA () -> logic {
+            var b: logic;
+            b = 0;
+            return b;
+        }
+    }
+    "#;
+    assert_ne!(base, signature(code));
+}
+
+#[test]
+fn drop_file() {
+    let code = r#"
+    package PackageB {
+        const B: u32 = 0;
+    }
+    package PackageA {
+        const A: u32 = PackageB::B;
+    }
+    "#;
+    let errors = analyze(code);
+    assert!(errors.is_empty());
+
+    // Symbols of the dropped file are removed from the graph before analyzing it again
+    let code = r#"
+    package PackageA {
+        const A: u32 = 0;
+    }
+    package PackageB {
+        const B: u32 = PackageA::A;
+    }
+    "#;
+    let path = veryl_parser::resource_table::insert_path(std::path::Path::new(""));
+    Analyzer::drop_file(path);
+
+    let metadata: Metadata =
+        toml::from_str(&Metadata::create_default_toml("prj").unwrap()).unwrap();
+    let parser = Parser::parse(code, &"").unwrap();
+    let analyzer = Analyzer::new(&metadata);
+
+    let mut errors = vec![];
+    errors.append(&mut analyzer.analyze_pass1("prj", "", &parser.veryl));
+    errors.append(&mut Analyzer::analyze_post_pass1());
+    errors.append(&mut analyzer.analyze_pass2("prj", "", &parser.veryl));
+    assert!(errors.is_empty());
+
+    let mut names: Vec<_> = crate::type_dag::toposort()
+        .iter()
+        .map(|x| x.token.to_string())
+        .collect();
+    names.sort();
+    assert_eq!(names, ["A", "B", "PackageA", "PackageB"]);
+}
@@ -8,6 +8,7 @@ use bimap::BiMap;
 use daggy::petgraph::visit::Dfs;
 use daggy::{Dag, Walker, petgraph::algo};
 use std::cell::RefCell;
+use veryl_parser::resource_table::PathId;
 use veryl_parser::veryl_token::Token;
 
 #[allow(clippy::large_enum_variant)]
@@ -392,6 +393,41 @@ impl TypeDag {
         ret
     }
 
+    /// Returns symbols referred from the symbol and its owned types
+    fn dependencies(&self, id: SymbolId) -> Vec<Symbol> {
+        let Some(node) = self.nodes.get_by_left(&id) else {
+            return Vec::new();
+        };
+
+        let mut owned = vec![*node];
+        let mut i = 0;
+        while i < owned.len() {
+            if let Some(x) = self.dag_owned.get(&owned[i]) {
+                for x in x {
+                    if !owned.contains(x) {
+                        owned.push(*x);
+                    }
+                }
+            }
+            i += 1;
+        }
+
+        let mut ret = Vec::new();
+        let mut set = HashSet::default();
+        for node in &owned {
+            for parent in self.dag.parents((*node).into()).iter(&self.dag) {
+                let parent = parent.1.index() as u32;
+                if !owned.contains(&parent)
+                    && self.paths.contains_key(&parent)
+                    && set.insert(parent)
+                {
+                    ret.push(self.get_symbol(parent));
+                }
+            }
+        }
+        ret
+    }
+
     fn connected_components(&self) -> Vec<Vec<Symbol>> {
         let mut ret = Vec::new();
         let mut graph = self.dag.graph().clone();
@@ -475,6 +511,39 @@ impl TypeDag {
         ret
     }
 
+    /// Detaches nodes declared in the file.
+    /// Nodes themselves are left because removing them changes indices of the other nodes.
+    fn drop(&mut self, path: PathId) {
+        let drop_list: Vec<_> = self
+            .paths
+            .iter()
+            .filter(|(_, x)| x.token.source == path)
+            .map(|(x, _)| *x)
+            .collect();
+
+        for node in &drop_list {
+            let neighbors: Vec<_> = self
+                .dag
+                .graph()
+                .neighbors_undirected((*node).into())
+                .map(|x| x.index() as u32)
+                .collect();
+            for x in neighbors {
+                self.remove_edge(*node, x);
+                self.remove_edge(x, *node);
+            }
+
+            self.nodes.remove_by_right(node);
+            self.paths.remove(node);
+            self.symbols.remove(node);
+            self.dag_owned.remove(node);
+        }
+
+        for owned in self.dag_owned.values_mut() {
+            owned.retain(|x| !drop_list.contains(x));
+        }
+    }
+
     fn clear(&mut self) {
         self.clone_from(&Self::new());
     }
@@ -494,6 +563,10 @@ pub fn toposort() -> Vec<Symbol> {
     TYPE_DAG.with(|f| f.borrow().toposort())
 }
 
+pub fn dependencies(id: SymbolId) -> Vec<Symbol> {
+    TYPE_DAG.with(|f| f.borrow().dependencies(id))
+}
+
 pub fn connected_components() -> Vec<Vec<Symbol>> {
     TYPE_DAG.with(|f| f.borrow().connected_components())
 }
@@ -502,6 +575,10 @@ pub fn dump() -> String {
     TYPE_DAG.with(|f| f.borrow().dump())
 }
 
+pub fn drop(path: PathId) {
+    TYPE_DAG.with(|f| f.borrow_mut().drop(path))
+}
+
 pub fn clear() {
     TYPE_DAG.with(|f| f.borrow_mut().clear())
 }
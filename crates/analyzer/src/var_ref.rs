@@ -274,8 +274,9 @@ impl fmt::Display for VarRefPathItem {
     }
 }
 
+/// Path items, symbols of the identifiers, and tokens of the identifiers in the source
 #[derive(Clone, Debug)]
-pub struct VarRefPath(Vec<VarRefPathItem>, Vec<SymbolId>);
+pub struct VarRefPath(Vec<VarRefPathItem>, Vec<SymbolId>, Vec<Token>);
 
 impl VarRefPath {
     pub fn new(x: Vec<VarRefPathItem>) -> Self {
@@ -287,7 +288,15 @@ impl VarRefPath {
             }
         }
 
-        Self(x, full_path)
+        Self(x, full_path, Vec::new())
+    }
+
+    /// Tokens are kept only if they correspond to `full_path` one by one
+    fn with_tokens(mut self, tokens: Vec<Token>) -> Self {
+        if tokens.len() == self.1.len() {
+            self.2 = tokens;
+        }
+        self
     }
 
     pub fn push(&mut self, x: VarRefPathItem) {
@@ -339,6 +348,12 @@ impl VarRefPath {
         &self.1
     }
 
+    /// Returns the identifier token referring to the symbol in the source
+    pub fn token(&self, id: SymbolId) -> Option<&Token> {
+        let i = self.1.iter().position(|x| *x == id)?;
+        self.2.get(i)
+    }
+
     pub fn is_partial(&self) -> bool {
         self.0
             .iter()
@@ -366,7 +381,7 @@ impl TryFrom<&Identifier> for VarRefPath {
     fn try_from(arg: &Identifier) -> Result<Self, Self::Error> {
         if let Ok(symbol) = symbol_table::resolve(arg) {
             let path_items = symbol.full_path.iter().map(VarRefPathItem::from).collect();
-            Ok(VarRefPath::new(path_items))
+            Ok(VarRefPath::new(path_items).with_tokens(vec![arg.identifier_token.token]))
         } else {
             Err(())
         }
@@ -394,6 +409,7 @@ impl TryFrom<&HierarchicalIdentifier> for VarRefPath {
             return Err(());
         };
         path_items.push(item);
+        let mut tokens = vec![arg.identifier.identifier_token.token];
         for x in &arg.hierarchical_identifier_list {
             path_items.push(VarRefPathItem::from(&*x.select));
         }
@@ -403,12 +419,13 @@ impl TryFrom<&HierarchicalIdentifier> for VarRefPath {
                 return Err(());
             };
             path_items.push(item);
+            tokens.push(x.identifier.identifier_token.token);
             for x in &x.hierarchical_identifier_list0_list {
                 path_items.push(VarRefPathItem::from(&*x.select));
             }
         }
 
-        Ok(VarRefPath::new(path_items))
+        Ok(VarRefPath::new(path_items).with_tokens(tokens))
     }
 }
 
@@ -433,11 +450,13 @@ impl TryFrom<&ExpressionIdentifier> for VarRefPath {
             return Err(());
         };
         path_items.push(item);
-        for _x in &arg.scoped_identifier.scoped_identifier_list {
+        let mut tokens = vec![arg.identifier().token];
+        for x in &arg.scoped_identifier.scoped_identifier_list {
             let Some(item) = full_path.pop() else {
                 return Err(());
             };
             path_items.push(item);
+            tokens.push(x.identifier.identifier_token.token);
         }
 
         for x in &arg.expression_identifier_list {
@@ -449,12 +468,13 @@ impl TryFrom<&ExpressionIdentifier> for VarRefPath {
                 return Err(());
             };
             path_items.push(item);
+            tokens.push(x.identifier.identifier_token.token);
             for x in &x.expression_identifier_list0_list {
                 path_items.push(VarRefPathItem::from(&*x.select));
             }
         }
 
-        Ok(VarRefPath::new(path_items))
+        Ok(VarRefPath::new(path_items).with_tokens(tokens))
     }
 }
 
@@ -484,7 +504,7 @@ impl TryFrom<(&ConnectTargetIdentifier, &Namespace)> for VarRefPath {
                     path_items.push(VarRefPathItem::from(select));
                 }
             }
-            Ok(VarRefPath::new(path_items))
+            Ok(VarRefPath::new(path_items).with_tokens(arg.0.tokens.clone()))
         } else {
             Err(())
         }
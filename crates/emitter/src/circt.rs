@@ -0,0 +1,3423 @@
+use crate::emitter::{SymbolContext, symbol_string};
+use relative_path::PathExt;
+use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet, VecDeque};
+use std::path::{Path, PathBuf};
+use veryl_analyzer::definition_table::{self, Definition};
+use veryl_analyzer::evaluator::{Evaluated, Evaluator};
+use veryl_analyzer::namespace::Namespace;
+use veryl_analyzer::symbol::Direction as SymDirection;
+use veryl_analyzer::symbol::Type as SymType;
+use veryl_analyzer::symbol::{GenericTables, Symbol, SymbolId, SymbolKind, TypeKind};
+use veryl_analyzer::{namespace_table, symbol_table};
+use veryl_metadata::{Build, ClockType, Metadata, ResetType};
+use veryl_parser::resource_table::{self, PathId, StrId};
+use veryl_parser::veryl_grammar_trait::*;
+use veryl_parser::veryl_token::{Token, TokenSource, VerylToken};
+
+#[cfg(target_os = "windows")]
+const NEWLINE: &str = "\r\n";
+#[cfg(not(target_os = "windows"))]
+const NEWLINE: &str = "\n";
+
+/// Upper bound of iterations when unrolling `for` loops
+const MAX_UNROLL: usize = 65536;
+
+/// SSA value with an optional constant which is materialized on use
+#[derive(Clone, Debug, PartialEq)]
+struct Value {
+    ssa: Option<String>,
+    width: usize,
+    konst: Option<u128>,
+}
+
+impl Value {
+    fn konst(value: u128, width: usize) -> Self {
+        Self {
+            ssa: None,
+            width,
+            konst: Some(value & mask(width)),
+        }
+    }
+
+    fn ssa(name: &str, width: usize) -> Self {
+        Self {
+            ssa: Some(name.to_string()),
+            width,
+            konst: None,
+        }
+    }
+
+    fn folded(&self) -> Option<u128> {
+        if self.width <= 128 { self.konst } else { None }
+    }
+}
+
+/// Packed bit layout of a type
+///
+/// `dims` are packed dimensions from the outermost one, and `base` is the width of the element.
+/// `base` is larger than 1 only for structs and unions which are referred by `record`.
+#[derive(Clone, Debug)]
+struct Layout {
+    dims: Vec<usize>,
+    base: usize,
+    signed: bool,
+    record: Option<Symbol>,
+}
+
+impl Layout {
+    fn bits(width: usize, signed: bool) -> Self {
+        Self {
+            dims: if width == 1 { vec![] } else { vec![width] },
+            base: 1,
+            signed,
+            record: None,
+        }
+    }
+
+    fn width(&self) -> usize {
+        self.dims.iter().product::<usize>() * self.base
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum SignalKind {
+    Input,
+    ClockInput,
+    Output,
+    Var,
+}
+
+#[derive(Clone, Debug)]
+struct Driver {
+    lsb: usize,
+    value: Value,
+}
+
+#[derive(Clone, Debug)]
+struct Signal {
+    name: String,
+    layout: Layout,
+    kind: SignalKind,
+    drivers: Vec<Driver>,
+    token: Token,
+}
+
+impl Signal {
+    fn width(&self) -> usize {
+        self.layout.width()
+    }
+}
+
+/// Symbolic value of a signal or a local variable in a process
+#[derive(Clone, Debug)]
+struct Slot {
+    value: Value,
+    mask: Vec<bool>,
+}
+
+#[derive(Clone, Debug, Default)]
+struct Env {
+    signals: BTreeMap<usize, Slot>,
+    locals: BTreeMap<SymbolId, Slot>,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+enum SlotKey {
+    Signal(usize),
+    Local(SymbolId),
+}
+
+struct Process {
+    env: Env,
+    ff: bool,
+    reset_active: Option<Value>,
+}
+
+#[derive(Clone, Debug)]
+enum Base {
+    Signal(usize),
+    Local(SymbolId),
+    Value(Value),
+}
+
+/// Bit offset of a selected part; `region` covers the bits which a dynamic select may touch
+#[derive(Clone, Debug, Default)]
+struct Offset {
+    fixed: usize,
+    dynamic: Option<Value>,
+    region: Option<(usize, usize)>,
+}
+
+#[derive(Clone, Debug)]
+struct Place {
+    base: Base,
+    total: usize,
+    layout: Layout,
+    offset: Offset,
+}
+
+enum Segment<'a> {
+    Select(&'a Select),
+    Member(StrId),
+}
+
+#[derive(Clone)]
+enum Ast<'a> {
+    Binary(&'static str, Box<Ast<'a>>, Box<Ast<'a>>, Token),
+    Unary(&'static str, Box<Ast<'a>>, Token),
+    Cast(&'a CastingType, Box<Ast<'a>>, Token),
+    Cond(Vec<(Ast<'a>, Ast<'a>)>, Box<Ast<'a>>),
+    Inside(Box<Ast<'a>>, Vec<&'a Range>, bool, Token),
+    Factor(&'a Factor),
+}
+
+enum Body<'a> {
+    Block(&'a StatementBlock),
+    Statement(&'a Statement),
+    Empty,
+}
+
+struct Port {
+    identifier: StrId,
+    name: String,
+    direction: SymDirection,
+    width: usize,
+    clock: bool,
+    default: Option<Value>,
+}
+
+struct Specialization {
+    name: String,
+    module: Symbol,
+    overrides: Vec<(SymbolId, Evaluated)>,
+}
+
+/// Per-module emission state
+#[derive(Default)]
+struct ModuleState {
+    lines: Vec<String>,
+    names: HashSet<String>,
+    temp: usize,
+    consts: HashMap<(usize, u128), String>,
+    exprs: HashMap<String, String>,
+    signals: Vec<Signal>,
+    scopes: Vec<HashMap<SymbolId, usize>>,
+    prefix: String,
+    default_clock: Option<SymbolId>,
+    default_reset: Option<SymbolId>,
+    from_clocks: HashMap<usize, String>,
+    to_clocks: HashMap<usize, String>,
+    regs: HashMap<String, usize>,
+}
+
+/// Emits CIRCT `hw`/`comb`/`seq` dialect IR from the analyzed design
+///
+/// Parameters, widths, generate blocks and loops are elaborated through `Evaluator`,
+/// and each operation carries the location of the source token.
+pub struct CirctEmitter {
+    project_name: Option<StrId>,
+    build_opt: Build,
+    dst_dir: PathBuf,
+    string: String,
+    loc_paths: HashMap<PathId, String>,
+    m: ModuleState,
+    token: Option<Token>,
+    dry_run: usize,
+    process: Option<Process>,
+    select_msb: Vec<usize>,
+    top_name: String,
+    queue: VecDeque<Specialization>,
+    specialized: HashMap<(SymbolId, Vec<(SymbolId, isize)>), String>,
+}
+
+impl Default for CirctEmitter {
+    fn default() -> Self {
+        Self {
+            project_name: None,
+            build_opt: Build::default(),
+            dst_dir: PathBuf::new(),
+            string: String::new(),
+            loc_paths: HashMap::new(),
+            m: ModuleState::default(),
+            token: None,
+            dry_run: 0,
+            process: None,
+            select_msb: Vec::new(),
+            top_name: String::new(),
+            queue: VecDeque::new(),
+            specialized: HashMap::new(),
+        }
+    }
+}
+
+impl CirctEmitter {
+    pub fn new(metadata: &Metadata, _src_path: &Path, dst_path: &Path, _map_path: &Path) -> Self {
+        let dst_dir = dst_path
+            .parent()
+            .map(|x| x.to_path_buf())
+            .unwrap_or_default();
+
+        Self {
+            project_name: Some(metadata.project.name.as_str().into()),
+            build_opt: metadata.build.clone(),
+            dst_dir,
+            ..Default::default()
+        }
+    }
+
+    pub fn emit(&mut self, project_name: &str, input: &Veryl) {
+        namespace_table::set_default(&[project_name.into()]);
+
+        for x in &input.veryl_list {
+            let items: Vec<DescriptionItem> = x.description_group.as_ref().into();
+            for item in items {
+                if let DescriptionItem::DescriptionItemOptPublicDescriptionItem(x) = item
+                    && let PublicDescriptionItem::ModuleDeclaration(x) =
+                        x.public_description_item.as_ref()
+                {
+                    self.top_module(&x.module_declaration);
+                }
+            }
+        }
+    }
+
+    pub fn as_str(&self) -> &str {
+        &self.string
+    }
+
+    fn context(&self) -> SymbolContext {
+        SymbolContext {
+            project_name: self.project_name,
+            build_opt: self.build_opt.clone(),
+            in_import: false,
+            in_direction_modport: false,
+            generic_map: Vec::new(),
+        }
+    }
+
+    // ------------------------------------------------------------------------
+    // Output
+    // ------------------------------------------------------------------------
+
+    fn loc(&mut self, token: Option<&Token>) -> String {
+        let Some(token) = token else {
+            return "loc(unknown)".to_string();
+        };
+        let path = match token.source {
+            TokenSource::File { path, .. } | TokenSource::Generated(path) => path,
+            _ => return "loc(unknown)".to_string(),
+        };
+        let text = if let Some(x) = self.loc_paths.get(&path) {
+            x.clone()
+        } else {
+            let text = if let Some(src) = resource_table::get_path_value(path) {
+                if let Ok(x) = src.relative_to(&self.dst_dir) {
+                    x.as_str().to_string()
+                } else {
+                    src.to_string_lossy().to_string()
+                }
+            } else {
+                String::new()
+            };
+            let text = text.replace('\\', "\\\\").replace('"', "\\\"");
+            self.loc_paths.insert(path, text.clone());
+            text
+        };
+        format!("loc(\"{text}\":{}:{})", token.line, token.column)
+    }
+
+    fn temp(&mut self) -> String {
+        let ret = format!("%{}", self.m.temp);
+        self.m.temp += 1;
+        ret
+    }
+
+    fn unique(&mut self, name: &str) -> String {
+        let mut ret = name.to_string();
+        let mut i = 0;
+        while self.m.names.contains(&ret) {
+            i += 1;
+            ret = format!("{name}_{i}");
+        }
+        self.m.names.insert(ret.clone());
+        ret
+    }
+
+    fn op(&mut self, rhs: &str, width: usize) -> Value {
+        if self.dry_run > 0 {
+            return Value::ssa("%dry", width);
+        }
+        // combinational operations are pure, so the same operation can be shared
+        let pure = rhs.starts_with("comb.");
+        if pure && let Some(x) = self.m.exprs.get(rhs) {
+            return Value::ssa(x, width);
+        }
+        let name = self.temp();
+        let token = self.token;
+        let loc = self.loc(token.as_ref());
+        self.m.lines.push(format!("{name} = {rhs} {loc}"));
+        if pure {
+            self.m.exprs.insert(rhs.to_string(), name.clone());
+        }
+        Value::ssa(&name, width)
+    }
+
+    /// Returns the SSA name of the value with materializing constants
+    fn name(&mut self, value: &Value) -> String {
+        if let Some(x) = &value.ssa {
+            return x.clone();
+        }
+        if self.dry_run > 0 {
+            return "%dry".to_string();
+        }
+        let konst = value.konst.unwrap_or(0);
+        let key = (value.width, konst);
+        if let Some(x) = self.m.consts.get(&key) {
+            return x.clone();
+        }
+        let rhs = if value.width == 1 {
+            let text = if konst == 1 { "true" } else { "false" };
+            format!("hw.constant {text}")
+        } else {
+            format!(
+                "hw.constant {} : i{}",
+                signed_text(konst, value.width),
+                value.width
+            )
+        };
+        let ret = self.op(&rhs, value.width).ssa.unwrap();
+        self.m.consts.insert(key, ret.clone());
+        ret
+    }
+
+    // ------------------------------------------------------------------------
+    // Operations
+    // ------------------------------------------------------------------------
+
+    fn extract(&mut self, value: &Value, lsb: usize, width: usize) -> Value {
+        if width == 0 {
+            return Value::konst(0, 0);
+        }
+        if lsb == 0 && width == value.width {
+            return value.clone();
+        }
+        if lsb >= value.width {
+            return Value::konst(0, width);
+        }
+        if lsb + width > value.width {
+            let x = self.extract(value, lsb, value.width - lsb);
+            return self.resize(&x, width, false);
+        }
+        if let Some(x) = value.folded() {
+            return Value::konst(x >> lsb, width);
+        }
+        let a = self.name(value);
+        self.op(
+            &format!(
+                "comb.extract {a} from {lsb} : (i{}) -> i{width}",
+                value.width
+            ),
+            width,
+        )
+    }
+
+    /// Concatenates values from the MSB side
+    fn concat(&mut self, parts: &[Value]) -> Value {
+        let parts: Vec<_> = parts.iter().filter(|x| x.width > 0).cloned().collect();
+        if parts.len() == 1 {
+            return parts[0].clone();
+        }
+        let width: usize = parts.iter().map(|x| x.width).sum();
+        if width <= 128 && parts.iter().all(|x| x.konst.is_some()) {
+            let mut ret = 0u128;
+            for x in &parts {
+                ret = if x.width >= 128 { 0 } else { ret << x.width };
+                ret |= x.konst.unwrap();
+            }
+            return Value::konst(ret, width);
+        }
+        let names: Vec<_> = parts.iter().map(|x| self.name(x)).collect();
+        let types: Vec<_> = parts.iter().map(|x| format!("i{}", x.width)).collect();
+        self.op(
+            &format!("comb.concat {} : {}", names.join(", "), types.join(", ")),
+            width,
+        )
+    }
+
+    fn replicate(&mut self, value: &Value, count: usize) -> Value {
+        if count == 1 {
+            return value.clone();
+        }
+        let width = value.width * count;
+        if let Some(x) = value.folded()
+            && width <= 128
+        {
+            let mut ret = 0u128;
+            for _ in 0..count {
+                ret = (ret << value.width) | x;
+            }
+            return Value::konst(ret, width);
+        }
+        let a = self.name(value);
+        self.op(
+            &format!("comb.replicate {a} : (i{}) -> i{width}", value.width),
+            width,
+        )
+    }
+
+    fn resize(&mut self, value: &Value, width: usize, signed: bool) -> Value {
+        if width == value.width {
+            value.clone()
+        } else if width < value.width {
+            self.extract(value, 0, width)
+        } else if !signed {
+            let zero = Value::konst(0, width - value.width);
+            self.concat(&[zero, value.clone()])
+        } else if let Some(x) = value.folded()
+            && width <= 128
+        {
+            Value::konst(sign_extend(x, value.width) as u128, width)
+        } else {
+            let sign = self.extract(value, value.width - 1, 1);
+            let sign = self.replicate(&sign, width - value.width);
+            self.concat(&[sign, value.clone()])
+        }
+    }
+
+    fn binop(&mut self, name: &str, a: &Value, b: &Value) -> Value {
+        let width = a.width;
+        if let (Some(x), Some(y)) = (a.folded(), b.folded())
+            && let Some(ret) = fold_binop(name, x, y, width)
+        {
+            return Value::konst(ret, width);
+        }
+        if matches!(name, "add" | "or" | "xor") {
+            if a.folded() == Some(0) {
+                return b.clone();
+            }
+            if b.folded() == Some(0) {
+                return a.clone();
+            }
+        }
+        let x = self.name(a);
+        let y = self.name(b);
+        self.op(&format!("comb.{name} {x}, {y} : i{width}"), width)
+    }
+
+    fn icmp(&mut self, pred: &str, a: &Value, b: &Value) -> Value {
+        if let (Some(x), Some(y)) = (a.folded(), b.folded())
+            && let Some(ret) = fold_icmp(pred, x, y, a.width)
+        {
+            return Value::konst(ret as u128, 1);
+        }
+        let x = self.name(a);
+        let y = self.name(b);
+        self.op(&format!("comb.icmp {pred} {x}, {y} : i{}", a.width), 1)
+    }
+
+    fn mux(&mut self, cond: &Value, a: &Value, b: &Value) -> Value {
+        if let Some(x) = cond.konst {
+            return if x != 0 { a.clone() } else { b.clone() };
+        }
+        if a == b {
+            return a.clone();
+        }
+        let c = self.name(cond);
+        let x = self.name(a);
+        let y = self.name(b);
+        self.op(&format!("comb.mux {c}, {x}, {y} : i{}", a.width), a.width)
+    }
+
+    fn not(&mut self, value: &Value) -> Value {
+        let ones = Value::konst(u128::MAX, value.width);
+        let ones = if value.width > 128 {
+            let one = Value::konst(1, 1);
+            self.replicate(&one, value.width)
+        } else {
+            ones
+        };
+        self.binop("xor", value, &ones)
+    }
+
+    fn zero(width: usize) -> Value {
+        Value::konst(0, width)
+    }
+
+    fn ones(&mut self, width: usize) -> Value {
+        if width > 128 {
+            let one = Value::konst(1, 1);
+            self.replicate(&one, width)
+        } else {
+            Value::konst(u128::MAX, width)
+        }
+    }
+
+    fn bool_value(&mut self, value: &Value) -> Value {
+        if value.width == 1 {
+            value.clone()
+        } else {
+            let zero = Self::zero(value.width);
+            self.icmp("ne", value, &zero)
+        }
+    }
+
+    fn reduce(&mut self, op: &str, value: &Value) -> Value {
+        match op {
+            "rand" | "rnand" => {
+                let ones = self.ones(value.width);
+                let x = self.icmp("eq", value, &ones);
+                if op == "rnand" { self.not(&x) } else { x }
+            }
+            "ror" | "rnor" => {
+                let x = self.bool_value(value);
+                if op == "rnor" { self.not(&x) } else { x }
+            }
+            _ => {
+                let x = if let Some(x) = value.folded() {
+                    Value::konst((x.count_ones() & 1) as u128, 1)
+                } else {
+                    let a = self.name(value);
+                    self.op(&format!("comb.parity {a} : i{}", value.width), 1)
+                };
+                if op == "rxnor" { self.not(&x) } else { x }
+            }
+        }
+    }
+
+    fn shift(&mut self, name: &str, value: &Value, amount: &Value) -> Value {
+        let width = value.width;
+        if let Some(x) = amount.folded() {
+            if x >= width as u128 {
+                if name == "shrs" {
+                    let amount = Value::konst(width as u128 - 1, width);
+                    return self.binop(name, value, &amount);
+                } else {
+                    return Self::zero(width);
+                }
+            }
+            let amount = Value::konst(x, width);
+            return self.binop(name, value, &amount);
+        }
+        if amount.width <= width {
+            let amount = self.resize(amount, width, false);
+            return self.binop(name, value, &amount);
+        }
+        let limit = Value::konst(width as u128, amount.width);
+        let over = self.icmp("uge", amount, &limit);
+        let low = self.extract(amount, 0, width);
+        let shifted = self.binop(name, value, &low);
+        let fill = if name == "shrs" {
+            let amount = Value::konst(width as u128 - 1, width);
+            self.binop(name, value, &amount)
+        } else {
+            Self::zero(width)
+        };
+        self.mux(&over, &fill, &shifted)
+    }
+
+    fn pow(&mut self, base: &Value, exponent: &Value) -> Value {
+        let width = base.width;
+        if let (Some(x), Some(y)) = (base.folded(), exponent.folded()) {
+            let mut ret = 1u128;
+            let mut b = x;
+            let mut e = y;
+            while e > 0 {
+                if e & 1 == 1 {
+                    ret = ret.wrapping_mul(b);
+                }
+                b = b.wrapping_mul(b);
+                e >>= 1;
+            }
+            return Value::konst(ret, width);
+        }
+        let mut ret = Value::konst(1, width);
+        let mut b = base.clone();
+        for i in 0..exponent.width {
+            let bit = self.extract(exponent, i, 1);
+            let mul = self.binop("mul", &ret, &b);
+            ret = self.mux(&bit, &mul, &ret);
+            if i + 1 < exponent.width {
+                b = self.binop("mul", &b, &b);
+            }
+        }
+        ret
+    }
+
+    // ------------------------------------------------------------------------
+    // Layout
+    // ------------------------------------------------------------------------
+
+    fn const_value(&mut self, arg: &Expression) -> Option<isize> {
+        let mut evaluator = Evaluator::new(&[]);
+        if let Some(x) = evaluator.expression(arg).get_value() {
+            return Some(x);
+        }
+        self.dry_run += 1;
+        let (width, signed) = self.expression_info(arg);
+        let value = self.lower_expression(arg, width);
+        self.dry_run -= 1;
+        value.folded().map(|x| {
+            if signed {
+                sign_extend(x, width) as isize
+            } else {
+                x as isize
+            }
+        })
+    }
+
+    fn dims(&mut self, args: &[Expression]) -> Vec<usize> {
+        args.iter()
+            .map(|x| self.const_value(x).unwrap_or(1).max(1) as usize)
+            .collect()
+    }
+
+    fn type_layout(&mut self, arg: &SymType, namespace: &Namespace) -> Layout {
+        let mut dims = self.dims(&arg.width);
+        let signed = arg.is_signed();
+        let fixed = match arg.kind {
+            TypeKind::U8 | TypeKind::I8 => Some(8),
+            TypeKind::U16 | TypeKind::I16 => Some(16),
+            TypeKind::U32 | TypeKind::I32 => Some(32),
+            TypeKind::U64 | TypeKind::I64 => Some(64),
+            _ => None,
+        };
+        if let Some(x) = fixed {
+            let signed = matches!(
+                arg.kind,
+                TypeKind::I8 | TypeKind::I16 | TypeKind::I32 | TypeKind::I64
+            );
+            return Layout::bits(x, signed);
+        }
+        if let TypeKind::UserDefined(x) = &arg.kind {
+            if let Ok(symbol) = symbol_table::resolve((&x.path.generic_path(), namespace)) {
+                let mut ret = self.symbol_layout(&symbol.found);
+                dims.append(&mut ret.dims);
+                ret.dims = dims;
+                ret.signed |= signed;
+                return ret;
+            }
+            return Layout::bits(1, false);
+        }
+        Layout {
+            dims,
+            base: 1,
+            signed,
+            record: None,
+        }
+    }
+
+    /// Layout of a type symbol
+    fn symbol_layout(&mut self, symbol: &Symbol) -> Layout {
+        match &symbol.kind {
+            SymbolKind::Struct(x) => {
+                let base = x
+                    .members
+                    .iter()
+                    .map(|x| self.member_layout(*x).width())
+                    .sum::<usize>()
+                    .max(1);
+                Layout {
+                    dims: vec![],
+                    base,
+                    signed: false,
+                    record: Some(symbol.clone()),
+                }
+            }
+            SymbolKind::Union(x) => {
+                let base = x
+                    .members
+                    .iter()
+                    .map(|x| self.member_layout(*x).width())
+                    .max()
+                    .unwrap_or(1);
+                Layout {
+                    dims: vec![],
+                    base,
+                    signed: false,
+                    record: Some(symbol.clone()),
+                }
+            }
+            SymbolKind::Enum(x) => {
+                if let Some(r#type) = &x.r#type {
+                    self.type_layout(r#type, &symbol.inner_namespace())
+                } else {
+                    Layout::bits(x.width.max(1), false)
+                }
+            }
+            SymbolKind::TypeDef(x) => self.type_layout(&x.r#type, &symbol.namespace),
+            _ => Layout::bits(1, false),
+        }
+    }
+
+    fn member_layout(&mut self, id: SymbolId) -> Layout {
+        let Some(symbol) = symbol_table::get(id) else {
+            return Layout::bits(1, false);
+        };
+        match &symbol.kind {
+            SymbolKind::StructMember(x) => self.type_layout(&x.r#type, &symbol.namespace),
+            SymbolKind::UnionMember(x) => self.type_layout(&x.r#type, &symbol.namespace),
+            _ => Layout::bits(1, false),
+        }
+    }
+
+    // ------------------------------------------------------------------------
+    // Module
+    // ------------------------------------------------------------------------
+
+    fn module_name(&self, symbol: &Symbol) -> String {
+        symbol_string(
+            &VerylToken::new(symbol.token),
+            symbol,
+            &[],
+            &GenericTables::default(),
+            &self.context(),
+        )
+    }
+
+    fn top_module(&mut self, arg: &ModuleDeclaration) {
+        if arg.module_declaration_opt.is_some() {
+            return;
+        }
+        let Ok(symbol) = symbol_table::resolve(arg.identifier.as_ref()) else {
+            return;
+        };
+        let symbol = symbol.found;
+        let name = self.module_name(&symbol);
+        self.top_name = name.clone();
+        self.module_declaration(arg, &symbol, &name);
+
+        while let Some(x) = self.queue.pop_front() {
+            let Some(id) = x.module.kind.get_definition() else {
+                continue;
+            };
+            let Some(Definition::Module(decl)) = definition_table::get(id) else {
+                continue;
+            };
+            push_overrides(&x.overrides);
+            self.module_declaration(&decl, &x.module, &x.name);
+            pop_overrides(&x.overrides);
+        }
+    }
+
+    fn module_declaration(&mut self, arg: &ModuleDeclaration, symbol: &Symbol, name: &str) {
+        let SymbolKind::Module(property) = &symbol.kind else {
+            return;
+        };
+
+        self.m = ModuleState {
+            scopes: vec![HashMap::new()],
+            default_clock: property.default_clock,
+            default_reset: property.default_reset,
+            ..Default::default()
+        };
+
+        let mut ports = Vec::new();
+        let mut outputs = Vec::new();
+        for port in &property.ports {
+            let Some(port_symbol) = symbol_table::get(port.symbol) else {
+                continue;
+            };
+            let property = port.property();
+            let layout = self.type_layout(&property.r#type, &port_symbol.namespace);
+            let width = layout.width();
+            let port_name = self.unique(&declared_name(&port_symbol));
+            let kind = match property.direction {
+                SymDirection::Input if property.r#type.kind.is_clock() && width == 1 => {
+                    SignalKind::ClockInput
+                }
+                SymDirection::Output => SignalKind::Output,
+                _ => SignalKind::Input,
+            };
+            match kind {
+                SignalKind::ClockInput => ports.push(format!("in %{port_name} : !seq.clock")),
+                SignalKind::Output => {
+                    ports.push(format!("out {port_name} : i{width}"));
+                    outputs.push(self.m.signals.len());
+                }
+                _ => ports.push(format!("in %{port_name} : i{width}")),
+            }
+            self.declare(port_symbol.id, port_name, layout, kind, port.token.token);
+        }
+
+        let mut items = Vec::new();
+        for x in &arg.module_declaration_list {
+            let groups: Vec<ModuleItem> = x.module_group.as_ref().into();
+            for x in groups {
+                flatten_generate_item(x.generate_item.as_ref(), &mut items);
+            }
+        }
+        self.generate_items(&items);
+        self.finalize_signals();
+
+        let close = arg.r_brace.r_brace_token.token;
+        self.token = Some(close);
+        let mut names = Vec::new();
+        let mut types = Vec::new();
+        for x in &outputs {
+            names.push(format!("%{}", self.m.signals[*x].name));
+            types.push(format!("i{}", self.m.signals[*x].width()));
+        }
+        let loc = self.loc(Some(&close));
+        let output = if names.is_empty() {
+            format!("hw.output {loc}")
+        } else {
+            format!(
+                "hw.output {} : {} {loc}",
+                names.join(", "),
+                types.join(", ")
+            )
+        };
+        self.m.lines.push(output);
+
+        if !self.string.is_empty() {
+            self.string.push_str(NEWLINE);
+        }
+        let loc = self.loc(Some(&arg.module.module_token.token));
+        self.string.push_str(&format!(
+            "hw.module @{name}({}) {{{NEWLINE}",
+            ports.join(", ")
+        ));
+        let lines = std::mem::take(&mut self.m.lines);
+        for x in &lines {
+            self.string.push_str(&format!("  {x}{NEWLINE}"));
+        }
+        self.string.push_str(&format!("}} {loc}{NEWLINE}"));
+        self.token = None;
+    }
+
+    fn declare(
+        &mut self,
+        id: SymbolId,
+        name: String,
+        layout: Layout,
+        kind: SignalKind,
+        token: Token,
+    ) -> usize {
+        let index = self.m.signals.len();
+        self.m.signals.push(Signal {
+            name,
+            layout,
+            kind,
+            drivers: Vec::new(),
+            token,
+        });
+        self.m.scopes.last_mut().unwrap().insert(id, index);
+        index
+    }
+
+    fn declare_variable(&mut self, arg: &Identifier) {
+        let Ok(symbol) = symbol_table::resolve(arg) else {
+            return;
+        };
+        let symbol = symbol.found;
+        if let SymbolKind::Variable(x) = &symbol.kind {
+            let layout = self.type_layout(&x.r#type, &symbol.namespace);
+            let name = format!("{}{}", self.m.prefix, declared_name(&symbol));
+            let name = self.unique(&name);
+            self.declare(
+                symbol.id,
+                name,
+                layout,
+                SignalKind::Var,
+                arg.identifier_token.token,
+            );
+        }
+    }
+
+    fn lookup(&self, id: SymbolId) -> Option<usize> {
+        self.m.scopes.iter().rev().find_map(|x| x.get(&id).copied())
+    }
+
+    /// Defines each signal from its drivers
+    fn finalize_signals(&mut self) {
+        for i in 0..self.m.signals.len() {
+            let signal = self.m.signals[i].clone();
+            if matches!(signal.kind, SignalKind::Input | SignalKind::ClockInput) {
+                continue;
+            }
+            self.token = Some(signal.token);
+            let width = signal.width();
+            let mut drivers = signal.drivers.clone();
+            drivers.sort_by_key(|x| x.lsb);
+
+            if drivers.len() == 1
+                && drivers[0].lsb == 0
+                && drivers[0].value.width == width
+                && let Some(ssa) = &drivers[0].value.ssa
+                && let Some(line) = self.m.regs.remove(ssa)
+            {
+                // rename the register to the signal instead of adding a wire
+                let text = &self.m.lines[line];
+                let text = format!("%{}{}", signal.name, &text[ssa.len()..]);
+                self.m.lines[line] = text;
+                continue;
+            }
+
+            let mut parts = Vec::new();
+            let mut pos = width;
+            for x in drivers.iter().rev() {
+                let msb = x.lsb + x.value.width;
+                if msb > pos {
+                    continue;
+                }
+                if msb < pos {
+                    parts.push(Self::zero(pos - msb));
+                }
+                parts.push(x.value.clone());
+                pos = x.lsb;
+            }
+            if pos > 0 {
+                parts.push(Self::zero(pos));
+            }
+            let value = self.concat(&parts);
+            let value = self.name(&value);
+            let loc = self.loc(Some(&signal.token));
+            self.m.lines.push(format!(
+                "%{} = hw.wire {value} : i{width} {loc}",
+                signal.name
+            ));
+        }
+    }
+
+    fn drive(&mut self, place: &Place, value: &Value) {
+        if let Base::Signal(index) = place.base
+            && place.offset.dynamic.is_none()
+        {
+            let value = self.resize(value, place.layout.width(), false);
+            self.m.signals[index].drivers.push(Driver {
+                lsb: place.offset.fixed,
+                value,
+            });
+        }
+    }
+
+    // ------------------------------------------------------------------------
+    // Generate items
+    // ------------------------------------------------------------------------
+
+    fn generate_items(&mut self, items: &[GenerateItem]) {
+        for item in items {
+            match item {
+                GenerateItem::VarDeclaration(x) => {
+                    self.declare_variable(&x.var_declaration.identifier)
+                }
+                GenerateItem::LetDeclaration(x) => {
+                    self.declare_variable(&x.let_declaration.identifier)
+                }
+                _ => (),
+            }
+        }
+        for item in items {
+            self.generate_item(item);
+        }
+    }
+
+    fn generate_item(&mut self, arg: &GenerateItem) {
+        match arg {
+            GenerateItem::LetDeclaration(x) => self.let_declaration(&x.let_declaration),
+            GenerateItem::AssignDeclaration(x) => self.assign_declaration(&x.assign_declaration),
+            GenerateItem::AlwaysCombDeclaration(x) => {
+                self.always_comb_declaration(&x.always_comb_declaration)
+            }
+            GenerateItem::AlwaysFfDeclaration(x) => {
+                self.always_ff_declaration(&x.always_ff_declaration)
+            }
+            GenerateItem::InstDeclaration(x) => self.inst_declaration(&x.inst_declaration),
+            GenerateItem::GenerateIfDeclaration(x) => {
+                self.generate_if_declaration(&x.generate_if_declaration)
+            }
+            GenerateItem::GenerateForDeclaration(x) => {
+                self.generate_for_declaration(&x.generate_for_declaration)
+            }
+            GenerateItem::GenerateBlockDeclaration(x) => {
+                let block = x.generate_block_declaration.generate_named_block.as_ref();
+                let label = block.identifier.identifier_token.to_string();
+                let items = generate_named_block_items(block);
+                self.generate_scope(&format!("{label}."), &items);
+            }
+            _ => (),
+        }
+    }
+
+    fn generate_scope(&mut self, prefix: &str, items: &[GenerateItem]) {
+        let saved = self.m.prefix.clone();
+        self.m.prefix = format!("{saved}{prefix}");
+        self.m.scopes.push(HashMap::new());
+        self.generate_items(items);
+        self.m.scopes.pop();
+        self.m.prefix = saved;
+    }
+
+    fn let_declaration(&mut self, arg: &LetDeclaration) {
+        let Some(place) = self.identifier_place(&arg.identifier) else {
+            return;
+        };
+        self.token = Some(arg.identifier.identifier_token.token);
+        let value = self.lower_expression(&arg.expression, place.layout.width());
+        self.drive(&place, &value);
+    }
+
+    fn assign_declaration(&mut self, arg: &AssignDeclaration) {
+        let dsts: Vec<HierarchicalIdentifier> = arg.assign_destination.as_ref().into();
+        let places: Vec<_> = dsts
+            .iter()
+            .filter_map(|x| self.hierarchical_place(x))
+            .collect();
+        self.token = Some(arg.assign.assign_token.token);
+        let total: usize = places.iter().map(|x| x.layout.width()).sum();
+        if total == 0 {
+            return;
+        }
+        let value = self.lower_expression(&arg.expression, total);
+        self.drive_parts(&places, &value);
+    }
+
+    /// Drives places from the MSB side of the value
+    fn drive_parts(&mut self, places: &[Place], value: &Value) {
+        let mut pos = value.width;
+        for x in places {
+            let width = x.layout.width();
+            let lsb = pos.saturating_sub(width);
+            let part = self.extract(value, lsb, width);
+            self.drive(x, &part);
+            pos = lsb;
+        }
+    }
+
+    fn generate_if_declaration(&mut self, arg: &GenerateIfDeclaration) {
+        if self.const_value(&arg.expression).unwrap_or(0) != 0 {
+            let block = arg.generate_named_block.as_ref();
+            let label = block.identifier.identifier_token.to_string();
+            let items = generate_named_block_items(block);
+            self.generate_scope(&format!("{label}."), &items);
+            return;
+        }
+        let label = arg
+            .generate_named_block
+            .identifier
+            .identifier_token
+            .to_string();
+        for x in &arg.generate_if_declaration_list {
+            if self.const_value(&x.expression).unwrap_or(0) != 0 {
+                self.generate_optional_block(&x.generate_optional_named_block, &label);
+                return;
+            }
+        }
+        if let Some(x) = &arg.generate_if_declaration_opt {
+            self.generate_optional_block(&x.generate_optional_named_block, &label);
+        }
+    }
+
+    fn generate_optional_block(&mut self, arg: &GenerateOptionalNamedBlock, label: &str) {
+        let label = if let Some(x) = &arg.generate_optional_named_block_opt {
+            x.identifier.identifier_token.to_string()
+        } else {
+            label.to_string()
+        };
+        let items = generate_optional_named_block_items(arg);
+        self.generate_scope(&format!("{label}."), &items);
+    }
+
+    fn generate_for_declaration(&mut self, arg: &GenerateForDeclaration) {
+        let Ok(symbol) = symbol_table::resolve(arg.identifier.as_ref()) else {
+            return;
+        };
+        let id = symbol.found.id;
+        let step = arg
+            .generate_for_declaration_opt
+            .as_ref()
+            .map(|x| (x.assignment_operator.as_ref(), x.expression.as_ref()));
+        let values = self.range_values(&arg.range, step);
+        let block = arg.generate_named_block.as_ref();
+        let label = block.identifier.identifier_token.to_string();
+        let items = generate_named_block_items(block);
+        for value in values {
+            push_overrides(&[(id, Evaluated::create_fixed(value, true, vec![32], vec![]))]);
+            self.generate_scope(&format!("{label}_{value}."), &items);
+            pop_overrides(&[(id, Evaluated::create_unknown())]);
+        }
+    }
+
+    /// Values of a loop variable over a range with an optional step
+    fn range_values(
+        &mut self,
+        range: &Range,
+        step: Option<(&AssignmentOperator, &Expression)>,
+    ) -> Vec<isize> {
+        let Some(beg) = self.const_value(&range.expression) else {
+            return vec![];
+        };
+        let Some(x) = &range.range_opt else {
+            return vec![beg];
+        };
+        let Some(end) = self.const_value(&x.expression) else {
+            return vec![];
+        };
+        let end = match x.range_operator.as_ref() {
+            RangeOperator::DotDot(_) => end,
+            RangeOperator::DotDotEqu(_) => end + 1,
+        };
+        let (op, amount) = if let Some((op, expression)) = step {
+            let op = op.assignment_operator_token.to_string();
+            (op, self.const_value(expression).unwrap_or(1))
+        } else {
+            ("+=".to_string(), 1)
+        };
+
+        let mut ret = Vec::new();
+        let mut value = beg;
+        while value < end && ret.len() < MAX_UNROLL {
+            ret.push(value);
+            let next = match op.as_str() {
+                "+=" => value.checked_add(amount),
+                "-=" => value.checked_sub(amount),
+                "*=" => value.checked_mul(amount),
+                "/=" if amount != 0 => Some(value / amount),
+                "<<=" => value.checked_shl(amount as u32),
+                ">>=" => value.checked_shr(amount as u32),
+                _ => None,
+            };
+            match next {
+                Some(x) if x != value => value = x,
+                _ => break,
+            }
+        }
+        ret
+    }
+
+    // ------------------------------------------------------------------------
+    // Instance
+    // ------------------------------------------------------------------------
+
+    fn inst_declaration(&mut self, arg: &InstDeclaration) {
+        let Ok(symbol) = symbol_table::resolve(arg.scoped_identifier.as_ref()) else {
+            return;
+        };
+        let module = symbol.found;
+        let SymbolKind::Module(property) = &module.kind else {
+            return;
+        };
+        self.token = Some(arg.identifier.identifier_token.token);
+
+        // parameter overrides are evaluated in the parent context
+        let mut items: Vec<InstParameterItem> = Vec::new();
+        if let Some(x) = &arg.inst_declaration_opt1
+            && let Some(x) = &x.inst_parameter.inst_parameter_opt
+        {
+            items = x.inst_parameter_list.as_ref().into();
+        }
+        let mut overrides = Vec::new();
+        let mut key = Vec::new();
+        for param in &property.parameters {
+            let Some(item) = items
+                .iter()
+                .find(|x| x.identifier.identifier_token.token.text == param.name)
+            else {
+                continue;
+            };
+            let value = if let Some(x) = &item.inst_parameter_item_opt {
+                Evaluator::new(&[]).expression(&x.expression)
+            } else if let Ok(x) = symbol_table::resolve(item.identifier.as_ref()) {
+                x.found.evaluate()
+            } else {
+                continue;
+            };
+            let Some(x) = value.get_value() else {
+                continue;
+            };
+            let default = symbol_table::get(param.symbol).and_then(|x| x.evaluate().get_value());
+            if default != Some(x) {
+                overrides.push((param.symbol, value));
+                key.push((param.symbol, x));
+            }
+        }
+
+        let name = if overrides.is_empty() {
+            self.module_name(&module)
+        } else if let Some(x) = self.specialized.get(&(module.id, key.clone())) {
+            x.clone()
+        } else {
+            let name = format!(
+                "{}__{}_{}",
+                self.module_name(&module),
+                self.top_name,
+                self.specialized.len()
+            );
+            self.specialized
+                .insert((module.id, key.clone()), name.clone());
+            self.queue.push_back(Specialization {
+                name: name.clone(),
+                module: module.clone(),
+                overrides: overrides.clone(),
+            });
+            name
+        };
+
+        push_overrides(&overrides);
+        let ports = self.child_ports(&module);
+        pop_overrides(&overrides);
+
+        let mut connects: Vec<InstPortItem> = Vec::new();
+        if let Some(x) = &arg.inst_declaration_opt2
+            && let Some(x) = &x.inst_declaration_opt3
+        {
+            connects = x.inst_port_list.as_ref().into();
+        }
+        let inst_name = format!("{}{}", self.m.prefix, arg.identifier.identifier_token);
+
+        let mut args = Vec::new();
+        let mut results = Vec::new();
+        let mut result_types = Vec::new();
+        let mut outputs = Vec::new();
+        for port in &ports {
+            let connect = connects
+                .iter()
+                .find(|x| x.identifier.identifier_token.token.text == port.identifier);
+            self.token = Some(
+                connect
+                    .map(|x| x.identifier.identifier_token.token)
+                    .unwrap_or(arg.identifier.identifier_token.token),
+            );
+            match port.direction {
+                SymDirection::Output => {
+                    let name = format!("%{}", self.unique(&format!("{inst_name}.{}", port.name)));
+                    results.push(name.clone());
+                    result_types.push(format!("{}: i{}", port.name, port.width));
+                    if let Some(x) = connect {
+                        outputs.push((x.clone(), Value::ssa(&name, port.width)));
+                    }
+                }
+                _ => {
+                    let value = if port.clock {
+                        self.clock_connection(connect)
+                    } else if let Some(x) = connect {
+                        let value = self.connection_value(x, port.width);
+                        self.name(&value)
+                    } else {
+                        let value = port.default.clone().unwrap_or(Self::zero(port.width));
+                        let value = self.resize(&value, port.width, false);
+                        self.name(&value)
+                    };
+                    let r#type = if port.clock {
+                        "!seq.clock".to_string()
+                    } else {
+                        format!("i{}", port.width)
+                    };
+                    args.push(format!("{}: {value}: {type}", port.name));
+                }
+            }
+        }
+
+        self.token = Some(arg.identifier.identifier_token.token);
+        let inst_text = inst_name.replace('"', "\\\"");
+        let rhs = format!(
+            "hw.instance \"{inst_text}\" @{name}({}) -> ({})",
+            args.join(", "),
+            result_types.join(", ")
+        );
+        let token = self.token;
+        let loc = self.loc(token.as_ref());
+        if results.is_empty() {
+            self.m.lines.push(format!("{rhs} {loc}"));
+        } else {
+            self.m
+                .lines
+                .push(format!("{} = {rhs} {loc}", results.join(", ")));
+        }
+
+        for (connect, value) in outputs {
+            self.token = Some(connect.identifier.identifier_token.token);
+            let places = if let Some(x) = &connect.inst_port_item_opt {
+                self.expression_places(&x.expression)
+            } else {
+                self.identifier_place(&connect.identifier)
+                    .into_iter()
+                    .collect()
+            };
+            let total: usize = places.iter().map(|x| x.layout.width()).sum();
+            if total == 0 {
+                continue;
+            }
+            let value = self.resize(&value, total, false);
+            self.drive_parts(&places, &value);
+        }
+    }
+
+    /// Ports of a child module under the active parameter overrides
+    fn child_ports(&mut self, module: &Symbol) -> Vec<Port> {
+        let SymbolKind::Module(property) = &module.kind else {
+            return vec![];
+        };
+        let mut ret = Vec::new();
+        for port in &property.ports {
+            let Some(symbol) = symbol_table::get(port.symbol) else {
+                continue;
+            };
+            let x = port.property();
+            let width = self.type_layout(&x.r#type, &symbol.namespace).width();
+            let default = x.default_value.as_ref().map(|x| {
+                self.dry_run += 1;
+                let value = self.lower_expression(x, width);
+                self.dry_run -= 1;
+                if value.konst.is_some() {
+                    value
+                } else {
+                    Self::zero(width)
+                }
+            });
+            ret.push(Port {
+                identifier: symbol.token.text,
+                name: declared_name(&symbol),
+                direction: x.direction,
+                width,
+                clock: x.direction == SymDirection::Input && x.r#type.kind.is_clock() && width == 1,
+                default,
+            });
+        }
+        ret
+    }
+
+    fn connection_value(&mut self, arg: &InstPortItem, width: usize) -> Value {
+        if let Some(x) = &arg.inst_port_item_opt {
+            self.lower_expression(&x.expression, width)
+        } else if let Some(place) = self.identifier_place(&arg.identifier) {
+            let value = self.read(&place);
+            self.resize(&value, width, place.layout.signed)
+        } else {
+            Self::zero(width)
+        }
+    }
+
+    fn clock_connection(&mut self, arg: Option<&InstPortItem>) -> String {
+        let Some(arg) = arg else {
+            let zero = Self::zero(1);
+            return self.clock_of(&zero);
+        };
+        let place = if let Some(x) = &arg.inst_port_item_opt {
+            single_factor(&x.expression).and_then(|x| match x {
+                Factor::IdentifierFactor(x)
+                    if x.identifier_factor.identifier_factor_opt.is_none() =>
+                {
+                    self.expression_place(&x.identifier_factor.expression_identifier)
+                }
+                _ => None,
+            })
+        } else {
+            self.identifier_place(&arg.identifier)
+        };
+        if let Some(place) = &place
+            && let Base::Signal(index) = place.base
+            && self.m.signals[index].kind == SignalKind::ClockInput
+        {
+            return format!("%{}", self.m.signals[index].name);
+        }
+        let value = self.connection_value(arg, 1);
+        self.clock_of(&value)
+    }
+
+    fn clock_of(&mut self, value: &Value) -> String {
+        let a = self.name(value);
+        self.op(&format!("seq.to_clock {a}"), 1).ssa.unwrap()
+    }
+
+    // ------------------------------------------------------------------------
+    // Process
+    // ------------------------------------------------------------------------
+
+    fn always_comb_declaration(&mut self, arg: &AlwaysCombDeclaration) {
+        self.token = Some(arg.always_comb.always_comb_token.token);
+        self.process = Some(Process {
+            env: Env::default(),
+            ff: false,
+            reset_active: None,
+        });
+        self.statement_block(&arg.statement_block);
+        let process = self.process.take().unwrap();
+
+        self.token = Some(arg.always_comb.always_comb_token.token);
+        for (index, slot) in process.env.signals {
+            for (lsb, width) in mask_runs(&slot.mask) {
+                let value = self.extract(&slot.value, lsb, width);
+                self.m.signals[index].drivers.push(Driver { lsb, value });
+            }
+        }
+    }
+
+    fn always_ff_declaration(&mut self, arg: &AlwaysFfDeclaration) {
+        let token = arg.always_ff.always_ff_token.token;
+        self.token = Some(token);
+
+        let clock = if let Some(x) = arg.get_explicit_clock() {
+            symbol_table::resolve(&x).ok().map(|x| x.found)
+        } else {
+            self.m.default_clock.and_then(symbol_table::get)
+        };
+        let reset = if let Some(x) = arg.get_explicit_reset() {
+            symbol_table::resolve(&x).ok().map(|x| x.found)
+        } else if arg.has_if_reset() {
+            self.m.default_reset.and_then(symbol_table::get)
+        } else {
+            None
+        };
+        let Some(clock) = clock else {
+            return;
+        };
+        let clock = self.clock_value(&clock);
+        let reset = reset.map(|x| {
+            let reset_type = reset_type(&self.build_opt, &x);
+            let value = self
+                .lookup(x.id)
+                .map(|i| self.signal_value(i))
+                .unwrap_or(Self::zero(1));
+            let value = self.extract(&value, 0, 1);
+            let active = match reset_type {
+                ResetType::AsyncLow | ResetType::SyncLow => self.not(&value),
+                _ => value,
+            };
+            let sync = matches!(reset_type, ResetType::SyncHigh | ResetType::SyncLow);
+            (active, sync)
+        });
+
+        self.process = Some(Process {
+            env: Env::default(),
+            ff: true,
+            reset_active: reset.as_ref().map(|x| x.0.clone()),
+        });
+
+        let items = statement_block_items(&arg.statement_block);
+        let if_reset = match items.first() {
+            Some(StatementBlockItem::Statement(x)) if reset.is_some() => match x.statement.as_ref()
+            {
+                Statement::IfResetStatement(x) => Some(x.if_reset_statement.as_ref().clone()),
+                _ => None,
+            },
+            _ => None,
+        };
+
+        let reset_env = if let Some(x) = &if_reset {
+            self.statement_block(&x.statement_block);
+            let env = std::mem::take(&mut self.process.as_mut().unwrap().env);
+
+            let mut conds = Vec::new();
+            let mut bodies = Vec::new();
+            for y in &x.if_reset_statement_list {
+                self.token = Some(y.r#if.if_token.token);
+                conds.push(self.lower_condition(&y.expression));
+                bodies.push(Body::Block(&y.statement_block));
+            }
+            let default = if let Some(y) = &x.if_reset_statement_opt {
+                Body::Block(&y.statement_block)
+            } else {
+                Body::Empty
+            };
+            self.branches(conds, bodies, default);
+            for item in &items[1..] {
+                self.statement_block_item(item);
+            }
+            Some(env)
+        } else {
+            for item in &items {
+                self.statement_block_item(item);
+            }
+            None
+        };
+        let process = self.process.take().unwrap();
+
+        let mut indexes: BTreeSet<usize> = process.env.signals.keys().copied().collect();
+        if let Some(x) = &reset_env {
+            indexes.extend(x.signals.keys().copied());
+        }
+
+        for index in indexes {
+            self.token = Some(token);
+            let width = self.m.signals[index].width();
+            let current = Value::ssa(&format!("%{}", self.m.signals[index].name), width);
+            let mut mask = vec![false; width];
+            let next = if let Some(x) = process.env.signals.get(&index) {
+                or_mask(&mut mask, &x.mask);
+                x.value.clone()
+            } else {
+                current.clone()
+            };
+            let reset_value = reset_env
+                .as_ref()
+                .and_then(|x| x.signals.get(&index))
+                .map(|x| {
+                    or_mask(&mut mask, &x.mask);
+                    x.value.clone()
+                });
+
+            let next = self.name(&next);
+            let mut rhs = format!("seq.firreg {next} clock {clock}");
+            if let Some((active, sync)) = &reset
+                && let Some(x) = &reset_value
+            {
+                let active = self.name(active);
+                let value = self.name(x);
+                let kind = if *sync { "sync" } else { "async" };
+                rhs.push_str(&format!(" reset {kind} {active}, {value}"));
+            }
+            rhs.push_str(&format!(" : i{width}"));
+            let reg = self.op(&rhs, width);
+            if let Some(x) = &reg.ssa {
+                self.m.regs.insert(x.clone(), self.m.lines.len() - 1);
+            }
+
+            for (lsb, width) in mask_runs(&mask) {
+                let value = self.extract(&reg, lsb, width);
+                self.m.signals[index].drivers.push(Driver { lsb, value });
+            }
+        }
+    }
+
+    /// `!seq.clock` value of a clock signal
+    fn clock_value(&mut self, symbol: &Symbol) -> String {
+        let clock_type = clock_type(&self.build_opt, symbol);
+        let clock = if let Some(index) = self.lookup(symbol.id) {
+            if self.m.signals[index].kind == SignalKind::ClockInput {
+                format!("%{}", self.m.signals[index].name)
+            } else if let Some(x) = self.m.to_clocks.get(&index) {
+                x.clone()
+            } else {
+                let value = self.signal_value(index);
+                let value = self.extract(&value, 0, 1);
+                let ret = self.clock_of(&value);
+                self.m.to_clocks.insert(index, ret.clone());
+                ret
+            }
+        } else {
+            let zero = Self::zero(1);
+            self.clock_of(&zero)
+        };
+        match clock_type {
+            ClockType::PosEdge => clock,
+            ClockType::NegEdge => self.op(&format!("seq.clock_inv {clock}"), 1).ssa.unwrap(),
+        }
+    }
+
+    fn base_slot(&self, key: SlotKey) -> Slot {
+        match key {
+            SlotKey::Signal(index) => {
+                let width = self.m.signals[index].width();
+                let ff = self.process.as_ref().map(|x| x.ff).unwrap_or(false);
+                let value = if ff {
+                    Value::ssa(&format!("%{}", self.m.signals[index].name), width)
+                } else {
+                    Self::zero(width)
+                };
+                Slot {
+                    value,
+                    mask: vec![false; width],
+                }
+            }
+            SlotKey::Local(id) => {
+                let width = symbol_table::get(id)
+                    .and_then(|x| match &x.kind {
+                        SymbolKind::Variable(y) => Some((y.r#type.clone(), x.namespace.clone())),
+                        _ => None,
+                    })
+                    .map(|(t, n)| {
+                        let mut evaluator = Evaluator::new(&[]);
+                        let _ = &n;
+                        evaluator
+                            .type_width(t)
+                            .map(|x| x.iter().product::<usize>())
+                            .unwrap_or(1)
+                    })
+                    .unwrap_or(1);
+                Slot {
+                    value: Self::zero(width.max(1)),
+                    mask: vec![false; width.max(1)],
+                }
+            }
+        }
+    }
+
+    fn slot(&self, key: SlotKey) -> Option<&Slot> {
+        let env = &self.process.as_ref()?.env;
+        match key {
+            SlotKey::Signal(x) => env.signals.get(&x),
+            SlotKey::Local(x) => env.locals.get(&x),
+        }
+    }
+
+    fn set_slot(&mut self, key: SlotKey, slot: Slot) {
+        if let Some(process) = &mut self.process {
+            match key {
+                SlotKey::Signal(x) => {
+                    process.env.signals.insert(x, slot);
+                }
+                SlotKey::Local(x) => {
+                    process.env.locals.insert(x, slot);
+                }
+            }
+        }
+    }
+
+    fn env(&self) -> Env {
+        self.process
+            .as_ref()
+            .map(|x| x.env.clone())
+            .unwrap_or_default()
+    }
+
+    fn set_env(&mut self, env: Env) {
+        if let Some(x) = &mut self.process {
+            x.env = env;
+        }
+    }
+
+    /// Executes bodies as a priority chain and merges the results by multiplexers
+    fn branches(&mut self, conds: Vec<Value>, bodies: Vec<Body>, default: Body) {
+        let init = self.env();
+        let mut envs = Vec::new();
+        for body in &bodies {
+            self.set_env(init.clone());
+            self.body(body);
+            envs.push(self.env());
+        }
+        self.set_env(init.clone());
+        self.body(&default);
+        let mut acc = self.env();
+        for (cond, env) in conds.iter().zip(envs).rev() {
+            acc = self.merge(cond, env, acc);
+        }
+        self.set_env(acc);
+    }
+
+    fn body(&mut self, arg: &Body) {
+        match arg {
+            Body::Block(x) => self.statement_block(x),
+            Body::Statement(x) => self.statement(x),
+            Body::Empty => (),
+        }
+    }
+
+    fn merge(&mut self, cond: &Value, a: Env, b: Env) -> Env {
+        let mut keys: BTreeSet<SlotKey> = BTreeSet::new();
+        keys.extend(a.signals.keys().map(|x| SlotKey::Signal(*x)));
+        keys.extend(b.signals.keys().map(|x| SlotKey::Signal(*x)));
+        keys.extend(a.locals.keys().map(|x| SlotKey::Local(*x)));
+        keys.extend(b.locals.keys().map(|x| SlotKey::Local(*x)));
+
+        let mut ret = Env::default();
+        for key in keys {
+            let get = |env: &Env| match key {
+                SlotKey::Signal(x) => env.signals.get(&x).cloned(),
+                SlotKey::Local(x) => env.locals.get(&x).cloned(),
+            };
+            let x = get(&a).unwrap_or_else(|| self.base_slot(key));
+            let y = get(&b).unwrap_or_else(|| self.base_slot(key));
+            let value = self.mux(cond, &x.value, &y.value);
+            let mut mask = x.mask.clone();
+            or_mask(&mut mask, &y.mask);
+            let slot = Slot { value, mask };
+            match key {
+                SlotKey::Signal(x) => {
+                    ret.signals.insert(x, slot);
+                }
+                SlotKey::Local(x) => {
+                    ret.locals.insert(x, slot);
+                }
+            }
+        }
+        ret
+    }
+
+    // ------------------------------------------------------------------------
+    // Statement
+    // ------------------------------------------------------------------------
+
+    fn statement_block(&mut self, arg: &StatementBlock) {
+        for item in statement_block_items(arg) {
+            self.statement_block_item(&item);
+        }
+    }
+
+    fn statement_block_item(&mut self, arg: &StatementBlockItem) {
+        match arg {
+            StatementBlockItem::VarDeclaration(x) => {
+                let x = x.var_declaration.as_ref();
+                if let Ok(symbol) = symbol_table::resolve(x.identifier.as_ref()) {
+                    let key = SlotKey::Local(symbol.found.id);
+                    let slot = self.base_slot(key);
+                    self.set_slot(key, slot);
+                }
+            }
+            StatementBlockItem::LetStatement(x) => {
+                let x = x.let_statement.as_ref();
+                self.token = Some(x.identifier.identifier_token.token);
+                if let Ok(symbol) = symbol_table::resolve(x.identifier.as_ref()) {
+                    let key = SlotKey::Local(symbol.found.id);
+                    let mut slot = self.base_slot(key);
+                    let width = slot.value.width;
+                    slot.value = self.lower_expression(&x.expression, width);
+                    slot.mask = vec![true; width];
+                    self.set_slot(key, slot);
+                }
+            }
+            StatementBlockItem::Statement(x) => self.statement(&x.statement),
+            _ => (),
+        }
+    }
+
+    fn statement(&mut self, arg: &Statement) {
+        match arg {
+            Statement::IdentifierStatement(x) => self.identifier_statement(&x.identifier_statement),
+            Statement::IfStatement(x) => {
+                let x = x.if_statement.as_ref();
+                self.token = Some(x.r#if.if_token.token);
+                let mut conds = vec![self.lower_condition(&x.expression)];
+                let mut bodies = vec![Body::Block(&x.statement_block)];
+                for y in &x.if_statement_list {
+                    self.token = Some(y.r#if.if_token.token);
+                    conds.push(self.lower_condition(&y.expression));
+                    bodies.push(Body::Block(&y.statement_block));
+                }
+                let default = if let Some(y) = &x.if_statement_opt {
+                    Body::Block(&y.statement_block)
+                } else {
+                    Body::Empty
+                };
+                self.branches(conds, bodies, default);
+            }
+            Statement::IfResetStatement(x) => {
+                let x = x.if_reset_statement.as_ref();
+                self.token = Some(x.if_reset.if_reset_token.token);
+                let active = self
+                    .process
+                    .as_ref()
+                    .and_then(|x| x.reset_active.clone())
+                    .unwrap_or(Self::zero(1));
+                let mut conds = vec![active];
+                let mut bodies = vec![Body::Block(&x.statement_block)];
+                for y in &x.if_reset_statement_list {
+                    self.token = Some(y.r#if.if_token.token);
+                    conds.push(self.lower_condition(&y.expression));
+                    bodies.push(Body::Block(&y.statement_block));
+                }
+                let default = if let Some(y) = &x.if_reset_statement_opt {
+                    Body::Block(&y.statement_block)
+                } else {
+                    Body::Empty
+                };
+                self.branches(conds, bodies, default);
+            }
+            Statement::ForStatement(x) => self.for_statement(&x.for_statement),
+            Statement::CaseStatement(x) => self.case_statement(&x.case_statement),
+            Statement::SwitchStatement(x) => self.switch_statement(&x.switch_statement),
+            _ => (),
+        }
+    }
+
+    fn identifier_statement(&mut self, arg: &IdentifierStatement) {
+        let IdentifierStatementGroup::Assignment(x) = arg.identifier_statement_group.as_ref()
+        else {
+            return;
+        };
+        let Some(place) = self.expression_place(&arg.expression_identifier) else {
+            return;
+        };
+        let x = x.assignment.as_ref();
+        let width = place.layout.width();
+        let value = match x.assignment_group.as_ref() {
+            AssignmentGroup::Equ(y) => {
+                self.token = Some(y.equ.equ_token.token);
+                self.lower_expression(&x.expression, width)
+            }
+            AssignmentGroup::AssignmentOperator(y) => {
+                let token = &y.assignment_operator.assignment_operator_token;
+                self.token = Some(token.token);
+                let op = token.to_string();
+                let op = binary_operator(op.trim_end_matches('='));
+                let ast = expression_ast(&x.expression);
+                let (rw, rs) = self.info(&ast);
+                let signed = place.layout.signed && rs;
+                let current = self.read(&place);
+                let value = match op {
+                    "shl" | "ashl" | "shr" | "ashr" => {
+                        let amount = self.lower(&ast, rw, rs);
+                        let kind = match op {
+                            "ashr" if place.layout.signed => "shrs",
+                            "shr" | "ashr" => "shru",
+                            _ => "shl",
+                        };
+                        self.shift(kind, &current, &amount)
+                    }
+                    _ => {
+                        let ew = width.max(rw);
+                        let lhs = self.resize(&current, ew, signed);
+                        let rhs = self.lower(&ast, ew, signed);
+                        self.arith(op, &lhs, &rhs, signed)
+                    }
+                };
+                self.resize(&value, width, signed)
+            }
+            AssignmentGroup::DiamondOperator(_) => return,
+        };
+        self.write(&place, &value);
+    }
+
+    fn for_statement(&mut self, arg: &ForStatement) {
+        let Ok(symbol) = symbol_table::resolve(arg.identifier.as_ref()) else {
+            return;
+        };
+        let symbol = symbol.found;
+        let (width, signed) = if let SymbolKind::Variable(x) = &symbol.kind {
+            let layout = self.type_layout(&x.r#type, &symbol.namespace);
+            (layout.width(), layout.signed)
+        } else {
+            (32, false)
+        };
+        let step = arg
+            .for_statement_opt
+            .as_ref()
+            .map(|x| (x.assignment_operator.as_ref(), x.expression.as_ref()));
+        let values = self.range_values(&arg.range, step);
+        for value in values {
+            let evaluated = Evaluated::create_fixed(value, signed, vec![width], vec![]);
+            push_overrides(&[(symbol.id, evaluated)]);
+            self.statement_block(&arg.statement_block);
+            pop_overrides(&[(symbol.id, Evaluated::create_unknown())]);
+        }
+    }
+
+    fn case_statement(&mut self, arg: &CaseStatement) {
+        self.token = Some(arg.case.case_token.token);
+        let subject = expression_ast(&arg.expression);
+        let mut conds = Vec::new();
+        let mut bodies = Vec::new();
+        let mut default = Body::Empty;
+        for x in &arg.case_statement_list {
+            let item = x.case_item.as_ref();
+            let body = match item.case_item_group0.as_ref() {
+                CaseItemGroup0::Statement(y) => Body::Statement(&y.statement),
+                CaseItemGroup0::StatementBlock(y) => Body::Block(&y.statement_block),
+            };
+            match item.case_item_group.as_ref() {
+                CaseItemGroup::CaseCondition(y) => {
+                    let ranges = case_ranges(&y.case_condition);
+                    let ast = Ast::Inside(
+                        Box::new(subject.clone()),
+                        ranges,
+                        false,
+                        arg.case.case_token.token,
+                    );
+                    conds.push(self.lower(&ast, 1, false));
+                    bodies.push(body);
+                }
+                CaseItemGroup::Defaul(_) => default = body,
+            }
+        }
+        self.branches(conds, bodies, default);
+    }
+
+    fn switch_statement(&mut self, arg: &SwitchStatement) {
+        self.token = Some(arg.switch.switch_token.token);
+        let mut conds = Vec::new();
+        let mut bodies = Vec::new();
+        let mut default = Body::Empty;
+        for x in &arg.switch_statement_list {
+            let item = x.switch_item.as_ref();
+            let body = match item.switch_item_group0.as_ref() {
+                SwitchItemGroup0::Statement(y) => Body::Statement(&y.statement),
+                SwitchItemGroup0::StatementBlock(y) => Body::Block(&y.statement_block),
+            };
+            match item.switch_item_group.as_ref() {
+                SwitchItemGroup::SwitchCondition(y) => {
+                    let cond = self.switch_condition(&y.switch_condition);
+                    conds.push(cond);
+                    bodies.push(body);
+                }
+                SwitchItemGroup::Defaul(_) => default = body,
+            }
+        }
+        self.branches(conds, bodies, default);
+    }
+
+    fn switch_condition(&mut self, arg: &SwitchCondition) -> Value {
+        let mut ret = self.lower_condition(&arg.expression);
+        for x in &arg.switch_condition_list {
+            let cond = self.lower_condition(&x.expression);
+            ret = self.binop("or", &ret, &cond);
+        }
+        ret
+    }
+
+    // ------------------------------------------------------------------------
+    // Place
+    // ------------------------------------------------------------------------
+
+    fn expression_place(&mut self, arg: &ExpressionIdentifier) -> Option<Place> {
+        let symbol = symbol_table::resolve(arg.scoped_identifier.as_ref())
+            .ok()?
+            .found;
+        let mut segments: Vec<Segment> = arg
+            .expression_identifier_list
+            .iter()
+            .map(|x| Segment::Select(&x.select))
+            .collect();
+        for x in &arg.expression_identifier_list0 {
+            segments.push(Segment::Member(x.identifier.identifier_token.token.text));
+            for y in &x.expression_identifier_list0_list {
+                segments.push(Segment::Select(&y.select));
+            }
+        }
+        self.place(&symbol, &segments)
+    }
+
+    fn hierarchical_place(&mut self, arg: &HierarchicalIdentifier) -> Option<Place> {
+        let symbol = symbol_table::resolve(arg).ok()?.found;
+        let symbol = if matches!(
+            symbol.kind,
+            SymbolKind::StructMember(_) | SymbolKind::UnionMember(_)
+        ) {
+            symbol_table::resolve(arg.identifier.as_ref()).ok()?.found
+        } else {
+            symbol
+        };
+        let mut segments: Vec<Segment> = arg
+            .hierarchical_identifier_list
+            .iter()
+            .map(|x| Segment::Select(&x.select))
+            .collect();
+        for x in &arg.hierarchical_identifier_list0 {
+            segments.push(Segment::Member(x.identifier.identifier_token.token.text));
+            for y in &x.hierarchical_identifier_list0_list {
+                segments.push(Segment::Select(&y.select));
+            }
+        }
+        self.place(&symbol, &segments)
+    }
+
+    fn identifier_place(&mut self, arg: &Identifier) -> Option<Place> {
+        let symbol = symbol_table::resolve(arg).ok()?.found;
+        self.place(&symbol, &[])
+    }
+
+    /// Places of an expression which is an identifier or a concatenation of identifiers
+    fn expression_places(&mut self, arg: &Expression) -> Vec<Place> {
+        let mut ret = Vec::new();
+        match single_factor(arg) {
+            Some(Factor::IdentifierFactor(x))
+                if x.identifier_factor.identifier_factor_opt.is_none() =>
+            {
+                if let Some(x) = self.expression_place(&x.identifier_factor.expression_identifier) {
+                    ret.push(x);
+                }
+            }
+            Some(Factor::LBraceConcatenationListRBrace(x)) => {
+                let items: Vec<ConcatenationItem> = x.concatenation_list.as_ref().into();
+                for item in &items {
+                    ret.append(&mut self.expression_places(&item.expression));
+                }
+            }
+            Some(Factor::LParenExpressionRParen(x)) => {
+                ret.append(&mut self.expression_places(&x.expression));
+            }
+            _ => (),
+        }
+        ret
+    }
+
+    fn place(&mut self, symbol: &Symbol, segments: &[Segment]) -> Option<Place> {
+        let (base, layout) = self.base(symbol)?;
+        let mut place = Place {
+            base,
+            total: layout.width(),
+            layout,
+            offset: Offset::default(),
+        };
+        for x in segments {
+            match x {
+                Segment::Select(x) => self.apply_select(&mut place, x),
+                Segment::Member(x) => self.apply_member(&mut place, *x),
+            }
+        }
+        Some(place)
+    }
+
+    fn base(&mut self, symbol: &Symbol) -> Option<(Base, Layout)> {
+        match &symbol.kind {
+            SymbolKind::Port(_) => {
+                let index = self.lookup(symbol.id)?;
+                Some((Base::Signal(index), self.m.signals[index].layout.clone()))
+            }
+            SymbolKind::Variable(x) => {
+                let layout = self.type_layout(&x.r#type, &symbol.namespace);
+                if x.loop_variable {
+                    let value = evaluated_value(&symbol.evaluate(), layout.width());
+                    return Some((Base::Value(value), layout));
+                }
+                let is_local = self
+                    .process
+                    .as_ref()
+                    .map(|p| p.env.locals.contains_key(&symbol.id))
+                    .unwrap_or(false);
+                if is_local {
+                    Some((Base::Local(symbol.id), layout))
+                } else if let Some(index) = self.lookup(symbol.id) {
+                    Some((Base::Signal(index), self.m.signals[index].layout.clone()))
+                } else {
+                    Some((Base::Value(Self::zero(layout.width())), layout))
+                }
+            }
+            SymbolKind::Parameter(x) => {
+                let layout = self.type_layout(&x.r#type, &symbol.namespace);
+                let width = layout.width();
+                let evaluated = symbol.evaluate();
+                let value = if evaluated.get_value().is_some() {
+                    evaluated_value(&evaluated, width)
+                } else {
+                    let value = x.value.clone();
+                    self.lower_expression(&value, width)
+                };
+                Some((Base::Value(value), layout))
+            }
+            SymbolKind::Genvar => {
+                let value = evaluated_value(&symbol.evaluate(), 32);
+                Some((Base::Value(value), Layout::bits(32, true)))
+            }
+            _ => {
+                let evaluated = symbol.evaluate();
+                let width = evaluated.get_total_width().unwrap_or(32).max(1);
+                let value = evaluated_value(&evaluated, width);
+                Some((Base::Value(value), Layout::bits(width, false)))
+            }
+        }
+    }
+
+    fn apply_select(&mut self, place: &mut Place, arg: &Select) {
+        let layout = &mut place.layout;
+        if layout.dims.is_empty() {
+            *layout = Layout {
+                dims: vec![layout.base],
+                base: 1,
+                signed: false,
+                record: None,
+            };
+        }
+        let size = layout.dims[0];
+        let elem = layout.dims[1..].iter().product::<usize>() * layout.base;
+        let region = (place.offset.fixed, size * elem);
+        place.layout.signed = false;
+
+        self.select_msb.push(size.saturating_sub(1));
+        let index = self.index_value(&arg.expression);
+        match &arg.select_opt {
+            None => {
+                place.layout.dims.remove(0);
+                self.add_offset(place, &index, elem, 0, region);
+            }
+            Some(x) => {
+                let second = self.const_value(&x.expression).unwrap_or(0);
+                match x.select_operator.as_ref() {
+                    SelectOperator::Colon(_) => {
+                        let lsb = second.max(0) as usize;
+                        let msb = index.folded().unwrap_or(0) as usize;
+                        place.layout.dims[0] = msb.saturating_sub(lsb) + 1;
+                        place.offset.fixed += lsb * elem;
+                    }
+                    SelectOperator::PlusColon(_) => {
+                        let width = second.max(1) as usize;
+                        place.layout.dims[0] = width;
+                        self.add_offset(place, &index, elem, 0, region);
+                    }
+                    SelectOperator::MinusColon(_) => {
+                        let width = second.max(1) as usize;
+                        place.layout.dims[0] = width;
+                        self.add_offset(place, &index, elem, width - 1, region);
+                    }
+                    SelectOperator::Step(_) => {
+                        let width = second.max(1) as usize;
+                        place.layout.dims[0] = width;
+                        self.add_offset(place, &index, elem * width, 0, region);
+                    }
+                }
+            }
+        }
+        self.select_msb.pop();
+    }
+
+    fn index_value(&mut self, arg: &Expression) -> Value {
+        if let Some(x) = Evaluator::new(&[]).expression(arg).get_value() {
+            let width = (usize::BITS - x.max(0).leading_zeros()).max(1) as usize;
+            return Value::konst(x.max(0) as u128, width);
+        }
+        let ast = expression_ast(arg);
+        let (width, signed) = self.info(&ast);
+        self.lower(&ast, width, signed)
+    }
+
+    /// Adds `(index - minus) * scale` to the offset
+    fn add_offset(
+        &mut self,
+        place: &mut Place,
+        index: &Value,
+        scale: usize,
+        minus: usize,
+        region: (usize, usize),
+    ) {
+        if let Some(x) = index.folded() {
+            let x = (x as usize).saturating_sub(minus);
+            place.offset.fixed += x * scale;
+            return;
+        }
+        let width = (usize::BITS - place.total.leading_zeros()) as usize;
+        let width = width.max(index.width) + 1;
+        let index = self.resize(index, width, false);
+        let index = if minus > 0 {
+            let minus = Value::konst(minus as u128, width);
+            self.binop("sub", &index, &minus)
+        } else {
+            index
+        };
+        let scaled = if scale == 1 {
+            index
+        } else if scale.is_power_of_two() {
+            let amount = Value::konst(scale.trailing_zeros() as u128, width);
+            self.binop("shl", &index, &amount)
+        } else {
+            let scale = Value::konst(scale as u128, width);
+            self.binop("mul", &index, &scale)
+        };
+        let dynamic = if let Some(x) = place.offset.dynamic.take() {
+            let x = self.resize(&x, width.max(x.width), false);
+            let y = self.resize(&scaled, x.width, false);
+            self.binop("add", &x, &y)
+        } else {
+            scaled
+        };
+        place.offset.dynamic = Some(dynamic);
+        if place.offset.region.is_none() {
+            place.offset.region = Some(region);
+        }
+    }
+
+    fn apply_member(&mut self, place: &mut Place, name: StrId) {
+        let Some(record) = place.layout.record.clone() else {
+            return;
+        };
+        let (members, is_union) = match &record.kind {
+            SymbolKind::Struct(x) => (x.members.clone(), false),
+            SymbolKind::Union(x) => (x.members.clone(), true),
+            _ => return,
+        };
+        let mut lsb = 0;
+        let mut found = None;
+        for id in members.iter().rev() {
+            let layout = self.member_layout(*id);
+            let width = layout.width();
+            if symbol_table::get(*id).map(|x| x.token.text) == Some(name) {
+                found = Some(layout);
+                break;
+            }
+            if !is_union {
+                lsb += width;
+            }
+        }
+        if let Some(layout) = found {
+            place.offset.fixed += if is_union { 0 } else { lsb };
+            place.layout = layout;
+        }
+    }
+
+    // ------------------------------------------------------------------------
+    // Read and write
+    // ------------------------------------------------------------------------
+
+    /// Current value of a signal outside of the process
+    fn signal_value(&mut self, index: usize) -> Value {
+        let signal = &self.m.signals[index];
+        let width = signal.width();
+        if signal.kind == SignalKind::ClockInput {
+            if let Some(x) = self.m.from_clocks.get(&index) {
+                return Value::ssa(x, 1);
+            }
+            let name = format!("%{}", signal.name);
+            let ret = self.op(&format!("seq.from_clock {name}"), 1);
+            if self.dry_run == 0 {
+                self.m.from_clocks.insert(index, ret.ssa.clone().unwrap());
+            }
+            ret
+        } else {
+            Value::ssa(&format!("%{}", signal.name), width)
+        }
+    }
+
+    fn base_value(&mut self, base: &Base) -> Value {
+        match base {
+            Base::Signal(index) => {
+                let comb = self.process.as_ref().map(|x| !x.ff).unwrap_or(false);
+                let slot = self.slot(SlotKey::Signal(*index)).cloned();
+                let current = self.signal_value(*index);
+                match slot {
+                    Some(slot) if comb => self.compose(&slot, &current),
+                    _ => current,
+                }
+            }
+            Base::Local(id) => {
+                if let Some(x) = self.slot(SlotKey::Local(*id)) {
+                    x.value.clone()
+                } else {
+                    self.base_slot(SlotKey::Local(*id)).value
+                }
+            }
+            Base::Value(x) => x.clone(),
+        }
+    }
+
+    /// Combines assigned bits in the process with the current value
+    fn compose(&mut self, slot: &Slot, current: &Value) -> Value {
+        let mut parts = Vec::new();
+        let width = slot.mask.len();
+        let mut msb = width;
+        while msb > 0 {
+            let assigned = slot.mask[msb - 1];
+            let mut lsb = msb - 1;
+            while lsb > 0 && slot.mask[lsb - 1] == assigned {
+                lsb -= 1;
+            }
+            let source = if assigned { &slot.value } else { current };
+            let source = source.clone();
+            parts.push(self.extract(&source, lsb, msb - lsb));
+            msb = lsb;
+        }
+        self.concat(&parts)
+    }
+
+    fn read(&mut self, place: &Place) -> Value {
+        let value = self.base_value(&place.base);
+        let width = place.layout.width();
+        if let Some(x) = &place.offset.dynamic {
+            let amount = self.resize(x, value.width, false);
+            let shifted = self.shift("shru", &value, &amount);
+            self.extract(&shifted, place.offset.fixed, width)
+        } else {
+            self.extract(&value, place.offset.fixed, width)
+        }
+    }
+
+    fn write(&mut self, place: &Place, value: &Value) {
+        let key = match place.base {
+            Base::Signal(x) => SlotKey::Signal(x),
+            Base::Local(x) => SlotKey::Local(x),
+            Base::Value(_) => return,
+        };
+        let mut slot = self
+            .slot(key)
+            .cloned()
+            .unwrap_or_else(|| self.base_slot(key));
+        let total = slot.value.width;
+        let width = place.layout.width();
+        let value = self.resize(value, width, false);
+        let lsb = place.offset.fixed;
+
+        if let Some(x) = &place.offset.dynamic {
+            let offset = self.resize(x, total, false);
+            let fixed = Value::konst(lsb as u128, total);
+            let offset = self.binop("add", &offset, &fixed);
+            let ones = self.ones(width);
+            let ones = self.resize(&ones, total, false);
+            let bits = self.shift("shl", &ones, &offset);
+            let keep = self.not(&bits);
+            let keep = self.binop("and", &slot.value, &keep);
+            let value = self.resize(&value, total, false);
+            let value = self.shift("shl", &value, &offset);
+            slot.value = self.binop("or", &keep, &value);
+            let (beg, len) = place.offset.region.unwrap_or((0, total));
+            for x in slot.mask.iter_mut().skip(beg).take(len) {
+                *x = true;
+            }
+        } else {
+            if lsb >= total {
+                return;
+            }
+            let width = width.min(total - lsb);
+            let value = self.extract(&value, 0, width);
+            let upper = self.extract(&slot.value, lsb + width, total - lsb - width);
+            let lower = self.extract(&slot.value, 0, lsb);
+            slot.value = self.concat(&[upper, value, lower]);
+            for x in slot.mask.iter_mut().skip(lsb).take(width) {
+                *x = true;
+            }
+        }
+        self.set_slot(key, slot);
+    }
+
+    // ------------------------------------------------------------------------
+    // Expression
+    // ------------------------------------------------------------------------
+
+    fn expression_info(&mut self, arg: &Expression) -> (usize, bool) {
+        let ast = expression_ast(arg);
+        self.info(&ast)
+    }
+
+    /// Lowers an expression into the given width with SystemVerilog width rules
+    fn lower_expression(&mut self, arg: &Expression, width: usize) -> Value {
+        let ast = expression_ast(arg);
+        let (w, s) = self.info(&ast);
+        let value = self.lower(&ast, w.max(width), s);
+        self.extract(&value, 0, width)
+    }
+
+    fn lower_condition(&mut self, arg: &Expression) -> Value {
+        let ast = expression_ast(arg);
+        self.condition(&ast)
+    }
+
+    fn condition(&mut self, arg: &Ast) -> Value {
+        let (w, s) = self.info(arg);
+        let value = self.lower(arg, w, s);
+        self.bool_value(&value)
+    }
+
+    /// Self-determined width and signedness
+    fn info(&mut self, arg: &Ast) -> (usize, bool) {
+        match arg {
+            Ast::Binary(op, x, y, _) => match *op {
+                "add" | "sub" | "mul" | "div" | "mod" | "and" | "or" | "xor" | "xnor" => {
+                    let (xw, xs) = self.info(x);
+                    let (yw, ys) = self.info(y);
+                    (xw.max(yw), xs && ys)
+                }
+                "shl" | "ashl" | "shr" | "ashr" => self.info(x),
+                "pow" => {
+                    let (xw, xs) = self.info(x);
+                    let (_, ys) = self.info(y);
+                    (xw, xs && ys)
+                }
+                _ => (1, false),
+            },
+            Ast::Unary(op, x, _) => match *op {
+                "neg" | "pos" | "not" => self.info(x),
+                _ => (1, false),
+            },
+            Ast::Cast(x, y, _) => {
+                let (w, s) = self.info(y);
+                self.cast_info(x, w, s)
+            }
+            Ast::Cond(x, y) => {
+                let (mut w, mut s) = self.info(y);
+                for (_, z) in x {
+                    let (zw, zs) = self.info(z);
+                    w = w.max(zw);
+                    s &= zs;
+                }
+                (w, s)
+            }
+            Ast::Inside(..) => (1, false),
+            Ast::Factor(x) => self.factor_info(x),
+        }
+    }
+
+    fn cast_info(&mut self, arg: &CastingType, width: usize, signed: bool) -> (usize, bool) {
+        match arg {
+            CastingType::U8(_) => (8, false),
+            CastingType::U16(_) => (16, false),
+            CastingType::U32(_) => (32, false),
+            CastingType::U64(_) => (64, false),
+            CastingType::I8(_) => (8, true),
+            CastingType::I16(_) => (16, true),
+            CastingType::I32(_) => (32, true),
+            CastingType::I64(_) => (64, true),
+            CastingType::UserDefinedType(x) => {
+                let scoped = x.user_defined_type.scoped_identifier.as_ref();
+                if let Ok(symbol) = symbol_table::resolve(scoped) {
+                    let layout = self.symbol_layout(&symbol.found);
+                    (layout.width(), layout.signed)
+                } else {
+                    (width, signed)
+                }
+            }
+            CastingType::Based(x) => {
+                let text = x.based.based_token.to_string();
+                let (width, _) = based_width(&text);
+                (width.unwrap_or(32), signed)
+            }
+            CastingType::BaseLess(x) => {
+                let text = x.base_less.base_less_token.to_string().replace('_', "");
+                (text.parse().unwrap_or(width), signed)
+            }
+            CastingType::F32(_) | CastingType::F64(_) => (width, signed),
+            _ => (1, false),
+        }
+    }
+
+    fn lower(&mut self, arg: &Ast, width: usize, signed: bool) -> Value {
+        match arg {
+            Ast::Binary(op, x, y, token) => {
+                self.token = Some(*token);
+                match *op {
+                    "add" | "sub" | "mul" | "div" | "mod" | "and" | "or" | "xor" | "xnor" => {
+                        let a = self.lower(x, width, signed);
+                        let b = self.lower(y, width, signed);
+                        self.token = Some(*token);
+                        self.arith(op, &a, &b, signed)
+                    }
+                    "shl" | "ashl" | "shr" | "ashr" => {
+                        let a = self.lower(x, width, signed);
+                        let (yw, _) = self.info(y);
+                        let b = self.lower(y, yw, false);
+                        let kind = match *op {
+                            "ashr" if signed => "shrs",
+                            "shr" | "ashr" => "shru",
+                            _ => "shl",
+                        };
+                        self.token = Some(*token);
+                        self.shift(kind, &a, &b)
+                    }
+                    "pow" => {
+                        let a = self.lower(x, width, signed);
+                        let (yw, ys) = self.info(y);
+                        let b = self.lower(y, yw, ys);
+                        self.token = Some(*token);
+                        self.pow(&a, &b)
+                    }
+                    "land" | "lor" => {
+                        let a = self.condition(x);
+                        let b = self.condition(y);
+                        self.token = Some(*token);
+                        let name = if *op == "land" { "and" } else { "or" };
+                        let ret = self.binop(name, &a, &b);
+                        self.resize(&ret, width, false)
+                    }
+                    _ => {
+                        let (xw, xs) = self.info(x);
+                        let (yw, ys) = self.info(y);
+                        let w = xw.max(yw);
+                        let s = xs && ys;
+                        let a = self.lower(x, w, s);
+                        let b = self.lower(y, w, s);
+                        self.token = Some(*token);
+                        let pred = match (*op, s) {
+                            ("eq", _) => "eq",
+                            ("ne", _) => "ne",
+                            ("lt", true) => "slt",
+                            ("lt", false) => "ult",
+                            ("le", true) => "sle",
+                            ("le", false) => "ule",
+                            ("gt", true) => "sgt",
+                            ("gt", false) => "ugt",
+                            ("ge", true) => "sge",
+                            _ => "uge",
+                        };
+                        let ret = self.icmp(pred, &a, &b);
+                        self.resize(&ret, width, false)
+                    }
+                }
+            }
+            Ast::Unary(op, x, token) => match *op {
+                "neg" => {
+                    let a = self.lower(x, width, signed);
+                    self.token = Some(*token);
+                    let zero = Self::zero(width);
+                    self.binop("sub", &zero, &a)
+                }
+                "pos" => self.lower(x, width, signed),
+                "not" => {
+                    let a = self.lower(x, width, signed);
+                    self.token = Some(*token);
+                    self.not(&a)
+                }
+                "lnot" => {
+                    let a = self.condition(x);
+                    self.token = Some(*token);
+                    let ret = self.not(&a);
+                    self.resize(&ret, width, false)
+                }
+                _ => {
+                    let (w, s) = self.info(x);
+                    let a = self.lower(x, w, s);
+                    self.token = Some(*token);
+                    let ret = self.reduce(op, &a);
+                    self.resize(&ret, width, false)
+                }
+            },
+            Ast::Cast(x, y, token) => {
+                let (w, s) = self.info(y);
+                let (cw, _) = self.cast_info(x, w, s);
+                let a = self.lower(y, w.max(cw), s);
+                self.token = Some(*token);
+                let a = self.resize(&a, cw, s);
+                self.resize(&a, width, signed)
+            }
+            Ast::Cond(x, y) => {
+                let mut conds = Vec::new();
+                let mut values = Vec::new();
+                for (c, v) in x {
+                    conds.push(self.condition(c));
+                    values.push(self.lower(v, width, signed));
+                }
+                let mut ret = self.lower(y, width, signed);
+                for (c, v) in conds.iter().zip(values.iter()).rev() {
+                    ret = self.mux(c, v, &ret);
+                }
+                ret
+            }
+            Ast::Inside(x, ranges, outside, token) => {
+                let (mut w, mut s) = self.info(x);
+                let mut items = Vec::new();
+                for range in ranges {
+                    let a = expression_ast(&range.expression);
+                    let b = range.range_opt.as_ref().map(|y| {
+                        let inclusive =
+                            matches!(y.range_operator.as_ref(), RangeOperator::DotDotEqu(_));
+                        (expression_ast(&y.expression), inclusive)
+                    });
+                    let (aw, as_) = self.info(&a);
+                    w = w.max(aw);
+                    s &= as_;
+                    if let Some((b, _)) = &b {
+                        let (bw, bs) = self.info(b);
+                        w = w.max(bw);
+                        s &= bs;
+                    }
+                    items.push((a, b));
+                }
+                let subject = self.lower(x, w, s);
+                let mut ret = Self::zero(1);
+                for (a, b) in &items {
+                    let a = self.lower(a, w, s);
+                    self.token = Some(*token);
+                    let hit = if let Some((b, inclusive)) = b {
+                        let b = self.lower(b, w, s);
+                        let (ge, lt) = if s { ("sge", "slt") } else { ("uge", "ult") };
+                        let lt = if *inclusive {
+                            if s { "sle" } else { "ule" }
+                        } else {
+                            lt
+                        };
+                        let lower = self.icmp(ge, &subject, &a);
+                        let upper = self.icmp(lt, &subject, &b);
+                        self.binop("and", &lower, &upper)
+                    } else {
+                        self.icmp("eq", &subject, &a)
+                    };
+                    ret = self.binop("or", &ret, &hit);
+                }
+                if *outside {
+                    ret = self.not(&ret);
+                }
+                self.resize(&ret, width, false)
+            }
+            Ast::Factor(x) => self.lower_factor(x, width, signed),
+        }
+    }
+
+    fn arith(&mut self, op: &str, a: &Value, b: &Value, signed: bool) -> Value {
+        match op {
+            "xnor" => {
+                let x = self.binop("xor", a, b);
+                self.not(&x)
+            }
+            "div" => self.binop(if signed { "divs" } else { "divu" }, a, b),
+            "mod" => self.binop(if signed { "mods" } else { "modu" }, a, b),
+            _ => self.binop(op, a, b),
+        }
+    }
+
+    fn factor_info(&mut self, arg: &Factor) -> (usize, bool) {
+        if let Factor::Number(x) = arg
+            && let Number::IntegralNumber(x) = x.number.as_ref()
+            && let IntegralNumber::AllBit(x) = x.integral_number.as_ref()
+        {
+            let text = x.all_bit.all_bit_token.to_string();
+            if text.starts_with('\'') {
+                return (1, false);
+            }
+        }
+        self.dry_run += 1;
+        let (value, signed) = self.factor(arg);
+        self.dry_run -= 1;
+        (value.width, signed)
+    }
+
+    fn lower_factor(&mut self, arg: &Factor, width: usize, signed: bool) -> Value {
+        if let Factor::Number(x) = arg
+            && let Number::IntegralNumber(x) = x.number.as_ref()
+            && let IntegralNumber::AllBit(x) = x.integral_number.as_ref()
+        {
+            let text = x.all_bit.all_bit_token.to_string();
+            if let Some(x) = text.strip_prefix('\'') {
+                return if x == "1" {
+                    self.ones(width)
+                } else {
+                    Self::zero(width)
+                };
+            }
+        }
+        let (value, _) = self.factor(arg);
+        self.resize(&value, width, signed)
+    }
+
+    /// Self-determined value of a factor
+    fn factor(&mut self, arg: &Factor) -> (Value, bool) {
+        match arg {
+            Factor::Number(x) => match x.number.as_ref() {
+                Number::IntegralNumber(x) => integral_number(x.integral_number.as_ref()),
+                Number::RealNumber(_) => (Self::zero(64), false),
+            },
+            Factor::BooleanLiteral(x) => {
+                let value = matches!(x.boolean_literal.as_ref(), BooleanLiteral::True(_));
+                (Value::konst(value as u128, 1), false)
+            }
+            Factor::IdentifierFactor(x) => self.identifier_factor(&x.identifier_factor),
+            Factor::LParenExpressionRParen(x) => {
+                let ast = expression_ast(&x.expression);
+                let (w, s) = self.info(&ast);
+                (self.lower(&ast, w, s), s)
+            }
+            Factor::LBraceConcatenationListRBrace(x) => {
+                let items: Vec<ConcatenationItem> = x.concatenation_list.as_ref().into();
+                let mut parts = Vec::new();
+                for item in &items {
+                    let ast = expression_ast(&item.expression);
+                    let (w, s) = self.info(&ast);
+                    let value = self.lower(&ast, w, s);
+                    if let Some(y) = &item.concatenation_item_opt {
+                        let count = self.const_value(&y.expression).unwrap_or(1).max(0) as usize;
+                        if count > 0 {
+                            parts.push(self.replicate(&value, count));
+                        }
+                    } else {
+                        parts.push(value);
+                    }
+                }
+                if parts.is_empty() {
+                    (Self::zero(1), false)
+                } else {
+                    (self.concat(&parts), false)
+                }
+            }
+            Factor::FactorGroup(x) => {
+                let value = match x.factor_group.as_ref() {
+                    FactorGroup::Msb(_) => self.select_msb.last().copied().unwrap_or(0),
+                    FactorGroup::Lsb(_) => 0,
+                };
+                (Value::konst(value as u128, 32), true)
+            }
+            Factor::CaseExpression(_)
+            | Factor::SwitchExpression(_)
+            | Factor::InsideExpression(_)
+            | Factor::OutsideExpression(_) => {
+                let ast = factor_ast(arg);
+                let (w, s) = self.info(&ast);
+                (self.lower(&ast, w, s), s)
+            }
+            _ => (Self::zero(1), false),
+        }
+    }
+
+    fn identifier_factor(&mut self, arg: &IdentifierFactor) -> (Value, bool) {
+        if let Some(x) = &arg.identifier_factor_opt {
+            if let IdentifierFactorOptGroup::FunctionCall(x) =
+                x.identifier_factor_opt_group.as_ref()
+            {
+                let name = arg.expression_identifier.identifier().to_string();
+                return self.system_function(&name, &x.function_call);
+            }
+            return (Self::zero(1), false);
+        }
+        if let Some(place) = self.expression_place(&arg.expression_identifier) {
+            let value = self.read(&place);
+            (value, place.layout.signed)
+        } else {
+            (Self::zero(1), false)
+        }
+    }
+
+    fn system_function(&mut self, name: &str, arg: &FunctionCall) -> (Value, bool) {
+        let args: Vec<ArgumentItem> = if let Some(x) = &arg.function_call_opt {
+            x.argument_list.as_ref().into()
+        } else {
+            vec![]
+        };
+        let Some(first) = args.first() else {
+            return (Self::zero(32), false);
+        };
+        let expression = first.argument_expression.expression.as_ref();
+        match name {
+            "$signed" | "$unsigned" => {
+                let ast = expression_ast(expression);
+                let (w, s) = self.info(&ast);
+                (self.lower(&ast, w, s), name == "$signed")
+            }
+            "$clog2" => {
+                let value = self.const_value(expression).unwrap_or(0).max(0) as u128;
+                let ret = if value <= 1 {
+                    0
+                } else {
+                    128 - (value - 1).leading_zeros() as u128
+                };
+                (Value::konst(ret, 32), true)
+            }
+            "$bits" | "$size" => {
+                let layout = self.argument_layout(expression);
+                let value = if name == "$bits" {
+                    layout.width()
+                } else {
+                    layout.dims.first().copied().unwrap_or(1)
+                };
+                (Value::konst(value as u128, 32), true)
+            }
+            _ => (Self::zero(1), false),
+        }
+    }
+
+    fn argument_layout(&mut self, arg: &Expression) -> Layout {
+        if let Some(Factor::IdentifierFactor(x)) = single_factor(arg) {
+            let x = &x.identifier_factor.expression_identifier;
+            if let Ok(symbol) = symbol_table::resolve(x.scoped_identifier.as_ref())
+                && matches!(
+                    symbol.found.kind,
+                    SymbolKind::Struct(_)
+                        | SymbolKind::Union(_)
+                        | SymbolKind::Enum(_)
+                        | SymbolKind::TypeDef(_)
+                )
+            {
+                return self.symbol_layout(&symbol.found);
+            }
+            self.dry_run += 1;
+            let place = self.expression_place(x);
+            self.dry_run -= 1;
+            if let Some(x) = place {
+                return x.layout;
+            }
+        }
+        let (width, signed) = self.expression_info(arg);
+        Layout::bits(width, signed)
+    }
+}
+
+fn push_overrides(overrides: &[(SymbolId, Evaluated)]) {
+    if overrides.is_empty() {
+        return;
+    }
+    for (id, value) in overrides {
+        symbol_table::push_override(*id, value.clone());
+    }
+    symbol_table::clear_evaluated_cache(&Namespace::new());
+}
+
+fn pop_overrides(overrides: &[(SymbolId, Evaluated)]) {
+    if overrides.is_empty() {
+        return;
+    }
+    for (id, _) in overrides {
+        symbol_table::pop_override(*id);
+    }
+    symbol_table::clear_evaluated_cache(&Namespace::new());
+}
+
+fn declared_name(symbol: &Symbol) -> String {
+    let text = symbol.token.to_string();
+    let text = text.strip_prefix("r#").unwrap_or(&text);
+    let (prefix, suffix) = match &symbol.kind {
+        SymbolKind::Port(x) => (x.prefix.clone(), x.suffix.clone()),
+        SymbolKind::Variable(x) => (x.prefix.clone(), x.suffix.clone()),
+        _ => (None, None),
+    };
+    format!(
+        "{}{text}{}",
+        prefix.unwrap_or_default(),
+        suffix.unwrap_or_default()
+    )
+}
+
+fn clock_type(build_opt: &Build, symbol: &Symbol) -> ClockType {
+    match variable_type_kind(symbol) {
+        Some(TypeKind::ClockPosedge) => ClockType::PosEdge,
+        Some(TypeKind::ClockNegedge) => ClockType::NegEdge,
+        _ => build_opt.clock_type,
+    }
+}
+
+fn reset_type(build_opt: &Build, symbol: &Symbol) -> ResetType {
+    match variable_type_kind(symbol) {
+        Some(TypeKind::ResetAsyncHigh) => ResetType::AsyncHigh,
+        Some(TypeKind::ResetAsyncLow) => ResetType::AsyncLow,
+        Some(TypeKind::ResetSyncHigh) => ResetType::SyncHigh,
+        Some(TypeKind::ResetSyncLow) => ResetType::SyncLow,
+        _ => build_opt.reset_type,
+    }
+}
+
+fn variable_type_kind(symbol: &Symbol) -> Option<TypeKind> {
+    match &symbol.kind {
+        SymbolKind::Port(x) => Some(x.r#type.kind.clone()),
+        SymbolKind::Variable(x) => Some(x.r#type.kind.clone()),
+        _ => None,
+    }
+}
+
+fn evaluated_value(arg: &Evaluated, width: usize) -> Value {
+    let value = arg.get_value().unwrap_or(0);
+    Value::konst(value as i128 as u128, width)
+}
+
+fn mask(width: usize) -> u128 {
+    if width >= 128 {
+        u128::MAX
+    } else {
+        (1u128 << width) - 1
+    }
+}
+
+fn sign_extend(value: u128, width: usize) -> i128 {
+    if width == 0 || width >= 128 {
+        value as i128
+    } else if (value >> (width - 1)) & 1 == 1 {
+        (value | !mask(width)) as i128
+    } else {
+        value as i128
+    }
+}
+
+/// Constant text which is valid for signless integer types
+fn signed_text(value: u128, width: usize) -> String {
+    if width > 128 {
+        value.to_string()
+    } else {
+        sign_extend(value, width).to_string()
+    }
+}
+
+fn fold_binop(name: &str, x: u128, y: u128, width: usize) -> Option<u128> {
+    let sx = sign_extend(x, width);
+    let sy = sign_extend(y, width);
+    let ret = match name {
+        "add" => x.wrapping_add(y),
+        "sub" => x.wrapping_sub(y),
+        "mul" => x.wrapping_mul(y),
+        "divu" if y != 0 => x / y,
+        "modu" if y != 0 => x % y,
+        "divs" if sy != 0 => sx.checked_div(sy)? as u128,
+        "mods" if sy != 0 => sx.checked_rem(sy)? as u128,
+        "and" => x & y,
+        "or" => x | y,
+        "xor" => x ^ y,
+        "shl" if y < width as u128 => x << y,
+        "shl" => 0,
+        "shru" if y < width as u128 => x >> y,
+        "shru" => 0,
+        "shrs" => (sx >> y.min(width as u128 - 1)) as u128,
+        _ => return None,
+    };
+    Some(ret & mask(width))
+}
+
+fn fold_icmp(pred: &str, x: u128, y: u128, width: usize) -> Option<bool> {
+    let sx = sign_extend(x, width);
+    let sy = sign_extend(y, width);
+    let ret = match pred {
+        "eq" => x == y,
+        "ne" => x != y,
+        "ult" => x < y,
+        "ule" => x <= y,
+        "ugt" => x > y,
+        "uge" => x >= y,
+        "slt" => sx < sy,
+        "sle" => sx <= sy,
+        "sgt" => sx > sy,
+        "sge" => sx >= sy,
+        _ => return None,
+    };
+    Some(ret)
+}
+
+fn or_mask(dst: &mut [bool], src: &[bool]) {
+    for (x, y) in dst.iter_mut().zip(src.iter()) {
+        *x |= *y;
+    }
+}
+
+/// Runs of assigned bits as `(lsb, width)`
+fn mask_runs(mask: &[bool]) -> Vec<(usize, usize)> {
+    let mut ret = Vec::new();
+    let mut i = 0;
+    while i < mask.len() {
+        if mask[i] {
+            let beg = i;
+            while i < mask.len() && mask[i] {
+                i += 1;
+            }
+            ret.push((beg, i - beg));
+        } else {
+            i += 1;
+        }
+    }
+    ret
+}
+
+/// Width and signedness of a based literal prefix like `8'sh`
+fn based_width(text: &str) -> (Option<usize>, bool) {
+    if let Some((width, rest)) = text.split_once('\'') {
+        let width = width.replace('_', "").parse().ok();
+        (width, rest.starts_with('s'))
+    } else {
+        (text.replace('_', "").parse().ok(), false)
+    }
+}
+
+fn integral_number(arg: &IntegralNumber) -> (Value, bool) {
+    match arg {
+        IntegralNumber::Based(x) => {
+            let text = x.based.based_token.to_string();
+            let (width, signed) = based_width(&text);
+            let rest = text.split_once('\'').map(|x| x.1).unwrap_or("");
+            let rest = rest.trim_start_matches('s');
+            let mut chars = rest.chars();
+            let radix = match chars.next() {
+                Some('b') => 2,
+                Some('o') => 8,
+                Some('d') => 10,
+                _ => 16,
+            };
+            let mut value = 0u128;
+            for c in chars {
+                if let Some(x) = c.to_digit(radix) {
+                    value = value.wrapping_mul(radix as u128).wrapping_add(x as u128);
+                }
+            }
+            let width = width.unwrap_or(32).max(1);
+            (Value::konst(value, width), signed)
+        }
+        IntegralNumber::BaseLess(x) => {
+            let text = x.base_less.base_less_token.to_string().replace('_', "");
+            let value: u128 = text.parse().unwrap_or(0);
+            let width = (128 - value.leading_zeros() as usize + 1).max(32);
+            (Value::konst(value, width), true)
+        }
+        IntegralNumber::AllBit(x) => {
+            let text = x.all_bit.all_bit_token.to_string();
+            let (width, _) = based_width(&text);
+            let width = width.unwrap_or(1).max(1);
+            let value = if text.ends_with('1') { u128::MAX } else { 0 };
+            (Value::konst(value, width), false)
+        }
+    }
+}
+
+fn binary_operator(text: &str) -> &'static str {
+    match text {
+        "||" => "lor",
+        "&&" => "land",
+        "|" => "or",
+        "&" => "and",
+        "^" => "xor",
+        "~^" | "^~" => "xnor",
+        "==" | "===" | "==?" => "eq",
+        "!=" | "!==" | "!=?" => "ne",
+        "<:" => "lt",
+        "<=" => "le",
+        ">:" => "gt",
+        ">=" => "ge",
+        "<<" => "shl",
+        "<<<" => "ashl",
+        ">>" => "shr",
+        ">>>" => "ashr",
+        "+" => "add",
+        "-" => "sub",
+        "*" => "mul",
+        "/" => "div",
+        "%" => "mod",
+        "**" => "pow",
+        _ => "add",
+    }
+}
+
+fn expression_ast(arg: &Expression) -> Ast<'_> {
+    let x = arg.if_expression.as_ref();
+    let default = expression01_ast(&x.expression01);
+    if x.if_expression_list.is_empty() {
+        default
+    } else {
+        let arms = x
+            .if_expression_list
+            .iter()
+            .map(|y| {
+                (
+                    expression_ast(&y.expression),
+                    expression_ast(&y.expression0),
+                )
+            })
+            .collect();
+        Ast::Cond(arms, Box::new(default))
+    }
+}
+
+macro_rules! binary_ast {
+    ($name:ident, $arg:ty, $first:ident, $list:ident, $next:ident, $op:ident, $op_token:ident) => {
+        fn $name(arg: &$arg) -> Ast<'_> {
+            let mut ret = $next(&arg.$first);
+            for x in &arg.$list {
+                let token = &x.$op.$op_token;
+                let op = binary_operator(&token.to_string());
+                ret = Ast::Binary(op, Box::new(ret), Box::new($next(&x.$first)), token.token);
+            }
+            ret
+        }
+    };
+}
+
+binary_ast!(
+    expression01_ast,
+    Expression01,
+    expression02,
+    expression01_list,
+    expression02_ast,
+    operator02,
+    operator02_token
+);
+binary_ast!(
+    expression02_ast,
+    Expression02,
+    expression03,
+    expression02_list,
+    expression03_ast,
+    operator03,
+    operator03_token
+);
+binary_ast!(
+    expression03_ast,
+    Expression03,
+    expression04,
+    expression03_list,
+    expression04_ast,
+    operator04,
+    operator04_token
+);
+binary_ast!(
+    expression04_ast,
+    Expression04,
+    expression05,
+    expression04_list,
+    expression05_ast,
+    operator05,
+    operator05_token
+);
+binary_ast!(
+    expression05_ast,
+    Expression05,
+    expression06,
+    expression05_list,
+    expression06_ast,
+    operator06,
+    operator06_token
+);
+binary_ast!(
+    expression06_ast,
+    Expression06,
+    expression07,
+    expression06_list,
+    expression07_ast,
+    operator07,
+    operator07_token
+);
+binary_ast!(
+    expression07_ast,
+    Expression07,
+    expression08,
+    expression07_list,
+    expression08_ast,
+    operator08,
+    operator08_token
+);
+binary_ast!(
+    expression08_ast,
+    Expression08,
+    expression09,
+    expression08_list,
+    expression09_ast,
+    operator09,
+    operator09_token
+);
+binary_ast!(
+    expression09_ast,
+    Expression09,
+    expression10,
+    expression09_list,
+    expression10_ast,
+    operator10,
+    operator10_token
+);
+binary_ast!(
+    expression11_ast,
+    Expression11,
+    expression12,
+    expression11_list,
+    expression12_ast,
+    operator12,
+    operator12_token
+);
+
+fn expression10_ast(arg: &Expression10) -> Ast<'_> {
+    let mut ret = expression11_ast(&arg.expression11);
+    for x in &arg.expression10_list {
+        let token = match x.expression10_list_group.as_ref() {
+            Expression10ListGroup::Operator11(y) => &y.operator11.operator11_token,
+            Expression10ListGroup::Star(y) => &y.star.star_token,
+        };
+        let op = binary_operator(&token.to_string());
+        ret = Ast::Binary(
+            op,
+            Box::new(ret),
+            Box::new(expression11_ast(&x.expression11)),
+            token.token,
+        );
+    }
+    ret
+}
+
+fn expression12_ast(arg: &Expression12) -> Ast<'_> {
+    let ret = expression13_ast(&arg.expression13);
+    if let Some(x) = &arg.expression12_opt {
+        Ast::Cast(&x.casting_type, Box::new(ret), x.r#as.as_token.token)
+    } else {
+        ret
+    }
+}
+
+fn expression13_ast(arg: &Expression13) -> Ast<'_> {
+    let mut ret = factor_ast(&arg.factor);
+    for x in arg.expression13_list.iter().rev() {
+        let (token, op) = match x.expression13_list_group.as_ref() {
+            Expression13ListGroup::UnaryOperator(y) => {
+                let token = &y.unary_operator.unary_operator_token;
+                let op = match token.to_string().as_str() {
+                    "~&" => "rnand",
+                    "~|" => "rnor",
+                    "!" => "lnot",
+                    _ => "not",
+                };
+                (token, op)
+            }
+            Expression13ListGroup::Operator10(y) => {
+                let token = &y.operator10.operator10_token;
+                let op = if token.to_string() == "-" {
+                    "neg"
+                } else {
+                    "pos"
+                };
+                (token, op)
+            }
+            Expression13ListGroup::Operator06(y) => (&y.operator06.operator06_token, "rand"),
+            Expression13ListGroup::Operator04(y) => (&y.operator04.operator04_token, "ror"),
+            Expression13ListGroup::Operator05(y) => {
+                let token = &y.operator05.operator05_token;
+                let op = if token.to_string() == "^" {
+                    "rxor"
+                } else {
+                    "rxnor"
+                };
+                (token, op)
+            }
+        };
+        ret = Ast::Unary(op, Box::new(ret), token.token);
+    }
+    ret
+}
+
+fn factor_ast(arg: &Factor) -> Ast<'_> {
+    match arg {
+        Factor::LParenExpressionRParen(x) => expression_ast(&x.expression),
+        Factor::CaseExpression(x) => {
+            let x = x.case_expression.as_ref();
+            let token = x.case.case_token.token;
+            let subject = expression_ast(&x.expression);
+            let mut arms = vec![(
+                Ast::Inside(
+                    Box::new(subject.clone()),
+                    case_ranges(&x.case_condition),
+                    false,
+                    token,
+                ),
+                expression_ast(&x.expression0),
+            )];
+            for y in &x.case_expression_list {
+                arms.push((
+                    Ast::Inside(
+                        Box::new(subject.clone()),
+                        case_ranges(&y.case_condition),
+                        false,
+                        token,
+                    ),
+                    expression_ast(&y.expression),
+                ));
+            }
+            Ast::Cond(arms, Box::new(expression_ast(&x.expression1)))
+        }
+        Factor::SwitchExpression(x) => {
+            let x = x.switch_expression.as_ref();
+            let token = x.switch.switch_token.token;
+            let mut arms = vec![(
+                switch_ast(&x.switch_condition, token),
+                expression_ast(&x.expression),
+            )];
+            for y in &x.switch_expression_list {
+                arms.push((
+                    switch_ast(&y.switch_condition, token),
+                    expression_ast(&y.expression),
+                ));
+            }
+            Ast::Cond(arms, Box::new(expression_ast(&x.expression0)))
+        }
+        Factor::InsideExpression(x) => {
+            let x = x.inside_expression.as_ref();
+            Ast::Inside(
+                Box::new(expression_ast(&x.expression)),
+                list_ranges(&x.range_list),
+                false,
+                x.inside.inside_token.token,
+            )
+        }
+        Factor::OutsideExpression(x) => {
+            let x = x.outside_expression.as_ref();
+            Ast::Inside(
+                Box::new(expression_ast(&x.expression)),
+                list_ranges(&x.range_list),
+                true,
+                x.outside.outside_token.token,
+            )
+        }
+        _ => Ast::Factor(arg),
+    }
+}
+
+fn switch_ast(arg: &SwitchCondition, token: Token) -> Ast<'_> {
+    let mut ret = expression_ast(&arg.expression);
+    for x in &arg.switch_condition_list {
+        ret = Ast::Binary(
+            "lor",
+            Box::new(ret),
+            Box::new(expression_ast(&x.expression)),
+            token,
+        );
+    }
+    ret
+}
+
+fn case_ranges(arg: &CaseCondition) -> Vec<&Range> {
+    let mut ret = vec![arg.range_item.range.as_ref()];
+    for x in &arg.case_condition_list {
+        ret.push(x.range_item.range.as_ref());
+    }
+    ret
+}
+
+fn list_ranges(arg: &RangeList) -> Vec<&Range> {
+    let mut ret = vec![arg.range_item.range.as_ref()];
+    for x in &arg.range_list_list {
+        ret.push(x.range_item.range.as_ref());
+    }
+    ret
+}
+
+/// Returns the factor if the expression consists of it only
+fn single_factor(arg: &Expression) -> Option<&Factor> {
+    let x = arg.if_expression.as_ref();
+    if !x.if_expression_list.is_empty() {
+        return None;
+    }
+    let x = x.expression01.as_ref();
+    if !x.expression01_list.is_empty() {
+        return None;
+    }
+    let x = x.expression02.as_ref();
+    if !x.expression02_list.is_empty() {
+        return None;
+    }
+    let x = x.expression03.as_ref();
+    if !x.expression03_list.is_empty() {
+        return None;
+    }
+    let x = x.expression04.as_ref();
+    if !x.expression04_list.is_empty() {
+        return None;
+    }
+    let x = x.expression05.as_ref();
+    if !x.expression05_list.is_empty() {
+        return None;
+    }
+    let x = x.expression06.as_ref();
+    if !x.expression06_list.is_empty() {
+        return None;
+    }
+    let x = x.expression07.as_ref();
+    if !x.expression07_list.is_empty() {
+        return None;
+    }
+    let x = x.expression08.as_ref();
+    if !x.expression08_list.is_empty() {
+        return None;
+    }
+    let x = x.expression09.as_ref();
+    if !x.expression09_list.is_empty() {
+        return None;
+    }
+    let x = x.expression10.as_ref();
+    if !x.expression10_list.is_empty() {
+        return None;
+    }
+    let x = x.expression11.as_ref();
+    if !x.expression11_list.is_empty() {
+        return None;
+    }
+    let x = x.expression12.as_ref();
+    if x.expression12_opt.is_some() {
+        return None;
+    }
+    let x = x.expression13.as_ref();
+    if !x.expression13_list.is_empty() {
+        return None;
+    }
+    Some(x.factor.as_ref())
+}
+
+fn statement_block_items(arg: &StatementBlock) -> Vec<StatementBlockItem> {
+    let mut ret = Vec::new();
+    for x in &arg.statement_block_list {
+        let items: Vec<StatementBlockItem> = x.statement_block_group.as_ref().into();
+        ret.extend(items);
+    }
+    ret
+}
+
+/// Collects generate items with expanding `unsafe` blocks
+fn flatten_generate_item(arg: &GenerateItem, ret: &mut Vec<GenerateItem>) {
+    if let GenerateItem::UnsafeBlock(x) = arg {
+        for x in &x.unsafe_block.unsafe_block_list {
+            let items: Vec<GenerateItem> = x.generate_group.as_ref().into();
+            for x in &items {
+                flatten_generate_item(x, ret);
+            }
+        }
+    } else {
+        ret.push(arg.clone());
+    }
+}
+
+fn generate_named_block_items(arg: &GenerateNamedBlock) -> Vec<GenerateItem> {
+    let mut ret = Vec::new();
+    for x in &arg.generate_named_block_list {
+        let items: Vec<GenerateItem> = x.generate_group.as_ref().into();
+        for x in &items {
+            flatten_generate_item(x, &mut ret);
+        }
+    }
+    ret
+}
+
+fn generate_optional_named_block_items(arg: &GenerateOptionalNamedBlock) -> Vec<GenerateItem> {
+    let mut ret = Vec::new();
+    for x in &arg.generate_optional_named_block_list {
+        let items: Vec<GenerateItem> = x.generate_group.as_ref().into();
+        for x in &items {
+            flatten_generate_item(x, &mut ret);
+        }
+    }
+    ret
+}
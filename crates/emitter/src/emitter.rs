@@ -1,4 +1,7 @@
 use crate::expaneded_modport::{ExpandModportConnectionsTable, ExpandedModportPortTable};
+use crate::flattened_port::FlattenedPortTable;
+use crate::verilog2005::{self, Verilog2005Info};
+use std::collections::{BTreeMap, HashSet};
 use std::fs;
 use std::path::Path;
 use veryl_aligner::{Aligner, Location, Measure, align_kind};
@@ -8,7 +11,9 @@ use veryl_analyzer::attribute_table;
 use veryl_analyzer::connect_operation_table;
 use veryl_analyzer::evaluator::{EvaluatedTypeResetKind, Evaluator};
 use veryl_analyzer::namespace::Namespace;
+use veryl_analyzer::packed_layout;
 use veryl_analyzer::symbol::Direction as SymDirection;
+use veryl_analyzer::symbol::Type as SymType;
 use veryl_analyzer::symbol::TypeModifierKind as SymTypeModifierKind;
 use veryl_analyzer::symbol::{
     GenericMap, GenericTables, Port, Symbol, SymbolId, SymbolKind, TypeKind, VariableAffiliation,
@@ -16,7 +21,10 @@ use veryl_analyzer::symbol::{
 use veryl_analyzer::symbol_path::{GenericSymbolPath, SymbolPath};
 use veryl_analyzer::symbol_table::{self, ResolveError, ResolveResult};
 use veryl_analyzer::{msb_table, namespace_table};
-use veryl_metadata::{Build, BuiltinType, ClockType, Format, Metadata, ResetType, SourceMapTarget};
+use veryl_metadata::{
+    AssertionGuard, Build, BuiltinType, ClockType, Format, Language, Metadata, ResetType,
+    SourceMapTarget,
+};
 use veryl_parser::Stringifier;
 use veryl_parser::resource_table::{self, StrId};
 use veryl_parser::token_range::TokenExt;
@@ -42,6 +50,35 @@ enum Mode {
     Align,
 }
 
+/// Top-level module, interface or package which can be written as a separate file
+#[derive(Clone, Debug)]
+pub struct EmittedUnit {
+    /// Identifier of the unit in the emitted code
+    pub name: String,
+    pub symbol: SymbolId,
+    pub text: String,
+    /// Lines of the unit in the whole emitted code
+    pub lines: std::ops::Range<u32>,
+    /// Line of `text` where the unit starts after the preceding directives
+    pub line: u32,
+}
+
+/// Preprocessor directives and attributes surrounding a top-level unit
+#[derive(Clone, Debug)]
+struct UnitGuard {
+    lines: Vec<String>,
+    endif: bool,
+}
+
+#[derive(Clone, Debug)]
+struct UnitRange {
+    name: String,
+    symbol: SymbolId,
+    start: usize,
+    end: usize,
+    guards: Vec<UnitGuard>,
+}
+
 pub struct Emitter {
     mode: Mode,
     project_name: Option<StrId>,
@@ -86,6 +123,15 @@ pub struct Emitter {
     duplicated_index: usize,
     modport_connections_tables: Vec<ExpandModportConnectionsTable>,
     modport_ports_table: Option<ExpandedModportPortTable>,
+    verilog2005_info: Option<Verilog2005Info>,
+    net_type: Option<&'static str>,
+    function_name: Option<String>,
+    genvars: Vec<HashSet<StrId>>,
+    inline_package_constants: bool,
+    mangled_names: BTreeMap<String, String>,
+    units: Vec<UnitRange>,
+    unit_guards: Vec<UnitGuard>,
+    ifdef_chain: Vec<String>,
 }
 
 impl Default for Emitter {
@@ -134,6 +180,15 @@ impl Default for Emitter {
             duplicated_index: 0,
             modport_connections_tables: Vec::new(),
             modport_ports_table: None,
+            verilog2005_info: None,
+            net_type: None,
+            function_name: None,
+            genvars: Vec::new(),
+            inline_package_constants: false,
+            mangled_names: BTreeMap::new(),
+            units: Vec::new(),
+            unit_guards: Vec::new(),
+            ifdef_chain: Vec::new(),
         }
     }
 }
@@ -149,9 +204,16 @@ impl Emitter {
     pub fn new(metadata: &Metadata, src_path: &Path, dst_path: &Path, map_path: &Path) -> Self {
         let source_map = SourceMap::new(src_path, dst_path, map_path);
 
+        let mut build_opt = metadata.build.clone();
+        if build_opt.language == Language::Verilog2005 {
+            // `inside` operator and `unique`/`priority` are not available at Verilog-2005
+            build_opt.expand_inside_operation = true;
+            build_opt.emit_cond_type = false;
+        }
+
         Self {
             project_name: Some(metadata.project.name.as_str().into()),
-            build_opt: metadata.build.clone(),
+            build_opt,
             format_opt: metadata.format.clone(),
             aligner: Aligner::new(),
             source_map: Some(source_map),
@@ -161,6 +223,9 @@ impl Emitter {
 
     pub fn emit(&mut self, project_name: &str, input: &Veryl) {
         namespace_table::set_default(&[project_name.into()]);
+        if self.verilog2005() {
+            self.verilog2005_info = Some(Verilog2005Info::new(input));
+        }
         self.mode = Mode::Align;
         self.duplicated_index = 0;
         self.veryl(input);
@@ -175,10 +240,162 @@ impl Emitter {
         &self.string
     }
 
+    fn verilog2005(&self) -> bool {
+        self.build_opt.language == Language::Verilog2005
+    }
+
+    fn is_emitted_description(&self, arg: &DescriptionGroup) -> bool {
+        if !self.verilog2005() {
+            return true;
+        }
+
+        let items: Vec<DescriptionItem> = arg.into();
+        items.iter().any(|x| {
+            !matches!(
+                x,
+                DescriptionItem::DescriptionItemOptPublicDescriptionItem(x)
+                    if matches!(
+                        x.public_description_item.as_ref(),
+                        PublicDescriptionItem::InterfaceDeclaration(_)
+                            | PublicDescriptionItem::PackageDeclaration(_)
+                    )
+            )
+        })
+    }
+
+    /// Returns whether the module item emits anything.
+    /// Type declarations and imports are removed at Verilog-2005.
+    fn is_emitted_module_group(&self, arg: &ModuleGroup) -> bool {
+        if !self.verilog2005() {
+            return true;
+        }
+
+        match arg.module_group_group.as_ref() {
+            ModuleGroupGroup::ModuleItem(x) => !matches!(
+                x.module_item.generate_item.as_ref(),
+                GenerateItem::StructUnionDeclaration(_)
+                    | GenerateItem::TypeDefDeclaration(_)
+                    | GenerateItem::ImportDeclaration(_)
+            ),
+            _ => true,
+        }
+    }
+
+    /// Returns `reg` if the variable is assigned in `always`/`initial` blocks, otherwise `wire`.
+    fn verilog2005_net_type(&self, identifier: &Identifier, member: Option<StrId>) -> &'static str {
+        let Ok(symbol) = symbol_table::resolve(identifier) else {
+            return "wire";
+        };
+        if let SymbolKind::Variable(x) = &symbol.found.kind
+            && matches!(
+                x.affiliation,
+                VariableAffiliation::StatementBlock | VariableAffiliation::Function
+            )
+        {
+            return "reg";
+        }
+        let info = self.verilog2005_info.as_ref().unwrap();
+        if info.is_procedural_target(symbol.found.id, member) {
+            "reg"
+        } else {
+            "wire"
+        }
+    }
+
     pub fn source_map(&mut self) -> &mut SourceMap {
         self.source_map.as_mut().unwrap()
     }
 
+    /// Hashed names of emitted generic instances and their Veryl paths with generic arguments
+    pub fn mangled_names(&self) -> &BTreeMap<String, String> {
+        &self.mangled_names
+    }
+
+    /// Emitted modules, interfaces and packages with the directives surrounding them
+    pub fn units(&self) -> Vec<EmittedUnit> {
+        self.units
+            .iter()
+            .map(|x| {
+                let mut text = String::new();
+                for line in x.guards.iter().flat_map(|x| &x.lines) {
+                    text.push_str(line);
+                    text.push_str(NEWLINE);
+                }
+                let line = text.matches('\n').count() as u32 + 1;
+
+                let body = &self.string[x.start..x.end];
+                let start = self.string[..x.start].matches('\n').count()
+                    + body[..body.len() - body.trim_start().len()]
+                        .matches('\n')
+                        .count()
+                    + 1;
+                let body = body.trim();
+                let end = start + body.matches('\n').count() + 1;
+
+                text.push_str(body);
+                text.push_str(NEWLINE);
+                for _ in x.guards.iter().filter(|x| x.endif) {
+                    text.push_str("`endif");
+                    text.push_str(NEWLINE);
+                }
+                EmittedUnit {
+                    name: x.name.clone(),
+                    symbol: x.symbol,
+                    text,
+                    lines: start as u32..end as u32,
+                    line,
+                }
+            })
+            .collect()
+    }
+
+    fn push_unit(&mut self, name: String, symbol: SymbolId, start: usize) {
+        if self.mode == Mode::Emit {
+            self.units.push(UnitRange {
+                name,
+                symbol,
+                start,
+                end: self.string.len(),
+                guards: self.unit_guards.clone(),
+            });
+        }
+    }
+
+    /// Records the directive emitted by the attribute of description group as a guard of units.
+    /// `elsif` and `else` are preceded by the former directives in the chain
+    /// because the unit is written without the other branches.
+    fn push_unit_guard(&mut self, arg: &Attribute) -> bool {
+        if self.mode != Mode::Emit {
+            return false;
+        }
+
+        let text = self.string.trim_end();
+        let line = text[text.rfind('\n').map(|x| x + 1).unwrap_or(0)..].to_string();
+        let guard = match arg.identifier.identifier_token.to_string().as_str() {
+            "ifdef" | "ifndef" => {
+                self.ifdef_chain = vec![line];
+                UnitGuard {
+                    lines: self.ifdef_chain.clone(),
+                    endif: true,
+                }
+            }
+            "elsif" | "else" => {
+                self.ifdef_chain.push(line);
+                UnitGuard {
+                    lines: self.ifdef_chain.clone(),
+                    endif: true,
+                }
+            }
+            "sv" if arg.attribute_opt.is_some() => UnitGuard {
+                lines: vec![line],
+                endif: false,
+            },
+            _ => return false,
+        };
+        self.unit_guards.push(guard);
+        true
+    }
+
     fn str(&mut self, x: &str) {
         match self.mode {
             Mode::Emit => {
@@ -510,6 +727,12 @@ impl Emitter {
             // dummy space for implicit type
             self.space(1);
         }
+        if self.verilog2005() {
+            self.emit_verilog2005_scalar_type(arg);
+            self.in_scalar_type = false;
+            self.align_finish(align_kind::WIDTH);
+            return;
+        }
         if self.in_direction_with_var {
             self.str("var");
             self.space(1);
@@ -541,6 +764,152 @@ impl Emitter {
         self.align_finish(align_kind::WIDTH);
     }
 
+    /// Emits type as `reg`/`wire` with packed range because Verilog-2005 has no data type.
+    /// The net type is given through `net_type`, and parameters and function ports omit it.
+    fn emit_verilog2005_scalar_type(&mut self, arg: &ScalarType) {
+        let token = VerylToken::new(arg.first());
+        let namespace = namespace_table::get(token.token.id).unwrap_or_default();
+        let r#type: SymType = arg.into();
+        let net = self.net_type.unwrap_or("");
+
+        let mut signed = r#type.has_modifier(&SymTypeModifierKind::Signed);
+        let (keyword, fixed_width) = match &r#type.kind {
+            TypeKind::F32 | TypeKind::F64 => ("real", None),
+            TypeKind::String | TypeKind::Type | TypeKind::Any | TypeKind::AbstractInterface(_) => {
+                ("", None)
+            }
+            TypeKind::U8 | TypeKind::U16 | TypeKind::U32 | TypeKind::U64 => {
+                (net, packed_layout::packed_width(&r#type, &namespace))
+            }
+            TypeKind::I8 | TypeKind::I16 | TypeKind::I32 | TypeKind::I64 => {
+                signed = true;
+                (net, packed_layout::packed_width(&r#type, &namespace))
+            }
+            TypeKind::UserDefined(_) => (net, packed_layout::packed_width(&r#type, &namespace)),
+            _ => (net, Some(1)),
+        };
+        let is_real = keyword == "real";
+        let keyword = if r#type.has_modifier(&SymTypeModifierKind::Tri) && !net.is_empty() {
+            "tri"
+        } else {
+            keyword
+        };
+
+        let width = match arg.scalar_type_group.as_ref() {
+            ScalarTypeGroup::FactorType(x) => match x.factor_type.factor_type_group.as_ref() {
+                FactorTypeGroup::VariableTypeFactorTypeOpt(x) => {
+                    x.factor_type_opt.as_ref().map(|x| x.width.as_ref().clone())
+                }
+                _ => None,
+            },
+            _ => None,
+        };
+
+        let mut text = vec![keyword];
+        if signed && !is_real {
+            text.push("signed");
+        }
+        let text: Vec<_> = text.into_iter().filter(|x| !x.is_empty()).collect();
+        let text = text.join(" ");
+
+        // parameters and function ports require explicit range to have 1-bit width
+        let range = match fixed_width {
+            Some(x) if width.is_none() && (x > 1 || net.is_empty()) => {
+                Some(format!("[{}:0]", x.saturating_sub(1)))
+            }
+            _ => None,
+        };
+
+        self.token(&token.replace(&text));
+        self.align_finish(align_kind::TYPE);
+        self.align_start(align_kind::WIDTH);
+        if let Some(x) = width {
+            if !text.is_empty() {
+                self.space(1);
+            }
+            self.width(&x);
+        } else if let Some(x) = range {
+            if !text.is_empty() {
+                self.space(1);
+            }
+            self.str(&x);
+
+            // the range has no token, so a location which is not shared with tokens is used to align
+            let loc = Location {
+                duplicated: Some(usize::MAX),
+                ..token.token.into()
+            };
+            match self.mode {
+                Mode::Align => self.align_dummy_location(align_kind::WIDTH, Some(loc)),
+                Mode::Emit => {
+                    if let Some(width) = self.aligner.additions.get(&loc) {
+                        self.space(*width as usize);
+                    }
+                }
+            }
+        } else {
+            let loc = self.align_last_location(align_kind::TYPE);
+            self.align_dummy_location(align_kind::WIDTH, loc);
+        }
+    }
+
+    /// Emits constants of referred packages as `localparam` because Verilog-2005 has no package
+    fn emit_package_constants(&mut self, module: &VerylToken) {
+        // localparams are not aligned, so they are emitted at Emit mode only
+        if self.mode == Mode::Align {
+            return;
+        }
+
+        let info = self.verilog2005_info.as_ref().unwrap();
+        let constants = info.package_constants(module.token.id);
+        if constants.is_empty() {
+            return;
+        }
+
+        let context: SymbolContext = self.into();
+        for symbol in &constants {
+            let token = VerylToken::new(symbol.token);
+            let name = symbol_string(&token, symbol, &[], &GenericTables::default(), &context);
+            match &symbol.kind {
+                SymbolKind::Parameter(x) => {
+                    let range = verilog2005_parameter_range(&x.r#type, &symbol.namespace);
+                    let value = self.expression_string(&x.value);
+                    self.str(&format!("localparam {range}{name} = {value};"));
+                }
+                SymbolKind::EnumMember(x) => {
+                    let width = verilog2005::enum_width(symbol).unwrap_or(1);
+                    let value = x.value.value().unwrap_or(0);
+                    self.str(&format!(
+                        "localparam [{}:0] {name} = {width}'d{value};",
+                        width - 1
+                    ));
+                }
+                _ => (),
+            }
+            self.newline();
+        }
+    }
+
+    /// Returns the emitted text of the expression without comments
+    fn expression_string(&self, arg: &Expression) -> String {
+        let mut emitter = Emitter {
+            project_name: self.project_name,
+            build_opt: self.build_opt.clone(),
+            format_opt: self.format_opt.clone(),
+            ..Default::default()
+        };
+        emitter.build_opt.strip_comments = true;
+        emitter.inline_package_constants = self.inline_package_constants;
+        emitter.mode = Mode::Align;
+        emitter.expression(arg);
+        emitter.aligner.finish_group();
+        emitter.aligner.gather_additions();
+        emitter.mode = Mode::Emit;
+        emitter.duplicated_index = 0;
+        emitter.expression(arg);
+        emitter.string
+    }
+
     fn case_inside_statement(&mut self, arg: &CaseStatement) {
         let (prefix, force_last_item_default) = self.cond_type_prefix(&arg.case.case_token.token);
         self.token(&arg.case.case_token.append(&prefix, &None));
@@ -719,7 +1088,11 @@ impl Emitter {
         } else {
             self.str("(");
             self.expression(lhs);
-            self.str(") ==? (");
+            if self.verilog2005() {
+                self.str(") == (");
+            } else {
+                self.str(") ==? (");
+            }
             self.expression(&rhs.range.expression);
             self.str(")");
         }
@@ -801,14 +1174,7 @@ impl Emitter {
             SymbolKind::Variable(x) => (x.r#type.kind, x.prefix.clone(), x.suffix.clone()),
             _ => unreachable!(),
         };
-        let reset_type = match reset_kind {
-            TypeKind::ResetAsyncHigh => ResetType::AsyncHigh,
-            TypeKind::ResetAsyncLow => ResetType::AsyncLow,
-            TypeKind::ResetSyncHigh => ResetType::SyncHigh,
-            TypeKind::ResetSyncLow => ResetType::SyncLow,
-            TypeKind::Reset => self.build_opt.reset_type,
-            _ => unreachable!(),
-        };
+        let reset_type = self.reset_type(&reset_kind);
 
         let token = if prefix.is_some() || suffix.is_some() {
             VerylToken::new(symbol.token).append(&prefix, &suffix).token
@@ -840,6 +1206,76 @@ impl Emitter {
         self.reset_signal = Some(format!("{prefix_op}{token}"));
     }
 
+    fn reset_type(&self, kind: &TypeKind) -> ResetType {
+        match kind {
+            TypeKind::ResetAsyncHigh => ResetType::AsyncHigh,
+            TypeKind::ResetAsyncLow => ResetType::AsyncLow,
+            TypeKind::ResetSyncHigh => ResetType::SyncHigh,
+            TypeKind::ResetSyncLow => ResetType::SyncLow,
+            TypeKind::Reset => self.build_opt.reset_type,
+            _ => unreachable!(),
+        }
+    }
+
+    /// Returns active condition of reset which is used as `disable iff` of assertion
+    fn assertion_disable_condition(&self, arg: &AssertionDeclaration) -> Option<String> {
+        let (reset_kind, text) = if let Some(x) = arg.get_explicit_reset() {
+            let found = symbol_table::resolve(&x).ok()?.found;
+            let (kind, prefix, suffix) = match found.kind {
+                SymbolKind::Port(x) => (x.r#type.kind, x.prefix, x.suffix),
+                SymbolKind::Variable(x) => (x.r#type.kind, x.prefix, x.suffix),
+                SymbolKind::ModportVariableMember(x) => {
+                    let symbol = symbol_table::get(x.variable)?;
+                    if let SymbolKind::Variable(x) = symbol.kind {
+                        (x.r#type.kind, x.prefix, x.suffix)
+                    } else {
+                        return None;
+                    }
+                }
+                _ => return None,
+            };
+            let mut stringifier = Stringifier::new();
+            stringifier.hierarchical_identifier_with_prefix_suffix(&x, &prefix, &suffix);
+            (kind, stringifier.as_str().to_string())
+        } else if arg.has_explicit_clock() {
+            return None;
+        } else {
+            let symbol = symbol_table::get(self.default_reset?)?;
+            let (kind, prefix, suffix) = match symbol.kind {
+                SymbolKind::Port(x) => (x.r#type.kind, x.prefix, x.suffix),
+                SymbolKind::Variable(x) => (x.r#type.kind, x.prefix, x.suffix),
+                _ => return None,
+            };
+            let token = VerylToken::new(symbol.token).append(&prefix, &suffix);
+            (kind, token.token.to_string())
+        };
+
+        let prefix_op = match self.reset_type(&reset_kind) {
+            ResetType::AsyncHigh | ResetType::SyncHigh => "",
+            ResetType::AsyncLow | ResetType::SyncLow => "!",
+        };
+        Some(format!("{prefix_op}{text}"))
+    }
+
+    fn assertion_guard_start(&mut self, token: &Token) {
+        let directive = match &self.build_opt.assertion_guard {
+            AssertionGuard::Ifndef { name } => format!("`ifndef {name}"),
+            AssertionGuard::Ifdef { name } => format!("`ifdef {name}"),
+            AssertionGuard::None => return,
+        };
+        self.consume_adjust_line(token);
+        self.str(&directive);
+        self.newline();
+        self.clear_adjust_line();
+    }
+
+    fn assertion_guard_end(&mut self) {
+        if self.build_opt.assertion_guard != AssertionGuard::None {
+            self.newline();
+            self.str("`endif");
+        }
+    }
+
     fn always_ff_reset_exist_in_sensitivity_list(&mut self, arg: &AlwaysFfReset) -> bool {
         if let Ok(found) = symbol_table::resolve(arg.hierarchical_identifier.as_ref()) {
             let reset_kind = match found.found.kind {
@@ -935,23 +1371,171 @@ impl Emitter {
         self.space(1);
         self.colon(&arg.colon);
         self.identifier(&arg.identifier);
+        self.genvars.push(HashSet::new());
         for (i, x) in arg.generate_named_block_list.iter().enumerate() {
             self.newline_list(i);
             self.generate_group(&x.generate_group);
         }
+        self.genvars.pop();
         self.newline_list_post(arg.generate_named_block_list.is_empty());
         self.token(&arg.r_brace.r_brace_token.replace("end"));
     }
 
-    fn emit_statement_block(&mut self, arg: &StatementBlock, begin_kw: &str, end_kw: &str) {
-        self.token_will_push(&arg.l_brace.l_brace_token.replace(begin_kw));
+    /// Emits generate `for` with `genvar` declared before it
+    fn emit_verilog2005_generate_for_declaration(&mut self, arg: &GenerateForDeclaration) {
+        let name = arg.identifier.identifier_token.token.text;
+        let declared = self.genvars.iter().any(|x| x.contains(&name));
+        if declared {
+            self.r#for(&arg.r#for);
+        } else {
+            if let Some(x) = self.genvars.last_mut() {
+                x.insert(name);
+            }
+            self.token(&arg.r#for.for_token.replace("genvar"));
+            self.space(1);
+            self.identifier(&arg.identifier);
+            self.str(";");
+            self.newline();
+            self.str("for");
+        }
+        self.space(1);
+        self.str("(");
+        self.identifier(&arg.identifier);
+        self.space(1);
+        self.str("=");
+        self.space(1);
+        self.expression(&arg.range.expression);
+        self.str(";");
+        self.space(1);
+        self.identifier(&arg.identifier);
+        self.space(1);
+        if let Some(ref x) = arg.range.range_opt {
+            match &*x.range_operator {
+                RangeOperator::DotDot(_) => self.str("<"),
+                RangeOperator::DotDotEqu(_) => self.str("<="),
+            }
+            self.space(1);
+            self.expression(&x.expression);
+        } else {
+            self.str("<=");
+            self.space(1);
+            self.expression(&arg.range.expression);
+        }
+        self.str(";");
+        self.space(1);
+        self.identifier(&arg.identifier);
+        self.space(1);
+        self.str("=");
+        self.space(1);
+        self.identifier(&arg.identifier);
+        self.space(1);
+        if let Some(ref x) = arg.generate_for_declaration_opt {
+            let operator = x.assignment_operator.assignment_operator_token.to_string();
+            let operator = operator.strip_suffix('=').unwrap_or(&operator);
+            self.token(
+                &x.assignment_operator
+                    .assignment_operator_token
+                    .replace(operator),
+            );
+            self.space(1);
+            self.str("(");
+            self.expression(&x.expression);
+            self.str(")");
+        } else {
+            self.str("+ 1");
+        }
+        self.str(")");
+        self.space(1);
+        self.generate_named_block(&arg.generate_named_block);
+    }
+
+    /// Emits `for` statement with the loop variable declared in the enclosing named block
+    fn emit_verilog2005_for_statement(&mut self, arg: &ForStatement) {
+        let token = &arg.r#for.for_token.token;
+        let name = format!("begin : __for_{}_{}", token.line, token.column);
+        self.token_will_push(&arg.r#for.for_token.replace(&name));
+        self.newline_push();
+        self.net_type = Some("reg");
+        self.emit_scalar_type(&arg.scalar_type, false);
+        self.net_type = None;
+        self.space(1);
+        self.identifier(&arg.identifier);
+        self.str(";");
+        self.newline();
+        self.str("for");
+        self.space(1);
+        self.str("(");
+        self.identifier(&arg.identifier);
+        self.space(1);
+        self.str("=");
+        self.space(1);
+        self.expression(&arg.range.expression);
+        self.str(";");
+        self.space(1);
+        self.identifier(&arg.identifier);
+        self.space(1);
+        if let Some(ref x) = arg.range.range_opt {
+            match &*x.range_operator {
+                RangeOperator::DotDot(_) => self.str("<"),
+                RangeOperator::DotDotEqu(_) => self.str("<="),
+            }
+            self.space(1);
+            self.expression(&x.expression);
+        } else {
+            self.str("<=");
+            self.space(1);
+            self.expression(&arg.range.expression);
+        }
+        self.str(";");
+        self.space(1);
+        self.identifier(&arg.identifier);
+        self.space(1);
+        self.str("=");
+        self.space(1);
+        self.identifier(&arg.identifier);
+        self.space(1);
+        if let Some(ref x) = arg.for_statement_opt {
+            let operator = x.assignment_operator.assignment_operator_token.to_string();
+            let operator = operator.strip_suffix('=').unwrap_or(&operator);
+            self.token(
+                &x.assignment_operator
+                    .assignment_operator_token
+                    .replace(operator),
+            );
+            self.space(1);
+            self.str("(");
+            self.expression(&x.expression);
+            self.str(")");
+        } else {
+            self.str("+ 1");
+        }
+        self.str(")");
+        self.space(1);
+        self.statement_block(&arg.statement_block);
+        self.newline_pop();
+        self.str("end");
+    }
 
+    fn emit_statement_block(&mut self, arg: &StatementBlock, begin_kw: &str, end_kw: &str) {
         let statement_block_list: Vec<_> = arg
             .statement_block_list
             .iter()
             .map(|x| Into::<Vec<StatementBlockItem>>::into(x.statement_block_group.as_ref()))
             .collect();
 
+        // Verilog-2005 allows variable declarations in named blocks only
+        let has_declaration = statement_block_list
+            .iter()
+            .flatten()
+            .any(|x| is_var_declaration(x) || is_let_statement(x));
+        if self.verilog2005() && has_declaration && begin_kw == "begin" {
+            let token = &arg.l_brace.l_brace_token.token;
+            let name = format!("begin : __block_{}_{}", token.line, token.column);
+            self.token_will_push(&arg.l_brace.l_brace_token.replace(&name));
+        } else {
+            self.token_will_push(&arg.l_brace.l_brace_token.replace(begin_kw));
+        }
+
         let mut base = 0;
         let mut n_newlines = 0;
         for x in &statement_block_list {
@@ -1016,7 +1600,9 @@ impl Emitter {
             }
             StatementBlockItem::LetStatement(x) => {
                 let x = &x.let_statement;
+                self.net_type = Some("reg");
                 self.scalar_type(&x.array_type.scalar_type);
+                self.net_type = None;
                 self.space(1);
                 self.identifier(&x.identifier);
                 if let Some(ref x) = x.array_type.array_type_opt {
@@ -1268,6 +1854,236 @@ impl Emitter {
         self.force_duplicated = false;
     }
 
+    fn emit_flattened_port_concatenation(&mut self, ports: &[VerylToken]) {
+        if ports.len() == 1 {
+            self.duplicated_token(&ports[0]);
+        } else {
+            self.str("{");
+            for (i, port) in ports.iter().enumerate() {
+                if i > 0 {
+                    self.str(",");
+                    self.space(1);
+                }
+                self.duplicated_token(port);
+            }
+            self.str("}");
+        }
+    }
+
+    /// Emits the wrapper module which exposes flattened ports of the module annotated by `#[flatten]`
+    fn emit_flatten_wrapper(
+        &mut self,
+        arg: &ModuleDeclaration,
+        symbol: &ResolveResult,
+        ports: &[Port],
+        name: Option<StrId>,
+        separator: Option<StrId>,
+    ) {
+        let separator = separator
+            .map(|x| x.to_string())
+            .unwrap_or_else(|| "_".to_string());
+        let context: SymbolContext = self.into();
+        let table = FlattenedPortTable::create(
+            ports,
+            &self.get_generic_map(),
+            &arg.identifier.identifier_token,
+            &symbol.found.namespace,
+            &separator,
+            &context,
+        );
+
+        let token = &arg.identifier.identifier_token;
+        let prefix = namespace_string(&symbol.found.namespace, &symbol.generic_tables, &context);
+        let module_name = format!("{prefix}{token}");
+        let wrapper_name = if let Some(x) = name {
+            format!("{prefix}{x}")
+        } else {
+            format!("{module_name}_flat")
+        };
+        let parameters: Vec<_> = if let SymbolKind::Module(ref x) = symbol.found.kind {
+            x.parameters
+                .iter()
+                .filter(|x| !x.property().kind.is_const())
+                .map(|x| token.replace(&x.name.to_string()))
+                .collect()
+        } else {
+            unreachable!()
+        };
+
+        let src_line = self.src_line;
+        self.force_duplicated = true;
+        self.aligner.disable_auto_finish();
+        self.align_reset();
+        self.clear_adjust_line();
+
+        self.newline();
+        self.newline();
+        self.str("module");
+        self.space(1);
+        self.duplicated_token(&token.replace(&wrapper_name));
+        self.inline_package_constants = self.verilog2005();
+        if let Some(ref x) = arg.module_declaration_opt1 {
+            self.space(1);
+            self.with_parameter(&x.with_parameter);
+            self.align_reset();
+        }
+        self.inline_package_constants = false;
+        self.space(1);
+        self.str("(");
+        self.newline_push();
+        for (i, port) in table.ports.iter().enumerate() {
+            if i > 0 {
+                self.str(",");
+                self.newline();
+            }
+            self.clear_adjust_line();
+            if !port.generic_maps.is_empty() {
+                self.generic_map.push(port.generic_maps.clone());
+            }
+            let array_type = port.r#type.array_type.as_ref().unwrap();
+
+            self.align_start(align_kind::DIRECTION);
+            self.duplicated_token(&port.direction_token);
+            self.align_finish(align_kind::DIRECTION);
+            self.space(1);
+
+            self.in_direction_with_var = !matches!(port.direction, SymDirection::Inout);
+            if self.verilog2005() {
+                self.net_type = Some("wire");
+            }
+            self.scalar_type(&array_type.scalar_type);
+            self.net_type = None;
+            self.in_direction_with_var = false;
+            self.space(1);
+
+            self.align_start(align_kind::IDENTIFIER);
+            self.duplicated_token(&port.identifier);
+            self.align_finish(align_kind::IDENTIFIER);
+            self.align_start(align_kind::ARRAY);
+            if let Some(ref x) = array_type.array_type_opt {
+                self.space(1);
+                self.array(&x.array);
+            } else {
+                let loc = self.align_last_location(align_kind::IDENTIFIER);
+                self.align_dummy_location(align_kind::ARRAY, loc);
+            }
+            self.align_finish(align_kind::ARRAY);
+
+            if !port.generic_maps.is_empty() {
+                self.generic_map.pop();
+            }
+        }
+        self.newline_pop();
+        self.str(");");
+        self.align_reset();
+        self.newline_push();
+
+        // emit interface instances connected to the flattened ports
+        for interface in &table.interfaces {
+            self.clear_adjust_line();
+            self.single_line_start();
+            self.duplicated_token(&interface.interface_name);
+            self.space(1);
+            self.duplicated_token(&interface.identifier);
+            if !interface.array_size.is_empty() {
+                self.space(1);
+                for size in &interface.array_size {
+                    self.str(&format!("[0:{size}-1]"));
+                }
+            }
+            self.space(1);
+            self.str("();");
+            self.single_line_finish();
+            self.newline();
+
+            for assign in &interface.assigns {
+                self.clear_adjust_line();
+                self.str("assign");
+                self.space(1);
+                self.align_start(align_kind::IDENTIFIER);
+                if matches!(assign.direction, SymDirection::Input) {
+                    self.duplicated_token(&assign.interface_target);
+                } else {
+                    self.emit_flattened_port_concatenation(&assign.ports);
+                }
+                self.align_finish(align_kind::IDENTIFIER);
+                self.space(1);
+                self.str("=");
+                self.space(1);
+                self.align_start(align_kind::EXPRESSION);
+                if matches!(assign.direction, SymDirection::Input) {
+                    self.emit_flattened_port_concatenation(&assign.ports);
+                } else {
+                    self.duplicated_token(&assign.interface_target);
+                }
+                self.align_finish(align_kind::EXPRESSION);
+                self.str(";");
+                self.newline();
+            }
+            self.align_reset();
+        }
+
+        // emit instance of the wrapped module
+        self.clear_adjust_line();
+        self.duplicated_token(&token.replace(&module_name));
+        self.space(1);
+        if !parameters.is_empty() {
+            self.str("#(");
+            self.newline_push();
+            for (i, parameter) in parameters.iter().enumerate() {
+                if i > 0 {
+                    self.str(",");
+                    self.newline();
+                }
+                self.str(".");
+                self.clear_adjust_line();
+                self.align_start(align_kind::IDENTIFIER);
+                self.duplicated_token(parameter);
+                self.align_finish(align_kind::IDENTIFIER);
+                self.space(1);
+                self.str("(");
+                self.align_start(align_kind::EXPRESSION);
+                self.duplicated_token(parameter);
+                self.align_finish(align_kind::EXPRESSION);
+                self.str(")");
+            }
+            self.newline_pop();
+            self.str(")");
+            self.space(1);
+            self.align_reset();
+        }
+        self.str("u");
+        self.space(1);
+        self.str("(");
+        self.newline_push();
+        for (i, connection) in table.connections.iter().enumerate() {
+            if i > 0 {
+                self.str(",");
+                self.newline();
+            }
+            self.str(".");
+            self.clear_adjust_line();
+            self.align_start(align_kind::IDENTIFIER);
+            self.duplicated_token(&connection.port_target);
+            self.align_finish(align_kind::IDENTIFIER);
+            self.space(1);
+            self.str("(");
+            self.align_start(align_kind::EXPRESSION);
+            self.emit_flattened_port_concatenation(&connection.ports);
+            self.align_finish(align_kind::EXPRESSION);
+            self.str(")");
+        }
+        self.newline_pop();
+        self.str(");");
+        self.align_reset();
+        self.newline_pop();
+        self.str("endmodule");
+
+        self.aligner.enable_auto_finish();
+        self.src_line = src_line;
+        self.force_duplicated = false;
+    }
+
     fn emit_inst_param_port_item_assigned_by_name(&mut self, identifier: &Identifier) {
         self.align_start(align_kind::EXPRESSION);
         match self.resolve_generic_path(&identifier.into(), None) {
@@ -1844,56 +2660,294 @@ impl Emitter {
             .collect()
     }
 
-    fn push_generic_map(&mut self, map: GenericMap) {
-        if let Some(maps) = self.generic_map.last_mut() {
-            maps.push(map);
-        } else {
-            self.generic_map.push(vec![map]);
+    fn push_generic_map(&mut self, map: GenericMap) {
+        if let Some(maps) = self.generic_map.last_mut() {
+            maps.push(map);
+        } else {
+            self.generic_map.push(vec![map]);
+        }
+    }
+
+    fn get_generic_map(&self) -> Vec<GenericMap> {
+        if let Some(map) = self.generic_map.last() {
+            map.clone()
+        } else {
+            vec![]
+        }
+    }
+
+    fn pop_generic_map(&mut self) {
+        if let Some(maps) = self.generic_map.last_mut() {
+            maps.pop();
+        }
+    }
+
+    fn emit_generic_instance_name_comment(&mut self, generic_map: &GenericMap) {
+        if generic_map.generic() && self.build_opt.hashed_mangled_name {
+            let name = generic_map.name(false, false);
+            self.str(&format!("// {name}"));
+            self.newline();
+        }
+    }
+
+    fn emit_generic_instance_name(
+        &mut self,
+        token: &VerylToken,
+        generic_map: &GenericMap,
+        omit_project_prefix: bool,
+    ) -> String {
+        let name = generic_map.name(true, self.build_opt.hashed_mangled_name);
+        let name = if self.build_opt.omit_project_prefix || omit_project_prefix {
+            let project_name = format!("{}_", self.project_name.unwrap());
+            if let Some(x) = name.strip_prefix(&project_name) {
+                x
+            } else {
+                &name
+            }
+        } else {
+            &name
+        };
+        let name = name.replace("$std_", "__std_");
+
+        if generic_map.generic()
+            && self.build_opt.hashed_mangled_name
+            && let Some(path) = generic_instance_path(generic_map)
+        {
+            self.mangled_names.insert(name.clone(), path);
+        }
+
+        self.token(&token.replace(&name));
+        name
+    }
+
+    /// Emits hierarchical identifier with members as flattened interface members and bit slices
+    fn emit_verilog2005_hierarchical_identifier(&mut self, arg: &HierarchicalIdentifier) {
+        let member = &arg.hierarchical_identifier_list0[0];
+        let identifier = &arg.identifier.identifier_token;
+        let member_token = &member.identifier.identifier_token.token;
+
+        let mut expanded = None;
+        if let Some(table) = self.modport_ports_table.as_ref() {
+            expanded = table
+                .get_modport_member(&identifier.token, member_token, &[])
+                .map(|x| x.identifier.to_string());
+        }
+        if expanded.is_none() {
+            expanded = verilog2005_interface_member(&identifier.token, member_token);
+        }
+
+        let skip = if let Some(text) = expanded.as_ref() {
+            self.veryl_token(&identifier.replace(text));
+            1
+        } else {
+            self.identifier(&arg.identifier);
+            for x in &arg.hierarchical_identifier_list {
+                self.select(&x.select);
+            }
+            0
+        };
+
+        let members: Vec<_> = arg
+            .hierarchical_identifier_list0
+            .iter()
+            .map(|x| x.identifier.identifier_token.token.text)
+            .collect();
+        let struct_member = symbol_table::resolve(arg)
+            .ok()
+            .and_then(|x| verilog2005_struct_member(&x.full_path, &members, skip));
+
+        let len = arg.hierarchical_identifier_list0.len();
+        for (i, x) in arg.hierarchical_identifier_list0.iter().enumerate() {
+            if let Some((start, lsb, width)) = struct_member
+                && i >= start
+            {
+                self.token(&x.dot.dot_token.replace(""));
+                self.identifier(&Identifier {
+                    identifier_token: x.identifier.identifier_token.replace(""),
+                });
+                if i + 1 == len {
+                    let select = x.hierarchical_identifier_list0_list.first();
+                    self.emit_verilog2005_struct_select(lsb, width, select.map(|x| &*x.select));
+                }
+                continue;
+            }
+
+            if i >= skip {
+                self.dot(&x.dot);
+                self.identifier(&x.identifier);
+            }
+            for x in &x.hierarchical_identifier_list0_list {
+                self.select(&x.select);
+            }
+        }
+    }
+
+    /// Emits bit slice of struct member whose LSB is `lsb` at Verilog-2005
+    fn emit_verilog2005_struct_select(
+        &mut self,
+        lsb: usize,
+        width: usize,
+        select: Option<&Select>,
+    ) {
+        let Some(select) = select else {
+            if width == 1 {
+                self.str(&format!("[{lsb}]"));
+            } else {
+                self.str(&format!("[{}:{lsb}]", lsb + width - 1));
+            }
+            return;
+        };
+
+        if lsb == 0 {
+            self.select(select);
+            return;
+        }
+
+        self.l_bracket(&select.l_bracket);
+        self.str(&format!("{lsb} + ("));
+        self.expression(&select.expression);
+        self.str(")");
+        if let Some(ref x) = select.select_opt {
+            match &*x.select_operator {
+                SelectOperator::Step(_) => {
+                    self.str("*(");
+                    self.expression(&x.expression);
+                    self.str(")+:(");
+                    self.expression(&x.expression);
+                    self.str(")");
+                }
+                SelectOperator::Colon(_) => {
+                    self.select_operator(&x.select_operator);
+                    self.str(&format!("{lsb} + ("));
+                    self.expression(&x.expression);
+                    self.str(")");
+                }
+                _ => {
+                    self.select_operator(&x.select_operator);
+                    self.expression(&x.expression);
+                }
+            }
         }
+        self.r_bracket(&select.r_bracket);
     }
 
-    fn get_generic_map(&self) -> Vec<GenericMap> {
-        if let Some(map) = self.generic_map.last() {
-            map.clone()
+    /// Emits enum members as `localparam` because Verilog-2005 has no enum type
+    fn emit_verilog2005_enum_declaration(&mut self, arg: &EnumDeclaration) {
+        let width = if let Ok(symbol) = symbol_table::resolve(arg.identifier.as_ref()) {
+            match &symbol.found.kind {
+                SymbolKind::Enum(x) => match &x.r#type {
+                    Some(r#type) => packed_layout::packed_width(r#type, &symbol.found.namespace),
+                    None => Some(x.width),
+                },
+                _ => None,
+            }
         } else {
-            vec![]
+            None
         }
-    }
+        .unwrap_or(1);
 
-    fn pop_generic_map(&mut self) {
-        if let Some(maps) = self.generic_map.last_mut() {
-            maps.pop();
-        }
-    }
+        let mut items = Vec::new();
+        collect_enum_items(&arg.enum_list, &mut items);
 
-    fn emit_generic_instance_name_comment(&mut self, generic_map: &GenericMap) {
-        if generic_map.generic() && self.build_opt.hashed_mangled_name {
-            let name = generic_map.name(false, false);
-            self.str(&format!("// {name}"));
-            self.newline();
+        for (i, item) in items.iter().enumerate() {
+            let member_symbol = symbol_table::resolve(item.identifier.as_ref()).unwrap();
+            let SymbolKind::EnumMember(member) = member_symbol.found.kind else {
+                unreachable!();
+            };
+
+            if i == 0 {
+                self.token(&arg.r#enum.enum_token.replace("localparam"));
+            } else {
+                self.newline();
+                self.str("localparam");
+            }
+            self.space(1);
+            self.str(&format!("[{}:0]", width - 1));
+            self.space(1);
+            self.identifier(&identifier_with_prefix_suffix(
+                &item.identifier,
+                &Some(format!("{}_", member.prefix)),
+                &None,
+            ));
+            self.space(1);
+            self.str("=");
+            self.space(1);
+            match (member.value.value(), &item.enum_item_opt) {
+                (Some(value), _) => self.str(&format!("{width}'d{value}")),
+                (None, Some(x)) => self.expression(&x.expression),
+                (None, None) => self.str("0"),
+            }
+            self.str(";");
         }
+        self.token(&arg.r_brace.r_brace_token.replace(""));
     }
 
-    fn emit_generic_instance_name(
-        &mut self,
-        token: &VerylToken,
-        generic_map: &GenericMap,
-        omit_project_prefix: bool,
-    ) {
-        let name = generic_map.name(true, self.build_opt.hashed_mangled_name);
-        let name = if self.build_opt.omit_project_prefix || omit_project_prefix {
-            let project_name = format!("{}_", self.project_name.unwrap());
-            if let Some(x) = name.strip_prefix(&project_name) {
-                x
-            } else {
-                &name
+    /// Emits variables of interface instance as flattened variables because Verilog-2005 has no interface
+    fn emit_verilog2005_interface_instance(&mut self, arg: &InstDeclaration, members: &[SymbolId]) {
+        self.token(&arg.inst.inst_token.replace(""));
+
+        // declarations are not aligned, so they are emitted at Emit mode only
+        if self.mode == Mode::Emit {
+            let inst = arg.identifier.identifier_token.to_string();
+            let inst_id = symbol_table::resolve(arg.identifier.as_ref())
+                .map(|x| x.found.id)
+                .ok();
+            let info = self.verilog2005_info.as_ref().unwrap();
+
+            let mut declarations = Vec::new();
+            for symbol in members.iter().filter_map(|x| symbol_table::get(*x)) {
+                let SymbolKind::Variable(x) = &symbol.kind else {
+                    continue;
+                };
+
+                let is_reg = inst_id
+                    .map(|id| info.is_procedural_target(id, Some(symbol.token.text)))
+                    .unwrap_or(false);
+                let is_bit = packed_layout::packed_width(&x.r#type, &symbol.namespace) == Some(1);
+                let (net, range) = match x.r#type.kind {
+                    TypeKind::F32 | TypeKind::F64 => ("real", String::new()),
+                    _ if is_bit => (if is_reg { "reg" } else { "wire" }, String::new()),
+                    _ if is_reg => (
+                        "reg",
+                        verilog2005_parameter_range(&x.r#type, &symbol.namespace),
+                    ),
+                    _ => (
+                        "wire",
+                        verilog2005_parameter_range(&x.r#type, &symbol.namespace),
+                    ),
+                };
+                let array: String = x
+                    .r#type
+                    .array
+                    .iter()
+                    .map(|x| match packed_layout::static_value(x) {
+                        Some(x) => format!(" [0:{}]", x.saturating_sub(1)),
+                        None => format!(" [0:{}-1]", self.expression_string(x)),
+                    })
+                    .collect();
+                declarations.push(format!("{net} {range}__{inst}_{}{array};", symbol.token));
             }
-        } else {
-            &name
-        };
-        let name = name.replace("$std_", "__std_");
 
-        self.token(&token.replace(&name));
+            for (i, x) in declarations.iter().enumerate() {
+                if i != 0 {
+                    self.newline();
+                }
+                self.str(x);
+            }
+        }
+
+        self.token(&arg.semicolon.semicolon_token.replace(""));
+    }
+}
+
+fn collect_enum_items<'a>(arg: &'a EnumList, items: &mut Vec<&'a EnumItem>) {
+    let mut groups = vec![arg.enum_group.as_ref()];
+    groups.extend(arg.enum_list_list.iter().map(|x| x.enum_group.as_ref()));
+    for group in groups {
+        match group.enum_group_group.as_ref() {
+            EnumGroupGroup::LBraceEnumListRBrace(x) => collect_enum_items(&x.enum_list, items),
+            EnumGroupGroup::EnumItem(x) => items.push(&x.enum_item),
+        }
     }
 }
 
@@ -1969,7 +3023,16 @@ impl VerylWalker for Emitter {
         let text = &arg.all_bit_token.to_string();
         let (width, tail) = text.split_once('\'').unwrap();
 
-        if width.is_empty() {
+        if width.is_empty() && self.verilog2005() {
+            // unsized `'0` and `'1` are not available at Verilog-2005
+            let text = match tail {
+                "0" => "0",
+                "1" => "~0",
+                "x" | "X" => "'bx",
+                _ => "'bz",
+            };
+            self.veryl_token(&arg.all_bit_token.replace(text));
+        } else if width.is_empty() {
             self.veryl_token(&arg.all_bit_token);
         } else {
             let width: usize = width.parse().unwrap();
@@ -2198,6 +3261,11 @@ impl VerylWalker for Emitter {
             vec![]
         };
 
+        if self.verilog2005() && *list_len != 0 {
+            self.emit_verilog2005_hierarchical_identifier(arg);
+            return;
+        }
+
         if *list_len == 0 {
             self.identifier(&identifier_with_prefix_suffix(
                 &arg.identifier,
@@ -2253,6 +3321,24 @@ impl VerylWalker for Emitter {
             self.veryl_token(&arg.identifier().replace(""));
         } else {
             match self.resolve_scoped_idnetifier(arg) {
+                (Ok(symbol), _)
+                    if self.inline_package_constants
+                        && verilog2005::is_package_constant(&symbol.found) =>
+                {
+                    let text = match &symbol.found.kind {
+                        SymbolKind::Parameter(x) => {
+                            format!("({})", self.expression_string(&x.value))
+                        }
+                        SymbolKind::EnumMember(x) => {
+                            let width = verilog2005::enum_width(&symbol.found).unwrap_or(1);
+                            format!("{width}'d{}", x.value.value().unwrap_or(0))
+                        }
+                        _ => unreachable!(),
+                    };
+                    self.identifier(&Identifier {
+                        identifier_token: arg.identifier().replace(&text),
+                    });
+                }
                 (Ok(symbol), _) => {
                     let context: SymbolContext = self.into();
                     let text = symbol_string(
@@ -2290,9 +3376,20 @@ impl VerylWalker for Emitter {
                         &member_identifier.identifier.identifier_token.token,
                         &[],
                     )
-                    .map(|x| (port_identifier, x));
+                    .map(|x| (port_identifier, x.identifier.to_string()));
             }
         }
+        if expanded_modport.is_none()
+            && self.verilog2005()
+            && let Some(member_identifier) = arg.expression_identifier_list0.first()
+        {
+            let port_identifier = arg.scoped_identifier.identifier();
+            expanded_modport = verilog2005_interface_member(
+                &port_identifier.token,
+                &member_identifier.identifier.identifier_token.token,
+            )
+            .map(|x| (port_identifier, x));
+        }
 
         let array_size = if self.build_opt.flatten_array_interface
             && !arg.expression_identifier_list.is_empty()
@@ -2304,10 +3401,9 @@ impl VerylWalker for Emitter {
         };
 
         self.resolved_identifier.push("".to_string());
-        if let Some((token, modport_member)) = expanded_modport.as_ref() {
-            let text = modport_member.identifier.to_string();
-            self.veryl_token(&token.replace(&text));
-            self.push_resolved_identifier(&text);
+        if let Some((token, text)) = expanded_modport.as_ref() {
+            self.veryl_token(&token.replace(text));
+            self.push_resolved_identifier(text);
         } else if array_size.len() > 1 {
             let select: Vec<_> = arg
                 .expression_identifier_list
@@ -2327,7 +3423,36 @@ impl VerylWalker for Emitter {
             }
         }
 
+        // struct members are lowered into bit slices at Verilog-2005
+        let struct_member = if self.verilog2005() {
+            let skip = if expanded_modport.is_some() { 1 } else { 0 };
+            let members: Vec<_> = arg
+                .expression_identifier_list0
+                .iter()
+                .map(|x| x.identifier.identifier_token.token.text)
+                .collect();
+            symbol_table::resolve(arg)
+                .ok()
+                .and_then(|x| verilog2005_struct_member(&x.full_path, &members, skip))
+        } else {
+            None
+        };
+
         for (i, x) in arg.expression_identifier_list0.iter().enumerate() {
+            if let Some((start, lsb, width)) = struct_member
+                && i >= start
+            {
+                self.token(&x.dot.dot_token.replace(""));
+                self.identifier(&Identifier {
+                    identifier_token: x.identifier.identifier_token.replace(""),
+                });
+                if i + 1 == arg.expression_identifier_list0.len() {
+                    let select = x.expression_identifier_list0_list.first();
+                    self.emit_verilog2005_struct_select(lsb, width, select.map(|x| &*x.select));
+                }
+                continue;
+            }
+
             if i > 0 || expanded_modport.is_none() {
                 self.dot(&x.dot);
                 self.push_resolved_identifier(".");
@@ -3198,9 +4323,12 @@ impl VerylWalker for Emitter {
         };
 
         self.space(1);
-        if is_nba {
+        // Verilog-2005 has no assignment operator like `+=`, so it is expanded like NBA
+        if is_nba || self.verilog2005() {
             self.align_start(align_kind::ASSIGNMENT);
-            self.str("<");
+            if is_nba {
+                self.str("<");
+            }
             match &*arg.assignment_group {
                 AssignmentGroup::Equ(x) => {
                     self.equ(&x.equ);
@@ -3333,7 +4461,12 @@ impl VerylWalker for Emitter {
 
     /// Semantic action for non-terminal 'ReturnStatement'
     fn return_statement(&mut self, arg: &ReturnStatement) {
-        self.r#return(&arg.r#return);
+        if let Some(name) = self.function_name.clone() {
+            // return value is given by assignment to function name
+            self.token(&arg.r#return.return_token.replace(&format!("{name} =")));
+        } else {
+            self.r#return(&arg.r#return);
+        }
         self.space(1);
         self.expression(&arg.expression);
         self.semicolon(&arg.semicolon);
@@ -3347,6 +4480,11 @@ impl VerylWalker for Emitter {
 
     /// Semantic action for non-terminal 'ForStatement'
     fn for_statement(&mut self, arg: &ForStatement) {
+        if self.verilog2005() {
+            self.emit_verilog2005_for_statement(arg);
+            return;
+        }
+
         self.r#for(&arg.r#for);
         self.space(1);
         self.str("(");
@@ -3443,6 +4581,18 @@ impl VerylWalker for Emitter {
         }
     }
 
+    /// Semantic action for non-terminal 'AssertionStatement'
+    fn assertion_statement(&mut self, arg: &AssertionStatement) {
+        self.assertion_guard_start(&arg.assertion_keyword.first());
+        self.assertion_keyword(&arg.assertion_keyword);
+        self.space(1);
+        self.str("(");
+        self.expression(&arg.expression);
+        self.str(")");
+        self.semicolon(&arg.semicolon);
+        self.assertion_guard_end();
+    }
+
     /// Semantic action for non-terminal 'Attribute'
     fn attribute(&mut self, arg: &Attribute) {
         self.in_attribute = true;
@@ -3536,7 +4686,9 @@ impl VerylWalker for Emitter {
             .iter()
             .any(|x| matches!(x.type_modifier.as_ref(), TypeModifier::Tri(_)));
 
+        self.net_type = Some("wire");
         self.scalar_type(&arg.array_type.scalar_type);
+        self.net_type = None;
         self.space(1);
         self.align_start(align_kind::IDENTIFIER);
         self.identifier(&arg.identifier);
@@ -3552,7 +4704,7 @@ impl VerylWalker for Emitter {
         self.align_finish(align_kind::ARRAY);
         self.str(";");
         self.space(1);
-        if is_tri {
+        if is_tri || self.verilog2005() {
             self.str("assign");
         } else {
             self.str("always_comb");
@@ -3568,7 +4720,11 @@ impl VerylWalker for Emitter {
 
     /// Semantic action for non-terminal 'VarDeclaration'
     fn var_declaration(&mut self, arg: &VarDeclaration) {
+        if self.verilog2005() {
+            self.net_type = Some(self.verilog2005_net_type(&arg.identifier, None));
+        }
         self.scalar_type(&arg.array_type.scalar_type);
+        self.net_type = None;
         self.space(1);
         self.align_start(align_kind::IDENTIFIER);
         self.identifier(&arg.identifier);
@@ -3641,6 +4797,11 @@ impl VerylWalker for Emitter {
 
     /// Semantic action for non-terminal 'TypeDefDeclaration'
     fn type_def_declaration(&mut self, arg: &TypeDefDeclaration) {
+        // type alias is resolved to its packed width at Verilog-2005
+        if self.verilog2005() {
+            return;
+        }
+
         self.token(&arg.r#type.type_token.replace("typedef"));
         self.space(1);
         self.scalar_type(&arg.array_type.scalar_type);
@@ -3660,7 +4821,11 @@ impl VerylWalker for Emitter {
     /// Semantic action for non-terminal 'AlwaysFfDeclaration'
     fn always_ff_declaration(&mut self, arg: &AlwaysFfDeclaration) {
         self.in_always_ff = true;
-        self.always_ff(&arg.always_ff);
+        if self.verilog2005() {
+            self.token(&arg.always_ff.always_ff_token.replace("always"));
+        } else {
+            self.always_ff(&arg.always_ff);
+        }
         self.space(1);
         self.str("@");
         self.space(1);
@@ -3724,14 +4889,7 @@ impl VerylWalker for Emitter {
                 }
                 _ => unreachable!(),
             };
-            let reset_type = match reset_kind {
-                TypeKind::ResetAsyncHigh => ResetType::AsyncHigh,
-                TypeKind::ResetAsyncLow => ResetType::AsyncLow,
-                TypeKind::ResetSyncHigh => ResetType::SyncHigh,
-                TypeKind::ResetSyncLow => ResetType::SyncLow,
-                TypeKind::Reset => self.build_opt.reset_type,
-                _ => unreachable!(),
-            };
+            let reset_type = self.reset_type(&reset_kind);
             let prefix_op = match reset_type {
                 ResetType::AsyncHigh => {
                     self.str("posedge");
@@ -3763,11 +4921,57 @@ impl VerylWalker for Emitter {
 
     /// Semantic action for non-terminal 'AlwaysCombDeclaration'
     fn always_comb_declaration(&mut self, arg: &AlwaysCombDeclaration) {
-        self.always_comb(&arg.always_comb);
+        if self.verilog2005() {
+            self.token(&arg.always_comb.always_comb_token.replace("always @*"));
+        } else {
+            self.always_comb(&arg.always_comb);
+        }
         self.space(1);
         self.statement_block(&arg.statement_block);
     }
 
+    /// Semantic action for non-terminal 'AssertionDeclaration'
+    fn assertion_declaration(&mut self, arg: &AssertionDeclaration) {
+        self.assertion_guard_start(&arg.assertion_keyword.first());
+        if let Some(ref x) = arg.assertion_declaration_opt {
+            self.identifier(&x.identifier);
+            self.str(":");
+            self.space(1);
+        }
+        self.assertion_keyword(&arg.assertion_keyword);
+        self.space(1);
+        self.str("property");
+        self.space(1);
+        self.token(&arg.l_brace.l_brace_token.replace("("));
+        self.str("@");
+        if let Some(ref x) = arg.assertion_declaration_opt0 {
+            self.l_paren(&x.always_ff_event_list.l_paren);
+            self.always_ff_clock(&x.always_ff_event_list.always_ff_clock);
+            self.r_paren(&x.always_ff_event_list.r_paren);
+        } else {
+            self.str("(");
+            self.always_ff_implicit_clock_event();
+            self.str(")");
+        }
+        if let Some(x) = self.assertion_disable_condition(arg) {
+            self.space(1);
+            self.str("disable iff");
+            self.space(1);
+            self.str(&format!("({x})"));
+        }
+        self.space(1);
+        self.expression(&arg.expression);
+        if let Some(ref x) = arg.assertion_declaration_opt1 {
+            self.space(1);
+            self.implication(&x.implication);
+            self.space(1);
+            self.expression(&x.expression);
+        }
+        self.token(&arg.r_brace.r_brace_token.replace(")"));
+        self.str(";");
+        self.assertion_guard_end();
+    }
+
     /// Semantic action for non-terminal 'AssignDeclaration'
     fn assign_declaration(&mut self, arg: &AssignDeclaration) {
         let idents: Vec<_> = arg.assign_destination.as_ref().into();
@@ -3792,7 +4996,7 @@ impl VerylWalker for Emitter {
                 emit_assign = true;
             }
         }
-        if emit_assign {
+        if emit_assign || self.verilog2005() {
             self.assign(&arg.assign);
         } else {
             self.token(&arg.assign.assign_token.replace("always_comb"));
@@ -4073,6 +5277,11 @@ impl VerylWalker for Emitter {
 
     /// Semantic action for non-terminal 'EnumDeclaration'
     fn enum_declaration(&mut self, arg: &EnumDeclaration) {
+        if self.verilog2005() {
+            self.emit_verilog2005_enum_declaration(arg);
+            return;
+        }
+
         let enum_symbol = symbol_table::resolve(arg.identifier.as_ref()).unwrap();
         if let SymbolKind::Enum(r#enum) = enum_symbol.found.kind {
             self.enum_width = r#enum.width;
@@ -4178,6 +5387,11 @@ impl VerylWalker for Emitter {
 
     /// Semantic action for non-terminal 'StructUnionDeclaration'
     fn struct_union_declaration(&mut self, arg: &StructUnionDeclaration) {
+        // struct and union are lowered to bit slices at Verilog-2005
+        if self.verilog2005() {
+            return;
+        }
+
         let symbol = symbol_table::resolve(arg.identifier.as_ref()).unwrap();
         let maps = self.get_generic_maps(&symbol.found);
 
@@ -4277,6 +5491,14 @@ impl VerylWalker for Emitter {
 
     /// Semantic action for non-terminal 'InstDeclaration'
     fn inst_declaration(&mut self, arg: &InstDeclaration) {
+        if self.verilog2005()
+            && let (Ok(symbol), _) = self.resolve_scoped_idnetifier(&arg.scoped_identifier)
+            && let SymbolKind::Interface(x) = &symbol.found.kind
+        {
+            self.emit_verilog2005_interface_instance(arg, &x.members);
+            return;
+        }
+
         let allow_missing_port = attribute_table::contains(
             &arg.inst.inst_token.token,
             Attr::Allow(AllowItem::MissingPort),
@@ -4316,6 +5538,7 @@ impl VerylWalker for Emitter {
             &connected_ports,
             &generic_map,
             &namespace,
+            self.verilog2005(),
         );
         self.modport_connections_tables
             .push(modport_connections_table);
@@ -4705,7 +5928,14 @@ impl VerylWalker for Emitter {
                         self.align_finish(align_kind::DIRECTION);
                         self.space(1);
 
+                        if self.verilog2005() {
+                            self.net_type = Some(match port.direction {
+                                SymDirection::Input => "wire",
+                                _ => self.verilog2005_net_type(&arg.identifier, Some(port.id)),
+                            });
+                        }
                         self.scalar_type(&array_type.scalar_type);
+                        self.net_type = None;
                         self.space(1);
 
                         self.align_start(align_kind::IDENTIFIER);
@@ -4743,7 +5973,17 @@ impl VerylWalker for Emitter {
                             self.space(1);
                         }
                     }
+                    // function ports have no net type
+                    if self.verilog2005() && self.function_name.is_none() {
+                        self.net_type = Some(match x.direction.as_ref() {
+                            Direction::Output(_) => {
+                                self.verilog2005_net_type(&arg.identifier, None)
+                            }
+                            _ => "wire",
+                        });
+                    }
                     self.scalar_type(&x.array_type.scalar_type);
+                    self.net_type = None;
                     self.space(1);
                     self.align_start(align_kind::IDENTIFIER);
                     self.identifier(&arg.identifier);
@@ -4806,6 +6046,9 @@ impl VerylWalker for Emitter {
     fn function_declaration(&mut self, arg: &FunctionDeclaration) {
         let symbol = symbol_table::resolve(arg.identifier.as_ref()).unwrap();
         let maps = self.get_generic_maps(&symbol.found);
+        // modport ports of module are kept through module body at Verilog-2005
+        let mut outer_modport_ports_table = None;
+        let mut replaced = false;
 
         for (i, map) in maps.iter().enumerate() {
             if i != 0 {
@@ -4823,10 +6066,19 @@ impl VerylWalker for Emitter {
                     &self.into(),
                 );
                 if !modport_ports_table.is_empty() {
-                    self.modport_ports_table = Some(modport_ports_table);
+                    let outer = self.modport_ports_table.replace(modport_ports_table);
+                    if !replaced {
+                        outer_modport_ports_table = outer;
+                        replaced = true;
+                    }
                 }
             }
 
+            if self.verilog2005() {
+                let name = arg.identifier.identifier_token.strip_prefix("r#");
+                self.function_name = Some(name.to_string());
+            }
+
             self.emit_generic_instance_name_comment(map);
             self.function(&arg.function);
             self.space(1);
@@ -4851,13 +6103,24 @@ impl VerylWalker for Emitter {
                 self.token(&x.minus_g_t.minus_g_t_token.replace(""));
             }
             self.str(";");
-            self.emit_statement_block(&arg.statement_block, "", "endfunction");
+            if self.verilog2005() {
+                // function body should be a single statement
+                self.newline_push();
+                self.emit_statement_block(&arg.statement_block, "begin", "end");
+                self.newline_pop();
+                self.str("endfunction");
+                self.function_name = None;
+            } else {
+                self.emit_statement_block(&arg.statement_block, "", "endfunction");
+            }
 
             self.pop_generic_map();
             self.align_reset();
         }
 
-        self.modport_ports_table = None;
+        if replaced {
+            self.modport_ports_table = outer_modport_ports_table;
+        }
     }
 
     /// Semantic action for non-terminal 'ImportDeclaration'
@@ -4905,11 +6168,12 @@ impl VerylWalker for Emitter {
                 self.modport_ports_table = Some(modport_ports_table);
             }
 
+            let start = self.string.len();
             self.emit_generic_instance_name_comment(map);
             self.module(&arg.module);
             self.space(1);
-            if map.generic() {
-                self.emit_generic_instance_name(&arg.identifier.identifier_token, map, false);
+            let name = if map.generic() {
+                self.emit_generic_instance_name(&arg.identifier.identifier_token, map, false)
             } else {
                 let context: SymbolContext = self.into();
                 let text = format!(
@@ -4918,11 +6182,12 @@ impl VerylWalker for Emitter {
                     arg.identifier.identifier_token
                 );
                 self.veryl_token(&arg.identifier.identifier_token.replace(&text));
-            }
+                text
+            };
 
             let mut import_declarations = self.file_scope_import.clone();
             import_declarations.append(&mut arg.collect_import_declarations());
-            if !import_declarations.is_empty() {
+            if !import_declarations.is_empty() && !self.verilog2005() {
                 self.newline_push();
                 for (i, x) in import_declarations.iter().enumerate() {
                     if i != 0 {
@@ -4933,6 +6198,8 @@ impl VerylWalker for Emitter {
                 self.newline_pop();
             }
 
+            // package constants in header are inlined because localparams in body can't be referred
+            self.inline_package_constants = self.verilog2005();
             if let Some(ref x) = arg.module_declaration_opt1 {
                 self.space(1);
                 self.with_parameter(&x.with_parameter);
@@ -4941,22 +6208,51 @@ impl VerylWalker for Emitter {
                 self.space(1);
                 self.port_declaration(&x.port_declaration);
             }
+            self.inline_package_constants = false;
             self.token_will_push(&arg.l_brace.l_brace_token.replace(";"));
-            for (i, x) in arg.module_declaration_list.iter().enumerate() {
+            if self.verilog2005() {
+                self.genvars.push(HashSet::new());
+            }
+            let module_declaration_list: Vec<_> = arg
+                .module_declaration_list
+                .iter()
+                .filter(|x| self.is_emitted_module_group(&x.module_group))
+                .collect();
+            for (i, x) in module_declaration_list.iter().enumerate() {
                 self.newline_list(i);
-                if i == 0 && self.modport_ports_table.is_some() {
+                if i == 0 && self.verilog2005() {
+                    self.emit_package_constants(&arg.identifier.identifier_token);
+                } else if i == 0 && self.modport_ports_table.is_some() {
                     self.emit_expanded_modport_connections();
                     self.modport_ports_table = None;
                 }
                 self.module_group(&x.module_group);
             }
-            self.newline_list_post(arg.module_declaration_list.is_empty());
+            self.newline_list_post(module_declaration_list.is_empty());
             self.token(&arg.r_brace.r_brace_token.replace("endmodule"));
+            self.push_unit(name, symbol.found.id, start);
+            if self.verilog2005() {
+                self.genvars.pop();
+                self.modport_ports_table = None;
+            }
 
             self.pop_generic_map();
             self.align_reset();
         }
 
+        if let Some((name, separator)) =
+            attribute_table::get_flatten(&arg.identifier.identifier_token.token)
+            && arg.module_declaration_opt.is_none()
+        {
+            self.emit_flatten_wrapper(arg, &symbol, &ports, name, separator);
+            // the wrapper is written with the wrapped module
+            if self.mode == Mode::Emit
+                && let Some(x) = self.units.last_mut()
+            {
+                x.end = self.string.len();
+            }
+        }
+
         self.default_clock = None;
         self.default_reset = None;
     }
@@ -4993,11 +6289,12 @@ impl VerylWalker for Emitter {
             }
             self.push_generic_map(map.clone());
 
+            let start = self.string.len();
             self.emit_generic_instance_name_comment(map);
             self.interface(&arg.interface);
             self.space(1);
-            if map.generic() {
-                self.emit_generic_instance_name(&arg.identifier.identifier_token, map, false);
+            let name = if map.generic() {
+                self.emit_generic_instance_name(&arg.identifier.identifier_token, map, false)
             } else {
                 let context: SymbolContext = self.into();
                 let text = format!(
@@ -5006,7 +6303,8 @@ impl VerylWalker for Emitter {
                     arg.identifier.identifier_token
                 );
                 self.veryl_token(&arg.identifier.identifier_token.replace(&text));
-            }
+                text
+            };
 
             let mut import_declarations = self.file_scope_import.clone();
             import_declarations.append(&mut arg.collect_import_declarations());
@@ -5032,6 +6330,7 @@ impl VerylWalker for Emitter {
             }
             self.newline_list_post(arg.interface_declaration_list.is_empty());
             self.token(&arg.r_brace.r_brace_token.replace("endinterface"));
+            self.push_unit(name, symbol.found.id, start);
 
             self.pop_generic_map();
             self.align_reset();
@@ -5090,6 +6389,11 @@ impl VerylWalker for Emitter {
 
     /// Semantic action for non-terminal 'GenerateForDeclaration'
     fn generate_for_declaration(&mut self, arg: &GenerateForDeclaration) {
+        if self.verilog2005() {
+            self.emit_verilog2005_generate_for_declaration(arg);
+            return;
+        }
+
         self.r#for(&arg.r#for);
         self.space(1);
         self.str("(");
@@ -5159,10 +6463,12 @@ impl VerylWalker for Emitter {
             self.identifier(&name);
         }
         self.token_will_push(&arg.l_brace.l_brace_token.replace(""));
+        self.genvars.push(HashSet::new());
         for (i, x) in arg.generate_optional_named_block_list.iter().enumerate() {
             self.newline_list(i);
             self.generate_group(&x.generate_group);
         }
+        self.genvars.pop();
         self.newline_list_post(arg.generate_optional_named_block_list.is_empty());
         self.token(&arg.r_brace.r_brace_token.replace("end"));
     }
@@ -5199,11 +6505,12 @@ impl VerylWalker for Emitter {
             }
             self.push_generic_map(map.clone());
 
+            let start = self.string.len();
             self.emit_generic_instance_name_comment(map);
             self.package(&arg.package);
             self.space(1);
-            if map.generic() {
-                self.emit_generic_instance_name(&arg.identifier.identifier_token, map, false);
+            let name = if map.generic() {
+                self.emit_generic_instance_name(&arg.identifier.identifier_token, map, false)
             } else {
                 let context: SymbolContext = self.into();
                 let text = format!(
@@ -5212,7 +6519,8 @@ impl VerylWalker for Emitter {
                     arg.identifier.identifier_token
                 );
                 self.veryl_token(&arg.identifier.identifier_token.replace(&text));
-            }
+                text
+            };
             self.token_will_push(&arg.l_brace.l_brace_token.replace(";"));
             for (i, x) in arg.package_declaration_list.iter().enumerate() {
                 self.newline_list(i);
@@ -5228,6 +6536,7 @@ impl VerylWalker for Emitter {
             }
             self.newline_list_post(arg.package_declaration_list.is_empty());
             self.token(&arg.r_brace.r_brace_token.replace("endpackage"));
+            self.push_unit(name, symbol.found.id, start);
 
             self.pop_generic_map();
             self.align_reset();
@@ -5303,8 +6612,10 @@ impl VerylWalker for Emitter {
 
     /// Semantic action for non-terminal 'DescriptionGroup'
     fn description_group(&mut self, arg: &DescriptionGroup) {
+        let unit_guards = self.unit_guards.len();
         for x in &arg.description_group_list {
             self.attribute(&x.attribute);
+            self.push_unit_guard(&x.attribute);
         }
         match &*arg.description_group_group {
             DescriptionGroupGroup::LBraceDescriptionGroupGroupListRBrace(x) => {
@@ -5320,6 +6631,7 @@ impl VerylWalker for Emitter {
         for _ in &arg.description_group_list {
             self.attribute_end();
         }
+        self.unit_guards.truncate(unit_guards);
     }
 
     /// Semantic action for non-terminal 'DescriptionItem'
@@ -5343,6 +6655,10 @@ impl VerylWalker for Emitter {
             PublicDescriptionItem::ModuleDeclaration(x) => {
                 self.module_declaration(&x.module_declaration)
             }
+            // interface and package are lowered into modules at Verilog-2005
+            PublicDescriptionItem::InterfaceDeclaration(_)
+            | PublicDescriptionItem::PackageDeclaration(_)
+                if self.verilog2005() => {}
             PublicDescriptionItem::InterfaceDeclaration(x) => {
                 self.interface_declaration(&x.interface_declaration)
             }
@@ -5374,7 +6690,12 @@ impl VerylWalker for Emitter {
                         }
                     }
                 }
-                for (i, x) in arg.veryl_list.iter().enumerate() {
+                let veryl_list: Vec<_> = arg
+                    .veryl_list
+                    .iter()
+                    .filter(|x| self.is_emitted_description(&x.description_group))
+                    .collect();
+                for (i, x) in veryl_list.iter().enumerate() {
                     if i != 0 {
                         self.newline();
                     }
@@ -5424,6 +6745,78 @@ impl From<&mut Emitter> for SymbolContext {
     }
 }
 
+/// Returns the flattened name of interface member referred through interface instance
+fn verilog2005_interface_member(identifier: &Token, member: &Token) -> Option<String> {
+    let symbol = symbol_table::resolve(identifier).ok()?;
+    let SymbolKind::Instance(x) = &symbol.found.kind else {
+        return None;
+    };
+    let r#type =
+        symbol_table::resolve((&x.type_name.generic_path(), &symbol.found.namespace)).ok()?;
+    if matches!(r#type.found.kind, SymbolKind::Interface(_)) {
+        Some(format!("__{identifier}_{member}"))
+    } else {
+        None
+    }
+}
+
+/// Returns the index of the first struct member, LSB and width of the referred struct member
+fn verilog2005_struct_member(
+    full_path: &[SymbolId],
+    members: &[StrId],
+    skip: usize,
+) -> Option<(usize, usize, usize)> {
+    let base = full_path.get(skip)?;
+    let base = symbol_table::get(*base)?;
+    let base = match &base.kind {
+        SymbolKind::ModportVariableMember(x) => symbol_table::get(x.variable)?,
+        _ => base,
+    };
+    let (mut r#type, mut namespace) = match &base.kind {
+        SymbolKind::Variable(x) => (x.r#type.clone(), base.namespace.clone()),
+        SymbolKind::Port(x) => (x.r#type.clone(), base.namespace.clone()),
+        _ => return None,
+    };
+    if !packed_layout::is_struct_union(&r#type, &namespace) {
+        return None;
+    }
+
+    let members = &members[skip..];
+    if members.is_empty() {
+        return None;
+    }
+    let mut lsb = 0;
+    let mut width = 0;
+    for x in members {
+        let layout = packed_layout::member_layout(&r#type, &namespace, *x)?;
+        lsb += layout.lsb;
+        width = layout.width;
+        r#type = layout.r#type;
+        namespace = layout.namespace;
+    }
+    Some((skip, lsb, width))
+}
+
+fn is_verilog2005_package_constant(symbol: &Symbol, context: &SymbolContext) -> bool {
+    context.build_opt.language == Language::Verilog2005 && verilog2005::is_package_constant(symbol)
+}
+
+fn verilog2005_parameter_range(r#type: &SymType, namespace: &Namespace) -> String {
+    match r#type.kind {
+        TypeKind::F32 | TypeKind::F64 => "real ".to_string(),
+        TypeKind::I8 | TypeKind::I16 | TypeKind::I32 | TypeKind::I64 => {
+            match packed_layout::packed_width(r#type, namespace) {
+                Some(x) => format!("signed [{}:0] ", x - 1),
+                None => "signed ".to_string(),
+            }
+        }
+        _ => match packed_layout::packed_width(r#type, namespace) {
+            Some(x) if x > 0 => format!("[{}:0] ", x - 1),
+            _ => String::new(),
+        },
+    }
+}
+
 fn namespace_string(
     namespace: &Namespace,
     generic_tables: &GenericTables,
@@ -5459,11 +6852,7 @@ fn namespace_string(
                 } else if let Some(symbol) = get_generic_instance(&symbol.found, generic_tables) {
                     generic_instance_namespace_string(&symbol, context)
                 } else {
-                    let separator = namespace_separator(
-                        &symbol.found,
-                        context.in_direction_modport,
-                        in_sv_namespace,
-                    );
+                    let separator = namespace_separator(&symbol.found, context, in_sv_namespace);
                     format!("{path}{separator}")
                 };
                 ret.push_str(&text);
@@ -5484,7 +6873,7 @@ fn generic_instance_namespace_string(symbol: &Symbol, context: &SymbolContext) -
     };
 
     let base = symbol_table::get(inst.base).unwrap();
-    let separator = namespace_separator(&base, context.in_direction_modport, false);
+    let separator = namespace_separator(&base, context, false);
     if context.build_opt.hashed_mangled_name {
         let name = symbol
             .generic_maps()
@@ -5497,12 +6886,45 @@ fn generic_instance_namespace_string(symbol: &Symbol, context: &SymbolContext) -
     }
 }
 
-fn namespace_separator(
-    symbol: &Symbol,
-    in_direction_modport: bool,
-    in_sv_namespace: bool,
-) -> String {
+/// Returns Veryl path of the generic instance like `prj::Module::<8, Pkg::X>`
+fn generic_instance_path(generic_map: &GenericMap) -> Option<String> {
+    let symbol = symbol_table::get(generic_map.id?)?;
+    let SymbolKind::GenericInstance(ref inst) = symbol.kind else {
+        return None;
+    };
+
+    let base = symbol_table::get(inst.base)?;
+    let arguments: Vec<_> = inst.arguments.iter().map(generic_symbol_path).collect();
+    Some(format!(
+        "{}::{}::<{}>",
+        base.namespace,
+        base.token,
+        arguments.join(", ")
+    ))
+}
+
+fn generic_symbol_path(path: &GenericSymbolPath) -> String {
+    let paths: Vec<_> = path
+        .paths
+        .iter()
+        .map(|x| {
+            if x.arguments.is_empty() {
+                x.base.to_string()
+            } else {
+                let arguments: Vec<_> = x.arguments.iter().map(generic_symbol_path).collect();
+                format!("{}::<{}>", x.base, arguments.join(", "))
+            }
+        })
+        .collect();
+    paths.join("::")
+}
+
+fn namespace_separator(symbol: &Symbol, context: &SymbolContext, in_sv_namespace: bool) -> String {
+    let in_direction_modport = context.in_direction_modport;
     let separator = match symbol.kind {
+        // package constants are emitted as `localparam` with mangled name at Verilog-2005
+        SymbolKind::Package(_) if context.build_opt.language == Language::Verilog2005 => "_",
+        SymbolKind::Package(_) if context.build_opt.language == Language::Vhdl2008 => ".",
         SymbolKind::Package(_) => "::",
         SymbolKind::Interface(_) => ".",
         SymbolKind::SystemVerilog if in_direction_modport => ".",
@@ -5568,6 +6990,7 @@ pub fn symbol_string(
         | SymbolKind::Enum(_) => {
             let visible = namespace.included(&symbol.namespace)
                 || symbol.imported.iter().any(|(_, x)| *x == namespace);
+            let visible = visible && !is_verilog2005_package_constant(symbol, context);
             if visible & !context.in_import {
                 ret.push_str(&token_text);
             } else {
@@ -5584,7 +7007,9 @@ pub fn symbol_string(
             enum_namespace.pop();
 
             // if enum definition is not visible, explicit namespace is required
-            if !namespace.included(&enum_namespace) {
+            if !namespace.included(&enum_namespace)
+                || is_verilog2005_package_constant(symbol, context)
+            {
                 ret.push_str(&namespace_string(&enum_namespace, generic_tables, context));
             }
             ret.push_str(&x.prefix);
@@ -5623,10 +7048,11 @@ pub fn symbol_string(
                 ));
             }
             if context.build_opt.hashed_mangled_name {
+                // namespace prefix is already pushed above
                 let name = symbol
                     .generic_maps()
                     .first()
-                    .map(|x| x.name(true, true))
+                    .map(|x| x.name(false, true))
                     .unwrap();
                 ret.push_str(&name);
             } else {
@@ -10,6 +10,7 @@ use veryl_analyzer::symbol::{
     GenericMap, GenericTables, Port, Symbol, SymbolId, SymbolKind, VariableProperty,
 };
 use veryl_analyzer::symbol_table;
+use veryl_metadata::Language;
 use veryl_parser::resource_table::StrId;
 use veryl_parser::veryl_grammar_trait::*;
 use veryl_parser::veryl_token::{Token, VerylToken};
@@ -29,14 +30,20 @@ impl ExpandModportConnections {
         modport: &Symbol,
         interface_name: &VerylToken,
         array_index: &[isize],
+        flatten_interface: bool,
     ) -> Self {
         let connections: Vec<_> = collect_modport_member_variables(modport)
             .iter()
             .map(|(variable_token, _variable, _direction)| {
                 let (port_target, interface_target) = if array_index.is_empty() {
+                    let interface_target = if flatten_interface {
+                        format!("__{interface_name}_{variable_token}")
+                    } else {
+                        format!("{interface_name}.{variable_token}")
+                    };
                     (
                         format!("__{}_{}", port.name(), variable_token),
-                        format!("{interface_name}.{variable_token}"),
+                        interface_target,
                     )
                 } else {
                     let index: Vec<_> = array_index.iter().map(|x| format!("{x}")).collect();
@@ -78,6 +85,7 @@ impl ExpandModportConnectionsTable {
         inst_ports: &[InstPortItem],
         generic_map: &[GenericMap],
         namespace: &Namespace,
+        flatten_interface: bool,
     ) -> Self {
         let connected_ports: HashMap<StrId, Option<&VerylToken>> = inst_ports
             .iter()
@@ -98,6 +106,7 @@ impl ExpandModportConnectionsTable {
             generic_map,
             namespace,
             false,
+            flatten_interface,
         );
         ret
     }
@@ -152,6 +161,7 @@ impl ExpandModportConnectionsTable {
             generic_map,
             namespace,
             true,
+            false,
         );
         ret
     }
@@ -163,9 +173,12 @@ impl ExpandModportConnectionsTable {
         generic_map: &[GenericMap],
         namespace: &Namespace,
         in_function: bool,
+        flatten_interface: bool,
     ) {
         for (modport, port, index) in collect_modports(defined_ports, namespace) {
-            if !(in_function || attribute_table::is_expand(&port.token.token, ExpandItem::Modport))
+            if !(in_function
+                || flatten_interface
+                || attribute_table::is_expand(&port.token.token, ExpandItem::Modport))
             {
                 continue;
             }
@@ -183,12 +196,19 @@ impl ExpandModportConnectionsTable {
                     &modport,
                     connected_interface,
                     &[],
+                    flatten_interface,
                 )]
             } else {
                 array_index
                     .iter()
                     .map(|index| {
-                        ExpandModportConnections::new(&port, &modport, connected_interface, index)
+                        ExpandModportConnections::new(
+                            &port,
+                            &modport,
+                            connected_interface,
+                            index,
+                            flatten_interface,
+                        )
                     })
                     .collect()
             };
@@ -325,8 +345,12 @@ impl ExpandedModportPortTable {
         in_function: bool,
         context: &SymbolContext,
     ) {
+        // Verilog-2005 has no interface, so all modport ports are expanded
+        let verilog2005 = context.build_opt.language == Language::Verilog2005;
         for (modport, port, _) in collect_modports(defined_ports, namespace) {
-            if !(in_function || attribute_table::is_expand(&port.token.token, ExpandItem::Modport))
+            if !(in_function
+                || verilog2005
+                || attribute_table::is_expand(&port.token.token, ExpandItem::Modport))
             {
                 continue;
             }
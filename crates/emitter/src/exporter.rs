@@ -0,0 +1,423 @@
+use log::warn;
+use std::fmt::Write;
+use veryl_analyzer::handlers::check_identifier::to_snake_case;
+use veryl_analyzer::namespace::Namespace;
+use veryl_analyzer::packed_layout;
+use veryl_analyzer::symbol::{Symbol, SymbolKind, Type, TypeKind};
+use veryl_analyzer::symbol_table;
+use veryl_metadata::{Export, ExportLanguage, Metadata};
+use veryl_parser::resource_table;
+
+struct ExportedConst {
+    name: String,
+    width: Option<usize>,
+    signed: bool,
+    /// Value of unsigned constants is wrapped into the width
+    value: i128,
+}
+
+struct ExportedEnum {
+    name: String,
+    width: usize,
+    members: Vec<(String, usize)>,
+}
+
+struct ExportedField {
+    name: String,
+    lsb: usize,
+    width: usize,
+}
+
+struct ExportedStruct {
+    name: String,
+    width: usize,
+    fields: Vec<ExportedField>,
+}
+
+struct ExportedPackage {
+    name: String,
+    consts: Vec<ExportedConst>,
+    enums: Vec<ExportedEnum>,
+    structs: Vec<ExportedStruct>,
+}
+
+pub struct ExportedFile {
+    pub name: String,
+    pub text: String,
+}
+
+/// Exports constants, enum encodings and packed struct layouts of packages
+/// as C header, Python module and Rust module.
+pub struct Exporter {
+    project_name: String,
+    export_opt: Export,
+}
+
+impl Exporter {
+    pub fn new(metadata: &Metadata) -> Self {
+        Self {
+            project_name: metadata.project.name.clone(),
+            export_opt: metadata.export.clone(),
+        }
+    }
+
+    pub fn export(&self) -> Vec<ExportedFile> {
+        let mut prj_namespace = Namespace::new();
+        prj_namespace.push(resource_table::insert_str(&self.project_name));
+
+        let mut packages: Vec<_> = symbol_table::get_all()
+            .into_iter()
+            .filter(|x| {
+                if let SymbolKind::Package(property) = &x.kind {
+                    let name = x.token.to_string();
+                    x.namespace.matched(&prj_namespace)
+                        && property.generic_parameters.is_empty()
+                        && (self.export_opt.packages.is_empty()
+                            || self.export_opt.packages.contains(&name))
+                } else {
+                    false
+                }
+            })
+            .collect();
+        packages.sort_by_key(|x| x.token.to_string());
+
+        let mut ret = Vec::new();
+        for package in packages {
+            let package = collect_package(&package);
+            for language in &self.export_opt.languages {
+                let text = match language {
+                    ExportLanguage::C => self.emit_c(&package),
+                    ExportLanguage::Python => emit_python(&package),
+                    ExportLanguage::Rust => emit_rust(&package),
+                };
+                let name = format!("{}.{}", to_snake_case(&package.name), language.extension());
+                ret.push(ExportedFile { name, text });
+            }
+        }
+        ret
+    }
+
+    fn emit_c(&self, package: &ExportedPackage) -> String {
+        let prefix = to_snake_case(&package.name).to_uppercase();
+        let guard = format!("{}_{prefix}_H", self.project_name.to_uppercase());
+
+        let mut ret = String::new();
+        let _ = writeln!(ret, "{}", header("//", &package.name));
+        let _ = writeln!(ret, "#ifndef {guard}");
+        let _ = writeln!(ret, "#define {guard}");
+
+        if !package.consts.is_empty() {
+            let _ = writeln!(ret);
+            for x in &package.consts {
+                if let Some(value) = c_const(x) {
+                    let _ = writeln!(ret, "#define {prefix}_{} {value}", x.name);
+                } else {
+                    warn!(
+                        "Constant {}::{} is not exported to C because it exceeds 64 bits",
+                        package.name, x.name
+                    );
+                }
+            }
+        }
+
+        for x in &package.enums {
+            let name = format!("{prefix}_{}", to_snake_case(&x.name).to_uppercase());
+            let _ = writeln!(ret);
+            let _ = writeln!(ret, "// enum {}", x.name);
+            let _ = writeln!(ret, "#define {name}_WIDTH {}", x.width);
+            for (member, value) in &x.members {
+                let _ = writeln!(ret, "#define {name}_{member} {}", c_value(*value as u64));
+            }
+        }
+
+        for x in &package.structs {
+            let name = format!("{prefix}_{}", to_snake_case(&x.name).to_uppercase());
+            let _ = writeln!(ret);
+            let _ = writeln!(ret, "// struct {}", x.name);
+            let _ = writeln!(ret, "#define {name}_WIDTH {}", x.width);
+            for field in &x.fields {
+                let field_name = format!("{name}_{}", field.name.to_uppercase());
+                let _ = writeln!(ret, "#define {field_name}_LSB {}", field.lsb);
+                let _ = writeln!(ret, "#define {field_name}_WIDTH {}", field.width);
+                if let Some(mask) = mask(field).filter(|x| *x <= u64::MAX as u128) {
+                    let suffix = if mask > u32::MAX as u128 { "ULL" } else { "U" };
+                    let _ = writeln!(ret, "#define {field_name}_MASK 0x{mask:x}{suffix}");
+                }
+            }
+        }
+
+        let _ = writeln!(ret);
+        let _ = writeln!(ret, "#endif");
+        ret
+    }
+}
+
+fn collect_package(symbol: &Symbol) -> ExportedPackage {
+    let SymbolKind::Package(property) = &symbol.kind else {
+        unreachable!()
+    };
+
+    let mut ret = ExportedPackage {
+        name: symbol.token.to_string(),
+        consts: Vec::new(),
+        enums: Vec::new(),
+        structs: Vec::new(),
+    };
+
+    for member in &property.members {
+        let Some(member) = symbol_table::get(*member) else {
+            continue;
+        };
+        let name = member.token.to_string();
+        match &member.kind {
+            SymbolKind::Parameter(x) if x.kind.is_const() => {
+                // Only integer constants which can be evaluated statically are exported
+                let width = packed_layout::packed_width(&x.r#type, &member.namespace);
+                let signed = is_signed(&x.r#type);
+                let Some(value) = packed_layout::static_integer(&x.value) else {
+                    warn!(
+                        "Constant {}::{name} is not exported because it can't be evaluated statically",
+                        symbol.token
+                    );
+                    continue;
+                };
+                let Some(value) = wrap_value(value as i128, width, signed) else {
+                    warn!(
+                        "Constant {}::{name} is not exported because it exceeds 128 bits",
+                        symbol.token
+                    );
+                    continue;
+                };
+                ret.consts.push(ExportedConst {
+                    name,
+                    width,
+                    signed,
+                    value,
+                });
+            }
+            SymbolKind::Enum(x) => {
+                let width = if let Some(r#type) = &x.r#type {
+                    packed_layout::packed_width(r#type, &member.namespace)
+                } else {
+                    Some(x.width)
+                };
+                let members: Option<Vec<_>> = x
+                    .members
+                    .iter()
+                    .map(|id| {
+                        let member = symbol_table::get(*id)?;
+                        if let SymbolKind::EnumMember(x) = &member.kind {
+                            Some((member.token.to_string(), x.value.value()?))
+                        } else {
+                            None
+                        }
+                    })
+                    .collect();
+                if let (Some(width), Some(members)) = (width, members) {
+                    ret.enums.push(ExportedEnum {
+                        name,
+                        width,
+                        members,
+                    });
+                }
+            }
+            SymbolKind::Struct(_) => {
+                if let Some(layout) = packed_layout::struct_layout(&member) {
+                    let fields: Vec<_> = layout
+                        .into_iter()
+                        .map(|(name, x)| ExportedField {
+                            name: name.to_string(),
+                            lsb: x.lsb,
+                            width: x.width,
+                        })
+                        .collect();
+                    let width = fields.iter().map(|x| x.width).sum();
+                    ret.structs.push(ExportedStruct {
+                        name,
+                        width,
+                        fields,
+                    });
+                }
+            }
+            _ => (),
+        }
+    }
+
+    ret
+}
+
+fn emit_python(package: &ExportedPackage) -> String {
+    let mut ret = String::new();
+    let _ = writeln!(ret, "{}", header("#", &package.name));
+
+    if !package.enums.is_empty() {
+        let _ = writeln!(ret, "import enum");
+    }
+
+    if !package.consts.is_empty() {
+        let _ = writeln!(ret);
+        for x in &package.consts {
+            let _ = writeln!(ret, "{} = {}", x.name, x.value);
+        }
+    }
+
+    for x in &package.enums {
+        let _ = writeln!(ret);
+        let _ = writeln!(ret);
+        let _ = writeln!(ret, "class {}(enum.IntEnum):", x.name);
+        for (member, value) in &x.members {
+            let _ = writeln!(ret, "    {member} = {value}");
+        }
+    }
+
+    for x in &package.structs {
+        let _ = writeln!(ret);
+        let _ = writeln!(ret);
+        let _ = writeln!(ret, "class {}:", x.name);
+        let _ = writeln!(ret, "    WIDTH = {}", x.width);
+        for field in &x.fields {
+            let name = field.name.to_uppercase();
+            let _ = writeln!(ret, "    {name}_LSB = {}", field.lsb);
+            let _ = writeln!(ret, "    {name}_WIDTH = {}", field.width);
+            if let Some(mask) = mask(field) {
+                let _ = writeln!(ret, "    {name}_MASK = 0x{mask:x}");
+            }
+        }
+    }
+
+    ret
+}
+
+fn emit_rust(package: &ExportedPackage) -> String {
+    let mut ret = String::new();
+    let _ = writeln!(ret, "{}", header("//", &package.name));
+
+    if !package.consts.is_empty() {
+        let _ = writeln!(ret);
+    }
+    for x in &package.consts {
+        let r#type = if x.signed || x.value < 0 {
+            let width = bit_length(x.value.unsigned_abs()) + 1;
+            signed_rust_type(x.width.unwrap_or(64).max(width))
+        } else {
+            rust_type(x.width.unwrap_or(64).max(bit_length(x.value as u128)))
+        };
+        let _ = writeln!(ret, "pub const {}: {type} = {};", x.name, x.value);
+    }
+
+    for x in &package.enums {
+        let _ = writeln!(ret);
+        let _ = writeln!(ret, "#[allow(non_camel_case_types)]");
+        let _ = writeln!(ret, "#[derive(Clone, Copy, Debug, PartialEq, Eq)]");
+        let _ = writeln!(ret, "#[repr({})]", rust_type(x.width));
+        let _ = writeln!(ret, "pub enum {} {{", x.name);
+        for (member, value) in &x.members {
+            let _ = writeln!(ret, "    {member} = {value},");
+        }
+        let _ = writeln!(ret, "}}");
+    }
+
+    for x in &package.structs {
+        let _ = writeln!(ret);
+        let mask_type = rust_type(x.width);
+        let _ = writeln!(ret, "pub mod {} {{", to_snake_case(&x.name));
+        let _ = writeln!(ret, "    pub const WIDTH: usize = {};", x.width);
+        for field in &x.fields {
+            let name = field.name.to_uppercase();
+            let _ = writeln!(ret, "    pub const {name}_LSB: usize = {};", field.lsb);
+            let _ = writeln!(ret, "    pub const {name}_WIDTH: usize = {};", field.width);
+            if let Some(mask) = mask(field) {
+                let _ = writeln!(ret, "    pub const {name}_MASK: {mask_type} = 0x{mask:x};");
+            }
+        }
+        let _ = writeln!(ret, "}}");
+    }
+
+    ret
+}
+
+fn header(comment: &str, package: &str) -> String {
+    format!("{comment} Generated from package {package} by Veryl. DO NOT EDIT.")
+}
+
+fn mask(field: &ExportedField) -> Option<u128> {
+    let msb = field.lsb + field.width;
+    if msb > 128 || field.width == 0 {
+        None
+    } else if field.width == 128 {
+        Some(u128::MAX)
+    } else {
+        Some(((1u128 << field.width) - 1) << field.lsb)
+    }
+}
+
+fn c_value(value: u64) -> String {
+    if value > u32::MAX as u64 {
+        format!("{value}ULL")
+    } else {
+        format!("{value}")
+    }
+}
+
+fn c_const(x: &ExportedConst) -> Option<String> {
+    if x.value >= 0 {
+        return u64::try_from(x.value).ok().map(c_value);
+    }
+
+    let value = i64::try_from(x.value).ok()?;
+    let ret = if value == i64::MIN {
+        // The literal of the minimum value overflows before negation
+        format!("(-{}LL - 1)", i64::MAX)
+    } else if value < i32::MIN as i64 {
+        format!("({value}LL)")
+    } else {
+        format!("({value})")
+    };
+    Some(ret)
+}
+
+fn is_signed(r#type: &Type) -> bool {
+    r#type.is_signed()
+        || matches!(
+            r#type.kind,
+            TypeKind::I8 | TypeKind::I16 | TypeKind::I32 | TypeKind::I64
+        )
+}
+
+/// Converts negative values of unsigned types into two's complement of the width
+fn wrap_value(value: i128, width: Option<usize>, signed: bool) -> Option<i128> {
+    match width {
+        Some(width) if value < 0 && !signed => {
+            if width >= 128 {
+                None
+            } else {
+                Some(value & ((1i128 << width) - 1))
+            }
+        }
+        _ => Some(value),
+    }
+}
+
+fn bit_length(value: u128) -> usize {
+    (u128::BITS - value.leading_zeros()) as usize
+}
+
+fn rust_type(width: usize) -> &'static str {
+    match width {
+        0..=8 => "u8",
+        9..=16 => "u16",
+        17..=32 => "u32",
+        33..=64 => "u64",
+        _ => "u128",
+    }
+}
+
+fn signed_rust_type(width: usize) -> &'static str {
+    match width {
+        0..=8 => "i8",
+        9..=16 => "i16",
+        17..=32 => "i32",
+        33..=64 => "i64",
+        _ => "i128",
+    }
+}
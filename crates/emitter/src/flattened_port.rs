@@ -0,0 +1,221 @@
+use crate::emitter::SymbolContext;
+use crate::expaneded_modport::ExpandedModportPortTable;
+use veryl_analyzer::namespace::Namespace;
+use veryl_analyzer::namespace_table;
+use veryl_analyzer::symbol::Direction as SymDirection;
+use veryl_analyzer::symbol::Type as SymType;
+use veryl_analyzer::symbol::{GenericMap, Port, SymbolKind, TypeKind};
+use veryl_analyzer::symbol_table;
+use veryl_parser::veryl_token::VerylToken;
+
+#[derive(Clone, Debug)]
+pub struct FlattenedPort {
+    pub identifier: VerylToken,
+    pub r#type: SymType,
+    pub direction_token: VerylToken,
+    pub direction: SymDirection,
+    pub generic_maps: Vec<GenericMap>,
+}
+
+pub struct FlattenedInterfaceAssign {
+    pub interface_target: VerylToken,
+    pub direction: SymDirection,
+    pub ports: Vec<VerylToken>,
+}
+
+pub struct FlattenedInterface {
+    pub identifier: VerylToken,
+    pub interface_name: VerylToken,
+    pub array_size: Vec<isize>,
+    pub assigns: Vec<FlattenedInterfaceAssign>,
+}
+
+pub struct FlattenedConnection {
+    pub port_target: VerylToken,
+    /// Wrapper ports connected to the port in MSB-first order
+    pub ports: Vec<VerylToken>,
+}
+
+/// Ports of the wrapper module generated by `#[flatten]`
+///
+/// Modport ports are split into their members, and struct typed ports and members
+/// are split into their fields recursively.
+pub struct FlattenedPortTable {
+    pub ports: Vec<FlattenedPort>,
+    pub interfaces: Vec<FlattenedInterface>,
+    pub connections: Vec<FlattenedConnection>,
+    separator: String,
+}
+
+impl FlattenedPortTable {
+    pub fn create(
+        defined_ports: &[Port],
+        generic_map: &[GenericMap],
+        namespace_token: &VerylToken,
+        namespace: &Namespace,
+        separator: &str,
+        context: &SymbolContext,
+    ) -> Self {
+        let mut ret = FlattenedPortTable {
+            ports: Vec::new(),
+            interfaces: Vec::new(),
+            connections: Vec::new(),
+            separator: separator.to_string(),
+        };
+
+        // modport ports which are already expanded in the wrapped module
+        let expanded = ExpandedModportPortTable::create(
+            defined_ports,
+            generic_map,
+            namespace_token,
+            namespace,
+            false,
+            context,
+        );
+        let all = ExpandedModportPortTable::create(
+            defined_ports,
+            generic_map,
+            namespace_token,
+            namespace,
+            true,
+            context,
+        );
+
+        for port in defined_ports {
+            if let Some(entry) = all.get(&port.token.token) {
+                let is_expanded = expanded.get(&port.token.token).is_some();
+                let mut assigns = Vec::new();
+
+                for member in entry.ports.iter().flat_map(|x| x.ports.iter()) {
+                    let mut name = vec![port.name().to_string()];
+                    name.extend(member.array_index.iter().map(|x| x.to_string()));
+                    name.push(member.id.to_string());
+                    let name = name.join(separator);
+
+                    let ports = ret.push_ports(
+                        &port.token,
+                        &name,
+                        &member.r#type,
+                        &member.direction_token,
+                        member.direction,
+                        &entry.generic_maps,
+                    );
+
+                    if is_expanded {
+                        ret.connections.push(FlattenedConnection {
+                            port_target: member.identifier.clone(),
+                            ports,
+                        });
+                    } else {
+                        assigns.push(FlattenedInterfaceAssign {
+                            interface_target: member.interface_target.clone(),
+                            direction: member.direction,
+                            ports,
+                        });
+                    }
+                }
+
+                if !is_expanded {
+                    ret.interfaces.push(FlattenedInterface {
+                        identifier: entry.identifier.clone(),
+                        interface_name: entry.interface_name.clone(),
+                        array_size: entry.array_size.clone(),
+                        assigns,
+                    });
+                    ret.connections.push(FlattenedConnection {
+                        port_target: port.token.clone(),
+                        ports: vec![port.token.clone()],
+                    });
+                }
+            } else {
+                let property = port.property();
+                let direction_token = match property.direction {
+                    SymDirection::Output => port.token.replace("output"),
+                    SymDirection::Inout => port.token.replace("inout"),
+                    _ => port.token.replace("input"),
+                };
+                let ports = ret.push_ports(
+                    &port.token,
+                    &port.name().to_string(),
+                    &property.r#type,
+                    &direction_token,
+                    property.direction,
+                    &[],
+                );
+                ret.connections.push(FlattenedConnection {
+                    port_target: port.token.clone(),
+                    ports,
+                });
+            }
+        }
+
+        ret
+    }
+
+    fn push_ports(
+        &mut self,
+        token: &VerylToken,
+        name: &str,
+        r#type: &SymType,
+        direction_token: &VerylToken,
+        direction: SymDirection,
+        generic_maps: &[GenericMap],
+    ) -> Vec<VerylToken> {
+        if let Some(members) = struct_members(r#type) {
+            let mut ret = Vec::new();
+            for (member_name, member_type) in members {
+                let name = format!("{name}{}{member_name}", self.separator);
+                ret.append(&mut self.push_ports(
+                    token,
+                    &name,
+                    &member_type,
+                    direction_token,
+                    direction,
+                    generic_maps,
+                ));
+            }
+            ret
+        } else {
+            let identifier = token.replace(name);
+            self.ports.push(FlattenedPort {
+                identifier: identifier.clone(),
+                r#type: r#type.clone(),
+                direction_token: direction_token.clone(),
+                direction,
+                generic_maps: generic_maps.to_vec(),
+            });
+            vec![identifier]
+        }
+    }
+}
+
+/// Returns fields of the struct if the type is a non-generic and non-array struct
+fn struct_members(r#type: &SymType) -> Option<Vec<(String, SymType)>> {
+    if !r#type.array.is_empty() {
+        return None;
+    }
+
+    let TypeKind::UserDefined(x) = &r#type.kind else {
+        return None;
+    };
+    let namespace = namespace_table::get(x.path.paths.first()?.base.id)?;
+    let (_, Some(symbol)) = r#type.trace_user_defined(&namespace)? else {
+        return None;
+    };
+    let SymbolKind::Struct(x) = symbol.kind else {
+        return None;
+    };
+    if !x.generic_parameters.is_empty() {
+        return None;
+    }
+
+    let mut ret = Vec::new();
+    for member in &x.members {
+        let member = symbol_table::get(*member)?;
+        if let SymbolKind::StructMember(property) = member.kind {
+            property.r#type.array_type.as_ref()?;
+            ret.push((member.token.to_string(), property.r#type));
+        }
+    }
+    Some(ret)
+}
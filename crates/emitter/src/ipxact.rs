@@ -0,0 +1,481 @@
+use crate::emitter::SymbolContext;
+use crate::expaneded_modport::ExpandedModportPortTable;
+use crate::exporter::ExportedFile;
+use relative_path::PathExt;
+use std::collections::HashSet;
+use std::fmt::Write;
+use std::path::{Path, PathBuf};
+use veryl_analyzer::attribute::ExpandItem;
+use veryl_analyzer::attribute_table;
+use veryl_analyzer::evaluator::Evaluator;
+use veryl_analyzer::namespace::Namespace;
+use veryl_analyzer::namespace_table;
+use veryl_analyzer::packed_layout;
+use veryl_analyzer::symbol::Direction as SymDirection;
+use veryl_analyzer::symbol::Type as SymType;
+use veryl_analyzer::symbol::{ModuleProperty, Symbol, SymbolKind, TypeKind};
+use veryl_analyzer::symbol_table;
+use veryl_metadata::{Build, IpXact, Language, Metadata};
+use veryl_parser::resource_table;
+use veryl_parser::veryl_token::VerylToken;
+
+const IPXACT_NAMESPACE: &str = "http://www.accellera.org/XMLSchema/IPXACT/1685-2014";
+const FILE_SET_NAME: &str = "fs-rtl";
+const INSTANTIATION_NAME: &str = "rtl_implementation";
+
+/// Generates IEEE 1685-2014 IP-XACT component descriptions of top-level modules.
+///
+/// Modport ports are described as bus interfaces. Because SystemVerilog interface ports
+/// can't be described as IP-XACT ports, port maps are generated only for modport ports
+/// expanded by `#[expand(modport)]` or Verilog-2005 output.
+pub struct IpXactGenerator {
+    project_name: String,
+    build_opt: Build,
+    ipxact_opt: IpXact,
+    ipxact_path: PathBuf,
+}
+
+impl IpXactGenerator {
+    pub fn new(metadata: &Metadata) -> Self {
+        Self {
+            project_name: metadata.project.name.clone(),
+            build_opt: metadata.build.clone(),
+            ipxact_opt: metadata.ipxact.clone(),
+            ipxact_path: metadata.ipxact_path(),
+        }
+    }
+
+    /// Generates a component description per top-level module.
+    /// `files` are the emitted files listed in the file set of each component.
+    pub fn generate(&self, files: &[PathBuf]) -> Vec<ExportedFile> {
+        let mut ret = Vec::new();
+        for symbol in self.top_modules() {
+            let name = self.module_name(&symbol);
+            let text = self.component(&symbol, files);
+            ret.push(ExportedFile {
+                name: format!("{name}.xml"),
+                text,
+            });
+        }
+        ret
+    }
+
+    /// Returns non-generic modules of the project which are not instantiated from other modules
+    fn top_modules(&self) -> Vec<Symbol> {
+        let mut prj_namespace = Namespace::new();
+        prj_namespace.push(resource_table::insert_str(&self.project_name));
+
+        let symbols = symbol_table::get_all();
+        let mut instantiated = HashSet::new();
+        for symbol in &symbols {
+            if let SymbolKind::Instance(x) = &symbol.kind
+                && let Ok(x) =
+                    symbol_table::resolve((&x.type_name.generic_path(), &symbol.namespace))
+            {
+                instantiated.insert(x.found.id);
+            }
+        }
+
+        let mut ret: Vec<_> = symbols
+            .into_iter()
+            .filter(|x| {
+                if let SymbolKind::Module(property) = &x.kind {
+                    x.namespace.matched(&prj_namespace)
+                        && property.generic_parameters.is_empty()
+                        && !instantiated.contains(&x.id)
+                } else {
+                    false
+                }
+            })
+            .collect();
+        ret.sort_by_key(|x| x.token.to_string());
+        ret
+    }
+
+    fn module_name(&self, symbol: &Symbol) -> String {
+        if self.build_opt.omit_project_prefix {
+            symbol.token.to_string()
+        } else {
+            format!("{}_{}", self.project_name, symbol.token)
+        }
+    }
+
+    fn component(&self, symbol: &Symbol, files: &[PathBuf]) -> String {
+        let SymbolKind::Module(property) = &symbol.kind else {
+            unreachable!()
+        };
+
+        let name = self.module_name(symbol);
+        let mut ret = String::new();
+        let _ = writeln!(ret, r#"<?xml version="1.0" encoding="UTF-8"?>"#);
+        let _ = writeln!(
+            ret,
+            "<!-- Generated from module {} by Veryl. DO NOT EDIT. -->",
+            symbol.token
+        );
+        let _ = writeln!(
+            ret,
+            r#"<ipxact:component xmlns:ipxact="{IPXACT_NAMESPACE}" xmlns:xsi="http://www.w3.org/2001/XMLSchema-instance" xsi:schemaLocation="{IPXACT_NAMESPACE} {IPXACT_NAMESPACE}/index.xsd">"#
+        );
+        self.vlnv(&mut ret, 1, &name);
+        self.bus_interfaces(&mut ret, symbol, property);
+        self.model(&mut ret, symbol, property, &name);
+        self.file_sets(&mut ret, files);
+        let _ = writeln!(ret, "</ipxact:component>");
+        ret
+    }
+
+    fn vlnv(&self, ret: &mut String, depth: usize, name: &str) {
+        element(ret, depth, "vendor", &self.ipxact_opt.vendor);
+        element(ret, depth, "library", &self.library());
+        element(ret, depth, "name", name);
+        element(ret, depth, "version", &self.ipxact_opt.version);
+    }
+
+    fn vlnv_attribute(&self, name: &str) -> String {
+        format!(
+            r#"vendor="{}" library="{}" name="{}" version="{}""#,
+            escape(&self.ipxact_opt.vendor),
+            escape(&self.library()),
+            escape(name),
+            escape(&self.ipxact_opt.version)
+        )
+    }
+
+    fn library(&self) -> String {
+        if self.ipxact_opt.library.is_empty() {
+            self.project_name.clone()
+        } else {
+            self.ipxact_opt.library.clone()
+        }
+    }
+
+    fn symbol_context(&self) -> SymbolContext {
+        SymbolContext {
+            project_name: Some(resource_table::insert_str(&self.project_name)),
+            build_opt: self.build_opt.clone(),
+            in_import: false,
+            in_direction_modport: false,
+            generic_map: Vec::new(),
+        }
+    }
+
+    fn is_expanded(&self, token: &VerylToken) -> bool {
+        self.build_opt.language == Language::Verilog2005
+            || attribute_table::is_expand(&token.token, ExpandItem::Modport)
+    }
+
+    fn bus_interfaces(&self, ret: &mut String, symbol: &Symbol, property: &ModuleProperty) {
+        let token = VerylToken::new(symbol.token);
+        let table = ExpandedModportPortTable::create(
+            &property.ports,
+            &[],
+            &token,
+            &symbol.namespace,
+            true,
+            &self.symbol_context(),
+        );
+
+        let mut text = String::new();
+        for port in &property.ports {
+            let Some(entry) = table.get(&port.token.token) else {
+                continue;
+            };
+            let modport = port
+                .property()
+                .r#type
+                .trace_user_defined(&symbol.namespace)
+                .and_then(|(_, x)| x)
+                .map(|x| x.token.to_string())
+                .unwrap_or_default();
+            let is_expanded = self.is_expanded(&port.token);
+            let interface_name = entry.interface_name.to_string();
+
+            for ports in &entry.ports {
+                let mut name = vec![port.name().to_string()];
+                if let Some(x) = ports.ports.first() {
+                    name.extend(x.array_index.iter().map(|x| x.to_string()));
+                }
+
+                let _ = writeln!(text, "{}<ipxact:busInterface>", indent(2));
+                element(&mut text, 3, "name", &name.join("_"));
+                let _ = writeln!(
+                    text,
+                    "{}<ipxact:busType {}/>",
+                    indent(3),
+                    self.vlnv_attribute(&interface_name)
+                );
+                if is_expanded {
+                    let _ = writeln!(text, "{}<ipxact:abstractionTypes>", indent(3));
+                    let _ = writeln!(text, "{}<ipxact:abstractionType>", indent(4));
+                    let _ = writeln!(
+                        text,
+                        "{}<ipxact:abstractionRef {}/>",
+                        indent(5),
+                        self.vlnv_attribute(&format!("{interface_name}_rtl"))
+                    );
+                    let _ = writeln!(text, "{}<ipxact:portMaps>", indent(5));
+                    for member in &ports.ports {
+                        let _ = writeln!(text, "{}<ipxact:portMap>", indent(6));
+                        let _ = writeln!(text, "{}<ipxact:logicalPort>", indent(7));
+                        element(&mut text, 8, "name", &member.id.to_string());
+                        let _ = writeln!(text, "{}</ipxact:logicalPort>", indent(7));
+                        let _ = writeln!(text, "{}<ipxact:physicalPort>", indent(7));
+                        element(&mut text, 8, "name", &member.identifier.to_string());
+                        let _ = writeln!(text, "{}</ipxact:physicalPort>", indent(7));
+                        let _ = writeln!(text, "{}</ipxact:portMap>", indent(6));
+                    }
+                    let _ = writeln!(text, "{}</ipxact:portMaps>", indent(5));
+                    let _ = writeln!(text, "{}</ipxact:abstractionType>", indent(4));
+                    let _ = writeln!(text, "{}</ipxact:abstractionTypes>", indent(3));
+                }
+                interface_mode(&mut text, 3, &modport);
+                let _ = writeln!(text, "{}</ipxact:busInterface>", indent(2));
+            }
+        }
+
+        if !text.is_empty() {
+            let _ = writeln!(ret, "{}<ipxact:busInterfaces>", indent(1));
+            ret.push_str(&text);
+            let _ = writeln!(ret, "{}</ipxact:busInterfaces>", indent(1));
+        }
+    }
+
+    fn model(&self, ret: &mut String, symbol: &Symbol, property: &ModuleProperty, name: &str) {
+        let language = match self.build_opt.language {
+            Language::Verilog2005 => "verilog",
+            Language::Vhdl2008 => "vhdl",
+            _ => "systemverilog",
+        };
+
+        let _ = writeln!(ret, "{}<ipxact:model>", indent(1));
+        let _ = writeln!(ret, "{}<ipxact:views>", indent(2));
+        let _ = writeln!(ret, "{}<ipxact:view>", indent(3));
+        element(ret, 4, "name", "rtl");
+        element(ret, 4, "componentInstantiationRef", INSTANTIATION_NAME);
+        let _ = writeln!(ret, "{}</ipxact:view>", indent(3));
+        let _ = writeln!(ret, "{}</ipxact:views>", indent(2));
+
+        let _ = writeln!(ret, "{}<ipxact:instantiations>", indent(2));
+        let _ = writeln!(ret, "{}<ipxact:componentInstantiation>", indent(3));
+        element(ret, 4, "name", INSTANTIATION_NAME);
+        element(ret, 4, "language", language);
+        element(ret, 4, "moduleName", name);
+
+        let parameters = parameters(property);
+        if !parameters.is_empty() {
+            let _ = writeln!(ret, "{}<ipxact:moduleParameters>", indent(4));
+            for (name, value) in parameters {
+                let _ = writeln!(
+                    ret,
+                    r#"{}<ipxact:moduleParameter parameterId="{name}" resolve="user">"#,
+                    indent(5)
+                );
+                element(ret, 6, "name", &name);
+                element(ret, 6, "value", &value.to_string());
+                let _ = writeln!(ret, "{}</ipxact:moduleParameter>", indent(5));
+            }
+            let _ = writeln!(ret, "{}</ipxact:moduleParameters>", indent(4));
+        }
+
+        let _ = writeln!(ret, "{}<ipxact:fileSetRef>", indent(4));
+        element(ret, 5, "localName", FILE_SET_NAME);
+        let _ = writeln!(ret, "{}</ipxact:fileSetRef>", indent(4));
+        let _ = writeln!(ret, "{}</ipxact:componentInstantiation>", indent(3));
+        let _ = writeln!(ret, "{}</ipxact:instantiations>", indent(2));
+
+        let mut ports = Vec::new();
+        let token = VerylToken::new(symbol.token);
+        let table = ExpandedModportPortTable::create(
+            &property.ports,
+            &[],
+            &token,
+            &symbol.namespace,
+            true,
+            &self.symbol_context(),
+        );
+        for port in &property.ports {
+            if let Some(entry) = table.get(&port.token.token) {
+                if self.is_expanded(&port.token) {
+                    for member in entry.ports.iter().flat_map(|x| x.ports.iter()) {
+                        let namespace = type_namespace(&member.r#type, &symbol.namespace);
+                        self.push_port(
+                            &mut ports,
+                            &member.identifier.to_string(),
+                            member.direction,
+                            &member.r#type,
+                            &namespace,
+                        );
+                    }
+                }
+            } else {
+                let property = port.property();
+                self.push_port(
+                    &mut ports,
+                    &port.name().to_string(),
+                    property.direction,
+                    &property.r#type,
+                    &symbol.namespace,
+                );
+            }
+        }
+
+        if !ports.is_empty() {
+            let _ = writeln!(ret, "{}<ipxact:ports>", indent(2));
+            for port in ports {
+                ret.push_str(&port);
+            }
+            let _ = writeln!(ret, "{}</ipxact:ports>", indent(2));
+        }
+        let _ = writeln!(ret, "{}</ipxact:model>", indent(1));
+    }
+
+    fn push_port(
+        &self,
+        ports: &mut Vec<String>,
+        name: &str,
+        direction: SymDirection,
+        r#type: &SymType,
+        namespace: &Namespace,
+    ) {
+        let direction = match direction {
+            SymDirection::Input => "in",
+            SymDirection::Output => "out",
+            SymDirection::Inout => "inout",
+            _ => return,
+        };
+
+        let mut ret = String::new();
+        let _ = writeln!(ret, "{}<ipxact:port>", indent(3));
+        element(&mut ret, 4, "name", name);
+        let _ = writeln!(ret, "{}<ipxact:wire>", indent(4));
+        element(&mut ret, 5, "direction", direction);
+        if let Some(width) = type_width(r#type, namespace)
+            && (width > 1 || !r#type.width.is_empty())
+        {
+            let _ = writeln!(ret, "{}<ipxact:vectors>", indent(5));
+            range(&mut ret, 6, "vector", width - 1, 0);
+            let _ = writeln!(ret, "{}</ipxact:vectors>", indent(5));
+        }
+        let _ = writeln!(ret, "{}</ipxact:wire>", indent(4));
+        if !r#type.array.is_empty()
+            && let Some(array) = Evaluator::new(&[]).expression_list(&r#type.array)
+        {
+            let _ = writeln!(ret, "{}<ipxact:arrays>", indent(4));
+            for size in array {
+                range(&mut ret, 5, "array", 0, size.saturating_sub(1));
+            }
+            let _ = writeln!(ret, "{}</ipxact:arrays>", indent(4));
+        }
+        let _ = writeln!(ret, "{}</ipxact:port>", indent(3));
+        ports.push(ret);
+    }
+
+    fn file_sets(&self, ret: &mut String, files: &[PathBuf]) {
+        let file_type = match self.build_opt.language {
+            Language::Verilog2005 => "verilogSource",
+            Language::Vhdl2008 => "vhdlSource",
+            _ => "systemVerilogSource",
+        };
+
+        let _ = writeln!(ret, "{}<ipxact:fileSets>", indent(1));
+        let _ = writeln!(ret, "{}<ipxact:fileSet>", indent(2));
+        element(ret, 3, "name", FILE_SET_NAME);
+        for file in files {
+            let _ = writeln!(ret, "{}<ipxact:file>", indent(3));
+            element(ret, 4, "name", &self.relative_path(file));
+            element(ret, 4, "fileType", file_type);
+            let _ = writeln!(ret, "{}</ipxact:file>", indent(3));
+        }
+        let _ = writeln!(ret, "{}</ipxact:fileSet>", indent(2));
+        let _ = writeln!(ret, "{}</ipxact:fileSets>", indent(1));
+    }
+
+    /// Returns the path relative to the output directory of IP-XACT
+    fn relative_path(&self, path: &Path) -> String {
+        if let Ok(x) = path.relative_to(&self.ipxact_path) {
+            x.as_str().to_owned()
+        } else {
+            path.to_string_lossy().to_string()
+        }
+    }
+}
+
+/// Returns overridable parameters and their evaluated default values
+fn parameters(property: &ModuleProperty) -> Vec<(String, isize)> {
+    let mut ret = Vec::new();
+    for parameter in &property.parameters {
+        let property = parameter.property();
+        if property.kind.is_const() || matches!(property.r#type.kind, TypeKind::Type) {
+            continue;
+        }
+        if let Some(value) = Evaluator::new(&[]).expression(&property.value).get_value() {
+            ret.push((parameter.name.to_string(), value));
+        }
+    }
+    ret
+}
+
+fn type_width(r#type: &SymType, namespace: &Namespace) -> Option<usize> {
+    packed_layout::packed_width(r#type, namespace).or_else(|| {
+        Evaluator::new(&[])
+            .type_width(r#type.clone())
+            .map(|x| x.iter().product())
+    })
+}
+
+/// Returns the namespace where the user defined type should be resolved
+fn type_namespace(r#type: &SymType, namespace: &Namespace) -> Namespace {
+    if let TypeKind::UserDefined(x) = &r#type.kind
+        && let Some(path) = x.path.paths.first()
+        && let Some(namespace) = namespace_table::get(path.base.id)
+    {
+        namespace
+    } else {
+        namespace.clone()
+    }
+}
+
+/// Maps well-known modport names to interface modes, and the others to system mode
+fn interface_mode(ret: &mut String, depth: usize, modport: &str) {
+    match modport.to_lowercase().as_str() {
+        "master" | "manager" | "initiator" => {
+            let _ = writeln!(ret, "{}<ipxact:master/>", indent(depth));
+        }
+        "slave" | "subordinate" | "target" => {
+            let _ = writeln!(ret, "{}<ipxact:slave/>", indent(depth));
+        }
+        _ => {
+            let _ = writeln!(ret, "{}<ipxact:system>", indent(depth));
+            element(ret, depth + 1, "group", modport);
+            let _ = writeln!(ret, "{}</ipxact:system>", indent(depth));
+        }
+    }
+}
+
+fn range(ret: &mut String, depth: usize, name: &str, left: usize, right: usize) {
+    let _ = writeln!(ret, "{}<ipxact:{name}>", indent(depth));
+    element(ret, depth + 1, "left", &left.to_string());
+    element(ret, depth + 1, "right", &right.to_string());
+    let _ = writeln!(ret, "{}</ipxact:{name}>", indent(depth));
+}
+
+fn element(ret: &mut String, depth: usize, name: &str, value: &str) {
+    let _ = writeln!(
+        ret,
+        "{}<ipxact:{name}>{}</ipxact:{name}>",
+        indent(depth),
+        escape(value)
+    );
+}
+
+fn indent(depth: usize) -> String {
+    "  ".repeat(depth)
+}
+
+fn escape(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
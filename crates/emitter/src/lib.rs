@@ -1,5 +1,15 @@
+mod circt;
 pub mod emitter;
 mod expaneded_modport;
-pub use emitter::Emitter;
+mod exporter;
+mod flattened_port;
+mod ipxact;
+mod verilog2005;
+mod vhdl;
+pub use circt::CirctEmitter;
+pub use emitter::{EmittedUnit, Emitter};
+pub use exporter::{ExportedFile, Exporter};
+pub use ipxact::IpXactGenerator;
+pub use vhdl::VhdlEmitter;
 #[cfg(test)]
 mod tests;
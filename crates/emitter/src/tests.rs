@@ -1,7 +1,7 @@
-use crate::Emitter;
+use crate::{CirctEmitter, Emitter, Exporter, IpXactGenerator, VhdlEmitter};
 use std::path::PathBuf;
 use veryl_analyzer::{Analyzer, attribute_table, symbol_table};
-use veryl_metadata::{ClockType, Metadata, ResetType};
+use veryl_metadata::{AssertionGuard, ClockType, ExportLanguage, Metadata, ResetType};
 use veryl_parser::Parser;
 
 #[track_caller]
@@ -1790,6 +1790,174 @@ endmodule
     assert_eq!(ret, expect);
 }
 
+#[test]
+fn hashed_mangled_name_map() {
+    let code = r#"
+package PkgA::<A: u32, B: u32> {
+    const V: u32 = A + B;
+}
+module ModuleA {
+    function FuncA::<V: u32>() -> u32 {
+        return V;
+    }
+    let _a: u32 = FuncA::<PkgA::<0, 1>::V>();
+    let _b: u32 = FuncA::<PkgA::<2, 3>::V>();
+}
+module ModuleB::<W: u32> {}
+module ModuleC {
+    inst u: ModuleB::<4>;
+}
+"#;
+
+    symbol_table::clear();
+    attribute_table::clear();
+
+    let parser = Parser::parse(code, &"").unwrap();
+    let mut metadata: Metadata =
+        toml::from_str(&Metadata::create_default_toml("prj").unwrap()).unwrap();
+    metadata.build.hashed_mangled_name = true;
+    let analyzer = Analyzer::new(&metadata);
+
+    analyzer.analyze_pass1("prj", "", &parser.veryl);
+    Analyzer::analyze_post_pass1();
+    analyzer.analyze_pass2("prj", "", &parser.veryl);
+
+    let mut emitter = Emitter::new(
+        &metadata,
+        &PathBuf::from("test.veryl"),
+        &PathBuf::from("test.sv"),
+        &PathBuf::from("test.sv.map"),
+    );
+    emitter.emit("prj", &parser.veryl);
+
+    assert!(
+        emitter
+            .as_str()
+            .contains("prj___ModuleB__4cc6fe58e70583f2 u ();")
+    );
+
+    let ret: Vec<_> = emitter
+        .mangled_names()
+        .iter()
+        .map(|(x, y)| (x.as_str(), y.as_str()))
+        .collect();
+    assert_eq!(
+        ret,
+        vec![
+            (
+                "__FuncA__60bb904c8aa531ee",
+                "prj::ModuleA::FuncA::<PkgA::<2, 3>::V>"
+            ),
+            (
+                "__FuncA__79ec94ce8312c72e",
+                "prj::ModuleA::FuncA::<PkgA::<0, 1>::V>"
+            ),
+            ("prj___ModuleB__4cc6fe58e70583f2", "prj::ModuleB::<4>"),
+            ("prj___PkgA__3a1051091da88aab", "prj::PkgA::<0, 1>"),
+            ("prj___PkgA__f664fd1f65ea5829", "prj::PkgA::<2, 3>"),
+        ]
+    );
+}
+
+#[test]
+fn assertion() {
+    let code = r#"module ModuleA (
+    i_clk: input  clock,
+    i_rst: input  reset,
+    i_req: input  logic,
+    o_ack: output logic,
+) {
+    assign o_ack = i_req;
+
+    assert { i_req |-> o_ack }
+    assume req_known (i_clk, i_rst) { !$isunknown(i_req) }
+    cover (i_clk) { i_req |=> o_ack }
+
+    always_comb {
+        assert i_req == o_ack;
+    }
+}
+"#;
+
+    let expect = r#"module prj_ModuleA (
+    input  var logic i_clk,
+    input  var logic i_rst,
+    input  var logic i_req,
+    output var logic o_ack
+);
+    always_comb o_ack = i_req;
+
+    `ifndef SYNTHESIS
+    assert property (@(posedge i_clk) disable iff (!i_rst) i_req |-> o_ack);
+    `endif
+    `ifndef SYNTHESIS
+    req_known: assume property (@(posedge i_clk) disable iff (!i_rst) !$isunknown(i_req));
+    `endif
+    `ifndef SYNTHESIS
+    cover property (@(posedge i_clk) i_req |=> o_ack);
+    `endif
+
+    always_comb begin
+        `ifndef SYNTHESIS
+        assert (i_req == o_ack);
+        `endif
+    end
+endmodule
+//# sourceMappingURL=test.sv.map
+"#;
+
+    let metadata: Metadata =
+        toml::from_str(&Metadata::create_default_toml("prj").unwrap()).unwrap();
+
+    let ret = if cfg!(windows) {
+        emit(&metadata, code).replace("\r\n", "\n")
+    } else {
+        emit(&metadata, code)
+    };
+
+    assert_eq!(ret, expect);
+
+    let expect = r#"module prj_ModuleA (
+    input  var logic i_clk,
+    input  var logic i_rst,
+    input  var logic i_req,
+    output var logic o_ack
+);
+    always_comb o_ack = i_req;
+
+    `ifdef ASSERT_ON
+    assert property (@(posedge i_clk) disable iff (!i_rst) i_req |-> o_ack);
+    `endif
+    `ifdef ASSERT_ON
+    req_known: assume property (@(posedge i_clk) disable iff (!i_rst) !$isunknown(i_req));
+    `endif
+    `ifdef ASSERT_ON
+    cover property (@(posedge i_clk) i_req |=> o_ack);
+    `endif
+
+    always_comb begin
+        `ifdef ASSERT_ON
+        assert (i_req == o_ack);
+        `endif
+    end
+endmodule
+//# sourceMappingURL=test.sv.map
+"#;
+
+    let mut metadata = metadata;
+    metadata.build.assertion_guard = AssertionGuard::Ifdef {
+        name: "ASSERT_ON".to_string(),
+    };
+
+    let ret = if cfg!(windows) {
+        emit(&metadata, code).replace("\r\n", "\n")
+    } else {
+        emit(&metadata, code)
+    };
+
+    assert_eq!(ret, expect);
+}
+
 #[test]
 fn struct_member_as_generic_arg() {
     let code = r#"
@@ -2007,3 +2175,1260 @@ endmodule
     println!("ret\n{}exp\n{}", ret, expect);
     assert_eq!(ret, expect);
 }
+
+#[test]
+fn verilog2005() {
+    let code = r#"
+package PkgA {
+    const W: u32 = 8;
+    const V: u32 = W * 2;
+    enum State: logic<2> {
+        Idle,
+        Run = 2,
+    }
+}
+interface InterfaceA {
+    var data : logic<PkgA::W>;
+    var valid: logic;
+    modport master {
+        data : output,
+        valid: output,
+    }
+    modport slave {
+        data : input,
+        valid: input,
+    }
+}
+module ModuleA (
+    i_clk: input  clock,
+    i_rst: input  reset,
+    i_a  : input  logic<PkgA::W>,
+    o_b  : output logic<PkgA::V>,
+    p    : modport InterfaceA::master,
+) {
+    struct Pair {
+        hi: logic<4>,
+        lo: logic<4>,
+    }
+
+    var r: logic<PkgA::W>;
+    var s: Pair;
+    var st: PkgA::State;
+    let c: logic = r == 0;
+
+    always_ff {
+        if_reset {
+            r  = 0;
+            st = PkgA::State::Idle;
+        } else {
+            r  = i_a;
+            st = PkgA::State::Run;
+        }
+    }
+
+    always_comb {
+        s.hi = r[7:4];
+        s.lo = r[3:0];
+    }
+
+    assign o_b     = {s.lo, s.hi[3:0], r};
+    assign p.data  = r;
+    assign p.valid = c;
+}
+module ModuleB (
+    i_clk: input clock,
+    i_rst: input reset,
+) {
+    inst u_if: InterfaceA;
+    inst u_a: ModuleA (
+        i_clk          ,
+        i_rst          ,
+        i_a  : u_if.data,
+        o_b  : _        ,
+        p    : u_if     ,
+    );
+
+    for i in 0..2 :g {
+        let _x: logic = u_if.valid;
+    }
+}
+"#;
+
+    let expect = r#"module prj_ModuleA (
+    input  wire                 i_clk    ,
+    input  wire                 i_rst    ,
+    input  wire [(8)-1:0]       i_a      ,
+    output wire [((8) * 2)-1:0] o_b      ,
+    output wire [(8)-1:0]       __p_data ,
+    output wire                 __p_valid
+);
+    localparam [31:0] prj_PkgA_W = 8;
+    localparam [31:0] prj_PkgA_V = prj_PkgA_W * 2;
+    localparam [1:0] prj_PkgA_State_Idle = 2'd0;
+    localparam [1:0] prj_PkgA_State_Run = 2'd2;
+
+    reg  [prj_PkgA_W-1:0] r ;
+    reg  [7:0]            s ;
+    reg  [1:0]            st;
+    wire                  c ; assign c  = r == 0;
+
+    always @ (posedge i_clk, negedge i_rst) begin
+        if (!i_rst) begin
+            r  <= 0;
+            st <= prj_PkgA_State_Idle;
+        end else begin
+            r  <= i_a;
+            st <= prj_PkgA_State_Run;
+        end
+    end
+
+    always @* begin
+        s[7:4] = r[7:4];
+        s[3:0] = r[3:0];
+    end
+
+    assign o_b       = {s[3:0], s[4 + (3):4 + (0)], r};
+    assign __p_data  = r;
+    assign __p_valid = c;
+endmodule
+module prj_ModuleB (
+    input wire i_clk,
+    input wire i_rst
+);
+    wire [7:0] __u_if_data;
+    wire __u_if_valid;
+    prj_ModuleA u_a (
+        .i_clk     (i_clk       ),
+        .i_rst     (i_rst       ),
+        .i_a       (__u_if_data ),
+        .o_b       (            ),
+        .__p_data  (__u_if_data ),
+        .__p_valid (__u_if_valid)
+    );
+
+    genvar i;
+    for (i = 0; i < 2; i = i + 1) begin :g
+        wire _x; assign _x = __u_if_valid;
+    end
+endmodule
+"#;
+
+    let mut metadata: Metadata =
+        toml::from_str(&Metadata::create_default_toml("prj").unwrap()).unwrap();
+    metadata.build.language = veryl_metadata::Language::Verilog2005;
+    metadata.build.sourcemap_target = veryl_metadata::SourceMapTarget::None;
+
+    let ret = if cfg!(windows) {
+        emit(&metadata, code).replace("\r\n", "\n")
+    } else {
+        emit(&metadata, code)
+    };
+
+    println!("ret\n{}\nexp\n{}", ret, expect);
+    assert_eq!(ret, expect);
+}
+
+#[test]
+fn vhdl2008() {
+    let code = r#"
+package PkgA {
+    const W: u32 = 8;
+    struct Pair {
+        hi: logic<4>,
+        lo: logic<4>,
+    }
+    enum State: logic<2> {
+        Idle,
+        Run = 2,
+    }
+}
+module ModuleA #(
+    param N: u32 = 2,
+) (
+    i_clk: input  clock,
+    i_rst: input  reset,
+    i_a  : input  logic<PkgA::W>,
+    o_b  : output logic<PkgA::W>,
+    o_c  : output logic,
+) {
+    var r : logic<PkgA::W>;
+    var s : PkgA::Pair;
+    var st: PkgA::State;
+
+    always_ff {
+        if_reset {
+            r  = 0;
+            st = PkgA::State::Idle;
+        } else if i_a[0] {
+            r  = i_a + 1;
+            st = PkgA::State::Run;
+        }
+    }
+
+    always_comb {
+        s.hi = r[7:4];
+        s.lo = r[3:0];
+    }
+
+    assign o_b = {s.lo, s.hi};
+    assign o_c = if st == PkgA::State::Run ? r[N] : 0;
+}
+module ModuleB (
+    i_clk: input  clock,
+    i_rst: input  reset,
+    i_a  : input  logic<8>,
+) {
+    inst u_a: ModuleA #(
+        N: 3,
+    ) (
+        i_clk      ,
+        i_rst      ,
+        i_a        ,
+        o_b  : _   ,
+        o_c  : _   ,
+    );
+}
+"#;
+
+    let expect = r#"library ieee;
+use ieee.std_logic_1164.all;
+use ieee.numeric_std.all;
+use ieee.numeric_std_unsigned.all;
+use ieee.math_real.all;
+use work.all;
+
+package prj_PkgA is
+    constant W : natural := 8;
+    type Pair is record
+        hi : std_logic_vector(3 downto 0);
+        lo : std_logic_vector(3 downto 0);
+    end record Pair;
+    subtype State is std_logic_vector(1 downto 0);
+    constant State_Idle : State := 2d"0";
+    constant State_Run : State := 2d"2";
+end package prj_PkgA;
+
+library ieee;
+use ieee.std_logic_1164.all;
+use ieee.numeric_std.all;
+use ieee.numeric_std_unsigned.all;
+use ieee.math_real.all;
+use work.all;
+
+entity prj_ModuleA is
+    generic (
+        N : natural := 2
+    );
+    port (
+        i_clk : in    std_logic;
+        i_rst : in    std_logic;
+        i_a   : in    std_logic_vector(prj_PkgA.W - 1 downto 0);
+        o_b   : out   std_logic_vector(prj_PkgA.W - 1 downto 0);
+        o_c   : out   std_logic
+    );
+end entity prj_ModuleA;
+
+architecture rtl of prj_ModuleA is
+    signal r : std_logic_vector(prj_PkgA.W - 1 downto 0);
+    signal s : prj_PkgA.Pair;
+    signal st : prj_PkgA.State;
+begin
+    process (i_clk) is
+    begin
+        if rising_edge(i_clk) then
+            if i_rst = '1' then
+                r <= to_slv(0, r'length);
+                st <= prj_PkgA.State_Idle;
+            elsif i_a(0) then
+                r <= i_a + 1;
+                st <= prj_PkgA.State_Run;
+            end if;
+        end if;
+    end process;
+
+    process (all) is
+    begin
+        s.hi <= r(7 downto 4);
+        s.lo <= r(3 downto 0);
+    end process;
+
+    o_b <= (s.lo & s.hi);
+    o_c <= r(N) when st ?= prj_PkgA.State_Run else '0';
+end architecture rtl;
+
+library ieee;
+use ieee.std_logic_1164.all;
+use ieee.numeric_std.all;
+use ieee.numeric_std_unsigned.all;
+use ieee.math_real.all;
+use work.all;
+
+entity prj_ModuleB is
+    port (
+        i_clk : in    std_logic;
+        i_rst : in    std_logic;
+        i_a   : in    std_logic_vector(7 downto 0)
+    );
+end entity prj_ModuleB;
+
+architecture rtl of prj_ModuleB is
+begin
+    u_a: entity work.prj_ModuleA
+        generic map (
+            N => 3
+        )
+        port map (
+            i_clk => i_clk,
+            i_rst => i_rst,
+            i_a   => i_a,
+            o_b   => open,
+            o_c   => open
+        );
+end architecture rtl;
+"#;
+
+    let mut metadata: Metadata =
+        toml::from_str(&Metadata::create_default_toml("prj").unwrap()).unwrap();
+    metadata.build.language = veryl_metadata::Language::Vhdl2008;
+    metadata.build.clock_type = ClockType::PosEdge;
+    metadata.build.reset_type = ResetType::SyncHigh;
+    metadata.build.sourcemap_target = veryl_metadata::SourceMapTarget::None;
+
+    symbol_table::clear();
+    attribute_table::clear();
+
+    let parser = Parser::parse(code, &"").unwrap();
+    let analyzer = Analyzer::new(&metadata);
+
+    analyzer.analyze_pass1("prj", "", &parser.veryl);
+    Analyzer::analyze_post_pass1();
+    analyzer.analyze_pass2("prj", "", &parser.veryl);
+
+    let mut emitter = VhdlEmitter::new(
+        &metadata,
+        &PathBuf::from("test.veryl"),
+        &PathBuf::from("test.vhd"),
+        &PathBuf::from("test.vhd.map"),
+    );
+    emitter.emit("prj", &parser.veryl);
+
+    let ret = if cfg!(windows) {
+        emitter.as_str().replace("\r\n", "\n")
+    } else {
+        emitter.as_str().to_string()
+    };
+
+    println!("ret\n{}\nexp\n{}", ret, expect);
+    assert_eq!(ret, expect);
+}
+
+#[test]
+fn vhdl2008_case_clash() {
+    let code = r#"
+package PkgA {
+    const Z: u32 = 1;
+}
+module ModuleA (
+    o_z: output logic,
+) {
+    import PkgA::*;
+
+    var z: logic<2>;
+    var y: logic;
+
+    assign z   = Z;
+    assign y   = 1;
+    assign o_z = z[0] & y;
+}
+"#;
+
+    let expect = r#"library ieee;
+use ieee.std_logic_1164.all;
+use ieee.numeric_std.all;
+use ieee.numeric_std_unsigned.all;
+use ieee.math_real.all;
+use work.all;
+
+package prj_PkgA is
+    constant \Z\ : natural := 1;
+end package prj_PkgA;
+
+library ieee;
+use ieee.std_logic_1164.all;
+use ieee.numeric_std.all;
+use ieee.numeric_std_unsigned.all;
+use ieee.math_real.all;
+use work.all;
+use work.prj_PkgA.all;
+
+entity prj_ModuleA is
+    port (
+        o_z : out   std_logic
+    );
+end entity prj_ModuleA;
+
+architecture rtl of prj_ModuleA is
+    signal \z\ : std_logic_vector(1 downto 0);
+    signal y : std_logic;
+begin
+    \z\ <= to_slv(\Z\, \z\'length);
+    y <= '1';
+    o_z <= \z\(0) and y;
+end architecture rtl;
+"#;
+
+    let mut metadata: Metadata =
+        toml::from_str(&Metadata::create_default_toml("prj").unwrap()).unwrap();
+    metadata.build.language = veryl_metadata::Language::Vhdl2008;
+    metadata.build.sourcemap_target = veryl_metadata::SourceMapTarget::None;
+
+    symbol_table::clear();
+    attribute_table::clear();
+
+    let parser = Parser::parse(code, &"").unwrap();
+    let analyzer = Analyzer::new(&metadata);
+
+    analyzer.analyze_pass1("prj", "", &parser.veryl);
+    Analyzer::analyze_post_pass1();
+    analyzer.analyze_pass2("prj", "", &parser.veryl);
+
+    let mut emitter = VhdlEmitter::new(
+        &metadata,
+        &PathBuf::from("test.veryl"),
+        &PathBuf::from("test.vhd"),
+        &PathBuf::from("test.vhd.map"),
+    );
+    emitter.emit("prj", &parser.veryl);
+
+    let ret = if cfg!(windows) {
+        emitter.as_str().replace("\r\n", "\n")
+    } else {
+        emitter.as_str().to_string()
+    };
+
+    println!("ret\n{}\nexp\n{}", ret, expect);
+    assert_eq!(ret, expect);
+}
+
+#[test]
+fn circt() {
+    let code = r#"module ModuleA #(
+    param N: u32 = 4,
+) (
+    i_clk: input  clock       ,
+    i_rst: input  reset       ,
+    i_a  : input  logic<N>    ,
+    i_sel: input  logic<2>    ,
+    o_b  : output logic<N>    ,
+    o_c  : output logic       ,
+    o_d  : output logic<N + 1>,
+) {
+    var r: logic<N>;
+
+    always_ff {
+        if_reset {
+            r = 0;
+        } else {
+            r += i_a;
+        }
+    }
+
+    always_comb {
+        o_c = 0;
+        case i_sel {
+            0      : o_c = r[0];
+            1, 2   : o_c = r[N - 1];
+            default: o_c = ^r;
+        }
+    }
+
+    assign o_b = r;
+    assign o_d = {1'b0, r} + 1;
+}
+
+module ModuleB (
+    i_clk: input  clock   ,
+    i_rst: input  reset   ,
+    i_a  : input  logic<3>,
+    o_b  : output logic<3>,
+) {
+    inst u_a: ModuleA #(
+        N: 3,
+    ) (
+        i_clk         ,
+        i_rst         ,
+        i_a           ,
+        i_sel: 2'd1   ,
+        o_b           ,
+        o_c  : _      ,
+        o_d  : _      ,
+    );
+}
+"#;
+
+    let expect = r#"hw.module @prj_ModuleA(in %i_clk : !seq.clock, in %i_rst : i1, in %i_a : i4, in %i_sel : i2, out o_b : i4, out o_c : i1, out o_d : i5) {
+  %0 = hw.constant true loc("test.veryl":14:5)
+  %1 = comb.xor %i_rst, %0 : i1 loc("test.veryl":14:5)
+  %2 = comb.add %r, %i_a : i4 loc("test.veryl":18:15)
+  %3 = hw.constant 0 : i4 loc("test.veryl":14:5)
+  %r = seq.firreg %2 clock %i_clk reset async %1, %3 : i4 loc("test.veryl":14:5)
+  %5 = hw.constant 0 : i30 loc("test.veryl":24:9)
+  %6 = comb.concat %5, %i_sel : i30, i2 loc("test.veryl":24:9)
+  %7 = hw.constant 0 : i32 loc("test.veryl":24:9)
+  %8 = comb.icmp eq %6, %7 : i32 loc("test.veryl":24:9)
+  %9 = hw.constant 1 : i32 loc("test.veryl":24:9)
+  %10 = comb.icmp eq %6, %9 : i32 loc("test.veryl":24:9)
+  %11 = hw.constant 2 : i32 loc("test.veryl":24:9)
+  %12 = comb.icmp eq %6, %11 : i32 loc("test.veryl":24:9)
+  %13 = comb.or %10, %12 : i1 loc("test.veryl":24:9)
+  %14 = comb.extract %r from 0 : (i4) -> i1 loc("test.veryl":25:26)
+  %15 = comb.extract %r from 3 : (i4) -> i1 loc("test.veryl":26:26)
+  %16 = comb.parity %r : i4 loc("test.veryl":27:28)
+  %17 = comb.mux %13, %15, %16 : i1 loc("test.veryl":27:28)
+  %18 = comb.mux %8, %14, %17 : i1 loc("test.veryl":27:28)
+  %19 = hw.constant false loc("test.veryl":32:28)
+  %20 = comb.concat %19, %r : i1, i4 loc("test.veryl":32:28)
+  %21 = hw.constant 0 : i27 loc("test.veryl":32:28)
+  %22 = comb.concat %21, %20 : i27, i5 loc("test.veryl":32:28)
+  %23 = comb.add %22, %9 : i32 loc("test.veryl":32:28)
+  %24 = comb.extract %23 from 0 : (i32) -> i5 loc("test.veryl":32:28)
+  %o_b = hw.wire %r : i4 loc("test.veryl":8:5)
+  %o_c = hw.wire %18 : i1 loc("test.veryl":9:5)
+  %o_d = hw.wire %24 : i5 loc("test.veryl":10:5)
+  hw.output %o_b, %o_c, %o_d : i4, i1, i5 loc("test.veryl":33:1)
+} loc("test.veryl":1:1)
+
+hw.module @prj_ModuleB(in %i_clk : !seq.clock, in %i_rst : i1, in %i_a : i3, out o_b : i3) {
+  %0 = hw.constant 1 : i2 loc("test.veryl":47:9)
+  %u_a.o_b, %u_a.o_c, %u_a.o_d = hw.instance "u_a" @prj_ModuleA__prj_ModuleB_0(i_clk: %i_clk: !seq.clock, i_rst: %i_rst: i1, i_a: %i_a: i3, i_sel: %0: i2) -> (o_b: i3, o_c: i1, o_d: i4) loc("test.veryl":41:10)
+  %o_b = hw.wire %u_a.o_b : i3 loc("test.veryl":39:5)
+  hw.output %o_b : i3 loc("test.veryl":52:1)
+} loc("test.veryl":35:1)
+
+hw.module @prj_ModuleA__prj_ModuleB_0(in %i_clk : !seq.clock, in %i_rst : i1, in %i_a : i3, in %i_sel : i2, out o_b : i3, out o_c : i1, out o_d : i4) {
+  %0 = hw.constant true loc("test.veryl":14:5)
+  %1 = comb.xor %i_rst, %0 : i1 loc("test.veryl":14:5)
+  %2 = comb.add %r, %i_a : i3 loc("test.veryl":18:15)
+  %3 = hw.constant 0 : i3 loc("test.veryl":14:5)
+  %r = seq.firreg %2 clock %i_clk reset async %1, %3 : i3 loc("test.veryl":14:5)
+  %5 = hw.constant 0 : i30 loc("test.veryl":24:9)
+  %6 = comb.concat %5, %i_sel : i30, i2 loc("test.veryl":24:9)
+  %7 = hw.constant 0 : i32 loc("test.veryl":24:9)
+  %8 = comb.icmp eq %6, %7 : i32 loc("test.veryl":24:9)
+  %9 = hw.constant 1 : i32 loc("test.veryl":24:9)
+  %10 = comb.icmp eq %6, %9 : i32 loc("test.veryl":24:9)
+  %11 = hw.constant 2 : i32 loc("test.veryl":24:9)
+  %12 = comb.icmp eq %6, %11 : i32 loc("test.veryl":24:9)
+  %13 = comb.or %10, %12 : i1 loc("test.veryl":24:9)
+  %14 = comb.extract %r from 0 : (i3) -> i1 loc("test.veryl":25:26)
+  %15 = comb.extract %r from 2 : (i3) -> i1 loc("test.veryl":26:26)
+  %16 = comb.parity %r : i3 loc("test.veryl":27:28)
+  %17 = comb.mux %13, %15, %16 : i1 loc("test.veryl":27:28)
+  %18 = comb.mux %8, %14, %17 : i1 loc("test.veryl":27:28)
+  %19 = hw.constant false loc("test.veryl":32:28)
+  %20 = comb.concat %19, %r : i1, i3 loc("test.veryl":32:28)
+  %21 = hw.constant 0 : i28 loc("test.veryl":32:28)
+  %22 = comb.concat %21, %20 : i28, i4 loc("test.veryl":32:28)
+  %23 = comb.add %22, %9 : i32 loc("test.veryl":32:28)
+  %24 = comb.extract %23 from 0 : (i32) -> i4 loc("test.veryl":32:28)
+  %o_b = hw.wire %r : i3 loc("test.veryl":8:5)
+  %o_c = hw.wire %18 : i1 loc("test.veryl":9:5)
+  %o_d = hw.wire %24 : i4 loc("test.veryl":10:5)
+  hw.output %o_b, %o_c, %o_d : i3, i1, i4 loc("test.veryl":33:1)
+} loc("test.veryl":1:1)
+"#;
+
+    let mut metadata: Metadata =
+        toml::from_str(&Metadata::create_default_toml("prj").unwrap()).unwrap();
+    metadata.build.language = veryl_metadata::Language::Circt;
+    metadata.build.clock_type = ClockType::PosEdge;
+    metadata.build.reset_type = ResetType::AsyncLow;
+
+    symbol_table::clear();
+    attribute_table::clear();
+
+    let parser = Parser::parse(code, &"test.veryl").unwrap();
+    let analyzer = Analyzer::new(&metadata);
+
+    analyzer.analyze_pass1("prj", "test.veryl", &parser.veryl);
+    Analyzer::analyze_post_pass1();
+    analyzer.analyze_pass2("prj", "test.veryl", &parser.veryl);
+
+    let mut emitter = CirctEmitter::new(
+        &metadata,
+        &PathBuf::from("test.veryl"),
+        &PathBuf::from("test.mlir"),
+        &PathBuf::from("test.mlir.map"),
+    );
+    emitter.emit("prj", &parser.veryl);
+
+    let ret = if cfg!(windows) {
+        emitter.as_str().replace("\r\n", "\n")
+    } else {
+        emitter.as_str().to_string()
+    };
+
+    println!("ret\n{}\nexp\n{}", ret, expect);
+    assert_eq!(ret, expect);
+}
+
+#[test]
+fn flatten_ports() {
+    let code = r#"
+package PkgA {
+    struct Inner {
+        x: logic<2>,
+        y: logic   ,
+    }
+    struct Outer {
+        a: logic<4>,
+        b: Inner   ,
+    }
+}
+interface InterfaceA {
+    var ready: logic       ;
+    var valid: logic       ;
+    var data : PkgA::Inner ;
+
+    modport master {
+        ready: input ,
+        valid: output,
+        data : output,
+    }
+
+    modport slave {
+        ..converse(master)
+    }
+}
+#[flatten]
+module ModuleA #(
+    param WIDTH: u32 = 8,
+) (
+    i_clk : input  clock                 ,
+    i_data: input  PkgA::Outer           ,
+    o_data: output logic<WIDTH>          ,
+    m_if  : modport InterfaceA::master[2],
+) {
+    assign o_data = 0;
+    for i in 0..2 :g {
+        assign m_if[i].valid = 0;
+        assign m_if[i].data  = 0;
+    }
+}
+#[flatten(ModuleBWrapper, "__")]
+#[expand(modport)]
+module ModuleB (
+    s_if: modport InterfaceA::slave,
+) {
+    assign s_if.ready = 0;
+}
+"#;
+
+    let expect = r#"package prj_PkgA;
+    typedef struct packed {
+        logic [2-1:0] x;
+        logic         y;
+    } Inner;
+    typedef struct packed {
+        logic [4-1:0] a;
+        Inner         b;
+    } Outer;
+endpackage
+interface prj_InterfaceA;
+    logic           ready;
+    logic           valid;
+    prj_PkgA::Inner data ;
+
+    modport master (
+        input  ready,
+        output valid,
+        output data 
+    );
+
+    modport slave (
+        output ready,
+        input  valid,
+        input  data 
+    );
+endinterface
+
+module prj_ModuleA #(
+    parameter int unsigned WIDTH = 8
+) (
+    input  var logic                         i_clk         ,
+    input  var prj_PkgA::Outer               i_data        ,
+    output var logic             [WIDTH-1:0] o_data        ,
+    prj_InterfaceA.master             m_if   [0:2-1]
+);
+    always_comb o_data = 0;
+    for (genvar i = 0; i < 2; i++) begin :g
+        always_comb m_if[i].valid = 0;
+        always_comb m_if[i].data  = 0;
+    end
+endmodule
+
+module prj_ModuleA_flat #(
+    parameter int unsigned WIDTH = 8
+) (
+    input  var logic             i_clk        ,
+    input  var logic [4-1:0]     i_data_a     ,
+    input  var logic [2-1:0]     i_data_b_x   ,
+    input  var logic             i_data_b_y   ,
+    output var logic [WIDTH-1:0] o_data       ,
+    input  var logic             m_if_0_ready ,
+    output var logic             m_if_0_valid ,
+    output var logic [2-1:0]     m_if_0_data_x,
+    output var logic             m_if_0_data_y,
+    input  var logic             m_if_1_ready ,
+    output var logic             m_if_1_valid ,
+    output var logic [2-1:0]     m_if_1_data_x,
+    output var logic             m_if_1_data_y
+);
+    prj_InterfaceA m_if [0:2-1] ();
+    assign m_if[0].ready                  = m_if_0_ready ;
+    assign m_if_0_valid                   = m_if[0].valid;
+    assign {m_if_0_data_x, m_if_0_data_y} = m_if[0].data ;
+    assign m_if[1].ready                  = m_if_1_ready ;
+    assign m_if_1_valid                   = m_if[1].valid;
+    assign {m_if_1_data_x, m_if_1_data_y} = m_if[1].data ;
+    prj_ModuleA #(
+        .WIDTH (WIDTH)
+    ) u (
+        .i_clk  (i_clk                             ),
+        .i_data ({i_data_a, i_data_b_x, i_data_b_y}),
+        .o_data (o_data                            ),
+        .m_if   (m_if                              )
+    );
+endmodule
+
+module prj_ModuleB (
+    output var logic           __s_if_ready,
+    input  var logic           __s_if_valid,
+    input  var prj_PkgA::Inner __s_if_data 
+);
+    prj_InterfaceA s_if ();
+    always_comb begin
+        __s_if_ready = s_if.ready  ;
+        s_if.valid   = __s_if_valid;
+        s_if.data    = __s_if_data ;
+    end
+    always_comb s_if.ready = 0;
+endmodule
+
+module prj_ModuleBWrapper (
+    output var logic         s_if__ready  ,
+    input  var logic         s_if__valid  ,
+    input  var logic [2-1:0] s_if__data__x,
+    input  var logic         s_if__data__y
+);
+    prj_ModuleB u (
+        .__s_if_ready (s_if__ready                   ),
+        .__s_if_valid (s_if__valid                   ),
+        .__s_if_data  ({s_if__data__x, s_if__data__y})
+    );
+endmodule
+//# sourceMappingURL=test.sv.map
+"#;
+
+    let metadata: Metadata =
+        toml::from_str(&Metadata::create_default_toml("prj").unwrap()).unwrap();
+
+    let ret = if cfg!(windows) {
+        emit(&metadata, code).replace("\r\n", "\n")
+    } else {
+        emit(&metadata, code)
+    };
+
+    assert_eq!(ret, expect);
+}
+
+#[test]
+fn export() {
+    let code = r#"
+package PkgA {
+    const WIDTH: u32 = 8;
+    const DEPTH: u32 = WIDTH * 4;
+
+    #[enum_encoding(onehot)]
+    enum State {
+        IDLE,
+        BUSY,
+        DONE,
+    }
+
+    enum Command: logic<2> {
+        WRITE = 2'd1,
+        READ = 2'd2,
+    }
+
+    struct Header {
+        valid: logic        ,
+        cmd  : Command      ,
+        addr : logic<WIDTH> ,
+    }
+}
+"#;
+
+    symbol_table::clear();
+    attribute_table::clear();
+
+    let parser = Parser::parse(code, &"").unwrap();
+    let mut metadata: Metadata =
+        toml::from_str(&Metadata::create_default_toml("prj").unwrap()).unwrap();
+    metadata.export.languages = vec![
+        ExportLanguage::C,
+        ExportLanguage::Python,
+        ExportLanguage::Rust,
+    ];
+    let analyzer = Analyzer::new(&metadata);
+
+    analyzer.analyze_pass1("prj", "", &parser.veryl);
+    Analyzer::analyze_post_pass1();
+    analyzer.analyze_pass2("prj", "", &parser.veryl);
+
+    let exporter = Exporter::new(&metadata);
+    let files = exporter.export();
+
+    let c_expect = r#"// Generated from package PkgA by Veryl. DO NOT EDIT.
+#ifndef PRJ_PKG_A_H
+#define PRJ_PKG_A_H
+
+#define PKG_A_WIDTH 8
+#define PKG_A_DEPTH 32
+
+// enum State
+#define PKG_A_STATE_WIDTH 3
+#define PKG_A_STATE_IDLE 1
+#define PKG_A_STATE_BUSY 2
+#define PKG_A_STATE_DONE 4
+
+// enum Command
+#define PKG_A_COMMAND_WIDTH 2
+#define PKG_A_COMMAND_WRITE 1
+#define PKG_A_COMMAND_READ 2
+
+// struct Header
+#define PKG_A_HEADER_WIDTH 11
+#define PKG_A_HEADER_VALID_LSB 10
+#define PKG_A_HEADER_VALID_WIDTH 1
+#define PKG_A_HEADER_VALID_MASK 0x400U
+#define PKG_A_HEADER_CMD_LSB 8
+#define PKG_A_HEADER_CMD_WIDTH 2
+#define PKG_A_HEADER_CMD_MASK 0x300U
+#define PKG_A_HEADER_ADDR_LSB 0
+#define PKG_A_HEADER_ADDR_WIDTH 8
+#define PKG_A_HEADER_ADDR_MASK 0xffU
+
+#endif
+"#;
+
+    let python_expect = r#"# Generated from package PkgA by Veryl. DO NOT EDIT.
+import enum
+
+WIDTH = 8
+DEPTH = 32
+
+
+class State(enum.IntEnum):
+    IDLE = 1
+    BUSY = 2
+    DONE = 4
+
+
+class Command(enum.IntEnum):
+    WRITE = 1
+    READ = 2
+
+
+class Header:
+    WIDTH = 11
+    VALID_LSB = 10
+    VALID_WIDTH = 1
+    VALID_MASK = 0x400
+    CMD_LSB = 8
+    CMD_WIDTH = 2
+    CMD_MASK = 0x300
+    ADDR_LSB = 0
+    ADDR_WIDTH = 8
+    ADDR_MASK = 0xff
+"#;
+
+    let rust_expect = r#"// Generated from package PkgA by Veryl. DO NOT EDIT.
+
+pub const WIDTH: u32 = 8;
+pub const DEPTH: u32 = 32;
+
+#[allow(non_camel_case_types)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[repr(u8)]
+pub enum State {
+    IDLE = 1,
+    BUSY = 2,
+    DONE = 4,
+}
+
+#[allow(non_camel_case_types)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[repr(u8)]
+pub enum Command {
+    WRITE = 1,
+    READ = 2,
+}
+
+pub mod header {
+    pub const WIDTH: usize = 11;
+    pub const VALID_LSB: usize = 10;
+    pub const VALID_WIDTH: usize = 1;
+    pub const VALID_MASK: u16 = 0x400;
+    pub const CMD_LSB: usize = 8;
+    pub const CMD_WIDTH: usize = 2;
+    pub const CMD_MASK: u16 = 0x300;
+    pub const ADDR_LSB: usize = 0;
+    pub const ADDR_WIDTH: usize = 8;
+    pub const ADDR_MASK: u16 = 0xff;
+}
+"#;
+
+    assert_eq!(files[0].name, "pkg_a.h");
+    assert_eq!(files[0].text, c_expect);
+    assert_eq!(files[1].name, "pkg_a.py");
+    assert_eq!(files[1].text, python_expect);
+    assert_eq!(files[2].name, "pkg_a.rs");
+    assert_eq!(files[2].text, rust_expect);
+}
+
+#[test]
+fn ipxact() {
+    let code = r#"
+interface Bus {
+    var valid: logic   ;
+    var data : logic<8>;
+    modport master {
+        valid: output,
+        data : output,
+    }
+}
+
+module Sub (
+    i_clk: input clock,
+) {}
+
+module Top #(
+    param WIDTH: u32 = 4 * 2,
+    const DEPTH: u32 = 3,
+) (
+    i_clk : input   clock               ,
+    o_data: output  logic<WIDTH> [DEPTH],
+    #[expand(modport)]
+    bus_m : modport Bus::master         ,
+) {
+    inst u: Sub (i_clk);
+    assign bus_m.valid = 0;
+    assign bus_m.data  = 0;
+    assign o_data      = '{0, 0, 0};
+}
+"#;
+
+    symbol_table::clear();
+    attribute_table::clear();
+
+    let parser = Parser::parse(code, &"").unwrap();
+    let mut metadata: Metadata =
+        toml::from_str(&Metadata::create_default_toml("prj").unwrap()).unwrap();
+    metadata.metadata_path = PathBuf::from("/prj/Veryl.toml");
+    metadata.ipxact.vendor = "example.com".to_string();
+    let analyzer = Analyzer::new(&metadata);
+
+    analyzer.analyze_pass1("prj", "", &parser.veryl);
+    Analyzer::analyze_post_pass1();
+    analyzer.analyze_pass2("prj", "", &parser.veryl);
+
+    let generator = IpXactGenerator::new(&metadata);
+    let files = generator.generate(&[PathBuf::from("/prj/target/test.sv")]);
+
+    let expect = r#"<?xml version="1.0" encoding="UTF-8"?>
+<!-- Generated from module Top by Veryl. DO NOT EDIT. -->
+<ipxact:component xmlns:ipxact="http://www.accellera.org/XMLSchema/IPXACT/1685-2014" xmlns:xsi="http://www.w3.org/2001/XMLSchema-instance" xsi:schemaLocation="http://www.accellera.org/XMLSchema/IPXACT/1685-2014 http://www.accellera.org/XMLSchema/IPXACT/1685-2014/index.xsd">
+  <ipxact:vendor>example.com</ipxact:vendor>
+  <ipxact:library>prj</ipxact:library>
+  <ipxact:name>prj_Top</ipxact:name>
+  <ipxact:version>1.0</ipxact:version>
+  <ipxact:busInterfaces>
+    <ipxact:busInterface>
+      <ipxact:name>bus_m</ipxact:name>
+      <ipxact:busType vendor="example.com" library="prj" name="prj_Bus" version="1.0"/>
+      <ipxact:abstractionTypes>
+        <ipxact:abstractionType>
+          <ipxact:abstractionRef vendor="example.com" library="prj" name="prj_Bus_rtl" version="1.0"/>
+          <ipxact:portMaps>
+            <ipxact:portMap>
+              <ipxact:logicalPort>
+                <ipxact:name>valid</ipxact:name>
+              </ipxact:logicalPort>
+              <ipxact:physicalPort>
+                <ipxact:name>__bus_m_valid</ipxact:name>
+              </ipxact:physicalPort>
+            </ipxact:portMap>
+            <ipxact:portMap>
+              <ipxact:logicalPort>
+                <ipxact:name>data</ipxact:name>
+              </ipxact:logicalPort>
+              <ipxact:physicalPort>
+                <ipxact:name>__bus_m_data</ipxact:name>
+              </ipxact:physicalPort>
+            </ipxact:portMap>
+          </ipxact:portMaps>
+        </ipxact:abstractionType>
+      </ipxact:abstractionTypes>
+      <ipxact:master/>
+    </ipxact:busInterface>
+  </ipxact:busInterfaces>
+  <ipxact:model>
+    <ipxact:views>
+      <ipxact:view>
+        <ipxact:name>rtl</ipxact:name>
+        <ipxact:componentInstantiationRef>rtl_implementation</ipxact:componentInstantiationRef>
+      </ipxact:view>
+    </ipxact:views>
+    <ipxact:instantiations>
+      <ipxact:componentInstantiation>
+        <ipxact:name>rtl_implementation</ipxact:name>
+        <ipxact:language>systemverilog</ipxact:language>
+        <ipxact:moduleName>prj_Top</ipxact:moduleName>
+        <ipxact:moduleParameters>
+          <ipxact:moduleParameter parameterId="WIDTH" resolve="user">
+            <ipxact:name>WIDTH</ipxact:name>
+            <ipxact:value>8</ipxact:value>
+          </ipxact:moduleParameter>
+        </ipxact:moduleParameters>
+        <ipxact:fileSetRef>
+          <ipxact:localName>fs-rtl</ipxact:localName>
+        </ipxact:fileSetRef>
+      </ipxact:componentInstantiation>
+    </ipxact:instantiations>
+    <ipxact:ports>
+      <ipxact:port>
+        <ipxact:name>i_clk</ipxact:name>
+        <ipxact:wire>
+          <ipxact:direction>in</ipxact:direction>
+        </ipxact:wire>
+      </ipxact:port>
+      <ipxact:port>
+        <ipxact:name>o_data</ipxact:name>
+        <ipxact:wire>
+          <ipxact:direction>out</ipxact:direction>
+          <ipxact:vectors>
+            <ipxact:vector>
+              <ipxact:left>7</ipxact:left>
+              <ipxact:right>0</ipxact:right>
+            </ipxact:vector>
+          </ipxact:vectors>
+        </ipxact:wire>
+        <ipxact:arrays>
+          <ipxact:array>
+            <ipxact:left>0</ipxact:left>
+            <ipxact:right>2</ipxact:right>
+          </ipxact:array>
+        </ipxact:arrays>
+      </ipxact:port>
+      <ipxact:port>
+        <ipxact:name>__bus_m_valid</ipxact:name>
+        <ipxact:wire>
+          <ipxact:direction>out</ipxact:direction>
+        </ipxact:wire>
+      </ipxact:port>
+      <ipxact:port>
+        <ipxact:name>__bus_m_data</ipxact:name>
+        <ipxact:wire>
+          <ipxact:direction>out</ipxact:direction>
+          <ipxact:vectors>
+            <ipxact:vector>
+              <ipxact:left>7</ipxact:left>
+              <ipxact:right>0</ipxact:right>
+            </ipxact:vector>
+          </ipxact:vectors>
+        </ipxact:wire>
+      </ipxact:port>
+    </ipxact:ports>
+  </ipxact:model>
+  <ipxact:fileSets>
+    <ipxact:fileSet>
+      <ipxact:name>fs-rtl</ipxact:name>
+      <ipxact:file>
+        <ipxact:name>../target/test.sv</ipxact:name>
+        <ipxact:fileType>systemVerilogSource</ipxact:fileType>
+      </ipxact:file>
+    </ipxact:fileSet>
+  </ipxact:fileSets>
+</ipxact:component>
+"#;
+
+    assert_eq!(files.len(), 1);
+    assert_eq!(files[0].name, "prj_Top.xml");
+    assert_eq!(files[0].text, expect);
+}
+
+#[test]
+fn units() {
+    let code = r#"
+package PkgA {
+    type word_t = logic<8>;
+}
+module ModuleA::<W: u32> (
+    o: output logic<W>,
+) {
+    assign o = 0;
+}
+#[ifdef(DEFINE_A)]
+module ModuleB {
+    inst u0: ModuleA::<8> (o: _);
+    inst u1: ModuleA::<4> (o: _);
+}
+#[else]
+#[sv("keep_hierarchy")]
+module ModuleC (
+    o: output PkgA::word_t,
+) {
+    assign o = 0;
+}
+"#;
+
+    symbol_table::clear();
+    attribute_table::clear();
+
+    let parser = Parser::parse(code, &"").unwrap();
+    let metadata: Metadata =
+        toml::from_str(&Metadata::create_default_toml("prj").unwrap()).unwrap();
+    let analyzer = Analyzer::new(&metadata);
+
+    analyzer.analyze_pass1("prj", "", &parser.veryl);
+    Analyzer::analyze_post_pass1();
+    analyzer.analyze_pass2("prj", "", &parser.veryl);
+
+    let mut emitter = Emitter::new(
+        &metadata,
+        &PathBuf::from("test.veryl"),
+        &PathBuf::from("test.sv"),
+        &PathBuf::from("test.sv.map"),
+    );
+    emitter.emit("prj", &parser.veryl);
+    let units = emitter.units();
+
+    let names: Vec<_> = units.iter().map(|x| x.name.as_str()).collect();
+    assert_eq!(
+        names,
+        [
+            "prj_PkgA",
+            "prj___ModuleA__8",
+            "prj___ModuleA__4",
+            "prj_ModuleB",
+            "prj_ModuleC"
+        ]
+    );
+
+    let expect = r#"module prj___ModuleA__4 (
+    output var logic [4-1:0] o
+);
+    always_comb o = 0;
+endmodule
+"#;
+    assert_eq!(units[2].text, expect);
+
+    let expect = r#"`ifdef DEFINE_A
+module prj_ModuleB;
+    prj___ModuleA__8 u0 (
+        .o ()
+    );
+    prj___ModuleA__4 u1 (
+        .o ()
+    );
+endmodule
+`endif
+"#;
+    assert_eq!(units[3].text, expect);
+
+    let expect = r#"`ifdef DEFINE_A
+`else
+(* keep_hierarchy *)
+module prj_ModuleC (
+    output var prj_PkgA::word_t o
+);
+    always_comb o = 0;
+endmodule
+`endif
+"#;
+    assert_eq!(units[4].text, expect);
+    assert_eq!(units[4].line, 4);
+    assert_eq!(units[4].lines.len(), 5);
+    assert_eq!(
+        emitter
+            .as_str()
+            .lines()
+            .nth(units[4].lines.start as usize - 1),
+        Some("module prj_ModuleC (")
+    );
+}
+
+#[test]
+fn export_signed_const() {
+    let code = r#"
+package PkgB {
+    const NEG : i32      = -5;
+    const MASK: logic<8> = -1;
+    const BIG : i64      = -3000000000;
+    const POS : i32      = 3;
+}
+"#;
+
+    symbol_table::clear();
+    attribute_table::clear();
+
+    let parser = Parser::parse(code, &"").unwrap();
+    let mut metadata: Metadata =
+        toml::from_str(&Metadata::create_default_toml("prj").unwrap()).unwrap();
+    metadata.export.languages = vec![
+        ExportLanguage::C,
+        ExportLanguage::Python,
+        ExportLanguage::Rust,
+    ];
+    let analyzer = Analyzer::new(&metadata);
+
+    analyzer.analyze_pass1("prj", "", &parser.veryl);
+    Analyzer::analyze_post_pass1();
+    analyzer.analyze_pass2("prj", "", &parser.veryl);
+
+    let exporter = Exporter::new(&metadata);
+    let files = exporter.export();
+
+    let c_expect = r#"// Generated from package PkgB by Veryl. DO NOT EDIT.
+#ifndef PRJ_PKG_B_H
+#define PRJ_PKG_B_H
+
+#define PKG_B_NEG (-5)
+#define PKG_B_MASK 255
+#define PKG_B_BIG (-3000000000LL)
+#define PKG_B_POS 3
+
+#endif
+"#;
+
+    let python_expect = r#"# Generated from package PkgB by Veryl. DO NOT EDIT.
+
+NEG = -5
+MASK = 255
+BIG = -3000000000
+POS = 3
+"#;
+
+    let rust_expect = r#"// Generated from package PkgB by Veryl. DO NOT EDIT.
+
+pub const NEG: i32 = -5;
+pub const MASK: u8 = 255;
+pub const BIG: i64 = -3000000000;
+pub const POS: i32 = 3;
+"#;
+
+    assert_eq!(files[0].text, c_expect);
+    assert_eq!(files[1].text, python_expect);
+    assert_eq!(files[2].text, rust_expect);
+}
@@ -0,0 +1,185 @@
+use std::collections::{HashMap, HashSet};
+use veryl_analyzer::namespace::Namespace;
+use veryl_analyzer::packed_layout;
+use veryl_analyzer::symbol::{Symbol, SymbolId, SymbolKind};
+use veryl_analyzer::symbol_path::SymbolPath;
+use veryl_analyzer::symbol_table;
+use veryl_parser::ParolError;
+use veryl_parser::resource_table::{StrId, TokenId};
+use veryl_parser::veryl_grammar_trait::*;
+use veryl_parser::veryl_walker::{Handler, HandlerPoint, VerylWalker};
+
+/// Information gathered before emission to lower constructs into Verilog-2005
+#[derive(Default)]
+pub struct Verilog2005Info {
+    procedural_targets: HashSet<(SymbolId, Option<StrId>)>,
+    package_references: HashMap<TokenId, Vec<SymbolId>>,
+}
+
+impl Verilog2005Info {
+    pub fn new(input: &Veryl) -> Self {
+        let mut collector = Collector::default();
+        VerylWalker::veryl(&mut collector, input);
+        collector.info
+    }
+
+    /// Returns whether the variable is assigned in `always` or `initial` blocks.
+    ///
+    /// `member` specifies the member variable of interface instance or modport port.
+    pub fn is_procedural_target(&self, id: SymbolId, member: Option<StrId>) -> bool {
+        self.procedural_targets.contains(&(id, member))
+    }
+
+    /// Returns constants of packages referred from the module in dependency order
+    pub fn package_constants(&self, module: TokenId) -> Vec<Symbol> {
+        let mut ret = Vec::new();
+        let mut visited = HashSet::new();
+        if let Some(ids) = self.package_references.get(&module) {
+            for id in ids {
+                visit_package_constant(*id, &mut visited, &mut ret);
+            }
+        }
+        ret
+    }
+}
+
+fn visit_package_constant(id: SymbolId, visited: &mut HashSet<SymbolId>, ret: &mut Vec<Symbol>) {
+    if !visited.insert(id) {
+        return;
+    }
+    let Some(symbol) = symbol_table::get(id) else {
+        return;
+    };
+
+    if let SymbolKind::Parameter(x) = &symbol.kind {
+        let mut collector = Collector {
+            in_module: true,
+            ..Default::default()
+        };
+        VerylWalker::expression(&mut collector, &x.value);
+        for id in collector.references {
+            visit_package_constant(id, visited, ret);
+        }
+    }
+    ret.push(symbol);
+}
+
+/// Returns whether the symbol is a constant defined in a package
+pub fn is_package_constant(symbol: &Symbol) -> bool {
+    if !matches!(
+        symbol.kind,
+        SymbolKind::Parameter(_) | SymbolKind::EnumMember(_)
+    ) {
+        return false;
+    }
+
+    let paths = &symbol.namespace.paths;
+    if paths.len() < 2 {
+        return false;
+    }
+
+    let mut namespace = Namespace::new();
+    namespace.push(paths[0]);
+    symbol_table::resolve((&SymbolPath::new(&[paths[1]]), &namespace))
+        .map(|x| matches!(x.found.kind, SymbolKind::Package(_)))
+        .unwrap_or(false)
+}
+
+/// Returns the width of the enum which the member belongs to
+pub fn enum_width(member: &Symbol) -> Option<usize> {
+    let mut namespace = member.namespace.clone();
+    let name = namespace.pop()?;
+    let symbol = symbol_table::resolve((&SymbolPath::new(&[name]), &namespace)).ok()?;
+    if let SymbolKind::Enum(x) = &symbol.found.kind {
+        if let Some(r#type) = &x.r#type {
+            packed_layout::packed_width(r#type, &symbol.found.namespace)
+        } else {
+            Some(x.width)
+        }
+    } else {
+        None
+    }
+}
+
+#[derive(Default)]
+struct Collector {
+    info: Verilog2005Info,
+    point: HandlerPoint,
+    in_module: bool,
+    in_function: bool,
+    references: Vec<SymbolId>,
+}
+
+impl VerylWalker for Collector {
+    fn get_handlers(&mut self) -> Option<Vec<(bool, &mut dyn Handler)>> {
+        Some(vec![(true, self)])
+    }
+}
+
+impl Handler for Collector {
+    fn set_point(&mut self, p: HandlerPoint) {
+        self.point = p;
+    }
+}
+
+impl VerylGrammarTrait for Collector {
+    fn module_declaration(&mut self, arg: &ModuleDeclaration) -> Result<(), ParolError> {
+        match self.point {
+            HandlerPoint::Before => self.in_module = true,
+            HandlerPoint::After => {
+                self.in_module = false;
+                let references = std::mem::take(&mut self.references);
+                self.info
+                    .package_references
+                    .insert(arg.identifier.identifier_token.token.id, references);
+            }
+        }
+        Ok(())
+    }
+
+    fn function_declaration(&mut self, _arg: &FunctionDeclaration) -> Result<(), ParolError> {
+        match self.point {
+            HandlerPoint::Before => self.in_function = true,
+            HandlerPoint::After => self.in_function = false,
+        }
+        Ok(())
+    }
+
+    fn identifier_statement(&mut self, arg: &IdentifierStatement) -> Result<(), ParolError> {
+        if let HandlerPoint::Before = self.point {
+            if self.in_function {
+                return Ok(());
+            }
+            if let IdentifierStatementGroup::Assignment(_) = arg.identifier_statement_group.as_ref()
+            {
+                let arg = arg.expression_identifier.as_ref();
+                if let Ok(x) = symbol_table::resolve(arg.scoped_identifier.as_ref()) {
+                    let member = arg
+                        .expression_identifier_list0
+                        .first()
+                        .map(|x| x.identifier.identifier_token.token.text);
+                    self.info.procedural_targets.insert((x.found.id, None));
+                    if member.is_some() {
+                        self.info.procedural_targets.insert((x.found.id, member));
+                    }
+                }
+            }
+        }
+        Ok(())
+    }
+
+    fn scoped_identifier(&mut self, arg: &ScopedIdentifier) -> Result<(), ParolError> {
+        if let HandlerPoint::Before = self.point {
+            if !self.in_module {
+                return Ok(());
+            }
+            if let Ok(x) = symbol_table::resolve(arg)
+                && is_package_constant(&x.found)
+                && !self.references.contains(&x.found.id)
+            {
+                self.references.push(x.found.id);
+            }
+        }
+        Ok(())
+    }
+}
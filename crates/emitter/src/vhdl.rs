@@ -0,0 +1,3746 @@
+use crate::emitter::{SymbolContext, emitting_identifier, symbol_string};
+use std::cell::RefCell;
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::path::Path;
+use veryl_analyzer::symbol::Type as SymType;
+use veryl_analyzer::symbol::{
+    GenericTables, Symbol, SymbolId, SymbolKind, TypeKind, TypeModifierKind, VariableAffiliation,
+};
+use veryl_analyzer::symbol_path::SymbolPath;
+use veryl_analyzer::{namespace_table, symbol_table};
+use veryl_metadata::{Build, ClockType, Metadata, ResetType, SourceMapTarget};
+use veryl_parser::resource_table::StrId;
+use veryl_parser::token_range::{TokenExt, TokenRange};
+use veryl_parser::veryl_grammar_trait::*;
+use veryl_parser::veryl_token::{Token, VerylToken, is_anonymous_token};
+use veryl_parser::veryl_walker::VerylWalker;
+use veryl_sourcemap::SourceMap;
+
+#[cfg(target_os = "windows")]
+const NEWLINE: &str = "\r\n";
+#[cfg(not(target_os = "windows"))]
+const NEWLINE: &str = "\n";
+
+const CONTEXT_CLAUSE: &[&str] = &[
+    "library ieee;",
+    "use ieee.std_logic_1164.all;",
+    "use ieee.numeric_std.all;",
+    "use ieee.numeric_std_unsigned.all;",
+    "use ieee.math_real.all;",
+];
+
+const RESERVED_WORDS: &[&str] = &[
+    "abs",
+    "access",
+    "after",
+    "alias",
+    "all",
+    "and",
+    "architecture",
+    "array",
+    "assert",
+    "assume",
+    "attribute",
+    "begin",
+    "block",
+    "body",
+    "buffer",
+    "bus",
+    "case",
+    "component",
+    "configuration",
+    "constant",
+    "context",
+    "cover",
+    "default",
+    "disconnect",
+    "downto",
+    "else",
+    "elsif",
+    "end",
+    "entity",
+    "exit",
+    "fairness",
+    "file",
+    "for",
+    "force",
+    "function",
+    "generate",
+    "generic",
+    "group",
+    "guarded",
+    "if",
+    "impure",
+    "in",
+    "inertial",
+    "inout",
+    "is",
+    "label",
+    "library",
+    "linkage",
+    "literal",
+    "loop",
+    "map",
+    "mod",
+    "nand",
+    "new",
+    "next",
+    "nor",
+    "not",
+    "null",
+    "of",
+    "on",
+    "open",
+    "or",
+    "others",
+    "out",
+    "package",
+    "parameter",
+    "port",
+    "postponed",
+    "procedure",
+    "process",
+    "property",
+    "protected",
+    "pure",
+    "range",
+    "record",
+    "register",
+    "reject",
+    "release",
+    "rem",
+    "report",
+    "restrict",
+    "return",
+    "rol",
+    "ror",
+    "select",
+    "sequence",
+    "severity",
+    "shared",
+    "signal",
+    "sla",
+    "sll",
+    "sra",
+    "srl",
+    "strong",
+    "subtype",
+    "then",
+    "to",
+    "transport",
+    "type",
+    "unaffected",
+    "units",
+    "until",
+    "use",
+    "variable",
+    "vmode",
+    "vprop",
+    "vunit",
+    "wait",
+    "when",
+    "while",
+    "with",
+    "xnor",
+    "xor",
+];
+
+thread_local!(static CASE_CLASHES: RefCell<HashSet<String>> = RefCell::new(HashSet::new()));
+
+/// Type category of VHDL expression which is used to insert type conversions
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Kind {
+    Bit,
+    Vector,
+    Int,
+    Real,
+    Bool,
+    Unknown,
+}
+
+#[derive(Clone, Debug)]
+enum Literal {
+    Int(String),
+    /// Bit string literal with the least significant bit
+    Bits(char),
+    AllBit(char),
+    Bool(bool),
+    Real,
+}
+
+// Precedence levels of VHDL operators
+const LOGICAL: u8 = 1;
+const RELATIONAL: u8 = 2;
+const ADDING: u8 = 4;
+const SIGN: u8 = 5;
+const MULTIPLYING: u8 = 6;
+const MISCELLANEOUS: u8 = 7;
+
+#[derive(Clone, Debug)]
+struct Operand {
+    text: String,
+    kind: Kind,
+    /// Precedence level of the top-level VHDL operator, 0 means primary
+    prec: u8,
+    op: Option<&'static str>,
+    literal: Option<Literal>,
+}
+
+impl Operand {
+    fn new(text: String, kind: Kind) -> Self {
+        Self {
+            text,
+            kind,
+            prec: 0,
+            op: None,
+            literal: None,
+        }
+    }
+
+    fn wrapped(&self) -> String {
+        if self.prec == 0 {
+            self.text.clone()
+        } else {
+            format!("({})", self.text)
+        }
+    }
+}
+
+/// Expected type of the destination of an expression
+#[derive(Clone, Debug)]
+struct Target {
+    kind: Kind,
+    len: Option<String>,
+    signed: bool,
+}
+
+impl Target {
+    fn unknown() -> Self {
+        Self {
+            kind: Kind::Unknown,
+            len: None,
+            signed: false,
+        }
+    }
+}
+
+/// Usage of type which changes the translation of integral types
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum TypeUsage {
+    Signal,
+    Constant,
+    Mark,
+}
+
+/// Right-hand side of assignment
+enum Rhs {
+    Plain(String),
+    Conditional(Vec<(String, String)>, String),
+    Selected(String, Vec<(String, String)>, String),
+}
+
+#[derive(Default)]
+struct CommentCollector {
+    comments: BTreeMap<(u32, u32), String>,
+}
+
+impl VerylWalker for CommentCollector {
+    fn veryl_token(&mut self, arg: &VerylToken) {
+        for x in &arg.comments {
+            self.comments.insert((x.line, x.column), x.to_string());
+        }
+    }
+}
+
+/// Code generator which translates the analyzed Veryl into VHDL-2008
+pub struct VhdlEmitter {
+    project_name: Option<StrId>,
+    build_opt: Build,
+    indent_width: usize,
+    string: String,
+    indent: usize,
+    dst_line: u32,
+    comments: BTreeMap<(u32, u32), String>,
+    source_map: Option<SourceMap>,
+    file_scope_import: Vec<ImportDeclaration>,
+    default_clock: Option<SymbolId>,
+    default_reset: Option<SymbolId>,
+    reset_condition: Option<String>,
+    function_return: Option<Target>,
+    select_base: Vec<String>,
+}
+
+impl Default for VhdlEmitter {
+    fn default() -> Self {
+        Self {
+            project_name: None,
+            build_opt: Build::default(),
+            indent_width: 4,
+            string: String::new(),
+            indent: 0,
+            dst_line: 1,
+            comments: BTreeMap::new(),
+            source_map: None,
+            file_scope_import: Vec::new(),
+            default_clock: None,
+            default_reset: None,
+            reset_condition: None,
+            function_return: None,
+            select_base: Vec::new(),
+        }
+    }
+}
+
+impl VhdlEmitter {
+    pub fn new(metadata: &Metadata, src_path: &Path, dst_path: &Path, map_path: &Path) -> Self {
+        let source_map = SourceMap::new(src_path, dst_path, map_path);
+
+        Self {
+            project_name: Some(metadata.project.name.as_str().into()),
+            build_opt: metadata.build.clone(),
+            indent_width: metadata.format.indent_width,
+            source_map: Some(source_map),
+            ..Default::default()
+        }
+    }
+
+    pub fn emit(&mut self, project_name: &str, input: &Veryl) {
+        namespace_table::set_default(&[project_name.into()]);
+
+        CASE_CLASHES.with(|x| *x.borrow_mut() = case_clashes());
+
+        let mut collector = CommentCollector::default();
+        collector.veryl(input);
+        self.comments = collector.comments;
+
+        let mut descriptions = Vec::new();
+        for x in &input.veryl_list {
+            let items: Vec<DescriptionItem> = x.description_group.as_ref().into();
+            for item in items {
+                match item {
+                    DescriptionItem::ImportDeclaration(x) => {
+                        self.file_scope_import
+                            .push(x.import_declaration.as_ref().clone());
+                    }
+                    DescriptionItem::DescriptionItemOptPublicDescriptionItem(x) => {
+                        match x.public_description_item.as_ref() {
+                            PublicDescriptionItem::ModuleDeclaration(_)
+                            | PublicDescriptionItem::PackageDeclaration(_) => {
+                                descriptions.push(x.public_description_item.as_ref().clone())
+                            }
+                            _ => (),
+                        }
+                    }
+                    _ => (),
+                }
+            }
+        }
+
+        let mut prev_line = 0;
+        for (i, x) in descriptions.iter().enumerate() {
+            if i != 0 {
+                self.newline();
+            }
+            let range: TokenRange = match x {
+                PublicDescriptionItem::ModuleDeclaration(x) => x.module_declaration.as_ref().into(),
+                PublicDescriptionItem::PackageDeclaration(x) => {
+                    x.package_declaration.as_ref().into()
+                }
+                _ => unreachable!(),
+            };
+            self.leading_comments(prev_line, range.beg.line);
+            match x {
+                PublicDescriptionItem::ModuleDeclaration(x) => {
+                    self.module_declaration(&x.module_declaration)
+                }
+                PublicDescriptionItem::PackageDeclaration(x) => {
+                    self.package_declaration(&x.package_declaration)
+                }
+                _ => unreachable!(),
+            }
+            prev_line = range.end.line;
+        }
+        self.leading_comments(prev_line, u32::MAX);
+
+        // build map and insert link to map
+        if self.build_opt.sourcemap_target != SourceMapTarget::None {
+            self.source_map.as_mut().unwrap().build();
+            let link = self.source_map.as_ref().unwrap().get_vhdl_link();
+            self.line(None, &link);
+        }
+    }
+
+    pub fn as_str(&self) -> &str {
+        &self.string
+    }
+
+    pub fn source_map(&mut self) -> &mut SourceMap {
+        self.source_map.as_mut().unwrap()
+    }
+
+    fn context(&self) -> SymbolContext {
+        SymbolContext {
+            project_name: self.project_name,
+            build_opt: self.build_opt.clone(),
+            in_import: false,
+            in_direction_modport: false,
+            generic_map: Vec::new(),
+        }
+    }
+
+    fn newline(&mut self) {
+        self.string.push_str(NEWLINE);
+        self.dst_line += 1;
+    }
+
+    fn line(&mut self, token: Option<&Token>, text: &str) {
+        let indent = self.indent * self.indent_width;
+        self.string.push_str(&" ".repeat(indent));
+        if let Some(token) = token
+            && let Some(map) = &mut self.source_map
+        {
+            map.add(
+                self.dst_line,
+                indent as u32 + 1,
+                token.line,
+                token.column,
+                &token.to_string(),
+            );
+        }
+        self.string.push_str(text);
+        self.newline();
+    }
+
+    /// Emits a line which corresponds to the given source range with trailing comments in it
+    fn item_line(&mut self, range: &TokenRange, text: &str) {
+        let comments = self.take_comments(range.beg.line, range.end.line + 1);
+        if comments.is_empty() {
+            self.line(Some(&range.beg), text);
+        } else {
+            let comments: Vec<_> = comments.iter().flat_map(|x| comment_lines(x)).collect();
+            let text = format!("{text} {}", comments.join(" "));
+            self.line(Some(&range.beg), &text);
+        }
+    }
+
+    fn take_comments(&mut self, from: u32, to: u32) -> Vec<String> {
+        if from >= to {
+            return Vec::new();
+        }
+        let keys: Vec<_> = self
+            .comments
+            .range((from, 0)..(to, 0))
+            .map(|(k, _)| *k)
+            .collect();
+        keys.iter()
+            .map(|x| self.comments.remove(x).unwrap())
+            .collect()
+    }
+
+    /// Emits a blank line if lines `from..to` contain a blank line in the source
+    fn blank_line(&mut self, from: u32, to: u32) {
+        if from + 1 >= to {
+            return;
+        }
+        let comment_lines: u32 = self
+            .comments
+            .range((from + 1, 0)..(to, 0))
+            .map(|(_, x)| x.trim_end().lines().count() as u32)
+            .sum();
+        if to - from - 1 > comment_lines {
+            self.newline();
+        }
+    }
+
+    /// Emits comments in lines `from..to` as separated lines
+    fn leading_comments(&mut self, from: u32, to: u32) {
+        for x in self.take_comments(from, to) {
+            for line in comment_lines(&x) {
+                self.line(None, &line);
+            }
+        }
+    }
+
+    fn context_clause(&mut self, imports: &[ImportDeclaration]) {
+        for x in CONTEXT_CLAUSE {
+            self.line(None, x);
+        }
+        self.line(None, "use work.all;");
+        for x in imports {
+            let text = self.import_declaration(x);
+            self.line(Some(&x.import.import_token.token), &text);
+        }
+        self.newline();
+    }
+
+    fn import_declaration(&mut self, arg: &ImportDeclaration) -> String {
+        let mut context = self.context();
+        context.in_import = true;
+        let text = match symbol_table::resolve(arg.scoped_identifier.as_ref()) {
+            Ok(x) => symbol_string(
+                arg.scoped_identifier.identifier(),
+                &x.found,
+                &x.full_path,
+                &x.generic_tables,
+                &context,
+            ),
+            Err(_) => arg.scoped_identifier.identifier().to_string(),
+        };
+        let text = qualified_identifier(&text);
+        if arg.import_declaration_opt.is_some() {
+            format!("use work.{text}.all;")
+        } else {
+            format!("use work.{text};")
+        }
+    }
+
+    fn design_unit_name(&self, arg: &Identifier) -> String {
+        let symbol = symbol_table::resolve(arg).unwrap();
+        let text = symbol_string(
+            &arg.identifier_token,
+            &symbol.found,
+            &[],
+            &GenericTables::default(),
+            &self.context(),
+        );
+        vhdl_identifier(&text)
+    }
+
+    fn declared_name(&self, arg: &Identifier) -> String {
+        vhdl_identifier(&emitting_identifier(arg).identifier_token.to_string())
+    }
+
+    // ------------------------------------------------------------------------
+    // Design units
+    // ------------------------------------------------------------------------
+
+    fn module_declaration(&mut self, arg: &ModuleDeclaration) {
+        let symbol = symbol_table::resolve(arg.identifier.as_ref()).unwrap();
+        if let SymbolKind::Module(ref x) = symbol.found.kind {
+            self.default_clock = x.default_clock;
+            self.default_reset = x.default_reset;
+        }
+        let name = self.design_unit_name(&arg.identifier);
+
+        let mut imports = self.file_scope_import.clone();
+        imports.append(&mut arg.collect_import_declarations());
+        self.context_clause(&imports);
+
+        let token = arg.module.module_token.token;
+        self.line(Some(&token), &format!("entity {name} is"));
+        self.indent += 1;
+
+        let mut constants = Vec::new();
+        if let Some(ref x) = arg.module_declaration_opt1
+            && let Some(ref x) = x.with_parameter.with_parameter_opt
+        {
+            let items: Vec<WithParameterItem> = x.with_parameter_list.as_ref().into();
+            let mut generics = Vec::new();
+            for item in items {
+                match item.with_parameter_item_group.as_ref() {
+                    WithParameterItemGroup::Param(_) => generics.push(item),
+                    WithParameterItemGroup::Const(_) => constants.push(item),
+                }
+            }
+            if !generics.is_empty() {
+                self.line(None, "generic (");
+                self.indent += 1;
+                let lines: Vec<_> = generics
+                    .iter()
+                    .map(|x| {
+                        let (name, r#type, value) = self.with_parameter_item(x);
+                        (name, format!(": {type} := {value}"))
+                    })
+                    .collect();
+                let ranges: Vec<TokenRange> = generics.iter().map(|x| x.into()).collect();
+                self.aligned_list(&ranges, &lines);
+                self.indent -= 1;
+                self.line(None, ");");
+            }
+        }
+
+        if let Some(ref x) = arg.module_declaration_opt2
+            && let Some(ref x) = x.port_declaration.port_declaration_opt
+        {
+            let items: Vec<PortDeclarationItem> = x.port_declaration_list.as_ref().into();
+            if !items.is_empty() {
+                self.line(None, "port (");
+                self.indent += 1;
+                let lines: Vec<_> = items
+                    .iter()
+                    .map(|x| self.port_declaration_item(x))
+                    .collect();
+                let ranges: Vec<TokenRange> = items.iter().map(|x| x.into()).collect();
+                self.aligned_list(&ranges, &lines);
+                self.indent -= 1;
+                self.line(None, ");");
+            }
+        }
+
+        self.indent -= 1;
+        self.line(None, &format!("end entity {name};"));
+        self.newline();
+
+        self.line(Some(&token), &format!("architecture rtl of {name} is"));
+        self.indent += 1;
+        for x in &constants {
+            let (name, r#type, value) = self.with_parameter_item(x);
+            let range: TokenRange = x.into();
+            self.item_line(&range, &format!("constant {name} : {type} := {value};"));
+        }
+
+        let mut items = Vec::new();
+        for x in &arg.module_declaration_list {
+            let groups: Vec<ModuleItem> = x.module_group.as_ref().into();
+            for x in groups {
+                flatten_generate_item(x.generate_item.as_ref(), &mut items);
+            }
+        }
+        let open_line = arg.l_brace.l_brace_token.token.line;
+        let close_line = arg.r_brace.r_brace_token.token.line;
+        self.declarative_part(&items, open_line);
+        self.indent -= 1;
+        self.line(None, "begin");
+        self.indent += 1;
+        self.statement_part(&items, open_line, close_line);
+        self.indent -= 1;
+        self.line(
+            Some(&arg.r_brace.r_brace_token.token),
+            "end architecture rtl;",
+        );
+
+        self.default_clock = None;
+        self.default_reset = None;
+    }
+
+    fn package_declaration(&mut self, arg: &PackageDeclaration) {
+        let name = self.design_unit_name(&arg.identifier);
+
+        let mut imports = self.file_scope_import.clone();
+        imports.append(&mut arg.collect_import_declarations());
+        self.context_clause(&imports);
+
+        let mut items = Vec::new();
+        for x in &arg.package_declaration_list {
+            let groups: Vec<PackageItem> = x.package_group.as_ref().into();
+            items.extend(groups);
+        }
+
+        let token = arg.package.package_token.token;
+        self.line(Some(&token), &format!("package {name} is"));
+        self.indent += 1;
+        let mut prev_line = arg.l_brace.l_brace_token.token.line;
+        let mut functions = Vec::new();
+        let mut first = true;
+        for x in &items {
+            let range: TokenRange = match x {
+                PackageItem::ConstDeclaration(x) => x.const_declaration.as_ref().into(),
+                PackageItem::TypeDefDeclaration(x) => x.type_def_declaration.as_ref().into(),
+                PackageItem::EnumDeclaration(x) => x.enum_declaration.as_ref().into(),
+                PackageItem::StructUnionDeclaration(x) => {
+                    x.struct_union_declaration.as_ref().into()
+                }
+                PackageItem::FunctionDeclaration(x) => x.function_declaration.as_ref().into(),
+                _ => continue,
+            };
+            if !first {
+                self.blank_line(prev_line, range.beg.line);
+            }
+            first = false;
+            self.leading_comments(prev_line, range.beg.line);
+            match x {
+                PackageItem::ConstDeclaration(x) => self.const_declaration(&x.const_declaration),
+                PackageItem::TypeDefDeclaration(x) => {
+                    self.type_def_declaration(&x.type_def_declaration)
+                }
+                PackageItem::EnumDeclaration(x) => self.enum_declaration(&x.enum_declaration),
+                PackageItem::StructUnionDeclaration(x) => {
+                    self.struct_union_declaration(&x.struct_union_declaration)
+                }
+                PackageItem::FunctionDeclaration(x) => {
+                    let header = self.function_header(&x.function_declaration);
+                    let last = header.len() - 1;
+                    for (i, line) in header.iter().enumerate() {
+                        let line = if i == last {
+                            format!("{line};")
+                        } else {
+                            line.clone()
+                        };
+                        let token = (i == 0).then_some(&range.beg);
+                        self.line(token, &line);
+                    }
+                    functions.push(x.function_declaration.as_ref().clone());
+                }
+                _ => unreachable!(),
+            }
+            prev_line = range.end.line;
+        }
+        self.leading_comments(prev_line, arg.r_brace.r_brace_token.token.line);
+        self.indent -= 1;
+        self.line(
+            Some(&arg.r_brace.r_brace_token.token),
+            &format!("end package {name};"),
+        );
+
+        if !functions.is_empty() {
+            self.newline();
+            self.line(Some(&token), &format!("package body {name} is"));
+            self.indent += 1;
+            for (i, x) in functions.iter().enumerate() {
+                if i != 0 {
+                    self.newline();
+                }
+                self.function_declaration(x);
+            }
+            self.indent -= 1;
+            self.line(None, &format!("end package body {name};"));
+        }
+    }
+
+    /// Emits `name : rest` lines separated by `;` with aligned colons
+    fn aligned_list(&mut self, ranges: &[TokenRange], lines: &[(String, String)]) {
+        let width = lines.iter().map(|x| x.0.len()).max().unwrap_or(0);
+        let mut prev_line = 0;
+        for (i, ((name, rest), range)) in lines.iter().zip(ranges).enumerate() {
+            if i != 0 {
+                self.leading_comments(prev_line, range.beg.line);
+            }
+            let separator = if i + 1 == lines.len() { "" } else { ";" };
+            let text = format!("{name:width$} {rest}{separator}");
+            self.item_line(range, &text);
+            prev_line = range.end.line;
+        }
+    }
+
+    fn with_parameter_item(&mut self, arg: &WithParameterItem) -> (String, String, String) {
+        let name = self.declared_name(&arg.identifier);
+        let (r#type, target) = match arg.with_parameter_item_group0.as_ref() {
+            WithParameterItemGroup0::ArrayType(x) => (
+                self.scalar_type(&x.array_type.scalar_type, TypeUsage::Constant),
+                self.scalar_target(&x.array_type.scalar_type, TypeUsage::Constant),
+            ),
+            WithParameterItemGroup0::Type(_) => ("natural".to_string(), Target::unknown()),
+        };
+        let value = self.expression(&arg.expression);
+        let value = self.adapt(&value, &target);
+        (name, r#type, value)
+    }
+
+    fn port_declaration_item(&mut self, arg: &PortDeclarationItem) -> (String, String) {
+        let name = self.declared_name(&arg.identifier);
+        let rest = match arg.port_declaration_item_group.as_ref() {
+            PortDeclarationItemGroup::PortTypeConcrete(x) => {
+                let x = x.port_type_concrete.as_ref();
+                let direction = match x.direction.as_ref() {
+                    Direction::Input(_) => "in",
+                    Direction::Output(_) => "out",
+                    Direction::Inout(_) => "inout",
+                    _ => "in",
+                };
+                let r#type = self.scalar_type(&x.array_type.scalar_type, TypeUsage::Signal);
+                let mut rest = format!(": {direction:5} {type}");
+                if let Some(ref y) = x.port_type_concrete_opt0 {
+                    let target = self.scalar_target(&x.array_type.scalar_type, TypeUsage::Signal);
+                    let value = self.expression(&y.port_default_value.expression);
+                    let value = self.adapt(&value, &target);
+                    rest.push_str(&format!(" := {value}"));
+                }
+                rest.trim_end().to_string()
+            }
+            PortDeclarationItemGroup::PortTypeAbstract(_) => ": in std_logic".to_string(),
+        };
+        (name, rest)
+    }
+
+    // ------------------------------------------------------------------------
+    // Declarative and statement parts
+    // ------------------------------------------------------------------------
+
+    fn declarative_part(&mut self, items: &[GenerateItem], open_line: u32) {
+        let mut prev_line = open_line;
+        let mut first = true;
+        for x in items {
+            let range: TokenRange = x.into();
+            if is_declarative_item(x) {
+                if !first {
+                    self.blank_line(prev_line, range.beg.line);
+                }
+                first = false;
+                self.leading_comments(prev_line, range.beg.line);
+                self.declarative_item(x, &range);
+            }
+            prev_line = range.end.line;
+        }
+    }
+
+    fn declarative_item(&mut self, arg: &GenerateItem, range: &TokenRange) {
+        match arg {
+            GenerateItem::VarDeclaration(x) => {
+                let x = x.var_declaration.as_ref();
+                let name = self.declared_name(&x.identifier);
+                let r#type = self.signal_type(&x.array_type, &name, range);
+                self.item_line(range, &format!("signal {name} : {type};"));
+            }
+            GenerateItem::LetDeclaration(x) => {
+                let x = x.let_declaration.as_ref();
+                let name = self.declared_name(&x.identifier);
+                let r#type = self.signal_type(&x.array_type, &name, range);
+                self.item_line(range, &format!("signal {name} : {type};"));
+            }
+            GenerateItem::ConstDeclaration(x) => self.const_declaration(&x.const_declaration),
+            GenerateItem::TypeDefDeclaration(x) => {
+                self.type_def_declaration(&x.type_def_declaration)
+            }
+            GenerateItem::EnumDeclaration(x) => self.enum_declaration(&x.enum_declaration),
+            GenerateItem::StructUnionDeclaration(x) => {
+                self.struct_union_declaration(&x.struct_union_declaration)
+            }
+            GenerateItem::FunctionDeclaration(x) => {
+                self.function_declaration(&x.function_declaration)
+            }
+            _ => (),
+        }
+    }
+
+    fn statement_part(&mut self, items: &[GenerateItem], open_line: u32, close_line: u32) {
+        let mut prev_line = open_line;
+        let mut first = true;
+        for x in items {
+            let range: TokenRange = x.into();
+            if is_concurrent_item(x) {
+                if !first {
+                    self.blank_line(prev_line, range.beg.line);
+                }
+                first = false;
+                self.leading_comments(prev_line, range.beg.line);
+                self.concurrent_item(x, &range);
+            }
+            prev_line = range.end.line;
+        }
+        self.leading_comments(prev_line, close_line);
+    }
+
+    fn concurrent_item(&mut self, arg: &GenerateItem, range: &TokenRange) {
+        match arg {
+            GenerateItem::LetDeclaration(x) => {
+                let x = x.let_declaration.as_ref();
+                let name = self.declared_name(&x.identifier);
+                let target = self.array_target(&x.array_type, &name);
+                let rhs = self.rhs(&x.expression, &target);
+                let text = self.assignment_text(&name, "<=", rhs);
+                self.line(Some(&range.beg), &text);
+            }
+            GenerateItem::AssignDeclaration(x) => self.assign_declaration(&x.assign_declaration),
+            GenerateItem::AlwaysFfDeclaration(x) => {
+                self.always_ff_declaration(&x.always_ff_declaration)
+            }
+            GenerateItem::AlwaysCombDeclaration(x) => {
+                let x = x.always_comb_declaration.as_ref();
+                let token = x.always_comb.always_comb_token.token;
+                self.process(
+                    &token,
+                    "process (all) is",
+                    &x.statement_block,
+                    |s, block| {
+                        s.statement_block(block);
+                    },
+                );
+            }
+            GenerateItem::InitialDeclaration(x) => {
+                let x = x.initial_declaration.as_ref();
+                let token = x.initial.initial_token.token;
+                self.process(&token, "process is", &x.statement_block, |s, block| {
+                    s.statement_block(block);
+                    s.line(None, "wait;");
+                });
+            }
+            GenerateItem::InstDeclaration(x) => self.inst_declaration(&x.inst_declaration),
+            GenerateItem::GenerateIfDeclaration(x) => {
+                self.generate_if_declaration(&x.generate_if_declaration)
+            }
+            GenerateItem::GenerateForDeclaration(x) => {
+                self.generate_for_declaration(&x.generate_for_declaration)
+            }
+            GenerateItem::GenerateBlockDeclaration(x) => {
+                let x = &x.generate_block_declaration.generate_named_block;
+                let label = self.declared_name(&x.identifier);
+                let items = generate_named_block_items(x);
+                self.line(Some(&range.beg), &format!("{label}: block is"));
+                self.generate_body(
+                    &items,
+                    x.l_brace.l_brace_token.token.line,
+                    x.r_brace.r_brace_token.token.line,
+                    true,
+                );
+                self.line(None, &format!("end block {label};"));
+            }
+            _ => (),
+        }
+    }
+
+    /// Emits declarations and statements of generate body.
+    /// `begin` is omitted if there is no declaration unless `force_begin` is specified.
+    fn generate_body(
+        &mut self,
+        items: &[GenerateItem],
+        open_line: u32,
+        close_line: u32,
+        force_begin: bool,
+    ) {
+        self.indent += 1;
+        self.declarative_part(items, open_line);
+        self.indent -= 1;
+        if force_begin || items.iter().any(is_declarative_item) {
+            self.line(None, "begin");
+        }
+        self.indent += 1;
+        self.statement_part(items, open_line, close_line);
+        self.indent -= 1;
+    }
+
+    fn generate_if_declaration(&mut self, arg: &GenerateIfDeclaration) {
+        let label = self.declared_name(&arg.generate_named_block.identifier);
+        let cond = self.condition(&arg.expression);
+        let token = arg.r#if.if_token.token;
+        self.line(Some(&token), &format!("{label}: if {cond} generate"));
+        let block = &arg.generate_named_block;
+        let items = generate_named_block_items(block);
+        self.generate_body(
+            &items,
+            block.l_brace.l_brace_token.token.line,
+            block.r_brace.r_brace_token.token.line,
+            false,
+        );
+        for x in &arg.generate_if_declaration_list {
+            let cond = self.condition(&x.expression);
+            let block = &x.generate_optional_named_block;
+            let alt = self.alternative_label(block, &label);
+            self.line(
+                Some(&x.r#if.if_token.token),
+                &format!("elsif {alt}{cond} generate"),
+            );
+            let items = generate_optional_named_block_items(block);
+            self.generate_body(
+                &items,
+                block.l_brace.l_brace_token.token.line,
+                block.r_brace.r_brace_token.token.line,
+                false,
+            );
+        }
+        if let Some(ref x) = arg.generate_if_declaration_opt {
+            let block = &x.generate_optional_named_block;
+            let alt = self.alternative_label(block, &label);
+            self.line(
+                Some(&x.r#else.else_token.token),
+                &format!("else {alt}generate"),
+            );
+            let items = generate_optional_named_block_items(block);
+            self.generate_body(
+                &items,
+                block.l_brace.l_brace_token.token.line,
+                block.r_brace.r_brace_token.token.line,
+                false,
+            );
+        }
+        self.line(None, &format!("end generate {label};"));
+    }
+
+    fn alternative_label(&self, arg: &GenerateOptionalNamedBlock, label: &str) -> String {
+        if let Some(ref x) = arg.generate_optional_named_block_opt {
+            let alt = self.declared_name(&x.identifier);
+            if alt != label {
+                return format!("{alt}: ");
+            }
+        }
+        String::new()
+    }
+
+    fn generate_for_declaration(&mut self, arg: &GenerateForDeclaration) {
+        let block = &arg.generate_named_block;
+        let label = self.declared_name(&block.identifier);
+        let index = self.declared_name(&arg.identifier);
+        let range = self.range(&arg.range);
+        let token = arg.r#for.for_token.token;
+        self.line(
+            Some(&token),
+            &format!("{label}: for {index} in {range} generate"),
+        );
+        let items = generate_named_block_items(block);
+        self.generate_body(
+            &items,
+            block.l_brace.l_brace_token.token.line,
+            block.r_brace.r_brace_token.token.line,
+            false,
+        );
+        self.line(None, &format!("end generate {label};"));
+    }
+
+    fn assign_declaration(&mut self, arg: &AssignDeclaration) {
+        let token = arg.assign.assign_token.token;
+        let (dst, target) = match arg.assign_destination.as_ref() {
+            AssignDestination::HierarchicalIdentifier(x) => {
+                let x = x.hierarchical_identifier.as_ref();
+                let text = self.hierarchical_identifier(x);
+                let target = self.identifier_target(
+                    symbol_table::resolve(x).ok().map(|x| x.found),
+                    &text,
+                    x.hierarchical_identifier_list0
+                        .last()
+                        .map(|x| x.hierarchical_identifier_list0_list.len())
+                        .unwrap_or(x.hierarchical_identifier_list.len()),
+                    self.last_select_is_range(x),
+                );
+                (text, target)
+            }
+            AssignDestination::LBraceAssignConcatenationListRBrace(x) => {
+                let items: Vec<AssignConcatenationItem> =
+                    x.assign_concatenation_list.as_ref().into();
+                let items: Vec<_> = items
+                    .iter()
+                    .map(|x| self.hierarchical_identifier(&x.hierarchical_identifier))
+                    .collect();
+                (format!("({})", items.join(", ")), Target::unknown())
+            }
+        };
+        let rhs = self.rhs(&arg.expression, &target);
+        let text = self.assignment_text(&dst, "<=", rhs);
+        let range = TokenRange {
+            beg: token,
+            end: arg.semicolon.semicolon_token.token,
+        };
+        self.item_line(&range, &text);
+    }
+
+    fn last_select_is_range(&self, arg: &HierarchicalIdentifier) -> bool {
+        let select = if let Some(x) = arg.hierarchical_identifier_list0.last() {
+            x.hierarchical_identifier_list0_list
+                .last()
+                .map(|x| x.select.as_ref())
+        } else {
+            arg.hierarchical_identifier_list
+                .last()
+                .map(|x| x.select.as_ref())
+        };
+        select.map(|x| x.select_opt.is_some()).unwrap_or(false)
+    }
+
+    fn inst_declaration(&mut self, arg: &InstDeclaration) {
+        let label = self.declared_name(&arg.identifier);
+        let module = symbol_table::resolve(arg.scoped_identifier.as_ref()).ok();
+        let name = match &module {
+            Some(x) => vhdl_identifier(&symbol_string(
+                arg.scoped_identifier.identifier(),
+                &x.found,
+                &x.full_path,
+                &x.generic_tables,
+                &self.context(),
+            )),
+            None => vhdl_identifier(&arg.scoped_identifier.identifier().to_string()),
+        };
+        let ports = match module.as_ref().map(|x| &x.found.kind) {
+            Some(SymbolKind::Module(x)) => x.ports.clone(),
+            _ => Vec::new(),
+        };
+
+        let token = arg.inst.inst_token.token;
+        self.line(Some(&token), &format!("{label}: entity work.{name}"));
+        self.indent += 1;
+
+        if let Some(ref x) = arg.inst_declaration_opt1
+            && let Some(ref x) = x.inst_parameter.inst_parameter_opt
+        {
+            let items: Vec<InstParameterItem> = x.inst_parameter_list.as_ref().into();
+            let lines: Vec<_> = items
+                .iter()
+                .map(|x| {
+                    let name = self.declared_name(&x.identifier);
+                    let value = if let Some(ref y) = x.inst_parameter_item_opt {
+                        self.expression(&y.expression).text
+                    } else {
+                        self.identifier(&x.identifier)
+                    };
+                    (name, value)
+                })
+                .collect();
+            let ranges: Vec<TokenRange> = items.iter().map(|x| x.into()).collect();
+            self.line(None, "generic map (");
+            self.indent += 1;
+            self.association_list(&ranges, &lines, false);
+            self.indent -= 1;
+            self.line(None, ")");
+        }
+
+        let items: Vec<InstPortItem> = if let Some(ref x) = arg.inst_declaration_opt2
+            && let Some(ref x) = x.inst_declaration_opt3
+        {
+            x.inst_port_list.as_ref().into()
+        } else {
+            Vec::new()
+        };
+        if !items.is_empty() {
+            let lines: Vec<_> = items
+                .iter()
+                .map(|x| {
+                    let name = self.declared_name(&x.identifier);
+                    let target = ports
+                        .iter()
+                        .find(|p| p.name() == x.identifier.identifier_token.token.text)
+                        .map(|p| {
+                            let target = self.type_target(&p.property().r#type, TypeUsage::Signal);
+                            // width expression in the instantiated module is not available here
+                            let len = target.len.filter(|x| x.parse::<usize>().is_ok());
+                            Target { len, ..target }
+                        })
+                        .unwrap_or_else(Target::unknown);
+                    let value = if let Some(ref y) = x.inst_port_item_opt {
+                        let value = self.expression(&y.expression);
+                        self.adapt(&value, &target)
+                    } else {
+                        self.identifier(&x.identifier)
+                    };
+                    (name, value)
+                })
+                .collect();
+            let ranges: Vec<TokenRange> = items.iter().map(|x| x.into()).collect();
+            self.line(None, "port map (");
+            self.indent += 1;
+            self.association_list(&ranges, &lines, true);
+            self.indent -= 1;
+            self.line(None, ");");
+        } else {
+            let text = self.string.trim_end().to_string();
+            self.string = format!("{text};{NEWLINE}");
+        }
+        self.indent -= 1;
+        if arg.inst_declaration_opt1.is_some() && items.is_empty() {
+            // terminate generic map
+            let text = self.string.trim_end().trim_end_matches(';').to_string();
+            self.string = format!("{text};{NEWLINE}");
+        }
+    }
+
+    fn association_list(&mut self, ranges: &[TokenRange], lines: &[(String, String)], _last: bool) {
+        let width = lines.iter().map(|x| x.0.len()).max().unwrap_or(0);
+        for (i, ((name, value), range)) in lines.iter().zip(ranges).enumerate() {
+            let separator = if i + 1 == lines.len() { "" } else { "," };
+            let text = format!("{name:width$} => {value}{separator}");
+            self.item_line(range, &text);
+        }
+    }
+
+    // ------------------------------------------------------------------------
+    // Type declarations
+    // ------------------------------------------------------------------------
+
+    fn const_declaration(&mut self, arg: &ConstDeclaration) {
+        let range: TokenRange = arg.into();
+        let name = self.declared_name(&arg.identifier);
+        let (r#type, target) = match arg.const_declaration_group.as_ref() {
+            ConstDeclarationGroup::ArrayType(x) => {
+                let r#type = if x.array_type.array_type_opt.is_some() {
+                    self.signal_type(&x.array_type, &name, &range)
+                } else {
+                    self.scalar_type(&x.array_type.scalar_type, TypeUsage::Constant)
+                };
+                (
+                    r#type,
+                    self.scalar_target(&x.array_type.scalar_type, TypeUsage::Constant),
+                )
+            }
+            ConstDeclarationGroup::Type(_) => ("natural".to_string(), Target::unknown()),
+        };
+        let value = self.expression(&arg.expression);
+        let value = self.adapt(&value, &target);
+        self.item_line(&range, &format!("constant {name} : {type} := {value};"));
+    }
+
+    fn type_def_declaration(&mut self, arg: &TypeDefDeclaration) {
+        let range: TokenRange = arg.into();
+        let name = self.declared_name(&arg.identifier);
+        if let Some(ref x) = arg.array_type.array_type_opt {
+            let element = self.scalar_type(&arg.array_type.scalar_type, TypeUsage::Signal);
+            let dims: Vec<Expression> = x.array.as_ref().into();
+            let r#type = self.array_definition(&dims, &element, &name, &range);
+            self.item_line(&range, &format!("type {name} is {type};"));
+        } else {
+            let r#type = self.scalar_type(&arg.array_type.scalar_type, TypeUsage::Signal);
+            self.item_line(&range, &format!("subtype {name} is {type};"));
+        }
+    }
+
+    fn enum_declaration(&mut self, arg: &EnumDeclaration) {
+        let range: TokenRange = arg.into();
+        let name = self.declared_name(&arg.identifier);
+        let symbol = symbol_table::resolve(arg.identifier.as_ref()).unwrap();
+        let SymbolKind::Enum(property) = symbol.found.kind else {
+            unreachable!()
+        };
+
+        let (r#type, target) = if let Some(ref x) = arg.enum_declaration_opt {
+            let r#type = self.scalar_type(&x.scalar_type, TypeUsage::Signal);
+            let target = self.scalar_target(&x.scalar_type, TypeUsage::Signal);
+            (r#type, target)
+        } else {
+            let width = property.width.max(1);
+            let target = Target {
+                kind: Kind::Vector,
+                len: Some(width.to_string()),
+                signed: false,
+            };
+            (format!("std_logic_vector({} downto 0)", width - 1), target)
+        };
+        let len = target.len.unwrap_or(format!("{name}'length"));
+        let head = TokenRange {
+            beg: range.beg,
+            end: arg.l_brace.l_brace_token.token,
+        };
+        self.item_line(&head, &format!("subtype {name} is {type};"));
+
+        for id in &property.members {
+            let member = symbol_table::get(*id).unwrap();
+            let SymbolKind::EnumMember(ref x) = member.kind else {
+                unreachable!()
+            };
+            let text = symbol_string(
+                &VerylToken::new(member.token),
+                &member,
+                &[],
+                &GenericTables::default(),
+                &self.context(),
+            );
+            let member_name = vhdl_identifier(&text);
+            let value = match x.value.value() {
+                Some(value) if target.kind == Kind::Bit => format!("'{}'", value & 1),
+                Some(value) if len.parse::<usize>().is_ok() => format!("{len}d\"{value}\""),
+                Some(value) => format!("to_slv({value}, {len})"),
+                None => "(others => '0')".to_string(),
+            };
+            let member_range = TokenRange {
+                beg: member.token,
+                end: member.token,
+            };
+            self.item_line(
+                &member_range,
+                &format!("constant {member_name} : {name} := {value};"),
+            );
+        }
+        // drop comments in the enum body
+        self.take_comments(range.beg.line, range.end.line + 1);
+    }
+
+    fn struct_union_declaration(&mut self, arg: &StructUnionDeclaration) {
+        let range: TokenRange = arg.into();
+        let name = self.declared_name(&arg.identifier);
+        let head = TokenRange {
+            beg: range.beg,
+            end: arg.l_brace.l_brace_token.token,
+        };
+        self.item_line(&head, &format!("type {name} is record"));
+        self.indent += 1;
+        let items: Vec<StructUnionItem> = arg.struct_union_list.as_ref().into();
+        let lines: Vec<_> = items
+            .iter()
+            .map(|x| {
+                let member = vhdl_identifier(&x.identifier.identifier_token.to_string());
+                let r#type = self.scalar_type(&x.scalar_type, TypeUsage::Signal);
+                (member, r#type)
+            })
+            .collect();
+        let width = lines.iter().map(|x| x.0.len()).max().unwrap_or(0);
+        for ((member, r#type), item) in lines.iter().zip(&items) {
+            let item_range = TokenRange {
+                beg: item.identifier.identifier_token.token,
+                end: item.scalar_type.last(),
+            };
+            self.item_line(&item_range, &format!("{member:width$} : {type};"));
+        }
+        self.indent -= 1;
+        self.line(
+            Some(&arg.r_brace.r_brace_token.token),
+            &format!("end record {name};"),
+        );
+        self.take_comments(range.beg.line, range.end.line + 1);
+    }
+
+    /// Returns the type of signal and emits the declaration of array type if required
+    fn signal_type(&mut self, arg: &ArrayType, name: &str, range: &TokenRange) -> String {
+        let element = self.scalar_type(&arg.scalar_type, TypeUsage::Signal);
+        if let Some(ref x) = arg.array_type_opt {
+            let dims: Vec<Expression> = x.array.as_ref().into();
+            let type_name = vhdl_identifier(&format!("{}_t", name.trim_matches('\\')));
+            let r#type = self.array_definition(&dims, &element, &type_name, range);
+            self.line(Some(&range.beg), &format!("type {type_name} is {type};"));
+            type_name
+        } else {
+            element
+        }
+    }
+
+    /// Returns the array type definition of the outermost dimension.
+    /// Inner dimensions are emitted as separated type declarations.
+    fn array_definition(
+        &mut self,
+        dims: &[Expression],
+        element: &str,
+        name: &str,
+        range: &TokenRange,
+    ) -> String {
+        let mut element = element.to_string();
+        for (i, dim) in dims.iter().enumerate().skip(1).rev() {
+            let type_name = vhdl_identifier(&format!("{}{}", name.trim_matches('\\'), i));
+            let high = self.high_index(dim);
+            self.line(
+                Some(&range.beg),
+                &format!("type {type_name} is array (0 to {high}) of {element};"),
+            );
+            element = type_name;
+        }
+        let high = self.high_index(&dims[0]);
+        format!("array (0 to {high}) of {element}")
+    }
+
+    /// Returns `expression - 1`
+    fn high_index(&mut self, arg: &Expression) -> String {
+        let x = self.expression(arg);
+        minus_one(x)
+    }
+
+    fn scalar_type(&mut self, arg: &ScalarType, usage: TypeUsage) -> String {
+        let signed = arg
+            .scalar_type_list
+            .iter()
+            .any(|x| matches!(x.type_modifier.as_ref(), TypeModifier::Signed(_)));
+        match arg.scalar_type_group.as_ref() {
+            ScalarTypeGroup::UserDefinedTypeScalarTypeOpt(x) => {
+                self.scoped_identifier(&x.user_defined_type.scoped_identifier)
+            }
+            ScalarTypeGroup::FactorType(x) => match x.factor_type.factor_type_group.as_ref() {
+                FactorTypeGroup::VariableTypeFactorTypeOpt(x) => {
+                    let base = if signed { "signed" } else { "std_logic_vector" };
+                    match &x.factor_type_opt {
+                        Some(x) if usage == TypeUsage::Mark => {
+                            let _ = x;
+                            base.to_string()
+                        }
+                        Some(x) => {
+                            let high = self.high_index(&x.width.expression);
+                            format!("{base}({high} downto 0)")
+                        }
+                        None => "std_logic".to_string(),
+                    }
+                }
+                FactorTypeGroup::FixedType(x) => fixed_type(&x.fixed_type, usage),
+            },
+        }
+    }
+
+    fn scalar_target(&mut self, arg: &ScalarType, usage: TypeUsage) -> Target {
+        let signed = arg
+            .scalar_type_list
+            .iter()
+            .any(|x| matches!(x.type_modifier.as_ref(), TypeModifier::Signed(_)));
+        match arg.scalar_type_group.as_ref() {
+            ScalarTypeGroup::UserDefinedTypeScalarTypeOpt(x) => {
+                let name = self.scoped_identifier(&x.user_defined_type.scoped_identifier);
+                match symbol_table::resolve(x.user_defined_type.scoped_identifier.as_ref())
+                    .map(|x| x.found)
+                {
+                    Ok(symbol) if matches!(symbol.kind, SymbolKind::Enum(_)) => {
+                        let mut target = self.enum_target(&symbol);
+                        if target.kind == Kind::Vector {
+                            target.len = Some(format!("{name}'length"));
+                        }
+                        target
+                    }
+                    Ok(Symbol {
+                        kind: SymbolKind::TypeDef(x),
+                        ..
+                    }) if x.r#type.array.is_empty() => {
+                        let mut target = self.type_target(&x.r#type, usage);
+                        if target.kind == Kind::Vector {
+                            target.len = Some(format!("{name}'length"));
+                        }
+                        target
+                    }
+                    _ => Target::unknown(),
+                }
+            }
+            ScalarTypeGroup::FactorType(x) => match x.factor_type.factor_type_group.as_ref() {
+                FactorTypeGroup::VariableTypeFactorTypeOpt(x) => match &x.factor_type_opt {
+                    Some(x) => {
+                        let len = self.expression(&x.width.expression);
+                        Target {
+                            kind: Kind::Vector,
+                            len: Some(len.wrapped()),
+                            signed,
+                        }
+                    }
+                    None => Target {
+                        kind: Kind::Bit,
+                        len: None,
+                        signed: false,
+                    },
+                },
+                FactorTypeGroup::FixedType(x) => fixed_target(&x.fixed_type, usage),
+            },
+        }
+    }
+
+    fn array_target(&mut self, arg: &ArrayType, name: &str) -> Target {
+        if arg.array_type_opt.is_some() {
+            return Target::unknown();
+        }
+        let mut target = self.scalar_target(&arg.scalar_type, TypeUsage::Signal);
+        if target.kind == Kind::Vector {
+            target.len = Some(format!("{name}'length"));
+        }
+        target
+    }
+
+    fn type_target(&mut self, arg: &SymType, usage: TypeUsage) -> Target {
+        if !arg.array.is_empty() {
+            return Target::unknown();
+        }
+        if let Some(ref x) = arg.array_type {
+            return self.scalar_target(&x.scalar_type, usage);
+        }
+        let signed = arg
+            .modifier
+            .iter()
+            .any(|x| x.kind == TypeModifierKind::Signed);
+        let bits = match arg.kind {
+            TypeKind::Clock
+            | TypeKind::ClockPosedge
+            | TypeKind::ClockNegedge
+            | TypeKind::Reset
+            | TypeKind::ResetAsyncHigh
+            | TypeKind::ResetAsyncLow
+            | TypeKind::ResetSyncHigh
+            | TypeKind::ResetSyncLow
+            | TypeKind::Bit
+            | TypeKind::Logic => {
+                if let Some(x) = arg.width.first() {
+                    let len = self.expression(x);
+                    return Target {
+                        kind: Kind::Vector,
+                        len: Some(len.wrapped()),
+                        signed,
+                    };
+                } else {
+                    return Target {
+                        kind: Kind::Bit,
+                        len: None,
+                        signed: false,
+                    };
+                }
+            }
+            TypeKind::U8 | TypeKind::I8 => 8,
+            TypeKind::U16 | TypeKind::I16 => 16,
+            TypeKind::U32 | TypeKind::I32 => 32,
+            TypeKind::U64 | TypeKind::I64 => 64,
+            TypeKind::Bool if usage == TypeUsage::Constant => {
+                return Target {
+                    kind: Kind::Bool,
+                    len: None,
+                    signed: false,
+                };
+            }
+            TypeKind::Bool => {
+                return Target {
+                    kind: Kind::Bit,
+                    len: None,
+                    signed: false,
+                };
+            }
+            TypeKind::UserDefined(ref x) => {
+                return match x.symbol.and_then(symbol_table::get) {
+                    Some(symbol) if matches!(symbol.kind, SymbolKind::Enum(_)) => {
+                        self.enum_target(&symbol)
+                    }
+                    Some(Symbol {
+                        kind: SymbolKind::TypeDef(x),
+                        ..
+                    }) => self.type_target(&x.r#type, usage),
+                    _ => Target::unknown(),
+                };
+            }
+            _ => return Target::unknown(),
+        };
+        let signed = matches!(
+            arg.kind,
+            TypeKind::I8 | TypeKind::I16 | TypeKind::I32 | TypeKind::I64
+        );
+        if usage == TypeUsage::Constant {
+            Target {
+                kind: Kind::Int,
+                len: None,
+                signed,
+            }
+        } else {
+            Target {
+                kind: Kind::Vector,
+                len: Some(bits.to_string()),
+                signed,
+            }
+        }
+    }
+
+    fn enum_target(&mut self, symbol: &Symbol) -> Target {
+        let SymbolKind::Enum(ref x) = symbol.kind else {
+            return Target::unknown();
+        };
+        match &x.r#type {
+            Some(x) => {
+                let target = self.type_target(x, TypeUsage::Signal);
+                Target {
+                    len: None,
+                    ..target
+                }
+            }
+            None => Target {
+                kind: Kind::Vector,
+                len: None,
+                signed: false,
+            },
+        }
+    }
+
+    /// Returns the target of the referred symbol.
+    /// `selects` is the number of selects applied to the last element.
+    fn identifier_target(
+        &mut self,
+        symbol: Option<Symbol>,
+        text: &str,
+        selects: usize,
+        range_select: bool,
+    ) -> Target {
+        let Some(symbol) = symbol else {
+            return Target::unknown();
+        };
+        let (r#type, usage) = match &symbol.kind {
+            SymbolKind::Variable(x) if x.loop_variable => {
+                return Target {
+                    kind: Kind::Int,
+                    len: None,
+                    signed: false,
+                };
+            }
+            SymbolKind::Genvar => {
+                return Target {
+                    kind: Kind::Int,
+                    len: None,
+                    signed: false,
+                };
+            }
+            SymbolKind::Variable(x) => (x.r#type.clone(), TypeUsage::Signal),
+            SymbolKind::Port(x) => (x.r#type.clone(), TypeUsage::Signal),
+            SymbolKind::Parameter(x) => (x.r#type.clone(), TypeUsage::Constant),
+            SymbolKind::StructMember(x) => (x.r#type.clone(), TypeUsage::Signal),
+            SymbolKind::EnumMember(_) => {
+                let mut namespace = symbol.namespace.clone();
+                let name = namespace.pop();
+                return name
+                    .and_then(|x| symbol_table::resolve((&SymbolPath::new(&[x]), &namespace)).ok())
+                    .map(|x| self.enum_target(&x.found))
+                    .unwrap_or_else(Target::unknown);
+            }
+            _ => return Target::unknown(),
+        };
+
+        let dims = r#type.array.len();
+        let mut base = r#type.clone();
+        base.array.clear();
+        let target = if selects < dims {
+            Target::unknown()
+        } else if selects == dims {
+            self.type_target(&base, usage)
+        } else if range_select {
+            Target {
+                kind: Kind::Vector,
+                len: None,
+                signed: false,
+            }
+        } else {
+            Target {
+                kind: Kind::Bit,
+                len: None,
+                signed: false,
+            }
+        };
+        if target.kind == Kind::Vector {
+            Target {
+                len: Some(format!("{text}'length")),
+                ..target
+            }
+        } else {
+            target
+        }
+    }
+
+    // ------------------------------------------------------------------------
+    // Function
+    // ------------------------------------------------------------------------
+
+    fn function_header(&mut self, arg: &FunctionDeclaration) -> Vec<String> {
+        let name = self.declared_name(&arg.identifier);
+        let ret = if let Some(ref x) = arg.function_declaration_opt1 {
+            self.scalar_type(&x.scalar_type, TypeUsage::Mark)
+        } else {
+            "std_logic".to_string()
+        };
+        let items: Vec<PortDeclarationItem> = if let Some(ref x) = arg.function_declaration_opt0
+            && let Some(ref x) = x.port_declaration.port_declaration_opt
+        {
+            x.port_declaration_list.as_ref().into()
+        } else {
+            Vec::new()
+        };
+        if items.is_empty() {
+            return vec![format!("function {name} return {ret}")];
+        }
+
+        let mut ret_lines = vec![format!("function {name} (")];
+        let lines: Vec<_> = items
+            .iter()
+            .map(|x| {
+                let name = self.declared_name(&x.identifier);
+                let r#type = match x.port_declaration_item_group.as_ref() {
+                    PortDeclarationItemGroup::PortTypeConcrete(x) => self.scalar_type(
+                        &x.port_type_concrete.array_type.scalar_type,
+                        TypeUsage::Signal,
+                    ),
+                    PortDeclarationItemGroup::PortTypeAbstract(_) => "std_logic".to_string(),
+                };
+                (name, r#type)
+            })
+            .collect();
+        let width = lines.iter().map(|x| x.0.len()).max().unwrap_or(0);
+        let indent = " ".repeat(self.indent_width);
+        for (i, (name, r#type)) in lines.iter().enumerate() {
+            let separator = if i + 1 == lines.len() { "" } else { ";" };
+            ret_lines.push(format!("{indent}{name:width$} : {type}{separator}"));
+        }
+        ret_lines.push(format!(") return {ret}"));
+        ret_lines
+    }
+
+    fn function_declaration(&mut self, arg: &FunctionDeclaration) {
+        let name = self.declared_name(&arg.identifier);
+        let range: TokenRange = arg.into();
+        let header = self.function_header(arg);
+        let last = header.len() - 1;
+        for (i, line) in header.iter().enumerate() {
+            let line = if i == last {
+                format!("{line} is")
+            } else {
+                line.clone()
+            };
+            let token = (i == 0).then_some(&range.beg);
+            self.line(token, &line);
+        }
+        // parameters are emitted in the header
+        let block_line = arg.statement_block.l_brace.l_brace_token.token.line;
+        self.take_comments(range.beg.line, block_line);
+
+        self.function_return = arg
+            .function_declaration_opt1
+            .as_ref()
+            .map(|x| self.scalar_target(&x.scalar_type, TypeUsage::Signal));
+        self.indent += 1;
+        self.block_declarations(&arg.statement_block);
+        self.indent -= 1;
+        self.line(None, "begin");
+        self.indent += 1;
+        self.statement_block(&arg.statement_block);
+        self.indent -= 1;
+        self.line(
+            Some(&arg.statement_block.r_brace.r_brace_token.token),
+            &format!("end function {name};"),
+        );
+        self.function_return = None;
+    }
+
+    // ------------------------------------------------------------------------
+    // Process
+    // ------------------------------------------------------------------------
+
+    fn process<F: FnOnce(&mut Self, &StatementBlock)>(
+        &mut self,
+        token: &Token,
+        header: &str,
+        block: &StatementBlock,
+        body: F,
+    ) {
+        self.line(Some(token), header);
+        self.indent += 1;
+        self.block_declarations(block);
+        self.indent -= 1;
+        self.line(None, "begin");
+        self.indent += 1;
+        body(self, block);
+        self.indent -= 1;
+        self.line(Some(&block.r_brace.r_brace_token.token), "end process;");
+    }
+
+    fn always_ff_declaration(&mut self, arg: &AlwaysFfDeclaration) {
+        let (clock, clock_type) = if let Some(x) = arg.get_explicit_clock() {
+            let text = self.hierarchical_identifier(&x);
+            let symbol = symbol_table::resolve(&x).ok().map(|x| x.found);
+            (text, self.clock_type(symbol.as_ref()))
+        } else {
+            let symbol = self.default_clock.and_then(symbol_table::get);
+            let text = symbol.as_ref().map(symbol_name).unwrap_or_default();
+            (text, self.clock_type(symbol.as_ref()))
+        };
+        let reset = if let Some(x) = arg.get_explicit_reset() {
+            let text = self.hierarchical_identifier(&x);
+            let symbol = symbol_table::resolve(&x).ok().map(|x| x.found);
+            Some((text, self.reset_type(symbol.as_ref())))
+        } else if arg.has_if_reset() {
+            let symbol = self.default_reset.and_then(symbol_table::get);
+            let text = symbol.as_ref().map(symbol_name).unwrap_or_default();
+            Some((text, self.reset_type(symbol.as_ref())))
+        } else {
+            None
+        };
+
+        let edge = match clock_type {
+            ClockType::PosEdge => "rising_edge",
+            ClockType::NegEdge => "falling_edge",
+        };
+        let (async_reset, sensitivity) = match &reset {
+            Some((text, ResetType::AsyncHigh | ResetType::AsyncLow)) if arg.has_if_reset() => {
+                (true, format!("{clock}, {text}"))
+            }
+            _ => (false, clock.clone()),
+        };
+        self.reset_condition = reset.map(|(text, reset_type)| match reset_type {
+            ResetType::AsyncHigh | ResetType::SyncHigh => format!("{text} = '1'"),
+            ResetType::AsyncLow | ResetType::SyncLow => format!("{text} = '0'"),
+        });
+
+        let token = arg.always_ff.always_ff_token.token;
+        let header = format!("process ({sensitivity}) is");
+        self.process(&token, &header, &arg.statement_block, |s, block| {
+            let items = statement_block_items(block);
+            let open_line = block.l_brace.l_brace_token.token.line;
+            let close_line = block.r_brace.r_brace_token.token.line;
+            let if_reset = match items.first() {
+                Some(StatementBlockItem::Statement(x)) if async_reset => match x.statement.as_ref()
+                {
+                    Statement::IfResetStatement(x) => Some(x.if_reset_statement.as_ref().clone()),
+                    _ => None,
+                },
+                _ => None,
+            };
+
+            if let Some(x) = if_reset {
+                let condition = s.reset_condition.clone().unwrap();
+                s.line(
+                    Some(&x.if_reset.if_reset_token.token),
+                    &format!("if {condition} then"),
+                );
+                s.indent += 1;
+                s.statement_block(&x.statement_block);
+                s.indent -= 1;
+                s.line(None, &format!("elsif {edge}({clock}) then"));
+                s.indent += 1;
+                if !x.if_reset_statement_list.is_empty() || x.if_reset_statement_opt.is_some() {
+                    for (i, y) in x.if_reset_statement_list.iter().enumerate() {
+                        let cond = s.condition(&y.expression);
+                        let keyword = if i == 0 { "if" } else { "elsif" };
+                        s.line(
+                            Some(&y.r#if.if_token.token),
+                            &format!("{keyword} {cond} then"),
+                        );
+                        s.indent += 1;
+                        s.statement_block(&y.statement_block);
+                        s.indent -= 1;
+                    }
+                    if let Some(ref y) = x.if_reset_statement_opt {
+                        if x.if_reset_statement_list.is_empty() {
+                            s.statement_block(&y.statement_block);
+                        } else {
+                            s.line(Some(&y.r#else.else_token.token), "else");
+                            s.indent += 1;
+                            s.statement_block(&y.statement_block);
+                            s.indent -= 1;
+                        }
+                    }
+                    if !x.if_reset_statement_list.is_empty() {
+                        s.line(None, "end if;");
+                    }
+                }
+                let range: TokenRange = (&x).into();
+                s.statement_items(&items[1..], range.end.line, close_line);
+                s.indent -= 1;
+                s.line(None, "end if;");
+            } else {
+                s.line(None, &format!("if {edge}({clock}) then"));
+                s.indent += 1;
+                s.statement_items(&items, open_line, close_line);
+                s.indent -= 1;
+                s.line(None, "end if;");
+            }
+        });
+        self.reset_condition = None;
+    }
+
+    fn clock_type(&self, symbol: Option<&Symbol>) -> ClockType {
+        match symbol.and_then(variable_type_kind) {
+            Some(TypeKind::ClockPosedge) => ClockType::PosEdge,
+            Some(TypeKind::ClockNegedge) => ClockType::NegEdge,
+            _ => self.build_opt.clock_type,
+        }
+    }
+
+    fn reset_type(&self, symbol: Option<&Symbol>) -> ResetType {
+        match symbol.and_then(variable_type_kind) {
+            Some(TypeKind::ResetAsyncHigh) => ResetType::AsyncHigh,
+            Some(TypeKind::ResetAsyncLow) => ResetType::AsyncLow,
+            Some(TypeKind::ResetSyncHigh) => ResetType::SyncHigh,
+            Some(TypeKind::ResetSyncLow) => ResetType::SyncLow,
+            _ => self.build_opt.reset_type,
+        }
+    }
+
+    // ------------------------------------------------------------------------
+    // Statement
+    // ------------------------------------------------------------------------
+
+    /// Emits declarations of variables and constants in the block and its nested blocks
+    fn block_declarations(&mut self, arg: &StatementBlock) {
+        for x in statement_block_items(arg) {
+            match &x {
+                StatementBlockItem::VarDeclaration(x) => {
+                    let x = x.var_declaration.as_ref();
+                    let range: TokenRange = x.into();
+                    let name = self.declared_name(&x.identifier);
+                    let r#type = self.signal_type(&x.array_type, &name, &range);
+                    self.item_line(&range, &format!("variable {name} : {type};"));
+                }
+                StatementBlockItem::LetStatement(x) => {
+                    let x = x.let_statement.as_ref();
+                    let range = TokenRange {
+                        beg: x.r#let.let_token.token,
+                        end: x.identifier.identifier_token.token,
+                    };
+                    let name = self.declared_name(&x.identifier);
+                    let r#type = self.signal_type(&x.array_type, &name, &range);
+                    self.line(Some(&range.beg), &format!("variable {name} : {type};"));
+                }
+                StatementBlockItem::ConstDeclaration(x) => {
+                    self.const_declaration(&x.const_declaration)
+                }
+                StatementBlockItem::Statement(x) => {
+                    for block in nested_blocks(&x.statement) {
+                        self.block_declarations(block);
+                    }
+                }
+            }
+        }
+    }
+
+    fn statement_block(&mut self, arg: &StatementBlock) {
+        let items = statement_block_items(arg);
+        self.statement_items(
+            &items,
+            arg.l_brace.l_brace_token.token.line,
+            arg.r_brace.r_brace_token.token.line,
+        );
+    }
+
+    fn statement_items(&mut self, items: &[StatementBlockItem], open_line: u32, close_line: u32) {
+        let mut prev_line = open_line;
+        let mut first = true;
+        for x in items {
+            let range: TokenRange = x.into();
+            if matches!(
+                x,
+                StatementBlockItem::LetStatement(_) | StatementBlockItem::Statement(_)
+            ) {
+                if !first {
+                    self.blank_line(prev_line, range.beg.line);
+                }
+                first = false;
+            }
+            match x {
+                StatementBlockItem::LetStatement(x) => {
+                    self.leading_comments(prev_line, range.beg.line);
+                    let x = x.let_statement.as_ref();
+                    let name = self.declared_name(&x.identifier);
+                    let target = self.array_target(&x.array_type, &name);
+                    let rhs = self.rhs(&x.expression, &target);
+                    let text = self.assignment_text(&name, ":=", rhs);
+                    self.item_line(&range, &text);
+                }
+                StatementBlockItem::Statement(x) => {
+                    self.leading_comments(prev_line, range.beg.line);
+                    self.statement(&x.statement, &range);
+                }
+                _ => (),
+            }
+            prev_line = range.end.line;
+        }
+        self.leading_comments(prev_line, close_line);
+    }
+
+    fn statement(&mut self, arg: &Statement, range: &TokenRange) {
+        match arg {
+            Statement::IdentifierStatement(x) => self.identifier_statement(&x.identifier_statement),
+            Statement::IfStatement(x) => self.if_statement(&x.if_statement),
+            Statement::IfResetStatement(x) => self.if_reset_statement(&x.if_reset_statement),
+            Statement::ReturnStatement(x) => {
+                let value = self.expression(&x.return_statement.expression);
+                let target = self.function_return.clone().unwrap_or_else(Target::unknown);
+                if value.kind == Kind::Bool && target.kind == Kind::Bit {
+                    self.item_line(range, &format!("if {} then", value.text));
+                    self.indent += 1;
+                    self.line(None, "return '1';");
+                    self.indent -= 1;
+                    self.line(None, "end if;");
+                    self.line(None, "return '0';");
+                } else {
+                    let value = self.adapt(&value, &target);
+                    self.item_line(range, &format!("return {value};"));
+                }
+            }
+            Statement::BreakStatement(_) => self.item_line(range, "exit;"),
+            Statement::ForStatement(x) => self.for_statement(&x.for_statement),
+            Statement::CaseStatement(x) => self.case_statement(&x.case_statement),
+            Statement::SwitchStatement(x) => self.switch_statement(&x.switch_statement),
+            // assertion is rejected by analyzer
+            Statement::AssertionStatement(_) => (),
+        }
+    }
+
+    fn identifier_statement(&mut self, arg: &IdentifierStatement) {
+        let range = TokenRange {
+            beg: arg.expression_identifier.first(),
+            end: arg.semicolon.semicolon_token.token,
+        };
+        match arg.identifier_statement_group.as_ref() {
+            IdentifierStatementGroup::FunctionCall(x) => {
+                let text =
+                    self.function_call_statement(&arg.expression_identifier, &x.function_call);
+                self.item_line(&range, &text);
+            }
+            IdentifierStatementGroup::Assignment(x) => {
+                let dst = self.expression_identifier(&arg.expression_identifier);
+                let symbol = symbol_table::resolve(arg.expression_identifier.as_ref())
+                    .ok()
+                    .map(|x| x.found);
+                let operator = match &symbol {
+                    Some(Symbol {
+                        kind: SymbolKind::Variable(x),
+                        ..
+                    }) if matches!(
+                        x.affiliation,
+                        VariableAffiliation::StatementBlock | VariableAffiliation::Function
+                    ) =>
+                    {
+                        ":="
+                    }
+                    _ => "<=",
+                };
+                let x = x.assignment.as_ref();
+                let target = self.expression_identifier_target(
+                    &arg.expression_identifier,
+                    &dst.text,
+                    symbol,
+                );
+                let text = match x.assignment_group.as_ref() {
+                    AssignmentGroup::AssignmentOperator(y) => {
+                        let op = y.assignment_operator.assignment_operator_token.to_string();
+                        let op = op.trim_end_matches('=');
+                        let rhs = self.expression(&x.expression);
+                        let value = self.binary(dst.clone(), op, rhs);
+                        let value = self.adapt(&value, &target);
+                        format!("{} {operator} {value};", dst.text)
+                    }
+                    _ => {
+                        let rhs = self.rhs(&x.expression, &target);
+                        self.assignment_text(&dst.text, operator, rhs)
+                    }
+                };
+                self.item_line(&range, &text);
+            }
+        }
+    }
+
+    fn expression_identifier_target(
+        &mut self,
+        arg: &ExpressionIdentifier,
+        text: &str,
+        symbol: Option<Symbol>,
+    ) -> Target {
+        let (selects, range_select) = if let Some(x) = arg.expression_identifier_list0.last() {
+            (
+                x.expression_identifier_list0_list.len(),
+                x.expression_identifier_list0_list
+                    .last()
+                    .map(|x| x.select.select_opt.is_some())
+                    .unwrap_or(false),
+            )
+        } else {
+            (
+                arg.expression_identifier_list.len(),
+                arg.expression_identifier_list
+                    .last()
+                    .map(|x| x.select.select_opt.is_some())
+                    .unwrap_or(false),
+            )
+        };
+        self.identifier_target(symbol, text, selects, range_select)
+    }
+
+    fn function_call_statement(
+        &mut self,
+        name: &ExpressionIdentifier,
+        arg: &FunctionCall,
+    ) -> String {
+        let text = name.scoped_identifier.identifier().to_string();
+        let args: Vec<Operand> = if let Some(ref x) = arg.function_call_opt {
+            let items: Vec<ArgumentItem> = x.argument_list.as_ref().into();
+            items
+                .iter()
+                .map(|x| self.expression(&x.argument_expression.expression))
+                .collect()
+        } else {
+            Vec::new()
+        };
+        let message = args
+            .iter()
+            .map(|x| x.text.clone())
+            .collect::<Vec<_>>()
+            .join(" & ");
+        match text.as_str() {
+            "$display" | "$write" | "$info" => format!("report {message};"),
+            "$warning" => format!("report {message} severity warning;"),
+            "$error" => format!("report {message} severity error;"),
+            "$fatal" => format!("report {message} severity failure;"),
+            "$finish" => "std.env.finish;".to_string(),
+            "$stop" => "std.env.stop;".to_string(),
+            _ => {
+                let name = self.expression_identifier(name).text;
+                if args.is_empty() {
+                    format!("{name};")
+                } else {
+                    let args: Vec<_> = args.iter().map(|x| x.text.clone()).collect();
+                    format!("{name}({});", args.join(", "))
+                }
+            }
+        }
+    }
+
+    fn assignment_text(&mut self, dst: &str, operator: &str, rhs: Rhs) -> String {
+        match rhs {
+            Rhs::Plain(x) => format!("{dst} {operator} {x};"),
+            Rhs::Conditional(items, default) => {
+                let mut text = format!("{dst} {operator}");
+                for (value, cond) in items {
+                    text.push_str(&format!(" {value} when {cond} else"));
+                }
+                format!("{text} {default};")
+            }
+            Rhs::Selected(selector, items, default) => {
+                let mut text = format!("with {selector} select {dst} {operator}");
+                for (value, choice) in items {
+                    text.push_str(&format!(" {value} when {choice},"));
+                }
+                format!("{text} {default} when others;")
+            }
+        }
+    }
+
+    fn if_statement(&mut self, arg: &IfStatement) {
+        let cond = self.condition(&arg.expression);
+        self.line(Some(&arg.r#if.if_token.token), &format!("if {cond} then"));
+        self.indented_block(&arg.statement_block);
+        for x in &arg.if_statement_list {
+            let cond = self.condition(&x.expression);
+            self.line(Some(&x.r#if.if_token.token), &format!("elsif {cond} then"));
+            self.indented_block(&x.statement_block);
+        }
+        if let Some(ref x) = arg.if_statement_opt {
+            self.line(Some(&x.r#else.else_token.token), "else");
+            self.indented_block(&x.statement_block);
+        }
+        self.line(None, "end if;");
+    }
+
+    fn if_reset_statement(&mut self, arg: &IfResetStatement) {
+        let cond = self.reset_condition.clone().unwrap_or_default();
+        self.line(
+            Some(&arg.if_reset.if_reset_token.token),
+            &format!("if {cond} then"),
+        );
+        self.indented_block(&arg.statement_block);
+        for x in &arg.if_reset_statement_list {
+            let cond = self.condition(&x.expression);
+            self.line(Some(&x.r#if.if_token.token), &format!("elsif {cond} then"));
+            self.indented_block(&x.statement_block);
+        }
+        if let Some(ref x) = arg.if_reset_statement_opt {
+            self.line(Some(&x.r#else.else_token.token), "else");
+            self.indented_block(&x.statement_block);
+        }
+        self.line(None, "end if;");
+    }
+
+    fn indented_block(&mut self, arg: &StatementBlock) {
+        self.indent += 1;
+        self.statement_block(arg);
+        self.indent -= 1;
+    }
+
+    fn for_statement(&mut self, arg: &ForStatement) {
+        let index = self.declared_name(&arg.identifier);
+        let range = self.range(&arg.range);
+        self.line(
+            Some(&arg.r#for.for_token.token),
+            &format!("for {index} in {range} loop"),
+        );
+        self.indented_block(&arg.statement_block);
+        self.line(None, "end loop;");
+    }
+
+    fn case_statement(&mut self, arg: &CaseStatement) {
+        let selector = self.expression(&arg.expression);
+        let conditions: Vec<_> = arg
+            .case_statement_list
+            .iter()
+            .filter_map(|x| match x.case_item.case_item_group.as_ref() {
+                CaseItemGroup::CaseCondition(x) => Some(x.case_condition.as_ref()),
+                CaseItemGroup::Defaul(_) => None,
+            })
+            .collect();
+        let (selector, kind) = case_selector(&selector, &conditions);
+        self.line(
+            Some(&arg.case.case_token.token),
+            &format!("case {selector} is"),
+        );
+        self.indent += 1;
+        let mut has_default = false;
+        for x in &arg.case_statement_list {
+            let x = x.case_item.as_ref();
+            let choice = match x.case_item_group.as_ref() {
+                CaseItemGroup::CaseCondition(y) => self.case_choices(&y.case_condition, kind),
+                CaseItemGroup::Defaul(_) => {
+                    has_default = true;
+                    "others".to_string()
+                }
+            };
+            let range: TokenRange = x.into();
+            match x.case_item_group0.as_ref() {
+                CaseItemGroup0::Statement(y) => {
+                    self.line(Some(&range.beg), &format!("when {choice} =>"));
+                    self.indent += 1;
+                    let stmt_range: TokenRange = y.statement.as_ref().into();
+                    self.statement(&y.statement, &stmt_range);
+                    self.indent -= 1;
+                }
+                CaseItemGroup0::StatementBlock(y) => {
+                    self.line(Some(&range.beg), &format!("when {choice} =>"));
+                    self.indented_block(&y.statement_block);
+                }
+            }
+        }
+        if !has_default {
+            self.line(None, "when others =>");
+            self.indent += 1;
+            self.line(None, "null;");
+            self.indent -= 1;
+        }
+        self.indent -= 1;
+        self.line(Some(&arg.r_brace.r_brace_token.token), "end case;");
+    }
+
+    /// Returns choices of case item for the selector of the given kind
+    fn case_choices(&mut self, arg: &CaseCondition, kind: Kind) -> String {
+        let items: Vec<RangeItem> = arg.into();
+        let choices: Vec<_> = items
+            .iter()
+            .map(|x| {
+                if x.range.range_opt.is_some() {
+                    self.range(&x.range)
+                } else {
+                    let x = self.expression(&x.range.expression);
+                    match kind {
+                        Kind::Int => integer_choice(&x),
+                        Kind::Bit => to_bit_literal(x).text,
+                        _ => x.text,
+                    }
+                }
+            })
+            .collect();
+        choices.join(" | ")
+    }
+
+    fn switch_statement(&mut self, arg: &SwitchStatement) {
+        let mut first = true;
+        let mut default = None;
+        for x in &arg.switch_statement_list {
+            let x = x.switch_item.as_ref();
+            let range: TokenRange = x.into();
+            match x.switch_item_group.as_ref() {
+                SwitchItemGroup::SwitchCondition(y) => {
+                    let cond = self.switch_condition(&y.switch_condition);
+                    let keyword = if first { "if" } else { "elsif" };
+                    first = false;
+                    self.line(Some(&range.beg), &format!("{keyword} {cond} then"));
+                }
+                SwitchItemGroup::Defaul(_) => {
+                    default = Some(x.clone());
+                    continue;
+                }
+            }
+            self.switch_item_body(x);
+        }
+        if let Some(x) = default {
+            let range: TokenRange = (&x).into();
+            if first {
+                self.switch_item_body_without_indent(&x);
+                return;
+            }
+            self.line(Some(&range.beg), "else");
+            self.switch_item_body(&x);
+        }
+        if !first {
+            self.line(None, "end if;");
+        }
+    }
+
+    fn switch_condition(&mut self, arg: &SwitchCondition) -> String {
+        let items: Vec<Expression> = arg.into();
+        let conds: Vec<_> = items.iter().map(|x| self.condition(x)).collect();
+        if conds.len() == 1 {
+            conds[0].clone()
+        } else {
+            conds
+                .iter()
+                .map(|x| format!("({x})"))
+                .collect::<Vec<_>>()
+                .join(" or ")
+        }
+    }
+
+    fn switch_item_body(&mut self, arg: &SwitchItem) {
+        self.indent += 1;
+        self.switch_item_body_without_indent(arg);
+        self.indent -= 1;
+    }
+
+    fn switch_item_body_without_indent(&mut self, arg: &SwitchItem) {
+        match arg.switch_item_group0.as_ref() {
+            SwitchItemGroup0::Statement(y) => {
+                let range: TokenRange = y.statement.as_ref().into();
+                self.statement(&y.statement, &range);
+            }
+            SwitchItemGroup0::StatementBlock(y) => self.statement_block(&y.statement_block),
+        }
+    }
+
+    // ------------------------------------------------------------------------
+    // Expression
+    // ------------------------------------------------------------------------
+
+    /// Returns the VHDL range of `a..b` or `a..=b`
+    fn range(&mut self, arg: &Range) -> String {
+        let beg = self.expression(&arg.expression);
+        if let Some(ref x) = arg.range_opt {
+            let end = self.expression(&x.expression);
+            let end = match x.range_operator.as_ref() {
+                RangeOperator::DotDot(_) => minus_one(end),
+                RangeOperator::DotDotEqu(_) => end.text,
+            };
+            format!("{} to {end}", beg.text)
+        } else {
+            beg.text
+        }
+    }
+
+    /// Returns the expression as condition
+    fn condition(&mut self, arg: &Expression) -> String {
+        let x = self.expression(arg);
+        match x.kind {
+            Kind::Vector => format!("or {}", x.wrapped()),
+            Kind::Int => format!("{} /= 0", x.wrapped()),
+            _ => x.text,
+        }
+    }
+
+    /// Returns the right-hand side of assignment.
+    /// If/case/switch expressions are translated to conditional/selected assignments.
+    fn rhs(&mut self, arg: &Expression, target: &Target) -> Rhs {
+        let if_expression = arg.if_expression.as_ref();
+        if !if_expression.if_expression_list.is_empty() {
+            let mut items = Vec::new();
+            for x in &if_expression.if_expression_list {
+                let cond = self.condition(&x.expression);
+                let value = self.expression(&x.expression0);
+                items.push((self.adapt(&value, target), cond));
+            }
+            let default = self.expression01(&if_expression.expression01);
+            let default = self.adapt(&default, target);
+            return Rhs::Conditional(items, default);
+        }
+
+        match arg.unwrap_factor() {
+            Some(Factor::CaseExpression(x)) => {
+                let x = x.case_expression.as_ref();
+                let selector = self.expression(&x.expression);
+                let mut conditions = vec![x.case_condition.as_ref()];
+                for y in &x.case_expression_list {
+                    conditions.push(y.case_condition.as_ref());
+                }
+                let (selector, kind) = case_selector(&selector, &conditions);
+                let mut items = Vec::new();
+                let choice = self.case_choices(&x.case_condition, kind);
+                let value = self.expression(&x.expression0);
+                items.push((self.adapt(&value, target), choice));
+                for y in &x.case_expression_list {
+                    let choice = self.case_choices(&y.case_condition, kind);
+                    let value = self.expression(&y.expression);
+                    items.push((self.adapt(&value, target), choice));
+                }
+                let default = self.expression(&x.expression1);
+                let default = self.adapt(&default, target);
+                Rhs::Selected(selector, items, default)
+            }
+            Some(Factor::SwitchExpression(x)) => {
+                let x = x.switch_expression.as_ref();
+                let mut items = Vec::new();
+                let cond = self.switch_condition(&x.switch_condition);
+                let value = self.expression(&x.expression);
+                items.push((self.adapt(&value, target), cond));
+                for y in &x.switch_expression_list {
+                    let cond = self.switch_condition(&y.switch_condition);
+                    let value = self.expression(&y.expression);
+                    items.push((self.adapt(&value, target), cond));
+                }
+                let default = self.expression(&x.expression0);
+                let default = self.adapt(&default, target);
+                Rhs::Conditional(items, default)
+            }
+            _ => {
+                let x = self.expression(arg);
+                if x.kind == Kind::Bool && target.kind == Kind::Bit {
+                    Rhs::Conditional(vec![("'1'".to_string(), x.text)], "'0'".to_string())
+                } else {
+                    Rhs::Plain(self.adapt(&x, target))
+                }
+            }
+        }
+    }
+
+    /// Converts the operand to the type of the target
+    fn adapt(&self, arg: &Operand, target: &Target) -> String {
+        match (&arg.literal, target.kind) {
+            (Some(Literal::Int(x)), Kind::Bit) => format!("'{}'", literal_lsb(x)),
+            (Some(Literal::Bits(x)), Kind::Bit) => format!("'{x}'"),
+            (Some(Literal::Int(x)), Kind::Vector) => match &target.len {
+                Some(len) if target.signed => format!("to_signed({x}, {len})"),
+                Some(len) if x.starts_with('-') => {
+                    format!("std_logic_vector(to_signed({x}, {len}))")
+                }
+                Some(len) => format!("to_slv({x}, {len})"),
+                None => arg.text.clone(),
+            },
+            (Some(Literal::AllBit(x)), Kind::Bit) => format!("'{x}'"),
+            (Some(Literal::Bool(x)), Kind::Bool) => x.to_string(),
+            (Some(Literal::Real), Kind::Int) => format!("integer({})", arg.text),
+            // decimal bit string literal can't be converted to integer directly
+            (Some(Literal::Bits(_)), Kind::Int) if arg.text.contains("d\"") => {
+                let (_, digits) = arg.text.split_once('"').unwrap();
+                digits.trim_end_matches('"').to_string()
+            }
+            (Some(_), _) => arg.text.clone(),
+            (None, Kind::Vector) if arg.kind == Kind::Int => match &target.len {
+                Some(len) if target.signed => format!("to_signed({}, {len})", arg.text),
+                Some(len) => format!("to_slv({}, {len})", arg.text),
+                None => arg.text.clone(),
+            },
+            // the width of product is the sum of the widths of operands
+            (None, Kind::Vector) if arg.op == Some("*") && target.len.is_some() => {
+                format!("resize({}, {})", arg.text, target.len.as_ref().unwrap())
+            }
+            (None, Kind::Vector) if arg.kind == Kind::Bit && target.len.is_some() => {
+                format!("(0 => {}, others => '0')", arg.text)
+            }
+            (None, Kind::Vector) if arg.kind == Kind::Real => match &target.len {
+                Some(len) if target.signed => format!("to_signed(integer({}), {len})", arg.text),
+                Some(len) => format!("to_slv(integer({}), {len})", arg.text),
+                None => arg.text.clone(),
+            },
+            (None, Kind::Int) if arg.kind == Kind::Vector => {
+                format!("to_integer({})", arg.text)
+            }
+            (None, Kind::Int) if arg.kind == Kind::Real => format!("integer({})", arg.text),
+            (None, Kind::Bit) if arg.kind == Kind::Int => format!("to_slv({}, 1)(0)", arg.text),
+            (None, Kind::Bit) if arg.kind == Kind::Real => {
+                format!("to_slv(integer({}), 1)(0)", arg.text)
+            }
+            (None, Kind::Bit) if arg.kind == Kind::Vector && is_name(arg) => {
+                format!("{}(0)", arg.text)
+            }
+            // only names can be indexed, so the result of expression is resized to the LSB
+            (None, Kind::Bit) if arg.kind == Kind::Vector => {
+                format!("resize(std_logic_vector'({}), 1)(0)", arg.text)
+            }
+            _ => arg.text.clone(),
+        }
+    }
+
+    fn expression(&mut self, arg: &Expression) -> Operand {
+        let arg = arg.if_expression.as_ref();
+        if arg.if_expression_list.is_empty() {
+            return self.expression01(&arg.expression01);
+        }
+
+        // nested if expression is rejected by analyzer, so emit it as conditional expression
+        let mut text = String::new();
+        let mut kind = Kind::Unknown;
+        for x in &arg.if_expression_list {
+            let cond = self.condition(&x.expression);
+            let value = self.expression(&x.expression0);
+            kind = value.kind;
+            text.push_str(&format!("{} when {cond} else ", value.wrapped()));
+        }
+        let default = self.expression01(&arg.expression01);
+        text.push_str(&default.wrapped());
+        Operand {
+            prec: 1,
+            ..Operand::new(text, kind)
+        }
+    }
+
+    fn expression01(&mut self, arg: &Expression01) -> Operand {
+        let mut ret = self.expression02(&arg.expression02);
+        for x in &arg.expression01_list {
+            let rhs = self.expression02(&x.expression02);
+            ret = self.binary(ret, &x.operator02.operator02_token.to_string(), rhs);
+        }
+        ret
+    }
+
+    fn expression02(&mut self, arg: &Expression02) -> Operand {
+        let mut ret = self.expression03(&arg.expression03);
+        for x in &arg.expression02_list {
+            let rhs = self.expression03(&x.expression03);
+            ret = self.binary(ret, &x.operator03.operator03_token.to_string(), rhs);
+        }
+        ret
+    }
+
+    fn expression03(&mut self, arg: &Expression03) -> Operand {
+        let mut ret = self.expression04(&arg.expression04);
+        for x in &arg.expression03_list {
+            let rhs = self.expression04(&x.expression04);
+            ret = self.binary(ret, &x.operator04.operator04_token.to_string(), rhs);
+        }
+        ret
+    }
+
+    fn expression04(&mut self, arg: &Expression04) -> Operand {
+        let mut ret = self.expression05(&arg.expression05);
+        for x in &arg.expression04_list {
+            let rhs = self.expression05(&x.expression05);
+            ret = self.binary(ret, &x.operator05.operator05_token.to_string(), rhs);
+        }
+        ret
+    }
+
+    fn expression05(&mut self, arg: &Expression05) -> Operand {
+        let mut ret = self.expression06(&arg.expression06);
+        for x in &arg.expression05_list {
+            let rhs = self.expression06(&x.expression06);
+            ret = self.binary(ret, &x.operator06.operator06_token.to_string(), rhs);
+        }
+        ret
+    }
+
+    fn expression06(&mut self, arg: &Expression06) -> Operand {
+        let mut ret = self.expression07(&arg.expression07);
+        for x in &arg.expression06_list {
+            let rhs = self.expression07(&x.expression07);
+            ret = self.binary(ret, &x.operator07.operator07_token.to_string(), rhs);
+        }
+        ret
+    }
+
+    fn expression07(&mut self, arg: &Expression07) -> Operand {
+        let mut ret = self.expression08(&arg.expression08);
+        for x in &arg.expression07_list {
+            let rhs = self.expression08(&x.expression08);
+            ret = self.binary(ret, &x.operator08.operator08_token.to_string(), rhs);
+        }
+        ret
+    }
+
+    fn expression08(&mut self, arg: &Expression08) -> Operand {
+        let mut ret = self.expression09(&arg.expression09);
+        for x in &arg.expression08_list {
+            let rhs = self.expression09(&x.expression09);
+            ret = self.binary(ret, &x.operator09.operator09_token.to_string(), rhs);
+        }
+        ret
+    }
+
+    fn expression09(&mut self, arg: &Expression09) -> Operand {
+        let mut ret = self.expression10(&arg.expression10);
+        for x in &arg.expression09_list {
+            let rhs = self.expression10(&x.expression10);
+            ret = self.binary(ret, &x.operator10.operator10_token.to_string(), rhs);
+        }
+        ret
+    }
+
+    fn expression10(&mut self, arg: &Expression10) -> Operand {
+        let mut ret = self.expression11(&arg.expression11);
+        for x in &arg.expression10_list {
+            let op = match x.expression10_list_group.as_ref() {
+                Expression10ListGroup::Operator11(x) => x.operator11.operator11_token.to_string(),
+                Expression10ListGroup::Star(x) => x.star.star_token.to_string(),
+            };
+            let rhs = self.expression11(&x.expression11);
+            ret = self.binary(ret, &op, rhs);
+        }
+        ret
+    }
+
+    fn expression11(&mut self, arg: &Expression11) -> Operand {
+        let mut ret = self.expression12(&arg.expression12);
+        for x in &arg.expression11_list {
+            let rhs = self.expression12(&x.expression12);
+            ret = self.binary(ret, &x.operator12.operator12_token.to_string(), rhs);
+        }
+        ret
+    }
+
+    fn expression12(&mut self, arg: &Expression12) -> Operand {
+        let x = self.expression13(&arg.expression13);
+        let Some(ref cast) = arg.expression12_opt else {
+            return x;
+        };
+        let width = match cast.casting_type.as_ref() {
+            CastingType::Based(y) => Some(y.based.based_token.to_string()),
+            CastingType::BaseLess(y) => Some(y.base_less.base_less_token.to_string()),
+            _ => None,
+        };
+        match width {
+            Some(width) => {
+                let width = width.replace('_', "");
+                if x.kind == Kind::Int {
+                    Operand::new(format!("to_slv({}, {width})", x.text), Kind::Vector)
+                } else {
+                    Operand::new(format!("resize({}, {width})", x.text), Kind::Vector)
+                }
+            }
+            None => x,
+        }
+    }
+
+    fn expression13(&mut self, arg: &Expression13) -> Operand {
+        let mut ret = self.factor(&arg.factor);
+        for x in arg.expression13_list.iter().rev() {
+            let op = match x.expression13_list_group.as_ref() {
+                Expression13ListGroup::UnaryOperator(x) => {
+                    x.unary_operator.unary_operator_token.to_string()
+                }
+                Expression13ListGroup::Operator10(x) => x.operator10.operator10_token.to_string(),
+                Expression13ListGroup::Operator06(x) => x.operator06.operator06_token.to_string(),
+                Expression13ListGroup::Operator04(x) => x.operator04.operator04_token.to_string(),
+                Expression13ListGroup::Operator05(x) => x.operator05.operator05_token.to_string(),
+            };
+            ret = unary(&op, ret);
+        }
+        ret
+    }
+
+    fn binary(&mut self, lhs: Operand, op: &str, rhs: Operand) -> Operand {
+        if matches!(op, "<<" | "<<<" | ">>" | ">>>") {
+            return shift(lhs, op, rhs);
+        }
+
+        let (lhs, rhs) = adapt_bit_literals(lhs, rhs);
+        match op {
+            "||" | "&&" => {
+                let vhdl_op = if op == "||" { "or" } else { "and" };
+                let (lhs, rhs) = logical_operands(lhs, rhs);
+                let kind = lhs.kind;
+                binary_operand(lhs, vhdl_op, LOGICAL, rhs, kind)
+            }
+            "|" | "^" | "~^" | "^~" | "&" => {
+                let vhdl_op = match op {
+                    "|" => "or",
+                    "^" => "xor",
+                    "&" => "and",
+                    _ => "xnor",
+                };
+                // bitwise operation between integers is not available
+                let (lhs, rhs) = if matches!(lhs.literal, Some(Literal::Int(_)))
+                    && matches!(rhs.literal, Some(Literal::Int(_)))
+                {
+                    (to_bit_literal(lhs), to_bit_literal(rhs))
+                } else {
+                    (lhs, rhs)
+                };
+                let kind = if lhs.kind == Kind::Vector || rhs.kind == Kind::Vector {
+                    Kind::Vector
+                } else if lhs.kind == Kind::Bool && rhs.kind == Kind::Bool {
+                    Kind::Bool
+                } else {
+                    Kind::Bit
+                };
+                binary_operand(lhs, vhdl_op, LOGICAL, rhs, kind)
+            }
+            "==" | "===" | "==?" | "!=" | "!==" | "!=?" | "<=" | ">=" | "<:" | ">:" => {
+                let integer = lhs.kind == Kind::Int && rhs.kind == Kind::Int;
+                let equal = matches!(op, "==" | "===" | "==?");
+                let not_equal = matches!(op, "!=" | "!==" | "!=?");
+                let vhdl_op = match (op, integer) {
+                    (_, true) if equal => "=",
+                    (_, true) if not_equal => "/=",
+                    (_, false) if equal => "?=",
+                    (_, false) if not_equal => "?/=",
+                    ("<=", true) => "<=",
+                    (">=", true) => ">=",
+                    ("<:", true) => "<",
+                    (">:", true) => ">",
+                    ("<=", false) => "?<=",
+                    (">=", false) => "?>=",
+                    ("<:", false) => "?<",
+                    _ => "?>",
+                };
+                let kind = if integer { Kind::Bool } else { Kind::Bit };
+                binary_operand(lhs, vhdl_op, RELATIONAL, rhs, kind)
+            }
+            "+" | "-" | "*" | "/" | "%" | "**" => {
+                let (vhdl_op, level) = match op {
+                    "+" => ("+", ADDING),
+                    "-" => ("-", ADDING),
+                    "*" => ("*", MULTIPLYING),
+                    "/" => ("/", MULTIPLYING),
+                    "%" => ("mod", MULTIPLYING),
+                    _ => ("**", MISCELLANEOUS),
+                };
+                if lhs.kind == Kind::Real || rhs.kind == Kind::Real {
+                    return binary_operand(real(&lhs), vhdl_op, level, real(&rhs), Kind::Real);
+                }
+                let kind = if lhs.kind == Kind::Vector || rhs.kind == Kind::Vector {
+                    Kind::Vector
+                } else if lhs.kind == Kind::Int && rhs.kind == Kind::Int {
+                    Kind::Int
+                } else {
+                    Kind::Unknown
+                };
+                binary_operand(lhs, vhdl_op, level, rhs, kind)
+            }
+            _ => unreachable!(),
+        }
+    }
+
+    fn factor(&mut self, arg: &Factor) -> Operand {
+        match arg {
+            Factor::Number(x) => number(&x.number),
+            Factor::BooleanLiteral(x) => {
+                let value = matches!(x.boolean_literal.as_ref(), BooleanLiteral::True(_));
+                let text = if value { "'1'" } else { "'0'" };
+                Operand {
+                    literal: Some(Literal::Bool(value)),
+                    ..Operand::new(text.to_string(), Kind::Bit)
+                }
+            }
+            Factor::IdentifierFactor(x) => self.identifier_factor(&x.identifier_factor),
+            Factor::LParenExpressionRParen(x) => {
+                let inner = self.expression(&x.expression);
+                Operand {
+                    text: format!("({})", inner.text),
+                    prec: 0,
+                    op: None,
+                    ..inner
+                }
+            }
+            Factor::LBraceConcatenationListRBrace(x) => {
+                let items: Vec<ConcatenationItem> = x.concatenation_list.as_ref().into();
+                let mut texts = Vec::new();
+                for item in &items {
+                    let value = self.expression(&item.expression);
+                    if let Some(ref y) = item.concatenation_item_opt {
+                        let count = self.expression(&y.expression);
+                        match count.literal {
+                            Some(Literal::Int(ref n)) if n.parse::<usize>().is_ok() => {
+                                for _ in 0..n.parse::<usize>().unwrap() {
+                                    texts.push(value.wrapped());
+                                }
+                            }
+                            _ => {
+                                let high = self.high_index(&y.expression);
+                                texts.push(format!("(0 to {high} => {})", value.text));
+                            }
+                        }
+                    } else {
+                        texts.push(value.wrapped());
+                    }
+                }
+                Operand::new(format!("({})", texts.join(" & ")), Kind::Vector)
+            }
+            Factor::QuoteLBraceArrayLiteralListRBrace(x) => {
+                let list = x.array_literal_list.as_ref();
+                let mut items = vec![list.array_literal_item.as_ref().clone()];
+                for y in &list.array_literal_list_list {
+                    items.push(y.array_literal_item.as_ref().clone());
+                }
+                let mut texts = Vec::new();
+                for item in &items {
+                    match item.array_literal_item_group.as_ref() {
+                        ArrayLiteralItemGroup::ExpressionArrayLiteralItemOpt(y) => {
+                            let value = self.expression(&y.expression);
+                            let count = y
+                                .array_literal_item_opt
+                                .as_ref()
+                                .map(|z| self.expression(&z.expression));
+                            match count.and_then(|x| x.literal) {
+                                Some(Literal::Int(n)) if n.parse::<usize>().is_ok() => {
+                                    for _ in 0..n.parse::<usize>().unwrap() {
+                                        texts.push(value.text.clone());
+                                    }
+                                }
+                                _ => texts.push(value.text),
+                            }
+                        }
+                        ArrayLiteralItemGroup::DefaulColonExpression(y) => {
+                            let value = self.expression(&y.expression);
+                            texts.push(format!("others => {}", value.text));
+                        }
+                    }
+                }
+                Operand::new(format!("({})", texts.join(", ")), Kind::Unknown)
+            }
+            Factor::CaseExpression(x) => {
+                let x = x.case_expression.as_ref();
+                let selector = self.expression(&x.expression);
+                let mut text = String::new();
+                let mut conds = vec![(x.case_condition.as_ref(), x.expression0.as_ref())];
+                for y in &x.case_expression_list {
+                    conds.push((y.case_condition.as_ref(), y.expression.as_ref()));
+                }
+                for (cond, value) in conds {
+                    let items: Vec<RangeItem> = cond.into();
+                    let cond: Vec<_> = items
+                        .iter()
+                        .map(|y| {
+                            let y = self.expression(&y.range.expression);
+                            format!("{} ?= {}", selector.wrapped(), y.wrapped())
+                        })
+                        .collect();
+                    let value = self.expression(value);
+                    text.push_str(&format!(
+                        "{} when {} else ",
+                        value.wrapped(),
+                        cond.join(" or ")
+                    ));
+                }
+                let default = self.expression(&x.expression1);
+                text.push_str(&default.wrapped());
+                Operand {
+                    prec: 1,
+                    ..Operand::new(text, default.kind)
+                }
+            }
+            Factor::SwitchExpression(x) => {
+                let x = x.switch_expression.as_ref();
+                let mut text = String::new();
+                let mut conds = vec![(x.switch_condition.as_ref(), x.expression.as_ref())];
+                for y in &x.switch_expression_list {
+                    conds.push((y.switch_condition.as_ref(), y.expression.as_ref()));
+                }
+                for (cond, value) in conds {
+                    let cond = self.switch_condition(cond);
+                    let value = self.expression(value);
+                    text.push_str(&format!("{} when {cond} else ", value.wrapped()));
+                }
+                let default = self.expression(&x.expression0);
+                text.push_str(&default.wrapped());
+                Operand {
+                    prec: 1,
+                    ..Operand::new(text, default.kind)
+                }
+            }
+            Factor::StringLiteral(x) => Operand::new(
+                x.string_literal.string_literal_token.to_string(),
+                Kind::Unknown,
+            ),
+            Factor::FactorGroup(x) => {
+                let base = self.select_base.last().cloned().unwrap_or_default();
+                match x.factor_group.as_ref() {
+                    FactorGroup::Msb(_) => Operand::new(format!("{base}'high"), Kind::Int),
+                    FactorGroup::Lsb(_) => Operand::new(format!("{base}'low"), Kind::Int),
+                }
+            }
+            Factor::InsideExpression(x) => {
+                let x = x.inside_expression.as_ref();
+                self.inside_expression(&x.expression, &x.range_list)
+            }
+            Factor::OutsideExpression(x) => {
+                let x = x.outside_expression.as_ref();
+                let inside = self.inside_expression(&x.expression, &x.range_list);
+                unary("!", inside)
+            }
+            Factor::TypeExpression(x) => self.expression(&x.type_expression.expression),
+            Factor::FactorTypeFactor(x) => {
+                let text = match x.factor_type_factor.factor_type.factor_type_group.as_ref() {
+                    FactorTypeGroup::VariableTypeFactorTypeOpt(_) => "std_logic".to_string(),
+                    FactorTypeGroup::FixedType(y) => fixed_type(&y.fixed_type, TypeUsage::Mark),
+                };
+                Operand::new(text, Kind::Unknown)
+            }
+        }
+    }
+
+    fn inside_expression(&mut self, arg: &Expression, range_list: &RangeList) -> Operand {
+        let value = self.expression(arg);
+        let mut items = vec![range_list.range_item.as_ref().clone()];
+        for x in &range_list.range_list_list {
+            items.push(x.range_item.as_ref().clone());
+        }
+        let mut ret: Option<Operand> = None;
+        for item in &items {
+            let cond = if let Some(ref x) = item.range.range_opt {
+                let beg = self.expression(&item.range.expression);
+                let end = self.expression(&x.expression);
+                let upper = match x.range_operator.as_ref() {
+                    RangeOperator::DotDot(_) => "<:",
+                    RangeOperator::DotDotEqu(_) => "<=",
+                };
+                let lower = self.binary(value.clone(), ">=", beg);
+                let upper = self.binary(value.clone(), upper, end);
+                self.binary(lower, "&&", upper)
+            } else {
+                let x = self.expression(&item.range.expression);
+                self.binary(value.clone(), "==", x)
+            };
+            ret = Some(match ret {
+                Some(x) => self.binary(x, "||", cond),
+                None => cond,
+            });
+        }
+        let ret = ret.unwrap();
+        Operand {
+            text: format!("({})", ret.text),
+            prec: 0,
+            op: None,
+            ..ret
+        }
+    }
+
+    fn identifier_factor(&mut self, arg: &IdentifierFactor) -> Operand {
+        let Some(ref x) = arg.identifier_factor_opt else {
+            return self.expression_identifier(&arg.expression_identifier);
+        };
+
+        match x.identifier_factor_opt_group.as_ref() {
+            IdentifierFactorOptGroup::FunctionCall(x) => {
+                self.function_call(&arg.expression_identifier, &x.function_call)
+            }
+            IdentifierFactorOptGroup::StructConstructor(x) => {
+                let x = x.struct_constructor.as_ref();
+                let items: Vec<_> = {
+                    let list = x.struct_constructor_list.as_ref();
+                    let mut items = vec![list.struct_constructor_item.as_ref().clone()];
+                    for y in &list.struct_constructor_list_list {
+                        items.push(y.struct_constructor_item.as_ref().clone());
+                    }
+                    items
+                };
+                let members = match symbol_table::resolve(arg.expression_identifier.as_ref())
+                    .map(|x| x.found.kind)
+                {
+                    Ok(SymbolKind::Struct(x)) => x.members,
+                    _ => Vec::new(),
+                };
+                let members: Vec<_> = members
+                    .iter()
+                    .filter_map(|x| symbol_table::get(*x))
+                    .filter_map(|x| match x.kind {
+                        SymbolKind::StructMember(y) => {
+                            let target = self.type_target(&y.r#type, TypeUsage::Signal);
+                            // width expression in the struct declaration may not be visible here
+                            let len = target.len.filter(|x| x.parse::<usize>().is_ok());
+                            Some((x.token.text, Target { len, ..target }))
+                        }
+                        _ => None,
+                    })
+                    .collect();
+                let mut texts = Vec::new();
+                for item in &items {
+                    let name = item.identifier.identifier_token.token.text;
+                    let target = members
+                        .iter()
+                        .find(|x| x.0 == name)
+                        .map(|x| x.1.clone())
+                        .unwrap_or_else(Target::unknown);
+                    let value = self.expression(&item.expression);
+                    let value = self.adapt(&value, &target);
+                    let member = vhdl_identifier(&item.identifier.identifier_token.to_string());
+                    texts.push(format!("{member} => {value}"));
+                }
+                if let Some(ref y) = x.struct_constructor_opt {
+                    let value = self.expression(&y.expression);
+                    let rest: Vec<_> = members
+                        .iter()
+                        .filter(|x| {
+                            items
+                                .iter()
+                                .all(|y| y.identifier.identifier_token.token.text != x.0)
+                        })
+                        .collect();
+                    if members.is_empty() {
+                        texts.push(format!("others => {}", value.text));
+                    }
+                    // members may have different types, so `others` can't be used
+                    for (name, target) in rest {
+                        let value = self.adapt(&value, target);
+                        let member = vhdl_identifier(&name.to_string());
+                        texts.push(format!("{member} => {value}"));
+                    }
+                }
+                Operand::new(format!("({})", texts.join(", ")), Kind::Unknown)
+            }
+        }
+    }
+
+    fn function_call(&mut self, name: &ExpressionIdentifier, arg: &FunctionCall) -> Operand {
+        let args: Vec<(Option<String>, Operand)> = if let Some(ref x) = arg.function_call_opt {
+            let items: Vec<ArgumentItem> = x.argument_list.as_ref().into();
+            items
+                .iter()
+                .map(|x| {
+                    if let Some(ref y) = x.argument_item_opt {
+                        let formal = self.expression(&x.argument_expression.expression).text;
+                        (Some(formal), self.expression(&y.expression))
+                    } else {
+                        (None, self.expression(&x.argument_expression.expression))
+                    }
+                })
+                .collect()
+        } else {
+            Vec::new()
+        };
+
+        let function = name.scoped_identifier.identifier().to_string();
+        let first = args.first().map(|x| x.1.clone());
+        if let Some(first) = first {
+            match function.as_str() {
+                "$clog2" => {
+                    let x = if first.kind == Kind::Vector {
+                        format!("to_integer({})", first.text)
+                    } else {
+                        first.text
+                    };
+                    return Operand::new(format!("integer(ceil(log2(real({x}))))"), Kind::Int);
+                }
+                "$signed" => {
+                    return Operand::new(format!("signed({})", first.text), Kind::Vector);
+                }
+                "$unsigned" => {
+                    return Operand::new(
+                        format!("std_logic_vector(unsigned({}))", first.text),
+                        Kind::Vector,
+                    );
+                }
+                "$bits" | "$size" => {
+                    return Operand::new(format!("{}'length", first.wrapped()), Kind::Int);
+                }
+                "$pow" if args.len() == 2 => {
+                    let lhs = real(&first);
+                    let rhs = real(&args[1].1);
+                    return binary_operand(lhs, "**", MISCELLANEOUS, rhs, Kind::Real);
+                }
+                _ => (),
+            }
+        }
+
+        if let Some(function) = math_function(&function) {
+            // functions of math_real take and return real
+            let args: Vec<_> = args.iter().map(|x| real(&x.1).text).collect();
+            return Operand::new(format!("{function}({})", args.join(", ")), Kind::Real);
+        }
+
+        let text = self.expression_identifier(name).text;
+        let kind = symbol_table::resolve(name.scoped_identifier.as_ref())
+            .ok()
+            .and_then(|x| match x.found.kind {
+                SymbolKind::Function(x) => x.ret,
+                _ => None,
+            })
+            .map(|x| self.type_target(&x, TypeUsage::Mark).kind)
+            .unwrap_or(Kind::Unknown);
+        if args.is_empty() {
+            return Operand::new(text, kind);
+        }
+        let args: Vec<_> = args
+            .iter()
+            .map(|(formal, x)| match formal {
+                Some(formal) => format!("{formal} => {}", x.text),
+                None => x.text.clone(),
+            })
+            .collect();
+        Operand::new(format!("{text}({})", args.join(", ")), kind)
+    }
+
+    fn scoped_identifier(&mut self, arg: &ScopedIdentifier) -> String {
+        let token = arg.identifier();
+        if is_anonymous_token(&token.token) {
+            return "open".to_string();
+        }
+        match symbol_table::resolve(arg) {
+            Ok(x) => {
+                let text = symbol_string(
+                    token,
+                    &x.found,
+                    &x.full_path,
+                    &x.generic_tables,
+                    &self.context(),
+                );
+                qualified_identifier(&text)
+            }
+            Err(_) => vhdl_identifier(&token.to_string()),
+        }
+    }
+
+    fn identifier(&mut self, arg: &Identifier) -> String {
+        match symbol_table::resolve(arg) {
+            Ok(x) => {
+                let text = symbol_string(
+                    &arg.identifier_token,
+                    &x.found,
+                    &x.full_path,
+                    &x.generic_tables,
+                    &self.context(),
+                );
+                qualified_identifier(&text)
+            }
+            Err(_) => self.declared_name(arg),
+        }
+    }
+
+    fn hierarchical_identifier(&mut self, arg: &HierarchicalIdentifier) -> String {
+        let mut text = self.identifier(&arg.identifier);
+        for x in &arg.hierarchical_identifier_list {
+            let select = self.select(&text, &x.select);
+            text.push_str(&select);
+        }
+        for x in &arg.hierarchical_identifier_list0 {
+            text.push('.');
+            text.push_str(&vhdl_identifier(&x.identifier.identifier_token.to_string()));
+            for y in &x.hierarchical_identifier_list0_list {
+                let select = self.select(&text, &y.select);
+                text.push_str(&select);
+            }
+        }
+        text
+    }
+
+    fn expression_identifier(&mut self, arg: &ExpressionIdentifier) -> Operand {
+        let mut text = self.scoped_identifier(&arg.scoped_identifier);
+        let symbol = symbol_table::resolve(arg).ok().map(|x| x.found);
+        // `x[0]` of single bit variable is the variable itself
+        let bit = arg.expression_identifier_list0.is_empty()
+            && self.identifier_target(symbol.clone(), &text, 0, false).kind == Kind::Bit;
+        for x in &arg.expression_identifier_list {
+            let select = self.select(&text, &x.select);
+            if bit && select == "(0)" {
+                continue;
+            }
+            text.push_str(&select);
+        }
+        for x in &arg.expression_identifier_list0 {
+            text.push('.');
+            text.push_str(&vhdl_identifier(&x.identifier.identifier_token.to_string()));
+            for y in &x.expression_identifier_list0_list {
+                let select = self.select(&text, &y.select);
+                text.push_str(&select);
+            }
+        }
+
+        let kind = self.expression_identifier_target(arg, &text, symbol).kind;
+        Operand::new(text, kind)
+    }
+
+    fn select(&mut self, base: &str, arg: &Select) -> String {
+        self.select_base.push(base.to_string());
+        let index = self.expression(&arg.expression);
+        let ret = if let Some(ref x) = arg.select_opt {
+            let width = self.expression(&x.expression);
+            let index_text = natural(&index);
+            let wrapped_index = if index_text == index.text {
+                index.wrapped()
+            } else {
+                index_text.clone()
+            };
+            match x.select_operator.as_ref() {
+                SelectOperator::Colon(_) => {
+                    format!("({index_text} downto {})", natural(&width))
+                }
+                SelectOperator::PlusColon(_) => format!(
+                    "({wrapped_index} + {} - 1 downto {index_text})",
+                    width.wrapped()
+                ),
+                SelectOperator::MinusColon(_) => format!(
+                    "({index_text} downto {wrapped_index} - {} + 1)",
+                    width.wrapped()
+                ),
+                SelectOperator::Step(_) => format!(
+                    "(({wrapped_index} + 1) * {w} - 1 downto {wrapped_index} * {w})",
+                    w = width.wrapped()
+                ),
+            }
+        } else {
+            format!("({})", natural(&index))
+        };
+        self.select_base.pop();
+        ret
+    }
+}
+
+/// Returns the function of `ieee.math_real` corresponding to the system function
+fn math_function(name: &str) -> Option<&'static str> {
+    let ret = match name {
+        "$sin" => "sin",
+        "$cos" => "cos",
+        "$tan" => "tan",
+        "$asin" => "arcsin",
+        "$acos" => "arccos",
+        "$atan" | "$atan2" => "arctan",
+        "$sinh" => "sinh",
+        "$cosh" => "cosh",
+        "$tanh" => "tanh",
+        "$asinh" => "arcsinh",
+        "$acosh" => "arccosh",
+        "$atanh" => "arctanh",
+        "$exp" => "exp",
+        "$ln" => "log",
+        "$log10" => "log10",
+        "$sqrt" => "sqrt",
+        "$floor" => "floor",
+        "$ceil" => "ceil",
+        _ => return None,
+    };
+    Some(ret)
+}
+
+fn symbol_name(symbol: &Symbol) -> String {
+    let context = SymbolContext {
+        project_name: None,
+        build_opt: Build::default(),
+        in_import: false,
+        in_direction_modport: false,
+        generic_map: Vec::new(),
+    };
+    let text = symbol_string(
+        &VerylToken::new(symbol.token),
+        symbol,
+        &[],
+        &GenericTables::default(),
+        &context,
+    );
+    vhdl_identifier(&text)
+}
+
+fn variable_type_kind(symbol: &Symbol) -> Option<TypeKind> {
+    match &symbol.kind {
+        SymbolKind::Port(x) => Some(x.r#type.kind.clone()),
+        SymbolKind::Variable(x) => Some(x.r#type.kind.clone()),
+        _ => None,
+    }
+}
+
+fn comment_lines(text: &str) -> Vec<String> {
+    let text = text.trim_end();
+    if let Some(x) = text.strip_prefix("//") {
+        vec![format!("--{x}")]
+    } else {
+        text.lines().map(|x| x.trim().to_string()).collect()
+    }
+}
+
+/// Collects names which differ only by case from other names visible in the same design unit.
+/// Record elements are checked in the record because they have their own scope.
+fn case_clashes() -> HashSet<String> {
+    let mut names: HashMap<(Vec<StrId>, String), HashSet<String>> = HashMap::new();
+    for symbol in symbol_table::get_all() {
+        let scope = match symbol.kind {
+            // enum members are emitted with the name of enum as prefix
+            SymbolKind::EnumMember(_) => continue,
+            SymbolKind::StructMember(_) | SymbolKind::UnionMember(_) => {
+                symbol.namespace.paths.to_vec()
+            }
+            _ if symbol.namespace.paths.len() < 2 => continue,
+            _ => symbol.namespace.paths[..2].to_vec(),
+        };
+        let mut scopes = vec![scope];
+        for (_, x) in &symbol.imported {
+            if x.paths.len() >= 2 {
+                scopes.push(x.paths[..2].to_vec());
+            }
+        }
+
+        let name = symbol.token.to_string();
+        let name = name.strip_prefix("r#").unwrap_or(&name);
+        for scope in scopes {
+            names
+                .entry((scope, name.to_ascii_lowercase()))
+                .or_default()
+                .insert(name.to_string());
+        }
+    }
+    names
+        .into_values()
+        .filter(|x| x.len() > 1)
+        .flatten()
+        .collect()
+}
+
+/// Returns VHDL identifier which escapes reserved words and invalid names as extended identifier.
+/// Names which differ only by case from other names are also escaped
+/// because extended identifiers are case-sensitive.
+pub fn vhdl_identifier(text: &str) -> String {
+    let text = text.strip_prefix("r#").unwrap_or(text);
+    let lower = text.to_ascii_lowercase();
+    let invalid = text.starts_with('_')
+        || text.ends_with('_')
+        || text.contains("__")
+        || text.starts_with('$')
+        || RESERVED_WORDS.contains(&lower.as_str())
+        || CASE_CLASHES.with(|x| x.borrow().contains(text));
+    if invalid {
+        format!("\\{text}\\")
+    } else {
+        text.to_string()
+    }
+}
+
+fn qualified_identifier(text: &str) -> String {
+    text.split('.')
+        .map(vhdl_identifier)
+        .collect::<Vec<_>>()
+        .join(".")
+}
+
+fn fixed_type(arg: &FixedType, usage: TypeUsage) -> String {
+    let (bits, signed) = match arg {
+        FixedType::U8(_) => (8, false),
+        FixedType::U16(_) => (16, false),
+        FixedType::U32(_) => (32, false),
+        FixedType::U64(_) => (64, false),
+        FixedType::I8(_) => (8, true),
+        FixedType::I16(_) => (16, true),
+        FixedType::I32(_) => (32, true),
+        FixedType::I64(_) => (64, true),
+        FixedType::F32(_) | FixedType::F64(_) => return "real".to_string(),
+        FixedType::Bool(_) if usage == TypeUsage::Constant => return "boolean".to_string(),
+        FixedType::Bool(_) => return "std_logic".to_string(),
+        FixedType::Strin(_) => return "string".to_string(),
+    };
+    match usage {
+        TypeUsage::Constant if signed => "integer".to_string(),
+        TypeUsage::Constant => "natural".to_string(),
+        TypeUsage::Mark if signed => "signed".to_string(),
+        TypeUsage::Mark => "std_logic_vector".to_string(),
+        TypeUsage::Signal if signed => format!("signed({} downto 0)", bits - 1),
+        TypeUsage::Signal => format!("std_logic_vector({} downto 0)", bits - 1),
+    }
+}
+
+fn fixed_target(arg: &FixedType, usage: TypeUsage) -> Target {
+    let (bits, signed) = match arg {
+        FixedType::U8(_) => (8, false),
+        FixedType::U16(_) => (16, false),
+        FixedType::U32(_) => (32, false),
+        FixedType::U64(_) => (64, false),
+        FixedType::I8(_) => (8, true),
+        FixedType::I16(_) => (16, true),
+        FixedType::I32(_) => (32, true),
+        FixedType::I64(_) => (64, true),
+        FixedType::Bool(_) if usage == TypeUsage::Constant => {
+            return Target {
+                kind: Kind::Bool,
+                len: None,
+                signed: false,
+            };
+        }
+        FixedType::Bool(_) => {
+            return Target {
+                kind: Kind::Bit,
+                len: None,
+                signed: false,
+            };
+        }
+        _ => return Target::unknown(),
+    };
+    if usage == TypeUsage::Constant {
+        Target {
+            kind: Kind::Int,
+            len: None,
+            signed,
+        }
+    } else {
+        Target {
+            kind: Kind::Vector,
+            len: Some(bits.to_string()),
+            signed,
+        }
+    }
+}
+
+fn number(arg: &Number) -> Operand {
+    match arg {
+        Number::IntegralNumber(x) => match x.integral_number.as_ref() {
+            IntegralNumber::BaseLess(x) => {
+                let text = x.base_less.base_less_token.to_string();
+                Operand {
+                    literal: Some(Literal::Int(text.clone())),
+                    ..Operand::new(text, Kind::Int)
+                }
+            }
+            IntegralNumber::Based(x) => based_number(&x.based.based_token.to_string()),
+            IntegralNumber::AllBit(x) => {
+                let text = x.all_bit.all_bit_token.to_string();
+                let (width, value) = text.split_once('\'').unwrap();
+                let value = value.to_ascii_uppercase().chars().next().unwrap();
+                if width == "1" {
+                    Operand::new(format!("'{value}'"), Kind::Bit)
+                } else {
+                    Operand {
+                        literal: Some(Literal::AllBit(value)),
+                        ..Operand::new(format!("(others => '{value}')"), Kind::Unknown)
+                    }
+                }
+            }
+        },
+        Number::RealNumber(x) => {
+            let text = match x.real_number.as_ref() {
+                RealNumber::FixedPoint(x) => x.fixed_point.fixed_point_token.to_string(),
+                RealNumber::Exponent(x) => x.exponent.exponent_token.to_string(),
+            };
+            Operand {
+                literal: Some(Literal::Real),
+                ..Operand::new(text, Kind::Unknown)
+            }
+        }
+    }
+}
+
+/// Translates based number like `8'hff` to bit string literal like `8x"FF"`.
+/// Based number without width is translated to integer literal like `16#FF#`.
+fn based_number(text: &str) -> Operand {
+    let (width, rest) = text.split_once('\'').unwrap();
+    let (signed, rest) = match rest.strip_prefix('s') {
+        Some(x) => (true, x),
+        None => (false, rest),
+    };
+    let base = rest.chars().next().unwrap();
+    let digits = rest[1..].to_ascii_uppercase();
+
+    if width.is_empty() && digits.chars().all(|x| x.is_ascii_hexdigit() || x == '_') {
+        let text = match base {
+            'b' => format!("2#{digits}#"),
+            'o' => format!("8#{digits}#"),
+            'd' => digits,
+            _ => format!("16#{digits}#"),
+        };
+        return Operand {
+            literal: Some(Literal::Int(text.clone())),
+            ..Operand::new(text, Kind::Int)
+        };
+    }
+
+    if width == "1" && (digits == "0" || digits == "1") {
+        return Operand::new(format!("'{digits}'"), Kind::Bit);
+    }
+
+    let base = match base {
+        'b' => "b",
+        'o' => "o",
+        'd' => "d",
+        _ => "x",
+    };
+    let sign = if signed && base != "d" { "s" } else { "" };
+    Operand {
+        literal: Some(Literal::Bits(literal_lsb(&digits))),
+        ..Operand::new(format!("{width}{sign}{base}\"{digits}\""), Kind::Vector)
+    }
+}
+
+/// Returns the least significant bit of the digits of literal
+fn literal_lsb(digits: &str) -> char {
+    let digit = digits.trim_end_matches('#').chars().last().unwrap_or('0');
+    match digit.to_digit(16) {
+        Some(x) if x % 2 == 1 => '1',
+        Some(_) => '0',
+        None => digit,
+    }
+}
+
+fn unary(op: &str, arg: Operand) -> Operand {
+    let arg = match (op, &arg.literal) {
+        ("-" | "+", _) => arg,
+        (_, Some(Literal::Int(_) | Literal::Bits(_))) => to_bit_literal(arg),
+        _ => arg,
+    };
+
+    let (text, kind, level) = match op {
+        "!" if arg.kind == Kind::Vector => (
+            format!("not (or {})", arg.wrapped()),
+            Kind::Bit,
+            MISCELLANEOUS,
+        ),
+        "!" | "~" => (format!("not {}", arg.wrapped()), arg.kind, MISCELLANEOUS),
+        // reduction of single bit is the bit itself
+        "&" | "|" | "^" if arg.kind == Kind::Bit => return arg,
+        "~&" | "~|" | "~^" | "^~" if arg.kind == Kind::Bit => {
+            (format!("not {}", arg.wrapped()), Kind::Bit, MISCELLANEOUS)
+        }
+        "&" | "|" | "^" | "~&" | "~|" | "~^" | "^~" => {
+            let vhdl_op = match op {
+                "&" => "and",
+                "|" => "or",
+                "^" => "xor",
+                "~&" => "nand",
+                "~|" => "nor",
+                _ => "xnor",
+            };
+            (
+                format!("{vhdl_op} {}", arg.wrapped()),
+                Kind::Bit,
+                MISCELLANEOUS,
+            )
+        }
+        _ => {
+            if op == "-"
+                && let Some(Literal::Int(ref x)) = arg.literal
+            {
+                let text = format!("-{x}");
+                return Operand {
+                    literal: Some(Literal::Int(text.clone())),
+                    prec: SIGN,
+                    ..Operand::new(text, Kind::Int)
+                };
+            }
+            let operand = if arg.prec != 0 && arg.prec <= SIGN {
+                format!("({})", arg.text)
+            } else {
+                arg.text.clone()
+            };
+            (format!("{op}{operand}"), arg.kind, SIGN)
+        }
+    };
+    Operand {
+        prec: level,
+        ..Operand::new(text, kind)
+    }
+}
+
+fn binary_operand(lhs: Operand, op: &'static str, level: u8, rhs: Operand, kind: Kind) -> Operand {
+    // VHDL allows the sequence of the same associative logical operator,
+    // and the sequence of adding or multiplying operators
+    let sequence = match level {
+        LOGICAL => lhs.op == Some(op) && matches!(op, "and" | "or" | "xor"),
+        ADDING | MULTIPLYING => true,
+        _ => false,
+    };
+    let lhs_text = if lhs.prec == 0 || lhs.prec > level || (lhs.prec == level && sequence) {
+        lhs.text
+    } else {
+        format!("({})", lhs.text)
+    };
+    // sign operator can't follow other operators
+    let rhs_text = if rhs.prec == 0 || (rhs.prec > level && rhs.prec != SIGN) {
+        rhs.text
+    } else {
+        format!("({})", rhs.text)
+    };
+    Operand {
+        text: format!("{lhs_text} {op} {rhs_text}"),
+        kind,
+        prec: level,
+        op: Some(op),
+        literal: None,
+    }
+}
+
+/// Translates shift operators to `shift_left`/`shift_right` or multiplication/division by power of 2
+fn shift(lhs: Operand, op: &str, rhs: Operand) -> Operand {
+    let left = op.starts_with('<');
+    let amount = Operand::new(natural(&rhs), Kind::Int);
+    if lhs.kind == Kind::Int {
+        let power = binary_operand(
+            Operand::new("2".to_string(), Kind::Int),
+            "**",
+            MISCELLANEOUS,
+            amount,
+            Kind::Int,
+        );
+        let vhdl_op = if left { "*" } else { "/" };
+        binary_operand(lhs, vhdl_op, MULTIPLYING, power, Kind::Int)
+    } else {
+        let function = if left { "shift_left" } else { "shift_right" };
+        Operand::new(
+            format!("{function}({}, {})", lhs.text, amount.text),
+            Kind::Vector,
+        )
+    }
+}
+
+/// Converts the operand to real which is the type of arguments of math_real functions
+fn real(arg: &Operand) -> Operand {
+    let text = match arg.kind {
+        Kind::Int => format!("real({})", arg.text),
+        Kind::Vector => format!("real(to_integer({}))", arg.text),
+        _ => return arg.clone(),
+    };
+    Operand::new(text, Kind::Real)
+}
+
+fn to_bit_literal(arg: Operand) -> Operand {
+    let bit = match arg.literal {
+        Some(Literal::Int(ref x)) => literal_lsb(x),
+        Some(Literal::Bits(x) | Literal::AllBit(x)) => x,
+        _ => return arg,
+    };
+    Operand::new(format!("'{bit}'"), Kind::Bit)
+}
+
+/// Converts literals to `'0'`/`'1'` if the other operand is single bit
+fn adapt_bit_literals(lhs: Operand, rhs: Operand) -> (Operand, Operand) {
+    if lhs.kind == Kind::Bit && lhs.literal.is_none() {
+        (lhs, to_bit_literal(rhs))
+    } else if rhs.kind == Kind::Bit && rhs.literal.is_none() {
+        (to_bit_literal(lhs), rhs)
+    } else {
+        (lhs, rhs)
+    }
+}
+
+/// Converts operands of logical operators to the same type.
+/// Vector is reduced to bit, and bit is converted to boolean if the other is boolean.
+fn logical_operands(lhs: Operand, rhs: Operand) -> (Operand, Operand) {
+    let reduce = |x: Operand| match x.kind {
+        Kind::Vector => Operand {
+            prec: MISCELLANEOUS,
+            ..Operand::new(format!("or {}", x.wrapped()), Kind::Bit)
+        },
+        Kind::Int => Operand {
+            prec: RELATIONAL,
+            op: Some("/="),
+            ..Operand::new(format!("{} /= 0", x.wrapped()), Kind::Bool)
+        },
+        _ => x,
+    };
+    let lhs = reduce(lhs);
+    let rhs = reduce(rhs);
+    let condition = |x: Operand| {
+        if x.kind == Kind::Bool {
+            x
+        } else {
+            Operand::new(format!("(?? {})", x.wrapped()), Kind::Bool)
+        }
+    };
+    if lhs.kind == Kind::Bool || rhs.kind == Kind::Bool {
+        (condition(lhs), condition(rhs))
+    } else {
+        (lhs, rhs)
+    }
+}
+
+/// Returns whether the operand is a name which can be indexed
+fn is_name(arg: &Operand) -> bool {
+    arg.prec == 0 && !arg.text.starts_with('(') && !arg.text.contains('"')
+}
+
+/// Returns `arg - 1` as natural number
+fn minus_one(arg: Operand) -> String {
+    if let Some(Literal::Int(ref x)) = arg.literal
+        && let Ok(x) = x.parse::<usize>()
+        && x > 0
+    {
+        return (x - 1).to_string();
+    }
+    let arg = Operand {
+        text: natural(&arg),
+        ..arg
+    };
+    let one = Operand::new("1".to_string(), Kind::Int);
+    binary_operand(arg, "-", ADDING, one, Kind::Int).text
+}
+
+/// Returns the operand as natural number
+fn natural(arg: &Operand) -> String {
+    if arg.kind == Kind::Vector {
+        format!("to_integer({})", arg.text)
+    } else {
+        arg.text.clone()
+    }
+}
+
+/// Returns the selector of case statement and its kind.
+/// Vector selector is converted to integer to use integer choices except enum.
+fn case_selector(arg: &Operand, conditions: &[&CaseCondition]) -> (String, Kind) {
+    let is_enum = conditions.iter().any(|x| {
+        let items: Vec<RangeItem> = (*x).into();
+        items.iter().any(|x| {
+            x.range
+                .expression
+                .unwrap_identifier()
+                .and_then(|x| symbol_table::resolve(x).ok())
+                .map(|x| matches!(x.found.kind, SymbolKind::EnumMember(_)))
+                .unwrap_or(false)
+        })
+    });
+    if arg.kind == Kind::Vector && !is_enum {
+        (format!("to_integer({})", arg.text), Kind::Int)
+    } else {
+        (arg.text.clone(), arg.kind)
+    }
+}
+
+fn integer_choice(arg: &Operand) -> String {
+    if arg.literal.is_some() || arg.kind != Kind::Vector {
+        return arg.text.clone();
+    }
+    // convert bit string literal to based literal
+    if let Some((width, rest)) = arg.text.split_once(['b', 'o', 'x', 'd'])
+        && width.chars().all(|x| x.is_ascii_digit())
+        && let Some(digits) = rest.strip_prefix('"').and_then(|x| x.strip_suffix('"'))
+        && digits.chars().all(|x| x.is_ascii_hexdigit() || x == '_')
+    {
+        let base = &arg.text[width.len()..width.len() + 1];
+        return match base {
+            "b" => format!("2#{digits}#"),
+            "o" => format!("8#{digits}#"),
+            "x" => format!("16#{digits}#"),
+            _ => digits.to_string(),
+        };
+    }
+    format!("to_integer({})", arg.text)
+}
+
+fn statement_block_items(arg: &StatementBlock) -> Vec<StatementBlockItem> {
+    let mut ret = Vec::new();
+    for x in &arg.statement_block_list {
+        let items: Vec<StatementBlockItem> = x.statement_block_group.as_ref().into();
+        ret.extend(items);
+    }
+    ret
+}
+
+fn nested_blocks(arg: &Statement) -> Vec<&StatementBlock> {
+    let mut ret = Vec::new();
+    match arg {
+        Statement::IfStatement(x) => {
+            let x = x.if_statement.as_ref();
+            ret.push(x.statement_block.as_ref());
+            for y in &x.if_statement_list {
+                ret.push(y.statement_block.as_ref());
+            }
+            if let Some(ref y) = x.if_statement_opt {
+                ret.push(y.statement_block.as_ref());
+            }
+        }
+        Statement::IfResetStatement(x) => {
+            let x = x.if_reset_statement.as_ref();
+            ret.push(x.statement_block.as_ref());
+            for y in &x.if_reset_statement_list {
+                ret.push(y.statement_block.as_ref());
+            }
+            if let Some(ref y) = x.if_reset_statement_opt {
+                ret.push(y.statement_block.as_ref());
+            }
+        }
+        Statement::ForStatement(x) => ret.push(x.for_statement.statement_block.as_ref()),
+        Statement::CaseStatement(x) => {
+            for y in &x.case_statement.case_statement_list {
+                if let CaseItemGroup0::StatementBlock(z) = y.case_item.case_item_group0.as_ref() {
+                    ret.push(z.statement_block.as_ref());
+                }
+            }
+        }
+        Statement::SwitchStatement(x) => {
+            for y in &x.switch_statement.switch_statement_list {
+                if let SwitchItemGroup0::StatementBlock(z) =
+                    y.switch_item.switch_item_group0.as_ref()
+                {
+                    ret.push(z.statement_block.as_ref());
+                }
+            }
+        }
+        _ => (),
+    }
+    ret
+}
+
+/// Collects generate items with expanding `unsafe` blocks
+fn flatten_generate_item(arg: &GenerateItem, ret: &mut Vec<GenerateItem>) {
+    if let GenerateItem::UnsafeBlock(x) = arg {
+        for x in &x.unsafe_block.unsafe_block_list {
+            let items: Vec<GenerateItem> = x.generate_group.as_ref().into();
+            for x in &items {
+                flatten_generate_item(x, ret);
+            }
+        }
+    } else {
+        ret.push(arg.clone());
+    }
+}
+
+fn generate_named_block_items(arg: &GenerateNamedBlock) -> Vec<GenerateItem> {
+    let mut ret = Vec::new();
+    for x in &arg.generate_named_block_list {
+        let items: Vec<GenerateItem> = x.generate_group.as_ref().into();
+        for x in &items {
+            flatten_generate_item(x, &mut ret);
+        }
+    }
+    ret
+}
+
+fn generate_optional_named_block_items(arg: &GenerateOptionalNamedBlock) -> Vec<GenerateItem> {
+    let mut ret = Vec::new();
+    for x in &arg.generate_optional_named_block_list {
+        let items: Vec<GenerateItem> = x.generate_group.as_ref().into();
+        for x in &items {
+            flatten_generate_item(x, &mut ret);
+        }
+    }
+    ret
+}
+
+fn is_declarative_item(arg: &GenerateItem) -> bool {
+    matches!(
+        arg,
+        GenerateItem::VarDeclaration(_)
+            | GenerateItem::LetDeclaration(_)
+            | GenerateItem::ConstDeclaration(_)
+            | GenerateItem::TypeDefDeclaration(_)
+            | GenerateItem::EnumDeclaration(_)
+            | GenerateItem::StructUnionDeclaration(_)
+            | GenerateItem::FunctionDeclaration(_)
+    )
+}
+
+fn is_concurrent_item(arg: &GenerateItem) -> bool {
+    matches!(
+        arg,
+        GenerateItem::LetDeclaration(_)
+            | GenerateItem::AssignDeclaration(_)
+            | GenerateItem::AlwaysFfDeclaration(_)
+            | GenerateItem::AlwaysCombDeclaration(_)
+            | GenerateItem::InitialDeclaration(_)
+            | GenerateItem::InstDeclaration(_)
+            | GenerateItem::GenerateIfDeclaration(_)
+            | GenerateItem::GenerateForDeclaration(_)
+            | GenerateItem::GenerateBlockDeclaration(_)
+    )
+}
@@ -1398,6 +1398,14 @@ impl VerylWalker for Formatter {
         }
     }
 
+    /// Semantic action for non-terminal 'AssertionStatement'
+    fn assertion_statement(&mut self, arg: &AssertionStatement) {
+        self.assertion_keyword(&arg.assertion_keyword);
+        self.space(1);
+        self.expression(&arg.expression);
+        self.semicolon(&arg.semicolon);
+    }
+
     /// Semantic action for non-terminal 'Attribute'
     fn attribute(&mut self, arg: &Attribute) {
         self.in_attribute = true;
@@ -1426,6 +1434,28 @@ impl VerylWalker for Formatter {
         }
     }
 
+    /// Semantic action for non-terminal 'AttributeAssignment'
+    fn attribute_assignment(&mut self, arg: &AttributeAssignment) {
+        self.identifier(&arg.identifier);
+        self.space(1);
+        self.equ(&arg.equ);
+        self.space(1);
+        self.attribute_value(&arg.attribute_value);
+    }
+
+    /// Semantic action for non-terminal 'AttributeValueList'
+    fn attribute_value_list(&mut self, arg: &AttributeValueList) {
+        self.attribute_value(&arg.attribute_value);
+        for x in &arg.attribute_value_list_list {
+            self.comma(&x.comma);
+            self.space(1);
+            self.attribute_value(&x.attribute_value);
+        }
+        if let Some(ref x) = arg.attribute_value_list_opt {
+            self.comma(&x.comma);
+        }
+    }
+
     /// Semantic action for non-terminal 'LetDeclaration'
     fn let_declaration(&mut self, arg: &LetDeclaration) {
         self.r#let(&arg.r#let);
@@ -1556,6 +1586,30 @@ impl VerylWalker for Formatter {
         self.statement_block(&arg.statement_block);
     }
 
+    /// Semantic action for non-terminal 'AssertionDeclaration'
+    fn assertion_declaration(&mut self, arg: &AssertionDeclaration) {
+        self.assertion_keyword(&arg.assertion_keyword);
+        self.space(1);
+        if let Some(ref x) = arg.assertion_declaration_opt {
+            self.identifier(&x.identifier);
+            self.space(1);
+        }
+        if let Some(ref x) = arg.assertion_declaration_opt0 {
+            self.always_ff_event_list(&x.always_ff_event_list);
+        }
+        self.token_will_push(&arg.l_brace.l_brace_token);
+        self.newline_push();
+        self.expression(&arg.expression);
+        if let Some(ref x) = arg.assertion_declaration_opt1 {
+            self.space(1);
+            self.implication(&x.implication);
+            self.space(1);
+            self.expression(&x.expression);
+        }
+        self.newline_pop();
+        self.r_brace(&arg.r_brace);
+    }
+
     /// Semantic action for non-terminal 'AssignDeclaration'
     fn assign_declaration(&mut self, arg: &AssignDeclaration) {
         self.assign(&arg.assign);
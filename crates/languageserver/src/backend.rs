@@ -1,6 +1,7 @@
 use std::str::FromStr;
 
 use crate::server::{MsgFromServer, MsgToServer, Server, ServerConfigItem, semantic_legend};
+use crate::test_runner::RUN_TEST_COMMAND;
 use async_channel::{Receiver, Sender, unbounded};
 use serde_json::Value;
 use tower_lsp_server::jsonrpc::Result;
@@ -53,7 +54,7 @@ impl LanguageServer for Backend {
         Ok(InitializeResult {
             capabilities: ServerCapabilities {
                 text_document_sync: Some(TextDocumentSyncCapability::Kind(
-                    TextDocumentSyncKind::FULL,
+                    TextDocumentSyncKind::INCREMENTAL,
                 )),
                 workspace: Some(WorkspaceServerCapabilities {
                     workspace_folders: Some(WorkspaceFoldersServerCapabilities {
@@ -101,6 +102,17 @@ impl LanguageServer for Backend {
                 workspace_symbol_provider: Some(OneOf::Left(true)),
                 hover_provider: Some(HoverProviderCapability::Simple(true)),
                 references_provider: Some(OneOf::Left(true)),
+                call_hierarchy_provider: Some(CallHierarchyServerCapability::Simple(true)),
+                folding_range_provider: Some(FoldingRangeProviderCapability::Simple(true)),
+                selection_range_provider: Some(SelectionRangeProviderCapability::Simple(true)),
+                document_highlight_provider: Some(OneOf::Left(true)),
+                code_lens_provider: Some(CodeLensOptions {
+                    resolve_provider: Some(false),
+                }),
+                execute_command_provider: Some(ExecuteCommandOptions {
+                    commands: vec![RUN_TEST_COMMAND.to_string()],
+                    work_done_progress_options: WorkDoneProgressOptions::default(),
+                }),
                 semantic_tokens_provider: Some(
                     SemanticTokensServerCapabilities::SemanticTokensOptions(
                         SemanticTokensOptions {
@@ -156,11 +168,15 @@ impl LanguageServer for Backend {
             .await;
 
         let url = params.text_document.uri;
-        let text = std::mem::take(&mut params.content_changes[0].text);
+        let changes = std::mem::take(&mut params.content_changes);
         let version = params.text_document.version;
 
-        self.send(MsgToServer::DidChange { url, text, version })
-            .await;
+        self.send(MsgToServer::DidChange {
+            url,
+            changes,
+            version,
+        })
+        .await;
     }
 
     async fn did_change_watched_files(&self, params: DidChangeWatchedFilesParams) {
@@ -181,6 +197,12 @@ impl LanguageServer for Backend {
                     let x = ServerConfigItem::UseOperatorCompletion(*x);
                     self.send(MsgToServer::DidChangeConfiguration(x)).await;
                 }
+                if let Some(Value::Number(x)) = x.get("analysisDebounce")
+                    && let Some(x) = x.as_u64()
+                {
+                    let x = ServerConfigItem::AnalysisDebounce(x);
+                    self.send(MsgToServer::DidChangeConfiguration(x)).await;
+                }
             }
         }
     }
@@ -336,6 +358,127 @@ impl LanguageServer for Backend {
         }
     }
 
+    async fn folding_range(&self, params: FoldingRangeParams) -> Result<Option<Vec<FoldingRange>>> {
+        let url = params.text_document.uri;
+
+        self.send(MsgToServer::FoldingRange { url }).await;
+
+        if let Some(MsgFromServer::FoldingRange(x)) = self.recv().await {
+            Ok(x)
+        } else {
+            Ok(None)
+        }
+    }
+
+    async fn selection_range(
+        &self,
+        params: SelectionRangeParams,
+    ) -> Result<Option<Vec<SelectionRange>>> {
+        let url = params.text_document.uri;
+        let positions = params.positions;
+
+        self.send(MsgToServer::SelectionRange { url, positions })
+            .await;
+
+        if let Some(MsgFromServer::SelectionRange(x)) = self.recv().await {
+            Ok(x)
+        } else {
+            Ok(None)
+        }
+    }
+
+    async fn document_highlight(
+        &self,
+        params: DocumentHighlightParams,
+    ) -> Result<Option<Vec<DocumentHighlight>>> {
+        let url = params.text_document_position_params.text_document.uri;
+        let line = params.text_document_position_params.position.line as usize + 1;
+        let column = params.text_document_position_params.position.character as usize + 1;
+
+        self.send(MsgToServer::DocumentHighlight { url, line, column })
+            .await;
+
+        if let Some(MsgFromServer::DocumentHighlight(x)) = self.recv().await {
+            Ok(x)
+        } else {
+            Ok(None)
+        }
+    }
+
+    async fn code_lens(&self, params: CodeLensParams) -> Result<Option<Vec<CodeLens>>> {
+        let url = params.text_document.uri;
+
+        self.send(MsgToServer::CodeLens { url }).await;
+
+        if let Some(MsgFromServer::CodeLens(x)) = self.recv().await {
+            Ok(x)
+        } else {
+            Ok(None)
+        }
+    }
+
+    async fn execute_command(&self, params: ExecuteCommandParams) -> Result<Option<LSPAny>> {
+        let command = params.command;
+        let arguments = params.arguments;
+
+        self.send(MsgToServer::ExecuteCommand { command, arguments })
+            .await;
+
+        if let Some(MsgFromServer::ExecuteCommand(x)) = self.recv().await {
+            Ok(x)
+        } else {
+            Ok(None)
+        }
+    }
+
+    async fn prepare_call_hierarchy(
+        &self,
+        params: CallHierarchyPrepareParams,
+    ) -> Result<Option<Vec<CallHierarchyItem>>> {
+        let url = params.text_document_position_params.text_document.uri;
+        let line = params.text_document_position_params.position.line as usize + 1;
+        let column = params.text_document_position_params.position.character as usize + 1;
+
+        self.send(MsgToServer::PrepareCallHierarchy { url, line, column })
+            .await;
+
+        if let Some(MsgFromServer::PrepareCallHierarchy(x)) = self.recv().await {
+            Ok(x)
+        } else {
+            Ok(None)
+        }
+    }
+
+    async fn incoming_calls(
+        &self,
+        params: CallHierarchyIncomingCallsParams,
+    ) -> Result<Option<Vec<CallHierarchyIncomingCall>>> {
+        let item = params.item;
+
+        self.send(MsgToServer::IncomingCalls { item }).await;
+
+        if let Some(MsgFromServer::IncomingCalls(x)) = self.recv().await {
+            Ok(x)
+        } else {
+            Ok(None)
+        }
+    }
+
+    async fn outgoing_calls(
+        &self,
+        params: CallHierarchyOutgoingCallsParams,
+    ) -> Result<Option<Vec<CallHierarchyOutgoingCall>>> {
+        let item = params.item;
+
+        self.send(MsgToServer::OutgoingCalls { item }).await;
+
+        if let Some(MsgFromServer::OutgoingCalls(x)) = self.recv().await {
+            Ok(x)
+        } else {
+            Ok(None)
+        }
+    }
+
     async fn shutdown(&self) -> Result<()> {
         Ok(())
     }
@@ -2,7 +2,9 @@ pub const KEYWORDS: &[&str] = &[
     "alias",
     "always_comb",
     "always_ff",
+    "assert",
     "assign",
+    "assume",
     "as",
     "bit",
     "bool",
@@ -11,6 +13,7 @@ pub const KEYWORDS: &[&str] = &[
     "clock_posedge",
     "clock_negedge",
     "connect",
+    "cover",
     "const",
     "converse",
     "default",
@@ -2,7 +2,9 @@
 
 mod backend;
 mod keyword;
+mod navigation;
 mod server;
+mod test_runner;
 #[cfg(test)]
 mod tests;
 
@@ -0,0 +1,253 @@
+use tower_lsp_server::lsp_types::{
+    FoldingRange, FoldingRangeKind, Position, Range, SelectionRange,
+};
+use veryl_parser::ParolError;
+use veryl_parser::resource_table;
+use veryl_parser::token_range::TokenRange;
+use veryl_parser::veryl_grammar_trait::*;
+use veryl_parser::veryl_token::{Token, VerylToken};
+use veryl_parser::veryl_walker::{Handler, HandlerPoint, VerylWalker};
+
+/// Collects folding ranges of blocks, comments and embed contents
+#[derive(Default)]
+pub struct FoldingRangeCollector {
+    pub ranges: Vec<FoldingRange>,
+    braces: Vec<u32>,
+}
+
+impl FoldingRangeCollector {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    fn push(&mut self, beg: u32, end: u32, kind: Option<FoldingRangeKind>) {
+        if beg < end {
+            self.ranges.push(FoldingRange {
+                start_line: beg,
+                end_line: end,
+                kind,
+                ..Default::default()
+            });
+        }
+    }
+
+    fn comments(&mut self, comments: &[Token]) {
+        // Consecutive line comments are folded together
+        let mut group: Option<(u32, u32)> = None;
+        for comment in comments {
+            let text = token_text(comment);
+            let beg = comment.line - 1;
+            if text.starts_with("//") {
+                group = match group {
+                    Some((x, y)) if y + 1 == beg => Some((x, beg)),
+                    Some((x, y)) => {
+                        self.push(x, y, Some(FoldingRangeKind::Comment));
+                        Some((beg, beg))
+                    }
+                    None => Some((beg, beg)),
+                };
+            } else {
+                let end = beg + text.trim_end().matches('\n').count() as u32;
+                self.push(beg, end, Some(FoldingRangeKind::Comment));
+            }
+        }
+        if let Some((x, y)) = group {
+            self.push(x, y, Some(FoldingRangeKind::Comment));
+        }
+    }
+}
+
+impl VerylWalker for FoldingRangeCollector {
+    /// Semantic action for non-terminal 'VerylToken'
+    fn veryl_token(&mut self, arg: &VerylToken) {
+        self.comments(&arg.comments);
+    }
+
+    /// Semantic action for non-terminal 'QuoteLBrace'
+    fn quote_l_brace(&mut self, arg: &QuoteLBrace) {
+        self.braces.push(arg.quote_l_brace_token.token.line - 1);
+        self.veryl_token(&arg.quote_l_brace_token);
+    }
+
+    /// Semantic action for non-terminal 'LBrace'
+    fn l_brace(&mut self, arg: &LBrace) {
+        self.braces.push(arg.l_brace_token.token.line - 1);
+        self.veryl_token(&arg.l_brace_token);
+    }
+
+    /// Semantic action for non-terminal 'RBrace'
+    fn r_brace(&mut self, arg: &RBrace) {
+        // The line of the closing brace is kept visible
+        if let Some(beg) = self.braces.pop() {
+            let end = arg.r_brace_token.token.line - 1;
+            self.push(beg, end.saturating_sub(1), None);
+        }
+        self.veryl_token(&arg.r_brace_token);
+    }
+
+    /// Semantic action for non-terminal 'EmbedContent'
+    fn embed_content(&mut self, arg: &EmbedContent) {
+        let token = &arg.embed_content_token.token;
+        let beg = token.line - 1;
+        let end = beg + token_text(token).trim_end().matches('\n').count() as u32;
+        self.push(beg, end.saturating_sub(1), None);
+        self.veryl_token(&arg.embed_content_token);
+    }
+}
+
+/// Collects syntax nodes including the specified position from outer to inner
+pub struct SelectionRangeCollector {
+    pub ranges: Vec<Range>,
+    line: u32,
+    column: u32,
+    point: HandlerPoint,
+}
+
+impl SelectionRangeCollector {
+    pub fn new(line: u32, column: u32) -> Self {
+        Self {
+            ranges: Vec::new(),
+            line,
+            column,
+            point: HandlerPoint::Before,
+        }
+    }
+
+    /// Builds the selection range from the innermost node
+    pub fn selection_range(&self) -> Option<SelectionRange> {
+        let mut ret: Option<SelectionRange> = None;
+        for range in &self.ranges {
+            if ret.as_ref().is_some_and(|x| x.range == *range) {
+                continue;
+            }
+            ret = Some(SelectionRange {
+                range: *range,
+                parent: ret.map(Box::new),
+            });
+        }
+        ret
+    }
+
+    fn push<T>(&mut self, arg: &T)
+    where
+        for<'a> &'a T: Into<TokenRange>,
+    {
+        if let HandlerPoint::Before = self.point {
+            let range: TokenRange = arg.into();
+            let beg = (range.beg.line, range.beg.column);
+            let end = (range.end.line, range.end.column + range.end.length);
+            let pos = (self.line, self.column);
+            if beg <= pos && pos <= end {
+                self.ranges.push(Range::new(
+                    Position::new(beg.0 - 1, beg.1 - 1),
+                    Position::new(end.0 - 1, end.1 - 1),
+                ));
+            }
+        }
+    }
+}
+
+impl VerylWalker for SelectionRangeCollector {
+    fn get_handlers(&mut self) -> Option<Vec<(bool, &mut dyn Handler)>> {
+        Some(vec![(true, self)])
+    }
+}
+
+impl Handler for SelectionRangeCollector {
+    fn set_point(&mut self, p: HandlerPoint) {
+        self.point = p;
+    }
+}
+
+type Result = std::result::Result<(), ParolError>;
+
+impl VerylGrammarTrait for SelectionRangeCollector {
+    fn identifier(&mut self, arg: &Identifier) -> Result {
+        self.push(arg);
+        Ok(())
+    }
+
+    fn expression(&mut self, arg: &Expression) -> Result {
+        self.push(arg);
+        Ok(())
+    }
+
+    fn statement(&mut self, arg: &Statement) -> Result {
+        self.push(arg);
+        Ok(())
+    }
+
+    fn statement_block(&mut self, arg: &StatementBlock) -> Result {
+        self.push(arg);
+        Ok(())
+    }
+
+    fn let_declaration(&mut self, arg: &LetDeclaration) -> Result {
+        self.push(arg);
+        Ok(())
+    }
+
+    fn var_declaration(&mut self, arg: &VarDeclaration) -> Result {
+        self.push(arg);
+        Ok(())
+    }
+
+    fn const_declaration(&mut self, arg: &ConstDeclaration) -> Result {
+        self.push(arg);
+        Ok(())
+    }
+
+    fn assign_declaration(&mut self, arg: &AssignDeclaration) -> Result {
+        self.push(arg);
+        Ok(())
+    }
+
+    fn always_ff_declaration(&mut self, arg: &AlwaysFfDeclaration) -> Result {
+        self.push(arg);
+        Ok(())
+    }
+
+    fn always_comb_declaration(&mut self, arg: &AlwaysCombDeclaration) -> Result {
+        self.push(arg);
+        Ok(())
+    }
+
+    fn inst_declaration(&mut self, arg: &InstDeclaration) -> Result {
+        self.push(arg);
+        Ok(())
+    }
+
+    fn function_declaration(&mut self, arg: &FunctionDeclaration) -> Result {
+        self.push(arg);
+        Ok(())
+    }
+
+    fn generate_for_declaration(&mut self, arg: &GenerateForDeclaration) -> Result {
+        self.push(arg);
+        Ok(())
+    }
+
+    fn generate_named_block(&mut self, arg: &GenerateNamedBlock) -> Result {
+        self.push(arg);
+        Ok(())
+    }
+
+    fn module_declaration(&mut self, arg: &ModuleDeclaration) -> Result {
+        self.push(arg);
+        Ok(())
+    }
+
+    fn interface_declaration(&mut self, arg: &InterfaceDeclaration) -> Result {
+        self.push(arg);
+        Ok(())
+    }
+
+    fn package_declaration(&mut self, arg: &PackageDeclaration) -> Result {
+        self.push(arg);
+        Ok(())
+    }
+}
+
+fn token_text(token: &Token) -> String {
+    resource_table::get_str_value(token.text).unwrap_or_default()
+}
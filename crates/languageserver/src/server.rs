@@ -1,26 +1,30 @@
 use crate::keyword::KEYWORDS;
+use crate::navigation::{FoldingRangeCollector, SelectionRangeCollector};
+use crate::test_runner::{self, DiagnosticMap, RUN_TEST_COMMAND, TestRun};
 use async_channel::{Receiver, Sender};
 use dashmap::DashMap;
 use futures::executor::block_on;
 use ropey::Rope;
-use std::collections::VecDeque;
+use serde_json::Value;
+use std::collections::{HashSet, VecDeque};
 use std::path::PathBuf;
+use std::str::FromStr;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
 use tower_lsp_server::lsp_types::Uri as Url;
 use tower_lsp_server::lsp_types::*;
 use tower_lsp_server::{Client, UriExt};
+use veryl_analyzer::dependency_graph::{self, DependencyGraph};
 use veryl_analyzer::namespace::Namespace;
 use veryl_analyzer::symbol::SymbolKind as VerylSymbolKind;
-use veryl_analyzer::symbol::{Symbol, TypeKind};
+use veryl_analyzer::symbol::{Symbol, SymbolId, TypeKind};
 use veryl_analyzer::symbol_path::SymbolPath;
-use veryl_analyzer::{
-    Analyzer, AnalyzerError, attribute_table, definition_table, namespace_table, symbol_table,
-    unsafe_table,
-};
+use veryl_analyzer::{Analyzer, AnalyzerError, namespace_table, symbol_table};
 use veryl_formatter::Formatter;
 use veryl_metadata::Metadata;
 use veryl_parser::resource_table::{self, PathId};
-use veryl_parser::text_table;
-use veryl_parser::veryl_token::Token;
+use veryl_parser::token_range::TokenRange;
+use veryl_parser::veryl_token::{Token, TokenSource};
 use veryl_parser::veryl_walker::VerylWalker;
 use veryl_parser::{Finder, Parser, ParserError};
 use veryl_path::PathSet;
@@ -33,7 +37,7 @@ pub enum MsgToServer {
     },
     DidChange {
         url: Url,
-        text: String,
+        changes: Vec<TextDocumentContentChangeEvent>,
         version: i32,
     },
     DidChangeConfiguration(ServerConfigItem),
@@ -77,6 +81,36 @@ pub enum MsgToServer {
     Formatting {
         url: Url,
     },
+    PrepareCallHierarchy {
+        url: Url,
+        line: usize,
+        column: usize,
+    },
+    IncomingCalls {
+        item: CallHierarchyItem,
+    },
+    OutgoingCalls {
+        item: CallHierarchyItem,
+    },
+    CodeLens {
+        url: Url,
+    },
+    FoldingRange {
+        url: Url,
+    },
+    SelectionRange {
+        url: Url,
+        positions: Vec<Position>,
+    },
+    DocumentHighlight {
+        url: Url,
+        line: usize,
+        column: usize,
+    },
+    ExecuteCommand {
+        command: String,
+        arguments: Vec<Value>,
+    },
 }
 
 pub enum MsgFromServer {
@@ -87,6 +121,14 @@ pub enum MsgFromServer {
     References(Vec<Location>),
     SemanticTokens(Option<SemanticTokensResult>),
     Formatting(Option<Vec<TextEdit>>),
+    PrepareCallHierarchy(Option<Vec<CallHierarchyItem>>),
+    IncomingCalls(Option<Vec<CallHierarchyIncomingCall>>),
+    OutgoingCalls(Option<Vec<CallHierarchyOutgoingCall>>),
+    CodeLens(Option<Vec<CodeLens>>),
+    FoldingRange(Option<Vec<FoldingRange>>),
+    SelectionRange(Option<Vec<SelectionRange>>),
+    DocumentHighlight(Option<Vec<DocumentHighlight>>),
+    ExecuteCommand(Option<Value>),
 }
 
 pub struct BackgroundTask {
@@ -98,17 +140,30 @@ pub struct BackgroundTask {
 
 pub enum ServerConfigItem {
     UseOperatorCompletion(bool),
+    AnalysisDebounce(u64),
 }
 
-#[derive(Default)]
 pub struct ServerConfig {
     use_operator_completion: bool,
+    analysis_debounce: Duration,
+}
+
+impl Default for ServerConfig {
+    fn default() -> Self {
+        Self {
+            use_operator_completion: false,
+            analysis_debounce: Duration::from_millis(200),
+        }
+    }
 }
 
 impl ServerConfig {
     pub fn set(&mut self, item: ServerConfigItem) {
         match item {
             ServerConfigItem::UseOperatorCompletion(x) => self.use_operator_completion = x,
+            ServerConfigItem::AnalysisDebounce(x) => {
+                self.analysis_debounce = Duration::from_millis(x)
+            }
         }
     }
 }
@@ -119,13 +174,18 @@ pub struct Server {
     snd: Sender<MsgFromServer>,
     document_map: DashMap<PathBuf, Rope>,
     parser_map: DashMap<PathBuf, Parser>,
+    partial_parse: HashSet<PathBuf>,
+    diagnostics: DiagnosticMap,
+    test_diagnostics: DiagnosticMap,
     metadata_map: DashMap<PathBuf, Metadata>,
     cache_dir: PathBuf,
     lsp_token: i32,
     background_tasks: VecDeque<BackgroundTask>,
     background_done: bool,
     config: ServerConfig,
-    latest_change: Option<(Url, String, i32)>,
+    latest_change: Option<(Url, i32)>,
+    pending_change: Option<(Url, i32, Instant)>,
+    dependency_graph: DependencyGraph,
 }
 
 impl Server {
@@ -136,6 +196,9 @@ impl Server {
             snd,
             document_map: DashMap::new(),
             parser_map: DashMap::new(),
+            partial_parse: HashSet::new(),
+            diagnostics: Arc::new(DashMap::new()),
+            test_diagnostics: Arc::new(DashMap::new()),
             metadata_map: DashMap::new(),
             cache_dir: veryl_path::cache_path(),
             lsp_token: 0,
@@ -143,20 +206,39 @@ impl Server {
             background_done: true,
             config: ServerConfig::default(),
             latest_change: None,
+            pending_change: None,
+            dependency_graph: DependencyGraph::new(),
         }
     }
 
     pub fn serve(&mut self) {
         loop {
-            if let Ok(msg) = self.rcv.recv_blocking() {
+            if let Some(msg) = self.recv() {
+                // Analysis of the pending change is postponed until any other request
+                let is_change = matches!(msg, MsgToServer::DidChange { .. });
+                if !is_change {
+                    self.flush_change();
+                }
+
                 match msg {
                     MsgToServer::DidOpen { url, text, version } => {
                         self.did_open(&url, &text, version);
-                        self.latest_change = Some((url, text, version));
+                        self.latest_change = Some((url, version));
                     }
-                    MsgToServer::DidChange { url, text, version } => {
-                        self.did_change(&url, &text, version);
-                        self.latest_change = Some((url, text, version));
+                    MsgToServer::DidChange {
+                        url,
+                        changes,
+                        version,
+                    } => {
+                        self.apply_changes(&url, changes);
+                        if let Some((pending, _, _)) = &self.pending_change
+                            && *pending != url
+                        {
+                            self.flush_change();
+                        }
+                        let deadline = Instant::now() + self.config.analysis_debounce;
+                        self.pending_change = Some((url.clone(), version, deadline));
+                        self.latest_change = Some((url, version));
                     }
                     MsgToServer::DidChangeConfiguration(x) => self.config.set(x),
                     MsgToServer::WillRenameFile { old_url } => self.on_remove(old_url),
@@ -178,10 +260,29 @@ impl Server {
                     }
                     MsgToServer::SemanticTokens { url } => self.semantic_tokens(&url),
                     MsgToServer::Formatting { url } => self.formatting(&url),
+                    MsgToServer::PrepareCallHierarchy { url, line, column } => {
+                        self.prepare_call_hierarchy(&url, line, column)
+                    }
+                    MsgToServer::IncomingCalls { item } => self.incoming_calls(&item),
+                    MsgToServer::OutgoingCalls { item } => self.outgoing_calls(&item),
+                    MsgToServer::CodeLens { url } => self.code_lens(&url),
+                    MsgToServer::FoldingRange { url } => self.folding_range(&url),
+                    MsgToServer::SelectionRange { url, positions } => {
+                        self.selection_range(&url, &positions)
+                    }
+                    MsgToServer::DocumentHighlight { url, line, column } => {
+                        self.document_highlight(&url, line, column)
+                    }
+                    MsgToServer::ExecuteCommand { command, arguments } => {
+                        self.execute_command(&command, &arguments)
+                    }
                 }
             }
 
-            while self.rcv.is_empty() && !self.background_tasks.is_empty() {
+            while self.rcv.is_empty()
+                && self.pending_change.is_none()
+                && !self.background_tasks.is_empty()
+            {
                 if let Some(mut task) = self.background_tasks.pop_front() {
                     if !task.progress {
                         self.progress_start("background analyze");
@@ -200,10 +301,11 @@ impl Server {
                         self.progress_done("background analyze done");
                         if self.background_tasks.is_empty() {
                             self.background_done = true;
+                            self.dependency_graph.rebuild();
 
                             // call did_change after background_done to notify filtered errors
-                            if let Some((url, text, version)) = self.latest_change.take() {
-                                self.did_change(&url, &text, version);
+                            if let Some((url, version)) = self.latest_change.take() {
+                                self.did_change(&url, version);
                             }
                         }
                     } else {
@@ -213,6 +315,31 @@ impl Server {
             }
         }
     }
+
+    fn recv(&mut self) -> Option<MsgToServer> {
+        loop {
+            let Some((_, _, deadline)) = &self.pending_change else {
+                return self.rcv.recv_blocking().ok();
+            };
+
+            if let Ok(msg) = self.rcv.try_recv() {
+                return Some(msg);
+            }
+
+            let now = Instant::now();
+            if now >= *deadline {
+                self.flush_change();
+                return None;
+            }
+            std::thread::sleep((*deadline - now).min(Duration::from_millis(10)));
+        }
+    }
+
+    fn flush_change(&mut self) {
+        if let Some((url, version, _)) = self.pending_change.take() {
+            self.did_change(&url, version);
+        }
+    }
 }
 
 impl Server {
@@ -246,14 +373,45 @@ impl Server {
         }
     }
 
-    fn did_change(&mut self, url: &Url, text: &str, version: i32) {
+    fn did_change(&mut self, url: &Url, version: i32) {
+        let Some(text) = self.get_text(url) else {
+            return;
+        };
         if let Some(metadata) = self.get_metadata(url) {
-            self.on_change(&metadata.project.name, url, text, version);
+            self.on_change(&metadata.project.name, url, &text, version);
         } else {
-            self.on_change("", url, text, version);
+            self.on_change("", url, &text, version);
+        }
+    }
+
+    fn apply_changes(&mut self, url: &Url, changes: Vec<TextDocumentContentChangeEvent>) {
+        if let Some(path) = url.to_file_path() {
+            let mut rope = self
+                .document_map
+                .remove(path.as_ref())
+                .map(|(_, x)| x)
+                .unwrap_or_default();
+            for change in changes {
+                if let Some(range) = change.range {
+                    let beg = to_char_idx(&rope, &range.start);
+                    let end = to_char_idx(&rope, &range.end).max(beg);
+                    rope.remove(beg..end);
+                    rope.insert(beg, &change.text);
+                } else {
+                    rope = Rope::from_str(&change.text);
+                }
+            }
+            self.document_map.insert(path.to_path_buf(), rope);
         }
     }
 
+    fn get_text(&self, url: &Url) -> Option<String> {
+        let path = url.to_file_path()?;
+        self.document_map
+            .get(path.as_ref())
+            .map(|rope| rope.to_string())
+    }
+
     fn did_rename_files(&mut self, new_path: Url) {
         // Do not dispatch if there's already a pending analysis
         if !self.background_done {
@@ -565,7 +723,10 @@ impl Server {
             if let Some(metadata) = self.get_metadata(url) {
                 if let Some(rope) = self.document_map.get(path.as_ref()) {
                     let line = rope.len_lines() as u32;
-                    if let Some(parser) = self.parser_map.get(path.as_ref()) {
+                    // Formatting a partial tree drops the part removed by error recovery
+                    if let Some(parser) = self.parser_map.get(path.as_ref())
+                        && !self.partial_parse.contains(path.as_ref())
+                    {
                         let mut formatter = Formatter::new(&metadata);
                         formatter.format(&parser.veryl);
 
@@ -587,6 +748,252 @@ impl Server {
             .send_blocking(MsgFromServer::Formatting(None))
             .unwrap();
     }
+
+    fn folding_range(&mut self, url: &Url) {
+        let ret = url.to_file_path().and_then(|path| {
+            let parser = self.parser_map.get(path.as_ref())?;
+            let mut collector = FoldingRangeCollector::new();
+            collector.veryl(&parser.veryl);
+            Some(collector.ranges)
+        });
+
+        self.snd
+            .send_blocking(MsgFromServer::FoldingRange(ret))
+            .unwrap();
+    }
+
+    fn selection_range(&mut self, url: &Url, positions: &[Position]) {
+        let ret = url.to_file_path().and_then(|path| {
+            let parser = self.parser_map.get(path.as_ref())?;
+            let mut ret = Vec::new();
+            for position in positions {
+                let mut collector =
+                    SelectionRangeCollector::new(position.line + 1, position.character + 1);
+                collector.veryl(&parser.veryl);
+                // Empty range is returned if there is no syntax node at the position
+                let range = collector.selection_range().unwrap_or(SelectionRange {
+                    range: Range::new(*position, *position),
+                    parent: None,
+                });
+                ret.push(range);
+            }
+            Some(ret)
+        });
+
+        self.snd
+            .send_blocking(MsgFromServer::SelectionRange(ret))
+            .unwrap();
+    }
+
+    fn document_highlight(&mut self, url: &Url, line: usize, column: usize) {
+        let ret = url.to_file_path().and_then(|path| {
+            let path = resource_table::get_path_id(path.to_path_buf())?;
+            let symbol = self.resolve_symbol(url, line, column)?;
+            Some(document_highlights(&symbol, path))
+        });
+
+        self.snd
+            .send_blocking(MsgFromServer::DocumentHighlight(ret))
+            .unwrap();
+    }
+
+    fn code_lens(&mut self, url: &Url) {
+        let mut ret = Vec::new();
+        if let Some(path) = url.to_file_path()
+            && let Some(path) = resource_table::get_path_id(path.to_path_buf())
+        {
+            for symbol in symbol_table::get_all() {
+                if !matches!(symbol.kind, VerylSymbolKind::Test(_)) || symbol.token.source != path {
+                    continue;
+                }
+
+                let range = to_location(&symbol.token).range;
+                let test = symbol.token.text.to_string();
+                for (title, wave) in [("Run test", false), ("Run test with waves", true)] {
+                    let command = Command {
+                        title: title.to_string(),
+                        command: RUN_TEST_COMMAND.to_string(),
+                        arguments: Some(vec![
+                            Value::from(url.as_str()),
+                            Value::from(test.clone()),
+                            Value::from(wave),
+                        ]),
+                    };
+                    ret.push(CodeLens {
+                        range,
+                        command: Some(command),
+                        data: None,
+                    });
+                }
+            }
+        }
+
+        self.snd
+            .send_blocking(MsgFromServer::CodeLens(Some(ret)))
+            .unwrap();
+    }
+
+    fn execute_command(&mut self, command: &str, arguments: &[Value]) {
+        if command == RUN_TEST_COMMAND
+            && let [url, test, wave] = arguments
+            && let Some(url) = url.as_str().and_then(|x| Url::from_str(x).ok())
+            && let Some(test) = test.as_str()
+            && let Some(metadata) = self.get_metadata(&url)
+        {
+            // Tests run in background because it may take a long time
+            let run = TestRun {
+                client: self.client.clone(),
+                project_path: metadata.project_path(),
+                test: test.to_string(),
+                wave: wave.as_bool().unwrap_or(false),
+                diagnostics: self.diagnostics.clone(),
+                test_diagnostics: self.test_diagnostics.clone(),
+            };
+            std::thread::spawn(move || run.run());
+        }
+
+        self.snd
+            .send_blocking(MsgFromServer::ExecuteCommand(None))
+            .unwrap();
+    }
+
+    fn prepare_call_hierarchy(&mut self, url: &Url, line: usize, column: usize) {
+        let ret = self
+            .resolve_symbol(url, line, column)
+            .and_then(|x| call_hierarchy_target(&x))
+            .and_then(|x| to_call_hierarchy_item(&x))
+            .map(|x| vec![x]);
+
+        self.snd
+            .send_blocking(MsgFromServer::PrepareCallHierarchy(ret))
+            .unwrap();
+    }
+
+    fn incoming_calls(&mut self, item: &CallHierarchyItem) {
+        let ret = self.call_hierarchy_symbol(item).map(|target| {
+            // Generic instances like `M::<8>` are registered as separate symbols
+            let mut targets = vec![target.id];
+            targets.extend(target.generic_instances.iter().copied());
+
+            let mut calls = CallGroups::default();
+            for symbol in symbol_table::get_all() {
+                if let Some(found) = instance_type(&symbol)
+                    && targets.contains(&found.id)
+                    && let Some(caller) = enclosing_symbol(&symbol.token)
+                {
+                    calls.push(caller, &symbol.token);
+                }
+            }
+
+            if matches!(target.kind, VerylSymbolKind::Function(_)) {
+                for symbol in targets.iter().filter_map(|x| symbol_table::get(*x)) {
+                    for reference in &symbol.references {
+                        if let Some(caller) = enclosing_symbol(reference) {
+                            calls.push(caller, reference);
+                        }
+                    }
+                }
+            }
+
+            calls
+                .0
+                .into_iter()
+                .filter_map(|(caller, from_ranges)| {
+                    to_call_hierarchy_item(&caller)
+                        .map(|from| CallHierarchyIncomingCall { from, from_ranges })
+                })
+                .collect()
+        });
+
+        self.snd
+            .send_blocking(MsgFromServer::IncomingCalls(ret))
+            .unwrap();
+    }
+
+    fn outgoing_calls(&mut self, item: &CallHierarchyItem) {
+        let ret = self.call_hierarchy_symbol(item).and_then(|source| {
+            let range = symbol_range(&source)?;
+            let namespace = source.inner_namespace();
+
+            let mut calls = CallGroups::default();
+            for symbol in symbol_table::get_all() {
+                match symbol.kind {
+                    VerylSymbolKind::Instance(_) if symbol.namespace.included(&namespace) => {
+                        if let Some(callee) = instance_type(&symbol)
+                            .as_ref()
+                            .and_then(call_hierarchy_target)
+                        {
+                            calls.push(callee, &symbol.token);
+                        }
+                    }
+                    VerylSymbolKind::Function(_) | VerylSymbolKind::GenericInstance(_) => {
+                        let Some(callee) = call_hierarchy_target(&symbol) else {
+                            continue;
+                        };
+                        if !matches!(callee.kind, VerylSymbolKind::Function(_))
+                            || callee.id == source.id
+                        {
+                            continue;
+                        }
+                        for reference in &symbol.references {
+                            if token_included(&range, reference) {
+                                calls.push(callee.clone(), reference);
+                            }
+                        }
+                    }
+                    _ => (),
+                }
+            }
+
+            Some(
+                calls
+                    .0
+                    .into_iter()
+                    .filter_map(|(callee, from_ranges)| {
+                        to_call_hierarchy_item(&callee)
+                            .map(|to| CallHierarchyOutgoingCall { to, from_ranges })
+                    })
+                    .collect(),
+            )
+        });
+
+        self.snd
+            .send_blocking(MsgFromServer::OutgoingCalls(ret))
+            .unwrap();
+    }
+
+    fn resolve_symbol(&self, url: &Url, line: usize, column: usize) -> Option<Symbol> {
+        let path = url.to_file_path()?;
+        let parser = self.parser_map.get(path.as_ref())?;
+        let mut finder = Finder::new();
+        finder.line = line as u32;
+        finder.column = column as u32;
+        finder.veryl(&parser.veryl);
+
+        let token = finder.token?;
+        let namespace = namespace_table::get(token.id)?;
+        let path = if finder.token_group.is_empty() {
+            SymbolPath::new(&[token.text])
+        } else {
+            SymbolPath::from(finder.token_group.as_slice())
+        };
+        symbol_table::resolve((&path, &namespace))
+            .ok()
+            .map(|x| x.found)
+    }
+
+    fn call_hierarchy_symbol(&self, item: &CallHierarchyItem) -> Option<Symbol> {
+        let id = item.data.as_ref().and_then(|x| x.as_u64());
+        if let Some(symbol) = id.and_then(|x| symbol_table::get(SymbolId(x as usize))) {
+            return Some(symbol);
+        }
+
+        // Symbol IDs are renewed by re-analysis, so fall back to the item position
+        let line = item.selection_range.start.line as usize + 1;
+        let column = item.selection_range.start.character as usize + 1;
+        self.resolve_symbol(&item.uri, line, column)
+            .and_then(|x| call_hierarchy_target(&x))
+    }
 }
 
 impl Server {
@@ -654,9 +1061,9 @@ impl Server {
             if self.document_map.contains_key(&src) {
                 return;
             }
-            if let Ok(x) = Parser::parse(&text, &src) {
+            if let (Some(x), _) = Parser::parse_with_recovery(&text, &src) {
                 if let Some(src) = resource_table::get_path_id(&src) {
-                    drop_tables(src);
+                    Analyzer::drop_file(src);
                 }
                 let analyzer = Analyzer::new(metadata);
                 let _ = analyzer.analyze_pass1(&path.prj, &src, &x.veryl);
@@ -685,30 +1092,70 @@ impl Server {
     }
 
     fn on_change(&mut self, prj: &str, url: &Url, text: &str, version: i32) {
+        let changed = self.analyze(prj, url, text, Some(version));
+
+        // Re-analyze files depending on this file only if the exported symbols are changed
+        if let Some(path) = changed {
+            self.update_dependents(path);
+        }
+    }
+
+    /// Analyzes the document and returns its path if symbols referable from other files are changed
+    fn analyze(
+        &mut self,
+        prj: &str,
+        url: &Url,
+        text: &str,
+        version: Option<i32>,
+    ) -> Option<PathId> {
+        let mut changed = None;
         if let Some(path) = url.to_file_path() {
             let rope = Rope::from_str(text);
 
             if path.starts_with(&self.cache_dir) {
-                return;
+                return None;
             }
 
             if let Some(metadata) = self.get_metadata(url) {
-                let diag = match Parser::parse(text, &path) {
-                    Ok(x) => {
-                        if let Some(path) = resource_table::get_path_id(path.to_path_buf()) {
-                            drop_tables(path);
-                        }
+                let (parser, parser_errors) = Parser::parse_with_recovery(text, &path);
+                let partial = !parser_errors.is_empty();
+                if partial {
+                    self.partial_parse.insert(path.to_path_buf());
+                } else {
+                    self.partial_parse.remove(path.as_ref());
+                }
+                let mut diag: Vec<_> = parser_errors
+                    .into_iter()
+                    .map(|x| to_diag(x.into(), &rope))
+                    .collect();
+                diag.append(&mut match parser {
+                    Some(x) => {
+                        let path_id = resource_table::get_path_id(path.to_path_buf());
+                        let snapshot = path_id.map(|x| {
+                            let signature = dependency_graph::file_signature(x);
+                            let references = dependency_graph::external_references(x);
+                            Analyzer::drop_file(x);
+                            (x, signature, references)
+                        });
                         let analyzer = Analyzer::new(&metadata);
                         let mut errors = analyzer.analyze_pass1(prj, &path, &x.veryl);
                         errors.append(&mut Analyzer::analyze_post_pass1());
                         errors.append(&mut analyzer.analyze_pass2(prj, &path, &x.veryl));
                         let info = Analyzer::analyze_post_pass2();
                         errors.append(&mut analyzer.analyze_pass3(prj, &path, &x.veryl, &info));
+                        if let Some((path_id, signature, references)) = snapshot {
+                            dependency_graph::restore_references(path_id, references);
+                            self.dependency_graph.update(path_id);
+                            if signature != dependency_graph::file_signature(path_id) {
+                                changed = Some(path_id);
+                            }
+                        }
                         let ret: Vec<_> = errors
                             .drain(0..)
                             .filter(|x| {
-                                // Filter errors caused by unresolve error until background completion
-                                if self.background_done {
+                                // Filter errors caused by unresolve error until background completion,
+                                // and errors caused by statements removed by parser error recovery
+                                if self.background_done && !partial {
                                     true
                                 } else {
                                     !matches!(
@@ -729,15 +1176,19 @@ impl Server {
                         self.parser_map.insert(path.to_path_buf(), x);
                         ret
                     }
-                    Err(x) => {
+                    None => {
                         self.parser_map.remove(path.as_ref());
-                        vec![to_diag(x.into(), &rope)]
+                        vec![]
                     }
-                };
+                });
 
-                block_on(
-                    self.client
-                        .publish_diagnostics(url.clone(), diag, Some(version)),
+                self.diagnostics.insert(url.clone(), diag);
+                test_runner::publish(
+                    &self.client,
+                    url,
+                    &self.diagnostics,
+                    &self.test_diagnostics,
+                    version,
                 );
             } else {
                 block_on(self.client.log_message(
@@ -748,12 +1199,50 @@ impl Server {
 
             self.document_map.insert(path.to_path_buf(), rope);
         }
+        changed
+    }
+
+    fn update_dependents(&mut self, path: PathId) {
+        // Dependencies are not fixed until background analysis completion
+        if !self.background_done {
+            return;
+        }
+
+        for dependent in self.dependency_graph.dependents(path) {
+            let Some(src) = resource_table::get_path_value(dependent) else {
+                continue;
+            };
+            if src.starts_with(&self.cache_dir) {
+                continue;
+            }
+            let Some(url) = Url::from_file_path(&src) else {
+                continue;
+            };
+            let Some(metadata) = self.get_metadata(&url) else {
+                continue;
+            };
+
+            if let Some(text) = self.get_text(&url) {
+                self.analyze(&metadata.project.name, &url, &text, None);
+            } else if let Ok(text) = std::fs::read_to_string(&src)
+                && let (Some(x), _) = Parser::parse_with_recovery(&text, &src)
+            {
+                let references = dependency_graph::external_references(dependent);
+                Analyzer::drop_file(dependent);
+                let analyzer = Analyzer::new(&metadata);
+                let _ = analyzer.analyze_pass1(&metadata.project.name, &src, &x.veryl);
+                let _ = Analyzer::analyze_post_pass1();
+                dependency_graph::restore_references(dependent, references);
+                self.dependency_graph.update(dependent);
+            }
+        }
     }
 
     fn on_remove(&mut self, path: Url) {
         if let Some(path) = path.to_file_path() {
             if let Some(path_id) = resource_table::get_path_id(path.to_path_buf()) {
-                drop_tables(path_id);
+                Analyzer::drop_file(path_id);
+                self.dependency_graph.remove(path_id);
             }
         }
     }
@@ -803,12 +1292,16 @@ fn to_diag(err: miette::ErrReport, rope: &Rope) -> Diagnostic {
             Some(miette::Severity::Advice) => (DiagnosticSeverity::HINT, "Hint"),
             None => (DiagnosticSeverity::ERROR, "Error"),
         };
-        (severity, format!("Semantic {text}: {err}"))
+        let mut message = format!("Semantic {text}: {err}");
+        if let Some(help) = x.help() {
+            message.push_str(&format!("\nhelp: {help}"));
+        }
+        (severity, message)
     } else {
         (DiagnosticSeverity::ERROR, format!("Semantic Error: {err}"))
     };
 
-    Diagnostic::new(
+    let mut diag = Diagnostic::new(
         range,
         Some(severity),
         code,
@@ -816,6 +1309,127 @@ fn to_diag(err: miette::ErrReport, rope: &Rope) -> Diagnostic {
         message,
         None,
         None,
+    );
+    diag.code_description = miette_diag
+        .url()
+        .and_then(|x| Url::from_str(&x.to_string()).ok())
+        .map(|href| CodeDescription { href });
+    diag
+}
+
+#[derive(Default)]
+struct CallGroups(Vec<(Symbol, Vec<Range>)>);
+
+impl CallGroups {
+    fn push(&mut self, symbol: Symbol, token: &Token) {
+        let range = to_location(token).range;
+        if let Some((_, ranges)) = self.0.iter_mut().find(|(x, _)| x.id == symbol.id) {
+            if !ranges.contains(&range) {
+                ranges.push(range);
+            }
+        } else {
+            self.0.push((symbol, vec![range]));
+        }
+    }
+}
+
+fn call_hierarchy_target(symbol: &Symbol) -> Option<Symbol> {
+    match &symbol.kind {
+        VerylSymbolKind::Module(_)
+        | VerylSymbolKind::Interface(_)
+        | VerylSymbolKind::Package(_)
+        | VerylSymbolKind::Function(_) => Some(symbol.clone()),
+        VerylSymbolKind::AliasModule(x) => {
+            let found =
+                symbol_table::resolve((&x.target.generic_path(), &symbol.namespace)).ok()?;
+            call_hierarchy_target(&found.found)
+        }
+        VerylSymbolKind::AliasInterface(x) => {
+            let found =
+                symbol_table::resolve((&x.target.generic_path(), &symbol.namespace)).ok()?;
+            call_hierarchy_target(&found.found)
+        }
+        VerylSymbolKind::GenericInstance(x) => {
+            let base = symbol_table::get(x.base)?;
+            call_hierarchy_target(&base)
+        }
+        VerylSymbolKind::Instance(_) => call_hierarchy_target(&instance_type(symbol)?),
+        _ => None,
+    }
+}
+
+fn instance_type(symbol: &Symbol) -> Option<Symbol> {
+    if let VerylSymbolKind::Instance(x) = &symbol.kind {
+        symbol_table::resolve((&x.type_name.mangled_path(), &symbol.namespace))
+            .ok()
+            .map(|x| x.found)
+    } else {
+        None
+    }
+}
+
+fn symbol_range(symbol: &Symbol) -> Option<TokenRange> {
+    match &symbol.kind {
+        VerylSymbolKind::Module(x) => Some(x.range),
+        VerylSymbolKind::Interface(x) => Some(x.range),
+        VerylSymbolKind::Package(x) => Some(x.range),
+        VerylSymbolKind::Function(x) => Some(x.range),
+        _ => None,
+    }
+}
+
+fn token_included(range: &TokenRange, token: &Token) -> bool {
+    if let TokenSource::File { path, .. } = token.source {
+        range.include(path, token.line, token.column)
+    } else {
+        false
+    }
+}
+
+fn enclosing_symbol(token: &Token) -> Option<Symbol> {
+    let mut ret: Option<Symbol> = None;
+    for symbol in symbol_table::get_all() {
+        let included = symbol_range(&symbol)
+            .map(|x| token_included(&x, token))
+            .unwrap_or(false);
+        let inner = ret
+            .as_ref()
+            .map(|x| x.namespace.depth() < symbol.namespace.depth())
+            .unwrap_or(true);
+        if included && inner {
+            ret = Some(symbol);
+        }
+    }
+    ret
+}
+
+fn to_call_hierarchy_item(symbol: &Symbol) -> Option<CallHierarchyItem> {
+    let kind = match symbol.kind {
+        VerylSymbolKind::Module(_) => SymbolKind::MODULE,
+        VerylSymbolKind::Interface(_) => SymbolKind::INTERFACE,
+        VerylSymbolKind::Package(_) => SymbolKind::PACKAGE,
+        VerylSymbolKind::Function(_) => SymbolKind::FUNCTION,
+        _ => return None,
+    };
+    let range = to_range(&symbol_range(symbol)?);
+    let location = to_location(&symbol.token);
+
+    Some(CallHierarchyItem {
+        name: symbol.token.text.to_string(),
+        kind,
+        tags: None,
+        detail: Some(symbol.kind.to_string()),
+        uri: location.uri,
+        range,
+        selection_range: location.range,
+        data: Some(Value::from(symbol.id.0)),
+    })
+}
+
+fn to_range(range: &TokenRange) -> Range {
+    Range::new(
+        Position::new(range.beg.line - 1, range.beg.column - 1),
+        Position::new(range.end.line - 1, range.end.column - 1 + range.end.length),
     )
 }
 
@@ -1130,13 +1744,71 @@ fn current_namespace(url: &Url, line: usize, column: usize) -> Option<Namespace>
     ret_func.or(ret)
 }
 
-fn drop_tables(path: PathId) {
-    symbol_table::drop(path);
-    namespace_table::drop(path);
-    text_table::drop(path);
-    attribute_table::drop(path);
-    unsafe_table::drop(path);
-    definition_table::drop(path);
+fn document_highlights(symbol: &Symbol, path: PathId) -> Vec<DocumentHighlight> {
+    let mut tokens: Vec<Token> = Vec::new();
+    for token in &symbol.references {
+        if token.source == path && !tokens.iter().any(|x| x.id == token.id) {
+            tokens.push(*token);
+        }
+    }
+
+    let is_variable = matches!(
+        symbol.kind,
+        VerylSymbolKind::Variable(_) | VerylSymbolKind::Port(_)
+    );
+    let default_kind = if is_variable {
+        DocumentHighlightKind::READ
+    } else {
+        DocumentHighlightKind::TEXT
+    };
+    let mut kinds = vec![default_kind; tokens.len()];
+
+    // Assignment targets including members of the variable like `a.x` are marked
+    // by identifier tokens recorded in assignments.
+    if is_variable {
+        for var_ref in symbol_table::get_var_ref_list().values().flatten() {
+            if !var_ref.is_assign() {
+                continue;
+            }
+            if let Some(target) = var_ref.path.token(symbol.id)
+                && let Some(i) = tokens.iter().position(|x| x.id == target.id)
+            {
+                kinds[i] = DocumentHighlightKind::WRITE;
+            }
+        }
+    }
+
+    let mut ret: Vec<_> = tokens
+        .iter()
+        .zip(kinds)
+        .map(|(token, kind)| DocumentHighlight {
+            range: to_location(token).range,
+            kind: Some(kind),
+        })
+        .collect();
+
+    if symbol.token.source == path {
+        ret.push(DocumentHighlight {
+            range: to_location(&symbol.token).range,
+            kind: Some(DocumentHighlightKind::TEXT),
+        });
+    }
+
+    ret
+}
+
+fn to_char_idx(rope: &Rope, position: &Position) -> usize {
+    let line = (position.line as usize).min(rope.len_lines().saturating_sub(1));
+    let beg = rope.line_to_char(line);
+    let end = if line + 1 < rope.len_lines() {
+        rope.line_to_char(line + 1)
+    } else {
+        rope.len_chars()
+    };
+    let beg_cu = rope.char_to_utf16_cu(beg);
+    let end_cu = rope.char_to_utf16_cu(end);
+    let cu = (beg_cu + position.character as usize).min(end_cu);
+    rope.utf16_cu_to_char(cu)
 }
 
 fn completion_keyword(line: usize, column: usize) -> Vec<CompletionItem> {
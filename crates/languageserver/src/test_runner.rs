@@ -0,0 +1,243 @@
+use dashmap::DashMap;
+use futures::executor::block_on;
+use regex::Regex;
+use serde_json::{Value, json};
+use std::io::{BufRead, BufReader, Read};
+use std::path::{Path, PathBuf};
+use std::process::{Command, Stdio};
+use std::sync::Arc;
+use tower_lsp_server::lsp_types::Uri as Url;
+use tower_lsp_server::lsp_types::notification::Notification;
+use tower_lsp_server::lsp_types::*;
+use tower_lsp_server::{Client, UriExt};
+
+pub const RUN_TEST_COMMAND: &str = "veryl.runTest";
+
+/// Notification to stream the output of test runners
+pub enum TestOutput {}
+
+impl Notification for TestOutput {
+    type Params = Value;
+    const METHOD: &'static str = "veryl/testOutput";
+}
+
+pub type DiagnosticMap = Arc<DashMap<Url, Vec<Diagnostic>>>;
+
+pub struct TestRun {
+    pub client: Client,
+    pub project_path: PathBuf,
+    pub test: String,
+    pub wave: bool,
+    pub diagnostics: DiagnosticMap,
+    pub test_diagnostics: DiagnosticMap,
+}
+
+impl TestRun {
+    /// Runs the test by `veryl test` and publishes failures as diagnostics
+    pub fn run(self) {
+        self.clear_diagnostics();
+
+        // Messages of runners are taken from the JSON report with their levels
+        let report = tempfile_path(&self.test);
+        let mut command = Command::new(veryl_binary());
+        command
+            .current_dir(&self.project_path)
+            .arg("test")
+            .arg(&self.test)
+            .arg("--json")
+            .arg(&report)
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped());
+        if self.wave {
+            command.arg("--wave");
+        }
+
+        let mut child = match command.spawn() {
+            Ok(x) => x,
+            Err(x) => {
+                self.show_message(MessageType::ERROR, format!("failed to run veryl: {x}"));
+                return;
+            }
+        };
+
+        // Logs of veryl are written to stderr, and messages of runners are written to stdout
+        let stderr = child.stderr.take().map(|stderr| {
+            let client = self.client.clone();
+            let test = self.test.clone();
+            std::thread::spawn(move || stream(&client, &test, stderr))
+        });
+        if let Some(stdout) = child.stdout.take() {
+            stream(&self.client, &self.test, stdout);
+        }
+        if let Some(x) = stderr {
+            let _ = x.join();
+        }
+
+        let success = child.wait().is_ok_and(|x| x.success());
+
+        let mut parser = OutputParser::default();
+        if let Ok(text) = std::fs::read_to_string(&report) {
+            let _ = std::fs::remove_file(&report);
+            if let Ok(report) = serde_json::from_str::<Value>(&text) {
+                parser.push_report(&report);
+            }
+        }
+
+        for (url, diag) in parser.diagnostics {
+            self.test_diagnostics.entry(url).or_default().push(diag);
+        }
+        let urls: Vec<_> = self
+            .test_diagnostics
+            .iter()
+            .map(|x| x.key().clone())
+            .collect();
+        for url in urls {
+            publish(
+                &self.client,
+                &url,
+                &self.diagnostics,
+                &self.test_diagnostics,
+                None,
+            );
+        }
+
+        if success {
+            self.show_message(MessageType::INFO, format!("Test {} passed", self.test));
+        } else {
+            self.show_message(MessageType::ERROR, format!("Test {} failed", self.test));
+        }
+    }
+
+    fn clear_diagnostics(&self) {
+        let urls: Vec<_> = self
+            .test_diagnostics
+            .iter()
+            .map(|x| x.key().clone())
+            .collect();
+        self.test_diagnostics.clear();
+        for url in urls {
+            publish(
+                &self.client,
+                &url,
+                &self.diagnostics,
+                &self.test_diagnostics,
+                None,
+            );
+        }
+    }
+
+    fn show_message(&self, typ: MessageType, message: String) {
+        block_on(self.client.show_message(typ, message));
+    }
+}
+
+/// Publishes diagnostics of analyzer and test runner together
+pub fn publish(
+    client: &Client,
+    url: &Url,
+    diagnostics: &DiagnosticMap,
+    test_diagnostics: &DiagnosticMap,
+    version: Option<i32>,
+) {
+    let mut diag = diagnostics.get(url).map(|x| x.clone()).unwrap_or_default();
+    if let Some(x) = test_diagnostics.get(url) {
+        diag.extend(x.iter().cloned());
+    }
+    block_on(client.publish_diagnostics(url.clone(), diag, version));
+}
+
+#[derive(Default)]
+struct OutputParser {
+    diagnostics: Vec<(Url, Diagnostic)>,
+}
+
+impl OutputParser {
+    /// Collects messages of all tests in the JSON report of `veryl test`
+    fn push_report(&mut self, report: &Value) {
+        let tests = report["tests"].as_array().into_iter().flatten();
+        for message in tests.flat_map(|x| x["messages"].as_array().into_iter().flatten()) {
+            let severity = match message["level"].as_str() {
+                Some("error") => DiagnosticSeverity::ERROR,
+                Some("warning") => DiagnosticSeverity::WARNING,
+                _ => DiagnosticSeverity::INFORMATION,
+            };
+            if let Some(text) = message["text"].as_str() {
+                self.push(severity, text);
+            }
+        }
+    }
+
+    /// Collects locations remapped by sourcemap.
+    ///
+    /// Runners append `^ from: path:line:column` to messages including locations of generated code.
+    fn push(&mut self, severity: DiagnosticSeverity, text: &str) {
+        static FROM: std::sync::LazyLock<Regex> = std::sync::LazyLock::new(|| {
+            Regex::new(r"\^ from: (?<path>.+):(?<line>\d+):(?<column>\d+)").unwrap()
+        });
+
+        let mut message = Vec::new();
+        for line in text.lines() {
+            let line = strip_ansi(line);
+            let Some(caps) = FROM.captures(&line) else {
+                message.push(line);
+                continue;
+            };
+            let path = Path::new(&caps["path"]);
+            let Some(url) = Url::from_file_path(path) else {
+                continue;
+            };
+            let line: u32 = caps["line"].parse().unwrap_or(1);
+            let column: u32 = caps["column"].parse().unwrap_or(1);
+            let position = Position::new(line.saturating_sub(1), column.saturating_sub(1));
+            let diag = Diagnostic {
+                range: Range::new(position, position),
+                severity: Some(severity),
+                source: Some("veryl-test".to_string()),
+                message: message.join("\n").trim().to_string(),
+                ..Default::default()
+            };
+            self.diagnostics.push((url, diag));
+        }
+    }
+}
+
+/// Sends each line of the output to the client as soon as it is printed
+fn stream(client: &Client, test: &str, output: impl Read) {
+    for line in BufReader::new(output).lines().map_while(|x| x.ok()) {
+        notify(client, test, &strip_ansi(&line));
+    }
+}
+
+fn notify(client: &Client, test: &str, line: &str) {
+    let params = json!({ "test": test, "line": line });
+    block_on(client.send_notification::<TestOutput>(params));
+}
+
+fn strip_ansi(line: &str) -> String {
+    static ANSI: std::sync::LazyLock<Regex> =
+        std::sync::LazyLock::new(|| Regex::new(r"\x1b\[[0-9;]*m").unwrap());
+    ANSI.replace_all(line, "").to_string()
+}
+
+/// Prefers `veryl` placed with `veryl-ls`
+fn veryl_binary() -> PathBuf {
+    let name = format!("veryl{}", std::env::consts::EXE_SUFFIX);
+    if let Ok(exe) = std::env::current_exe()
+        && let Some(dir) = exe.parent()
+        && dir.join(&name).exists()
+    {
+        return dir.join(name);
+    }
+    PathBuf::from(name)
+}
+
+/// Path of the JSON report which is unique for each run
+fn tempfile_path(test: &str) -> PathBuf {
+    static COUNT: std::sync::atomic::AtomicUsize = std::sync::atomic::AtomicUsize::new(0);
+    let count = COUNT.fetch_add(1, std::sync::atomic::Ordering::Relaxed);
+    let name = test.replace(|x: char| !x.is_ascii_alphanumeric(), "_");
+    std::env::temp_dir().join(format!(
+        "veryl-ls-{}-{count}-{name}.json",
+        std::process::id()
+    ))
+}
@@ -3,7 +3,6 @@
 use crate::Backend;
 use mark_flaky_tests::flaky;
 use serde_json::{Value, json};
-use std::collections::VecDeque;
 use std::env;
 use std::path::PathBuf;
 use tokio::io::{AsyncReadExt, AsyncWriteExt, DuplexStream};
@@ -15,7 +14,7 @@ use tower_lsp_server::{Client, LanguageServer, LspService, Server, UriExt};
 struct TestServer {
     req_stream: DuplexStream,
     res_stream: DuplexStream,
-    responses: VecDeque<String>,
+    pending: Vec<u8>,
 }
 
 impl TestServer {
@@ -34,7 +33,7 @@ impl TestServer {
         Self {
             req_stream: req_client,
             res_stream: res_client,
-            responses: VecDeque::new(),
+            pending: Vec::new(),
         }
     }
 
@@ -42,27 +41,6 @@ impl TestServer {
         format!("Content-Length: {}\r\n\r\n{}", payload.len(), payload)
     }
 
-    fn decode(text: &str) -> Vec<String> {
-        let mut ret = Vec::new();
-        let mut temp = text;
-
-        while !temp.is_empty() {
-            let p = temp.find("\r\n\r\n").unwrap();
-            let (header, body) = temp.split_at(p + 4);
-            let len = header
-                .strip_prefix("Content-Length: ")
-                .unwrap()
-                .strip_suffix("\r\n\r\n")
-                .unwrap();
-            let len: usize = len.parse().unwrap();
-            let (body, rest) = body.split_at(len);
-            ret.push(body.to_string());
-            temp = rest;
-        }
-
-        ret
-    }
-
     async fn send_request(&mut self, req: Request) {
         let req = serde_json::to_string(&req).unwrap();
         let req = Self::encode(&req);
@@ -77,29 +55,47 @@ impl TestServer {
     }
 
     async fn recv_response(&mut self) -> Response {
-        if self.responses.is_empty() {
-            let mut buf = vec![0; 1024];
-            let n = self.res_stream.read(&mut buf).await.unwrap();
-            let ret = String::from_utf8(buf[..n].to_vec()).unwrap();
-            for x in Self::decode(&ret) {
-                self.responses.push_front(x);
-            }
-        }
-        let res = self.responses.pop_back().unwrap();
-        serde_json::from_str(&res).unwrap()
+        let res = self.recv_message().await;
+        serde_json::from_value(res).unwrap()
     }
 
     async fn recv_notification(&mut self) -> Request {
-        if self.responses.is_empty() {
+        let res = self.recv_message().await;
+        serde_json::from_value(res).unwrap()
+    }
+}
+
+impl TestServer {
+    async fn recv_message(&mut self) -> Value {
+        // Messages larger than the stream buffer are split across reads
+        loop {
+            let text = String::from_utf8_lossy(&self.pending).to_string();
+            if let Some(p) = text.find("\r\n\r\n") {
+                let len = text[..p].strip_prefix("Content-Length: ").unwrap();
+                let len: usize = len.parse().unwrap();
+                if self.pending.len() >= p + 4 + len {
+                    let body = self.pending[p + 4..p + 4 + len].to_vec();
+                    self.pending.drain(..p + 4 + len);
+                    return serde_json::from_slice(&body).unwrap();
+                }
+            }
+
             let mut buf = vec![0; 1024];
             let n = self.res_stream.read(&mut buf).await.unwrap();
-            let ret = String::from_utf8(buf[..n].to_vec()).unwrap();
-            for x in Self::decode(&ret) {
-                self.responses.push_front(x);
+            self.pending.extend_from_slice(&buf[..n]);
+        }
+    }
+
+    async fn recv_result(&mut self, id: i64) -> Value {
+        loop {
+            let res = self.recv_message().await;
+            if res["method"] == "window/workDoneProgress/create" {
+                let id: Id = serde_json::from_value(res["id"].clone()).unwrap();
+                self.send_ack(&id).await;
+            } else if res.get("method").is_none() && res["id"] == id {
+                return res["result"].clone();
             }
         }
-        let res = self.responses.pop_back().unwrap();
-        serde_json::from_str(&res).unwrap()
     }
 }
 
@@ -254,3 +250,423 @@ async fn progress() {
     }
     assert_eq!(percentage, 100);
 }
+
+#[tokio::test]
+#[ntest::timeout(60000)]
+async fn call_hierarchy() {
+    let mut server = TestServer::new(Backend::new);
+
+    let req = build_initialize(1);
+    server.send_request(req).await;
+    let res = server.recv_response().await;
+    assert!(res.is_ok());
+
+    let req = build_initialized();
+    server.send_request(req).await;
+    let res = server.recv_notification().await;
+    assert_eq!(res.method(), "window/logMessage");
+
+    let text = r#"module CallHierarchyTop {
+    inst u: CallHierarchyLeaf;
+}
+
+module CallHierarchyLeaf {}
+"#;
+    let req = build_did_open(text);
+    server.send_request(req).await;
+
+    let res = server.recv_notification().await;
+    assert_eq!(res.params().unwrap()["message"], "did_open");
+    let res = server.recv_notification().await;
+    assert_eq!(res.method(), "textDocument/publishDiagnostics");
+
+    let mut path = PathBuf::from(env::var("CARGO_MANIFEST_DIR").unwrap());
+    path.pop();
+    path.pop();
+    path.push("test.veryl");
+    let uri = Url::from_file_path(path).unwrap();
+
+    let params = CallHierarchyPrepareParams {
+        text_document_position_params: TextDocumentPositionParams {
+            text_document: TextDocumentIdentifier { uri },
+            position: Position::new(1, 12),
+        },
+        work_done_progress_params: WorkDoneProgressParams::default(),
+    };
+    let req = Request::build("textDocument/prepareCallHierarchy")
+        .params(json!(params))
+        .id(2)
+        .finish();
+    server.send_request(req).await;
+    let res = server.recv_result(2).await;
+    let items = res.as_array().unwrap();
+    assert_eq!(items.len(), 1);
+    assert_eq!(items[0]["name"], "CallHierarchyLeaf");
+    let leaf = items[0].clone();
+
+    let req = Request::build("callHierarchy/incomingCalls")
+        .params(json!({ "item": leaf }))
+        .id(3)
+        .finish();
+    server.send_request(req).await;
+    let res = server.recv_result(3).await;
+    let calls = res.as_array().unwrap();
+    assert_eq!(calls.len(), 1);
+    assert_eq!(calls[0]["from"]["name"], "CallHierarchyTop");
+    assert_eq!(calls[0]["fromRanges"][0]["start"]["line"], 1);
+
+    let top = calls[0]["from"].clone();
+    let req = Request::build("callHierarchy/outgoingCalls")
+        .params(json!({ "item": top }))
+        .id(4)
+        .finish();
+    server.send_request(req).await;
+    let res = server.recv_result(4).await;
+    let calls = res.as_array().unwrap();
+    assert_eq!(calls.len(), 1);
+    assert_eq!(calls[0]["to"]["name"], "CallHierarchyLeaf");
+}
+
+#[tokio::test]
+#[ntest::timeout(60000)]
+async fn incremental_change() {
+    let mut server = TestServer::new(Backend::new);
+
+    let req = build_initialize(1);
+    server.send_request(req).await;
+    let res = server.recv_response().await;
+    assert!(res.is_ok());
+
+    let req = build_initialized();
+    server.send_request(req).await;
+    let res = server.recv_notification().await;
+    assert_eq!(res.method(), "window/logMessage");
+
+    let req = build_did_open("module A {}");
+    server.send_request(req).await;
+
+    let res = server.recv_notification().await;
+    assert_eq!(res.params().unwrap()["message"], "did_open");
+    let res = server.recv_notification().await;
+    assert_eq!(res.method(), "textDocument/publishDiagnostics");
+
+    let mut path = PathBuf::from(env::var("CARGO_MANIFEST_DIR").unwrap());
+    path.pop();
+    path.pop();
+    path.push("test.veryl");
+    let uri = Url::from_file_path(path).unwrap();
+
+    // "module A {}" -> "module B {x}" by two incremental changes
+    let params = DidChangeTextDocumentParams {
+        text_document: VersionedTextDocumentIdentifier { uri, version: 1 },
+        content_changes: vec![
+            TextDocumentContentChangeEvent {
+                range: Some(Range::new(Position::new(0, 7), Position::new(0, 8))),
+                range_length: None,
+                text: "B".to_string(),
+            },
+            TextDocumentContentChangeEvent {
+                range: Some(Range::new(Position::new(0, 10), Position::new(0, 10))),
+                range_length: None,
+                text: "x".to_string(),
+            },
+        ],
+    };
+    let req = Request::build("textDocument/didChange")
+        .params(json!(params))
+        .finish();
+    server.send_request(req).await;
+
+    loop {
+        let res = server.recv_message().await;
+        if res["method"] == "window/workDoneProgress/create" {
+            let id: Id = serde_json::from_value(res["id"].clone()).unwrap();
+            server.send_ack(&id).await;
+        } else if res["method"] == "textDocument/publishDiagnostics"
+            && res["params"]["version"] == 1
+        {
+            let diags = res["params"]["diagnostics"].as_array().unwrap();
+            assert_eq!(diags[0]["code"], Value::from("ParserError::SyntaxError"));
+            assert_eq!(diags[0]["range"]["start"]["character"], Value::from(10));
+            break;
+        }
+    }
+}
+
+#[tokio::test]
+#[ntest::timeout(60000)]
+async fn code_lens() {
+    let mut server = TestServer::new(Backend::new);
+
+    let req = build_initialize(1);
+    server.send_request(req).await;
+    let res = server.recv_response().await;
+    assert!(res.is_ok());
+
+    let req = build_initialized();
+    server.send_request(req).await;
+    let res = server.recv_notification().await;
+    assert_eq!(res.method(), "window/logMessage");
+
+    let text = r#"#[test(code_lens_test)]
+embed (inline) sv{{{
+module code_lens_test;
+endmodule
+}}}
+"#;
+    let req = build_did_open(text);
+    server.send_request(req).await;
+
+    let res = server.recv_notification().await;
+    assert_eq!(res.params().unwrap()["message"], "did_open");
+    let res = server.recv_notification().await;
+    assert_eq!(res.method(), "textDocument/publishDiagnostics");
+
+    let mut path = PathBuf::from(env::var("CARGO_MANIFEST_DIR").unwrap());
+    path.pop();
+    path.pop();
+    path.push("test.veryl");
+    let uri = Url::from_file_path(path).unwrap();
+
+    let params = CodeLensParams {
+        text_document: TextDocumentIdentifier { uri },
+        work_done_progress_params: WorkDoneProgressParams::default(),
+        partial_result_params: PartialResultParams::default(),
+    };
+    let req = Request::build("textDocument/codeLens")
+        .params(json!(params))
+        .id(2)
+        .finish();
+    server.send_request(req).await;
+    let res = server.recv_result(2).await;
+    let lenses = res.as_array().unwrap();
+    assert_eq!(lenses.len(), 2);
+    assert_eq!(lenses[0]["range"]["start"]["line"], 0);
+    assert_eq!(lenses[0]["command"]["title"], "Run test");
+    assert_eq!(lenses[0]["command"]["command"], "veryl.runTest");
+    assert_eq!(lenses[0]["command"]["arguments"][1], "code_lens_test");
+    assert_eq!(lenses[0]["command"]["arguments"][2], false);
+    assert_eq!(lenses[1]["command"]["title"], "Run test with waves");
+    assert_eq!(lenses[1]["command"]["arguments"][2], true);
+}
+
+#[tokio::test]
+#[ntest::timeout(60000)]
+async fn navigation() {
+    let mut server = TestServer::new(Backend::new);
+
+    let req = build_initialize(1);
+    server.send_request(req).await;
+    let res = server.recv_response().await;
+    assert!(res.is_ok());
+
+    let req = build_initialized();
+    server.send_request(req).await;
+    let res = server.recv_notification().await;
+    assert_eq!(res.method(), "window/logMessage");
+
+    let text = r#"// comment a
+// comment b
+module NavigationTest (
+    o: output logic,
+) {
+    var a: logic;
+    always_comb {
+        a = 1;
+    }
+    assign o = a;
+}
+"#;
+    let req = build_did_open(text);
+    server.send_request(req).await;
+
+    let res = server.recv_notification().await;
+    assert_eq!(res.params().unwrap()["message"], "did_open");
+    let res = server.recv_notification().await;
+    assert_eq!(res.method(), "textDocument/publishDiagnostics");
+
+    let mut path = PathBuf::from(env::var("CARGO_MANIFEST_DIR").unwrap());
+    path.pop();
+    path.pop();
+    path.push("test.veryl");
+    let uri = Url::from_file_path(path).unwrap();
+
+    let params = FoldingRangeParams {
+        text_document: TextDocumentIdentifier { uri: uri.clone() },
+        work_done_progress_params: WorkDoneProgressParams::default(),
+        partial_result_params: PartialResultParams::default(),
+    };
+    let req = Request::build("textDocument/foldingRange")
+        .params(json!(params))
+        .id(2)
+        .finish();
+    server.send_request(req).await;
+    let res = server.recv_result(2).await;
+    let mut ranges: Vec<_> = res
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|x| {
+            (
+                x["startLine"].as_u64().unwrap(),
+                x["endLine"].as_u64().unwrap(),
+            )
+        })
+        .collect();
+    ranges.sort();
+    assert_eq!(ranges, vec![(0, 1), (4, 9), (6, 7)]);
+
+    let params = SelectionRangeParams {
+        text_document: TextDocumentIdentifier { uri: uri.clone() },
+        positions: vec![Position::new(7, 8)],
+        work_done_progress_params: WorkDoneProgressParams::default(),
+        partial_result_params: PartialResultParams::default(),
+    };
+    let req = Request::build("textDocument/selectionRange")
+        .params(json!(params))
+        .id(3)
+        .finish();
+    server.send_request(req).await;
+    let res = server.recv_result(3).await;
+    let range = &res[0];
+    assert_eq!(range["range"]["start"], json!({"line": 7, "character": 8}));
+    assert_eq!(range["range"]["end"], json!({"line": 7, "character": 9}));
+    let parent = &range["parent"];
+    assert_eq!(parent["range"]["end"], json!({"line": 7, "character": 14}));
+
+    let params = DocumentHighlightParams {
+        text_document_position_params: TextDocumentPositionParams {
+            text_document: TextDocumentIdentifier { uri },
+            position: Position::new(7, 8),
+        },
+        work_done_progress_params: WorkDoneProgressParams::default(),
+        partial_result_params: PartialResultParams::default(),
+    };
+    let req = Request::build("textDocument/documentHighlight")
+        .params(json!(params))
+        .id(4)
+        .finish();
+    server.send_request(req).await;
+    let res = server.recv_result(4).await;
+    let mut highlights: Vec<_> = res
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|x| {
+            (
+                x["range"]["start"]["line"].as_u64().unwrap(),
+                x["kind"].as_u64().unwrap(),
+            )
+        })
+        .collect();
+    highlights.sort();
+    // 1: Text, 2: Read, 3: Write
+    assert_eq!(highlights, vec![(5, 1), (7, 3), (9, 2)]);
+}
+
+async fn document_highlights(
+    server: &mut TestServer,
+    uri: &Url,
+    position: Position,
+    id: i64,
+) -> Vec<(u64, u64)> {
+    let params = DocumentHighlightParams {
+        text_document_position_params: TextDocumentPositionParams {
+            text_document: TextDocumentIdentifier { uri: uri.clone() },
+            position,
+        },
+        work_done_progress_params: WorkDoneProgressParams::default(),
+        partial_result_params: PartialResultParams::default(),
+    };
+    let req = Request::build("textDocument/documentHighlight")
+        .params(json!(params))
+        .id(id)
+        .finish();
+    server.send_request(req).await;
+    let res = server.recv_result(id).await;
+    let mut ret: Vec<_> = res
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|x| {
+            (
+                x["range"]["start"]["line"].as_u64().unwrap(),
+                x["kind"].as_u64().unwrap(),
+            )
+        })
+        .collect();
+    ret.sort();
+    ret
+}
+
+#[tokio::test]
+#[ntest::timeout(60000)]
+async fn document_highlight() {
+    let mut server = TestServer::new(Backend::new);
+
+    let req = build_initialize(1);
+    server.send_request(req).await;
+    let res = server.recv_response().await;
+    assert!(res.is_ok());
+
+    let req = build_initialized();
+    server.send_request(req).await;
+    let res = server.recv_notification().await;
+    assert_eq!(res.method(), "window/logMessage");
+
+    let text = r#"package HighlightPkg {
+    struct S {
+        x: logic,
+    }
+}
+module HighlightSub (
+    o: output logic,
+) {
+    assign o = 1;
+}
+module HighlightTest (
+    o: output logic,
+) {
+    var s: HighlightPkg::S;
+    var b: logic;
+    var c: logic;
+    function f (
+        y: output logic,
+    ) {
+        y = 1;
+    }
+    always_comb {
+        s.x = 1;
+        f(b);
+    }
+    inst u: HighlightSub (
+        o: c,
+    );
+    assign o = s.x & b & c;
+}
+"#;
+    let req = build_did_open(text);
+    server.send_request(req).await;
+
+    let res = server.recv_notification().await;
+    assert_eq!(res.params().unwrap()["message"], "did_open");
+    let res = server.recv_notification().await;
+    assert_eq!(res.method(), "textDocument/publishDiagnostics");
+
+    let mut path = PathBuf::from(env::var("CARGO_MANIFEST_DIR").unwrap());
+    path.pop();
+    path.pop();
+    path.push("test.veryl");
+    let uri = Url::from_file_path(path).unwrap();
+
+    // 1: Text, 2: Read, 3: Write
+    // member assignment
+    let res = document_highlights(&mut server, &uri, Position::new(13, 8), 2).await;
+    assert_eq!(res, vec![(13, 1), (22, 3), (28, 2)]);
+    // output argument of function
+    let res = document_highlights(&mut server, &uri, Position::new(23, 10), 3).await;
+    assert_eq!(res, vec![(14, 1), (23, 3), (28, 2)]);
+    // output port connection
+    let res = document_highlights(&mut server, &uri, Position::new(26, 11), 4).await;
+    assert_eq!(res, vec![(15, 1), (26, 3), (28, 2)]);
+}
@@ -44,6 +44,10 @@ pub struct Build {
     pub flatten_array_interface: bool,
     #[serde(default)]
     pub hashed_mangled_name: bool,
+    #[serde(default)]
+    pub language: Language,
+    #[serde(default)]
+    pub assertion_guard: AssertionGuard,
 }
 
 fn default_source() -> PathBuf {
@@ -97,6 +101,40 @@ pub enum FilelistType {
     Flgen,
 }
 
+#[derive(Clone, Copy, Debug, Default, Serialize, Deserialize, PartialEq, Eq)]
+pub enum Language {
+    #[default]
+    #[serde(rename = "systemverilog")]
+    SystemVerilog,
+    #[serde(rename = "verilog2005")]
+    Verilog2005,
+    #[serde(rename = "vhdl2008")]
+    Vhdl2008,
+    #[serde(rename = "circt")]
+    Circt,
+}
+
+impl Language {
+    pub fn extension(&self) -> &'static str {
+        match self {
+            Language::SystemVerilog => "sv",
+            Language::Verilog2005 => "v",
+            Language::Vhdl2008 => "vhd",
+            Language::Circt => "mlir",
+        }
+    }
+
+    /// Whether the standard library can be built for the language
+    ///
+    /// The standard library relies on generics and type parameters which can't be expressed in Verilog-2005, VHDL-2008 or CIRCT IR.
+    pub fn supports_std(&self) -> bool {
+        !matches!(
+            self,
+            Language::Verilog2005 | Language::Vhdl2008 | Language::Circt
+        )
+    }
+}
+
 #[derive(Clone, Debug, Default, Serialize, Deserialize, PartialEq, Eq)]
 #[serde(tag = "type")]
 pub enum Target {
@@ -107,6 +145,9 @@ pub enum Target {
     Directory { path: PathBuf },
     #[serde(rename = "bundle")]
     Bundle { path: PathBuf },
+    /// One file per module, interface and package with a manifest of them
+    #[serde(rename = "unit")]
+    Unit { path: PathBuf },
 }
 
 #[derive(Clone, Debug, Default, Serialize, Deserialize, PartialEq, Eq)]
@@ -121,6 +162,26 @@ pub enum SourceMapTarget {
     None,
 }
 
+/// Preprocessor guard surrounding emitted assertions
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(tag = "type")]
+pub enum AssertionGuard {
+    #[serde(rename = "ifndef")]
+    Ifndef { name: String },
+    #[serde(rename = "ifdef")]
+    Ifdef { name: String },
+    #[serde(rename = "none")]
+    None,
+}
+
+impl Default for AssertionGuard {
+    fn default() -> Self {
+        AssertionGuard::Ifndef {
+            name: "SYNTHESIS".to_string(),
+        }
+    }
+}
+
 #[derive(Clone, Copy, Debug, Serialize, Deserialize, PartialEq, Eq)]
 pub enum BuiltinType {
     #[serde(rename = "u32")]
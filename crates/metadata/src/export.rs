@@ -0,0 +1,43 @@
+use serde::{Deserialize, Serialize};
+use std::path::PathBuf;
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Export {
+    #[serde(default = "default_path")]
+    pub path: PathBuf,
+    #[serde(default)]
+    pub languages: Vec<ExportLanguage>,
+    #[serde(default)]
+    pub packages: Vec<String>,
+}
+
+impl Default for Export {
+    fn default() -> Self {
+        toml::from_str("").unwrap()
+    }
+}
+
+fn default_path() -> PathBuf {
+    "export".into()
+}
+
+#[derive(Clone, Copy, Debug, Serialize, Deserialize, PartialEq, Eq)]
+pub enum ExportLanguage {
+    #[serde(rename = "c")]
+    C,
+    #[serde(rename = "python")]
+    Python,
+    #[serde(rename = "rust")]
+    Rust,
+}
+
+impl ExportLanguage {
+    pub fn extension(&self) -> &'static str {
+        match self {
+            ExportLanguage::C => "h",
+            ExportLanguage::Python => "py",
+            ExportLanguage::Rust => "rs",
+        }
+    }
+}
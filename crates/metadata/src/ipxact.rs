@@ -0,0 +1,31 @@
+use serde::{Deserialize, Serialize};
+use std::path::PathBuf;
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct IpXact {
+    #[serde(default)]
+    pub enable: bool,
+    #[serde(default = "default_path")]
+    pub path: PathBuf,
+    #[serde(default)]
+    pub vendor: String,
+    #[serde(default)]
+    pub library: String,
+    #[serde(default = "default_version")]
+    pub version: String,
+}
+
+impl Default for IpXact {
+    fn default() -> Self {
+        toml::from_str("").unwrap()
+    }
+}
+
+fn default_path() -> PathBuf {
+    "ipxact".into()
+}
+
+fn default_version() -> String {
+    "1.0".to_string()
+}
@@ -2,8 +2,10 @@ mod build;
 mod build_info;
 mod doc;
 mod env_var;
+mod export;
 mod format;
 mod git;
+mod ipxact;
 mod lint;
 mod lockfile;
 mod lockfile_compat;
@@ -15,12 +17,17 @@ mod publish;
 mod test;
 #[cfg(test)]
 mod tests;
-pub use build::{Build, BuiltinType, ClockType, FilelistType, ResetType, SourceMapTarget, Target};
+pub use build::{
+    AssertionGuard, Build, BuiltinType, ClockType, FilelistType, Language, ResetType,
+    SourceMapTarget, Target,
+};
 pub use build_info::BuildInfo;
 pub use doc::Doc;
 pub use env_var::EnvVar;
+pub use export::{Export, ExportLanguage};
 pub use format::Format;
 pub use git::Git;
+pub use ipxact::IpXact;
 pub use lint::{Case, Lint};
 pub use lockfile::{LockSource, Lockfile};
 pub use metadata::{BumpKind, Metadata, UrlPath};
@@ -29,4 +36,4 @@ pub use project::Project;
 pub use pubfile::{Pubfile, Release};
 pub use publish::Publish;
 pub use semver;
-pub use test::{SimType, Test, WaveFormFormat, WaveFormTarget};
+pub use test::{CustomProperty, SimType, Test, WaveFormFormat, WaveFormTarget};
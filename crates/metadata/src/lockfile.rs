@@ -1,3 +1,4 @@
+use crate::build::Language;
 use crate::git::Git;
 use crate::lockfile_compat;
 use crate::metadata::{Dependency, Metadata, UrlPath};
@@ -237,7 +238,11 @@ impl Lockfile {
         Ok(modified)
     }
 
-    pub fn paths(&self, base_dst: &Path) -> Result<Vec<PathSet>, MetadataError> {
+    pub fn paths(
+        &self,
+        base_dst: &Path,
+        language: Language,
+    ) -> Result<Vec<PathSet>, MetadataError> {
         let mut ret = Vec::new();
 
         for locks in self.lock_table.values() {
@@ -251,9 +256,9 @@ impl Lockfile {
                     };
                     let mut dst = base_dst.join(&lock.name);
                     dst.push(rel);
-                    dst.set_extension("sv");
+                    dst.set_extension(language.extension());
                     let mut map = dst.clone();
-                    map.set_extension("sv.map");
+                    map.set_extension(format!("{}.map", language.extension()));
                     ret.push(PathSet {
                         prj: lock.name.clone(),
                         src: src.to_path_buf(),
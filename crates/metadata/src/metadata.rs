@@ -2,8 +2,10 @@ use crate::build::{Build, Target};
 use crate::build_info::BuildInfo;
 use crate::doc::Doc;
 use crate::env_var::EnvVar;
+use crate::export::Export;
 use crate::format::Format;
 use crate::git::Git;
+use crate::ipxact::IpXact;
 use crate::lint::Lint;
 use crate::lockfile::Lockfile;
 use crate::project::Project;
@@ -50,6 +52,10 @@ pub struct Metadata {
     #[serde(default)]
     pub test: Test,
     #[serde(default)]
+    pub export: Export,
+    #[serde(default)]
+    pub ipxact: IpXact,
+    #[serde(default)]
     pub dependencies: HashMap<String, Dependency>,
     #[serde(skip)]
     pub metadata_path: PathBuf,
@@ -282,32 +288,35 @@ impl Metadata {
             ret
         };
 
+        let ext = self.build.language.extension();
+        let map_ext = format!("{ext}.map");
+
         let mut ret = Vec::new();
         for src in src_files {
             let Ok(src_relative) = src.strip_prefix(&src_base) else {
                 return Err(MetadataError::InvalidSourceLocation(src));
             };
             let dst = match self.build.target {
-                Target::Source => src.with_extension("sv"),
+                Target::Source => src.with_extension(ext),
                 Target::Directory { ref path } => {
-                    base.join(path.join(src_relative.with_extension("sv")))
+                    base.join(path.join(src_relative.with_extension(ext)))
                 }
-                Target::Bundle { .. } => base.join(
-                    PathBuf::from("target").join(src.with_extension("sv").file_name().unwrap()),
+                Target::Bundle { .. } | Target::Unit { .. } => base.join(
+                    PathBuf::from("target").join(src.with_extension(ext).file_name().unwrap()),
                 ),
             };
             let map = match &self.build.sourcemap_target {
                 SourceMapTarget::Directory { path } => {
                     if let Target::Directory { .. } = self.build.target {
-                        base.join(path.join(src_relative.with_extension("sv.map")))
+                        base.join(path.join(src_relative.with_extension(&map_ext)))
                     } else {
                         let dst = dst.strip_prefix(&base).unwrap();
-                        base.join(path.join(dst.with_extension("sv.map")))
+                        base.join(path.join(dst.with_extension(&map_ext)))
                     }
                 }
                 _ => {
                     let mut map = dst.clone();
-                    map.set_extension("sv.map");
+                    map.set_extension(&map_ext);
                     map
                 }
             };
@@ -324,14 +333,14 @@ impl Metadata {
             ignore_already_exists(fs::create_dir(&base_dst))?;
         }
 
-        if !self.build.exclude_std {
+        if !self.build.exclude_std && self.build.language.supports_std() {
             veryl_std::expand()?;
             ret.append(&mut veryl_std::paths(&base_dst)?);
         }
 
         self.update_lockfile()?;
 
-        let mut deps = self.lockfile.paths(&base_dst)?;
+        let mut deps = self.lockfile.paths(&base_dst, self.build.language)?;
         ret.append(&mut deps);
 
         Ok(ret)
@@ -381,9 +390,22 @@ target = {{type = "directory", path = "target"}}"###
         self.metadata_path.with_file_name(filelist_name)
     }
 
+    pub fn mangled_name_map_path(&self) -> PathBuf {
+        self.metadata_path
+            .with_file_name(format!("{}.mangled.json", self.project.name))
+    }
+
     pub fn doc_path(&self) -> PathBuf {
         self.metadata_path.parent().unwrap().join(&self.doc.path)
     }
+
+    pub fn export_path(&self) -> PathBuf {
+        self.metadata_path.parent().unwrap().join(&self.export.path)
+    }
+
+    pub fn ipxact_path(&self) -> PathBuf {
+        self.metadata_path.parent().unwrap().join(&self.ipxact.path)
+    }
 }
 
 impl FromStr for Metadata {
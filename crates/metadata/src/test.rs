@@ -1,3 +1,4 @@
+use regex::Regex;
 use serde::{Deserialize, Serialize};
 use std::path::PathBuf;
 
@@ -13,11 +14,18 @@ pub struct Test {
     #[serde(default)]
     pub vivado: VivadoProperty,
     #[serde(default)]
+    pub iverilog: IverilogProperty,
+    #[serde(default)]
+    pub custom: CustomProperty,
+    #[serde(default)]
     pub waveform_target: WaveFormTarget,
     #[serde(default)]
     pub waveform_format: WaveFormFormat,
     #[serde(default)]
     pub include_files: Vec<PathBuf>,
+    /// Timeout of each test in seconds
+    #[serde(default)]
+    pub timeout: Option<u64>,
 }
 
 #[derive(Clone, Copy, Debug, Default, Serialize, Deserialize, PartialEq, Eq)]
@@ -29,6 +37,10 @@ pub enum SimType {
     Vcs,
     #[serde(rename = "vivado")]
     Vivado,
+    #[serde(rename = "iverilog")]
+    Icarus,
+    #[serde(rename = "custom")]
+    Custom,
 }
 
 #[derive(Clone, Debug, Default, Serialize, Deserialize)]
@@ -60,6 +72,55 @@ pub struct VivadoProperty {
     pub simulate_args: Vec<String>,
 }
 
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct IverilogProperty {
+    #[serde(default)]
+    pub compile_args: Vec<String>,
+    #[serde(default)]
+    pub simulate_args: Vec<String>,
+}
+
+/// Simulator executed by command templates
+///
+/// The following placeholders in the templates are replaced:
+/// `{filelist}`, `{top}`, `{test}`, `{project}`, `{defines}`, `{params}`, `{plusargs}`,
+/// `{seed}`, `{wave}` and `{work}`.
+/// An argument which is just `{defines}`, `{params}` or `{plusargs}` is expanded to multiple arguments.
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct CustomProperty {
+    #[serde(default)]
+    pub compile: Option<String>,
+    #[serde(default)]
+    pub elaborate: Option<String>,
+    #[serde(default)]
+    pub simulate: Option<String>,
+    /// Format of each define (`+define+{define}` by default)
+    #[serde(default)]
+    pub define_format: Option<String>,
+    /// Format of each parameter override (`-G{name}={value}` by default)
+    #[serde(default)]
+    pub param_format: Option<String>,
+    /// The test fails unless any line of simulation matches it
+    #[serde(default, with = "serde_regex")]
+    pub re_pass: Option<Regex>,
+    /// The test fails if any line of simulation matches it
+    #[serde(default, with = "serde_regex")]
+    pub re_fail: Option<Regex>,
+    #[serde(default, with = "serde_regex")]
+    pub re_info: Option<Regex>,
+    #[serde(default, with = "serde_regex")]
+    pub re_warning: Option<Regex>,
+    #[serde(default, with = "serde_regex")]
+    pub re_error: Option<Regex>,
+    #[serde(default, with = "serde_regex")]
+    pub re_fatal: Option<Regex>,
+    /// Source location in messages, which has `path`, `line` and optional `column` groups
+    #[serde(default, with = "serde_regex")]
+    pub re_location: Option<Regex>,
+}
+
 #[derive(Clone, Debug, Default, Serialize, Deserialize, PartialEq, Eq)]
 #[serde(tag = "type")]
 pub enum WaveFormTarget {
@@ -2,7 +2,7 @@ use crate::git::Git;
 use crate::*;
 use semver::Version;
 use std::fs;
-use std::path::Path;
+use std::path::{Path, PathBuf};
 use tempfile::TempDir;
 
 const GIT_IGNORE: &'static str = r#"
@@ -20,9 +20,28 @@ reset_type = "async_low"
 reset_low_suffix = "_n"
 target = {type = "source"}
 #target = {type = "directory", path = "aaa"}
+assertion_guard = {type = "ifdef", name = "ASSERT_ON"}
 
 [format]
 indent_width = 4
+
+[export]
+languages = ["c", "rust"]
+packages = ["PkgA"]
+
+[ipxact]
+enable = true
+vendor = "example.com"
+
+[test]
+simulator = "custom"
+
+[test.custom]
+compile = "vlog -sv -f {filelist} {defines}"
+simulate = "vsim -c {top} -do 'run -all; quit'"
+define_format = "+define+{define}"
+re_pass = "TEST PASSED"
+re_error = "^\\*\\* Error"
 "#;
 
 const MAIN_TOML: &'static str = r#"
@@ -166,7 +185,40 @@ fn check_toml() {
     assert!(metadata.build.reset_high_suffix.is_none());
     assert!(metadata.build.reset_low_prefix.is_none());
     assert_eq!(metadata.build.reset_low_suffix.unwrap(), "_n");
+    assert_eq!(
+        metadata.build.assertion_guard,
+        AssertionGuard::Ifdef {
+            name: "ASSERT_ON".to_string()
+        }
+    );
     assert_eq!(metadata.format.indent_width, 4);
+    assert_eq!(metadata.export.path, PathBuf::from("export"));
+    assert_eq!(
+        metadata.export.languages,
+        vec![ExportLanguage::C, ExportLanguage::Rust]
+    );
+    assert_eq!(metadata.export.packages, vec!["PkgA".to_string()]);
+    assert!(metadata.ipxact.enable);
+    assert_eq!(metadata.ipxact.path, PathBuf::from("ipxact"));
+    assert_eq!(metadata.ipxact.vendor, "example.com");
+    assert_eq!(metadata.ipxact.library, "");
+    assert_eq!(metadata.ipxact.version, "1.0");
+    assert_eq!(metadata.test.simulator, SimType::Custom);
+    assert_eq!(
+        metadata.test.custom.compile.as_deref(),
+        Some("vlog -sv -f {filelist} {defines}")
+    );
+    assert!(metadata.test.custom.elaborate.is_none());
+    assert!(
+        metadata
+            .test
+            .custom
+            .re_error
+            .as_ref()
+            .unwrap()
+            .is_match("** Error: foo")
+    );
+    assert!(metadata.test.custom.re_fail.is_none());
 }
 
 #[test]
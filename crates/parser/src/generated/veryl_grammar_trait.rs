@@ -70,6 +70,11 @@ pub trait VerylGrammarTrait {
         Ok(())
     }
 
+    /// Semantic action for non-terminal 'ImplicationTerm'
+    fn implication_term(&mut self, _arg: &ImplicationTerm) -> Result<()> {
+        Ok(())
+    }
+
     /// Semantic action for non-terminal 'AssignmentOperatorTerm'
     fn assignment_operator_term(&mut self, _arg: &AssignmentOperatorTerm) -> Result<()> {
         Ok(())
@@ -265,11 +270,21 @@ pub trait VerylGrammarTrait {
         Ok(())
     }
 
+    /// Semantic action for non-terminal 'AssertTerm'
+    fn assert_term(&mut self, _arg: &AssertTerm) -> Result<()> {
+        Ok(())
+    }
+
     /// Semantic action for non-terminal 'AssignTerm'
     fn assign_term(&mut self, _arg: &AssignTerm) -> Result<()> {
         Ok(())
     }
 
+    /// Semantic action for non-terminal 'AssumeTerm'
+    fn assume_term(&mut self, _arg: &AssumeTerm) -> Result<()> {
+        Ok(())
+    }
+
     /// Semantic action for non-terminal 'AsTerm'
     fn as_term(&mut self, _arg: &AsTerm) -> Result<()> {
         Ok(())
@@ -310,6 +325,11 @@ pub trait VerylGrammarTrait {
         Ok(())
     }
 
+    /// Semantic action for non-terminal 'CoverTerm'
+    fn cover_term(&mut self, _arg: &CoverTerm) -> Result<()> {
+        Ok(())
+    }
+
     /// Semantic action for non-terminal 'ConstTerm'
     fn const_term(&mut self, _arg: &ConstTerm) -> Result<()> {
         Ok(())
@@ -685,6 +705,11 @@ pub trait VerylGrammarTrait {
         Ok(())
     }
 
+    /// Semantic action for non-terminal 'ImplicationToken'
+    fn implication_token(&mut self, _arg: &ImplicationToken) -> Result<()> {
+        Ok(())
+    }
+
     /// Semantic action for non-terminal 'DiamondOperatorToken'
     fn diamond_operator_token(&mut self, _arg: &DiamondOperatorToken) -> Result<()> {
         Ok(())
@@ -895,11 +920,21 @@ pub trait VerylGrammarTrait {
         Ok(())
     }
 
+    /// Semantic action for non-terminal 'AssertToken'
+    fn assert_token(&mut self, _arg: &AssertToken) -> Result<()> {
+        Ok(())
+    }
+
     /// Semantic action for non-terminal 'AssignToken'
     fn assign_token(&mut self, _arg: &AssignToken) -> Result<()> {
         Ok(())
     }
 
+    /// Semantic action for non-terminal 'AssumeToken'
+    fn assume_token(&mut self, _arg: &AssumeToken) -> Result<()> {
+        Ok(())
+    }
+
     /// Semantic action for non-terminal 'BitToken'
     fn bit_token(&mut self, _arg: &BitToken) -> Result<()> {
         Ok(())
@@ -945,6 +980,11 @@ pub trait VerylGrammarTrait {
         Ok(())
     }
 
+    /// Semantic action for non-terminal 'CoverToken'
+    fn cover_token(&mut self, _arg: &CoverToken) -> Result<()> {
+        Ok(())
+    }
+
     /// Semantic action for non-terminal 'DefaultToken'
     fn default_token(&mut self, _arg: &DefaultToken) -> Result<()> {
         Ok(())
@@ -1300,6 +1340,11 @@ pub trait VerylGrammarTrait {
         Ok(())
     }
 
+    /// Semantic action for non-terminal 'Implication'
+    fn implication(&mut self, _arg: &Implication) -> Result<()> {
+        Ok(())
+    }
+
     /// Semantic action for non-terminal 'DiamondOperator'
     fn diamond_operator(&mut self, _arg: &DiamondOperator) -> Result<()> {
         Ok(())
@@ -1510,11 +1555,21 @@ pub trait VerylGrammarTrait {
         Ok(())
     }
 
+    /// Semantic action for non-terminal 'Assert'
+    fn assert(&mut self, _arg: &Assert) -> Result<()> {
+        Ok(())
+    }
+
     /// Semantic action for non-terminal 'Assign'
     fn assign(&mut self, _arg: &Assign) -> Result<()> {
         Ok(())
     }
 
+    /// Semantic action for non-terminal 'Assume'
+    fn assume(&mut self, _arg: &Assume) -> Result<()> {
+        Ok(())
+    }
+
     /// Semantic action for non-terminal 'Bit'
     fn bit(&mut self, _arg: &Bit) -> Result<()> {
         Ok(())
@@ -1565,6 +1620,11 @@ pub trait VerylGrammarTrait {
         Ok(())
     }
 
+    /// Semantic action for non-terminal 'Cover'
+    fn cover(&mut self, _arg: &Cover) -> Result<()> {
+        Ok(())
+    }
+
     /// Semantic action for non-terminal 'Defaul'
     fn defaul(&mut self, _arg: &Defaul) -> Result<()> {
         Ok(())
@@ -2255,6 +2315,16 @@ pub trait VerylGrammarTrait {
         Ok(())
     }
 
+    /// Semantic action for non-terminal 'AssertionStatement'
+    fn assertion_statement(&mut self, _arg: &AssertionStatement) -> Result<()> {
+        Ok(())
+    }
+
+    /// Semantic action for non-terminal 'AssertionKeyword'
+    fn assertion_keyword(&mut self, _arg: &AssertionKeyword) -> Result<()> {
+        Ok(())
+    }
+
     /// Semantic action for non-terminal 'Attribute'
     fn attribute(&mut self, _arg: &Attribute) -> Result<()> {
         Ok(())
@@ -2270,6 +2340,31 @@ pub trait VerylGrammarTrait {
         Ok(())
     }
 
+    /// Semantic action for non-terminal 'AttributeAssignment'
+    fn attribute_assignment(&mut self, _arg: &AttributeAssignment) -> Result<()> {
+        Ok(())
+    }
+
+    /// Semantic action for non-terminal 'AttributeCall'
+    fn attribute_call(&mut self, _arg: &AttributeCall) -> Result<()> {
+        Ok(())
+    }
+
+    /// Semantic action for non-terminal 'AttributeValue'
+    fn attribute_value(&mut self, _arg: &AttributeValue) -> Result<()> {
+        Ok(())
+    }
+
+    /// Semantic action for non-terminal 'AttributeArray'
+    fn attribute_array(&mut self, _arg: &AttributeArray) -> Result<()> {
+        Ok(())
+    }
+
+    /// Semantic action for non-terminal 'AttributeValueList'
+    fn attribute_value_list(&mut self, _arg: &AttributeValueList) -> Result<()> {
+        Ok(())
+    }
+
     /// Semantic action for non-terminal 'LetDeclaration'
     fn let_declaration(&mut self, _arg: &LetDeclaration) -> Result<()> {
         Ok(())
@@ -2315,6 +2410,11 @@ pub trait VerylGrammarTrait {
         Ok(())
     }
 
+    /// Semantic action for non-terminal 'AssertionDeclaration'
+    fn assertion_declaration(&mut self, _arg: &AssertionDeclaration) -> Result<()> {
+        Ok(())
+    }
+
     /// Semantic action for non-terminal 'AssignDeclaration'
     fn assign_declaration(&mut self, _arg: &AssignDeclaration) -> Result<()> {
         Ok(())
@@ -2766,7 +2866,7 @@ pub trait VerylGrammarTrait {
 //
 
 ///
-/// Type derived for production 373
+/// Type derived for production 385
 ///
 /// `Number: IntegralNumber;`
 ///
@@ -2778,7 +2878,7 @@ pub struct NumberIntegralNumber {
 }
 
 ///
-/// Type derived for production 374
+/// Type derived for production 386
 ///
 /// `Number: RealNumber;`
 ///
@@ -2790,7 +2890,7 @@ pub struct NumberRealNumber {
 }
 
 ///
-/// Type derived for production 375
+/// Type derived for production 387
 ///
 /// `IntegralNumber: Based;`
 ///
@@ -2802,7 +2902,7 @@ pub struct IntegralNumberBased {
 }
 
 ///
-/// Type derived for production 376
+/// Type derived for production 388
 ///
 /// `IntegralNumber: BaseLess;`
 ///
@@ -2814,7 +2914,7 @@ pub struct IntegralNumberBaseLess {
 }
 
 ///
-/// Type derived for production 377
+/// Type derived for production 389
 ///
 /// `IntegralNumber: AllBit;`
 ///
@@ -2826,7 +2926,7 @@ pub struct IntegralNumberAllBit {
 }
 
 ///
-/// Type derived for production 378
+/// Type derived for production 390
 ///
 /// `RealNumber: FixedPoint;`
 ///
@@ -2838,7 +2938,7 @@ pub struct RealNumberFixedPoint {
 }
 
 ///
-/// Type derived for production 379
+/// Type derived for production 391
 ///
 /// `RealNumber: Exponent;`
 ///
@@ -2850,7 +2950,7 @@ pub struct RealNumberExponent {
 }
 
 ///
-/// Type derived for production 388
+/// Type derived for production 400
 ///
 /// `ScopedIdentifierGroup: DollarIdentifier;`
 ///
@@ -2862,7 +2962,7 @@ pub struct ScopedIdentifierGroupDollarIdentifier {
 }
 
 ///
-/// Type derived for production 389
+/// Type derived for production 401
 ///
 /// `ScopedIdentifierGroup: Identifier ScopedIdentifierOpt /* Option */;`
 ///
@@ -2875,7 +2975,7 @@ pub struct ScopedIdentifierGroupIdentifierScopedIdentifierOpt {
 }
 
 ///
-/// Type derived for production 438
+/// Type derived for production 450
 ///
 /// `Expression10ListGroup: Operator11;`
 ///
@@ -2887,7 +2987,7 @@ pub struct Expression10ListGroupOperator11 {
 }
 
 ///
-/// Type derived for production 439
+/// Type derived for production 451
 ///
 /// `Expression10ListGroup: Star;`
 ///
@@ -2899,7 +2999,7 @@ pub struct Expression10ListGroupStar {
 }
 
 ///
-/// Type derived for production 449
+/// Type derived for production 461
 ///
 /// `Expression13ListGroup: UnaryOperator;`
 ///
@@ -2911,7 +3011,7 @@ pub struct Expression13ListGroupUnaryOperator {
 }
 
 ///
-/// Type derived for production 450
+/// Type derived for production 462
 ///
 /// `Expression13ListGroup: Operator10;`
 ///
@@ -2923,7 +3023,7 @@ pub struct Expression13ListGroupOperator10 {
 }
 
 ///
-/// Type derived for production 451
+/// Type derived for production 463
 ///
 /// `Expression13ListGroup: Operator06;`
 ///
@@ -2935,7 +3035,7 @@ pub struct Expression13ListGroupOperator06 {
 }
 
 ///
-/// Type derived for production 452
+/// Type derived for production 464
 ///
 /// `Expression13ListGroup: Operator04;`
 ///
@@ -2947,7 +3047,7 @@ pub struct Expression13ListGroupOperator04 {
 }
 
 ///
-/// Type derived for production 453
+/// Type derived for production 465
 ///
 /// `Expression13ListGroup: Operator05;`
 ///
@@ -2959,7 +3059,7 @@ pub struct Expression13ListGroupOperator05 {
 }
 
 ///
-/// Type derived for production 455
+/// Type derived for production 467
 ///
 /// `Factor: Number;`
 ///
@@ -2971,7 +3071,7 @@ pub struct FactorNumber {
 }
 
 ///
-/// Type derived for production 456
+/// Type derived for production 468
 ///
 /// `Factor: BooleanLiteral;`
 ///
@@ -2983,7 +3083,7 @@ pub struct FactorBooleanLiteral {
 }
 
 ///
-/// Type derived for production 457
+/// Type derived for production 469
 ///
 /// `Factor: IdentifierFactor;`
 ///
@@ -2995,7 +3095,7 @@ pub struct FactorIdentifierFactor {
 }
 
 ///
-/// Type derived for production 458
+/// Type derived for production 470
 ///
 /// `Factor: LParen Expression RParen;`
 ///
@@ -3009,7 +3109,7 @@ pub struct FactorLParenExpressionRParen {
 }
 
 ///
-/// Type derived for production 459
+/// Type derived for production 471
 ///
 /// `Factor: LBrace ConcatenationList RBrace;`
 ///
@@ -3023,7 +3123,7 @@ pub struct FactorLBraceConcatenationListRBrace {
 }
 
 ///
-/// Type derived for production 460
+/// Type derived for production 472
 ///
 /// `Factor: QuoteLBrace ArrayLiteralList RBrace;`
 ///
@@ -3037,7 +3137,7 @@ pub struct FactorQuoteLBraceArrayLiteralListRBrace {
 }
 
 ///
-/// Type derived for production 461
+/// Type derived for production 473
 ///
 /// `Factor: CaseExpression;`
 ///
@@ -3049,7 +3149,7 @@ pub struct FactorCaseExpression {
 }
 
 ///
-/// Type derived for production 462
+/// Type derived for production 474
 ///
 /// `Factor: SwitchExpression;`
 ///
@@ -3061,7 +3161,7 @@ pub struct FactorSwitchExpression {
 }
 
 ///
-/// Type derived for production 463
+/// Type derived for production 475
 ///
 /// `Factor: StringLiteral;`
 ///
@@ -3073,7 +3173,7 @@ pub struct FactorStringLiteral {
 }
 
 ///
-/// Type derived for production 464
+/// Type derived for production 476
 ///
 /// `Factor: FactorGroup;`
 ///
@@ -3085,7 +3185,7 @@ pub struct FactorFactorGroup {
 }
 
 ///
-/// Type derived for production 465
+/// Type derived for production 477
 ///
 /// `FactorGroup: Msb;`
 ///
@@ -3097,7 +3197,7 @@ pub struct FactorGroupMsb {
 }
 
 ///
-/// Type derived for production 466
+/// Type derived for production 478
 ///
 /// `FactorGroup: Lsb;`
 ///
@@ -3109,7 +3209,7 @@ pub struct FactorGroupLsb {
 }
 
 ///
-/// Type derived for production 467
+/// Type derived for production 479
 ///
 /// `Factor: InsideExpression;`
 ///
@@ -3121,7 +3221,7 @@ pub struct FactorInsideExpression {
 }
 
 ///
-/// Type derived for production 468
+/// Type derived for production 480
 ///
 /// `Factor: OutsideExpression;`
 ///
@@ -3133,7 +3233,7 @@ pub struct FactorOutsideExpression {
 }
 
 ///
-/// Type derived for production 469
+/// Type derived for production 481
 ///
 /// `Factor: TypeExpression;`
 ///
@@ -3145,7 +3245,7 @@ pub struct FactorTypeExpression {
 }
 
 ///
-/// Type derived for production 470
+/// Type derived for production 482
 ///
 /// `Factor: FactorTypeFactor;`
 ///
@@ -3157,7 +3257,7 @@ pub struct FactorFactorTypeFactor {
 }
 
 ///
-/// Type derived for production 471
+/// Type derived for production 483
 ///
 /// `BooleanLiteral: True;`
 ///
@@ -3169,7 +3269,7 @@ pub struct BooleanLiteralTrue {
 }
 
 ///
-/// Type derived for production 472
+/// Type derived for production 484
 ///
 /// `BooleanLiteral: False;`
 ///
@@ -3181,7 +3281,7 @@ pub struct BooleanLiteralFalse {
 }
 
 ///
-/// Type derived for production 475
+/// Type derived for production 487
 ///
 /// `IdentifierFactorOptGroup: FunctionCall;`
 ///
@@ -3193,7 +3293,7 @@ pub struct IdentifierFactorOptGroupFunctionCall {
 }
 
 ///
-/// Type derived for production 476
+/// Type derived for production 488
 ///
 /// `IdentifierFactorOptGroup: StructConstructor;`
 ///
@@ -3205,7 +3305,7 @@ pub struct IdentifierFactorOptGroupStructConstructor {
 }
 
 ///
-/// Type derived for production 516
+/// Type derived for production 528
 ///
 /// `ArrayLiteralItemGroup: Expression ArrayLiteralItemOpt /* Option */;`
 ///
@@ -3218,7 +3318,7 @@ pub struct ArrayLiteralItemGroupExpressionArrayLiteralItemOpt {
 }
 
 ///
-/// Type derived for production 517
+/// Type derived for production 529
 ///
 /// `ArrayLiteralItemGroup: Defaul Colon Expression;`
 ///
@@ -3232,7 +3332,7 @@ pub struct ArrayLiteralItemGroupDefaulColonExpression {
 }
 
 ///
-/// Type derived for production 542
+/// Type derived for production 554
 ///
 /// `SelectOperator: Colon;`
 ///
@@ -3244,7 +3344,7 @@ pub struct SelectOperatorColon {
 }
 
 ///
-/// Type derived for production 543
+/// Type derived for production 555
 ///
 /// `SelectOperator: PlusColon;`
 ///
@@ -3256,7 +3356,7 @@ pub struct SelectOperatorPlusColon {
 }
 
 ///
-/// Type derived for production 544
+/// Type derived for production 556
 ///
 /// `SelectOperator: MinusColon;`
 ///
@@ -3268,7 +3368,7 @@ pub struct SelectOperatorMinusColon {
 }
 
 ///
-/// Type derived for production 545
+/// Type derived for production 557
 ///
 /// `SelectOperator: Step;`
 ///
@@ -3280,7 +3380,7 @@ pub struct SelectOperatorStep {
 }
 
 ///
-/// Type derived for production 555
+/// Type derived for production 567
 ///
 /// `RangeOperator: DotDot;`
 ///
@@ -3292,7 +3392,7 @@ pub struct RangeOperatorDotDot {
 }
 
 ///
-/// Type derived for production 556
+/// Type derived for production 568
 ///
 /// `RangeOperator: DotDotEqu;`
 ///
@@ -3304,7 +3404,7 @@ pub struct RangeOperatorDotDotEqu {
 }
 
 ///
-/// Type derived for production 557
+/// Type derived for production 569
 ///
 /// `FixedType: U8;`
 ///
@@ -3316,7 +3416,7 @@ pub struct FixedTypeU8 {
 }
 
 ///
-/// Type derived for production 558
+/// Type derived for production 570
 ///
 /// `FixedType: U16;`
 ///
@@ -3328,7 +3428,7 @@ pub struct FixedTypeU16 {
 }
 
 ///
-/// Type derived for production 559
+/// Type derived for production 571
 ///
 /// `FixedType: U32;`
 ///
@@ -3340,7 +3440,7 @@ pub struct FixedTypeU32 {
 }
 
 ///
-/// Type derived for production 560
+/// Type derived for production 572
 ///
 /// `FixedType: U64;`
 ///
@@ -3352,7 +3452,7 @@ pub struct FixedTypeU64 {
 }
 
 ///
-/// Type derived for production 561
+/// Type derived for production 573
 ///
 /// `FixedType: I8;`
 ///
@@ -3364,7 +3464,7 @@ pub struct FixedTypeI8 {
 }
 
 ///
-/// Type derived for production 562
+/// Type derived for production 574
 ///
 /// `FixedType: I16;`
 ///
@@ -3376,7 +3476,7 @@ pub struct FixedTypeI16 {
 }
 
 ///
-/// Type derived for production 563
+/// Type derived for production 575
 ///
 /// `FixedType: I32;`
 ///
@@ -3388,7 +3488,7 @@ pub struct FixedTypeI32 {
 }
 
 ///
-/// Type derived for production 564
+/// Type derived for production 576
 ///
 /// `FixedType: I64;`
 ///
@@ -3400,7 +3500,7 @@ pub struct FixedTypeI64 {
 }
 
 ///
-/// Type derived for production 565
+/// Type derived for production 577
 ///
 /// `FixedType: F32;`
 ///
@@ -3412,7 +3512,7 @@ pub struct FixedTypeF32 {
 }
 
 ///
-/// Type derived for production 566
+/// Type derived for production 578
 ///
 /// `FixedType: F64;`
 ///
@@ -3424,7 +3524,7 @@ pub struct FixedTypeF64 {
 }
 
 ///
-/// Type derived for production 567
+/// Type derived for production 579
 ///
 /// `FixedType: Bool;`
 ///
@@ -3436,7 +3536,7 @@ pub struct FixedTypeBool {
 }
 
 ///
-/// Type derived for production 568
+/// Type derived for production 580
 ///
 /// `FixedType: Strin;`
 ///
@@ -3448,7 +3548,7 @@ pub struct FixedTypeStrin {
 }
 
 ///
-/// Type derived for production 569
+/// Type derived for production 581
 ///
 /// `VariableType: Clock;`
 ///
@@ -3460,7 +3560,7 @@ pub struct VariableTypeClock {
 }
 
 ///
-/// Type derived for production 570
+/// Type derived for production 582
 ///
 /// `VariableType: ClockPosedge;`
 ///
@@ -3472,7 +3572,7 @@ pub struct VariableTypeClockPosedge {
 }
 
 ///
-/// Type derived for production 571
+/// Type derived for production 583
 ///
 /// `VariableType: ClockNegedge;`
 ///
@@ -3484,7 +3584,7 @@ pub struct VariableTypeClockNegedge {
 }
 
 ///
-/// Type derived for production 572
+/// Type derived for production 584
 ///
 /// `VariableType: Reset;`
 ///
@@ -3496,7 +3596,7 @@ pub struct VariableTypeReset {
 }
 
 ///
-/// Type derived for production 573
+/// Type derived for production 585
 ///
 /// `VariableType: ResetAsyncHigh;`
 ///
@@ -3508,7 +3608,7 @@ pub struct VariableTypeResetAsyncHigh {
 }
 
 ///
-/// Type derived for production 574
+/// Type derived for production 586
 ///
 /// `VariableType: ResetAsyncLow;`
 ///
@@ -3520,7 +3620,7 @@ pub struct VariableTypeResetAsyncLow {
 }
 
 ///
-/// Type derived for production 575
+/// Type derived for production 587
 ///
 /// `VariableType: ResetSyncHigh;`
 ///
@@ -3532,7 +3632,7 @@ pub struct VariableTypeResetSyncHigh {
 }
 
 ///
-/// Type derived for production 576
+/// Type derived for production 588
 ///
 /// `VariableType: ResetSyncLow;`
 ///
@@ -3544,7 +3644,7 @@ pub struct VariableTypeResetSyncLow {
 }
 
 ///
-/// Type derived for production 577
+/// Type derived for production 589
 ///
 /// `VariableType: Logic;`
 ///
@@ -3556,7 +3656,7 @@ pub struct VariableTypeLogic {
 }
 
 ///
-/// Type derived for production 578
+/// Type derived for production 590
 ///
 /// `VariableType: Bit;`
 ///
@@ -3568,7 +3668,7 @@ pub struct VariableTypeBit {
 }
 
 ///
-/// Type derived for production 580
+/// Type derived for production 592
 ///
 /// `TypeModifier: Tri;`
 ///
@@ -3580,7 +3680,7 @@ pub struct TypeModifierTri {
 }
 
 ///
-/// Type derived for production 581
+/// Type derived for production 593
 ///
 /// `TypeModifier: Signed;`
 ///
@@ -3592,7 +3692,7 @@ pub struct TypeModifierSigned {
 }
 
 ///
-/// Type derived for production 582
+/// Type derived for production 594
 ///
 /// `TypeModifier: Defaul;`
 ///
@@ -3604,7 +3704,7 @@ pub struct TypeModifierDefaul {
 }
 
 ///
-/// Type derived for production 584
+/// Type derived for production 596
 ///
 /// `FactorTypeGroup: VariableType FactorTypeOpt /* Option */;`
 ///
@@ -3617,7 +3717,7 @@ pub struct FactorTypeGroupVariableTypeFactorTypeOpt {
 }
 
 ///
-/// Type derived for production 585
+/// Type derived for production 597
 ///
 /// `FactorTypeGroup: FixedType;`
 ///
@@ -3629,7 +3729,7 @@ pub struct FactorTypeGroupFixedType {
 }
 
 ///
-/// Type derived for production 589
+/// Type derived for production 601
 ///
 /// `ScalarTypeGroup: UserDefinedType ScalarTypeOpt /* Option */;`
 ///
@@ -3642,7 +3742,7 @@ pub struct ScalarTypeGroupUserDefinedTypeScalarTypeOpt {
 }
 
 ///
-/// Type derived for production 590
+/// Type derived for production 602
 ///
 /// `ScalarTypeGroup: FactorType;`
 ///
@@ -3654,7 +3754,7 @@ pub struct ScalarTypeGroupFactorType {
 }
 
 ///
-/// Type derived for production 598
+/// Type derived for production 610
 ///
 /// `CastingType: U8;`
 ///
@@ -3666,7 +3766,7 @@ pub struct CastingTypeU8 {
 }
 
 ///
-/// Type derived for production 599
+/// Type derived for production 611
 ///
 /// `CastingType: U16;`
 ///
@@ -3678,7 +3778,7 @@ pub struct CastingTypeU16 {
 }
 
 ///
-/// Type derived for production 600
+/// Type derived for production 612
 ///
 /// `CastingType: U32;`
 ///
@@ -3690,7 +3790,7 @@ pub struct CastingTypeU32 {
 }
 
 ///
-/// Type derived for production 601
+/// Type derived for production 613
 ///
 /// `CastingType: U64;`
 ///
@@ -3702,7 +3802,7 @@ pub struct CastingTypeU64 {
 }
 
 ///
-/// Type derived for production 602
+/// Type derived for production 614
 ///
 /// `CastingType: I8;`
 ///
@@ -3714,7 +3814,7 @@ pub struct CastingTypeI8 {
 }
 
 ///
-/// Type derived for production 603
+/// Type derived for production 615
 ///
 /// `CastingType: I16;`
 ///
@@ -3726,7 +3826,7 @@ pub struct CastingTypeI16 {
 }
 
 ///
-/// Type derived for production 604
+/// Type derived for production 616
 ///
 /// `CastingType: I32;`
 ///
@@ -3738,7 +3838,7 @@ pub struct CastingTypeI32 {
 }
 
 ///
-/// Type derived for production 605
+/// Type derived for production 617
 ///
 /// `CastingType: I64;`
 ///
@@ -3750,7 +3850,7 @@ pub struct CastingTypeI64 {
 }
 
 ///
-/// Type derived for production 606
+/// Type derived for production 618
 ///
 /// `CastingType: F32;`
 ///
@@ -3762,7 +3862,7 @@ pub struct CastingTypeF32 {
 }
 
 ///
-/// Type derived for production 607
+/// Type derived for production 619
 ///
 /// `CastingType: F64;`
 ///
@@ -3774,7 +3874,7 @@ pub struct CastingTypeF64 {
 }
 
 ///
-/// Type derived for production 608
+/// Type derived for production 620
 ///
 /// `CastingType: Bool;`
 ///
@@ -3786,7 +3886,7 @@ pub struct CastingTypeBool {
 }
 
 ///
-/// Type derived for production 609
+/// Type derived for production 621
 ///
 /// `CastingType: Clock;`
 ///
@@ -3798,7 +3898,7 @@ pub struct CastingTypeClock {
 }
 
 ///
-/// Type derived for production 610
+/// Type derived for production 622
 ///
 /// `CastingType: ClockPosedge;`
 ///
@@ -3810,7 +3910,7 @@ pub struct CastingTypeClockPosedge {
 }
 
 ///
-/// Type derived for production 611
+/// Type derived for production 623
 ///
 /// `CastingType: ClockNegedge;`
 ///
@@ -3822,7 +3922,7 @@ pub struct CastingTypeClockNegedge {
 }
 
 ///
-/// Type derived for production 612
+/// Type derived for production 624
 ///
 /// `CastingType: Reset;`
 ///
@@ -3834,7 +3934,7 @@ pub struct CastingTypeReset {
 }
 
 ///
-/// Type derived for production 613
+/// Type derived for production 625
 ///
 /// `CastingType: ResetAsyncHigh;`
 ///
@@ -3846,7 +3946,7 @@ pub struct CastingTypeResetAsyncHigh {
 }
 
 ///
-/// Type derived for production 614
+/// Type derived for production 626
 ///
 /// `CastingType: ResetAsyncLow;`
 ///
@@ -3858,7 +3958,7 @@ pub struct CastingTypeResetAsyncLow {
 }
 
 ///
-/// Type derived for production 615
+/// Type derived for production 627
 ///
 /// `CastingType: ResetSyncHigh;`
 ///
@@ -3870,7 +3970,7 @@ pub struct CastingTypeResetSyncHigh {
 }
 
 ///
-/// Type derived for production 616
+/// Type derived for production 628
 ///
 /// `CastingType: ResetSyncLow;`
 ///
@@ -3882,7 +3982,7 @@ pub struct CastingTypeResetSyncLow {
 }
 
 ///
-/// Type derived for production 617
+/// Type derived for production 629
 ///
 /// `CastingType: UserDefinedType;`
 ///
@@ -3894,7 +3994,7 @@ pub struct CastingTypeUserDefinedType {
 }
 
 ///
-/// Type derived for production 618
+/// Type derived for production 630
 ///
 /// `CastingType: Based;`
 ///
@@ -3906,7 +4006,7 @@ pub struct CastingTypeBased {
 }
 
 ///
-/// Type derived for production 619
+/// Type derived for production 631
 ///
 /// `CastingType: BaseLess;`
 ///
@@ -3918,7 +4018,7 @@ pub struct CastingTypeBaseLess {
 }
 
 ///
-/// Type derived for production 625
+/// Type derived for production 637
 ///
 /// `StatementBlockGroupGroup: LBrace StatementBlockGroupGroupList /* Vec */ RBrace;`
 ///
@@ -3932,7 +4032,7 @@ pub struct StatementBlockGroupGroupLBraceStatementBlockGroupGroupListRBrace {
 }
 
 ///
-/// Type derived for production 628
+/// Type derived for production 640
 ///
 /// `StatementBlockGroupGroup: StatementBlockItem;`
 ///
@@ -3944,7 +4044,7 @@ pub struct StatementBlockGroupGroupStatementBlockItem {
 }
 
 ///
-/// Type derived for production 631
+/// Type derived for production 643
 ///
 /// `StatementBlockItem: VarDeclaration;`
 ///
@@ -3956,7 +4056,7 @@ pub struct StatementBlockItemVarDeclaration {
 }
 
 ///
-/// Type derived for production 632
+/// Type derived for production 644
 ///
 /// `StatementBlockItem: LetStatement;`
 ///
@@ -3968,7 +4068,7 @@ pub struct StatementBlockItemLetStatement {
 }
 
 ///
-/// Type derived for production 633
+/// Type derived for production 645
 ///
 /// `StatementBlockItem: ConstDeclaration;`
 ///
@@ -3980,7 +4080,7 @@ pub struct StatementBlockItemConstDeclaration {
 }
 
 ///
-/// Type derived for production 634
+/// Type derived for production 646
 ///
 /// `StatementBlockItem: Statement;`
 ///
@@ -3992,7 +4092,7 @@ pub struct StatementBlockItemStatement {
 }
 
 ///
-/// Type derived for production 635
+/// Type derived for production 647
 ///
 /// `Statement: IdentifierStatement;`
 ///
@@ -4004,7 +4104,7 @@ pub struct StatementIdentifierStatement {
 }
 
 ///
-/// Type derived for production 636
+/// Type derived for production 648
 ///
 /// `Statement: IfStatement;`
 ///
@@ -4016,7 +4116,7 @@ pub struct StatementIfStatement {
 }
 
 ///
-/// Type derived for production 637
+/// Type derived for production 649
 ///
 /// `Statement: IfResetStatement;`
 ///
@@ -4028,7 +4128,7 @@ pub struct StatementIfResetStatement {
 }
 
 ///
-/// Type derived for production 638
+/// Type derived for production 650
 ///
 /// `Statement: ReturnStatement;`
 ///
@@ -4040,7 +4140,7 @@ pub struct StatementReturnStatement {
 }
 
 ///
-/// Type derived for production 639
+/// Type derived for production 651
 ///
 /// `Statement: BreakStatement;`
 ///
@@ -4052,7 +4152,7 @@ pub struct StatementBreakStatement {
 }
 
 ///
-/// Type derived for production 640
+/// Type derived for production 652
 ///
 /// `Statement: ForStatement;`
 ///
@@ -4064,7 +4164,7 @@ pub struct StatementForStatement {
 }
 
 ///
-/// Type derived for production 641
+/// Type derived for production 653
 ///
 /// `Statement: CaseStatement;`
 ///
@@ -4076,7 +4176,7 @@ pub struct StatementCaseStatement {
 }
 
 ///
-/// Type derived for production 642
+/// Type derived for production 654
 ///
 /// `Statement: SwitchStatement;`
 ///
@@ -4088,7 +4188,19 @@ pub struct StatementSwitchStatement {
 }
 
 ///
-/// Type derived for production 647
+/// Type derived for production 655
+///
+/// `Statement: AssertionStatement;`
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "parol_runtime::derive_builder")]
+pub struct StatementAssertionStatement {
+    pub assertion_statement: Box<AssertionStatement>,
+}
+
+///
+/// Type derived for production 660
 ///
 /// `IdentifierStatementGroup: FunctionCall;`
 ///
@@ -4100,7 +4212,7 @@ pub struct IdentifierStatementGroupFunctionCall {
 }
 
 ///
-/// Type derived for production 648
+/// Type derived for production 661
 ///
 /// `IdentifierStatementGroup: Assignment;`
 ///
@@ -4112,7 +4224,7 @@ pub struct IdentifierStatementGroupAssignment {
 }
 
 ///
-/// Type derived for production 650
+/// Type derived for production 663
 ///
 /// `AssignmentGroup: Equ;`
 ///
@@ -4124,7 +4236,7 @@ pub struct AssignmentGroupEqu {
 }
 
 ///
-/// Type derived for production 651
+/// Type derived for production 664
 ///
 /// `AssignmentGroup: AssignmentOperator;`
 ///
@@ -4136,7 +4248,7 @@ pub struct AssignmentGroupAssignmentOperator {
 }
 
 ///
-/// Type derived for production 652
+/// Type derived for production 665
 ///
 /// `AssignmentGroup: DiamondOperator;`
 ///
@@ -4148,7 +4260,7 @@ pub struct AssignmentGroupDiamondOperator {
 }
 
 ///
-/// Type derived for production 672
+/// Type derived for production 685
 ///
 /// `CaseItemGroup0: Statement;`
 ///
@@ -4160,7 +4272,7 @@ pub struct CaseItemGroup0Statement {
 }
 
 ///
-/// Type derived for production 673
+/// Type derived for production 686
 ///
 /// `CaseItemGroup0: StatementBlock;`
 ///
@@ -4172,7 +4284,7 @@ pub struct CaseItemGroup0StatementBlock {
 }
 
 ///
-/// Type derived for production 674
+/// Type derived for production 687
 ///
 /// `CaseItemGroup: CaseCondition;`
 ///
@@ -4184,7 +4296,7 @@ pub struct CaseItemGroupCaseCondition {
 }
 
 ///
-/// Type derived for production 675
+/// Type derived for production 688
 ///
 /// `CaseItemGroup: Defaul;`
 ///
@@ -4196,7 +4308,7 @@ pub struct CaseItemGroupDefaul {
 }
 
 ///
-/// Type derived for production 683
+/// Type derived for production 696
 ///
 /// `SwitchItemGroup0: Statement;`
 ///
@@ -4208,7 +4320,7 @@ pub struct SwitchItemGroup0Statement {
 }
 
 ///
-/// Type derived for production 684
+/// Type derived for production 697
 ///
 /// `SwitchItemGroup0: StatementBlock;`
 ///
@@ -4220,7 +4332,7 @@ pub struct SwitchItemGroup0StatementBlock {
 }
 
 ///
-/// Type derived for production 685
+/// Type derived for production 698
 ///
 /// `SwitchItemGroup: SwitchCondition;`
 ///
@@ -4232,7 +4344,7 @@ pub struct SwitchItemGroupSwitchCondition {
 }
 
 ///
-/// Type derived for production 686
+/// Type derived for production 699
 ///
 /// `SwitchItemGroup: Defaul;`
 ///
@@ -4244,7 +4356,43 @@ pub struct SwitchItemGroupDefaul {
 }
 
 ///
-/// Type derived for production 698
+/// Type derived for production 704
+///
+/// `AssertionKeyword: Assert;`
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "parol_runtime::derive_builder")]
+pub struct AssertionKeywordAssert {
+    pub assert: Box<Assert>,
+}
+
+///
+/// Type derived for production 705
+///
+/// `AssertionKeyword: Assume;`
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "parol_runtime::derive_builder")]
+pub struct AssertionKeywordAssume {
+    pub assume: Box<Assume>,
+}
+
+///
+/// Type derived for production 706
+///
+/// `AssertionKeyword: Cover;`
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "parol_runtime::derive_builder")]
+pub struct AssertionKeywordCover {
+    pub cover: Box<Cover>,
+}
+
+///
+/// Type derived for production 715
 ///
 /// `AttributeItem: Identifier;`
 ///
@@ -4256,7 +4404,7 @@ pub struct AttributeItemIdentifier {
 }
 
 ///
-/// Type derived for production 699
+/// Type derived for production 716
 ///
 /// `AttributeItem: StringLiteral;`
 ///
@@ -4268,7 +4416,79 @@ pub struct AttributeItemStringLiteral {
 }
 
 ///
-/// Type derived for production 707
+/// Type derived for production 717
+///
+/// `AttributeItem: AttributeAssignment;`
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "parol_runtime::derive_builder")]
+pub struct AttributeItemAttributeAssignment {
+    pub attribute_assignment: Box<AttributeAssignment>,
+}
+
+///
+/// Type derived for production 718
+///
+/// `AttributeItem: AttributeCall;`
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "parol_runtime::derive_builder")]
+pub struct AttributeItemAttributeCall {
+    pub attribute_call: Box<AttributeCall>,
+}
+
+///
+/// Type derived for production 721
+///
+/// `AttributeValue: IntegralNumber;`
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "parol_runtime::derive_builder")]
+pub struct AttributeValueIntegralNumber {
+    pub integral_number: Box<IntegralNumber>,
+}
+
+///
+/// Type derived for production 722
+///
+/// `AttributeValue: StringLiteral;`
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "parol_runtime::derive_builder")]
+pub struct AttributeValueStringLiteral {
+    pub string_literal: Box<StringLiteral>,
+}
+
+///
+/// Type derived for production 723
+///
+/// `AttributeValue: Identifier;`
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "parol_runtime::derive_builder")]
+pub struct AttributeValueIdentifier {
+    pub identifier: Box<Identifier>,
+}
+
+///
+/// Type derived for production 724
+///
+/// `AttributeValue: AttributeArray;`
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "parol_runtime::derive_builder")]
+pub struct AttributeValueAttributeArray {
+    pub attribute_array: Box<AttributeArray>,
+}
+
+///
+/// Type derived for production 738
 ///
 /// `ConstDeclarationGroup: ArrayType;`
 ///
@@ -4280,7 +4500,7 @@ pub struct ConstDeclarationGroupArrayType {
 }
 
 ///
-/// Type derived for production 708
+/// Type derived for production 739
 ///
 /// `ConstDeclarationGroup: Type;`
 ///
@@ -4292,7 +4512,7 @@ pub struct ConstDeclarationGroupType {
 }
 
 ///
-/// Type derived for production 720
+/// Type derived for production 758
 ///
 /// `AssignDestination: HierarchicalIdentifier;`
 ///
@@ -4304,7 +4524,7 @@ pub struct AssignDestinationHierarchicalIdentifier {
 }
 
 ///
-/// Type derived for production 721
+/// Type derived for production 759
 ///
 /// `AssignDestination: LBrace AssignConcatenationList RBrace;`
 ///
@@ -4318,7 +4538,7 @@ pub struct AssignDestinationLBraceAssignConcatenationListRBrace {
 }
 
 ///
-/// Type derived for production 740
+/// Type derived for production 778
 ///
 /// `ModportGroupGroup: LBrace ModportList RBrace;`
 ///
@@ -4332,7 +4552,7 @@ pub struct ModportGroupGroupLBraceModportListRBrace {
 }
 
 ///
-/// Type derived for production 741
+/// Type derived for production 779
 ///
 /// `ModportGroupGroup: ModportItem;`
 ///
@@ -4344,7 +4564,7 @@ pub struct ModportGroupGroupModportItem {
 }
 
 ///
-/// Type derived for production 745
+/// Type derived for production 783
 ///
 /// `ModportDefault: Input;`
 ///
@@ -4356,7 +4576,7 @@ pub struct ModportDefaultInput {
 }
 
 ///
-/// Type derived for production 746
+/// Type derived for production 784
 ///
 /// `ModportDefault: Output;`
 ///
@@ -4368,7 +4588,7 @@ pub struct ModportDefaultOutput {
 }
 
 ///
-/// Type derived for production 747
+/// Type derived for production 785
 ///
 /// `ModportDefault: Same LParen Identifier RParen;`
 ///
@@ -4383,7 +4603,7 @@ pub struct ModportDefaultSameLParenIdentifierRParen {
 }
 
 ///
-/// Type derived for production 748
+/// Type derived for production 786
 ///
 /// `ModportDefault: Converse LParen Identifier RParen;`
 ///
@@ -4398,7 +4618,7 @@ pub struct ModportDefaultConverseLParenIdentifierRParen {
 }
 
 ///
-/// Type derived for production 758
+/// Type derived for production 796
 ///
 /// `EnumGroupGroup: LBrace EnumList RBrace;`
 ///
@@ -4412,7 +4632,7 @@ pub struct EnumGroupGroupLBraceEnumListRBrace {
 }
 
 ///
-/// Type derived for production 759
+/// Type derived for production 797
 ///
 /// `EnumGroupGroup: EnumItem;`
 ///
@@ -4424,7 +4644,7 @@ pub struct EnumGroupGroupEnumItem {
 }
 
 ///
-/// Type derived for production 765
+/// Type derived for production 803
 ///
 /// `StructUnion: Struct;`
 ///
@@ -4436,7 +4656,7 @@ pub struct StructUnionStruct {
 }
 
 ///
-/// Type derived for production 766
+/// Type derived for production 804
 ///
 /// `StructUnion: Union;`
 ///
@@ -4448,7 +4668,7 @@ pub struct StructUnionUnion {
 }
 
 ///
-/// Type derived for production 776
+/// Type derived for production 814
 ///
 /// `StructUnionGroupGroup: LBrace StructUnionList RBrace;`
 ///
@@ -4462,7 +4682,7 @@ pub struct StructUnionGroupGroupLBraceStructUnionListRBrace {
 }
 
 ///
-/// Type derived for production 777
+/// Type derived for production 815
 ///
 /// `StructUnionGroupGroup: StructUnionItem;`
 ///
@@ -4474,7 +4694,7 @@ pub struct StructUnionGroupGroupStructUnionItem {
 }
 
 ///
-/// Type derived for production 803
+/// Type derived for production 841
 ///
 /// `InstParameterGroupGroup: LBrace InstParameterList RBrace;`
 ///
@@ -4488,7 +4708,7 @@ pub struct InstParameterGroupGroupLBraceInstParameterListRBrace {
 }
 
 ///
-/// Type derived for production 804
+/// Type derived for production 842
 ///
 /// `InstParameterGroupGroup: InstParameterItem;`
 ///
@@ -4500,7 +4720,7 @@ pub struct InstParameterGroupGroupInstParameterItem {
 }
 
 ///
-/// Type derived for production 816
+/// Type derived for production 854
 ///
 /// `InstPortGroupGroup: LBrace InstPortList RBrace;`
 ///
@@ -4514,7 +4734,7 @@ pub struct InstPortGroupGroupLBraceInstPortListRBrace {
 }
 
 ///
-/// Type derived for production 817
+/// Type derived for production 855
 ///
 /// `InstPortGroupGroup: InstPortItem;`
 ///
@@ -4526,7 +4746,7 @@ pub struct InstPortGroupGroupInstPortItem {
 }
 
 ///
-/// Type derived for production 832
+/// Type derived for production 870
 ///
 /// `WithParameterGroupGroup: LBrace WithParameterList RBrace;`
 ///
@@ -4540,7 +4760,7 @@ pub struct WithParameterGroupGroupLBraceWithParameterListRBrace {
 }
 
 ///
-/// Type derived for production 833
+/// Type derived for production 871
 ///
 /// `WithParameterGroupGroup: WithParameterItem;`
 ///
@@ -4552,7 +4772,7 @@ pub struct WithParameterGroupGroupWithParameterItem {
 }
 
 ///
-/// Type derived for production 837
+/// Type derived for production 875
 ///
 /// `WithParameterItemGroup0: ArrayType;`
 ///
@@ -4564,7 +4784,7 @@ pub struct WithParameterItemGroup0ArrayType {
 }
 
 ///
-/// Type derived for production 838
+/// Type derived for production 876
 ///
 /// `WithParameterItemGroup0: Type;`
 ///
@@ -4576,7 +4796,7 @@ pub struct WithParameterItemGroup0Type {
 }
 
 ///
-/// Type derived for production 839
+/// Type derived for production 877
 ///
 /// `WithParameterItemGroup: Param;`
 ///
@@ -4588,7 +4808,7 @@ pub struct WithParameterItemGroupParam {
 }
 
 ///
-/// Type derived for production 840
+/// Type derived for production 878
 ///
 /// `WithParameterItemGroup: Const;`
 ///
@@ -4600,7 +4820,7 @@ pub struct WithParameterItemGroupConst {
 }
 
 ///
-/// Type derived for production 841
+/// Type derived for production 879
 ///
 /// `GenericBound: Type;`
 ///
@@ -4612,7 +4832,7 @@ pub struct GenericBoundType {
 }
 
 ///
-/// Type derived for production 842
+/// Type derived for production 880
 ///
 /// `GenericBound: Inst ScopedIdentifier;`
 ///
@@ -4625,7 +4845,7 @@ pub struct GenericBoundInstScopedIdentifier {
 }
 
 ///
-/// Type derived for production 843
+/// Type derived for production 881
 ///
 /// `GenericBound: GenericProtoBound;`
 ///
@@ -4637,7 +4857,7 @@ pub struct GenericBoundGenericProtoBound {
 }
 
 ///
-/// Type derived for production 853
+/// Type derived for production 891
 ///
 /// `GenericProtoBound: ScopedIdentifier;`
 ///
@@ -4649,7 +4869,7 @@ pub struct GenericProtoBoundScopedIdentifier {
 }
 
 ///
-/// Type derived for production 854
+/// Type derived for production 892
 ///
 /// `GenericProtoBound: FixedType;`
 ///
@@ -4661,7 +4881,7 @@ pub struct GenericProtoBoundFixedType {
 }
 
 ///
-/// Type derived for production 863
+/// Type derived for production 901
 ///
 /// `WithGenericArgumentItem: ExpressionIdentifier;`
 ///
@@ -4673,7 +4893,7 @@ pub struct WithGenericArgumentItemExpressionIdentifier {
 }
 
 ///
-/// Type derived for production 864
+/// Type derived for production 902
 ///
 /// `WithGenericArgumentItem: FixedType;`
 ///
@@ -4685,7 +4905,7 @@ pub struct WithGenericArgumentItemFixedType {
 }
 
 ///
-/// Type derived for production 865
+/// Type derived for production 903
 ///
 /// `WithGenericArgumentItem: Number;`
 ///
@@ -4697,7 +4917,7 @@ pub struct WithGenericArgumentItemNumber {
 }
 
 ///
-/// Type derived for production 866
+/// Type derived for production 904
 ///
 /// `WithGenericArgumentItem: BooleanLiteral;`
 ///
@@ -4709,7 +4929,7 @@ pub struct WithGenericArgumentItemBooleanLiteral {
 }
 
 ///
-/// Type derived for production 876
+/// Type derived for production 914
 ///
 /// `PortDeclarationGroupGroup: LBrace PortDeclarationList RBrace;`
 ///
@@ -4723,7 +4943,7 @@ pub struct PortDeclarationGroupGroupLBracePortDeclarationListRBrace {
 }
 
 ///
-/// Type derived for production 877
+/// Type derived for production 915
 ///
 /// `PortDeclarationGroupGroup: PortDeclarationItem;`
 ///
@@ -4735,7 +4955,7 @@ pub struct PortDeclarationGroupGroupPortDeclarationItem {
 }
 
 ///
-/// Type derived for production 881
+/// Type derived for production 919
 ///
 /// `PortDeclarationItemGroup: PortTypeConcrete;`
 ///
@@ -4747,7 +4967,7 @@ pub struct PortDeclarationItemGroupPortTypeConcrete {
 }
 
 ///
-/// Type derived for production 882
+/// Type derived for production 920
 ///
 /// `PortDeclarationItemGroup: PortTypeAbstract;`
 ///
@@ -4759,7 +4979,7 @@ pub struct PortDeclarationItemGroupPortTypeAbstract {
 }
 
 ///
-/// Type derived for production 896
+/// Type derived for production 934
 ///
 /// `Direction: Input;`
 ///
@@ -4771,7 +4991,7 @@ pub struct DirectionInput {
 }
 
 ///
-/// Type derived for production 897
+/// Type derived for production 935
 ///
 /// `Direction: Output;`
 ///
@@ -4783,7 +5003,7 @@ pub struct DirectionOutput {
 }
 
 ///
-/// Type derived for production 898
+/// Type derived for production 936
 ///
 /// `Direction: Inout;`
 ///
@@ -4795,7 +5015,7 @@ pub struct DirectionInout {
 }
 
 ///
-/// Type derived for production 899
+/// Type derived for production 937
 ///
 /// `Direction: Modport;`
 ///
@@ -4807,7 +5027,7 @@ pub struct DirectionModport {
 }
 
 ///
-/// Type derived for production 900
+/// Type derived for production 938
 ///
 /// `Direction: Import;`
 ///
@@ -4819,7 +5039,7 @@ pub struct DirectionImport {
 }
 
 ///
-/// Type derived for production 926
+/// Type derived for production 964
 ///
 /// `ModuleGroupGroup: LBrace ModuleGroupGroupList /* Vec */ RBrace;`
 ///
@@ -4833,7 +5053,7 @@ pub struct ModuleGroupGroupLBraceModuleGroupGroupListRBrace {
 }
 
 ///
-/// Type derived for production 929
+/// Type derived for production 967
 ///
 /// `ModuleGroupGroup: ModuleItem;`
 ///
@@ -4845,7 +5065,7 @@ pub struct ModuleGroupGroupModuleItem {
 }
 
 ///
-/// Type derived for production 943
+/// Type derived for production 981
 ///
 /// `InterfaceGroupGroup: LBrace InterfaceGroupGroupList /* Vec */ RBrace;`
 ///
@@ -4859,7 +5079,7 @@ pub struct InterfaceGroupGroupLBraceInterfaceGroupGroupListRBrace {
 }
 
 ///
-/// Type derived for production 946
+/// Type derived for production 984
 ///
 /// `InterfaceGroupGroup: InterfaceItem;`
 ///
@@ -4871,7 +5091,7 @@ pub struct InterfaceGroupGroupInterfaceItem {
 }
 
 ///
-/// Type derived for production 949
+/// Type derived for production 987
 ///
 /// `InterfaceItem: GenerateItem;`
 ///
@@ -4883,7 +5103,7 @@ pub struct InterfaceItemGenerateItem {
 }
 
 ///
-/// Type derived for production 950
+/// Type derived for production 988
 ///
 /// `InterfaceItem: ModportDeclaration;`
 ///
@@ -4895,7 +5115,7 @@ pub struct InterfaceItemModportDeclaration {
 }
 
 ///
-/// Type derived for production 969
+/// Type derived for production 1007
 ///
 /// `GenerateGroupGroup: LBrace GenerateGroupGroupList /* Vec */ RBrace;`
 ///
@@ -4909,7 +5129,7 @@ pub struct GenerateGroupGroupLBraceGenerateGroupGroupListRBrace {
 }
 
 ///
-/// Type derived for production 972
+/// Type derived for production 1010
 ///
 /// `GenerateGroupGroup: GenerateItem;`
 ///
@@ -4921,7 +5141,7 @@ pub struct GenerateGroupGroupGenerateItem {
 }
 
 ///
-/// Type derived for production 975
+/// Type derived for production 1013
 ///
 /// `GenerateItem: LetDeclaration;`
 ///
@@ -4933,7 +5153,7 @@ pub struct GenerateItemLetDeclaration {
 }
 
 ///
-/// Type derived for production 976
+/// Type derived for production 1014
 ///
 /// `GenerateItem: VarDeclaration;`
 ///
@@ -4945,7 +5165,7 @@ pub struct GenerateItemVarDeclaration {
 }
 
 ///
-/// Type derived for production 977
+/// Type derived for production 1015
 ///
 /// `GenerateItem: InstDeclaration;`
 ///
@@ -4957,7 +5177,7 @@ pub struct GenerateItemInstDeclaration {
 }
 
 ///
-/// Type derived for production 978
+/// Type derived for production 1016
 ///
 /// `GenerateItem: ConstDeclaration;`
 ///
@@ -4969,7 +5189,7 @@ pub struct GenerateItemConstDeclaration {
 }
 
 ///
-/// Type derived for production 979
+/// Type derived for production 1017
 ///
 /// `GenerateItem: AlwaysFfDeclaration;`
 ///
@@ -4981,7 +5201,7 @@ pub struct GenerateItemAlwaysFfDeclaration {
 }
 
 ///
-/// Type derived for production 980
+/// Type derived for production 1018
 ///
 /// `GenerateItem: AlwaysCombDeclaration;`
 ///
@@ -4993,7 +5213,7 @@ pub struct GenerateItemAlwaysCombDeclaration {
 }
 
 ///
-/// Type derived for production 981
+/// Type derived for production 1019
 ///
 /// `GenerateItem: AssignDeclaration;`
 ///
@@ -5005,7 +5225,19 @@ pub struct GenerateItemAssignDeclaration {
 }
 
 ///
-/// Type derived for production 982
+/// Type derived for production 1020
+///
+/// `GenerateItem: AssertionDeclaration;`
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "parol_runtime::derive_builder")]
+pub struct GenerateItemAssertionDeclaration {
+    pub assertion_declaration: Box<AssertionDeclaration>,
+}
+
+///
+/// Type derived for production 1021
 ///
 /// `GenerateItem: ConnectDeclaration;`
 ///
@@ -5017,7 +5249,7 @@ pub struct GenerateItemConnectDeclaration {
 }
 
 ///
-/// Type derived for production 983
+/// Type derived for production 1022
 ///
 /// `GenerateItem: FunctionDeclaration;`
 ///
@@ -5029,7 +5261,7 @@ pub struct GenerateItemFunctionDeclaration {
 }
 
 ///
-/// Type derived for production 984
+/// Type derived for production 1023
 ///
 /// `GenerateItem: GenerateIfDeclaration;`
 ///
@@ -5041,7 +5273,7 @@ pub struct GenerateItemGenerateIfDeclaration {
 }
 
 ///
-/// Type derived for production 985
+/// Type derived for production 1024
 ///
 /// `GenerateItem: GenerateForDeclaration;`
 ///
@@ -5053,7 +5285,7 @@ pub struct GenerateItemGenerateForDeclaration {
 }
 
 ///
-/// Type derived for production 986
+/// Type derived for production 1025
 ///
 /// `GenerateItem: GenerateBlockDeclaration;`
 ///
@@ -5065,7 +5297,7 @@ pub struct GenerateItemGenerateBlockDeclaration {
 }
 
 ///
-/// Type derived for production 987
+/// Type derived for production 1026
 ///
 /// `GenerateItem: TypeDefDeclaration;`
 ///
@@ -5077,7 +5309,7 @@ pub struct GenerateItemTypeDefDeclaration {
 }
 
 ///
-/// Type derived for production 988
+/// Type derived for production 1027
 ///
 /// `GenerateItem: EnumDeclaration;`
 ///
@@ -5089,7 +5321,7 @@ pub struct GenerateItemEnumDeclaration {
 }
 
 ///
-/// Type derived for production 989
+/// Type derived for production 1028
 ///
 /// `GenerateItem: StructUnionDeclaration;`
 ///
@@ -5101,7 +5333,7 @@ pub struct GenerateItemStructUnionDeclaration {
 }
 
 ///
-/// Type derived for production 990
+/// Type derived for production 1029
 ///
 /// `GenerateItem: ImportDeclaration;`
 ///
@@ -5113,7 +5345,7 @@ pub struct GenerateItemImportDeclaration {
 }
 
 ///
-/// Type derived for production 991
+/// Type derived for production 1030
 ///
 /// `GenerateItem: AliasDeclaration;`
 ///
@@ -5125,7 +5357,7 @@ pub struct GenerateItemAliasDeclaration {
 }
 
 ///
-/// Type derived for production 992
+/// Type derived for production 1031
 ///
 /// `GenerateItem: InitialDeclaration;`
 ///
@@ -5137,7 +5369,7 @@ pub struct GenerateItemInitialDeclaration {
 }
 
 ///
-/// Type derived for production 993
+/// Type derived for production 1032
 ///
 /// `GenerateItem: FinalDeclaration;`
 ///
@@ -5149,7 +5381,7 @@ pub struct GenerateItemFinalDeclaration {
 }
 
 ///
-/// Type derived for production 994
+/// Type derived for production 1033
 ///
 /// `GenerateItem: UnsafeBlock;`
 ///
@@ -5161,7 +5393,7 @@ pub struct GenerateItemUnsafeBlock {
 }
 
 ///
-/// Type derived for production 995
+/// Type derived for production 1034
 ///
 /// `GenerateItem: EmbedDeclaration;`
 ///
@@ -5173,7 +5405,7 @@ pub struct GenerateItemEmbedDeclaration {
 }
 
 ///
-/// Type derived for production 1004
+/// Type derived for production 1043
 ///
 /// `PackageGroupGroup: LBrace PackageGroupGroupList /* Vec */ RBrace;`
 ///
@@ -5187,7 +5419,7 @@ pub struct PackageGroupGroupLBracePackageGroupGroupListRBrace {
 }
 
 ///
-/// Type derived for production 1007
+/// Type derived for production 1046
 ///
 /// `PackageGroupGroup: PackageItem;`
 ///
@@ -5199,7 +5431,7 @@ pub struct PackageGroupGroupPackageItem {
 }
 
 ///
-/// Type derived for production 1010
+/// Type derived for production 1049
 ///
 /// `PackageItem: ConstDeclaration;`
 ///
@@ -5211,7 +5443,7 @@ pub struct PackageItemConstDeclaration {
 }
 
 ///
-/// Type derived for production 1011
+/// Type derived for production 1050
 ///
 /// `PackageItem: TypeDefDeclaration;`
 ///
@@ -5223,7 +5455,7 @@ pub struct PackageItemTypeDefDeclaration {
 }
 
 ///
-/// Type derived for production 1012
+/// Type derived for production 1051
 ///
 /// `PackageItem: EnumDeclaration;`
 ///
@@ -5235,7 +5467,7 @@ pub struct PackageItemEnumDeclaration {
 }
 
 ///
-/// Type derived for production 1013
+/// Type derived for production 1052
 ///
 /// `PackageItem: StructUnionDeclaration;`
 ///
@@ -5247,7 +5479,7 @@ pub struct PackageItemStructUnionDeclaration {
 }
 
 ///
-/// Type derived for production 1014
+/// Type derived for production 1053
 ///
 /// `PackageItem: FunctionDeclaration;`
 ///
@@ -5259,7 +5491,7 @@ pub struct PackageItemFunctionDeclaration {
 }
 
 ///
-/// Type derived for production 1015
+/// Type derived for production 1054
 ///
 /// `PackageItem: ImportDeclaration;`
 ///
@@ -5271,7 +5503,7 @@ pub struct PackageItemImportDeclaration {
 }
 
 ///
-/// Type derived for production 1016
+/// Type derived for production 1055
 ///
 /// `PackageItem: AliasDeclaration;`
 ///
@@ -5283,7 +5515,7 @@ pub struct PackageItemAliasDeclaration {
 }
 
 ///
-/// Type derived for production 1017
+/// Type derived for production 1056
 ///
 /// `PackageItem: EmbedDeclaration;`
 ///
@@ -5295,7 +5527,7 @@ pub struct PackageItemEmbedDeclaration {
 }
 
 ///
-/// Type derived for production 1019
+/// Type derived for production 1058
 ///
 /// `AliasDeclarationGroup: Module;`
 ///
@@ -5307,7 +5539,7 @@ pub struct AliasDeclarationGroupModule {
 }
 
 ///
-/// Type derived for production 1020
+/// Type derived for production 1059
 ///
 /// `AliasDeclarationGroup: Interface;`
 ///
@@ -5319,7 +5551,7 @@ pub struct AliasDeclarationGroupInterface {
 }
 
 ///
-/// Type derived for production 1021
+/// Type derived for production 1060
 ///
 /// `AliasDeclarationGroup: Package;`
 ///
@@ -5331,7 +5563,7 @@ pub struct AliasDeclarationGroupPackage {
 }
 
 ///
-/// Type derived for production 1023
+/// Type derived for production 1062
 ///
 /// `ProtoDeclarationGroup: ProtoModuleDeclaration;`
 ///
@@ -5343,7 +5575,7 @@ pub struct ProtoDeclarationGroupProtoModuleDeclaration {
 }
 
 ///
-/// Type derived for production 1024
+/// Type derived for production 1063
 ///
 /// `ProtoDeclarationGroup: ProtoInterfaceDeclaration;`
 ///
@@ -5355,7 +5587,7 @@ pub struct ProtoDeclarationGroupProtoInterfaceDeclaration {
 }
 
 ///
-/// Type derived for production 1025
+/// Type derived for production 1064
 ///
 /// `ProtoDeclarationGroup: ProtoPackageDeclaration;`
 ///
@@ -5367,7 +5599,7 @@ pub struct ProtoDeclarationGroupProtoPackageDeclaration {
 }
 
 ///
-/// Type derived for production 1036
+/// Type derived for production 1075
 ///
 /// `ProtoInterfaceItem: VarDeclaration;`
 ///
@@ -5379,7 +5611,7 @@ pub struct ProtoInterfaceItemVarDeclaration {
 }
 
 ///
-/// Type derived for production 1037
+/// Type derived for production 1076
 ///
 /// `ProtoInterfaceItem: ProtoConstDeclaration;`
 ///
@@ -5391,7 +5623,7 @@ pub struct ProtoInterfaceItemProtoConstDeclaration {
 }
 
 ///
-/// Type derived for production 1038
+/// Type derived for production 1077
 ///
 /// `ProtoInterfaceItem: ProtoFunctionDeclaration;`
 ///
@@ -5403,7 +5635,7 @@ pub struct ProtoInterfaceItemProtoFunctionDeclaration {
 }
 
 ///
-/// Type derived for production 1039
+/// Type derived for production 1078
 ///
 /// `ProtoInterfaceItem: ProtoTypeDefDeclaration;`
 ///
@@ -5415,7 +5647,7 @@ pub struct ProtoInterfaceItemProtoTypeDefDeclaration {
 }
 
 ///
-/// Type derived for production 1040
+/// Type derived for production 1079
 ///
 /// `ProtoInterfaceItem: ProtoAliasDeclaration;`
 ///
@@ -5427,7 +5659,7 @@ pub struct ProtoInterfaceItemProtoAliasDeclaration {
 }
 
 ///
-/// Type derived for production 1041
+/// Type derived for production 1080
 ///
 /// `ProtoInterfaceItem: ModportDeclaration;`
 ///
@@ -5439,7 +5671,7 @@ pub struct ProtoInterfaceItemModportDeclaration {
 }
 
 ///
-/// Type derived for production 1042
+/// Type derived for production 1081
 ///
 /// `ProtoInterfaceItem: ImportDeclaration;`
 ///
@@ -5451,7 +5683,7 @@ pub struct ProtoInterfaceItemImportDeclaration {
 }
 
 ///
-/// Type derived for production 1046
+/// Type derived for production 1085
 ///
 /// `ProtoPacakgeItem: ProtoConstDeclaration;`
 ///
@@ -5463,7 +5695,7 @@ pub struct ProtoPacakgeItemProtoConstDeclaration {
 }
 
 ///
-/// Type derived for production 1047
+/// Type derived for production 1086
 ///
 /// `ProtoPacakgeItem: ProtoTypeDefDeclaration;`
 ///
@@ -5475,7 +5707,7 @@ pub struct ProtoPacakgeItemProtoTypeDefDeclaration {
 }
 
 ///
-/// Type derived for production 1048
+/// Type derived for production 1087
 ///
 /// `ProtoPacakgeItem: EnumDeclaration;`
 ///
@@ -5487,7 +5719,7 @@ pub struct ProtoPacakgeItemEnumDeclaration {
 }
 
 ///
-/// Type derived for production 1049
+/// Type derived for production 1088
 ///
 /// `ProtoPacakgeItem: StructUnionDeclaration;`
 ///
@@ -5499,7 +5731,7 @@ pub struct ProtoPacakgeItemStructUnionDeclaration {
 }
 
 ///
-/// Type derived for production 1050
+/// Type derived for production 1089
 ///
 /// `ProtoPacakgeItem: ProtoFunctionDeclaration;`
 ///
@@ -5511,7 +5743,7 @@ pub struct ProtoPacakgeItemProtoFunctionDeclaration {
 }
 
 ///
-/// Type derived for production 1051
+/// Type derived for production 1090
 ///
 /// `ProtoPacakgeItem: ProtoAliasDeclaration;`
 ///
@@ -5523,7 +5755,7 @@ pub struct ProtoPacakgeItemProtoAliasDeclaration {
 }
 
 ///
-/// Type derived for production 1052
+/// Type derived for production 1091
 ///
 /// `ProtoPacakgeItem: ImportDeclaration;`
 ///
@@ -5535,7 +5767,7 @@ pub struct ProtoPacakgeItemImportDeclaration {
 }
 
 ///
-/// Type derived for production 1054
+/// Type derived for production 1093
 ///
 /// `ProtoConstDeclarationGroup: ArrayType;`
 ///
@@ -5547,7 +5779,7 @@ pub struct ProtoConstDeclarationGroupArrayType {
 }
 
 ///
-/// Type derived for production 1055
+/// Type derived for production 1094
 ///
 /// `ProtoConstDeclarationGroup: Type;`
 ///
@@ -5559,7 +5791,7 @@ pub struct ProtoConstDeclarationGroupType {
 }
 
 ///
-/// Type derived for production 1067
+/// Type derived for production 1106
 ///
 /// `ProtoAliasDeclarationGroup: Module;`
 ///
@@ -5571,7 +5803,7 @@ pub struct ProtoAliasDeclarationGroupModule {
 }
 
 ///
-/// Type derived for production 1068
+/// Type derived for production 1107
 ///
 /// `ProtoAliasDeclarationGroup: Interface;`
 ///
@@ -5583,7 +5815,7 @@ pub struct ProtoAliasDeclarationGroupInterface {
 }
 
 ///
-/// Type derived for production 1069
+/// Type derived for production 1108
 ///
 /// `ProtoAliasDeclarationGroup: Package;`
 ///
@@ -5595,7 +5827,7 @@ pub struct ProtoAliasDeclarationGroupPackage {
 }
 
 ///
-/// Type derived for production 1075
+/// Type derived for production 1114
 ///
 /// `EmbedItem: LBraceTerm EmbedItemList /* Vec */ RBraceTerm;`
 ///
@@ -5609,7 +5841,7 @@ pub struct EmbedItemLBraceTermEmbedItemListRBraceTerm {
 }
 
 ///
-/// Type derived for production 1078
+/// Type derived for production 1117
 ///
 /// `EmbedItem: AnyTerm;`
 ///
@@ -5621,7 +5853,7 @@ pub struct EmbedItemAnyTerm {
 }
 
 ///
-/// Type derived for production 1081
+/// Type derived for production 1120
 ///
 /// `DescriptionGroupGroup: LBrace DescriptionGroupGroupList /* Vec */ RBrace;`
 ///
@@ -5635,7 +5867,7 @@ pub struct DescriptionGroupGroupLBraceDescriptionGroupGroupListRBrace {
 }
 
 ///
-/// Type derived for production 1084
+/// Type derived for production 1123
 ///
 /// `DescriptionGroupGroup: DescriptionItem;`
 ///
@@ -5647,7 +5879,7 @@ pub struct DescriptionGroupGroupDescriptionItem {
 }
 
 ///
-/// Type derived for production 1087
+/// Type derived for production 1126
 ///
 /// `DescriptionItem: DescriptionItemOpt /* Option */ PublicDescriptionItem;`
 ///
@@ -5660,7 +5892,7 @@ pub struct DescriptionItemDescriptionItemOptPublicDescriptionItem {
 }
 
 ///
-/// Type derived for production 1088
+/// Type derived for production 1127
 ///
 /// `DescriptionItem: ImportDeclaration;`
 ///
@@ -5672,7 +5904,7 @@ pub struct DescriptionItemImportDeclaration {
 }
 
 ///
-/// Type derived for production 1089
+/// Type derived for production 1128
 ///
 /// `DescriptionItem: EmbedDeclaration;`
 ///
@@ -5684,7 +5916,7 @@ pub struct DescriptionItemEmbedDeclaration {
 }
 
 ///
-/// Type derived for production 1090
+/// Type derived for production 1129
 ///
 /// `DescriptionItem: IncludeDeclaration;`
 ///
@@ -5696,7 +5928,7 @@ pub struct DescriptionItemIncludeDeclaration {
 }
 
 ///
-/// Type derived for production 1093
+/// Type derived for production 1132
 ///
 /// `PublicDescriptionItem: ModuleDeclaration;`
 ///
@@ -5708,7 +5940,7 @@ pub struct PublicDescriptionItemModuleDeclaration {
 }
 
 ///
-/// Type derived for production 1094
+/// Type derived for production 1133
 ///
 /// `PublicDescriptionItem: InterfaceDeclaration;`
 ///
@@ -5720,7 +5952,7 @@ pub struct PublicDescriptionItemInterfaceDeclaration {
 }
 
 ///
-/// Type derived for production 1095
+/// Type derived for production 1134
 ///
 /// `PublicDescriptionItem: PackageDeclaration;`
 ///
@@ -5732,7 +5964,7 @@ pub struct PublicDescriptionItemPackageDeclaration {
 }
 
 ///
-/// Type derived for production 1096
+/// Type derived for production 1135
 ///
 /// `PublicDescriptionItem: AliasDeclaration;`
 ///
@@ -5744,7 +5976,7 @@ pub struct PublicDescriptionItemAliasDeclaration {
 }
 
 ///
-/// Type derived for production 1097
+/// Type derived for production 1136
 ///
 /// `PublicDescriptionItem: ProtoDeclaration;`
 ///
@@ -6202,6 +6434,107 @@ pub struct AsToken {
     pub comments: Box<Comments>,
 }
 
+///
+/// Type derived for non-terminal Assert
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "parol_runtime::derive_builder")]
+pub struct Assert {
+    pub assert_token: crate::veryl_token::VerylToken,
+}
+
+///
+/// Type derived for non-terminal AssertTerm
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "parol_runtime::derive_builder")]
+pub struct AssertTerm {
+    pub assert_term: crate::veryl_token::Token, /* (?-u:\b)assert(?-u:\b) */
+}
+
+///
+/// Type derived for non-terminal AssertToken
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "parol_runtime::derive_builder")]
+pub struct AssertToken {
+    pub assert_term: crate::veryl_token::Token,
+    pub comments: Box<Comments>,
+}
+
+///
+/// Type derived for non-terminal AssertionDeclaration
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "parol_runtime::derive_builder")]
+pub struct AssertionDeclaration {
+    pub assertion_keyword: Box<AssertionKeyword>,
+    pub assertion_declaration_opt: Option<AssertionDeclarationOpt>,
+    pub assertion_declaration_opt0: Option<AssertionDeclarationOpt0>,
+    pub l_brace: Box<LBrace>,
+    pub expression: Box<Expression>,
+    pub assertion_declaration_opt1: Option<AssertionDeclarationOpt1>,
+    pub r_brace: Box<RBrace>,
+}
+
+///
+/// Type derived for non-terminal AssertionDeclarationOpt
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "parol_runtime::derive_builder")]
+pub struct AssertionDeclarationOpt {
+    pub identifier: Box<Identifier>,
+}
+
+///
+/// Type derived for non-terminal AssertionDeclarationOpt0
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "parol_runtime::derive_builder")]
+pub struct AssertionDeclarationOpt0 {
+    pub always_ff_event_list: Box<AlwaysFfEventList>,
+}
+
+///
+/// Type derived for non-terminal AssertionDeclarationOpt1
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "parol_runtime::derive_builder")]
+pub struct AssertionDeclarationOpt1 {
+    pub implication: Box<Implication>,
+    pub expression: Box<Expression>,
+}
+
+///
+/// Type derived for non-terminal AssertionKeyword
+///
+#[allow(dead_code)]
+#[derive(Debug, Clone)]
+pub enum AssertionKeyword {
+    Assert(AssertionKeywordAssert),
+    Assume(AssertionKeywordAssume),
+    Cover(AssertionKeywordCover),
+}
+
+///
+/// Type derived for non-terminal AssertionStatement
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "parol_runtime::derive_builder")]
+pub struct AssertionStatement {
+    pub assertion_keyword: Box<AssertionKeyword>,
+    pub expression: Box<Expression>,
+    pub semicolon: Box<Semicolon>,
+}
+
 ///
 /// Type derived for non-terminal Assign
 ///
@@ -6353,6 +6686,37 @@ pub struct AssignmentOperatorToken {
     pub comments: Box<Comments>,
 }
 
+///
+/// Type derived for non-terminal Assume
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "parol_runtime::derive_builder")]
+pub struct Assume {
+    pub assume_token: crate::veryl_token::VerylToken,
+}
+
+///
+/// Type derived for non-terminal AssumeTerm
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "parol_runtime::derive_builder")]
+pub struct AssumeTerm {
+    pub assume_term: crate::veryl_token::Token, /* (?-u:\b)assume(?-u:\b) */
+}
+
+///
+/// Type derived for non-terminal AssumeToken
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "parol_runtime::derive_builder")]
+pub struct AssumeToken {
+    pub assume_term: crate::veryl_token::Token,
+    pub comments: Box<Comments>,
+}
+
 ///
 /// Type derived for non-terminal Attribute
 ///
@@ -6367,6 +6731,43 @@ pub struct Attribute {
     pub r_bracket: Box<RBracket>,
 }
 
+///
+/// Type derived for non-terminal AttributeArray
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "parol_runtime::derive_builder")]
+pub struct AttributeArray {
+    pub l_bracket: Box<LBracket>,
+    pub attribute_value_list: Box<AttributeValueList>,
+    pub r_bracket: Box<RBracket>,
+}
+
+///
+/// Type derived for non-terminal AttributeAssignment
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "parol_runtime::derive_builder")]
+pub struct AttributeAssignment {
+    pub identifier: Box<Identifier>,
+    pub equ: Box<Equ>,
+    pub attribute_value: Box<AttributeValue>,
+}
+
+///
+/// Type derived for non-terminal AttributeCall
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "parol_runtime::derive_builder")]
+pub struct AttributeCall {
+    pub identifier: Box<Identifier>,
+    pub l_paren: Box<LParen>,
+    pub attribute_list: Box<AttributeList>,
+    pub r_paren: Box<RParen>,
+}
+
 ///
 /// Type derived for non-terminal AttributeItem
 ///
@@ -6375,6 +6776,8 @@ pub struct Attribute {
 pub enum AttributeItem {
     Identifier(AttributeItemIdentifier),
     StringLiteral(AttributeItemStringLiteral),
+    AttributeAssignment(AttributeItemAttributeAssignment),
+    AttributeCall(AttributeItemAttributeCall),
 }
 
 ///
@@ -6422,6 +6825,51 @@ pub struct AttributeOpt {
     pub r_paren: Box<RParen>,
 }
 
+///
+/// Type derived for non-terminal AttributeValue
+///
+#[allow(dead_code)]
+#[derive(Debug, Clone)]
+pub enum AttributeValue {
+    IntegralNumber(AttributeValueIntegralNumber),
+    StringLiteral(AttributeValueStringLiteral),
+    Identifier(AttributeValueIdentifier),
+    AttributeArray(AttributeValueAttributeArray),
+}
+
+///
+/// Type derived for non-terminal AttributeValueList
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "parol_runtime::derive_builder")]
+pub struct AttributeValueList {
+    pub attribute_value: Box<AttributeValue>,
+    pub attribute_value_list_list: Vec<AttributeValueListList>,
+    pub attribute_value_list_opt: Option<AttributeValueListOpt>,
+}
+
+///
+/// Type derived for non-terminal AttributeValueListList
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "parol_runtime::derive_builder")]
+pub struct AttributeValueListList {
+    pub comma: Box<Comma>,
+    pub attribute_value: Box<AttributeValue>,
+}
+
+///
+/// Type derived for non-terminal AttributeValueListOpt
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "parol_runtime::derive_builder")]
+pub struct AttributeValueListOpt {
+    pub comma: Box<Comma>,
+}
+
 ///
 /// Type derived for non-terminal BaseLess
 ///
@@ -7228,6 +7676,37 @@ pub struct ConverseToken {
     pub comments: Box<Comments>,
 }
 
+///
+/// Type derived for non-terminal Cover
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "parol_runtime::derive_builder")]
+pub struct Cover {
+    pub cover_token: crate::veryl_token::VerylToken,
+}
+
+///
+/// Type derived for non-terminal CoverTerm
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "parol_runtime::derive_builder")]
+pub struct CoverTerm {
+    pub cover_term: crate::veryl_token::Token, /* (?-u:\b)cover(?-u:\b) */
+}
+
+///
+/// Type derived for non-terminal CoverToken
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "parol_runtime::derive_builder")]
+pub struct CoverToken {
+    pub cover_term: crate::veryl_token::Token,
+    pub comments: Box<Comments>,
+}
+
 ///
 /// Type derived for non-terminal Defaul
 ///
@@ -8761,6 +9240,7 @@ pub enum GenerateItem {
     AlwaysFfDeclaration(GenerateItemAlwaysFfDeclaration),
     AlwaysCombDeclaration(GenerateItemAlwaysCombDeclaration),
     AssignDeclaration(GenerateItemAssignDeclaration),
+    AssertionDeclaration(GenerateItemAssertionDeclaration),
     ConnectDeclaration(GenerateItemConnectDeclaration),
     FunctionDeclaration(GenerateItemFunctionDeclaration),
     GenerateIfDeclaration(GenerateItemGenerateIfDeclaration),
@@ -9301,6 +9781,37 @@ pub struct IfToken {
     pub comments: Box<Comments>,
 }
 
+///
+/// Type derived for non-terminal Implication
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "parol_runtime::derive_builder")]
+pub struct Implication {
+    pub implication_token: crate::veryl_token::VerylToken,
+}
+
+///
+/// Type derived for non-terminal ImplicationTerm
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "parol_runtime::derive_builder")]
+pub struct ImplicationTerm {
+    pub implication_term: crate::veryl_token::Token, /* \|->|\|=> */
+}
+
+///
+/// Type derived for non-terminal ImplicationToken
+///
+#[allow(dead_code)]
+#[derive(Builder, Debug, Clone)]
+#[builder(crate = "parol_runtime::derive_builder")]
+pub struct ImplicationToken {
+    pub implication_term: crate::veryl_token::Token,
+    pub comments: Box<Comments>,
+}
+
 ///
 /// Type derived for non-terminal Import
 ///
@@ -12679,6 +13190,7 @@ pub enum Statement {
     ForStatement(StatementForStatement),
     CaseStatement(StatementCaseStatement),
     SwitchStatement(StatementSwitchStatement),
+    AssertionStatement(StatementAssertionStatement),
 }
 
 ///
@@ -14027,6 +14539,15 @@ pub enum ASTType {
     As(As),
     AsTerm(AsTerm),
     AsToken(AsToken),
+    Assert(Assert),
+    AssertTerm(AssertTerm),
+    AssertToken(AssertToken),
+    AssertionDeclaration(AssertionDeclaration),
+    AssertionDeclarationOpt(Option<AssertionDeclarationOpt>),
+    AssertionDeclarationOpt0(Option<AssertionDeclarationOpt0>),
+    AssertionDeclarationOpt1(Option<AssertionDeclarationOpt1>),
+    AssertionKeyword(AssertionKeyword),
+    AssertionStatement(AssertionStatement),
     Assign(Assign),
     AssignConcatenationItem(AssignConcatenationItem),
     AssignConcatenationList(AssignConcatenationList),
@@ -14041,12 +14562,22 @@ pub enum ASTType {
     AssignmentOperator(AssignmentOperator),
     AssignmentOperatorTerm(AssignmentOperatorTerm),
     AssignmentOperatorToken(AssignmentOperatorToken),
+    Assume(Assume),
+    AssumeTerm(AssumeTerm),
+    AssumeToken(AssumeToken),
     Attribute(Attribute),
+    AttributeArray(AttributeArray),
+    AttributeAssignment(AttributeAssignment),
+    AttributeCall(AttributeCall),
     AttributeItem(AttributeItem),
     AttributeList(AttributeList),
     AttributeListList(Vec<AttributeListList>),
     AttributeListOpt(Option<AttributeListOpt>),
     AttributeOpt(Option<AttributeOpt>),
+    AttributeValue(AttributeValue),
+    AttributeValueList(AttributeValueList),
+    AttributeValueListList(Vec<AttributeValueListList>),
+    AttributeValueListOpt(Option<AttributeValueListOpt>),
     BaseLess(BaseLess),
     BaseLessTerm(BaseLessTerm),
     BaseLessToken(BaseLessToken),
@@ -14120,6 +14651,9 @@ pub enum ASTType {
     Converse(Converse),
     ConverseTerm(ConverseTerm),
     ConverseToken(ConverseToken),
+    Cover(Cover),
+    CoverTerm(CoverTerm),
+    CoverToken(CoverToken),
     Defaul(Defaul),
     DefaultTerm(DefaultTerm),
     DefaultToken(DefaultToken),
@@ -14307,6 +14841,9 @@ pub enum ASTType {
     IfStatementOpt(Option<IfStatementOpt>),
     IfTerm(IfTerm),
     IfToken(IfToken),
+    Implication(Implication),
+    ImplicationTerm(ImplicationTerm),
+    ImplicationToken(ImplicationToken),
     Import(Import),
     ImportDeclaration(ImportDeclaration),
     ImportDeclarationOpt(Option<ImportDeclarationOpt>),
@@ -14886,7 +15423,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
 
     /// Semantic action for production 4:
     ///
-    /// `BasedTerm: <INITIAL, Generic>/(?:[0-9]+(?:_[0-9]+)*)?'s?[bodh][0-9a-fA-FxzXZ]+(?:_[0-9a-fA-FxzXZ]+)*/ : Token;`
+    /// `BasedTerm: <INITIAL, Generic, Attr>/(?:[0-9]+(?:_[0-9]+)*)?'s?[bodh][0-9a-fA-FxzXZ]+(?:_[0-9a-fA-FxzXZ]+)*/ : Token;`
     ///
     #[parol_runtime::function_name::named]
     fn based_term(&mut self, based_term: &ParseTreeType<'t>) -> Result<()> {
@@ -14905,7 +15442,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
 
     /// Semantic action for production 5:
     ///
-    /// `AllBitTerm: <INITIAL, Generic>/(?:[0-9]+(?:_[0-9]+)*)?'[01xzXZ]/ : Token;`
+    /// `AllBitTerm: <INITIAL, Generic, Attr>/(?:[0-9]+(?:_[0-9]+)*)?'[01xzXZ]/ : Token;`
     ///
     #[parol_runtime::function_name::named]
     fn all_bit_term(&mut self, all_bit_term: &ParseTreeType<'t>) -> Result<()> {
@@ -14924,7 +15461,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
 
     /// Semantic action for production 6:
     ///
-    /// `BaseLessTerm: <INITIAL, Generic>/[0-9]+(?:_[0-9]+)*/ : Token;`
+    /// `BaseLessTerm: <INITIAL, Generic, Attr>/[0-9]+(?:_[0-9]+)*/ : Token;`
     ///
     #[parol_runtime::function_name::named]
     fn base_less_term(&mut self, base_less_term: &ParseTreeType<'t>) -> Result<()> {
@@ -15001,6 +15538,26 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
 
     /// Semantic action for production 10:
     ///
+    /// `ImplicationTerm: "\|->|\|=>" : Token;`
+    ///
+    #[parol_runtime::function_name::named]
+    fn implication_term(&mut self, implication_term: &ParseTreeType<'t>) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let implication_term = implication_term
+            .token()?
+            .try_into()
+            .map_err(parol_runtime::ParolError::UserError)?;
+        let implication_term_built = ImplicationTerm { implication_term };
+        // Calling user action here
+        self.user_grammar
+            .implication_term(&implication_term_built)?;
+        self.push(ASTType::ImplicationTerm(implication_term_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 11:
+    ///
     /// `AssignmentOperatorTerm: "\+=|-=|\*=|/=|%=|&=|\|=|\^=|<<=|>>=|<<<=|>>>=" : Token;`
     ///
     #[parol_runtime::function_name::named]
@@ -15027,7 +15584,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 11:
+    /// Semantic action for production 12:
     ///
     /// `DiamondOperatorTerm: '<>' : Token;`
     ///
@@ -15052,7 +15609,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 12:
+    /// Semantic action for production 13:
     ///
     /// `Operator12Term: "\*\*" : Token;`
     ///
@@ -15071,7 +15628,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 13:
+    /// Semantic action for production 14:
     ///
     /// `Operator11Term: "/|%" : Token;`
     ///
@@ -15090,7 +15647,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 14:
+    /// Semantic action for production 15:
     ///
     /// `Operator10Term: "\+|-" : Token;`
     ///
@@ -15109,7 +15666,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 15:
+    /// Semantic action for production 16:
     ///
     /// `Operator09Term: "<<<|>>>|<<|>>" : Token;`
     ///
@@ -15128,7 +15685,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 16:
+    /// Semantic action for production 17:
     ///
     /// `Operator08Term: "<=|>=|<:|>:" : Token;`
     ///
@@ -15147,7 +15704,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 17:
+    /// Semantic action for production 18:
     ///
     /// `Operator07Term: "===|==\?|!==|!=\?|==|!=" : Token;`
     ///
@@ -15166,7 +15723,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 18:
+    /// Semantic action for production 19:
     ///
     /// `Operator03Term: "&&" : Token;`
     ///
@@ -15185,7 +15742,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 19:
+    /// Semantic action for production 20:
     ///
     /// `Operator02Term: "\|\|" : Token;`
     ///
@@ -15204,7 +15761,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 20:
+    /// Semantic action for production 21:
     ///
     /// `Operator06Term: "&" : Token;`
     ///
@@ -15223,7 +15780,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 21:
+    /// Semantic action for production 22:
     ///
     /// `Operator05Term: "\^~|\^|~\^" : Token;`
     ///
@@ -15242,7 +15799,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 22:
+    /// Semantic action for production 23:
     ///
     /// `Operator04Term: "\|" : Token;`
     ///
@@ -15261,7 +15818,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 23:
+    /// Semantic action for production 24:
     ///
     /// `UnaryOperatorTerm: "~&|~\||!|~" : Token;`
     ///
@@ -15286,7 +15843,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 24:
+    /// Semantic action for production 25:
     ///
     /// `ColonColonLAngleTerm: <INITIAL, Generic>'::<' : Token;`
     ///
@@ -15314,7 +15871,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 25:
+    /// Semantic action for production 26:
     ///
     /// `ColonColonTerm: <INITIAL, Generic>'::' : Token;`
     ///
@@ -15334,7 +15891,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 26:
+    /// Semantic action for production 27:
     ///
     /// `ColonTerm: <INITIAL, Generic, ClockDomain>':' : Token;`
     ///
@@ -15353,7 +15910,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 27:
+    /// Semantic action for production 28:
     ///
     /// `CommaTerm: <INITIAL, Generic, Attr>',' : Token;`
     ///
@@ -15372,7 +15929,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 28:
+    /// Semantic action for production 29:
     ///
     /// `DotDotEquTerm: <INITIAL, Generic>'..=' : Token;`
     ///
@@ -15392,7 +15949,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 29:
+    /// Semantic action for production 30:
     ///
     /// `DotDotTerm: <INITIAL, Generic>'..' : Token;`
     ///
@@ -15411,7 +15968,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 30:
+    /// Semantic action for production 31:
     ///
     /// `DotTerm: <INITIAL, Generic>'.' : Token;`
     ///
@@ -15430,9 +15987,9 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 31:
+    /// Semantic action for production 32:
     ///
-    /// `EquTerm: <INITIAL, Generic>'=' : Token;`
+    /// `EquTerm: <INITIAL, Generic, Attr>'=' : Token;`
     ///
     #[parol_runtime::function_name::named]
     fn equ_term(&mut self, equ_term: &ParseTreeType<'t>) -> Result<()> {
@@ -15449,7 +16006,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 32:
+    /// Semantic action for production 33:
     ///
     /// `HashTerm: <INITIAL, Generic, Attr>'#' : Token;`
     ///
@@ -15468,7 +16025,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 33:
+    /// Semantic action for production 34:
     ///
     /// `LAngleTerm: <INITIAL, Generic>'<' : Token;`
     ///
@@ -15487,7 +16044,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 34:
+    /// Semantic action for production 35:
     ///
     /// `QuestionTerm: '?' : Token;`
     ///
@@ -15506,7 +16063,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 35:
+    /// Semantic action for production 36:
     ///
     /// `QuoteTerm: <ClockDomain>"'" : Token;`
     ///
@@ -15525,7 +16082,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 36:
+    /// Semantic action for production 37:
     ///
     /// `QuoteLBraceTerm: <INITIAL, Generic>"'\{" : Token;`
     ///
@@ -15545,7 +16102,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 37:
+    /// Semantic action for production 38:
     ///
     /// `LBraceTerm: <INITIAL, Embed, Generic, Attr>'{' : Token;`
     ///
@@ -15564,7 +16121,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 38:
+    /// Semantic action for production 39:
     ///
     /// `LBracketTerm: <INITIAL, Generic, Attr>'[' : Token;`
     ///
@@ -15583,7 +16140,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 39:
+    /// Semantic action for production 40:
     ///
     /// `LParenTerm: <INITIAL, Generic, Attr>'(' : Token;`
     ///
@@ -15602,7 +16159,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 40:
+    /// Semantic action for production 41:
     ///
     /// `RAngleTerm: <INITIAL, Generic>'>' : Token;`
     ///
@@ -15621,7 +16178,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 41:
+    /// Semantic action for production 42:
     ///
     /// `RBraceTerm: <INITIAL, Embed, Generic, Attr>'}' : Token;`
     ///
@@ -15640,7 +16197,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 42:
+    /// Semantic action for production 43:
     ///
     /// `RBracketTerm: <INITIAL, Generic, Attr>']' : Token;`
     ///
@@ -15659,7 +16216,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 43:
+    /// Semantic action for production 44:
     ///
     /// `RParenTerm: <INITIAL, Generic, Attr>')' : Token;`
     ///
@@ -15678,7 +16235,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 44:
+    /// Semantic action for production 45:
     ///
     /// `SemicolonTerm: <INITIAL, Generic>';' : Token;`
     ///
@@ -15697,7 +16254,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 45:
+    /// Semantic action for production 46:
     ///
     /// `StarTerm: <INITIAL, Generic>'*' : Token;`
     ///
@@ -15716,7 +16273,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 46:
+    /// Semantic action for production 47:
     ///
     /// `AliasTerm: <INITIAL, Generic, ClockDomain>/(?-u:\b)alias(?-u:\b)/ : Token;`
     ///
@@ -15735,7 +16292,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 47:
+    /// Semantic action for production 48:
     ///
     /// `AlwaysCombTerm: <INITIAL, Generic, ClockDomain>/(?-u:\b)always_comb(?-u:\b)/ : Token;`
     ///
@@ -15755,7 +16312,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 48:
+    /// Semantic action for production 49:
     ///
     /// `AlwaysFfTerm: <INITIAL, Generic, ClockDomain>/(?-u:\b)always_ff(?-u:\b)/ : Token;`
     ///
@@ -15774,7 +16331,26 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 49:
+    /// Semantic action for production 50:
+    ///
+    /// `AssertTerm: <INITIAL, Generic, ClockDomain>/(?-u:\b)assert(?-u:\b)/ : Token;`
+    ///
+    #[parol_runtime::function_name::named]
+    fn assert_term(&mut self, assert_term: &ParseTreeType<'t>) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let assert_term = assert_term
+            .token()?
+            .try_into()
+            .map_err(parol_runtime::ParolError::UserError)?;
+        let assert_term_built = AssertTerm { assert_term };
+        // Calling user action here
+        self.user_grammar.assert_term(&assert_term_built)?;
+        self.push(ASTType::AssertTerm(assert_term_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 51:
     ///
     /// `AssignTerm: <INITIAL, Generic, ClockDomain>/(?-u:\b)assign(?-u:\b)/ : Token;`
     ///
@@ -15793,7 +16369,26 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 50:
+    /// Semantic action for production 52:
+    ///
+    /// `AssumeTerm: <INITIAL, Generic, ClockDomain>/(?-u:\b)assume(?-u:\b)/ : Token;`
+    ///
+    #[parol_runtime::function_name::named]
+    fn assume_term(&mut self, assume_term: &ParseTreeType<'t>) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let assume_term = assume_term
+            .token()?
+            .try_into()
+            .map_err(parol_runtime::ParolError::UserError)?;
+        let assume_term_built = AssumeTerm { assume_term };
+        // Calling user action here
+        self.user_grammar.assume_term(&assume_term_built)?;
+        self.push(ASTType::AssumeTerm(assume_term_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 53:
     ///
     /// `AsTerm: <INITIAL, Generic, ClockDomain>/(?-u:\b)as(?-u:\b)/ : Token;`
     ///
@@ -15812,7 +16407,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 51:
+    /// Semantic action for production 54:
     ///
     /// `BitTerm: <INITIAL, Generic, ClockDomain>/(?-u:\b)bit(?-u:\b)/ : Token;`
     ///
@@ -15831,7 +16426,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 52:
+    /// Semantic action for production 55:
     ///
     /// `BoolTerm: <INITIAL, Generic, ClockDomain>/(?-u:\b)bool(?-u:\b)/ : Token;`
     ///
@@ -15850,7 +16445,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 53:
+    /// Semantic action for production 56:
     ///
     /// `CaseTerm: <INITIAL, Generic, ClockDomain>/(?-u:\b)case(?-u:\b)/ : Token;`
     ///
@@ -15869,7 +16464,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 54:
+    /// Semantic action for production 57:
     ///
     /// `ClockTerm: <INITIAL, Generic, ClockDomain>/(?-u:\b)clock(?-u:\b)/ : Token;`
     ///
@@ -15888,7 +16483,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 55:
+    /// Semantic action for production 58:
     ///
     /// `ClockPosedgeTerm: <INITIAL, Generic, ClockDomain>/(?-u:\b)clock_posedge(?-u:\b)/ : Token;`
     ///
@@ -15908,7 +16503,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 56:
+    /// Semantic action for production 59:
     ///
     /// `ClockNegedgeTerm: <INITIAL, Generic, ClockDomain>/(?-u:\b)clock_negedge(?-u:\b)/ : Token;`
     ///
@@ -15928,7 +16523,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 57:
+    /// Semantic action for production 60:
     ///
     /// `ConnectTerm: <INITIAL, Generic, ClockDomain>/(?-u:\b)connect(?-u:\b)/ : Token;`
     ///
@@ -15947,7 +16542,26 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 58:
+    /// Semantic action for production 61:
+    ///
+    /// `CoverTerm: <INITIAL, Generic, ClockDomain>/(?-u:\b)cover(?-u:\b)/ : Token;`
+    ///
+    #[parol_runtime::function_name::named]
+    fn cover_term(&mut self, cover_term: &ParseTreeType<'t>) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let cover_term = cover_term
+            .token()?
+            .try_into()
+            .map_err(parol_runtime::ParolError::UserError)?;
+        let cover_term_built = CoverTerm { cover_term };
+        // Calling user action here
+        self.user_grammar.cover_term(&cover_term_built)?;
+        self.push(ASTType::CoverTerm(cover_term_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 62:
     ///
     /// `ConstTerm: <INITIAL, Generic, ClockDomain>/(?-u:\b)const(?-u:\b)/ : Token;`
     ///
@@ -15966,7 +16580,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 59:
+    /// Semantic action for production 63:
     ///
     /// `ConverseTerm: <INITIAL, Generic, ClockDomain>/(?-u:\b)converse(?-u:\b)/ : Token;`
     ///
@@ -15985,7 +16599,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 60:
+    /// Semantic action for production 64:
     ///
     /// `DefaultTerm: <INITIAL, Generic, ClockDomain>/(?-u:\b)default(?-u:\b)/ : Token;`
     ///
@@ -16004,7 +16618,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 61:
+    /// Semantic action for production 65:
     ///
     /// `ElseTerm: <INITIAL, Generic, ClockDomain>/(?-u:\b)else(?-u:\b)/ : Token;`
     ///
@@ -16023,7 +16637,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 62:
+    /// Semantic action for production 66:
     ///
     /// `EmbedTerm: <INITIAL, Generic, ClockDomain>/(?-u:\b)embed(?-u:\b)/ : Token;`
     ///
@@ -16042,7 +16656,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 63:
+    /// Semantic action for production 67:
     ///
     /// `EnumTerm: <INITIAL, Generic, ClockDomain>/(?-u:\b)enum(?-u:\b)/ : Token;`
     ///
@@ -16061,7 +16675,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 64:
+    /// Semantic action for production 68:
     ///
     /// `F32Term: <INITIAL, Generic, ClockDomain>/(?-u:\b)f32(?-u:\b)/ : Token;`
     ///
@@ -16080,7 +16694,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 65:
+    /// Semantic action for production 69:
     ///
     /// `F64Term: <INITIAL, Generic, ClockDomain>/(?-u:\b)f64(?-u:\b)/ : Token;`
     ///
@@ -16099,7 +16713,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 66:
+    /// Semantic action for production 70:
     ///
     /// `FalseTerm: <INITIAL, Generic, ClockDomain>/(?-u:\b)false(?-u:\b)/ : Token;`
     ///
@@ -16118,7 +16732,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 67:
+    /// Semantic action for production 71:
     ///
     /// `FinalTerm: <INITIAL, Generic, ClockDomain>/(?-u:\b)final(?-u:\b)/ : Token;`
     ///
@@ -16137,7 +16751,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 68:
+    /// Semantic action for production 72:
     ///
     /// `ForTerm: <INITIAL, Generic, ClockDomain>/(?-u:\b)for(?-u:\b)/ : Token;`
     ///
@@ -16156,7 +16770,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 69:
+    /// Semantic action for production 73:
     ///
     /// `FunctionTerm: <INITIAL, Generic, ClockDomain>/(?-u:\b)function(?-u:\b)/ : Token;`
     ///
@@ -16175,7 +16789,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 70:
+    /// Semantic action for production 74:
     ///
     /// `I8Term: <INITIAL, Generic, ClockDomain>/(?-u:\b)i8(?-u:\b)/ : Token;`
     ///
@@ -16194,7 +16808,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 71:
+    /// Semantic action for production 75:
     ///
     /// `I16Term: <INITIAL, Generic, ClockDomain>/(?-u:\b)i16(?-u:\b)/ : Token;`
     ///
@@ -16213,7 +16827,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 72:
+    /// Semantic action for production 76:
     ///
     /// `I32Term: <INITIAL, Generic, ClockDomain>/(?-u:\b)i32(?-u:\b)/ : Token;`
     ///
@@ -16232,7 +16846,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 73:
+    /// Semantic action for production 77:
     ///
     /// `I64Term: <INITIAL, Generic, ClockDomain>/(?-u:\b)i64(?-u:\b)/ : Token;`
     ///
@@ -16251,7 +16865,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 74:
+    /// Semantic action for production 78:
     ///
     /// `IfResetTerm: <INITIAL, Generic, ClockDomain>/(?-u:\b)if_reset(?-u:\b)/ : Token;`
     ///
@@ -16270,7 +16884,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 75:
+    /// Semantic action for production 79:
     ///
     /// `IfTerm: <INITIAL, Generic, ClockDomain>/(?-u:\b)if(?-u:\b)/ : Token;`
     ///
@@ -16289,7 +16903,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 76:
+    /// Semantic action for production 80:
     ///
     /// `ImportTerm: <INITIAL, Generic, ClockDomain>/(?-u:\b)import(?-u:\b)/ : Token;`
     ///
@@ -16308,7 +16922,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 77:
+    /// Semantic action for production 81:
     ///
     /// `IncludeTerm: <INITIAL, Generic, ClockDomain>/(?-u:\b)include(?-u:\b)/ : Token;`
     ///
@@ -16327,7 +16941,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 78:
+    /// Semantic action for production 82:
     ///
     /// `InitialTerm: <INITIAL, Generic, ClockDomain>/(?-u:\b)initial(?-u:\b)/ : Token;`
     ///
@@ -16346,7 +16960,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 79:
+    /// Semantic action for production 83:
     ///
     /// `InoutTerm: <INITIAL, Generic, ClockDomain>/(?-u:\b)inout(?-u:\b)/ : Token;`
     ///
@@ -16365,7 +16979,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 80:
+    /// Semantic action for production 84:
     ///
     /// `InputTerm: <INITIAL, Generic, ClockDomain>/(?-u:\b)input(?-u:\b)/ : Token;`
     ///
@@ -16384,7 +16998,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 81:
+    /// Semantic action for production 85:
     ///
     /// `InsideTerm: <INITIAL, Generic, ClockDomain>/(?-u:\b)inside(?-u:\b)/ : Token;`
     ///
@@ -16403,7 +17017,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 82:
+    /// Semantic action for production 86:
     ///
     /// `InstTerm: <INITIAL, Generic, ClockDomain>/(?-u:\b)inst(?-u:\b)/ : Token;`
     ///
@@ -16422,7 +17036,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 83:
+    /// Semantic action for production 87:
     ///
     /// `InterfaceTerm: <INITIAL, Generic, ClockDomain>/(?-u:\b)interface(?-u:\b)/ : Token;`
     ///
@@ -16441,7 +17055,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 84:
+    /// Semantic action for production 88:
     ///
     /// `InTerm: <INITIAL, Generic, ClockDomain>/(?-u:\b)in(?-u:\b)/ : Token;`
     ///
@@ -16460,7 +17074,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 85:
+    /// Semantic action for production 89:
     ///
     /// `LetTerm: <INITIAL, Generic, ClockDomain>/(?-u:\b)let(?-u:\b)/ : Token;`
     ///
@@ -16479,7 +17093,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 86:
+    /// Semantic action for production 90:
     ///
     /// `LogicTerm: <INITIAL, Generic, ClockDomain>/(?-u:\b)logic(?-u:\b)/ : Token;`
     ///
@@ -16498,7 +17112,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 87:
+    /// Semantic action for production 91:
     ///
     /// `LsbTerm: <INITIAL, Generic, ClockDomain>/(?-u:\b)lsb(?-u:\b)/ : Token;`
     ///
@@ -16517,7 +17131,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 88:
+    /// Semantic action for production 92:
     ///
     /// `ModportTerm: <INITIAL, Generic, ClockDomain>/(?-u:\b)modport(?-u:\b)/ : Token;`
     ///
@@ -16536,7 +17150,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 89:
+    /// Semantic action for production 93:
     ///
     /// `ModuleTerm: <INITIAL, Generic, ClockDomain>/(?-u:\b)module(?-u:\b)/ : Token;`
     ///
@@ -16555,7 +17169,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 90:
+    /// Semantic action for production 94:
     ///
     /// `MsbTerm: <INITIAL, Generic, ClockDomain>/(?-u:\b)msb(?-u:\b)/ : Token;`
     ///
@@ -16574,7 +17188,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 91:
+    /// Semantic action for production 95:
     ///
     /// `OutputTerm: <INITIAL, Generic, ClockDomain>/(?-u:\b)output(?-u:\b)/ : Token;`
     ///
@@ -16593,7 +17207,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 92:
+    /// Semantic action for production 96:
     ///
     /// `OutsideTerm: <INITIAL, Generic, ClockDomain>/(?-u:\b)outside(?-u:\b)/ : Token;`
     ///
@@ -16612,7 +17226,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 93:
+    /// Semantic action for production 97:
     ///
     /// `PackageTerm: <INITIAL, Generic, ClockDomain>/(?-u:\b)package(?-u:\b)/ : Token;`
     ///
@@ -16631,7 +17245,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 94:
+    /// Semantic action for production 98:
     ///
     /// `ParamTerm: <INITIAL, Generic, ClockDomain>/(?-u:\b)param(?-u:\b)/ : Token;`
     ///
@@ -16650,7 +17264,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 95:
+    /// Semantic action for production 99:
     ///
     /// `ProtoTerm: <INITIAL, Generic, ClockDomain>/(?-u:\b)proto(?-u:\b)/ : Token;`
     ///
@@ -16669,7 +17283,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 96:
+    /// Semantic action for production 100:
     ///
     /// `PubTerm: <INITIAL, Generic, ClockDomain>/(?-u:\b)pub(?-u:\b)/ : Token;`
     ///
@@ -16688,7 +17302,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 97:
+    /// Semantic action for production 101:
     ///
     /// `RepeatTerm: <INITIAL, Generic, ClockDomain>/(?-u:\b)repeat(?-u:\b)/ : Token;`
     ///
@@ -16707,7 +17321,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 98:
+    /// Semantic action for production 102:
     ///
     /// `ResetTerm: <INITIAL, Generic, ClockDomain>/(?-u:\b)reset(?-u:\b)/ : Token;`
     ///
@@ -16726,7 +17340,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 99:
+    /// Semantic action for production 103:
     ///
     /// `ResetAsyncHighTerm: <INITIAL, Generic, ClockDomain>/(?-u:\b)reset_async_high(?-u:\b)/ : Token;`
     ///
@@ -16751,7 +17365,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 100:
+    /// Semantic action for production 104:
     ///
     /// `ResetAsyncLowTerm: <INITIAL, Generic, ClockDomain>/(?-u:\b)reset_async_low(?-u:\b)/ : Token;`
     ///
@@ -16776,7 +17390,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 101:
+    /// Semantic action for production 105:
     ///
     /// `ResetSyncHighTerm: <INITIAL, Generic, ClockDomain>/(?-u:\b)reset_sync_high(?-u:\b)/ : Token;`
     ///
@@ -16801,7 +17415,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 102:
+    /// Semantic action for production 106:
     ///
     /// `ResetSyncLowTerm: <INITIAL, Generic, ClockDomain>/(?-u:\b)reset_sync_low(?-u:\b)/ : Token;`
     ///
@@ -16826,7 +17440,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 103:
+    /// Semantic action for production 107:
     ///
     /// `ReturnTerm: <INITIAL, Generic, ClockDomain>/(?-u:\b)return(?-u:\b)/ : Token;`
     ///
@@ -16845,7 +17459,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 104:
+    /// Semantic action for production 108:
     ///
     /// `BreakTerm: <INITIAL, Generic, ClockDomain>/(?-u:\b)break(?-u:\b)/ : Token;`
     ///
@@ -16864,7 +17478,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 105:
+    /// Semantic action for production 109:
     ///
     /// `SameTerm: <INITIAL, Generic, ClockDomain>/(?-u:\b)same(?-u:\b)/ : Token;`
     ///
@@ -16883,7 +17497,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 106:
+    /// Semantic action for production 110:
     ///
     /// `SignedTerm: <INITIAL, Generic, ClockDomain>/(?-u:\b)signed(?-u:\b)/ : Token;`
     ///
@@ -16902,7 +17516,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 107:
+    /// Semantic action for production 111:
     ///
     /// `StepTerm: <INITIAL, Generic, ClockDomain>/(?-u:\b)step(?-u:\b)/ : Token;`
     ///
@@ -16921,7 +17535,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 108:
+    /// Semantic action for production 112:
     ///
     /// `StringTerm: <INITIAL, Generic, ClockDomain>/(?-u:\b)string(?-u:\b)/ : Token;`
     ///
@@ -16940,7 +17554,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 109:
+    /// Semantic action for production 113:
     ///
     /// `StructTerm: <INITIAL, Generic, ClockDomain>/(?-u:\b)struct(?-u:\b)/ : Token;`
     ///
@@ -16959,7 +17573,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 110:
+    /// Semantic action for production 114:
     ///
     /// `SwitchTerm: <INITIAL, Generic, ClockDomain>/(?-u:\b)switch(?-u:\b)/ : Token;`
     ///
@@ -16978,7 +17592,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 111:
+    /// Semantic action for production 115:
     ///
     /// `TriTerm: <INITIAL, Generic, ClockDomain>/(?-u:\b)tri(?-u:\b)/ : Token;`
     ///
@@ -16997,7 +17611,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 112:
+    /// Semantic action for production 116:
     ///
     /// `TrueTerm: <INITIAL, Generic, ClockDomain>/(?-u:\b)true(?-u:\b)/ : Token;`
     ///
@@ -17016,7 +17630,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 113:
+    /// Semantic action for production 117:
     ///
     /// `TypeTerm: <INITIAL, Generic, ClockDomain>/(?-u:\b)type(?-u:\b)/ : Token;`
     ///
@@ -17035,7 +17649,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 114:
+    /// Semantic action for production 118:
     ///
     /// `U8Term: <INITIAL, Generic, ClockDomain>/(?-u:\b)u8(?-u:\b)/ : Token;`
     ///
@@ -17054,7 +17668,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 115:
+    /// Semantic action for production 119:
     ///
     /// `U16Term: <INITIAL, Generic, ClockDomain>/(?-u:\b)u16(?-u:\b)/ : Token;`
     ///
@@ -17073,7 +17687,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 116:
+    /// Semantic action for production 120:
     ///
     /// `U32Term: <INITIAL, Generic, ClockDomain>/(?-u:\b)u32(?-u:\b)/ : Token;`
     ///
@@ -17092,7 +17706,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 117:
+    /// Semantic action for production 121:
     ///
     /// `U64Term: <INITIAL, Generic, ClockDomain>/(?-u:\b)u64(?-u:\b)/ : Token;`
     ///
@@ -17111,7 +17725,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 118:
+    /// Semantic action for production 122:
     ///
     /// `UnionTerm: <INITIAL, Generic, ClockDomain>/(?-u:\b)union(?-u:\b)/ : Token;`
     ///
@@ -17130,7 +17744,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 119:
+    /// Semantic action for production 123:
     ///
     /// `UnsafeTerm: <INITIAL, Generic, ClockDomain>/(?-u:\b)unsafe(?-u:\b)/ : Token;`
     ///
@@ -17149,7 +17763,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 120:
+    /// Semantic action for production 124:
     ///
     /// `VarTerm: <INITIAL, Generic, ClockDomain>/(?-u:\b)var(?-u:\b)/ : Token;`
     ///
@@ -17168,7 +17782,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 121:
+    /// Semantic action for production 125:
     ///
     /// `DollarIdentifierTerm: <INITIAL, Generic, ClockDomain>/\$[a-zA-Z_][0-9a-zA-Z_$]*/ : Token;`
     ///
@@ -17193,7 +17807,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 122:
+    /// Semantic action for production 126:
     ///
     /// `IdentifierTerm: <INITIAL, Generic, Attr, ClockDomain>/(?:r#)?[a-zA-Z_][0-9a-zA-Z_$]*/ : Token;`
     ///
@@ -17212,7 +17826,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 123:
+    /// Semantic action for production 127:
     ///
     /// `AnyTerm: <Embed>/[^{}]+/ : Token;`
     ///
@@ -17231,7 +17845,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 124:
+    /// Semantic action for production 128:
     ///
     /// `Comments: CommentsOpt /* Option */;`
     ///
@@ -17247,7 +17861,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 125:
+    /// Semantic action for production 129:
     ///
     /// `CommentsOpt /* Option<T>::Some */: CommentsTerm;`
     ///
@@ -17263,7 +17877,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 126:
+    /// Semantic action for production 130:
     ///
     /// `CommentsOpt /* Option<T>::None */: ;`
     ///
@@ -17275,7 +17889,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 127:
+    /// Semantic action for production 131:
     ///
     /// `StartToken: Comments;`
     ///
@@ -17293,7 +17907,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 128:
+    /// Semantic action for production 132:
     ///
     /// `StringLiteralToken: StringLiteralTerm : Token Comments;`
     ///
@@ -17323,7 +17937,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 129:
+    /// Semantic action for production 133:
     ///
     /// `ExponentToken: ExponentTerm : Token Comments;`
     ///
@@ -17349,7 +17963,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 130:
+    /// Semantic action for production 134:
     ///
     /// `FixedPointToken: FixedPointTerm : Token Comments;`
     ///
@@ -17376,7 +17990,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 131:
+    /// Semantic action for production 135:
     ///
     /// `BasedToken: BasedTerm : Token Comments;`
     ///
@@ -17402,7 +18016,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 132:
+    /// Semantic action for production 136:
     ///
     /// `BaseLessToken: BaseLessTerm : Token Comments;`
     ///
@@ -17428,7 +18042,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 133:
+    /// Semantic action for production 137:
     ///
     /// `AllBitToken: AllBitTerm : Token Comments;`
     ///
@@ -17454,7 +18068,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 134:
+    /// Semantic action for production 138:
     ///
     /// `AssignmentOperatorToken: AssignmentOperatorTerm : Token Comments;`
     ///
@@ -17489,7 +18103,34 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 135:
+    /// Semantic action for production 139:
+    ///
+    /// `ImplicationToken: ImplicationTerm : Token Comments;`
+    ///
+    #[parol_runtime::function_name::named]
+    fn implication_token(
+        &mut self,
+        _implication_term: &ParseTreeType<'t>,
+        _comments: &ParseTreeType<'t>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let comments = pop_item!(self, comments, Comments, context);
+        let implication_term = pop_item!(self, implication_term, ImplicationTerm, context);
+        let implication_token_built = ImplicationToken {
+            implication_term: (&implication_term)
+                .try_into()
+                .map_err(parol_runtime::ParolError::UserError)?,
+            comments: Box::new(comments),
+        };
+        // Calling user action here
+        self.user_grammar
+            .implication_token(&implication_token_built)?;
+        self.push(ASTType::ImplicationToken(implication_token_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 140:
     ///
     /// `DiamondOperatorToken: DiamondOperatorTerm : Token Comments;`
     ///
@@ -17520,7 +18161,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 136:
+    /// Semantic action for production 141:
     ///
     /// `Operator02Token: Operator02Term : Token Comments;`
     ///
@@ -17547,7 +18188,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 137:
+    /// Semantic action for production 142:
     ///
     /// `Operator03Token: Operator03Term : Token Comments;`
     ///
@@ -17574,7 +18215,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 138:
+    /// Semantic action for production 143:
     ///
     /// `Operator04Token: Operator04Term : Token Comments;`
     ///
@@ -17601,7 +18242,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 139:
+    /// Semantic action for production 144:
     ///
     /// `Operator05Token: Operator05Term : Token Comments;`
     ///
@@ -17628,7 +18269,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 140:
+    /// Semantic action for production 145:
     ///
     /// `Operator06Token: Operator06Term : Token Comments;`
     ///
@@ -17655,7 +18296,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 141:
+    /// Semantic action for production 146:
     ///
     /// `Operator07Token: Operator07Term : Token Comments;`
     ///
@@ -17682,7 +18323,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 142:
+    /// Semantic action for production 147:
     ///
     /// `Operator08Token: Operator08Term : Token Comments;`
     ///
@@ -17709,7 +18350,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 143:
+    /// Semantic action for production 148:
     ///
     /// `Operator09Token: Operator09Term : Token Comments;`
     ///
@@ -17736,7 +18377,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 144:
+    /// Semantic action for production 149:
     ///
     /// `Operator10Token: Operator10Term : Token Comments;`
     ///
@@ -17763,7 +18404,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 145:
+    /// Semantic action for production 150:
     ///
     /// `Operator11Token: Operator11Term : Token Comments;`
     ///
@@ -17790,7 +18431,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 146:
+    /// Semantic action for production 151:
     ///
     /// `Operator12Token: Operator12Term : Token Comments;`
     ///
@@ -17817,7 +18458,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 147:
+    /// Semantic action for production 152:
     ///
     /// `UnaryOperatorToken: UnaryOperatorTerm : Token Comments;`
     ///
@@ -17847,7 +18488,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 148:
+    /// Semantic action for production 153:
     ///
     /// `ColonToken: ColonTerm : Token Comments;`
     ///
@@ -17873,7 +18514,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 149:
+    /// Semantic action for production 154:
     ///
     /// `ColonColonLAngleToken: ColonColonLAngleTerm : Token Comments;`
     ///
@@ -17908,7 +18549,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 150:
+    /// Semantic action for production 155:
     ///
     /// `ColonColonToken: ColonColonTerm : Token Comments;`
     ///
@@ -17935,7 +18576,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 151:
+    /// Semantic action for production 156:
     ///
     /// `CommaToken: CommaTerm : Token Comments;`
     ///
@@ -17961,7 +18602,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 152:
+    /// Semantic action for production 157:
     ///
     /// `DotDotToken: DotDotTerm : Token Comments;`
     ///
@@ -17987,7 +18628,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 153:
+    /// Semantic action for production 158:
     ///
     /// `DotDotEquToken: DotDotEquTerm : Token Comments;`
     ///
@@ -18014,7 +18655,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 154:
+    /// Semantic action for production 159:
     ///
     /// `DotToken: DotTerm : Token Comments;`
     ///
@@ -18040,7 +18681,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 155:
+    /// Semantic action for production 160:
     ///
     /// `EquToken: EquTerm : Token Comments;`
     ///
@@ -18066,7 +18707,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 156:
+    /// Semantic action for production 161:
     ///
     /// `HashToken: HashTerm : Token Comments;`
     ///
@@ -18092,7 +18733,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 157:
+    /// Semantic action for production 162:
     ///
     /// `QuestionToken: QuestionTerm : Token Comments;`
     ///
@@ -18118,7 +18759,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 158:
+    /// Semantic action for production 163:
     ///
     /// `QuoteToken: QuoteTerm : Token Comments;`
     ///
@@ -18144,7 +18785,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 159:
+    /// Semantic action for production 164:
     ///
     /// `QuoteLBraceToken: QuoteLBraceTerm : Token Comments;`
     ///
@@ -18174,7 +18815,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 160:
+    /// Semantic action for production 165:
     ///
     /// `LAngleToken: LAngleTerm : Token Comments;`
     ///
@@ -18200,7 +18841,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 161:
+    /// Semantic action for production 166:
     ///
     /// `LBraceToken: LBraceTerm : Token Comments;`
     ///
@@ -18226,7 +18867,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 162:
+    /// Semantic action for production 167:
     ///
     /// `LBracketToken: LBracketTerm : Token Comments;`
     ///
@@ -18252,7 +18893,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 163:
+    /// Semantic action for production 168:
     ///
     /// `LParenToken: LParenTerm : Token Comments;`
     ///
@@ -18278,7 +18919,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 164:
+    /// Semantic action for production 169:
     ///
     /// `MinusColonToken: MinusColonTerm : Token Comments;`
     ///
@@ -18305,7 +18946,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 165:
+    /// Semantic action for production 170:
     ///
     /// `MinusGTToken: MinusGTTerm : Token Comments;`
     ///
@@ -18331,7 +18972,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 166:
+    /// Semantic action for production 171:
     ///
     /// `PlusColonToken: PlusColonTerm : Token Comments;`
     ///
@@ -18358,7 +18999,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 167:
+    /// Semantic action for production 172:
     ///
     /// `RAngleToken: RAngleTerm : Token Comments;`
     ///
@@ -18384,7 +19025,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 168:
+    /// Semantic action for production 173:
     ///
     /// `RBraceToken: RBraceTerm : Token Comments;`
     ///
@@ -18410,7 +19051,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 169:
+    /// Semantic action for production 174:
     ///
     /// `RBracketToken: RBracketTerm : Token Comments;`
     ///
@@ -18436,7 +19077,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 170:
+    /// Semantic action for production 175:
     ///
     /// `RParenToken: RParenTerm : Token Comments;`
     ///
@@ -18462,7 +19103,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 171:
+    /// Semantic action for production 176:
     ///
     /// `SemicolonToken: SemicolonTerm : Token Comments;`
     ///
@@ -18488,7 +19129,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 172:
+    /// Semantic action for production 177:
     ///
     /// `StarToken: StarTerm : Token Comments;`
     ///
@@ -18514,7 +19155,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 173:
+    /// Semantic action for production 178:
     ///
     /// `AliasToken: AliasTerm : Token Comments;`
     ///
@@ -18540,7 +19181,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 174:
+    /// Semantic action for production 179:
     ///
     /// `AlwaysCombToken: AlwaysCombTerm : Token Comments;`
     ///
@@ -18567,7 +19208,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 175:
+    /// Semantic action for production 180:
     ///
     /// `AlwaysFfToken: AlwaysFfTerm : Token Comments;`
     ///
@@ -18593,7 +19234,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 176:
+    /// Semantic action for production 181:
     ///
     /// `AsToken: AsTerm : Token Comments;`
     ///
@@ -18619,7 +19260,33 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 177:
+    /// Semantic action for production 182:
+    ///
+    /// `AssertToken: AssertTerm : Token Comments;`
+    ///
+    #[parol_runtime::function_name::named]
+    fn assert_token(
+        &mut self,
+        _assert_term: &ParseTreeType<'t>,
+        _comments: &ParseTreeType<'t>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let comments = pop_item!(self, comments, Comments, context);
+        let assert_term = pop_item!(self, assert_term, AssertTerm, context);
+        let assert_token_built = AssertToken {
+            assert_term: (&assert_term)
+                .try_into()
+                .map_err(parol_runtime::ParolError::UserError)?,
+            comments: Box::new(comments),
+        };
+        // Calling user action here
+        self.user_grammar.assert_token(&assert_token_built)?;
+        self.push(ASTType::AssertToken(assert_token_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 183:
     ///
     /// `AssignToken: AssignTerm : Token Comments;`
     ///
@@ -18645,7 +19312,33 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 178:
+    /// Semantic action for production 184:
+    ///
+    /// `AssumeToken: AssumeTerm : Token Comments;`
+    ///
+    #[parol_runtime::function_name::named]
+    fn assume_token(
+        &mut self,
+        _assume_term: &ParseTreeType<'t>,
+        _comments: &ParseTreeType<'t>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let comments = pop_item!(self, comments, Comments, context);
+        let assume_term = pop_item!(self, assume_term, AssumeTerm, context);
+        let assume_token_built = AssumeToken {
+            assume_term: (&assume_term)
+                .try_into()
+                .map_err(parol_runtime::ParolError::UserError)?,
+            comments: Box::new(comments),
+        };
+        // Calling user action here
+        self.user_grammar.assume_token(&assume_token_built)?;
+        self.push(ASTType::AssumeToken(assume_token_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 185:
     ///
     /// `BitToken: BitTerm : Token Comments;`
     ///
@@ -18671,7 +19364,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 179:
+    /// Semantic action for production 186:
     ///
     /// `BoolToken: BoolTerm : Token Comments;`
     ///
@@ -18697,7 +19390,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 180:
+    /// Semantic action for production 187:
     ///
     /// `CaseToken: CaseTerm : Token Comments;`
     ///
@@ -18723,7 +19416,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 181:
+    /// Semantic action for production 188:
     ///
     /// `ClockToken: ClockTerm : Token Comments;`
     ///
@@ -18749,7 +19442,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 182:
+    /// Semantic action for production 189:
     ///
     /// `ClockPosedgeToken: ClockPosedgeTerm : Token Comments;`
     ///
@@ -18779,7 +19472,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 183:
+    /// Semantic action for production 190:
     ///
     /// `ClockNegedgeToken: ClockNegedgeTerm : Token Comments;`
     ///
@@ -18809,7 +19502,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 184:
+    /// Semantic action for production 191:
     ///
     /// `ConnectToken: ConnectTerm : Token Comments;`
     ///
@@ -18835,7 +19528,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 185:
+    /// Semantic action for production 192:
     ///
     /// `ConstToken: ConstTerm : Token Comments;`
     ///
@@ -18861,7 +19554,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 186:
+    /// Semantic action for production 193:
     ///
     /// `ConverseToken: ConverseTerm : Token Comments;`
     ///
@@ -18887,7 +19580,33 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 187:
+    /// Semantic action for production 194:
+    ///
+    /// `CoverToken: CoverTerm : Token Comments;`
+    ///
+    #[parol_runtime::function_name::named]
+    fn cover_token(
+        &mut self,
+        _cover_term: &ParseTreeType<'t>,
+        _comments: &ParseTreeType<'t>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let comments = pop_item!(self, comments, Comments, context);
+        let cover_term = pop_item!(self, cover_term, CoverTerm, context);
+        let cover_token_built = CoverToken {
+            cover_term: (&cover_term)
+                .try_into()
+                .map_err(parol_runtime::ParolError::UserError)?,
+            comments: Box::new(comments),
+        };
+        // Calling user action here
+        self.user_grammar.cover_token(&cover_token_built)?;
+        self.push(ASTType::CoverToken(cover_token_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 195:
     ///
     /// `DefaultToken: DefaultTerm : Token Comments;`
     ///
@@ -18913,7 +19632,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 188:
+    /// Semantic action for production 196:
     ///
     /// `ElseToken: ElseTerm : Token Comments;`
     ///
@@ -18939,7 +19658,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 189:
+    /// Semantic action for production 197:
     ///
     /// `EmbedToken: EmbedTerm : Token Comments;`
     ///
@@ -18965,7 +19684,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 190:
+    /// Semantic action for production 198:
     ///
     /// `EnumToken: EnumTerm : Token Comments;`
     ///
@@ -18991,7 +19710,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 191:
+    /// Semantic action for production 199:
     ///
     /// `F32Token: F32Term : Token Comments;`
     ///
@@ -19017,7 +19736,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 192:
+    /// Semantic action for production 200:
     ///
     /// `F64Token: F64Term : Token Comments;`
     ///
@@ -19043,7 +19762,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 193:
+    /// Semantic action for production 201:
     ///
     /// `FalseToken: FalseTerm : Token Comments;`
     ///
@@ -19069,7 +19788,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 194:
+    /// Semantic action for production 202:
     ///
     /// `FinalToken: FinalTerm : Token Comments;`
     ///
@@ -19095,7 +19814,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 195:
+    /// Semantic action for production 203:
     ///
     /// `ForToken: ForTerm : Token Comments;`
     ///
@@ -19121,7 +19840,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 196:
+    /// Semantic action for production 204:
     ///
     /// `FunctionToken: FunctionTerm : Token Comments;`
     ///
@@ -19147,7 +19866,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 197:
+    /// Semantic action for production 205:
     ///
     /// `I8Token: I8Term : Token Comments;`
     ///
@@ -19173,7 +19892,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 198:
+    /// Semantic action for production 206:
     ///
     /// `I16Token: I16Term : Token Comments;`
     ///
@@ -19199,7 +19918,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 199:
+    /// Semantic action for production 207:
     ///
     /// `I32Token: I32Term : Token Comments;`
     ///
@@ -19225,7 +19944,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 200:
+    /// Semantic action for production 208:
     ///
     /// `I64Token: I64Term : Token Comments;`
     ///
@@ -19251,7 +19970,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 201:
+    /// Semantic action for production 209:
     ///
     /// `IfResetToken: IfResetTerm : Token Comments;`
     ///
@@ -19277,7 +19996,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 202:
+    /// Semantic action for production 210:
     ///
     /// `IfToken: IfTerm : Token Comments;`
     ///
@@ -19303,7 +20022,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 203:
+    /// Semantic action for production 211:
     ///
     /// `ImportToken: ImportTerm : Token Comments;`
     ///
@@ -19329,7 +20048,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 204:
+    /// Semantic action for production 212:
     ///
     /// `IncludeToken: IncludeTerm : Token Comments;`
     ///
@@ -19355,7 +20074,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 205:
+    /// Semantic action for production 213:
     ///
     /// `InitialToken: InitialTerm : Token Comments;`
     ///
@@ -19381,7 +20100,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 206:
+    /// Semantic action for production 214:
     ///
     /// `InoutToken: InoutTerm : Token Comments;`
     ///
@@ -19407,7 +20126,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 207:
+    /// Semantic action for production 215:
     ///
     /// `InputToken: InputTerm : Token Comments;`
     ///
@@ -19433,7 +20152,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 208:
+    /// Semantic action for production 216:
     ///
     /// `InsideToken: InsideTerm : Token Comments;`
     ///
@@ -19459,7 +20178,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 209:
+    /// Semantic action for production 217:
     ///
     /// `InstToken: InstTerm : Token Comments;`
     ///
@@ -19485,7 +20204,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 210:
+    /// Semantic action for production 218:
     ///
     /// `InterfaceToken: InterfaceTerm : Token Comments;`
     ///
@@ -19511,7 +20230,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 211:
+    /// Semantic action for production 219:
     ///
     /// `InToken: InTerm : Token Comments;`
     ///
@@ -19537,7 +20256,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 212:
+    /// Semantic action for production 220:
     ///
     /// `LetToken: LetTerm : Token Comments;`
     ///
@@ -19563,7 +20282,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 213:
+    /// Semantic action for production 221:
     ///
     /// `LogicToken: LogicTerm : Token Comments;`
     ///
@@ -19589,7 +20308,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 214:
+    /// Semantic action for production 222:
     ///
     /// `LsbToken: LsbTerm : Token Comments;`
     ///
@@ -19615,7 +20334,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 215:
+    /// Semantic action for production 223:
     ///
     /// `ModportToken: ModportTerm : Token Comments;`
     ///
@@ -19641,7 +20360,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 216:
+    /// Semantic action for production 224:
     ///
     /// `ModuleToken: ModuleTerm : Token Comments;`
     ///
@@ -19667,7 +20386,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 217:
+    /// Semantic action for production 225:
     ///
     /// `MsbToken: MsbTerm : Token Comments;`
     ///
@@ -19693,7 +20412,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 218:
+    /// Semantic action for production 226:
     ///
     /// `OutputToken: OutputTerm : Token Comments;`
     ///
@@ -19719,7 +20438,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 219:
+    /// Semantic action for production 227:
     ///
     /// `OutsideToken: OutsideTerm : Token Comments;`
     ///
@@ -19745,7 +20464,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 220:
+    /// Semantic action for production 228:
     ///
     /// `PackageToken: PackageTerm : Token Comments;`
     ///
@@ -19771,7 +20490,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 221:
+    /// Semantic action for production 229:
     ///
     /// `ParamToken: ParamTerm : Token Comments;`
     ///
@@ -19797,7 +20516,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 222:
+    /// Semantic action for production 230:
     ///
     /// `ProtoToken: ProtoTerm : Token Comments;`
     ///
@@ -19823,7 +20542,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 223:
+    /// Semantic action for production 231:
     ///
     /// `PubToken: PubTerm : Token Comments;`
     ///
@@ -19849,7 +20568,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 224:
+    /// Semantic action for production 232:
     ///
     /// `RepeatToken: RepeatTerm : Token Comments;`
     ///
@@ -19875,7 +20594,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 225:
+    /// Semantic action for production 233:
     ///
     /// `ResetToken: ResetTerm : Token Comments;`
     ///
@@ -19901,7 +20620,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 226:
+    /// Semantic action for production 234:
     ///
     /// `ResetAsyncHighToken: ResetAsyncHighTerm : Token Comments;`
     ///
@@ -19932,7 +20651,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 227:
+    /// Semantic action for production 235:
     ///
     /// `ResetAsyncLowToken: ResetAsyncLowTerm : Token Comments;`
     ///
@@ -19963,7 +20682,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 228:
+    /// Semantic action for production 236:
     ///
     /// `ResetSyncHighToken: ResetSyncHighTerm : Token Comments;`
     ///
@@ -19994,7 +20713,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 229:
+    /// Semantic action for production 237:
     ///
     /// `ResetSyncLowToken: ResetSyncLowTerm : Token Comments;`
     ///
@@ -20024,7 +20743,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 230:
+    /// Semantic action for production 238:
     ///
     /// `ReturnToken: ReturnTerm : Token Comments;`
     ///
@@ -20050,7 +20769,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 231:
+    /// Semantic action for production 239:
     ///
     /// `BreakToken: BreakTerm : Token Comments;`
     ///
@@ -20076,7 +20795,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 232:
+    /// Semantic action for production 240:
     ///
     /// `SameToken: SameTerm : Token Comments;`
     ///
@@ -20102,7 +20821,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 233:
+    /// Semantic action for production 241:
     ///
     /// `SignedToken: SignedTerm : Token Comments;`
     ///
@@ -20128,7 +20847,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 234:
+    /// Semantic action for production 242:
     ///
     /// `StepToken: StepTerm : Token Comments;`
     ///
@@ -20154,7 +20873,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 235:
+    /// Semantic action for production 243:
     ///
     /// `StringToken: StringTerm : Token Comments;`
     ///
@@ -20180,7 +20899,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 236:
+    /// Semantic action for production 244:
     ///
     /// `StructToken: StructTerm : Token Comments;`
     ///
@@ -20206,7 +20925,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 237:
+    /// Semantic action for production 245:
     ///
     /// `SwitchToken: SwitchTerm : Token Comments;`
     ///
@@ -20232,7 +20951,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 238:
+    /// Semantic action for production 246:
     ///
     /// `TriToken: TriTerm : Token Comments;`
     ///
@@ -20258,7 +20977,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 239:
+    /// Semantic action for production 247:
     ///
     /// `TrueToken: TrueTerm : Token Comments;`
     ///
@@ -20284,7 +21003,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 240:
+    /// Semantic action for production 248:
     ///
     /// `TypeToken: TypeTerm : Token Comments;`
     ///
@@ -20310,7 +21029,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 241:
+    /// Semantic action for production 249:
     ///
     /// `U8Token: U8Term : Token Comments;`
     ///
@@ -20336,7 +21055,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 242:
+    /// Semantic action for production 250:
     ///
     /// `U16Token: U16Term : Token Comments;`
     ///
@@ -20362,7 +21081,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 243:
+    /// Semantic action for production 251:
     ///
     /// `U32Token: U32Term : Token Comments;`
     ///
@@ -20388,7 +21107,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 244:
+    /// Semantic action for production 252:
     ///
     /// `U64Token: U64Term : Token Comments;`
     ///
@@ -20414,7 +21133,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 245:
+    /// Semantic action for production 253:
     ///
     /// `UnionToken: UnionTerm : Token Comments;`
     ///
@@ -20440,7 +21159,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 246:
+    /// Semantic action for production 254:
     ///
     /// `UnsafeToken: UnsafeTerm : Token Comments;`
     ///
@@ -20466,7 +21185,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 247:
+    /// Semantic action for production 255:
     ///
     /// `VarToken: VarTerm : Token Comments;`
     ///
@@ -20492,7 +21211,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 248:
+    /// Semantic action for production 256:
     ///
     /// `DollarIdentifierToken: DollarIdentifierTerm : Token Comments;`
     ///
@@ -20523,7 +21242,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 249:
+    /// Semantic action for production 257:
     ///
     /// `IdentifierToken: IdentifierTerm : Token Comments;`
     ///
@@ -20550,7 +21269,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 250:
+    /// Semantic action for production 258:
     ///
     /// `Start: StartToken : VerylToken;`
     ///
@@ -20570,7 +21289,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 251:
+    /// Semantic action for production 259:
     ///
     /// `StringLiteral: StringLiteralToken : VerylToken;`
     ///
@@ -20591,7 +21310,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 252:
+    /// Semantic action for production 260:
     ///
     /// `Exponent: ExponentToken : VerylToken;`
     ///
@@ -20611,7 +21330,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 253:
+    /// Semantic action for production 261:
     ///
     /// `FixedPoint: FixedPointToken : VerylToken;`
     ///
@@ -20631,7 +21350,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 254:
+    /// Semantic action for production 262:
     ///
     /// `Based: BasedToken : VerylToken;`
     ///
@@ -20651,7 +21370,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 255:
+    /// Semantic action for production 263:
     ///
     /// `BaseLess: BaseLessToken : VerylToken;`
     ///
@@ -20671,7 +21390,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 256:
+    /// Semantic action for production 264:
     ///
     /// `AllBit: AllBitToken : VerylToken;`
     ///
@@ -20691,7 +21410,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 257:
+    /// Semantic action for production 265:
     ///
     /// `AssignmentOperator: AssignmentOperatorToken : VerylToken;`
     ///
@@ -20723,7 +21442,27 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 258:
+    /// Semantic action for production 266:
+    ///
+    /// `Implication: ImplicationToken : VerylToken;`
+    ///
+    #[parol_runtime::function_name::named]
+    fn implication(&mut self, _implication_token: &ParseTreeType<'t>) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let implication_token = pop_item!(self, implication_token, ImplicationToken, context);
+        let implication_built = Implication {
+            implication_token: (&implication_token)
+                .try_into()
+                .map_err(parol_runtime::ParolError::UserError)?,
+        };
+        // Calling user action here
+        self.user_grammar.implication(&implication_built)?;
+        self.push(ASTType::Implication(implication_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 267:
     ///
     /// `DiamondOperator: DiamondOperatorToken : VerylToken;`
     ///
@@ -20745,7 +21484,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 259:
+    /// Semantic action for production 268:
     ///
     /// `Operator02: Operator02Token : VerylToken;`
     ///
@@ -20765,7 +21504,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 260:
+    /// Semantic action for production 269:
     ///
     /// `Operator03: Operator03Token : VerylToken;`
     ///
@@ -20785,7 +21524,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 261:
+    /// Semantic action for production 270:
     ///
     /// `Operator04: Operator04Token : VerylToken;`
     ///
@@ -20805,7 +21544,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 262:
+    /// Semantic action for production 271:
     ///
     /// `Operator05: Operator05Token : VerylToken;`
     ///
@@ -20825,7 +21564,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 263:
+    /// Semantic action for production 272:
     ///
     /// `Operator06: Operator06Token : VerylToken;`
     ///
@@ -20845,7 +21584,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 264:
+    /// Semantic action for production 273:
     ///
     /// `Operator07: Operator07Token : VerylToken;`
     ///
@@ -20865,7 +21604,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 265:
+    /// Semantic action for production 274:
     ///
     /// `Operator08: Operator08Token : VerylToken;`
     ///
@@ -20885,7 +21624,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 266:
+    /// Semantic action for production 275:
     ///
     /// `Operator09: Operator09Token : VerylToken;`
     ///
@@ -20905,7 +21644,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 267:
+    /// Semantic action for production 276:
     ///
     /// `Operator10: Operator10Token : VerylToken;`
     ///
@@ -20925,7 +21664,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 268:
+    /// Semantic action for production 277:
     ///
     /// `Operator11: Operator11Token : VerylToken;`
     ///
@@ -20945,7 +21684,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 269:
+    /// Semantic action for production 278:
     ///
     /// `Operator12: Operator12Token : VerylToken;`
     ///
@@ -20965,7 +21704,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 270:
+    /// Semantic action for production 279:
     ///
     /// `UnaryOperator: UnaryOperatorToken : VerylToken;`
     ///
@@ -20986,7 +21725,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 271:
+    /// Semantic action for production 280:
     ///
     /// `Colon: ColonToken : VerylToken;`
     ///
@@ -21006,7 +21745,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 272:
+    /// Semantic action for production 281:
     ///
     /// `ColonColonLAngle: ColonColonLAngleToken : VerylToken;`
     ///
@@ -21038,7 +21777,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 273:
+    /// Semantic action for production 282:
     ///
     /// `ColonColon: ColonColonToken : VerylToken;`
     ///
@@ -21058,7 +21797,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 274:
+    /// Semantic action for production 283:
     ///
     /// `Comma: CommaToken : VerylToken;`
     ///
@@ -21078,7 +21817,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 275:
+    /// Semantic action for production 284:
     ///
     /// `DotDot: DotDotToken : VerylToken;`
     ///
@@ -21098,7 +21837,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 276:
+    /// Semantic action for production 285:
     ///
     /// `DotDotEqu: DotDotEquToken : VerylToken;`
     ///
@@ -21118,7 +21857,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 277:
+    /// Semantic action for production 286:
     ///
     /// `Dot: DotToken : VerylToken;`
     ///
@@ -21138,7 +21877,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 278:
+    /// Semantic action for production 287:
     ///
     /// `Equ: EquToken : VerylToken;`
     ///
@@ -21158,7 +21897,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 279:
+    /// Semantic action for production 288:
     ///
     /// `Hash: HashToken : VerylToken;`
     ///
@@ -21178,7 +21917,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 280:
+    /// Semantic action for production 289:
     ///
     /// `Question: QuestionToken : VerylToken;`
     ///
@@ -21198,7 +21937,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 281:
+    /// Semantic action for production 290:
     ///
     /// `Quote: QuoteToken : VerylToken;`
     ///
@@ -21218,7 +21957,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 282:
+    /// Semantic action for production 291:
     ///
     /// `QuoteLBrace: QuoteLBraceToken : VerylToken;`
     ///
@@ -21238,7 +21977,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 283:
+    /// Semantic action for production 292:
     ///
     /// `LAngle: LAngleToken : VerylToken;`
     ///
@@ -21258,7 +21997,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 284:
+    /// Semantic action for production 293:
     ///
     /// `LBrace: LBraceToken : VerylToken;`
     ///
@@ -21278,7 +22017,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 285:
+    /// Semantic action for production 294:
     ///
     /// `LBracket: LBracketToken : VerylToken;`
     ///
@@ -21298,7 +22037,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 286:
+    /// Semantic action for production 295:
     ///
     /// `LParen: LParenToken : VerylToken;`
     ///
@@ -21318,7 +22057,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 287:
+    /// Semantic action for production 296:
     ///
     /// `MinusColon: MinusColonToken : VerylToken;`
     ///
@@ -21338,7 +22077,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 288:
+    /// Semantic action for production 297:
     ///
     /// `MinusGT: MinusGTToken : VerylToken;`
     ///
@@ -21358,7 +22097,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 289:
+    /// Semantic action for production 298:
     ///
     /// `PlusColon: PlusColonToken : VerylToken;`
     ///
@@ -21378,7 +22117,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 290:
+    /// Semantic action for production 299:
     ///
     /// `RAngle: RAngleToken : VerylToken;`
     ///
@@ -21398,7 +22137,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 291:
+    /// Semantic action for production 300:
     ///
     /// `RBrace: RBraceToken : VerylToken;`
     ///
@@ -21418,7 +22157,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 292:
+    /// Semantic action for production 301:
     ///
     /// `RBracket: RBracketToken : VerylToken;`
     ///
@@ -21438,7 +22177,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 293:
+    /// Semantic action for production 302:
     ///
     /// `RParen: RParenToken : VerylToken;`
     ///
@@ -21458,7 +22197,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 294:
+    /// Semantic action for production 303:
     ///
     /// `Semicolon: SemicolonToken : VerylToken;`
     ///
@@ -21478,7 +22217,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 295:
+    /// Semantic action for production 304:
     ///
     /// `Star: StarToken : VerylToken;`
     ///
@@ -21498,7 +22237,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 296:
+    /// Semantic action for production 305:
     ///
     /// `Alias: AliasToken : VerylToken;`
     ///
@@ -21518,7 +22257,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 297:
+    /// Semantic action for production 306:
     ///
     /// `AlwaysComb: AlwaysCombToken : VerylToken;`
     ///
@@ -21538,7 +22277,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 298:
+    /// Semantic action for production 307:
     ///
     /// `AlwaysFf: AlwaysFfToken : VerylToken;`
     ///
@@ -21558,7 +22297,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 299:
+    /// Semantic action for production 308:
     ///
     /// `As: AsToken : VerylToken;`
     ///
@@ -21578,7 +22317,27 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 300:
+    /// Semantic action for production 309:
+    ///
+    /// `Assert: AssertToken : VerylToken;`
+    ///
+    #[parol_runtime::function_name::named]
+    fn assert(&mut self, _assert_token: &ParseTreeType<'t>) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let assert_token = pop_item!(self, assert_token, AssertToken, context);
+        let assert_built = Assert {
+            assert_token: (&assert_token)
+                .try_into()
+                .map_err(parol_runtime::ParolError::UserError)?,
+        };
+        // Calling user action here
+        self.user_grammar.assert(&assert_built)?;
+        self.push(ASTType::Assert(assert_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 310:
     ///
     /// `Assign: AssignToken : VerylToken;`
     ///
@@ -21598,7 +22357,27 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 301:
+    /// Semantic action for production 311:
+    ///
+    /// `Assume: AssumeToken : VerylToken;`
+    ///
+    #[parol_runtime::function_name::named]
+    fn assume(&mut self, _assume_token: &ParseTreeType<'t>) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let assume_token = pop_item!(self, assume_token, AssumeToken, context);
+        let assume_built = Assume {
+            assume_token: (&assume_token)
+                .try_into()
+                .map_err(parol_runtime::ParolError::UserError)?,
+        };
+        // Calling user action here
+        self.user_grammar.assume(&assume_built)?;
+        self.push(ASTType::Assume(assume_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 312:
     ///
     /// `Bit: BitToken : VerylToken;`
     ///
@@ -21618,7 +22397,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 302:
+    /// Semantic action for production 313:
     ///
     /// `Bool: BoolToken : VerylToken;`
     ///
@@ -21638,7 +22417,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 303:
+    /// Semantic action for production 314:
     ///
     /// `Break: BreakToken : VerylToken;`
     ///
@@ -21658,7 +22437,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 304:
+    /// Semantic action for production 315:
     ///
     /// `Case: CaseToken : VerylToken;`
     ///
@@ -21678,7 +22457,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 305:
+    /// Semantic action for production 316:
     ///
     /// `Clock: ClockToken : VerylToken;`
     ///
@@ -21698,7 +22477,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 306:
+    /// Semantic action for production 317:
     ///
     /// `ClockPosedge: ClockPosedgeToken : VerylToken;`
     ///
@@ -21718,7 +22497,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 307:
+    /// Semantic action for production 318:
     ///
     /// `ClockNegedge: ClockNegedgeToken : VerylToken;`
     ///
@@ -21738,7 +22517,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 308:
+    /// Semantic action for production 319:
     ///
     /// `Connect: ConnectToken : VerylToken;`
     ///
@@ -21758,7 +22537,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 309:
+    /// Semantic action for production 320:
     ///
     /// `Const: ConstToken : VerylToken;`
     ///
@@ -21778,7 +22557,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 310:
+    /// Semantic action for production 321:
     ///
     /// `Converse: ConverseToken : VerylToken;`
     ///
@@ -21798,7 +22577,27 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 311:
+    /// Semantic action for production 322:
+    ///
+    /// `Cover: CoverToken : VerylToken;`
+    ///
+    #[parol_runtime::function_name::named]
+    fn cover(&mut self, _cover_token: &ParseTreeType<'t>) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let cover_token = pop_item!(self, cover_token, CoverToken, context);
+        let cover_built = Cover {
+            cover_token: (&cover_token)
+                .try_into()
+                .map_err(parol_runtime::ParolError::UserError)?,
+        };
+        // Calling user action here
+        self.user_grammar.cover(&cover_built)?;
+        self.push(ASTType::Cover(cover_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 323:
     ///
     /// `Defaul: DefaultToken : VerylToken;`
     ///
@@ -21818,7 +22617,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 312:
+    /// Semantic action for production 324:
     ///
     /// `Else: ElseToken : VerylToken;`
     ///
@@ -21838,7 +22637,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 313:
+    /// Semantic action for production 325:
     ///
     /// `Embed: EmbedToken : VerylToken;`
     ///
@@ -21858,7 +22657,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 314:
+    /// Semantic action for production 326:
     ///
     /// `Enum: EnumToken : VerylToken;`
     ///
@@ -21878,7 +22677,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 315:
+    /// Semantic action for production 327:
     ///
     /// `F32: F32Token : VerylToken;`
     ///
@@ -21898,7 +22697,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 316:
+    /// Semantic action for production 328:
     ///
     /// `F64: F64Token : VerylToken;`
     ///
@@ -21918,7 +22717,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 317:
+    /// Semantic action for production 329:
     ///
     /// `False: FalseToken : VerylToken;`
     ///
@@ -21938,7 +22737,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 318:
+    /// Semantic action for production 330:
     ///
     /// `Final: FinalToken : VerylToken;`
     ///
@@ -21958,7 +22757,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 319:
+    /// Semantic action for production 331:
     ///
     /// `For: ForToken : VerylToken;`
     ///
@@ -21978,7 +22777,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 320:
+    /// Semantic action for production 332:
     ///
     /// `Function: FunctionToken : VerylToken;`
     ///
@@ -21998,7 +22797,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 321:
+    /// Semantic action for production 333:
     ///
     /// `I8: I8Token : VerylToken;`
     ///
@@ -22018,7 +22817,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 322:
+    /// Semantic action for production 334:
     ///
     /// `I16: I16Token : VerylToken;`
     ///
@@ -22038,7 +22837,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 323:
+    /// Semantic action for production 335:
     ///
     /// `I32: I32Token : VerylToken;`
     ///
@@ -22058,7 +22857,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 324:
+    /// Semantic action for production 336:
     ///
     /// `I64: I64Token : VerylToken;`
     ///
@@ -22078,7 +22877,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 325:
+    /// Semantic action for production 337:
     ///
     /// `If: IfToken : VerylToken;`
     ///
@@ -22098,7 +22897,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 326:
+    /// Semantic action for production 338:
     ///
     /// `IfReset: IfResetToken : VerylToken;`
     ///
@@ -22118,7 +22917,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 327:
+    /// Semantic action for production 339:
     ///
     /// `Import: ImportToken : VerylToken;`
     ///
@@ -22138,7 +22937,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 328:
+    /// Semantic action for production 340:
     ///
     /// `In: InToken : VerylToken;`
     ///
@@ -22158,7 +22957,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 329:
+    /// Semantic action for production 341:
     ///
     /// `Include: IncludeToken : VerylToken;`
     ///
@@ -22178,7 +22977,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 330:
+    /// Semantic action for production 342:
     ///
     /// `Initial: InitialToken : VerylToken;`
     ///
@@ -22198,7 +22997,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 331:
+    /// Semantic action for production 343:
     ///
     /// `Inout: InoutToken : VerylToken;`
     ///
@@ -22218,7 +23017,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 332:
+    /// Semantic action for production 344:
     ///
     /// `Input: InputToken : VerylToken;`
     ///
@@ -22238,7 +23037,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 333:
+    /// Semantic action for production 345:
     ///
     /// `Inside: InsideToken : VerylToken;`
     ///
@@ -22258,7 +23057,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 334:
+    /// Semantic action for production 346:
     ///
     /// `Inst: InstToken : VerylToken;`
     ///
@@ -22278,7 +23077,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 335:
+    /// Semantic action for production 347:
     ///
     /// `Interface: InterfaceToken : VerylToken;`
     ///
@@ -22298,7 +23097,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 336:
+    /// Semantic action for production 348:
     ///
     /// `Let: LetToken : VerylToken;`
     ///
@@ -22318,7 +23117,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 337:
+    /// Semantic action for production 349:
     ///
     /// `Logic: LogicToken : VerylToken;`
     ///
@@ -22338,7 +23137,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 338:
+    /// Semantic action for production 350:
     ///
     /// `Lsb: LsbToken : VerylToken;`
     ///
@@ -22358,7 +23157,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 339:
+    /// Semantic action for production 351:
     ///
     /// `Modport: ModportToken : VerylToken;`
     ///
@@ -22378,7 +23177,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 340:
+    /// Semantic action for production 352:
     ///
     /// `Module: ModuleToken : VerylToken;`
     ///
@@ -22398,7 +23197,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 341:
+    /// Semantic action for production 353:
     ///
     /// `Msb: MsbToken : VerylToken;`
     ///
@@ -22418,7 +23217,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 342:
+    /// Semantic action for production 354:
     ///
     /// `Output: OutputToken : VerylToken;`
     ///
@@ -22438,7 +23237,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 343:
+    /// Semantic action for production 355:
     ///
     /// `Outside: OutsideToken : VerylToken;`
     ///
@@ -22458,7 +23257,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 344:
+    /// Semantic action for production 356:
     ///
     /// `Package: PackageToken : VerylToken;`
     ///
@@ -22478,7 +23277,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 345:
+    /// Semantic action for production 357:
     ///
     /// `Param: ParamToken : VerylToken;`
     ///
@@ -22498,7 +23297,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 346:
+    /// Semantic action for production 358:
     ///
     /// `Proto: ProtoToken : VerylToken;`
     ///
@@ -22518,7 +23317,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 347:
+    /// Semantic action for production 359:
     ///
     /// `Pub: PubToken : VerylToken;`
     ///
@@ -22538,7 +23337,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 348:
+    /// Semantic action for production 360:
     ///
     /// `Repeat: RepeatToken : VerylToken;`
     ///
@@ -22558,7 +23357,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 349:
+    /// Semantic action for production 361:
     ///
     /// `Reset: ResetToken : VerylToken;`
     ///
@@ -22578,7 +23377,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 350:
+    /// Semantic action for production 362:
     ///
     /// `ResetAsyncHigh: ResetAsyncHighToken : VerylToken;`
     ///
@@ -22600,7 +23399,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 351:
+    /// Semantic action for production 363:
     ///
     /// `ResetAsyncLow: ResetAsyncLowToken : VerylToken;`
     ///
@@ -22621,7 +23420,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 352:
+    /// Semantic action for production 364:
     ///
     /// `ResetSyncHigh: ResetSyncHighToken : VerylToken;`
     ///
@@ -22642,7 +23441,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 353:
+    /// Semantic action for production 365:
     ///
     /// `ResetSyncLow: ResetSyncLowToken : VerylToken;`
     ///
@@ -22663,7 +23462,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 354:
+    /// Semantic action for production 366:
     ///
     /// `Return: ReturnToken : VerylToken;`
     ///
@@ -22683,7 +23482,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 355:
+    /// Semantic action for production 367:
     ///
     /// `Same: SameToken : VerylToken;`
     ///
@@ -22703,7 +23502,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 356:
+    /// Semantic action for production 368:
     ///
     /// `Signed: SignedToken : VerylToken;`
     ///
@@ -22723,7 +23522,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 357:
+    /// Semantic action for production 369:
     ///
     /// `Step: StepToken : VerylToken;`
     ///
@@ -22743,7 +23542,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 358:
+    /// Semantic action for production 370:
     ///
     /// `Strin: StringToken : VerylToken;`
     ///
@@ -22763,7 +23562,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 359:
+    /// Semantic action for production 371:
     ///
     /// `Struct: StructToken : VerylToken;`
     ///
@@ -22783,7 +23582,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 360:
+    /// Semantic action for production 372:
     ///
     /// `Switch: SwitchToken : VerylToken;`
     ///
@@ -22803,7 +23602,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 361:
+    /// Semantic action for production 373:
     ///
     /// `Tri: TriToken : VerylToken;`
     ///
@@ -22823,7 +23622,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 362:
+    /// Semantic action for production 374:
     ///
     /// `True: TrueToken : VerylToken;`
     ///
@@ -22843,7 +23642,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 363:
+    /// Semantic action for production 375:
     ///
     /// `Type: TypeToken : VerylToken;`
     ///
@@ -22863,7 +23662,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 364:
+    /// Semantic action for production 376:
     ///
     /// `U8: U8Token : VerylToken;`
     ///
@@ -22883,7 +23682,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 365:
+    /// Semantic action for production 377:
     ///
     /// `U16: U16Token : VerylToken;`
     ///
@@ -22903,7 +23702,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 366:
+    /// Semantic action for production 378:
     ///
     /// `U32: U32Token : VerylToken;`
     ///
@@ -22923,7 +23722,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 367:
+    /// Semantic action for production 379:
     ///
     /// `U64: U64Token : VerylToken;`
     ///
@@ -22943,7 +23742,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 368:
+    /// Semantic action for production 380:
     ///
     /// `Union: UnionToken : VerylToken;`
     ///
@@ -22963,7 +23762,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 369:
+    /// Semantic action for production 381:
     ///
     /// `Unsafe: UnsafeToken : VerylToken;`
     ///
@@ -22983,7 +23782,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 370:
+    /// Semantic action for production 382:
     ///
     /// `Var: VarToken : VerylToken;`
     ///
@@ -23003,7 +23802,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 371:
+    /// Semantic action for production 383:
     ///
     /// `DollarIdentifier: DollarIdentifierToken : VerylToken;`
     ///
@@ -23029,7 +23828,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 372:
+    /// Semantic action for production 384:
     ///
     /// `Identifier: IdentifierToken : VerylToken;`
     ///
@@ -23049,7 +23848,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 373:
+    /// Semantic action for production 385:
     ///
     /// `Number: IntegralNumber;`
     ///
@@ -23068,7 +23867,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 374:
+    /// Semantic action for production 386:
     ///
     /// `Number: RealNumber;`
     ///
@@ -23087,7 +23886,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 375:
+    /// Semantic action for production 387:
     ///
     /// `IntegralNumber: Based;`
     ///
@@ -23107,7 +23906,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 376:
+    /// Semantic action for production 388:
     ///
     /// `IntegralNumber: BaseLess;`
     ///
@@ -23127,7 +23926,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 377:
+    /// Semantic action for production 389:
     ///
     /// `IntegralNumber: AllBit;`
     ///
@@ -23147,7 +23946,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 378:
+    /// Semantic action for production 390:
     ///
     /// `RealNumber: FixedPoint;`
     ///
@@ -23166,7 +23965,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 379:
+    /// Semantic action for production 391:
     ///
     /// `RealNumber: Exponent;`
     ///
@@ -23185,7 +23984,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 380:
+    /// Semantic action for production 392:
     ///
     /// `HierarchicalIdentifier: Identifier HierarchicalIdentifierList /* Vec */ HierarchicalIdentifierList0 /* Vec */;`
     ///
@@ -23226,7 +24025,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 381:
+    /// Semantic action for production 393:
     ///
     /// `HierarchicalIdentifierList0 /* Vec<T>::Push */: Dot Identifier HierarchicalIdentifierList0List /* Vec */ HierarchicalIdentifierList0;`
     ///
@@ -23268,7 +24067,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 382:
+    /// Semantic action for production 394:
     ///
     /// `HierarchicalIdentifierList0List /* Vec<T>::Push */: Select HierarchicalIdentifierList0List;`
     ///
@@ -23299,7 +24098,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 383:
+    /// Semantic action for production 395:
     ///
     /// `HierarchicalIdentifierList0List /* Vec<T>::New */: ;`
     ///
@@ -23315,7 +24114,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 384:
+    /// Semantic action for production 396:
     ///
     /// `HierarchicalIdentifierList0 /* Vec<T>::New */: ;`
     ///
@@ -23331,7 +24130,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 385:
+    /// Semantic action for production 397:
     ///
     /// `HierarchicalIdentifierList /* Vec<T>::Push */: Select HierarchicalIdentifierList;`
     ///
@@ -23362,7 +24161,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 386:
+    /// Semantic action for production 398:
     ///
     /// `HierarchicalIdentifierList /* Vec<T>::New */: ;`
     ///
@@ -23378,7 +24177,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 387:
+    /// Semantic action for production 399:
     ///
     /// `ScopedIdentifier: ScopedIdentifierGroup ScopedIdentifierList /* Vec */;`
     ///
@@ -23409,7 +24208,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 388:
+    /// Semantic action for production 400:
     ///
     /// `ScopedIdentifierGroup: DollarIdentifier;`
     ///
@@ -23430,7 +24229,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 389:
+    /// Semantic action for production 401:
     ///
     /// `ScopedIdentifierGroup: Identifier ScopedIdentifierOpt /* Option */;`
     ///
@@ -23458,7 +24257,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 390:
+    /// Semantic action for production 402:
     ///
     /// `ScopedIdentifierList /* Vec<T>::Push */: ColonColon Identifier ScopedIdentifierOpt0 /* Option */ ScopedIdentifierList;`
     ///
@@ -23492,7 +24291,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 391:
+    /// Semantic action for production 403:
     ///
     /// `ScopedIdentifierList /* Vec<T>::New */: ;`
     ///
@@ -23508,7 +24307,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 392:
+    /// Semantic action for production 404:
     ///
     /// `ScopedIdentifierOpt0 /* Option<T>::Some */: WithGenericArgument;`
     ///
@@ -23531,7 +24330,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 393:
+    /// Semantic action for production 405:
     ///
     /// `ScopedIdentifierOpt0 /* Option<T>::None */: ;`
     ///
@@ -23543,7 +24342,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 394:
+    /// Semantic action for production 406:
     ///
     /// `ScopedIdentifierOpt /* Option<T>::Some */: WithGenericArgument;`
     ///
@@ -23566,7 +24365,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 395:
+    /// Semantic action for production 407:
     ///
     /// `ScopedIdentifierOpt /* Option<T>::None */: ;`
     ///
@@ -23578,7 +24377,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 396:
+    /// Semantic action for production 408:
     ///
     /// `ExpressionIdentifier: ScopedIdentifier ExpressionIdentifierOpt /* Option */ ExpressionIdentifierList /* Vec */ ExpressionIdentifierList0 /* Vec */;`
     ///
@@ -23627,7 +24426,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 397:
+    /// Semantic action for production 409:
     ///
     /// `ExpressionIdentifierList0 /* Vec<T>::Push */: Dot Identifier ExpressionIdentifierList0List /* Vec */ ExpressionIdentifierList0;`
     ///
@@ -23669,7 +24468,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 398:
+    /// Semantic action for production 410:
     ///
     /// `ExpressionIdentifierList0List /* Vec<T>::Push */: Select ExpressionIdentifierList0List;`
     ///
@@ -23700,7 +24499,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 399:
+    /// Semantic action for production 411:
     ///
     /// `ExpressionIdentifierList0List /* Vec<T>::New */: ;`
     ///
@@ -23716,7 +24515,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 400:
+    /// Semantic action for production 412:
     ///
     /// `ExpressionIdentifierList0 /* Vec<T>::New */: ;`
     ///
@@ -23732,7 +24531,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 401:
+    /// Semantic action for production 413:
     ///
     /// `ExpressionIdentifierList /* Vec<T>::Push */: Select ExpressionIdentifierList;`
     ///
@@ -23763,7 +24562,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 402:
+    /// Semantic action for production 414:
     ///
     /// `ExpressionIdentifierList /* Vec<T>::New */: ;`
     ///
@@ -23779,7 +24578,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 403:
+    /// Semantic action for production 415:
     ///
     /// `ExpressionIdentifierOpt /* Option<T>::Some */: Width;`
     ///
@@ -23798,7 +24597,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 404:
+    /// Semantic action for production 416:
     ///
     /// `ExpressionIdentifierOpt /* Option<T>::None */: ;`
     ///
@@ -23810,7 +24609,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 405:
+    /// Semantic action for production 417:
     ///
     /// `Expression: IfExpression;`
     ///
@@ -23828,7 +24627,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 406:
+    /// Semantic action for production 418:
     ///
     /// `IfExpression: IfExpressionList /* Vec */ Expression01;`
     ///
@@ -23853,7 +24652,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 407:
+    /// Semantic action for production 419:
     ///
     /// `IfExpressionList /* Vec<T>::Push */: If Expression Question Expression Colon IfExpressionList;`
     ///
@@ -23888,7 +24687,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 408:
+    /// Semantic action for production 420:
     ///
     /// `IfExpressionList /* Vec<T>::New */: ;`
     ///
@@ -23904,7 +24703,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 409:
+    /// Semantic action for production 421:
     ///
     /// `Expression01: Expression02 Expression01List /* Vec */;`
     ///
@@ -23929,7 +24728,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 410:
+    /// Semantic action for production 422:
     ///
     /// `Expression01List /* Vec<T>::Push */: Operator02 Expression02 Expression01List;`
     ///
@@ -23955,7 +24754,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 411:
+    /// Semantic action for production 423:
     ///
     /// `Expression01List /* Vec<T>::New */: ;`
     ///
@@ -23971,7 +24770,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 412:
+    /// Semantic action for production 424:
     ///
     /// `Expression02: Expression03 Expression02List /* Vec */;`
     ///
@@ -23996,7 +24795,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 413:
+    /// Semantic action for production 425:
     ///
     /// `Expression02List /* Vec<T>::Push */: Operator03 Expression03 Expression02List;`
     ///
@@ -24022,7 +24821,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 414:
+    /// Semantic action for production 426:
     ///
     /// `Expression02List /* Vec<T>::New */: ;`
     ///
@@ -24038,7 +24837,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 415:
+    /// Semantic action for production 427:
     ///
     /// `Expression03: Expression04 Expression03List /* Vec */;`
     ///
@@ -24063,7 +24862,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 416:
+    /// Semantic action for production 428:
     ///
     /// `Expression03List /* Vec<T>::Push */: Operator04 Expression04 Expression03List;`
     ///
@@ -24089,7 +24888,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 417:
+    /// Semantic action for production 429:
     ///
     /// `Expression03List /* Vec<T>::New */: ;`
     ///
@@ -24105,7 +24904,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 418:
+    /// Semantic action for production 430:
     ///
     /// `Expression04: Expression05 Expression04List /* Vec */;`
     ///
@@ -24130,7 +24929,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 419:
+    /// Semantic action for production 431:
     ///
     /// `Expression04List /* Vec<T>::Push */: Operator05 Expression05 Expression04List;`
     ///
@@ -24156,7 +24955,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 420:
+    /// Semantic action for production 432:
     ///
     /// `Expression04List /* Vec<T>::New */: ;`
     ///
@@ -24172,7 +24971,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 421:
+    /// Semantic action for production 433:
     ///
     /// `Expression05: Expression06 Expression05List /* Vec */;`
     ///
@@ -24197,7 +24996,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 422:
+    /// Semantic action for production 434:
     ///
     /// `Expression05List /* Vec<T>::Push */: Operator06 Expression06 Expression05List;`
     ///
@@ -24223,7 +25022,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 423:
+    /// Semantic action for production 435:
     ///
     /// `Expression05List /* Vec<T>::New */: ;`
     ///
@@ -24239,7 +25038,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 424:
+    /// Semantic action for production 436:
     ///
     /// `Expression06: Expression07 Expression06List /* Vec */;`
     ///
@@ -24264,7 +25063,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 425:
+    /// Semantic action for production 437:
     ///
     /// `Expression06List /* Vec<T>::Push */: Operator07 Expression07 Expression06List;`
     ///
@@ -24290,7 +25089,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 426:
+    /// Semantic action for production 438:
     ///
     /// `Expression06List /* Vec<T>::New */: ;`
     ///
@@ -24306,7 +25105,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 427:
+    /// Semantic action for production 439:
     ///
     /// `Expression07: Expression08 Expression07List /* Vec */;`
     ///
@@ -24331,7 +25130,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 428:
+    /// Semantic action for production 440:
     ///
     /// `Expression07List /* Vec<T>::Push */: Operator08 Expression08 Expression07List;`
     ///
@@ -24357,7 +25156,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 429:
+    /// Semantic action for production 441:
     ///
     /// `Expression07List /* Vec<T>::New */: ;`
     ///
@@ -24373,7 +25172,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 430:
+    /// Semantic action for production 442:
     ///
     /// `Expression08: Expression09 Expression08List /* Vec */;`
     ///
@@ -24398,7 +25197,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 431:
+    /// Semantic action for production 443:
     ///
     /// `Expression08List /* Vec<T>::Push */: Operator09 Expression09 Expression08List;`
     ///
@@ -24424,7 +25223,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 432:
+    /// Semantic action for production 444:
     ///
     /// `Expression08List /* Vec<T>::New */: ;`
     ///
@@ -24440,7 +25239,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 433:
+    /// Semantic action for production 445:
     ///
     /// `Expression09: Expression10 Expression09List /* Vec */;`
     ///
@@ -24465,7 +25264,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 434:
+    /// Semantic action for production 446:
     ///
     /// `Expression09List /* Vec<T>::Push */: Operator10 Expression10 Expression09List;`
     ///
@@ -24491,7 +25290,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 435:
+    /// Semantic action for production 447:
     ///
     /// `Expression09List /* Vec<T>::New */: ;`
     ///
@@ -24507,7 +25306,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 436:
+    /// Semantic action for production 448:
     ///
     /// `Expression10: Expression11 Expression10List /* Vec */;`
     ///
@@ -24532,7 +25331,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 437:
+    /// Semantic action for production 449:
     ///
     /// `Expression10List /* Vec<T>::Push */: Expression10ListGroup Expression11 Expression10List;`
     ///
@@ -24563,7 +25362,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 438:
+    /// Semantic action for production 450:
     ///
     /// `Expression10ListGroup: Operator11;`
     ///
@@ -24584,7 +25383,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 439:
+    /// Semantic action for production 451:
     ///
     /// `Expression10ListGroup: Star;`
     ///
@@ -24605,7 +25404,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 440:
+    /// Semantic action for production 452:
     ///
     /// `Expression10List /* Vec<T>::New */: ;`
     ///
@@ -24621,7 +25420,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 441:
+    /// Semantic action for production 453:
     ///
     /// `Expression11: Expression12 Expression11List /* Vec */;`
     ///
@@ -24646,7 +25445,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 442:
+    /// Semantic action for production 454:
     ///
     /// `Expression11List /* Vec<T>::Push */: Operator12 Expression12 Expression11List;`
     ///
@@ -24672,7 +25471,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 443:
+    /// Semantic action for production 455:
     ///
     /// `Expression11List /* Vec<T>::New */: ;`
     ///
@@ -24688,7 +25487,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 444:
+    /// Semantic action for production 456:
     ///
     /// `Expression12: Expression13 Expression12Opt /* Option */;`
     ///
@@ -24712,7 +25511,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 445:
+    /// Semantic action for production 457:
     ///
     /// `Expression12Opt /* Option<T>::Some */: As CastingType;`
     ///
@@ -24737,7 +25536,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 446:
+    /// Semantic action for production 458:
     ///
     /// `Expression12Opt /* Option<T>::None */: ;`
     ///
@@ -24749,7 +25548,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 447:
+    /// Semantic action for production 459:
     ///
     /// `Expression13: Expression13List /* Vec */ Factor;`
     ///
@@ -24774,7 +25573,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 448:
+    /// Semantic action for production 460:
     ///
     /// `Expression13List /* Vec<T>::Push */: Expression13ListGroup Expression13List;`
     ///
@@ -24802,7 +25601,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 449:
+    /// Semantic action for production 461:
     ///
     /// `Expression13ListGroup: UnaryOperator;`
     ///
@@ -24823,7 +25622,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 450:
+    /// Semantic action for production 462:
     ///
     /// `Expression13ListGroup: Operator10;`
     ///
@@ -24844,7 +25643,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 451:
+    /// Semantic action for production 463:
     ///
     /// `Expression13ListGroup: Operator06;`
     ///
@@ -24865,7 +25664,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 452:
+    /// Semantic action for production 464:
     ///
     /// `Expression13ListGroup: Operator04;`
     ///
@@ -24886,7 +25685,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 453:
+    /// Semantic action for production 465:
     ///
     /// `Expression13ListGroup: Operator05;`
     ///
@@ -24907,7 +25706,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 454:
+    /// Semantic action for production 466:
     ///
     /// `Expression13List /* Vec<T>::New */: ;`
     ///
@@ -24923,7 +25722,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 455:
+    /// Semantic action for production 467:
     ///
     /// `Factor: Number;`
     ///
@@ -24942,7 +25741,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 456:
+    /// Semantic action for production 468:
     ///
     /// `Factor: BooleanLiteral;`
     ///
@@ -24961,7 +25760,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 457:
+    /// Semantic action for production 469:
     ///
     /// `Factor: IdentifierFactor;`
     ///
@@ -24980,7 +25779,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 458:
+    /// Semantic action for production 470:
     ///
     /// `Factor: LParen Expression RParen;`
     ///
@@ -25008,7 +25807,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 459:
+    /// Semantic action for production 471:
     ///
     /// `Factor: LBrace ConcatenationList RBrace;`
     ///
@@ -25036,7 +25835,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 460:
+    /// Semantic action for production 472:
     ///
     /// `Factor: QuoteLBrace ArrayLiteralList RBrace;`
     ///
@@ -25064,7 +25863,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 461:
+    /// Semantic action for production 473:
     ///
     /// `Factor: CaseExpression;`
     ///
@@ -25083,7 +25882,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 462:
+    /// Semantic action for production 474:
     ///
     /// `Factor: SwitchExpression;`
     ///
@@ -25102,7 +25901,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 463:
+    /// Semantic action for production 475:
     ///
     /// `Factor: StringLiteral;`
     ///
@@ -25121,7 +25920,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 464:
+    /// Semantic action for production 476:
     ///
     /// `Factor: FactorGroup;`
     ///
@@ -25140,7 +25939,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 465:
+    /// Semantic action for production 477:
     ///
     /// `FactorGroup: Msb;`
     ///
@@ -25155,7 +25954,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 466:
+    /// Semantic action for production 478:
     ///
     /// `FactorGroup: Lsb;`
     ///
@@ -25170,7 +25969,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 467:
+    /// Semantic action for production 479:
     ///
     /// `Factor: InsideExpression;`
     ///
@@ -25189,7 +25988,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 468:
+    /// Semantic action for production 480:
     ///
     /// `Factor: OutsideExpression;`
     ///
@@ -25208,7 +26007,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 469:
+    /// Semantic action for production 481:
     ///
     /// `Factor: TypeExpression;`
     ///
@@ -25227,7 +26026,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 470:
+    /// Semantic action for production 482:
     ///
     /// `Factor: FactorTypeFactor;`
     ///
@@ -25246,7 +26045,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 471:
+    /// Semantic action for production 483:
     ///
     /// `BooleanLiteral: True;`
     ///
@@ -25266,7 +26065,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 472:
+    /// Semantic action for production 484:
     ///
     /// `BooleanLiteral: False;`
     ///
@@ -25286,7 +26085,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 473:
+    /// Semantic action for production 485:
     ///
     /// `IdentifierFactor: ExpressionIdentifier IdentifierFactorOpt /* Option */;`
     ///
@@ -25313,7 +26112,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 474:
+    /// Semantic action for production 486:
     ///
     /// `IdentifierFactorOpt /* Option<T>::Some */: IdentifierFactorOptGroup;`
     ///
@@ -25340,7 +26139,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 475:
+    /// Semantic action for production 487:
     ///
     /// `IdentifierFactorOptGroup: FunctionCall;`
     ///
@@ -25361,7 +26160,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 476:
+    /// Semantic action for production 488:
     ///
     /// `IdentifierFactorOptGroup: StructConstructor;`
     ///
@@ -25385,7 +26184,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 477:
+    /// Semantic action for production 489:
     ///
     /// `IdentifierFactorOpt /* Option<T>::None */: ;`
     ///
@@ -25397,7 +26196,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 478:
+    /// Semantic action for production 490:
     ///
     /// `FactorTypeFactor: FactorTypeFactorList /* Vec */ FactorType;`
     ///
@@ -25423,7 +26222,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 479:
+    /// Semantic action for production 491:
     ///
     /// `FactorTypeFactorList /* Vec<T>::Push */: TypeModifier FactorTypeFactorList;`
     ///
@@ -25450,7 +26249,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 480:
+    /// Semantic action for production 492:
     ///
     /// `FactorTypeFactorList /* Vec<T>::New */: ;`
     ///
@@ -25466,7 +26265,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 481:
+    /// Semantic action for production 493:
     ///
     /// `FunctionCall: LParen FunctionCallOpt /* Option */ RParen;`
     ///
@@ -25493,7 +26292,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 482:
+    /// Semantic action for production 494:
     ///
     /// `FunctionCallOpt /* Option<T>::Some */: ArgumentList;`
     ///
@@ -25512,7 +26311,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 483:
+    /// Semantic action for production 495:
     ///
     /// `FunctionCallOpt /* Option<T>::None */: ;`
     ///
@@ -25524,7 +26323,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 484:
+    /// Semantic action for production 496:
     ///
     /// `ArgumentList: ArgumentItem ArgumentListList /* Vec */ ArgumentListOpt /* Option */;`
     ///
@@ -25552,7 +26351,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 485:
+    /// Semantic action for production 497:
     ///
     /// `ArgumentListList /* Vec<T>::Push */: Comma ArgumentItem ArgumentListList;`
     ///
@@ -25578,7 +26377,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 486:
+    /// Semantic action for production 498:
     ///
     /// `ArgumentListList /* Vec<T>::New */: ;`
     ///
@@ -25594,7 +26393,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 487:
+    /// Semantic action for production 499:
     ///
     /// `ArgumentListOpt /* Option<T>::Some */: Comma;`
     ///
@@ -25613,7 +26412,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 488:
+    /// Semantic action for production 500:
     ///
     /// `ArgumentListOpt /* Option<T>::None */: ;`
     ///
@@ -25625,7 +26424,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 489:
+    /// Semantic action for production 501:
     ///
     /// `ArgumentItem: ArgumentExpression ArgumentItemOpt /* Option */;`
     ///
@@ -25649,7 +26448,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 490:
+    /// Semantic action for production 502:
     ///
     /// `ArgumentItemOpt /* Option<T>::Some */: Colon Expression;`
     ///
@@ -25674,7 +26473,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 491:
+    /// Semantic action for production 503:
     ///
     /// `ArgumentItemOpt /* Option<T>::None */: ;`
     ///
@@ -25686,7 +26485,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 492:
+    /// Semantic action for production 504:
     ///
     /// `ArgumentExpression: Expression;`
     ///
@@ -25708,7 +26507,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 493:
+    /// Semantic action for production 505:
     ///
     /// `StructConstructor: QuoteLBrace StructConstructorList StructConstructorOpt /* Option */ RBrace;`
     ///
@@ -25748,7 +26547,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 494:
+    /// Semantic action for production 506:
     ///
     /// `StructConstructorOpt /* Option<T>::Some */: DotDot Defaul LParen Expression RParen;`
     ///
@@ -25782,7 +26581,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 495:
+    /// Semantic action for production 507:
     ///
     /// `StructConstructorOpt /* Option<T>::None */: ;`
     ///
@@ -25794,7 +26593,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 496:
+    /// Semantic action for production 508:
     ///
     /// `StructConstructorList: StructConstructorItem StructConstructorListList /* Vec */ StructConstructorListOpt /* Option */;`
     ///
@@ -25840,7 +26639,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 497:
+    /// Semantic action for production 509:
     ///
     /// `StructConstructorListList /* Vec<T>::Push */: Comma StructConstructorItem StructConstructorListList;`
     ///
@@ -25879,7 +26678,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 498:
+    /// Semantic action for production 510:
     ///
     /// `StructConstructorListList /* Vec<T>::New */: ;`
     ///
@@ -25895,7 +26694,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 499:
+    /// Semantic action for production 511:
     ///
     /// `StructConstructorListOpt /* Option<T>::Some */: Comma;`
     ///
@@ -25914,7 +26713,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 500:
+    /// Semantic action for production 512:
     ///
     /// `StructConstructorListOpt /* Option<T>::None */: ;`
     ///
@@ -25926,7 +26725,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 501:
+    /// Semantic action for production 513:
     ///
     /// `StructConstructorItem: Identifier Colon Expression;`
     ///
@@ -25957,7 +26756,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 502:
+    /// Semantic action for production 514:
     ///
     /// `ConcatenationList: ConcatenationItem ConcatenationListList /* Vec */ ConcatenationListOpt /* Option */;`
     ///
@@ -25994,7 +26793,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 503:
+    /// Semantic action for production 515:
     ///
     /// `ConcatenationListList /* Vec<T>::Push */: Comma ConcatenationItem ConcatenationListList;`
     ///
@@ -26028,7 +26827,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 504:
+    /// Semantic action for production 516:
     ///
     /// `ConcatenationListList /* Vec<T>::New */: ;`
     ///
@@ -26044,7 +26843,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 505:
+    /// Semantic action for production 517:
     ///
     /// `ConcatenationListOpt /* Option<T>::Some */: Comma;`
     ///
@@ -26063,7 +26862,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 506:
+    /// Semantic action for production 518:
     ///
     /// `ConcatenationListOpt /* Option<T>::None */: ;`
     ///
@@ -26075,7 +26874,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 507:
+    /// Semantic action for production 519:
     ///
     /// `ConcatenationItem: Expression ConcatenationItemOpt /* Option */;`
     ///
@@ -26104,7 +26903,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 508:
+    /// Semantic action for production 520:
     ///
     /// `ConcatenationItemOpt /* Option<T>::Some */: Repeat Expression;`
     ///
@@ -26129,7 +26928,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 509:
+    /// Semantic action for production 521:
     ///
     /// `ConcatenationItemOpt /* Option<T>::None */: ;`
     ///
@@ -26141,7 +26940,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 510:
+    /// Semantic action for production 522:
     ///
     /// `ArrayLiteralList: ArrayLiteralItem ArrayLiteralListList /* Vec */ ArrayLiteralListOpt /* Option */;`
     ///
@@ -26171,7 +26970,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 511:
+    /// Semantic action for production 523:
     ///
     /// `ArrayLiteralListList /* Vec<T>::Push */: Comma ArrayLiteralItem ArrayLiteralListList;`
     ///
@@ -26201,7 +27000,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 512:
+    /// Semantic action for production 524:
     ///
     /// `ArrayLiteralListList /* Vec<T>::New */: ;`
     ///
@@ -26217,7 +27016,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 513:
+    /// Semantic action for production 525:
     ///
     /// `ArrayLiteralListOpt /* Option<T>::Some */: Comma;`
     ///
@@ -26236,7 +27035,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 514:
+    /// Semantic action for production 526:
     ///
     /// `ArrayLiteralListOpt /* Option<T>::None */: ;`
     ///
@@ -26248,7 +27047,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 515:
+    /// Semantic action for production 527:
     ///
     /// `ArrayLiteralItem: ArrayLiteralItemGroup;`
     ///
@@ -26272,7 +27071,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 516:
+    /// Semantic action for production 528:
     ///
     /// `ArrayLiteralItemGroup: Expression ArrayLiteralItemOpt /* Option */;`
     ///
@@ -26300,7 +27099,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 517:
+    /// Semantic action for production 529:
     ///
     /// `ArrayLiteralItemGroup: Defaul Colon Expression;`
     ///
@@ -26330,7 +27129,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 518:
+    /// Semantic action for production 530:
     ///
     /// `ArrayLiteralItemOpt /* Option<T>::Some */: Repeat Expression;`
     ///
@@ -26355,7 +27154,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 519:
+    /// Semantic action for production 531:
     ///
     /// `ArrayLiteralItemOpt /* Option<T>::None */: ;`
     ///
@@ -26367,7 +27166,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 520:
+    /// Semantic action for production 532:
     ///
     /// `CaseExpression: Case Expression LBrace CaseCondition Colon Expression Comma CaseExpressionList /* Vec */ Defaul Colon Expression CaseExpressionOpt /* Option */ RBrace;`
     ///
@@ -26425,7 +27224,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 521:
+    /// Semantic action for production 533:
     ///
     /// `CaseExpressionList /* Vec<T>::Push */: CaseCondition Colon Expression Comma CaseExpressionList;`
     ///
@@ -26458,7 +27257,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 522:
+    /// Semantic action for production 534:
     ///
     /// `CaseExpressionList /* Vec<T>::New */: ;`
     ///
@@ -26474,7 +27273,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 523:
+    /// Semantic action for production 535:
     ///
     /// `CaseExpressionOpt /* Option<T>::Some */: Comma;`
     ///
@@ -26493,7 +27292,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 524:
+    /// Semantic action for production 536:
     ///
     /// `CaseExpressionOpt /* Option<T>::None */: ;`
     ///
@@ -26505,7 +27304,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 525:
+    /// Semantic action for production 537:
     ///
     /// `SwitchExpression: Switch LBrace SwitchCondition Colon Expression Comma SwitchExpressionList /* Vec */ Defaul Colon Expression SwitchExpressionOpt /* Option */ RBrace;`
     ///
@@ -26562,7 +27361,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 526:
+    /// Semantic action for production 538:
     ///
     /// `SwitchExpressionList /* Vec<T>::Push */: SwitchCondition Colon Expression Comma SwitchExpressionList;`
     ///
@@ -26598,7 +27397,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 527:
+    /// Semantic action for production 539:
     ///
     /// `SwitchExpressionList /* Vec<T>::New */: ;`
     ///
@@ -26614,7 +27413,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 528:
+    /// Semantic action for production 540:
     ///
     /// `SwitchExpressionOpt /* Option<T>::Some */: Comma;`
     ///
@@ -26633,7 +27432,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 529:
+    /// Semantic action for production 541:
     ///
     /// `SwitchExpressionOpt /* Option<T>::None */: ;`
     ///
@@ -26645,7 +27444,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 530:
+    /// Semantic action for production 542:
     ///
     /// `TypeExpression: Type LParen Expression RParen;`
     ///
@@ -26675,7 +27474,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 531:
+    /// Semantic action for production 543:
     ///
     /// `InsideExpression: Inside Expression LBrace RangeList RBrace;`
     ///
@@ -26709,7 +27508,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 532:
+    /// Semantic action for production 544:
     ///
     /// `OutsideExpression: Outside Expression LBrace RangeList RBrace;`
     ///
@@ -26746,7 +27545,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 533:
+    /// Semantic action for production 545:
     ///
     /// `RangeList: RangeItem RangeListList /* Vec */ RangeListOpt /* Option */;`
     ///
@@ -26773,7 +27572,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 534:
+    /// Semantic action for production 546:
     ///
     /// `RangeListList /* Vec<T>::Push */: Comma RangeItem RangeListList;`
     ///
@@ -26799,7 +27598,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 535:
+    /// Semantic action for production 547:
     ///
     /// `RangeListList /* Vec<T>::New */: ;`
     ///
@@ -26812,7 +27611,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 536:
+    /// Semantic action for production 548:
     ///
     /// `RangeListOpt /* Option<T>::Some */: Comma;`
     ///
@@ -26828,7 +27627,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 537:
+    /// Semantic action for production 549:
     ///
     /// `RangeListOpt /* Option<T>::None */: ;`
     ///
@@ -26840,7 +27639,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 538:
+    /// Semantic action for production 550:
     ///
     /// `RangeItem: Range;`
     ///
@@ -26858,7 +27657,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 539:
+    /// Semantic action for production 551:
     ///
     /// `Select: LBracket Expression SelectOpt /* Option */ RBracket;`
     ///
@@ -26888,7 +27687,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 540:
+    /// Semantic action for production 552:
     ///
     /// `SelectOpt /* Option<T>::Some */: SelectOperator Expression;`
     ///
@@ -26910,7 +27709,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 541:
+    /// Semantic action for production 553:
     ///
     /// `SelectOpt /* Option<T>::None */: ;`
     ///
@@ -26922,7 +27721,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 542:
+    /// Semantic action for production 554:
     ///
     /// `SelectOperator: Colon;`
     ///
@@ -26942,7 +27741,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 543:
+    /// Semantic action for production 555:
     ///
     /// `SelectOperator: PlusColon;`
     ///
@@ -26962,7 +27761,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 544:
+    /// Semantic action for production 556:
     ///
     /// `SelectOperator: MinusColon;`
     ///
@@ -26982,7 +27781,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 545:
+    /// Semantic action for production 557:
     ///
     /// `SelectOperator: Step;`
     ///
@@ -27002,7 +27801,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 546:
+    /// Semantic action for production 558:
     ///
     /// `Width: LAngle Expression WidthList /* Vec */ RAngle;`
     ///
@@ -27032,7 +27831,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 547:
+    /// Semantic action for production 559:
     ///
     /// `WidthList /* Vec<T>::Push */: Comma Expression WidthList;`
     ///
@@ -27058,7 +27857,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 548:
+    /// Semantic action for production 560:
     ///
     /// `WidthList /* Vec<T>::New */: ;`
     ///
@@ -27071,7 +27870,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 549:
+    /// Semantic action for production 561:
     ///
     /// `Array: LBracket Expression ArrayList /* Vec */ RBracket;`
     ///
@@ -27101,7 +27900,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 550:
+    /// Semantic action for production 562:
     ///
     /// `ArrayList /* Vec<T>::Push */: Comma Expression ArrayList;`
     ///
@@ -27127,7 +27926,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 551:
+    /// Semantic action for production 563:
     ///
     /// `ArrayList /* Vec<T>::New */: ;`
     ///
@@ -27140,7 +27939,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 552:
+    /// Semantic action for production 564:
     ///
     /// `Range: Expression RangeOpt /* Option */;`
     ///
@@ -27164,7 +27963,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 553:
+    /// Semantic action for production 565:
     ///
     /// `RangeOpt /* Option<T>::Some */: RangeOperator Expression;`
     ///
@@ -27186,7 +27985,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 554:
+    /// Semantic action for production 566:
     ///
     /// `RangeOpt /* Option<T>::None */: ;`
     ///
@@ -27198,7 +27997,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 555:
+    /// Semantic action for production 567:
     ///
     /// `RangeOperator: DotDot;`
     ///
@@ -27217,7 +28016,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 556:
+    /// Semantic action for production 568:
     ///
     /// `RangeOperator: DotDotEqu;`
     ///
@@ -27236,7 +28035,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 557:
+    /// Semantic action for production 569:
     ///
     /// `FixedType: U8;`
     ///
@@ -27253,7 +28052,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 558:
+    /// Semantic action for production 570:
     ///
     /// `FixedType: U16;`
     ///
@@ -27270,7 +28069,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 559:
+    /// Semantic action for production 571:
     ///
     /// `FixedType: U32;`
     ///
@@ -27287,7 +28086,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 560:
+    /// Semantic action for production 572:
     ///
     /// `FixedType: U64;`
     ///
@@ -27304,7 +28103,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 561:
+    /// Semantic action for production 573:
     ///
     /// `FixedType: I8;`
     ///
@@ -27321,7 +28120,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 562:
+    /// Semantic action for production 574:
     ///
     /// `FixedType: I16;`
     ///
@@ -27338,7 +28137,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 563:
+    /// Semantic action for production 575:
     ///
     /// `FixedType: I32;`
     ///
@@ -27355,7 +28154,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 564:
+    /// Semantic action for production 576:
     ///
     /// `FixedType: I64;`
     ///
@@ -27372,7 +28171,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 565:
+    /// Semantic action for production 577:
     ///
     /// `FixedType: F32;`
     ///
@@ -27389,7 +28188,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 566:
+    /// Semantic action for production 578:
     ///
     /// `FixedType: F64;`
     ///
@@ -27406,7 +28205,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 567:
+    /// Semantic action for production 579:
     ///
     /// `FixedType: Bool;`
     ///
@@ -27425,7 +28224,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 568:
+    /// Semantic action for production 580:
     ///
     /// `FixedType: Strin;`
     ///
@@ -27444,7 +28243,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 569:
+    /// Semantic action for production 581:
     ///
     /// `VariableType: Clock;`
     ///
@@ -27463,7 +28262,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 570:
+    /// Semantic action for production 582:
     ///
     /// `VariableType: ClockPosedge;`
     ///
@@ -27482,7 +28281,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 571:
+    /// Semantic action for production 583:
     ///
     /// `VariableType: ClockNegedge;`
     ///
@@ -27501,7 +28300,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 572:
+    /// Semantic action for production 584:
     ///
     /// `VariableType: Reset;`
     ///
@@ -27520,7 +28319,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 573:
+    /// Semantic action for production 585:
     ///
     /// `VariableType: ResetAsyncHigh;`
     ///
@@ -27539,7 +28338,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 574:
+    /// Semantic action for production 586:
     ///
     /// `VariableType: ResetAsyncLow;`
     ///
@@ -27558,7 +28357,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 575:
+    /// Semantic action for production 587:
     ///
     /// `VariableType: ResetSyncHigh;`
     ///
@@ -27577,7 +28376,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 576:
+    /// Semantic action for production 588:
     ///
     /// `VariableType: ResetSyncLow;`
     ///
@@ -27596,7 +28395,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 577:
+    /// Semantic action for production 589:
     ///
     /// `VariableType: Logic;`
     ///
@@ -27615,7 +28414,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 578:
+    /// Semantic action for production 590:
     ///
     /// `VariableType: Bit;`
     ///
@@ -27632,7 +28431,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 579:
+    /// Semantic action for production 591:
     ///
     /// `UserDefinedType: ScopedIdentifier;`
     ///
@@ -27651,7 +28450,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 580:
+    /// Semantic action for production 592:
     ///
     /// `TypeModifier: Tri;`
     ///
@@ -27668,7 +28467,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 581:
+    /// Semantic action for production 593:
     ///
     /// `TypeModifier: Signed;`
     ///
@@ -27687,7 +28486,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 582:
+    /// Semantic action for production 594:
     ///
     /// `TypeModifier: Defaul;`
     ///
@@ -27706,7 +28505,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 583:
+    /// Semantic action for production 595:
     ///
     /// `FactorType: FactorTypeGroup;`
     ///
@@ -27724,7 +28523,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 584:
+    /// Semantic action for production 596:
     ///
     /// `FactorTypeGroup: VariableType FactorTypeOpt /* Option */;`
     ///
@@ -27748,7 +28547,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 585:
+    /// Semantic action for production 597:
     ///
     /// `FactorTypeGroup: FixedType;`
     ///
@@ -27765,7 +28564,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 586:
+    /// Semantic action for production 598:
     ///
     /// `FactorTypeOpt /* Option<T>::Some */: Width;`
     ///
@@ -27784,7 +28583,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 587:
+    /// Semantic action for production 599:
     ///
     /// `FactorTypeOpt /* Option<T>::None */: ;`
     ///
@@ -27796,7 +28595,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 588:
+    /// Semantic action for production 600:
     ///
     /// `ScalarType: ScalarTypeList /* Vec */ ScalarTypeGroup;`
     ///
@@ -27821,7 +28620,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 589:
+    /// Semantic action for production 601:
     ///
     /// `ScalarTypeGroup: UserDefinedType ScalarTypeOpt /* Option */;`
     ///
@@ -27845,7 +28644,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 590:
+    /// Semantic action for production 602:
     ///
     /// `ScalarTypeGroup: FactorType;`
     ///
@@ -27862,7 +28661,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 591:
+    /// Semantic action for production 603:
     ///
     /// `ScalarTypeList /* Vec<T>::Push */: TypeModifier ScalarTypeList;`
     ///
@@ -27885,7 +28684,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 592:
+    /// Semantic action for production 604:
     ///
     /// `ScalarTypeList /* Vec<T>::New */: ;`
     ///
@@ -27898,7 +28697,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 593:
+    /// Semantic action for production 605:
     ///
     /// `ScalarTypeOpt /* Option<T>::Some */: Width;`
     ///
@@ -27917,7 +28716,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 594:
+    /// Semantic action for production 606:
     ///
     /// `ScalarTypeOpt /* Option<T>::None */: ;`
     ///
@@ -27929,7 +28728,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 595:
+    /// Semantic action for production 607:
     ///
     /// `ArrayType: ScalarType ArrayTypeOpt /* Option */;`
     ///
@@ -27953,7 +28752,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 596:
+    /// Semantic action for production 608:
     ///
     /// `ArrayTypeOpt /* Option<T>::Some */: Array;`
     ///
@@ -27969,7 +28768,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 597:
+    /// Semantic action for production 609:
     ///
     /// `ArrayTypeOpt /* Option<T>::None */: ;`
     ///
@@ -27981,7 +28780,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 598:
+    /// Semantic action for production 610:
     ///
     /// `CastingType: U8;`
     ///
@@ -27998,7 +28797,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 599:
+    /// Semantic action for production 611:
     ///
     /// `CastingType: U16;`
     ///
@@ -28015,7 +28814,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 600:
+    /// Semantic action for production 612:
     ///
     /// `CastingType: U32;`
     ///
@@ -28032,7 +28831,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 601:
+    /// Semantic action for production 613:
     ///
     /// `CastingType: U64;`
     ///
@@ -28049,7 +28848,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 602:
+    /// Semantic action for production 614:
     ///
     /// `CastingType: I8;`
     ///
@@ -28066,7 +28865,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 603:
+    /// Semantic action for production 615:
     ///
     /// `CastingType: I16;`
     ///
@@ -28083,7 +28882,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 604:
+    /// Semantic action for production 616:
     ///
     /// `CastingType: I32;`
     ///
@@ -28100,7 +28899,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 605:
+    /// Semantic action for production 617:
     ///
     /// `CastingType: I64;`
     ///
@@ -28117,7 +28916,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 606:
+    /// Semantic action for production 618:
     ///
     /// `CastingType: F32;`
     ///
@@ -28134,7 +28933,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 607:
+    /// Semantic action for production 619:
     ///
     /// `CastingType: F64;`
     ///
@@ -28151,7 +28950,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 608:
+    /// Semantic action for production 620:
     ///
     /// `CastingType: Bool;`
     ///
@@ -28170,7 +28969,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 609:
+    /// Semantic action for production 621:
     ///
     /// `CastingType: Clock;`
     ///
@@ -28189,7 +28988,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 610:
+    /// Semantic action for production 622:
     ///
     /// `CastingType: ClockPosedge;`
     ///
@@ -28208,7 +29007,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 611:
+    /// Semantic action for production 623:
     ///
     /// `CastingType: ClockNegedge;`
     ///
@@ -28227,7 +29026,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 612:
+    /// Semantic action for production 624:
     ///
     /// `CastingType: Reset;`
     ///
@@ -28246,7 +29045,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 613:
+    /// Semantic action for production 625:
     ///
     /// `CastingType: ResetAsyncHigh;`
     ///
@@ -28265,7 +29064,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 614:
+    /// Semantic action for production 626:
     ///
     /// `CastingType: ResetAsyncLow;`
     ///
@@ -28284,7 +29083,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 615:
+    /// Semantic action for production 627:
     ///
     /// `CastingType: ResetSyncHigh;`
     ///
@@ -28303,7 +29102,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 616:
+    /// Semantic action for production 628:
     ///
     /// `CastingType: ResetSyncLow;`
     ///
@@ -28322,7 +29121,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 617:
+    /// Semantic action for production 629:
     ///
     /// `CastingType: UserDefinedType;`
     ///
@@ -28341,7 +29140,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 618:
+    /// Semantic action for production 630:
     ///
     /// `CastingType: Based;`
     ///
@@ -28360,7 +29159,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 619:
+    /// Semantic action for production 631:
     ///
     /// `CastingType: BaseLess;`
     ///
@@ -28379,7 +29178,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 620:
+    /// Semantic action for production 632:
     ///
     /// `ClockDomain: Quote Identifier;`
     ///
@@ -28403,7 +29202,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 621:
+    /// Semantic action for production 633:
     ///
     /// `StatementBlock: LBrace StatementBlockList /* Vec */ RBrace;`
     ///
@@ -28431,7 +29230,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 622:
+    /// Semantic action for production 634:
     ///
     /// `StatementBlockList /* Vec<T>::Push */: StatementBlockGroup StatementBlockList;`
     ///
@@ -28456,7 +29255,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 623:
+    /// Semantic action for production 635:
     ///
     /// `StatementBlockList /* Vec<T>::New */: ;`
     ///
@@ -28472,7 +29271,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 624:
+    /// Semantic action for production 636:
     ///
     /// `StatementBlockGroup: StatementBlockGroupList /* Vec */ StatementBlockGroupGroup;`
     ///
@@ -28510,7 +29309,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 625:
+    /// Semantic action for production 637:
     ///
     /// `StatementBlockGroupGroup: LBrace StatementBlockGroupGroupList /* Vec */ RBrace;`
     ///
@@ -28548,7 +29347,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 626:
+    /// Semantic action for production 638:
     ///
     /// `StatementBlockGroupGroupList /* Vec<T>::Push */: StatementBlockGroup StatementBlockGroupGroupList;`
     ///
@@ -28580,7 +29379,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 627:
+    /// Semantic action for production 639:
     ///
     /// `StatementBlockGroupGroupList /* Vec<T>::New */: ;`
     ///
@@ -28596,7 +29395,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 628:
+    /// Semantic action for production 640:
     ///
     /// `StatementBlockGroupGroup: StatementBlockItem;`
     ///
@@ -28621,7 +29420,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 629:
+    /// Semantic action for production 641:
     ///
     /// `StatementBlockGroupList /* Vec<T>::Push */: Attribute StatementBlockGroupList;`
     ///
@@ -28652,7 +29451,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 630:
+    /// Semantic action for production 642:
     ///
     /// `StatementBlockGroupList /* Vec<T>::New */: ;`
     ///
@@ -28668,7 +29467,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 631:
+    /// Semantic action for production 643:
     ///
     /// `StatementBlockItem: VarDeclaration;`
     ///
@@ -28692,7 +29491,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 632:
+    /// Semantic action for production 644:
     ///
     /// `StatementBlockItem: LetStatement;`
     ///
@@ -28716,7 +29515,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 633:
+    /// Semantic action for production 645:
     ///
     /// `StatementBlockItem: ConstDeclaration;`
     ///
@@ -28740,7 +29539,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 634:
+    /// Semantic action for production 646:
     ///
     /// `StatementBlockItem: Statement;`
     ///
@@ -28764,7 +29563,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 635:
+    /// Semantic action for production 647:
     ///
     /// `Statement: IdentifierStatement;`
     ///
@@ -28784,7 +29583,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 636:
+    /// Semantic action for production 648:
     ///
     /// `Statement: IfStatement;`
     ///
@@ -28803,7 +29602,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 637:
+    /// Semantic action for production 649:
     ///
     /// `Statement: IfResetStatement;`
     ///
@@ -28822,7 +29621,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 638:
+    /// Semantic action for production 650:
     ///
     /// `Statement: ReturnStatement;`
     ///
@@ -28841,7 +29640,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 639:
+    /// Semantic action for production 651:
     ///
     /// `Statement: BreakStatement;`
     ///
@@ -28860,7 +29659,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 640:
+    /// Semantic action for production 652:
     ///
     /// `Statement: ForStatement;`
     ///
@@ -28879,7 +29678,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 641:
+    /// Semantic action for production 653:
     ///
     /// `Statement: CaseStatement;`
     ///
@@ -28898,7 +29697,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 642:
+    /// Semantic action for production 654:
     ///
     /// `Statement: SwitchStatement;`
     ///
@@ -28917,7 +29716,26 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 643:
+    /// Semantic action for production 655:
+    ///
+    /// `Statement: AssertionStatement;`
+    ///
+    #[parol_runtime::function_name::named]
+    fn statement_8(&mut self, _assertion_statement: &ParseTreeType<'t>) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let assertion_statement = pop_item!(self, assertion_statement, AssertionStatement, context);
+        let statement_8_built = StatementAssertionStatement {
+            assertion_statement: Box::new(assertion_statement),
+        };
+        let statement_8_built = Statement::AssertionStatement(statement_8_built);
+        // Calling user action here
+        self.user_grammar.statement(&statement_8_built)?;
+        self.push(ASTType::Statement(statement_8_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 656:
     ///
     /// `LetStatement: Let Identifier %push(ClockDomain) Colon LetStatementOpt /* Option */ %pop() ArrayType Equ Expression Semicolon;`
     ///
@@ -28959,7 +29777,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 644:
+    /// Semantic action for production 657:
     ///
     /// `LetStatementOpt /* Option<T>::Some */: ClockDomain;`
     ///
@@ -28978,7 +29796,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 645:
+    /// Semantic action for production 658:
     ///
     /// `LetStatementOpt /* Option<T>::None */: ;`
     ///
@@ -28990,7 +29808,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 646:
+    /// Semantic action for production 659:
     ///
     /// `IdentifierStatement: ExpressionIdentifier IdentifierStatementGroup Semicolon;`
     ///
@@ -29027,7 +29845,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 647:
+    /// Semantic action for production 660:
     ///
     /// `IdentifierStatementGroup: FunctionCall;`
     ///
@@ -29048,7 +29866,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 648:
+    /// Semantic action for production 661:
     ///
     /// `IdentifierStatementGroup: Assignment;`
     ///
@@ -29069,7 +29887,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 649:
+    /// Semantic action for production 662:
     ///
     /// `Assignment: AssignmentGroup Expression;`
     ///
@@ -29093,7 +29911,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 650:
+    /// Semantic action for production 663:
     ///
     /// `AssignmentGroup: Equ;`
     ///
@@ -29108,7 +29926,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 651:
+    /// Semantic action for production 664:
     ///
     /// `AssignmentGroup: AssignmentOperator;`
     ///
@@ -29126,7 +29944,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 652:
+    /// Semantic action for production 665:
     ///
     /// `AssignmentGroup: DiamondOperator;`
     ///
@@ -29143,7 +29961,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 653:
+    /// Semantic action for production 666:
     ///
     /// `IfStatement: If Expression StatementBlock IfStatementList /* Vec */ IfStatementOpt /* Option */;`
     ///
@@ -29177,7 +29995,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 654:
+    /// Semantic action for production 667:
     ///
     /// `IfStatementList /* Vec<T>::Push */: Else If Expression StatementBlock IfStatementList;`
     ///
@@ -29209,7 +30027,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 655:
+    /// Semantic action for production 668:
     ///
     /// `IfStatementList /* Vec<T>::New */: ;`
     ///
@@ -29222,7 +30040,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 656:
+    /// Semantic action for production 669:
     ///
     /// `IfStatementOpt /* Option<T>::Some */: Else StatementBlock;`
     ///
@@ -29247,7 +30065,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 657:
+    /// Semantic action for production 670:
     ///
     /// `IfStatementOpt /* Option<T>::None */: ;`
     ///
@@ -29259,7 +30077,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 658:
+    /// Semantic action for production 671:
     ///
     /// `IfResetStatement: IfReset StatementBlock IfResetStatementList /* Vec */ IfResetStatementOpt /* Option */;`
     ///
@@ -29292,7 +30110,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 659:
+    /// Semantic action for production 672:
     ///
     /// `IfResetStatementList /* Vec<T>::Push */: Else If Expression StatementBlock IfResetStatementList;`
     ///
@@ -29328,7 +30146,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 660:
+    /// Semantic action for production 673:
     ///
     /// `IfResetStatementList /* Vec<T>::New */: ;`
     ///
@@ -29344,7 +30162,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 661:
+    /// Semantic action for production 674:
     ///
     /// `IfResetStatementOpt /* Option<T>::Some */: Else StatementBlock;`
     ///
@@ -29369,7 +30187,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 662:
+    /// Semantic action for production 675:
     ///
     /// `IfResetStatementOpt /* Option<T>::None */: ;`
     ///
@@ -29381,7 +30199,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 663:
+    /// Semantic action for production 676:
     ///
     /// `ReturnStatement: Return Expression Semicolon;`
     ///
@@ -29409,7 +30227,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 664:
+    /// Semantic action for production 677:
     ///
     /// `BreakStatement: Break Semicolon;`
     ///
@@ -29433,7 +30251,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 665:
+    /// Semantic action for production 678:
     ///
     /// `ForStatement: For Identifier Colon ScalarType In Range ForStatementOpt /* Option */ StatementBlock;`
     ///
@@ -29475,7 +30293,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 666:
+    /// Semantic action for production 679:
     ///
     /// `ForStatementOpt /* Option<T>::Some */: Step AssignmentOperator Expression;`
     ///
@@ -29503,7 +30321,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 667:
+    /// Semantic action for production 680:
     ///
     /// `ForStatementOpt /* Option<T>::None */: ;`
     ///
@@ -29515,7 +30333,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 668:
+    /// Semantic action for production 681:
     ///
     /// `CaseStatement: Case Expression LBrace CaseStatementList /* Vec */ RBrace;`
     ///
@@ -29549,7 +30367,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 669:
+    /// Semantic action for production 682:
     ///
     /// `CaseStatementList /* Vec<T>::Push */: CaseItem CaseStatementList;`
     ///
@@ -29573,7 +30391,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 670:
+    /// Semantic action for production 683:
     ///
     /// `CaseStatementList /* Vec<T>::New */: ;`
     ///
@@ -29589,7 +30407,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 671:
+    /// Semantic action for production 684:
     ///
     /// `CaseItem: CaseItemGroup Colon CaseItemGroup0;`
     ///
@@ -29616,7 +30434,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 672:
+    /// Semantic action for production 685:
     ///
     /// `CaseItemGroup0: Statement;`
     ///
@@ -29633,7 +30451,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 673:
+    /// Semantic action for production 686:
     ///
     /// `CaseItemGroup0: StatementBlock;`
     ///
@@ -29650,7 +30468,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 674:
+    /// Semantic action for production 687:
     ///
     /// `CaseItemGroup: CaseCondition;`
     ///
@@ -29667,7 +30485,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 675:
+    /// Semantic action for production 688:
     ///
     /// `CaseItemGroup: Defaul;`
     ///
@@ -29684,7 +30502,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 676:
+    /// Semantic action for production 689:
     ///
     /// `CaseCondition: RangeItem CaseConditionList /* Vec */;`
     ///
@@ -29709,7 +30527,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 677:
+    /// Semantic action for production 690:
     ///
     /// `CaseConditionList /* Vec<T>::Push */: Comma RangeItem CaseConditionList;`
     ///
@@ -29736,7 +30554,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 678:
+    /// Semantic action for production 691:
     ///
     /// `CaseConditionList /* Vec<T>::New */: ;`
     ///
@@ -29752,7 +30570,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 679:
+    /// Semantic action for production 692:
     ///
     /// `SwitchStatement: Switch LBrace SwitchStatementList /* Vec */ RBrace;`
     ///
@@ -29784,7 +30602,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 680:
+    /// Semantic action for production 693:
     ///
     /// `SwitchStatementList /* Vec<T>::Push */: SwitchItem SwitchStatementList;`
     ///
@@ -29808,7 +30626,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 681:
+    /// Semantic action for production 694:
     ///
     /// `SwitchStatementList /* Vec<T>::New */: ;`
     ///
@@ -29824,7 +30642,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 682:
+    /// Semantic action for production 695:
     ///
     /// `SwitchItem: SwitchItemGroup Colon SwitchItemGroup0;`
     ///
@@ -29851,7 +30669,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 683:
+    /// Semantic action for production 696:
     ///
     /// `SwitchItemGroup0: Statement;`
     ///
@@ -29871,7 +30689,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 684:
+    /// Semantic action for production 697:
     ///
     /// `SwitchItemGroup0: StatementBlock;`
     ///
@@ -29892,7 +30710,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 685:
+    /// Semantic action for production 698:
     ///
     /// `SwitchItemGroup: SwitchCondition;`
     ///
@@ -29909,7 +30727,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 686:
+    /// Semantic action for production 699:
     ///
     /// `SwitchItemGroup: Defaul;`
     ///
@@ -29926,7 +30744,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 687:
+    /// Semantic action for production 700:
     ///
     /// `SwitchCondition: Expression SwitchConditionList /* Vec */;`
     ///
@@ -29952,7 +30770,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 688:
+    /// Semantic action for production 701:
     ///
     /// `SwitchConditionList /* Vec<T>::Push */: Comma Expression SwitchConditionList;`
     ///
@@ -29979,7 +30797,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 689:
+    /// Semantic action for production 702:
     ///
     /// `SwitchConditionList /* Vec<T>::New */: ;`
     ///
@@ -29995,7 +30813,107 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 690:
+    /// Semantic action for production 703:
+    ///
+    /// `AssertionStatement: AssertionKeyword Expression Semicolon;`
+    ///
+    #[parol_runtime::function_name::named]
+    fn assertion_statement(
+        &mut self,
+        _assertion_keyword: &ParseTreeType<'t>,
+        _expression: &ParseTreeType<'t>,
+        _semicolon: &ParseTreeType<'t>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let semicolon = pop_item!(self, semicolon, Semicolon, context);
+        let expression = pop_item!(self, expression, Expression, context);
+        let assertion_keyword = pop_item!(self, assertion_keyword, AssertionKeyword, context);
+        let assertion_statement_built = AssertionStatement {
+            assertion_keyword: Box::new(assertion_keyword),
+            expression: Box::new(expression),
+            semicolon: Box::new(semicolon),
+        };
+        // Calling user action here
+        self.user_grammar
+            .assertion_statement(&assertion_statement_built)?;
+        self.push(
+            ASTType::AssertionStatement(assertion_statement_built),
+            context,
+        );
+        Ok(())
+    }
+
+    /// Semantic action for production 704:
+    ///
+    /// `AssertionKeyword: Assert;`
+    ///
+    #[parol_runtime::function_name::named]
+    fn assertion_keyword_0(&mut self, _assert: &ParseTreeType<'t>) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let assert = pop_item!(self, assert, Assert, context);
+        let assertion_keyword_0_built = AssertionKeywordAssert {
+            assert: Box::new(assert),
+        };
+        let assertion_keyword_0_built = AssertionKeyword::Assert(assertion_keyword_0_built);
+        // Calling user action here
+        self.user_grammar
+            .assertion_keyword(&assertion_keyword_0_built)?;
+        self.push(
+            ASTType::AssertionKeyword(assertion_keyword_0_built),
+            context,
+        );
+        Ok(())
+    }
+
+    /// Semantic action for production 705:
+    ///
+    /// `AssertionKeyword: Assume;`
+    ///
+    #[parol_runtime::function_name::named]
+    fn assertion_keyword_1(&mut self, _assume: &ParseTreeType<'t>) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let assume = pop_item!(self, assume, Assume, context);
+        let assertion_keyword_1_built = AssertionKeywordAssume {
+            assume: Box::new(assume),
+        };
+        let assertion_keyword_1_built = AssertionKeyword::Assume(assertion_keyword_1_built);
+        // Calling user action here
+        self.user_grammar
+            .assertion_keyword(&assertion_keyword_1_built)?;
+        self.push(
+            ASTType::AssertionKeyword(assertion_keyword_1_built),
+            context,
+        );
+        Ok(())
+    }
+
+    /// Semantic action for production 706:
+    ///
+    /// `AssertionKeyword: Cover;`
+    ///
+    #[parol_runtime::function_name::named]
+    fn assertion_keyword_2(&mut self, _cover: &ParseTreeType<'t>) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let cover = pop_item!(self, cover, Cover, context);
+        let assertion_keyword_2_built = AssertionKeywordCover {
+            cover: Box::new(cover),
+        };
+        let assertion_keyword_2_built = AssertionKeyword::Cover(assertion_keyword_2_built);
+        // Calling user action here
+        self.user_grammar
+            .assertion_keyword(&assertion_keyword_2_built)?;
+        self.push(
+            ASTType::AssertionKeyword(assertion_keyword_2_built),
+            context,
+        );
+        Ok(())
+    }
+
+    /// Semantic action for production 707:
     ///
     /// `Attribute: Hash %push(Attr) LBracket Identifier AttributeOpt /* Option */ RBracket %pop();`
     ///
@@ -30028,7 +30946,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 691:
+    /// Semantic action for production 708:
     ///
     /// `AttributeOpt /* Option<T>::Some */: LParen AttributeList RParen;`
     ///
@@ -30053,7 +30971,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 692:
+    /// Semantic action for production 709:
     ///
     /// `AttributeOpt /* Option<T>::None */: ;`
     ///
@@ -30065,7 +30983,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 693:
+    /// Semantic action for production 710:
     ///
     /// `AttributeList: AttributeItem AttributeListList /* Vec */ AttributeListOpt /* Option */;`
     ///
@@ -30093,7 +31011,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 694:
+    /// Semantic action for production 711:
     ///
     /// `AttributeListList /* Vec<T>::Push */: Comma AttributeItem AttributeListList;`
     ///
@@ -30120,7 +31038,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 695:
+    /// Semantic action for production 712:
     ///
     /// `AttributeListList /* Vec<T>::New */: ;`
     ///
@@ -30136,7 +31054,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 696:
+    /// Semantic action for production 713:
     ///
     /// `AttributeListOpt /* Option<T>::Some */: Comma;`
     ///
@@ -30155,7 +31073,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 697:
+    /// Semantic action for production 714:
     ///
     /// `AttributeListOpt /* Option<T>::None */: ;`
     ///
@@ -30167,7 +31085,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 698:
+    /// Semantic action for production 715:
     ///
     /// `AttributeItem: Identifier;`
     ///
@@ -30186,7 +31104,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 699:
+    /// Semantic action for production 716:
     ///
     /// `AttributeItem: StringLiteral;`
     ///
@@ -30205,7 +31123,337 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 700:
+    /// Semantic action for production 717:
+    ///
+    /// `AttributeItem: AttributeAssignment;`
+    ///
+    #[parol_runtime::function_name::named]
+    fn attribute_item_2(&mut self, _attribute_assignment: &ParseTreeType<'t>) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let attribute_assignment =
+            pop_item!(self, attribute_assignment, AttributeAssignment, context);
+        let attribute_item_2_built = AttributeItemAttributeAssignment {
+            attribute_assignment: Box::new(attribute_assignment),
+        };
+        let attribute_item_2_built = AttributeItem::AttributeAssignment(attribute_item_2_built);
+        // Calling user action here
+        self.user_grammar.attribute_item(&attribute_item_2_built)?;
+        self.push(ASTType::AttributeItem(attribute_item_2_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 718:
+    ///
+    /// `AttributeItem: AttributeCall;`
+    ///
+    #[parol_runtime::function_name::named]
+    fn attribute_item_3(&mut self, _attribute_call: &ParseTreeType<'t>) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let attribute_call = pop_item!(self, attribute_call, AttributeCall, context);
+        let attribute_item_3_built = AttributeItemAttributeCall {
+            attribute_call: Box::new(attribute_call),
+        };
+        let attribute_item_3_built = AttributeItem::AttributeCall(attribute_item_3_built);
+        // Calling user action here
+        self.user_grammar.attribute_item(&attribute_item_3_built)?;
+        self.push(ASTType::AttributeItem(attribute_item_3_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 719:
+    ///
+    /// `AttributeAssignment: Identifier Equ AttributeValue;`
+    ///
+    #[parol_runtime::function_name::named]
+    fn attribute_assignment(
+        &mut self,
+        _identifier: &ParseTreeType<'t>,
+        _equ: &ParseTreeType<'t>,
+        _attribute_value: &ParseTreeType<'t>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let attribute_value = pop_item!(self, attribute_value, AttributeValue, context);
+        let equ = pop_item!(self, equ, Equ, context);
+        let identifier = pop_item!(self, identifier, Identifier, context);
+        let attribute_assignment_built = AttributeAssignment {
+            identifier: Box::new(identifier),
+            equ: Box::new(equ),
+            attribute_value: Box::new(attribute_value),
+        };
+        // Calling user action here
+        self.user_grammar
+            .attribute_assignment(&attribute_assignment_built)?;
+        self.push(
+            ASTType::AttributeAssignment(attribute_assignment_built),
+            context,
+        );
+        Ok(())
+    }
+
+    /// Semantic action for production 720:
+    ///
+    /// `AttributeCall: Identifier LParen AttributeList RParen;`
+    ///
+    #[parol_runtime::function_name::named]
+    fn attribute_call(
+        &mut self,
+        _identifier: &ParseTreeType<'t>,
+        _l_paren: &ParseTreeType<'t>,
+        _attribute_list: &ParseTreeType<'t>,
+        _r_paren: &ParseTreeType<'t>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let r_paren = pop_item!(self, r_paren, RParen, context);
+        let attribute_list = pop_item!(self, attribute_list, AttributeList, context);
+        let l_paren = pop_item!(self, l_paren, LParen, context);
+        let identifier = pop_item!(self, identifier, Identifier, context);
+        let attribute_call_built = AttributeCall {
+            identifier: Box::new(identifier),
+            l_paren: Box::new(l_paren),
+            attribute_list: Box::new(attribute_list),
+            r_paren: Box::new(r_paren),
+        };
+        // Calling user action here
+        self.user_grammar.attribute_call(&attribute_call_built)?;
+        self.push(ASTType::AttributeCall(attribute_call_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 721:
+    ///
+    /// `AttributeValue: IntegralNumber;`
+    ///
+    #[parol_runtime::function_name::named]
+    fn attribute_value_0(&mut self, _integral_number: &ParseTreeType<'t>) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let integral_number = pop_item!(self, integral_number, IntegralNumber, context);
+        let attribute_value_0_built = AttributeValueIntegralNumber {
+            integral_number: Box::new(integral_number),
+        };
+        let attribute_value_0_built = AttributeValue::IntegralNumber(attribute_value_0_built);
+        // Calling user action here
+        self.user_grammar
+            .attribute_value(&attribute_value_0_built)?;
+        self.push(ASTType::AttributeValue(attribute_value_0_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 722:
+    ///
+    /// `AttributeValue: StringLiteral;`
+    ///
+    #[parol_runtime::function_name::named]
+    fn attribute_value_1(&mut self, _string_literal: &ParseTreeType<'t>) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let string_literal = pop_item!(self, string_literal, StringLiteral, context);
+        let attribute_value_1_built = AttributeValueStringLiteral {
+            string_literal: Box::new(string_literal),
+        };
+        let attribute_value_1_built = AttributeValue::StringLiteral(attribute_value_1_built);
+        // Calling user action here
+        self.user_grammar
+            .attribute_value(&attribute_value_1_built)?;
+        self.push(ASTType::AttributeValue(attribute_value_1_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 723:
+    ///
+    /// `AttributeValue: Identifier;`
+    ///
+    #[parol_runtime::function_name::named]
+    fn attribute_value_2(&mut self, _identifier: &ParseTreeType<'t>) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let identifier = pop_item!(self, identifier, Identifier, context);
+        let attribute_value_2_built = AttributeValueIdentifier {
+            identifier: Box::new(identifier),
+        };
+        let attribute_value_2_built = AttributeValue::Identifier(attribute_value_2_built);
+        // Calling user action here
+        self.user_grammar
+            .attribute_value(&attribute_value_2_built)?;
+        self.push(ASTType::AttributeValue(attribute_value_2_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 724:
+    ///
+    /// `AttributeValue: AttributeArray;`
+    ///
+    #[parol_runtime::function_name::named]
+    fn attribute_value_3(&mut self, _attribute_array: &ParseTreeType<'t>) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let attribute_array = pop_item!(self, attribute_array, AttributeArray, context);
+        let attribute_value_3_built = AttributeValueAttributeArray {
+            attribute_array: Box::new(attribute_array),
+        };
+        let attribute_value_3_built = AttributeValue::AttributeArray(attribute_value_3_built);
+        // Calling user action here
+        self.user_grammar
+            .attribute_value(&attribute_value_3_built)?;
+        self.push(ASTType::AttributeValue(attribute_value_3_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 725:
+    ///
+    /// `AttributeArray: LBracket AttributeValueList RBracket;`
+    ///
+    #[parol_runtime::function_name::named]
+    fn attribute_array(
+        &mut self,
+        _l_bracket: &ParseTreeType<'t>,
+        _attribute_value_list: &ParseTreeType<'t>,
+        _r_bracket: &ParseTreeType<'t>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let r_bracket = pop_item!(self, r_bracket, RBracket, context);
+        let attribute_value_list =
+            pop_item!(self, attribute_value_list, AttributeValueList, context);
+        let l_bracket = pop_item!(self, l_bracket, LBracket, context);
+        let attribute_array_built = AttributeArray {
+            l_bracket: Box::new(l_bracket),
+            attribute_value_list: Box::new(attribute_value_list),
+            r_bracket: Box::new(r_bracket),
+        };
+        // Calling user action here
+        self.user_grammar.attribute_array(&attribute_array_built)?;
+        self.push(ASTType::AttributeArray(attribute_array_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 726:
+    ///
+    /// `AttributeValueList: AttributeValue AttributeValueListList /* Vec */ AttributeValueListOpt /* Option */;`
+    ///
+    #[parol_runtime::function_name::named]
+    fn attribute_value_list(
+        &mut self,
+        _attribute_value: &ParseTreeType<'t>,
+        _attribute_value_list_list: &ParseTreeType<'t>,
+        _attribute_value_list_opt: &ParseTreeType<'t>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let attribute_value_list_opt = pop_item!(
+            self,
+            attribute_value_list_opt,
+            AttributeValueListOpt,
+            context
+        );
+        let attribute_value_list_list = pop_and_reverse_item!(
+            self,
+            attribute_value_list_list,
+            AttributeValueListList,
+            context
+        );
+        let attribute_value = pop_item!(self, attribute_value, AttributeValue, context);
+        let attribute_value_list_built = AttributeValueList {
+            attribute_value: Box::new(attribute_value),
+            attribute_value_list_list,
+            attribute_value_list_opt,
+        };
+        // Calling user action here
+        self.user_grammar
+            .attribute_value_list(&attribute_value_list_built)?;
+        self.push(
+            ASTType::AttributeValueList(attribute_value_list_built),
+            context,
+        );
+        Ok(())
+    }
+
+    /// Semantic action for production 727:
+    ///
+    /// `AttributeValueListList /* Vec<T>::Push */: Comma AttributeValue AttributeValueListList;`
+    ///
+    #[parol_runtime::function_name::named]
+    fn attribute_value_list_list_0(
+        &mut self,
+        _comma: &ParseTreeType<'t>,
+        _attribute_value: &ParseTreeType<'t>,
+        _attribute_value_list_list: &ParseTreeType<'t>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let mut attribute_value_list_list = pop_item!(
+            self,
+            attribute_value_list_list,
+            AttributeValueListList,
+            context
+        );
+        let attribute_value = pop_item!(self, attribute_value, AttributeValue, context);
+        let comma = pop_item!(self, comma, Comma, context);
+        let attribute_value_list_list_0_built = AttributeValueListList {
+            attribute_value: Box::new(attribute_value),
+            comma: Box::new(comma),
+        };
+        // Add an element to the vector
+        attribute_value_list_list.push(attribute_value_list_list_0_built);
+        self.push(
+            ASTType::AttributeValueListList(attribute_value_list_list),
+            context,
+        );
+        Ok(())
+    }
+
+    /// Semantic action for production 728:
+    ///
+    /// `AttributeValueListList /* Vec<T>::New */: ;`
+    ///
+    #[parol_runtime::function_name::named]
+    fn attribute_value_list_list_1(&mut self) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let attribute_value_list_list_1_built = Vec::new();
+        self.push(
+            ASTType::AttributeValueListList(attribute_value_list_list_1_built),
+            context,
+        );
+        Ok(())
+    }
+
+    /// Semantic action for production 729:
+    ///
+    /// `AttributeValueListOpt /* Option<T>::Some */: Comma;`
+    ///
+    #[parol_runtime::function_name::named]
+    fn attribute_value_list_opt_0(&mut self, _comma: &ParseTreeType<'t>) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let comma = pop_item!(self, comma, Comma, context);
+        let attribute_value_list_opt_0_built = AttributeValueListOpt {
+            comma: Box::new(comma),
+        };
+        self.push(
+            ASTType::AttributeValueListOpt(Some(attribute_value_list_opt_0_built)),
+            context,
+        );
+        Ok(())
+    }
+
+    /// Semantic action for production 730:
+    ///
+    /// `AttributeValueListOpt /* Option<T>::None */: ;`
+    ///
+    #[parol_runtime::function_name::named]
+    fn attribute_value_list_opt_1(&mut self) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        self.push(ASTType::AttributeValueListOpt(None), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 731:
     ///
     /// `LetDeclaration: Let Identifier %push(ClockDomain) Colon LetDeclarationOpt /* Option */ %pop() ArrayType Equ Expression Semicolon;`
     ///
@@ -30247,7 +31495,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 701:
+    /// Semantic action for production 732:
     ///
     /// `LetDeclarationOpt /* Option<T>::Some */: ClockDomain;`
     ///
@@ -30266,7 +31514,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 702:
+    /// Semantic action for production 733:
     ///
     /// `LetDeclarationOpt /* Option<T>::None */: ;`
     ///
@@ -30278,7 +31526,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 703:
+    /// Semantic action for production 734:
     ///
     /// `VarDeclaration: Var Identifier %push(ClockDomain) Colon VarDeclarationOpt /* Option */ %pop() ArrayType Semicolon;`
     ///
@@ -30314,7 +31562,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 704:
+    /// Semantic action for production 735:
     ///
     /// `VarDeclarationOpt /* Option<T>::Some */: ClockDomain;`
     ///
@@ -30333,7 +31581,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 705:
+    /// Semantic action for production 736:
     ///
     /// `VarDeclarationOpt /* Option<T>::None */: ;`
     ///
@@ -30345,7 +31593,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 706:
+    /// Semantic action for production 737:
     ///
     /// `ConstDeclaration: Const Identifier Colon ConstDeclarationGroup Equ Expression Semicolon;`
     ///
@@ -30390,7 +31638,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 707:
+    /// Semantic action for production 738:
     ///
     /// `ConstDeclarationGroup: ArrayType;`
     ///
@@ -30411,7 +31659,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 708:
+    /// Semantic action for production 739:
     ///
     /// `ConstDeclarationGroup: Type;`
     ///
@@ -30432,7 +31680,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 709:
+    /// Semantic action for production 740:
     ///
     /// `TypeDefDeclaration: Type Identifier Equ ArrayType Semicolon;`
     ///
@@ -30469,7 +31717,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 710:
+    /// Semantic action for production 741:
     ///
     /// `AlwaysFfDeclaration: AlwaysFf AlwaysFfDeclarationOpt /* Option */ StatementBlock;`
     ///
@@ -30505,7 +31753,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 711:
+    /// Semantic action for production 742:
     ///
     /// `AlwaysFfDeclarationOpt /* Option<T>::Some */: AlwaysFfEventList;`
     ///
@@ -30528,7 +31776,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 712:
+    /// Semantic action for production 743:
     ///
     /// `AlwaysFfDeclarationOpt /* Option<T>::None */: ;`
     ///
@@ -30540,7 +31788,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 713:
+    /// Semantic action for production 744:
     ///
     /// `AlwaysFfEventList: LParen AlwaysFfClock AlwaysFfEventListOpt /* Option */ RParen;`
     ///
@@ -30579,7 +31827,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 714:
+    /// Semantic action for production 745:
     ///
     /// `AlwaysFfEventListOpt /* Option<T>::Some */: Comma AlwaysFfReset;`
     ///
@@ -30604,7 +31852,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 715:
+    /// Semantic action for production 746:
     ///
     /// `AlwaysFfEventListOpt /* Option<T>::None */: ;`
     ///
@@ -30616,7 +31864,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 716:
+    /// Semantic action for production 747:
     ///
     /// `AlwaysFfClock: HierarchicalIdentifier;`
     ///
@@ -30639,7 +31887,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 717:
+    /// Semantic action for production 748:
     ///
     /// `AlwaysFfReset: HierarchicalIdentifier;`
     ///
@@ -30662,7 +31910,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 718:
+    /// Semantic action for production 749:
     ///
     /// `AlwaysCombDeclaration: AlwaysComb StatementBlock;`
     ///
@@ -30690,7 +31938,168 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 719:
+    /// Semantic action for production 750:
+    ///
+    /// `AssertionDeclaration: AssertionKeyword AssertionDeclarationOpt /* Option */ AssertionDeclarationOpt0 /* Option */ LBrace Expression AssertionDeclarationOpt1 /* Option */ RBrace;`
+    ///
+    #[parol_runtime::function_name::named]
+    fn assertion_declaration(
+        &mut self,
+        _assertion_keyword: &ParseTreeType<'t>,
+        _assertion_declaration_opt: &ParseTreeType<'t>,
+        _assertion_declaration_opt0: &ParseTreeType<'t>,
+        _l_brace: &ParseTreeType<'t>,
+        _expression: &ParseTreeType<'t>,
+        _assertion_declaration_opt1: &ParseTreeType<'t>,
+        _r_brace: &ParseTreeType<'t>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let r_brace = pop_item!(self, r_brace, RBrace, context);
+        let assertion_declaration_opt1 = pop_item!(
+            self,
+            assertion_declaration_opt1,
+            AssertionDeclarationOpt1,
+            context
+        );
+        let expression = pop_item!(self, expression, Expression, context);
+        let l_brace = pop_item!(self, l_brace, LBrace, context);
+        let assertion_declaration_opt0 = pop_item!(
+            self,
+            assertion_declaration_opt0,
+            AssertionDeclarationOpt0,
+            context
+        );
+        let assertion_declaration_opt = pop_item!(
+            self,
+            assertion_declaration_opt,
+            AssertionDeclarationOpt,
+            context
+        );
+        let assertion_keyword = pop_item!(self, assertion_keyword, AssertionKeyword, context);
+        let assertion_declaration_built = AssertionDeclaration {
+            assertion_keyword: Box::new(assertion_keyword),
+            assertion_declaration_opt,
+            assertion_declaration_opt0,
+            l_brace: Box::new(l_brace),
+            expression: Box::new(expression),
+            assertion_declaration_opt1,
+            r_brace: Box::new(r_brace),
+        };
+        // Calling user action here
+        self.user_grammar
+            .assertion_declaration(&assertion_declaration_built)?;
+        self.push(
+            ASTType::AssertionDeclaration(assertion_declaration_built),
+            context,
+        );
+        Ok(())
+    }
+
+    /// Semantic action for production 751:
+    ///
+    /// `AssertionDeclarationOpt1 /* Option<T>::Some */: Implication Expression;`
+    ///
+    #[parol_runtime::function_name::named]
+    fn assertion_declaration_opt1_0(
+        &mut self,
+        _implication: &ParseTreeType<'t>,
+        _expression: &ParseTreeType<'t>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let expression = pop_item!(self, expression, Expression, context);
+        let implication = pop_item!(self, implication, Implication, context);
+        let assertion_declaration_opt1_0_built = AssertionDeclarationOpt1 {
+            implication: Box::new(implication),
+            expression: Box::new(expression),
+        };
+        self.push(
+            ASTType::AssertionDeclarationOpt1(Some(assertion_declaration_opt1_0_built)),
+            context,
+        );
+        Ok(())
+    }
+
+    /// Semantic action for production 752:
+    ///
+    /// `AssertionDeclarationOpt1 /* Option<T>::None */: ;`
+    ///
+    #[parol_runtime::function_name::named]
+    fn assertion_declaration_opt1_1(&mut self) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        self.push(ASTType::AssertionDeclarationOpt1(None), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 753:
+    ///
+    /// `AssertionDeclarationOpt0 /* Option<T>::Some */: AlwaysFfEventList;`
+    ///
+    #[parol_runtime::function_name::named]
+    fn assertion_declaration_opt0_0(
+        &mut self,
+        _always_ff_event_list: &ParseTreeType<'t>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let always_ff_event_list =
+            pop_item!(self, always_ff_event_list, AlwaysFfEventList, context);
+        let assertion_declaration_opt0_0_built = AssertionDeclarationOpt0 {
+            always_ff_event_list: Box::new(always_ff_event_list),
+        };
+        self.push(
+            ASTType::AssertionDeclarationOpt0(Some(assertion_declaration_opt0_0_built)),
+            context,
+        );
+        Ok(())
+    }
+
+    /// Semantic action for production 754:
+    ///
+    /// `AssertionDeclarationOpt0 /* Option<T>::None */: ;`
+    ///
+    #[parol_runtime::function_name::named]
+    fn assertion_declaration_opt0_1(&mut self) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        self.push(ASTType::AssertionDeclarationOpt0(None), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 755:
+    ///
+    /// `AssertionDeclarationOpt /* Option<T>::Some */: Identifier;`
+    ///
+    #[parol_runtime::function_name::named]
+    fn assertion_declaration_opt_0(&mut self, _identifier: &ParseTreeType<'t>) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let identifier = pop_item!(self, identifier, Identifier, context);
+        let assertion_declaration_opt_0_built = AssertionDeclarationOpt {
+            identifier: Box::new(identifier),
+        };
+        self.push(
+            ASTType::AssertionDeclarationOpt(Some(assertion_declaration_opt_0_built)),
+            context,
+        );
+        Ok(())
+    }
+
+    /// Semantic action for production 756:
+    ///
+    /// `AssertionDeclarationOpt /* Option<T>::None */: ;`
+    ///
+    #[parol_runtime::function_name::named]
+    fn assertion_declaration_opt_1(&mut self) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        self.push(ASTType::AssertionDeclarationOpt(None), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 757:
     ///
     /// `AssignDeclaration: Assign AssignDestination Equ Expression Semicolon;`
     ///
@@ -30727,7 +32136,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 720:
+    /// Semantic action for production 758:
     ///
     /// `AssignDestination: HierarchicalIdentifier;`
     ///
@@ -30756,7 +32165,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 721:
+    /// Semantic action for production 759:
     ///
     /// `AssignDestination: LBrace AssignConcatenationList RBrace;`
     ///
@@ -30794,7 +32203,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 722:
+    /// Semantic action for production 760:
     ///
     /// `AssignConcatenationList: AssignConcatenationItem AssignConcatenationListList /* Vec */ AssignConcatenationListOpt /* Option */;`
     ///
@@ -30840,7 +32249,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 723:
+    /// Semantic action for production 761:
     ///
     /// `AssignConcatenationListList /* Vec<T>::Push */: Comma AssignConcatenationItem AssignConcatenationListList;`
     ///
@@ -30879,7 +32288,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 724:
+    /// Semantic action for production 762:
     ///
     /// `AssignConcatenationListList /* Vec<T>::New */: ;`
     ///
@@ -30895,7 +32304,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 725:
+    /// Semantic action for production 763:
     ///
     /// `AssignConcatenationListOpt /* Option<T>::Some */: Comma;`
     ///
@@ -30914,7 +32323,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 726:
+    /// Semantic action for production 764:
     ///
     /// `AssignConcatenationListOpt /* Option<T>::None */: ;`
     ///
@@ -30926,7 +32335,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 727:
+    /// Semantic action for production 765:
     ///
     /// `AssignConcatenationItem: HierarchicalIdentifier;`
     ///
@@ -30956,7 +32365,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 728:
+    /// Semantic action for production 766:
     ///
     /// `ConnectDeclaration: Connect HierarchicalIdentifier DiamondOperator Expression Semicolon;`
     ///
@@ -30998,7 +32407,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 729:
+    /// Semantic action for production 767:
     ///
     /// `ModportDeclaration: Modport Identifier LBrace ModportDeclarationOpt /* Option */ ModportDeclarationOpt0 /* Option */ RBrace;`
     ///
@@ -31048,7 +32457,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 730:
+    /// Semantic action for production 768:
     ///
     /// `ModportDeclarationOpt0 /* Option<T>::Some */: DotDot ModportDefault;`
     ///
@@ -31073,7 +32482,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 731:
+    /// Semantic action for production 769:
     ///
     /// `ModportDeclarationOpt0 /* Option<T>::None */: ;`
     ///
@@ -31085,7 +32494,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 732:
+    /// Semantic action for production 770:
     ///
     /// `ModportDeclarationOpt /* Option<T>::Some */: ModportList;`
     ///
@@ -31104,7 +32513,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 733:
+    /// Semantic action for production 771:
     ///
     /// `ModportDeclarationOpt /* Option<T>::None */: ;`
     ///
@@ -31116,7 +32525,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 734:
+    /// Semantic action for production 772:
     ///
     /// `ModportList: ModportGroup ModportListList /* Vec */ ModportListOpt /* Option */;`
     ///
@@ -31144,7 +32553,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 735:
+    /// Semantic action for production 773:
     ///
     /// `ModportListList /* Vec<T>::Push */: Comma ModportGroup ModportListList;`
     ///
@@ -31170,7 +32579,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 736:
+    /// Semantic action for production 774:
     ///
     /// `ModportListList /* Vec<T>::New */: ;`
     ///
@@ -31183,7 +32592,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 737:
+    /// Semantic action for production 775:
     ///
     /// `ModportListOpt /* Option<T>::Some */: Comma;`
     ///
@@ -31202,7 +32611,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 738:
+    /// Semantic action for production 776:
     ///
     /// `ModportListOpt /* Option<T>::None */: ;`
     ///
@@ -31214,7 +32623,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 739:
+    /// Semantic action for production 777:
     ///
     /// `ModportGroup: ModportGroupList /* Vec */ ModportGroupGroup;`
     ///
@@ -31239,7 +32648,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 740:
+    /// Semantic action for production 778:
     ///
     /// `ModportGroupGroup: LBrace ModportList RBrace;`
     ///
@@ -31269,7 +32678,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 741:
+    /// Semantic action for production 779:
     ///
     /// `ModportGroupGroup: ModportItem;`
     ///
@@ -31290,7 +32699,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 742:
+    /// Semantic action for production 780:
     ///
     /// `ModportGroupList /* Vec<T>::Push */: Attribute ModportGroupList;`
     ///
@@ -31313,7 +32722,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 743:
+    /// Semantic action for production 781:
     ///
     /// `ModportGroupList /* Vec<T>::New */: ;`
     ///
@@ -31329,7 +32738,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 744:
+    /// Semantic action for production 782:
     ///
     /// `ModportItem: Identifier Colon Direction;`
     ///
@@ -31356,7 +32765,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 745:
+    /// Semantic action for production 783:
     ///
     /// `ModportDefault: Input;`
     ///
@@ -31376,7 +32785,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 746:
+    /// Semantic action for production 784:
     ///
     /// `ModportDefault: Output;`
     ///
@@ -31396,7 +32805,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 747:
+    /// Semantic action for production 785:
     ///
     /// `ModportDefault: Same LParen Identifier RParen;`
     ///
@@ -31429,7 +32838,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 748:
+    /// Semantic action for production 786:
     ///
     /// `ModportDefault: Converse LParen Identifier RParen;`
     ///
@@ -31462,7 +32871,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 749:
+    /// Semantic action for production 787:
     ///
     /// `EnumDeclaration: Enum Identifier EnumDeclarationOpt /* Option */ LBrace EnumList RBrace;`
     ///
@@ -31500,7 +32909,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 750:
+    /// Semantic action for production 788:
     ///
     /// `EnumDeclarationOpt /* Option<T>::Some */: Colon ScalarType;`
     ///
@@ -31525,7 +32934,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 751:
+    /// Semantic action for production 789:
     ///
     /// `EnumDeclarationOpt /* Option<T>::None */: ;`
     ///
@@ -31537,7 +32946,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 752:
+    /// Semantic action for production 790:
     ///
     /// `EnumList: EnumGroup EnumListList /* Vec */ EnumListOpt /* Option */;`
     ///
@@ -31564,7 +32973,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 753:
+    /// Semantic action for production 791:
     ///
     /// `EnumListList /* Vec<T>::Push */: Comma EnumGroup EnumListList;`
     ///
@@ -31590,7 +32999,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 754:
+    /// Semantic action for production 792:
     ///
     /// `EnumListList /* Vec<T>::New */: ;`
     ///
@@ -31603,7 +33012,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 755:
+    /// Semantic action for production 793:
     ///
     /// `EnumListOpt /* Option<T>::Some */: Comma;`
     ///
@@ -31619,7 +33028,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 756:
+    /// Semantic action for production 794:
     ///
     /// `EnumListOpt /* Option<T>::None */: ;`
     ///
@@ -31631,7 +33040,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 757:
+    /// Semantic action for production 795:
     ///
     /// `EnumGroup: EnumGroupList /* Vec */ EnumGroupGroup;`
     ///
@@ -31655,7 +33064,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 758:
+    /// Semantic action for production 796:
     ///
     /// `EnumGroupGroup: LBrace EnumList RBrace;`
     ///
@@ -31682,7 +33091,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 759:
+    /// Semantic action for production 797:
     ///
     /// `EnumGroupGroup: EnumItem;`
     ///
@@ -31699,7 +33108,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 760:
+    /// Semantic action for production 798:
     ///
     /// `EnumGroupList /* Vec<T>::Push */: Attribute EnumGroupList;`
     ///
@@ -31722,7 +33131,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 761:
+    /// Semantic action for production 799:
     ///
     /// `EnumGroupList /* Vec<T>::New */: ;`
     ///
@@ -31735,7 +33144,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 762:
+    /// Semantic action for production 800:
     ///
     /// `EnumItem: Identifier EnumItemOpt /* Option */;`
     ///
@@ -31759,7 +33168,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 763:
+    /// Semantic action for production 801:
     ///
     /// `EnumItemOpt /* Option<T>::Some */: Equ Expression;`
     ///
@@ -31781,7 +33190,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 764:
+    /// Semantic action for production 802:
     ///
     /// `EnumItemOpt /* Option<T>::None */: ;`
     ///
@@ -31793,7 +33202,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 765:
+    /// Semantic action for production 803:
     ///
     /// `StructUnion: Struct;`
     ///
@@ -31812,7 +33221,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 766:
+    /// Semantic action for production 804:
     ///
     /// `StructUnion: Union;`
     ///
@@ -31831,7 +33240,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 767:
+    /// Semantic action for production 805:
     ///
     /// `StructUnionDeclaration: StructUnion Identifier StructUnionDeclarationOpt /* Option */ LBrace StructUnionList RBrace;`
     ///
@@ -31876,7 +33285,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 768:
+    /// Semantic action for production 806:
     ///
     /// `StructUnionDeclarationOpt /* Option<T>::Some */: WithGenericParameter;`
     ///
@@ -31899,7 +33308,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 769:
+    /// Semantic action for production 807:
     ///
     /// `StructUnionDeclarationOpt /* Option<T>::None */: ;`
     ///
@@ -31911,7 +33320,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 770:
+    /// Semantic action for production 808:
     ///
     /// `StructUnionList: StructUnionGroup StructUnionListList /* Vec */ StructUnionListOpt /* Option */;`
     ///
@@ -31941,7 +33350,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 771:
+    /// Semantic action for production 809:
     ///
     /// `StructUnionListList /* Vec<T>::Push */: Comma StructUnionGroup StructUnionListList;`
     ///
@@ -31971,7 +33380,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 772:
+    /// Semantic action for production 810:
     ///
     /// `StructUnionListList /* Vec<T>::New */: ;`
     ///
@@ -31987,7 +33396,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 773:
+    /// Semantic action for production 811:
     ///
     /// `StructUnionListOpt /* Option<T>::Some */: Comma;`
     ///
@@ -32006,7 +33415,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 774:
+    /// Semantic action for production 812:
     ///
     /// `StructUnionListOpt /* Option<T>::None */: ;`
     ///
@@ -32018,7 +33427,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 775:
+    /// Semantic action for production 813:
     ///
     /// `StructUnionGroup: StructUnionGroupList /* Vec */ StructUnionGroupGroup;`
     ///
@@ -32049,7 +33458,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 776:
+    /// Semantic action for production 814:
     ///
     /// `StructUnionGroupGroup: LBrace StructUnionList RBrace;`
     ///
@@ -32079,7 +33488,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 777:
+    /// Semantic action for production 815:
     ///
     /// `StructUnionGroupGroup: StructUnionItem;`
     ///
@@ -32100,7 +33509,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 778:
+    /// Semantic action for production 816:
     ///
     /// `StructUnionGroupList /* Vec<T>::Push */: Attribute StructUnionGroupList;`
     ///
@@ -32127,7 +33536,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 779:
+    /// Semantic action for production 817:
     ///
     /// `StructUnionGroupList /* Vec<T>::New */: ;`
     ///
@@ -32143,7 +33552,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 780:
+    /// Semantic action for production 818:
     ///
     /// `StructUnionItem: Identifier Colon ScalarType;`
     ///
@@ -32171,7 +33580,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 781:
+    /// Semantic action for production 819:
     ///
     /// `InitialDeclaration: Initial StatementBlock;`
     ///
@@ -32199,7 +33608,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 782:
+    /// Semantic action for production 820:
     ///
     /// `FinalDeclaration: Final StatementBlock;`
     ///
@@ -32224,7 +33633,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 783:
+    /// Semantic action for production 821:
     ///
     /// `InstDeclaration: Inst Identifier %push(ClockDomain) Colon InstDeclarationOpt /* Option */ %pop() ScopedIdentifier InstDeclarationOpt0 /* Option */ InstDeclarationOpt1 /* Option */ InstDeclarationOpt2 /* Option */ Semicolon;`
     ///
@@ -32274,7 +33683,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 784:
+    /// Semantic action for production 822:
     ///
     /// `InstDeclarationOpt2 /* Option<T>::Some */: LParen InstDeclarationOpt3 /* Option */ RParen;`
     ///
@@ -32303,7 +33712,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 785:
+    /// Semantic action for production 823:
     ///
     /// `InstDeclarationOpt3 /* Option<T>::Some */: InstPortList;`
     ///
@@ -32322,7 +33731,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 786:
+    /// Semantic action for production 824:
     ///
     /// `InstDeclarationOpt3 /* Option<T>::None */: ;`
     ///
@@ -32334,7 +33743,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 787:
+    /// Semantic action for production 825:
     ///
     /// `InstDeclarationOpt2 /* Option<T>::None */: ;`
     ///
@@ -32346,7 +33755,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 788:
+    /// Semantic action for production 826:
     ///
     /// `InstDeclarationOpt1 /* Option<T>::Some */: InstParameter;`
     ///
@@ -32365,7 +33774,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 789:
+    /// Semantic action for production 827:
     ///
     /// `InstDeclarationOpt1 /* Option<T>::None */: ;`
     ///
@@ -32377,7 +33786,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 790:
+    /// Semantic action for production 828:
     ///
     /// `InstDeclarationOpt0 /* Option<T>::Some */: Array;`
     ///
@@ -32396,7 +33805,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 791:
+    /// Semantic action for production 829:
     ///
     /// `InstDeclarationOpt0 /* Option<T>::None */: ;`
     ///
@@ -32408,7 +33817,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 792:
+    /// Semantic action for production 830:
     ///
     /// `InstDeclarationOpt /* Option<T>::Some */: ClockDomain;`
     ///
@@ -32427,7 +33836,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 793:
+    /// Semantic action for production 831:
     ///
     /// `InstDeclarationOpt /* Option<T>::None */: ;`
     ///
@@ -32439,7 +33848,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 794:
+    /// Semantic action for production 832:
     ///
     /// `InstParameter: Hash LParen InstParameterOpt /* Option */ RParen;`
     ///
@@ -32469,7 +33878,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 795:
+    /// Semantic action for production 833:
     ///
     /// `InstParameterOpt /* Option<T>::Some */: InstParameterList;`
     ///
@@ -32488,7 +33897,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 796:
+    /// Semantic action for production 834:
     ///
     /// `InstParameterOpt /* Option<T>::None */: ;`
     ///
@@ -32500,7 +33909,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 797:
+    /// Semantic action for production 835:
     ///
     /// `InstParameterList: InstParameterGroup InstParameterListList /* Vec */ InstParameterListOpt /* Option */;`
     ///
@@ -32538,7 +33947,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 798:
+    /// Semantic action for production 836:
     ///
     /// `InstParameterListList /* Vec<T>::Push */: Comma InstParameterGroup InstParameterListList;`
     ///
@@ -32573,7 +33982,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 799:
+    /// Semantic action for production 837:
     ///
     /// `InstParameterListList /* Vec<T>::New */: ;`
     ///
@@ -32589,7 +33998,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 800:
+    /// Semantic action for production 838:
     ///
     /// `InstParameterListOpt /* Option<T>::Some */: Comma;`
     ///
@@ -32608,7 +34017,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 801:
+    /// Semantic action for production 839:
     ///
     /// `InstParameterListOpt /* Option<T>::None */: ;`
     ///
@@ -32620,7 +34029,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 802:
+    /// Semantic action for production 840:
     ///
     /// `InstParameterGroup: InstParameterGroupList /* Vec */ InstParameterGroupGroup;`
     ///
@@ -32658,7 +34067,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 803:
+    /// Semantic action for production 841:
     ///
     /// `InstParameterGroupGroup: LBrace InstParameterList RBrace;`
     ///
@@ -32691,7 +34100,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 804:
+    /// Semantic action for production 842:
     ///
     /// `InstParameterGroupGroup: InstParameterItem;`
     ///
@@ -32715,7 +34124,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 805:
+    /// Semantic action for production 843:
     ///
     /// `InstParameterGroupList /* Vec<T>::Push */: Attribute InstParameterGroupList;`
     ///
@@ -32746,7 +34155,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 806:
+    /// Semantic action for production 844:
     ///
     /// `InstParameterGroupList /* Vec<T>::New */: ;`
     ///
@@ -32762,7 +34171,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 807:
+    /// Semantic action for production 845:
     ///
     /// `InstParameterItem: Identifier InstParameterItemOpt /* Option */;`
     ///
@@ -32791,7 +34200,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 808:
+    /// Semantic action for production 846:
     ///
     /// `InstParameterItemOpt /* Option<T>::Some */: Colon Expression;`
     ///
@@ -32816,7 +34225,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 809:
+    /// Semantic action for production 847:
     ///
     /// `InstParameterItemOpt /* Option<T>::None */: ;`
     ///
@@ -32828,7 +34237,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 810:
+    /// Semantic action for production 848:
     ///
     /// `InstPortList: InstPortGroup InstPortListList /* Vec */ InstPortListOpt /* Option */;`
     ///
@@ -32856,7 +34265,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 811:
+    /// Semantic action for production 849:
     ///
     /// `InstPortListList /* Vec<T>::Push */: Comma InstPortGroup InstPortListList;`
     ///
@@ -32883,7 +34292,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 812:
+    /// Semantic action for production 850:
     ///
     /// `InstPortListList /* Vec<T>::New */: ;`
     ///
@@ -32899,7 +34308,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 813:
+    /// Semantic action for production 851:
     ///
     /// `InstPortListOpt /* Option<T>::Some */: Comma;`
     ///
@@ -32918,7 +34327,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 814:
+    /// Semantic action for production 852:
     ///
     /// `InstPortListOpt /* Option<T>::None */: ;`
     ///
@@ -32930,7 +34339,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 815:
+    /// Semantic action for production 853:
     ///
     /// `InstPortGroup: InstPortGroupList /* Vec */ InstPortGroupGroup;`
     ///
@@ -32956,7 +34365,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 816:
+    /// Semantic action for production 854:
     ///
     /// `InstPortGroupGroup: LBrace InstPortList RBrace;`
     ///
@@ -32986,7 +34395,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 817:
+    /// Semantic action for production 855:
     ///
     /// `InstPortGroupGroup: InstPortItem;`
     ///
@@ -33007,7 +34416,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 818:
+    /// Semantic action for production 856:
     ///
     /// `InstPortGroupList /* Vec<T>::Push */: Attribute InstPortGroupList;`
     ///
@@ -33031,7 +34440,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 819:
+    /// Semantic action for production 857:
     ///
     /// `InstPortGroupList /* Vec<T>::New */: ;`
     ///
@@ -33047,7 +34456,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 820:
+    /// Semantic action for production 858:
     ///
     /// `InstPortItem: Identifier InstPortItemOpt /* Option */;`
     ///
@@ -33071,7 +34480,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 821:
+    /// Semantic action for production 859:
     ///
     /// `InstPortItemOpt /* Option<T>::Some */: Colon Expression;`
     ///
@@ -33096,7 +34505,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 822:
+    /// Semantic action for production 860:
     ///
     /// `InstPortItemOpt /* Option<T>::None */: ;`
     ///
@@ -33108,7 +34517,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 823:
+    /// Semantic action for production 861:
     ///
     /// `WithParameter: Hash LParen WithParameterOpt /* Option */ RParen;`
     ///
@@ -33138,7 +34547,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 824:
+    /// Semantic action for production 862:
     ///
     /// `WithParameterOpt /* Option<T>::Some */: WithParameterList;`
     ///
@@ -33157,7 +34566,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 825:
+    /// Semantic action for production 863:
     ///
     /// `WithParameterOpt /* Option<T>::None */: ;`
     ///
@@ -33169,7 +34578,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 826:
+    /// Semantic action for production 864:
     ///
     /// `WithParameterList: WithParameterGroup WithParameterListList /* Vec */ WithParameterListOpt /* Option */;`
     ///
@@ -33207,7 +34616,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 827:
+    /// Semantic action for production 865:
     ///
     /// `WithParameterListList /* Vec<T>::Push */: Comma WithParameterGroup WithParameterListList;`
     ///
@@ -33242,7 +34651,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 828:
+    /// Semantic action for production 866:
     ///
     /// `WithParameterListList /* Vec<T>::New */: ;`
     ///
@@ -33258,7 +34667,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 829:
+    /// Semantic action for production 867:
     ///
     /// `WithParameterListOpt /* Option<T>::Some */: Comma;`
     ///
@@ -33277,7 +34686,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 830:
+    /// Semantic action for production 868:
     ///
     /// `WithParameterListOpt /* Option<T>::None */: ;`
     ///
@@ -33289,7 +34698,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 831:
+    /// Semantic action for production 869:
     ///
     /// `WithParameterGroup: WithParameterGroupList /* Vec */ WithParameterGroupGroup;`
     ///
@@ -33327,7 +34736,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 832:
+    /// Semantic action for production 870:
     ///
     /// `WithParameterGroupGroup: LBrace WithParameterList RBrace;`
     ///
@@ -33360,7 +34769,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 833:
+    /// Semantic action for production 871:
     ///
     /// `WithParameterGroupGroup: WithParameterItem;`
     ///
@@ -33384,7 +34793,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 834:
+    /// Semantic action for production 872:
     ///
     /// `WithParameterGroupList /* Vec<T>::Push */: Attribute WithParameterGroupList;`
     ///
@@ -33415,7 +34824,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 835:
+    /// Semantic action for production 873:
     ///
     /// `WithParameterGroupList /* Vec<T>::New */: ;`
     ///
@@ -33431,7 +34840,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 836:
+    /// Semantic action for production 874:
     ///
     /// `WithParameterItem: WithParameterItemGroup Identifier Colon WithParameterItemGroup0 Equ Expression;`
     ///
@@ -33481,7 +34890,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 837:
+    /// Semantic action for production 875:
     ///
     /// `WithParameterItemGroup0: ArrayType;`
     ///
@@ -33502,7 +34911,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 838:
+    /// Semantic action for production 876:
     ///
     /// `WithParameterItemGroup0: Type;`
     ///
@@ -33523,7 +34932,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 839:
+    /// Semantic action for production 877:
     ///
     /// `WithParameterItemGroup: Param;`
     ///
@@ -33544,7 +34953,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 840:
+    /// Semantic action for production 878:
     ///
     /// `WithParameterItemGroup: Const;`
     ///
@@ -33565,7 +34974,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 841:
+    /// Semantic action for production 879:
     ///
     /// `GenericBound: Type;`
     ///
@@ -33584,7 +34993,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 842:
+    /// Semantic action for production 880:
     ///
     /// `GenericBound: Inst ScopedIdentifier;`
     ///
@@ -33609,7 +35018,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 843:
+    /// Semantic action for production 881:
     ///
     /// `GenericBound: GenericProtoBound;`
     ///
@@ -33628,7 +35037,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 844:
+    /// Semantic action for production 882:
     ///
     /// `WithGenericParameter: ColonColonLAngle WithGenericParameterList RAngle;`
     ///
@@ -33664,7 +35073,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 845:
+    /// Semantic action for production 883:
     ///
     /// `WithGenericParameterList: WithGenericParameterItem WithGenericParameterListList /* Vec */ WithGenericParameterListOpt /* Option */;`
     ///
@@ -33710,7 +35119,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 846:
+    /// Semantic action for production 884:
     ///
     /// `WithGenericParameterListList /* Vec<T>::Push */: Comma WithGenericParameterItem WithGenericParameterListList;`
     ///
@@ -33749,7 +35158,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 847:
+    /// Semantic action for production 885:
     ///
     /// `WithGenericParameterListList /* Vec<T>::New */: ;`
     ///
@@ -33765,7 +35174,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 848:
+    /// Semantic action for production 886:
     ///
     /// `WithGenericParameterListOpt /* Option<T>::Some */: Comma;`
     ///
@@ -33784,7 +35193,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 849:
+    /// Semantic action for production 887:
     ///
     /// `WithGenericParameterListOpt /* Option<T>::None */: ;`
     ///
@@ -33796,7 +35205,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 850:
+    /// Semantic action for production 888:
     ///
     /// `WithGenericParameterItem: Identifier Colon GenericBound WithGenericParameterItemOpt /* Option */;`
     ///
@@ -33835,7 +35244,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 851:
+    /// Semantic action for production 889:
     ///
     /// `WithGenericParameterItemOpt /* Option<T>::Some */: Equ WithGenericArgumentItem;`
     ///
@@ -33865,7 +35274,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 852:
+    /// Semantic action for production 890:
     ///
     /// `WithGenericParameterItemOpt /* Option<T>::None */: ;`
     ///
@@ -33877,7 +35286,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 853:
+    /// Semantic action for production 891:
     ///
     /// `GenericProtoBound: ScopedIdentifier;`
     ///
@@ -33901,7 +35310,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 854:
+    /// Semantic action for production 892:
     ///
     /// `GenericProtoBound: FixedType;`
     ///
@@ -33924,7 +35333,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 855:
+    /// Semantic action for production 893:
     ///
     /// `WithGenericArgument: ColonColonLAngle %push(Generic) WithGenericArgumentOpt /* Option */ RAngle %pop();`
     ///
@@ -33960,7 +35369,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 856:
+    /// Semantic action for production 894:
     ///
     /// `WithGenericArgumentOpt /* Option<T>::Some */: WithGenericArgumentList;`
     ///
@@ -33987,7 +35396,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 857:
+    /// Semantic action for production 895:
     ///
     /// `WithGenericArgumentOpt /* Option<T>::None */: ;`
     ///
@@ -33999,7 +35408,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 858:
+    /// Semantic action for production 896:
     ///
     /// `WithGenericArgumentList: WithGenericArgumentItem WithGenericArgumentListList /* Vec */ WithGenericArgumentListOpt /* Option */;`
     ///
@@ -34045,7 +35454,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 859:
+    /// Semantic action for production 897:
     ///
     /// `WithGenericArgumentListList /* Vec<T>::Push */: Comma WithGenericArgumentItem WithGenericArgumentListList;`
     ///
@@ -34084,7 +35493,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 860:
+    /// Semantic action for production 898:
     ///
     /// `WithGenericArgumentListList /* Vec<T>::New */: ;`
     ///
@@ -34100,7 +35509,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 861:
+    /// Semantic action for production 899:
     ///
     /// `WithGenericArgumentListOpt /* Option<T>::Some */: Comma;`
     ///
@@ -34119,7 +35528,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 862:
+    /// Semantic action for production 900:
     ///
     /// `WithGenericArgumentListOpt /* Option<T>::None */: ;`
     ///
@@ -34131,7 +35540,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 863:
+    /// Semantic action for production 901:
     ///
     /// `WithGenericArgumentItem: ExpressionIdentifier;`
     ///
@@ -34159,7 +35568,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 864:
+    /// Semantic action for production 902:
     ///
     /// `WithGenericArgumentItem: FixedType;`
     ///
@@ -34183,7 +35592,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 865:
+    /// Semantic action for production 903:
     ///
     /// `WithGenericArgumentItem: Number;`
     ///
@@ -34207,7 +35616,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 866:
+    /// Semantic action for production 904:
     ///
     /// `WithGenericArgumentItem: BooleanLiteral;`
     ///
@@ -34231,7 +35640,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 867:
+    /// Semantic action for production 905:
     ///
     /// `PortDeclaration: LParen PortDeclarationOpt /* Option */ RParen;`
     ///
@@ -34260,7 +35669,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 868:
+    /// Semantic action for production 906:
     ///
     /// `PortDeclarationOpt /* Option<T>::Some */: PortDeclarationList;`
     ///
@@ -34280,7 +35689,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 869:
+    /// Semantic action for production 907:
     ///
     /// `PortDeclarationOpt /* Option<T>::None */: ;`
     ///
@@ -34292,7 +35701,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 870:
+    /// Semantic action for production 908:
     ///
     /// `PortDeclarationList: PortDeclarationGroup PortDeclarationListList /* Vec */ PortDeclarationListOpt /* Option */;`
     ///
@@ -34334,7 +35743,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 871:
+    /// Semantic action for production 909:
     ///
     /// `PortDeclarationListList /* Vec<T>::Push */: Comma PortDeclarationGroup PortDeclarationListList;`
     ///
@@ -34369,7 +35778,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 872:
+    /// Semantic action for production 910:
     ///
     /// `PortDeclarationListList /* Vec<T>::New */: ;`
     ///
@@ -34385,7 +35794,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 873:
+    /// Semantic action for production 911:
     ///
     /// `PortDeclarationListOpt /* Option<T>::Some */: Comma;`
     ///
@@ -34404,7 +35813,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 874:
+    /// Semantic action for production 912:
     ///
     /// `PortDeclarationListOpt /* Option<T>::None */: ;`
     ///
@@ -34416,7 +35825,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 875:
+    /// Semantic action for production 913:
     ///
     /// `PortDeclarationGroup: PortDeclarationGroupList /* Vec */ PortDeclarationGroupGroup;`
     ///
@@ -34454,7 +35863,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 876:
+    /// Semantic action for production 914:
     ///
     /// `PortDeclarationGroupGroup: LBrace PortDeclarationList RBrace;`
     ///
@@ -34488,7 +35897,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 877:
+    /// Semantic action for production 915:
     ///
     /// `PortDeclarationGroupGroup: PortDeclarationItem;`
     ///
@@ -34513,7 +35922,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 878:
+    /// Semantic action for production 916:
     ///
     /// `PortDeclarationGroupList /* Vec<T>::Push */: Attribute PortDeclarationGroupList;`
     ///
@@ -34544,7 +35953,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 879:
+    /// Semantic action for production 917:
     ///
     /// `PortDeclarationGroupList /* Vec<T>::New */: ;`
     ///
@@ -34560,7 +35969,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 880:
+    /// Semantic action for production 918:
     ///
     /// `PortDeclarationItem: Identifier %push(ClockDomain) Colon PortDeclarationItemGroup;`
     ///
@@ -34596,7 +36005,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 881:
+    /// Semantic action for production 919:
     ///
     /// `PortDeclarationItemGroup: PortTypeConcrete;`
     ///
@@ -34620,7 +36029,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 882:
+    /// Semantic action for production 920:
     ///
     /// `PortDeclarationItemGroup: PortTypeAbstract;`
     ///
@@ -34644,7 +36053,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 883:
+    /// Semantic action for production 921:
     ///
     /// `PortTypeConcrete: Direction PortTypeConcreteOpt /* Option */ %pop() ArrayType PortTypeConcreteOpt0 /* Option */;`
     ///
@@ -34677,7 +36086,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 884:
+    /// Semantic action for production 922:
     ///
     /// `PortTypeConcreteOpt0 /* Option<T>::Some */: Equ PortDefaultValue;`
     ///
@@ -34702,7 +36111,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 885:
+    /// Semantic action for production 923:
     ///
     /// `PortTypeConcreteOpt0 /* Option<T>::None */: ;`
     ///
@@ -34714,7 +36123,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 886:
+    /// Semantic action for production 924:
     ///
     /// `PortTypeConcreteOpt /* Option<T>::Some */: ClockDomain;`
     ///
@@ -34733,7 +36142,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 887:
+    /// Semantic action for production 925:
     ///
     /// `PortTypeConcreteOpt /* Option<T>::None */: ;`
     ///
@@ -34745,7 +36154,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 888:
+    /// Semantic action for production 926:
     ///
     /// `PortDefaultValue: Expression;`
     ///
@@ -34764,7 +36173,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 889:
+    /// Semantic action for production 927:
     ///
     /// `PortTypeAbstract: PortTypeAbstractOpt /* Option */ %pop() Interface PortTypeAbstractOpt0 /* Option */ PortTypeAbstractOpt1 /* Option */;`
     ///
@@ -34798,7 +36207,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 890:
+    /// Semantic action for production 928:
     ///
     /// `PortTypeAbstractOpt1 /* Option<T>::Some */: Array;`
     ///
@@ -34817,7 +36226,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 891:
+    /// Semantic action for production 929:
     ///
     /// `PortTypeAbstractOpt1 /* Option<T>::None */: ;`
     ///
@@ -34829,7 +36238,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 892:
+    /// Semantic action for production 930:
     ///
     /// `PortTypeAbstractOpt0 /* Option<T>::Some */: ColonColon Identifier;`
     ///
@@ -34854,7 +36263,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 893:
+    /// Semantic action for production 931:
     ///
     /// `PortTypeAbstractOpt0 /* Option<T>::None */: ;`
     ///
@@ -34866,7 +36275,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 894:
+    /// Semantic action for production 932:
     ///
     /// `PortTypeAbstractOpt /* Option<T>::Some */: ClockDomain;`
     ///
@@ -34885,7 +36294,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 895:
+    /// Semantic action for production 933:
     ///
     /// `PortTypeAbstractOpt /* Option<T>::None */: ;`
     ///
@@ -34897,7 +36306,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 896:
+    /// Semantic action for production 934:
     ///
     /// `Direction: Input;`
     ///
@@ -34916,7 +36325,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 897:
+    /// Semantic action for production 935:
     ///
     /// `Direction: Output;`
     ///
@@ -34935,7 +36344,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 898:
+    /// Semantic action for production 936:
     ///
     /// `Direction: Inout;`
     ///
@@ -34954,7 +36363,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 899:
+    /// Semantic action for production 937:
     ///
     /// `Direction: Modport;`
     ///
@@ -34973,7 +36382,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 900:
+    /// Semantic action for production 938:
     ///
     /// `Direction: Import;`
     ///
@@ -34992,7 +36401,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 901:
+    /// Semantic action for production 939:
     ///
     /// `FunctionDeclaration: Function Identifier FunctionDeclarationOpt /* Option */ FunctionDeclarationOpt0 /* Option */ FunctionDeclarationOpt1 /* Option */ StatementBlock;`
     ///
@@ -35047,7 +36456,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 902:
+    /// Semantic action for production 940:
     ///
     /// `FunctionDeclarationOpt1 /* Option<T>::Some */: MinusGT ScalarType;`
     ///
@@ -35072,7 +36481,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 903:
+    /// Semantic action for production 941:
     ///
     /// `FunctionDeclarationOpt1 /* Option<T>::None */: ;`
     ///
@@ -35084,7 +36493,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 904:
+    /// Semantic action for production 942:
     ///
     /// `FunctionDeclarationOpt0 /* Option<T>::Some */: PortDeclaration;`
     ///
@@ -35103,7 +36512,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 905:
+    /// Semantic action for production 943:
     ///
     /// `FunctionDeclarationOpt0 /* Option<T>::None */: ;`
     ///
@@ -35115,7 +36524,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 906:
+    /// Semantic action for production 944:
     ///
     /// `FunctionDeclarationOpt /* Option<T>::Some */: WithGenericParameter;`
     ///
@@ -35138,7 +36547,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 907:
+    /// Semantic action for production 945:
     ///
     /// `FunctionDeclarationOpt /* Option<T>::None */: ;`
     ///
@@ -35150,7 +36559,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 908:
+    /// Semantic action for production 946:
     ///
     /// `ImportDeclaration: Import ScopedIdentifier ImportDeclarationOpt /* Option */ Semicolon;`
     ///
@@ -35185,7 +36594,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 909:
+    /// Semantic action for production 947:
     ///
     /// `ImportDeclarationOpt /* Option<T>::Some */: ColonColon Star;`
     ///
@@ -35210,7 +36619,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 910:
+    /// Semantic action for production 948:
     ///
     /// `ImportDeclarationOpt /* Option<T>::None */: ;`
     ///
@@ -35222,7 +36631,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 911:
+    /// Semantic action for production 949:
     ///
     /// `UnsafeBlock: Unsafe LParen Identifier RParen LBrace UnsafeBlockList /* Vec */ RBrace;`
     ///
@@ -35262,7 +36671,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 912:
+    /// Semantic action for production 950:
     ///
     /// `UnsafeBlockList /* Vec<T>::Push */: GenerateGroup UnsafeBlockList;`
     ///
@@ -35285,7 +36694,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 913:
+    /// Semantic action for production 951:
     ///
     /// `UnsafeBlockList /* Vec<T>::New */: ;`
     ///
@@ -35298,7 +36707,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 914:
+    /// Semantic action for production 952:
     ///
     /// `ModuleDeclaration: Module Identifier ModuleDeclarationOpt /* Option */ ModuleDeclarationOpt0 /* Option */ ModuleDeclarationOpt1 /* Option */ ModuleDeclarationOpt2 /* Option */ LBrace ModuleDeclarationList /* Vec */ RBrace;`
     ///
@@ -35368,7 +36777,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 915:
+    /// Semantic action for production 953:
     ///
     /// `ModuleDeclarationList /* Vec<T>::Push */: ModuleGroup ModuleDeclarationList;`
     ///
@@ -35399,7 +36808,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 916:
+    /// Semantic action for production 954:
     ///
     /// `ModuleDeclarationList /* Vec<T>::New */: ;`
     ///
@@ -35415,7 +36824,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 917:
+    /// Semantic action for production 955:
     ///
     /// `ModuleDeclarationOpt2 /* Option<T>::Some */: PortDeclaration;`
     ///
@@ -35434,7 +36843,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 918:
+    /// Semantic action for production 956:
     ///
     /// `ModuleDeclarationOpt2 /* Option<T>::None */: ;`
     ///
@@ -35446,7 +36855,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 919:
+    /// Semantic action for production 957:
     ///
     /// `ModuleDeclarationOpt1 /* Option<T>::Some */: WithParameter;`
     ///
@@ -35465,7 +36874,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 920:
+    /// Semantic action for production 958:
     ///
     /// `ModuleDeclarationOpt1 /* Option<T>::None */: ;`
     ///
@@ -35477,7 +36886,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 921:
+    /// Semantic action for production 959:
     ///
     /// `ModuleDeclarationOpt0 /* Option<T>::Some */: For ScopedIdentifier;`
     ///
@@ -35502,7 +36911,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 922:
+    /// Semantic action for production 960:
     ///
     /// `ModuleDeclarationOpt0 /* Option<T>::None */: ;`
     ///
@@ -35514,7 +36923,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 923:
+    /// Semantic action for production 961:
     ///
     /// `ModuleDeclarationOpt /* Option<T>::Some */: WithGenericParameter;`
     ///
@@ -35537,7 +36946,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 924:
+    /// Semantic action for production 962:
     ///
     /// `ModuleDeclarationOpt /* Option<T>::None */: ;`
     ///
@@ -35549,7 +36958,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 925:
+    /// Semantic action for production 963:
     ///
     /// `ModuleGroup: ModuleGroupList /* Vec */ ModuleGroupGroup;`
     ///
@@ -35574,7 +36983,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 926:
+    /// Semantic action for production 964:
     ///
     /// `ModuleGroupGroup: LBrace ModuleGroupGroupList /* Vec */ RBrace;`
     ///
@@ -35605,7 +37014,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 927:
+    /// Semantic action for production 965:
     ///
     /// `ModuleGroupGroupList /* Vec<T>::Push */: ModuleGroup ModuleGroupGroupList;`
     ///
@@ -35632,7 +37041,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 928:
+    /// Semantic action for production 966:
     ///
     /// `ModuleGroupGroupList /* Vec<T>::New */: ;`
     ///
@@ -35648,7 +37057,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 929:
+    /// Semantic action for production 967:
     ///
     /// `ModuleGroupGroup: ModuleItem;`
     ///
@@ -35668,7 +37077,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 930:
+    /// Semantic action for production 968:
     ///
     /// `ModuleGroupList /* Vec<T>::Push */: Attribute ModuleGroupList;`
     ///
@@ -35691,7 +37100,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 931:
+    /// Semantic action for production 969:
     ///
     /// `ModuleGroupList /* Vec<T>::New */: ;`
     ///
@@ -35704,7 +37113,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 932:
+    /// Semantic action for production 970:
     ///
     /// `ModuleItem: GenerateItem;`
     ///
@@ -35722,7 +37131,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 933:
+    /// Semantic action for production 971:
     ///
     /// `InterfaceDeclaration: Interface Identifier InterfaceDeclarationOpt /* Option */ InterfaceDeclarationOpt0 /* Option */ InterfaceDeclarationOpt1 /* Option */ LBrace InterfaceDeclarationList /* Vec */ RBrace;`
     ///
@@ -35788,7 +37197,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 934:
+    /// Semantic action for production 972:
     ///
     /// `InterfaceDeclarationList /* Vec<T>::Push */: InterfaceGroup InterfaceDeclarationList;`
     ///
@@ -35819,7 +37228,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 935:
+    /// Semantic action for production 973:
     ///
     /// `InterfaceDeclarationList /* Vec<T>::New */: ;`
     ///
@@ -35835,7 +37244,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 936:
+    /// Semantic action for production 974:
     ///
     /// `InterfaceDeclarationOpt1 /* Option<T>::Some */: WithParameter;`
     ///
@@ -35854,7 +37263,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 937:
+    /// Semantic action for production 975:
     ///
     /// `InterfaceDeclarationOpt1 /* Option<T>::None */: ;`
     ///
@@ -35866,7 +37275,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 938:
+    /// Semantic action for production 976:
     ///
     /// `InterfaceDeclarationOpt0 /* Option<T>::Some */: For ScopedIdentifier;`
     ///
@@ -35891,7 +37300,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 939:
+    /// Semantic action for production 977:
     ///
     /// `InterfaceDeclarationOpt0 /* Option<T>::None */: ;`
     ///
@@ -35903,7 +37312,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 940:
+    /// Semantic action for production 978:
     ///
     /// `InterfaceDeclarationOpt /* Option<T>::Some */: WithGenericParameter;`
     ///
@@ -35926,7 +37335,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 941:
+    /// Semantic action for production 979:
     ///
     /// `InterfaceDeclarationOpt /* Option<T>::None */: ;`
     ///
@@ -35938,7 +37347,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 942:
+    /// Semantic action for production 980:
     ///
     /// `InterfaceGroup: InterfaceGroupList /* Vec */ InterfaceGroupGroup;`
     ///
@@ -35964,7 +37373,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 943:
+    /// Semantic action for production 981:
     ///
     /// `InterfaceGroupGroup: LBrace InterfaceGroupGroupList /* Vec */ RBrace;`
     ///
@@ -36000,7 +37409,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 944:
+    /// Semantic action for production 982:
     ///
     /// `InterfaceGroupGroupList /* Vec<T>::Push */: InterfaceGroup InterfaceGroupGroupList;`
     ///
@@ -36031,7 +37440,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 945:
+    /// Semantic action for production 983:
     ///
     /// `InterfaceGroupGroupList /* Vec<T>::New */: ;`
     ///
@@ -36047,7 +37456,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 946:
+    /// Semantic action for production 984:
     ///
     /// `InterfaceGroupGroup: InterfaceItem;`
     ///
@@ -36068,7 +37477,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 947:
+    /// Semantic action for production 985:
     ///
     /// `InterfaceGroupList /* Vec<T>::Push */: Attribute InterfaceGroupList;`
     ///
@@ -36092,7 +37501,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 948:
+    /// Semantic action for production 986:
     ///
     /// `InterfaceGroupList /* Vec<T>::New */: ;`
     ///
@@ -36108,7 +37517,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 949:
+    /// Semantic action for production 987:
     ///
     /// `InterfaceItem: GenerateItem;`
     ///
@@ -36127,7 +37536,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 950:
+    /// Semantic action for production 988:
     ///
     /// `InterfaceItem: ModportDeclaration;`
     ///
@@ -36146,7 +37555,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 951:
+    /// Semantic action for production 989:
     ///
     /// `GenerateIfDeclaration: If Expression GenerateNamedBlock GenerateIfDeclarationList /* Vec */ GenerateIfDeclarationOpt /* Option */;`
     ///
@@ -36194,7 +37603,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 952:
+    /// Semantic action for production 990:
     ///
     /// `GenerateIfDeclarationList /* Vec<T>::Push */: Else If Expression GenerateOptionalNamedBlock GenerateIfDeclarationList;`
     ///
@@ -36239,7 +37648,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 953:
+    /// Semantic action for production 991:
     ///
     /// `GenerateIfDeclarationList /* Vec<T>::New */: ;`
     ///
@@ -36255,7 +37664,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 954:
+    /// Semantic action for production 992:
     ///
     /// `GenerateIfDeclarationOpt /* Option<T>::Some */: Else GenerateOptionalNamedBlock;`
     ///
@@ -36285,7 +37694,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 955:
+    /// Semantic action for production 993:
     ///
     /// `GenerateIfDeclarationOpt /* Option<T>::None */: ;`
     ///
@@ -36297,7 +37706,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 956:
+    /// Semantic action for production 994:
     ///
     /// `GenerateForDeclaration: For Identifier In Range GenerateForDeclarationOpt /* Option */ GenerateNamedBlock;`
     ///
@@ -36343,7 +37752,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 957:
+    /// Semantic action for production 995:
     ///
     /// `GenerateForDeclarationOpt /* Option<T>::Some */: Step AssignmentOperator Expression;`
     ///
@@ -36371,7 +37780,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 958:
+    /// Semantic action for production 996:
     ///
     /// `GenerateForDeclarationOpt /* Option<T>::None */: ;`
     ///
@@ -36383,7 +37792,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 959:
+    /// Semantic action for production 997:
     ///
     /// `GenerateBlockDeclaration: GenerateNamedBlock;`
     ///
@@ -36409,7 +37818,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 960:
+    /// Semantic action for production 998:
     ///
     /// `GenerateNamedBlock: Colon Identifier LBrace GenerateNamedBlockList /* Vec */ RBrace;`
     ///
@@ -36451,7 +37860,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 961:
+    /// Semantic action for production 999:
     ///
     /// `GenerateNamedBlockList /* Vec<T>::Push */: GenerateGroup GenerateNamedBlockList;`
     ///
@@ -36482,7 +37891,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 962:
+    /// Semantic action for production 1000:
     ///
     /// `GenerateNamedBlockList /* Vec<T>::New */: ;`
     ///
@@ -36498,7 +37907,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 963:
+    /// Semantic action for production 1001:
     ///
     /// `GenerateOptionalNamedBlock: GenerateOptionalNamedBlockOpt /* Option */ LBrace GenerateOptionalNamedBlockList /* Vec */ RBrace;`
     ///
@@ -36542,7 +37951,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 964:
+    /// Semantic action for production 1002:
     ///
     /// `GenerateOptionalNamedBlockList /* Vec<T>::Push */: GenerateGroup GenerateOptionalNamedBlockList;`
     ///
@@ -36573,7 +37982,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 965:
+    /// Semantic action for production 1003:
     ///
     /// `GenerateOptionalNamedBlockList /* Vec<T>::New */: ;`
     ///
@@ -36589,7 +37998,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 966:
+    /// Semantic action for production 1004:
     ///
     /// `GenerateOptionalNamedBlockOpt /* Option<T>::Some */: Colon Identifier;`
     ///
@@ -36614,7 +38023,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 967:
+    /// Semantic action for production 1005:
     ///
     /// `GenerateOptionalNamedBlockOpt /* Option<T>::None */: ;`
     ///
@@ -36626,7 +38035,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 968:
+    /// Semantic action for production 1006:
     ///
     /// `GenerateGroup: GenerateGroupList /* Vec */ GenerateGroupGroup;`
     ///
@@ -36652,7 +38061,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 969:
+    /// Semantic action for production 1007:
     ///
     /// `GenerateGroupGroup: LBrace GenerateGroupGroupList /* Vec */ RBrace;`
     ///
@@ -36687,7 +38096,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 970:
+    /// Semantic action for production 1008:
     ///
     /// `GenerateGroupGroupList /* Vec<T>::Push */: GenerateGroup GenerateGroupGroupList;`
     ///
@@ -36718,7 +38127,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 971:
+    /// Semantic action for production 1009:
     ///
     /// `GenerateGroupGroupList /* Vec<T>::New */: ;`
     ///
@@ -36734,7 +38143,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 972:
+    /// Semantic action for production 1010:
     ///
     /// `GenerateGroupGroup: GenerateItem;`
     ///
@@ -36755,7 +38164,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 973:
+    /// Semantic action for production 1011:
     ///
     /// `GenerateGroupList /* Vec<T>::Push */: Attribute GenerateGroupList;`
     ///
@@ -36779,7 +38188,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 974:
+    /// Semantic action for production 1012:
     ///
     /// `GenerateGroupList /* Vec<T>::New */: ;`
     ///
@@ -36795,7 +38204,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 975:
+    /// Semantic action for production 1013:
     ///
     /// `GenerateItem: LetDeclaration;`
     ///
@@ -36814,7 +38223,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 976:
+    /// Semantic action for production 1014:
     ///
     /// `GenerateItem: VarDeclaration;`
     ///
@@ -36833,7 +38242,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 977:
+    /// Semantic action for production 1015:
     ///
     /// `GenerateItem: InstDeclaration;`
     ///
@@ -36852,7 +38261,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 978:
+    /// Semantic action for production 1016:
     ///
     /// `GenerateItem: ConstDeclaration;`
     ///
@@ -36871,7 +38280,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 979:
+    /// Semantic action for production 1017:
     ///
     /// `GenerateItem: AlwaysFfDeclaration;`
     ///
@@ -36891,7 +38300,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 980:
+    /// Semantic action for production 1018:
     ///
     /// `GenerateItem: AlwaysCombDeclaration;`
     ///
@@ -36915,7 +38324,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 981:
+    /// Semantic action for production 1019:
     ///
     /// `GenerateItem: AssignDeclaration;`
     ///
@@ -36934,51 +38343,71 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 982:
+    /// Semantic action for production 1020:
+    ///
+    /// `GenerateItem: AssertionDeclaration;`
+    ///
+    #[parol_runtime::function_name::named]
+    fn generate_item_7(&mut self, _assertion_declaration: &ParseTreeType<'t>) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let assertion_declaration =
+            pop_item!(self, assertion_declaration, AssertionDeclaration, context);
+        let generate_item_7_built = GenerateItemAssertionDeclaration {
+            assertion_declaration: Box::new(assertion_declaration),
+        };
+        let generate_item_7_built = GenerateItem::AssertionDeclaration(generate_item_7_built);
+        // Calling user action here
+        self.user_grammar.generate_item(&generate_item_7_built)?;
+        self.push(ASTType::GenerateItem(generate_item_7_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 1021:
     ///
     /// `GenerateItem: ConnectDeclaration;`
     ///
     #[parol_runtime::function_name::named]
-    fn generate_item_7(&mut self, _connect_declaration: &ParseTreeType<'t>) -> Result<()> {
+    fn generate_item_8(&mut self, _connect_declaration: &ParseTreeType<'t>) -> Result<()> {
         let context = function_name!();
         trace!("{}", self.trace_item_stack(context));
         let connect_declaration = pop_item!(self, connect_declaration, ConnectDeclaration, context);
-        let generate_item_7_built = GenerateItemConnectDeclaration {
+        let generate_item_8_built = GenerateItemConnectDeclaration {
             connect_declaration: Box::new(connect_declaration),
         };
-        let generate_item_7_built = GenerateItem::ConnectDeclaration(generate_item_7_built);
+        let generate_item_8_built = GenerateItem::ConnectDeclaration(generate_item_8_built);
         // Calling user action here
-        self.user_grammar.generate_item(&generate_item_7_built)?;
-        self.push(ASTType::GenerateItem(generate_item_7_built), context);
+        self.user_grammar.generate_item(&generate_item_8_built)?;
+        self.push(ASTType::GenerateItem(generate_item_8_built), context);
         Ok(())
     }
 
-    /// Semantic action for production 983:
+    /// Semantic action for production 1022:
     ///
     /// `GenerateItem: FunctionDeclaration;`
     ///
     #[parol_runtime::function_name::named]
-    fn generate_item_8(&mut self, _function_declaration: &ParseTreeType<'t>) -> Result<()> {
+    fn generate_item_9(&mut self, _function_declaration: &ParseTreeType<'t>) -> Result<()> {
         let context = function_name!();
         trace!("{}", self.trace_item_stack(context));
         let function_declaration =
             pop_item!(self, function_declaration, FunctionDeclaration, context);
-        let generate_item_8_built = GenerateItemFunctionDeclaration {
+        let generate_item_9_built = GenerateItemFunctionDeclaration {
             function_declaration: Box::new(function_declaration),
         };
-        let generate_item_8_built = GenerateItem::FunctionDeclaration(generate_item_8_built);
+        let generate_item_9_built = GenerateItem::FunctionDeclaration(generate_item_9_built);
         // Calling user action here
-        self.user_grammar.generate_item(&generate_item_8_built)?;
-        self.push(ASTType::GenerateItem(generate_item_8_built), context);
+        self.user_grammar.generate_item(&generate_item_9_built)?;
+        self.push(ASTType::GenerateItem(generate_item_9_built), context);
         Ok(())
     }
 
-    /// Semantic action for production 984:
+    /// Semantic action for production 1023:
     ///
     /// `GenerateItem: GenerateIfDeclaration;`
     ///
     #[parol_runtime::function_name::named]
-    fn generate_item_9(&mut self, _generate_if_declaration: &ParseTreeType<'t>) -> Result<()> {
+    fn generate_item_10(&mut self, _generate_if_declaration: &ParseTreeType<'t>) -> Result<()> {
         let context = function_name!();
         trace!("{}", self.trace_item_stack(context));
         let generate_if_declaration = pop_item!(
@@ -36987,22 +38416,22 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
             GenerateIfDeclaration,
             context
         );
-        let generate_item_9_built = GenerateItemGenerateIfDeclaration {
+        let generate_item_10_built = GenerateItemGenerateIfDeclaration {
             generate_if_declaration: Box::new(generate_if_declaration),
         };
-        let generate_item_9_built = GenerateItem::GenerateIfDeclaration(generate_item_9_built);
+        let generate_item_10_built = GenerateItem::GenerateIfDeclaration(generate_item_10_built);
         // Calling user action here
-        self.user_grammar.generate_item(&generate_item_9_built)?;
-        self.push(ASTType::GenerateItem(generate_item_9_built), context);
+        self.user_grammar.generate_item(&generate_item_10_built)?;
+        self.push(ASTType::GenerateItem(generate_item_10_built), context);
         Ok(())
     }
 
-    /// Semantic action for production 985:
+    /// Semantic action for production 1024:
     ///
     /// `GenerateItem: GenerateForDeclaration;`
     ///
     #[parol_runtime::function_name::named]
-    fn generate_item_10(&mut self, _generate_for_declaration: &ParseTreeType<'t>) -> Result<()> {
+    fn generate_item_11(&mut self, _generate_for_declaration: &ParseTreeType<'t>) -> Result<()> {
         let context = function_name!();
         trace!("{}", self.trace_item_stack(context));
         let generate_for_declaration = pop_item!(
@@ -37011,22 +38440,22 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
             GenerateForDeclaration,
             context
         );
-        let generate_item_10_built = GenerateItemGenerateForDeclaration {
+        let generate_item_11_built = GenerateItemGenerateForDeclaration {
             generate_for_declaration: Box::new(generate_for_declaration),
         };
-        let generate_item_10_built = GenerateItem::GenerateForDeclaration(generate_item_10_built);
+        let generate_item_11_built = GenerateItem::GenerateForDeclaration(generate_item_11_built);
         // Calling user action here
-        self.user_grammar.generate_item(&generate_item_10_built)?;
-        self.push(ASTType::GenerateItem(generate_item_10_built), context);
+        self.user_grammar.generate_item(&generate_item_11_built)?;
+        self.push(ASTType::GenerateItem(generate_item_11_built), context);
         Ok(())
     }
 
-    /// Semantic action for production 986:
+    /// Semantic action for production 1025:
     ///
     /// `GenerateItem: GenerateBlockDeclaration;`
     ///
     #[parol_runtime::function_name::named]
-    fn generate_item_11(&mut self, _generate_block_declaration: &ParseTreeType<'t>) -> Result<()> {
+    fn generate_item_12(&mut self, _generate_block_declaration: &ParseTreeType<'t>) -> Result<()> {
         let context = function_name!();
         trace!("{}", self.trace_item_stack(context));
         let generate_block_declaration = pop_item!(
@@ -37035,61 +38464,61 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
             GenerateBlockDeclaration,
             context
         );
-        let generate_item_11_built = GenerateItemGenerateBlockDeclaration {
+        let generate_item_12_built = GenerateItemGenerateBlockDeclaration {
             generate_block_declaration: Box::new(generate_block_declaration),
         };
-        let generate_item_11_built = GenerateItem::GenerateBlockDeclaration(generate_item_11_built);
+        let generate_item_12_built = GenerateItem::GenerateBlockDeclaration(generate_item_12_built);
         // Calling user action here
-        self.user_grammar.generate_item(&generate_item_11_built)?;
-        self.push(ASTType::GenerateItem(generate_item_11_built), context);
+        self.user_grammar.generate_item(&generate_item_12_built)?;
+        self.push(ASTType::GenerateItem(generate_item_12_built), context);
         Ok(())
     }
 
-    /// Semantic action for production 987:
+    /// Semantic action for production 1026:
     ///
     /// `GenerateItem: TypeDefDeclaration;`
     ///
     #[parol_runtime::function_name::named]
-    fn generate_item_12(&mut self, _type_def_declaration: &ParseTreeType<'t>) -> Result<()> {
+    fn generate_item_13(&mut self, _type_def_declaration: &ParseTreeType<'t>) -> Result<()> {
         let context = function_name!();
         trace!("{}", self.trace_item_stack(context));
         let type_def_declaration =
             pop_item!(self, type_def_declaration, TypeDefDeclaration, context);
-        let generate_item_12_built = GenerateItemTypeDefDeclaration {
+        let generate_item_13_built = GenerateItemTypeDefDeclaration {
             type_def_declaration: Box::new(type_def_declaration),
         };
-        let generate_item_12_built = GenerateItem::TypeDefDeclaration(generate_item_12_built);
+        let generate_item_13_built = GenerateItem::TypeDefDeclaration(generate_item_13_built);
         // Calling user action here
-        self.user_grammar.generate_item(&generate_item_12_built)?;
-        self.push(ASTType::GenerateItem(generate_item_12_built), context);
+        self.user_grammar.generate_item(&generate_item_13_built)?;
+        self.push(ASTType::GenerateItem(generate_item_13_built), context);
         Ok(())
     }
 
-    /// Semantic action for production 988:
+    /// Semantic action for production 1027:
     ///
     /// `GenerateItem: EnumDeclaration;`
     ///
     #[parol_runtime::function_name::named]
-    fn generate_item_13(&mut self, _enum_declaration: &ParseTreeType<'t>) -> Result<()> {
+    fn generate_item_14(&mut self, _enum_declaration: &ParseTreeType<'t>) -> Result<()> {
         let context = function_name!();
         trace!("{}", self.trace_item_stack(context));
         let enum_declaration = pop_item!(self, enum_declaration, EnumDeclaration, context);
-        let generate_item_13_built = GenerateItemEnumDeclaration {
+        let generate_item_14_built = GenerateItemEnumDeclaration {
             enum_declaration: Box::new(enum_declaration),
         };
-        let generate_item_13_built = GenerateItem::EnumDeclaration(generate_item_13_built);
+        let generate_item_14_built = GenerateItem::EnumDeclaration(generate_item_14_built);
         // Calling user action here
-        self.user_grammar.generate_item(&generate_item_13_built)?;
-        self.push(ASTType::GenerateItem(generate_item_13_built), context);
+        self.user_grammar.generate_item(&generate_item_14_built)?;
+        self.push(ASTType::GenerateItem(generate_item_14_built), context);
         Ok(())
     }
 
-    /// Semantic action for production 989:
+    /// Semantic action for production 1028:
     ///
     /// `GenerateItem: StructUnionDeclaration;`
     ///
     #[parol_runtime::function_name::named]
-    fn generate_item_14(&mut self, _struct_union_declaration: &ParseTreeType<'t>) -> Result<()> {
+    fn generate_item_15(&mut self, _struct_union_declaration: &ParseTreeType<'t>) -> Result<()> {
         let context = function_name!();
         trace!("{}", self.trace_item_stack(context));
         let struct_union_declaration = pop_item!(
@@ -37098,131 +38527,131 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
             StructUnionDeclaration,
             context
         );
-        let generate_item_14_built = GenerateItemStructUnionDeclaration {
+        let generate_item_15_built = GenerateItemStructUnionDeclaration {
             struct_union_declaration: Box::new(struct_union_declaration),
         };
-        let generate_item_14_built = GenerateItem::StructUnionDeclaration(generate_item_14_built);
+        let generate_item_15_built = GenerateItem::StructUnionDeclaration(generate_item_15_built);
         // Calling user action here
-        self.user_grammar.generate_item(&generate_item_14_built)?;
-        self.push(ASTType::GenerateItem(generate_item_14_built), context);
+        self.user_grammar.generate_item(&generate_item_15_built)?;
+        self.push(ASTType::GenerateItem(generate_item_15_built), context);
         Ok(())
     }
 
-    /// Semantic action for production 990:
+    /// Semantic action for production 1029:
     ///
     /// `GenerateItem: ImportDeclaration;`
     ///
     #[parol_runtime::function_name::named]
-    fn generate_item_15(&mut self, _import_declaration: &ParseTreeType<'t>) -> Result<()> {
+    fn generate_item_16(&mut self, _import_declaration: &ParseTreeType<'t>) -> Result<()> {
         let context = function_name!();
         trace!("{}", self.trace_item_stack(context));
         let import_declaration = pop_item!(self, import_declaration, ImportDeclaration, context);
-        let generate_item_15_built = GenerateItemImportDeclaration {
+        let generate_item_16_built = GenerateItemImportDeclaration {
             import_declaration: Box::new(import_declaration),
         };
-        let generate_item_15_built = GenerateItem::ImportDeclaration(generate_item_15_built);
+        let generate_item_16_built = GenerateItem::ImportDeclaration(generate_item_16_built);
         // Calling user action here
-        self.user_grammar.generate_item(&generate_item_15_built)?;
-        self.push(ASTType::GenerateItem(generate_item_15_built), context);
+        self.user_grammar.generate_item(&generate_item_16_built)?;
+        self.push(ASTType::GenerateItem(generate_item_16_built), context);
         Ok(())
     }
 
-    /// Semantic action for production 991:
+    /// Semantic action for production 1030:
     ///
     /// `GenerateItem: AliasDeclaration;`
     ///
     #[parol_runtime::function_name::named]
-    fn generate_item_16(&mut self, _alias_declaration: &ParseTreeType<'t>) -> Result<()> {
+    fn generate_item_17(&mut self, _alias_declaration: &ParseTreeType<'t>) -> Result<()> {
         let context = function_name!();
         trace!("{}", self.trace_item_stack(context));
         let alias_declaration = pop_item!(self, alias_declaration, AliasDeclaration, context);
-        let generate_item_16_built = GenerateItemAliasDeclaration {
+        let generate_item_17_built = GenerateItemAliasDeclaration {
             alias_declaration: Box::new(alias_declaration),
         };
-        let generate_item_16_built = GenerateItem::AliasDeclaration(generate_item_16_built);
+        let generate_item_17_built = GenerateItem::AliasDeclaration(generate_item_17_built);
         // Calling user action here
-        self.user_grammar.generate_item(&generate_item_16_built)?;
-        self.push(ASTType::GenerateItem(generate_item_16_built), context);
+        self.user_grammar.generate_item(&generate_item_17_built)?;
+        self.push(ASTType::GenerateItem(generate_item_17_built), context);
         Ok(())
     }
 
-    /// Semantic action for production 992:
+    /// Semantic action for production 1031:
     ///
     /// `GenerateItem: InitialDeclaration;`
     ///
     #[parol_runtime::function_name::named]
-    fn generate_item_17(&mut self, _initial_declaration: &ParseTreeType<'t>) -> Result<()> {
+    fn generate_item_18(&mut self, _initial_declaration: &ParseTreeType<'t>) -> Result<()> {
         let context = function_name!();
         trace!("{}", self.trace_item_stack(context));
         let initial_declaration = pop_item!(self, initial_declaration, InitialDeclaration, context);
-        let generate_item_17_built = GenerateItemInitialDeclaration {
+        let generate_item_18_built = GenerateItemInitialDeclaration {
             initial_declaration: Box::new(initial_declaration),
         };
-        let generate_item_17_built = GenerateItem::InitialDeclaration(generate_item_17_built);
+        let generate_item_18_built = GenerateItem::InitialDeclaration(generate_item_18_built);
         // Calling user action here
-        self.user_grammar.generate_item(&generate_item_17_built)?;
-        self.push(ASTType::GenerateItem(generate_item_17_built), context);
+        self.user_grammar.generate_item(&generate_item_18_built)?;
+        self.push(ASTType::GenerateItem(generate_item_18_built), context);
         Ok(())
     }
 
-    /// Semantic action for production 993:
+    /// Semantic action for production 1032:
     ///
     /// `GenerateItem: FinalDeclaration;`
     ///
     #[parol_runtime::function_name::named]
-    fn generate_item_18(&mut self, _final_declaration: &ParseTreeType<'t>) -> Result<()> {
+    fn generate_item_19(&mut self, _final_declaration: &ParseTreeType<'t>) -> Result<()> {
         let context = function_name!();
         trace!("{}", self.trace_item_stack(context));
         let final_declaration = pop_item!(self, final_declaration, FinalDeclaration, context);
-        let generate_item_18_built = GenerateItemFinalDeclaration {
+        let generate_item_19_built = GenerateItemFinalDeclaration {
             final_declaration: Box::new(final_declaration),
         };
-        let generate_item_18_built = GenerateItem::FinalDeclaration(generate_item_18_built);
+        let generate_item_19_built = GenerateItem::FinalDeclaration(generate_item_19_built);
         // Calling user action here
-        self.user_grammar.generate_item(&generate_item_18_built)?;
-        self.push(ASTType::GenerateItem(generate_item_18_built), context);
+        self.user_grammar.generate_item(&generate_item_19_built)?;
+        self.push(ASTType::GenerateItem(generate_item_19_built), context);
         Ok(())
     }
 
-    /// Semantic action for production 994:
+    /// Semantic action for production 1033:
     ///
     /// `GenerateItem: UnsafeBlock;`
     ///
     #[parol_runtime::function_name::named]
-    fn generate_item_19(&mut self, _unsafe_block: &ParseTreeType<'t>) -> Result<()> {
+    fn generate_item_20(&mut self, _unsafe_block: &ParseTreeType<'t>) -> Result<()> {
         let context = function_name!();
         trace!("{}", self.trace_item_stack(context));
         let unsafe_block = pop_item!(self, unsafe_block, UnsafeBlock, context);
-        let generate_item_19_built = GenerateItemUnsafeBlock {
+        let generate_item_20_built = GenerateItemUnsafeBlock {
             unsafe_block: Box::new(unsafe_block),
         };
-        let generate_item_19_built = GenerateItem::UnsafeBlock(generate_item_19_built);
+        let generate_item_20_built = GenerateItem::UnsafeBlock(generate_item_20_built);
         // Calling user action here
-        self.user_grammar.generate_item(&generate_item_19_built)?;
-        self.push(ASTType::GenerateItem(generate_item_19_built), context);
+        self.user_grammar.generate_item(&generate_item_20_built)?;
+        self.push(ASTType::GenerateItem(generate_item_20_built), context);
         Ok(())
     }
 
-    /// Semantic action for production 995:
+    /// Semantic action for production 1034:
     ///
     /// `GenerateItem: EmbedDeclaration;`
     ///
     #[parol_runtime::function_name::named]
-    fn generate_item_20(&mut self, _embed_declaration: &ParseTreeType<'t>) -> Result<()> {
+    fn generate_item_21(&mut self, _embed_declaration: &ParseTreeType<'t>) -> Result<()> {
         let context = function_name!();
         trace!("{}", self.trace_item_stack(context));
         let embed_declaration = pop_item!(self, embed_declaration, EmbedDeclaration, context);
-        let generate_item_20_built = GenerateItemEmbedDeclaration {
+        let generate_item_21_built = GenerateItemEmbedDeclaration {
             embed_declaration: Box::new(embed_declaration),
         };
-        let generate_item_20_built = GenerateItem::EmbedDeclaration(generate_item_20_built);
+        let generate_item_21_built = GenerateItem::EmbedDeclaration(generate_item_21_built);
         // Calling user action here
-        self.user_grammar.generate_item(&generate_item_20_built)?;
-        self.push(ASTType::GenerateItem(generate_item_20_built), context);
+        self.user_grammar.generate_item(&generate_item_21_built)?;
+        self.push(ASTType::GenerateItem(generate_item_21_built), context);
         Ok(())
     }
 
-    /// Semantic action for production 996:
+    /// Semantic action for production 1035:
     ///
     /// `PackageDeclaration: Package Identifier PackageDeclarationOpt /* Option */ PackageDeclarationOpt0 /* Option */ LBrace PackageDeclarationList /* Vec */ RBrace;`
     ///
@@ -37280,7 +38709,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 997:
+    /// Semantic action for production 1036:
     ///
     /// `PackageDeclarationList /* Vec<T>::Push */: PackageGroup PackageDeclarationList;`
     ///
@@ -37311,7 +38740,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 998:
+    /// Semantic action for production 1037:
     ///
     /// `PackageDeclarationList /* Vec<T>::New */: ;`
     ///
@@ -37327,7 +38756,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 999:
+    /// Semantic action for production 1038:
     ///
     /// `PackageDeclarationOpt0 /* Option<T>::Some */: For ScopedIdentifier;`
     ///
@@ -37352,7 +38781,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 1000:
+    /// Semantic action for production 1039:
     ///
     /// `PackageDeclarationOpt0 /* Option<T>::None */: ;`
     ///
@@ -37364,7 +38793,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 1001:
+    /// Semantic action for production 1040:
     ///
     /// `PackageDeclarationOpt /* Option<T>::Some */: WithGenericParameter;`
     ///
@@ -37387,7 +38816,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 1002:
+    /// Semantic action for production 1041:
     ///
     /// `PackageDeclarationOpt /* Option<T>::None */: ;`
     ///
@@ -37399,7 +38828,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 1003:
+    /// Semantic action for production 1042:
     ///
     /// `PackageGroup: PackageGroupList /* Vec */ PackageGroupGroup;`
     ///
@@ -37424,7 +38853,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 1004:
+    /// Semantic action for production 1043:
     ///
     /// `PackageGroupGroup: LBrace PackageGroupGroupList /* Vec */ RBrace;`
     ///
@@ -37459,7 +38888,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 1005:
+    /// Semantic action for production 1044:
     ///
     /// `PackageGroupGroupList /* Vec<T>::Push */: PackageGroup PackageGroupGroupList;`
     ///
@@ -37490,7 +38919,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 1006:
+    /// Semantic action for production 1045:
     ///
     /// `PackageGroupGroupList /* Vec<T>::New */: ;`
     ///
@@ -37506,7 +38935,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 1007:
+    /// Semantic action for production 1046:
     ///
     /// `PackageGroupGroup: PackageItem;`
     ///
@@ -37527,7 +38956,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 1008:
+    /// Semantic action for production 1047:
     ///
     /// `PackageGroupList /* Vec<T>::Push */: Attribute PackageGroupList;`
     ///
@@ -37550,7 +38979,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 1009:
+    /// Semantic action for production 1048:
     ///
     /// `PackageGroupList /* Vec<T>::New */: ;`
     ///
@@ -37566,7 +38995,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 1010:
+    /// Semantic action for production 1049:
     ///
     /// `PackageItem: ConstDeclaration;`
     ///
@@ -37585,7 +39014,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 1011:
+    /// Semantic action for production 1050:
     ///
     /// `PackageItem: TypeDefDeclaration;`
     ///
@@ -37605,7 +39034,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 1012:
+    /// Semantic action for production 1051:
     ///
     /// `PackageItem: EnumDeclaration;`
     ///
@@ -37624,7 +39053,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 1013:
+    /// Semantic action for production 1052:
     ///
     /// `PackageItem: StructUnionDeclaration;`
     ///
@@ -37648,7 +39077,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 1014:
+    /// Semantic action for production 1053:
     ///
     /// `PackageItem: FunctionDeclaration;`
     ///
@@ -37668,7 +39097,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 1015:
+    /// Semantic action for production 1054:
     ///
     /// `PackageItem: ImportDeclaration;`
     ///
@@ -37687,7 +39116,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 1016:
+    /// Semantic action for production 1055:
     ///
     /// `PackageItem: AliasDeclaration;`
     ///
@@ -37706,7 +39135,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 1017:
+    /// Semantic action for production 1056:
     ///
     /// `PackageItem: EmbedDeclaration;`
     ///
@@ -37725,7 +39154,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 1018:
+    /// Semantic action for production 1057:
     ///
     /// `AliasDeclaration: Alias AliasDeclarationGroup Identifier Equ ScopedIdentifier Semicolon;`
     ///
@@ -37767,7 +39196,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 1019:
+    /// Semantic action for production 1058:
     ///
     /// `AliasDeclarationGroup: Module;`
     ///
@@ -37788,7 +39217,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 1020:
+    /// Semantic action for production 1059:
     ///
     /// `AliasDeclarationGroup: Interface;`
     ///
@@ -37809,7 +39238,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 1021:
+    /// Semantic action for production 1060:
     ///
     /// `AliasDeclarationGroup: Package;`
     ///
@@ -37830,7 +39259,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 1022:
+    /// Semantic action for production 1061:
     ///
     /// `ProtoDeclaration: Proto ProtoDeclarationGroup;`
     ///
@@ -37860,7 +39289,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 1023:
+    /// Semantic action for production 1062:
     ///
     /// `ProtoDeclarationGroup: ProtoModuleDeclaration;`
     ///
@@ -37889,7 +39318,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 1024:
+    /// Semantic action for production 1063:
     ///
     /// `ProtoDeclarationGroup: ProtoInterfaceDeclaration;`
     ///
@@ -37918,7 +39347,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 1025:
+    /// Semantic action for production 1064:
     ///
     /// `ProtoDeclarationGroup: ProtoPackageDeclaration;`
     ///
@@ -37947,7 +39376,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 1026:
+    /// Semantic action for production 1065:
     ///
     /// `ProtoModuleDeclaration: Module Identifier ProtoModuleDeclarationOpt /* Option */ ProtoModuleDeclarationOpt0 /* Option */ Semicolon;`
     ///
@@ -37994,7 +39423,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 1027:
+    /// Semantic action for production 1066:
     ///
     /// `ProtoModuleDeclarationOpt0 /* Option<T>::Some */: PortDeclaration;`
     ///
@@ -38016,7 +39445,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 1028:
+    /// Semantic action for production 1067:
     ///
     /// `ProtoModuleDeclarationOpt0 /* Option<T>::None */: ;`
     ///
@@ -38028,7 +39457,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 1029:
+    /// Semantic action for production 1068:
     ///
     /// `ProtoModuleDeclarationOpt /* Option<T>::Some */: WithParameter;`
     ///
@@ -38050,7 +39479,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 1030:
+    /// Semantic action for production 1069:
     ///
     /// `ProtoModuleDeclarationOpt /* Option<T>::None */: ;`
     ///
@@ -38062,7 +39491,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 1031:
+    /// Semantic action for production 1070:
     ///
     /// `ProtoInterfaceDeclaration: Interface Identifier ProtoInterfaceDeclarationOpt /* Option */ LBrace ProtoInterfaceDeclarationList /* Vec */ RBrace;`
     ///
@@ -38112,7 +39541,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 1032:
+    /// Semantic action for production 1071:
     ///
     /// `ProtoInterfaceDeclarationList /* Vec<T>::Push */: ProtoInterfaceItem ProtoInterfaceDeclarationList;`
     ///
@@ -38144,7 +39573,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 1033:
+    /// Semantic action for production 1072:
     ///
     /// `ProtoInterfaceDeclarationList /* Vec<T>::New */: ;`
     ///
@@ -38160,7 +39589,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 1034:
+    /// Semantic action for production 1073:
     ///
     /// `ProtoInterfaceDeclarationOpt /* Option<T>::Some */: WithParameter;`
     ///
@@ -38182,7 +39611,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 1035:
+    /// Semantic action for production 1074:
     ///
     /// `ProtoInterfaceDeclarationOpt /* Option<T>::None */: ;`
     ///
@@ -38194,7 +39623,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 1036:
+    /// Semantic action for production 1075:
     ///
     /// `ProtoInterfaceItem: VarDeclaration;`
     ///
@@ -38218,7 +39647,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 1037:
+    /// Semantic action for production 1076:
     ///
     /// `ProtoInterfaceItem: ProtoConstDeclaration;`
     ///
@@ -38250,7 +39679,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 1038:
+    /// Semantic action for production 1077:
     ///
     /// `ProtoInterfaceItem: ProtoFunctionDeclaration;`
     ///
@@ -38282,7 +39711,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 1039:
+    /// Semantic action for production 1078:
     ///
     /// `ProtoInterfaceItem: ProtoTypeDefDeclaration;`
     ///
@@ -38314,7 +39743,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 1040:
+    /// Semantic action for production 1079:
     ///
     /// `ProtoInterfaceItem: ProtoAliasDeclaration;`
     ///
@@ -38346,7 +39775,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 1041:
+    /// Semantic action for production 1080:
     ///
     /// `ProtoInterfaceItem: ModportDeclaration;`
     ///
@@ -38370,7 +39799,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 1042:
+    /// Semantic action for production 1081:
     ///
     /// `ProtoInterfaceItem: ImportDeclaration;`
     ///
@@ -38394,7 +39823,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 1043:
+    /// Semantic action for production 1082:
     ///
     /// `ProtoPackageDeclaration: Package Identifier LBrace ProtoPackageDeclarationList /* Vec */ RBrace;`
     ///
@@ -38436,7 +39865,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 1044:
+    /// Semantic action for production 1083:
     ///
     /// `ProtoPackageDeclarationList /* Vec<T>::Push */: ProtoPacakgeItem ProtoPackageDeclarationList;`
     ///
@@ -38467,7 +39896,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 1045:
+    /// Semantic action for production 1084:
     ///
     /// `ProtoPackageDeclarationList /* Vec<T>::New */: ;`
     ///
@@ -38483,7 +39912,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 1046:
+    /// Semantic action for production 1085:
     ///
     /// `ProtoPacakgeItem: ProtoConstDeclaration;`
     ///
@@ -38512,7 +39941,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 1047:
+    /// Semantic action for production 1086:
     ///
     /// `ProtoPacakgeItem: ProtoTypeDefDeclaration;`
     ///
@@ -38544,7 +39973,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 1048:
+    /// Semantic action for production 1087:
     ///
     /// `ProtoPacakgeItem: EnumDeclaration;`
     ///
@@ -38568,7 +39997,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 1049:
+    /// Semantic action for production 1088:
     ///
     /// `ProtoPacakgeItem: StructUnionDeclaration;`
     ///
@@ -38600,7 +40029,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 1050:
+    /// Semantic action for production 1089:
     ///
     /// `ProtoPacakgeItem: ProtoFunctionDeclaration;`
     ///
@@ -38632,7 +40061,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 1051:
+    /// Semantic action for production 1090:
     ///
     /// `ProtoPacakgeItem: ProtoAliasDeclaration;`
     ///
@@ -38661,7 +40090,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 1052:
+    /// Semantic action for production 1091:
     ///
     /// `ProtoPacakgeItem: ImportDeclaration;`
     ///
@@ -38685,7 +40114,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 1053:
+    /// Semantic action for production 1092:
     ///
     /// `ProtoConstDeclaration: Const Identifier Colon ProtoConstDeclarationGroup Semicolon;`
     ///
@@ -38727,7 +40156,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 1054:
+    /// Semantic action for production 1093:
     ///
     /// `ProtoConstDeclarationGroup: ArrayType;`
     ///
@@ -38748,7 +40177,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 1055:
+    /// Semantic action for production 1094:
     ///
     /// `ProtoConstDeclarationGroup: Type;`
     ///
@@ -38769,7 +40198,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 1056:
+    /// Semantic action for production 1095:
     ///
     /// `ProtoTypeDefDeclaration: Type Identifier ProtoTypeDefDeclarationOpt /* Option */ Semicolon;`
     ///
@@ -38808,7 +40237,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 1057:
+    /// Semantic action for production 1096:
     ///
     /// `ProtoTypeDefDeclarationOpt /* Option<T>::Some */: Equ ArrayType;`
     ///
@@ -38833,7 +40262,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 1058:
+    /// Semantic action for production 1097:
     ///
     /// `ProtoTypeDefDeclarationOpt /* Option<T>::None */: ;`
     ///
@@ -38845,7 +40274,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 1059:
+    /// Semantic action for production 1098:
     ///
     /// `ProtoFunctionDeclaration: Function Identifier ProtoFunctionDeclarationOpt /* Option */ ProtoFunctionDeclarationOpt0 /* Option */ ProtoFunctionDeclarationOpt1 /* Option */ Semicolon;`
     ///
@@ -38900,7 +40329,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 1060:
+    /// Semantic action for production 1099:
     ///
     /// `ProtoFunctionDeclarationOpt1 /* Option<T>::Some */: MinusGT ScalarType;`
     ///
@@ -38925,7 +40354,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 1061:
+    /// Semantic action for production 1100:
     ///
     /// `ProtoFunctionDeclarationOpt1 /* Option<T>::None */: ;`
     ///
@@ -38937,7 +40366,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 1062:
+    /// Semantic action for production 1101:
     ///
     /// `ProtoFunctionDeclarationOpt0 /* Option<T>::Some */: PortDeclaration;`
     ///
@@ -38959,7 +40388,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 1063:
+    /// Semantic action for production 1102:
     ///
     /// `ProtoFunctionDeclarationOpt0 /* Option<T>::None */: ;`
     ///
@@ -38971,7 +40400,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 1064:
+    /// Semantic action for production 1103:
     ///
     /// `ProtoFunctionDeclarationOpt /* Option<T>::Some */: WithGenericParameter;`
     ///
@@ -38994,7 +40423,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 1065:
+    /// Semantic action for production 1104:
     ///
     /// `ProtoFunctionDeclarationOpt /* Option<T>::None */: ;`
     ///
@@ -39006,7 +40435,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 1066:
+    /// Semantic action for production 1105:
     ///
     /// `ProtoAliasDeclaration: Alias ProtoAliasDeclarationGroup Identifier Colon ScopedIdentifier Semicolon;`
     ///
@@ -39051,7 +40480,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 1067:
+    /// Semantic action for production 1106:
     ///
     /// `ProtoAliasDeclarationGroup: Module;`
     ///
@@ -39072,7 +40501,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 1068:
+    /// Semantic action for production 1107:
     ///
     /// `ProtoAliasDeclarationGroup: Interface;`
     ///
@@ -39093,7 +40522,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 1069:
+    /// Semantic action for production 1108:
     ///
     /// `ProtoAliasDeclarationGroup: Package;`
     ///
@@ -39114,7 +40543,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 1070:
+    /// Semantic action for production 1109:
     ///
     /// `EmbedDeclaration: Embed LParen Identifier RParen Identifier EmbedContent;`
     ///
@@ -39151,7 +40580,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 1071:
+    /// Semantic action for production 1110:
     ///
     /// `EmbedContent: EmbedContentToken : VerylToken;`
     ///
@@ -39171,7 +40600,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 1072:
+    /// Semantic action for production 1111:
     ///
     /// `EmbedContentToken: LBraceTerm %push(Embed) LBraceTerm LBraceTerm EmbedContentTokenList /* Vec */ RBraceTerm RBraceTerm RBraceTerm %pop() Comments;`
     ///
@@ -39222,7 +40651,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 1073:
+    /// Semantic action for production 1112:
     ///
     /// `EmbedContentTokenList /* Vec<T>::Push */: EmbedItem EmbedContentTokenList;`
     ///
@@ -39253,7 +40682,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 1074:
+    /// Semantic action for production 1113:
     ///
     /// `EmbedContentTokenList /* Vec<T>::New */: ;`
     ///
@@ -39269,7 +40698,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 1075:
+    /// Semantic action for production 1114:
     ///
     /// `EmbedItem: LBraceTerm EmbedItemList /* Vec */ RBraceTerm;`
     ///
@@ -39297,7 +40726,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 1076:
+    /// Semantic action for production 1115:
     ///
     /// `EmbedItemList /* Vec<T>::Push */: EmbedItem EmbedItemList;`
     ///
@@ -39320,7 +40749,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 1077:
+    /// Semantic action for production 1116:
     ///
     /// `EmbedItemList /* Vec<T>::New */: ;`
     ///
@@ -39333,7 +40762,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 1078:
+    /// Semantic action for production 1117:
     ///
     /// `EmbedItem: AnyTerm;`
     ///
@@ -39352,7 +40781,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 1079:
+    /// Semantic action for production 1118:
     ///
     /// `IncludeDeclaration: Include LParen Identifier Comma StringLiteral RParen Semicolon;`
     ///
@@ -39395,7 +40824,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 1080:
+    /// Semantic action for production 1119:
     ///
     /// `DescriptionGroup: DescriptionGroupList /* Vec */ DescriptionGroupGroup;`
     ///
@@ -39426,7 +40855,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 1081:
+    /// Semantic action for production 1120:
     ///
     /// `DescriptionGroupGroup: LBrace DescriptionGroupGroupList /* Vec */ RBrace;`
     ///
@@ -39464,7 +40893,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 1082:
+    /// Semantic action for production 1121:
     ///
     /// `DescriptionGroupGroupList /* Vec<T>::Push */: DescriptionGroup DescriptionGroupGroupList;`
     ///
@@ -39495,7 +40924,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 1083:
+    /// Semantic action for production 1122:
     ///
     /// `DescriptionGroupGroupList /* Vec<T>::New */: ;`
     ///
@@ -39511,7 +40940,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 1084:
+    /// Semantic action for production 1123:
     ///
     /// `DescriptionGroupGroup: DescriptionItem;`
     ///
@@ -39532,7 +40961,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 1085:
+    /// Semantic action for production 1124:
     ///
     /// `DescriptionGroupList /* Vec<T>::Push */: Attribute DescriptionGroupList;`
     ///
@@ -39559,7 +40988,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 1086:
+    /// Semantic action for production 1125:
     ///
     /// `DescriptionGroupList /* Vec<T>::New */: ;`
     ///
@@ -39575,7 +41004,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 1087:
+    /// Semantic action for production 1126:
     ///
     /// `DescriptionItem: DescriptionItemOpt /* Option */ PublicDescriptionItem;`
     ///
@@ -39608,7 +41037,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 1088:
+    /// Semantic action for production 1127:
     ///
     /// `DescriptionItem: ImportDeclaration;`
     ///
@@ -39628,7 +41057,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 1089:
+    /// Semantic action for production 1128:
     ///
     /// `DescriptionItem: EmbedDeclaration;`
     ///
@@ -39648,7 +41077,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 1090:
+    /// Semantic action for production 1129:
     ///
     /// `DescriptionItem: IncludeDeclaration;`
     ///
@@ -39669,7 +41098,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 1091:
+    /// Semantic action for production 1130:
     ///
     /// `DescriptionItemOpt /* Option<T>::Some */: Pub;`
     ///
@@ -39688,7 +41117,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 1092:
+    /// Semantic action for production 1131:
     ///
     /// `DescriptionItemOpt /* Option<T>::None */: ;`
     ///
@@ -39700,7 +41129,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 1093:
+    /// Semantic action for production 1132:
     ///
     /// `PublicDescriptionItem: ModuleDeclaration;`
     ///
@@ -39724,7 +41153,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 1094:
+    /// Semantic action for production 1133:
     ///
     /// `PublicDescriptionItem: InterfaceDeclaration;`
     ///
@@ -39752,7 +41181,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 1095:
+    /// Semantic action for production 1134:
     ///
     /// `PublicDescriptionItem: PackageDeclaration;`
     ///
@@ -39779,7 +41208,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 1096:
+    /// Semantic action for production 1135:
     ///
     /// `PublicDescriptionItem: AliasDeclaration;`
     ///
@@ -39803,7 +41232,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 1097:
+    /// Semantic action for production 1136:
     ///
     /// `PublicDescriptionItem: ProtoDeclaration;`
     ///
@@ -39827,7 +41256,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 1098:
+    /// Semantic action for production 1137:
     ///
     /// `Veryl: Start VerylList /* Vec */;`
     ///
@@ -39847,7 +41276,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 1099:
+    /// Semantic action for production 1138:
     ///
     /// `VerylList /* Vec<T>::Push */: DescriptionGroup VerylList;`
     ///
@@ -39870,7 +41299,7 @@ impl<'t, 'u> VerylGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 1100:
+    /// Semantic action for production 1139:
     ///
     /// `VerylList /* Vec<T>::New */: ;`
     ///
@@ -39904,421 +41333,433 @@ impl<'t> UserActionsTrait<'t> for VerylGrammarAuto<'t, '_> {
             7 => self.minus_colon_term(&children[0]),
             8 => self.minus_g_t_term(&children[0]),
             9 => self.plus_colon_term(&children[0]),
-            10 => self.assignment_operator_term(&children[0]),
-            11 => self.diamond_operator_term(&children[0]),
-            12 => self.operator12_term(&children[0]),
-            13 => self.operator11_term(&children[0]),
-            14 => self.operator10_term(&children[0]),
-            15 => self.operator09_term(&children[0]),
-            16 => self.operator08_term(&children[0]),
-            17 => self.operator07_term(&children[0]),
-            18 => self.operator03_term(&children[0]),
-            19 => self.operator02_term(&children[0]),
-            20 => self.operator06_term(&children[0]),
-            21 => self.operator05_term(&children[0]),
-            22 => self.operator04_term(&children[0]),
-            23 => self.unary_operator_term(&children[0]),
-            24 => self.colon_colon_l_angle_term(&children[0]),
-            25 => self.colon_colon_term(&children[0]),
-            26 => self.colon_term(&children[0]),
-            27 => self.comma_term(&children[0]),
-            28 => self.dot_dot_equ_term(&children[0]),
-            29 => self.dot_dot_term(&children[0]),
-            30 => self.dot_term(&children[0]),
-            31 => self.equ_term(&children[0]),
-            32 => self.hash_term(&children[0]),
-            33 => self.l_angle_term(&children[0]),
-            34 => self.question_term(&children[0]),
-            35 => self.quote_term(&children[0]),
-            36 => self.quote_l_brace_term(&children[0]),
-            37 => self.l_brace_term(&children[0]),
-            38 => self.l_bracket_term(&children[0]),
-            39 => self.l_paren_term(&children[0]),
-            40 => self.r_angle_term(&children[0]),
-            41 => self.r_brace_term(&children[0]),
-            42 => self.r_bracket_term(&children[0]),
-            43 => self.r_paren_term(&children[0]),
-            44 => self.semicolon_term(&children[0]),
-            45 => self.star_term(&children[0]),
-            46 => self.alias_term(&children[0]),
-            47 => self.always_comb_term(&children[0]),
-            48 => self.always_ff_term(&children[0]),
-            49 => self.assign_term(&children[0]),
-            50 => self.as_term(&children[0]),
-            51 => self.bit_term(&children[0]),
-            52 => self.bool_term(&children[0]),
-            53 => self.case_term(&children[0]),
-            54 => self.clock_term(&children[0]),
-            55 => self.clock_posedge_term(&children[0]),
-            56 => self.clock_negedge_term(&children[0]),
-            57 => self.connect_term(&children[0]),
-            58 => self.const_term(&children[0]),
-            59 => self.converse_term(&children[0]),
-            60 => self.default_term(&children[0]),
-            61 => self.else_term(&children[0]),
-            62 => self.embed_term(&children[0]),
-            63 => self.enum_term(&children[0]),
-            64 => self.f32_term(&children[0]),
-            65 => self.f64_term(&children[0]),
-            66 => self.false_term(&children[0]),
-            67 => self.final_term(&children[0]),
-            68 => self.for_term(&children[0]),
-            69 => self.function_term(&children[0]),
-            70 => self.i8_term(&children[0]),
-            71 => self.i16_term(&children[0]),
-            72 => self.i32_term(&children[0]),
-            73 => self.i64_term(&children[0]),
-            74 => self.if_reset_term(&children[0]),
-            75 => self.if_term(&children[0]),
-            76 => self.import_term(&children[0]),
-            77 => self.include_term(&children[0]),
-            78 => self.initial_term(&children[0]),
-            79 => self.inout_term(&children[0]),
-            80 => self.input_term(&children[0]),
-            81 => self.inside_term(&children[0]),
-            82 => self.inst_term(&children[0]),
-            83 => self.interface_term(&children[0]),
-            84 => self.in_term(&children[0]),
-            85 => self.let_term(&children[0]),
-            86 => self.logic_term(&children[0]),
-            87 => self.lsb_term(&children[0]),
-            88 => self.modport_term(&children[0]),
-            89 => self.module_term(&children[0]),
-            90 => self.msb_term(&children[0]),
-            91 => self.output_term(&children[0]),
-            92 => self.outside_term(&children[0]),
-            93 => self.package_term(&children[0]),
-            94 => self.param_term(&children[0]),
-            95 => self.proto_term(&children[0]),
-            96 => self.pub_term(&children[0]),
-            97 => self.repeat_term(&children[0]),
-            98 => self.reset_term(&children[0]),
-            99 => self.reset_async_high_term(&children[0]),
-            100 => self.reset_async_low_term(&children[0]),
-            101 => self.reset_sync_high_term(&children[0]),
-            102 => self.reset_sync_low_term(&children[0]),
-            103 => self.return_term(&children[0]),
-            104 => self.break_term(&children[0]),
-            105 => self.same_term(&children[0]),
-            106 => self.signed_term(&children[0]),
-            107 => self.step_term(&children[0]),
-            108 => self.string_term(&children[0]),
-            109 => self.struct_term(&children[0]),
-            110 => self.switch_term(&children[0]),
-            111 => self.tri_term(&children[0]),
-            112 => self.true_term(&children[0]),
-            113 => self.type_term(&children[0]),
-            114 => self.u8_term(&children[0]),
-            115 => self.u16_term(&children[0]),
-            116 => self.u32_term(&children[0]),
-            117 => self.u64_term(&children[0]),
-            118 => self.union_term(&children[0]),
-            119 => self.unsafe_term(&children[0]),
-            120 => self.var_term(&children[0]),
-            121 => self.dollar_identifier_term(&children[0]),
-            122 => self.identifier_term(&children[0]),
-            123 => self.any_term(&children[0]),
-            124 => self.comments(&children[0]),
-            125 => self.comments_opt_0(&children[0]),
-            126 => self.comments_opt_1(),
-            127 => self.start_token(&children[0]),
-            128 => self.string_literal_token(&children[0], &children[1]),
-            129 => self.exponent_token(&children[0], &children[1]),
-            130 => self.fixed_point_token(&children[0], &children[1]),
-            131 => self.based_token(&children[0], &children[1]),
-            132 => self.base_less_token(&children[0], &children[1]),
-            133 => self.all_bit_token(&children[0], &children[1]),
-            134 => self.assignment_operator_token(&children[0], &children[1]),
-            135 => self.diamond_operator_token(&children[0], &children[1]),
-            136 => self.operator02_token(&children[0], &children[1]),
-            137 => self.operator03_token(&children[0], &children[1]),
-            138 => self.operator04_token(&children[0], &children[1]),
-            139 => self.operator05_token(&children[0], &children[1]),
-            140 => self.operator06_token(&children[0], &children[1]),
-            141 => self.operator07_token(&children[0], &children[1]),
-            142 => self.operator08_token(&children[0], &children[1]),
-            143 => self.operator09_token(&children[0], &children[1]),
-            144 => self.operator10_token(&children[0], &children[1]),
-            145 => self.operator11_token(&children[0], &children[1]),
-            146 => self.operator12_token(&children[0], &children[1]),
-            147 => self.unary_operator_token(&children[0], &children[1]),
-            148 => self.colon_token(&children[0], &children[1]),
-            149 => self.colon_colon_l_angle_token(&children[0], &children[1]),
-            150 => self.colon_colon_token(&children[0], &children[1]),
-            151 => self.comma_token(&children[0], &children[1]),
-            152 => self.dot_dot_token(&children[0], &children[1]),
-            153 => self.dot_dot_equ_token(&children[0], &children[1]),
-            154 => self.dot_token(&children[0], &children[1]),
-            155 => self.equ_token(&children[0], &children[1]),
-            156 => self.hash_token(&children[0], &children[1]),
-            157 => self.question_token(&children[0], &children[1]),
-            158 => self.quote_token(&children[0], &children[1]),
-            159 => self.quote_l_brace_token(&children[0], &children[1]),
-            160 => self.l_angle_token(&children[0], &children[1]),
-            161 => self.l_brace_token(&children[0], &children[1]),
-            162 => self.l_bracket_token(&children[0], &children[1]),
-            163 => self.l_paren_token(&children[0], &children[1]),
-            164 => self.minus_colon_token(&children[0], &children[1]),
-            165 => self.minus_g_t_token(&children[0], &children[1]),
-            166 => self.plus_colon_token(&children[0], &children[1]),
-            167 => self.r_angle_token(&children[0], &children[1]),
-            168 => self.r_brace_token(&children[0], &children[1]),
-            169 => self.r_bracket_token(&children[0], &children[1]),
-            170 => self.r_paren_token(&children[0], &children[1]),
-            171 => self.semicolon_token(&children[0], &children[1]),
-            172 => self.star_token(&children[0], &children[1]),
-            173 => self.alias_token(&children[0], &children[1]),
-            174 => self.always_comb_token(&children[0], &children[1]),
-            175 => self.always_ff_token(&children[0], &children[1]),
-            176 => self.as_token(&children[0], &children[1]),
-            177 => self.assign_token(&children[0], &children[1]),
-            178 => self.bit_token(&children[0], &children[1]),
-            179 => self.bool_token(&children[0], &children[1]),
-            180 => self.case_token(&children[0], &children[1]),
-            181 => self.clock_token(&children[0], &children[1]),
-            182 => self.clock_posedge_token(&children[0], &children[1]),
-            183 => self.clock_negedge_token(&children[0], &children[1]),
-            184 => self.connect_token(&children[0], &children[1]),
-            185 => self.const_token(&children[0], &children[1]),
-            186 => self.converse_token(&children[0], &children[1]),
-            187 => self.default_token(&children[0], &children[1]),
-            188 => self.else_token(&children[0], &children[1]),
-            189 => self.embed_token(&children[0], &children[1]),
-            190 => self.enum_token(&children[0], &children[1]),
-            191 => self.f32_token(&children[0], &children[1]),
-            192 => self.f64_token(&children[0], &children[1]),
-            193 => self.false_token(&children[0], &children[1]),
-            194 => self.final_token(&children[0], &children[1]),
-            195 => self.for_token(&children[0], &children[1]),
-            196 => self.function_token(&children[0], &children[1]),
-            197 => self.i8_token(&children[0], &children[1]),
-            198 => self.i16_token(&children[0], &children[1]),
-            199 => self.i32_token(&children[0], &children[1]),
-            200 => self.i64_token(&children[0], &children[1]),
-            201 => self.if_reset_token(&children[0], &children[1]),
-            202 => self.if_token(&children[0], &children[1]),
-            203 => self.import_token(&children[0], &children[1]),
-            204 => self.include_token(&children[0], &children[1]),
-            205 => self.initial_token(&children[0], &children[1]),
-            206 => self.inout_token(&children[0], &children[1]),
-            207 => self.input_token(&children[0], &children[1]),
-            208 => self.inside_token(&children[0], &children[1]),
-            209 => self.inst_token(&children[0], &children[1]),
-            210 => self.interface_token(&children[0], &children[1]),
-            211 => self.in_token(&children[0], &children[1]),
-            212 => self.let_token(&children[0], &children[1]),
-            213 => self.logic_token(&children[0], &children[1]),
-            214 => self.lsb_token(&children[0], &children[1]),
-            215 => self.modport_token(&children[0], &children[1]),
-            216 => self.module_token(&children[0], &children[1]),
-            217 => self.msb_token(&children[0], &children[1]),
-            218 => self.output_token(&children[0], &children[1]),
-            219 => self.outside_token(&children[0], &children[1]),
-            220 => self.package_token(&children[0], &children[1]),
-            221 => self.param_token(&children[0], &children[1]),
-            222 => self.proto_token(&children[0], &children[1]),
-            223 => self.pub_token(&children[0], &children[1]),
-            224 => self.repeat_token(&children[0], &children[1]),
-            225 => self.reset_token(&children[0], &children[1]),
-            226 => self.reset_async_high_token(&children[0], &children[1]),
-            227 => self.reset_async_low_token(&children[0], &children[1]),
-            228 => self.reset_sync_high_token(&children[0], &children[1]),
-            229 => self.reset_sync_low_token(&children[0], &children[1]),
-            230 => self.return_token(&children[0], &children[1]),
-            231 => self.break_token(&children[0], &children[1]),
-            232 => self.same_token(&children[0], &children[1]),
-            233 => self.signed_token(&children[0], &children[1]),
-            234 => self.step_token(&children[0], &children[1]),
-            235 => self.string_token(&children[0], &children[1]),
-            236 => self.struct_token(&children[0], &children[1]),
-            237 => self.switch_token(&children[0], &children[1]),
-            238 => self.tri_token(&children[0], &children[1]),
-            239 => self.true_token(&children[0], &children[1]),
-            240 => self.type_token(&children[0], &children[1]),
-            241 => self.u8_token(&children[0], &children[1]),
-            242 => self.u16_token(&children[0], &children[1]),
-            243 => self.u32_token(&children[0], &children[1]),
-            244 => self.u64_token(&children[0], &children[1]),
-            245 => self.union_token(&children[0], &children[1]),
-            246 => self.unsafe_token(&children[0], &children[1]),
-            247 => self.var_token(&children[0], &children[1]),
-            248 => self.dollar_identifier_token(&children[0], &children[1]),
-            249 => self.identifier_token(&children[0], &children[1]),
-            250 => self.start(&children[0]),
-            251 => self.string_literal(&children[0]),
-            252 => self.exponent(&children[0]),
-            253 => self.fixed_point(&children[0]),
-            254 => self.based(&children[0]),
-            255 => self.base_less(&children[0]),
-            256 => self.all_bit(&children[0]),
-            257 => self.assignment_operator(&children[0]),
-            258 => self.diamond_operator(&children[0]),
-            259 => self.operator02(&children[0]),
-            260 => self.operator03(&children[0]),
-            261 => self.operator04(&children[0]),
-            262 => self.operator05(&children[0]),
-            263 => self.operator06(&children[0]),
-            264 => self.operator07(&children[0]),
-            265 => self.operator08(&children[0]),
-            266 => self.operator09(&children[0]),
-            267 => self.operator10(&children[0]),
-            268 => self.operator11(&children[0]),
-            269 => self.operator12(&children[0]),
-            270 => self.unary_operator(&children[0]),
-            271 => self.colon(&children[0]),
-            272 => self.colon_colon_l_angle(&children[0]),
-            273 => self.colon_colon(&children[0]),
-            274 => self.comma(&children[0]),
-            275 => self.dot_dot(&children[0]),
-            276 => self.dot_dot_equ(&children[0]),
-            277 => self.dot(&children[0]),
-            278 => self.equ(&children[0]),
-            279 => self.hash(&children[0]),
-            280 => self.question(&children[0]),
-            281 => self.quote(&children[0]),
-            282 => self.quote_l_brace(&children[0]),
-            283 => self.l_angle(&children[0]),
-            284 => self.l_brace(&children[0]),
-            285 => self.l_bracket(&children[0]),
-            286 => self.l_paren(&children[0]),
-            287 => self.minus_colon(&children[0]),
-            288 => self.minus_g_t(&children[0]),
-            289 => self.plus_colon(&children[0]),
-            290 => self.r_angle(&children[0]),
-            291 => self.r_brace(&children[0]),
-            292 => self.r_bracket(&children[0]),
-            293 => self.r_paren(&children[0]),
-            294 => self.semicolon(&children[0]),
-            295 => self.star(&children[0]),
-            296 => self.alias(&children[0]),
-            297 => self.always_comb(&children[0]),
-            298 => self.always_ff(&children[0]),
-            299 => self.r#as(&children[0]),
-            300 => self.assign(&children[0]),
-            301 => self.bit(&children[0]),
-            302 => self.bool(&children[0]),
-            303 => self.r#break(&children[0]),
-            304 => self.case(&children[0]),
-            305 => self.clock(&children[0]),
-            306 => self.clock_posedge(&children[0]),
-            307 => self.clock_negedge(&children[0]),
-            308 => self.connect(&children[0]),
-            309 => self.r#const(&children[0]),
-            310 => self.converse(&children[0]),
-            311 => self.defaul(&children[0]),
-            312 => self.r#else(&children[0]),
-            313 => self.embed(&children[0]),
-            314 => self.r#enum(&children[0]),
-            315 => self.f32(&children[0]),
-            316 => self.f64(&children[0]),
-            317 => self.r#false(&children[0]),
-            318 => self.r#final(&children[0]),
-            319 => self.r#for(&children[0]),
-            320 => self.function(&children[0]),
-            321 => self.i8(&children[0]),
-            322 => self.i16(&children[0]),
-            323 => self.i32(&children[0]),
-            324 => self.i64(&children[0]),
-            325 => self.r#if(&children[0]),
-            326 => self.if_reset(&children[0]),
-            327 => self.import(&children[0]),
-            328 => self.r#in(&children[0]),
-            329 => self.include(&children[0]),
-            330 => self.initial(&children[0]),
-            331 => self.inout(&children[0]),
-            332 => self.input(&children[0]),
-            333 => self.inside(&children[0]),
-            334 => self.inst(&children[0]),
-            335 => self.interface(&children[0]),
-            336 => self.r#let(&children[0]),
-            337 => self.logic(&children[0]),
-            338 => self.lsb(&children[0]),
-            339 => self.modport(&children[0]),
-            340 => self.module(&children[0]),
-            341 => self.msb(&children[0]),
-            342 => self.output(&children[0]),
-            343 => self.outside(&children[0]),
-            344 => self.package(&children[0]),
-            345 => self.param(&children[0]),
-            346 => self.proto(&children[0]),
-            347 => self.r#pub(&children[0]),
-            348 => self.repeat(&children[0]),
-            349 => self.reset(&children[0]),
-            350 => self.reset_async_high(&children[0]),
-            351 => self.reset_async_low(&children[0]),
-            352 => self.reset_sync_high(&children[0]),
-            353 => self.reset_sync_low(&children[0]),
-            354 => self.r#return(&children[0]),
-            355 => self.same(&children[0]),
-            356 => self.signed(&children[0]),
-            357 => self.step(&children[0]),
-            358 => self.strin(&children[0]),
-            359 => self.r#struct(&children[0]),
-            360 => self.switch(&children[0]),
-            361 => self.tri(&children[0]),
-            362 => self.r#true(&children[0]),
-            363 => self.r#type(&children[0]),
-            364 => self.u8(&children[0]),
-            365 => self.u16(&children[0]),
-            366 => self.u32(&children[0]),
-            367 => self.u64(&children[0]),
-            368 => self.r#union(&children[0]),
-            369 => self.r#unsafe(&children[0]),
-            370 => self.var(&children[0]),
-            371 => self.dollar_identifier(&children[0]),
-            372 => self.identifier(&children[0]),
-            373 => self.number_0(&children[0]),
-            374 => self.number_1(&children[0]),
-            375 => self.integral_number_0(&children[0]),
-            376 => self.integral_number_1(&children[0]),
-            377 => self.integral_number_2(&children[0]),
-            378 => self.real_number_0(&children[0]),
-            379 => self.real_number_1(&children[0]),
-            380 => self.hierarchical_identifier(&children[0], &children[1], &children[2]),
-            381 => self.hierarchical_identifier_list0_0(
+            10 => self.implication_term(&children[0]),
+            11 => self.assignment_operator_term(&children[0]),
+            12 => self.diamond_operator_term(&children[0]),
+            13 => self.operator12_term(&children[0]),
+            14 => self.operator11_term(&children[0]),
+            15 => self.operator10_term(&children[0]),
+            16 => self.operator09_term(&children[0]),
+            17 => self.operator08_term(&children[0]),
+            18 => self.operator07_term(&children[0]),
+            19 => self.operator03_term(&children[0]),
+            20 => self.operator02_term(&children[0]),
+            21 => self.operator06_term(&children[0]),
+            22 => self.operator05_term(&children[0]),
+            23 => self.operator04_term(&children[0]),
+            24 => self.unary_operator_term(&children[0]),
+            25 => self.colon_colon_l_angle_term(&children[0]),
+            26 => self.colon_colon_term(&children[0]),
+            27 => self.colon_term(&children[0]),
+            28 => self.comma_term(&children[0]),
+            29 => self.dot_dot_equ_term(&children[0]),
+            30 => self.dot_dot_term(&children[0]),
+            31 => self.dot_term(&children[0]),
+            32 => self.equ_term(&children[0]),
+            33 => self.hash_term(&children[0]),
+            34 => self.l_angle_term(&children[0]),
+            35 => self.question_term(&children[0]),
+            36 => self.quote_term(&children[0]),
+            37 => self.quote_l_brace_term(&children[0]),
+            38 => self.l_brace_term(&children[0]),
+            39 => self.l_bracket_term(&children[0]),
+            40 => self.l_paren_term(&children[0]),
+            41 => self.r_angle_term(&children[0]),
+            42 => self.r_brace_term(&children[0]),
+            43 => self.r_bracket_term(&children[0]),
+            44 => self.r_paren_term(&children[0]),
+            45 => self.semicolon_term(&children[0]),
+            46 => self.star_term(&children[0]),
+            47 => self.alias_term(&children[0]),
+            48 => self.always_comb_term(&children[0]),
+            49 => self.always_ff_term(&children[0]),
+            50 => self.assert_term(&children[0]),
+            51 => self.assign_term(&children[0]),
+            52 => self.assume_term(&children[0]),
+            53 => self.as_term(&children[0]),
+            54 => self.bit_term(&children[0]),
+            55 => self.bool_term(&children[0]),
+            56 => self.case_term(&children[0]),
+            57 => self.clock_term(&children[0]),
+            58 => self.clock_posedge_term(&children[0]),
+            59 => self.clock_negedge_term(&children[0]),
+            60 => self.connect_term(&children[0]),
+            61 => self.cover_term(&children[0]),
+            62 => self.const_term(&children[0]),
+            63 => self.converse_term(&children[0]),
+            64 => self.default_term(&children[0]),
+            65 => self.else_term(&children[0]),
+            66 => self.embed_term(&children[0]),
+            67 => self.enum_term(&children[0]),
+            68 => self.f32_term(&children[0]),
+            69 => self.f64_term(&children[0]),
+            70 => self.false_term(&children[0]),
+            71 => self.final_term(&children[0]),
+            72 => self.for_term(&children[0]),
+            73 => self.function_term(&children[0]),
+            74 => self.i8_term(&children[0]),
+            75 => self.i16_term(&children[0]),
+            76 => self.i32_term(&children[0]),
+            77 => self.i64_term(&children[0]),
+            78 => self.if_reset_term(&children[0]),
+            79 => self.if_term(&children[0]),
+            80 => self.import_term(&children[0]),
+            81 => self.include_term(&children[0]),
+            82 => self.initial_term(&children[0]),
+            83 => self.inout_term(&children[0]),
+            84 => self.input_term(&children[0]),
+            85 => self.inside_term(&children[0]),
+            86 => self.inst_term(&children[0]),
+            87 => self.interface_term(&children[0]),
+            88 => self.in_term(&children[0]),
+            89 => self.let_term(&children[0]),
+            90 => self.logic_term(&children[0]),
+            91 => self.lsb_term(&children[0]),
+            92 => self.modport_term(&children[0]),
+            93 => self.module_term(&children[0]),
+            94 => self.msb_term(&children[0]),
+            95 => self.output_term(&children[0]),
+            96 => self.outside_term(&children[0]),
+            97 => self.package_term(&children[0]),
+            98 => self.param_term(&children[0]),
+            99 => self.proto_term(&children[0]),
+            100 => self.pub_term(&children[0]),
+            101 => self.repeat_term(&children[0]),
+            102 => self.reset_term(&children[0]),
+            103 => self.reset_async_high_term(&children[0]),
+            104 => self.reset_async_low_term(&children[0]),
+            105 => self.reset_sync_high_term(&children[0]),
+            106 => self.reset_sync_low_term(&children[0]),
+            107 => self.return_term(&children[0]),
+            108 => self.break_term(&children[0]),
+            109 => self.same_term(&children[0]),
+            110 => self.signed_term(&children[0]),
+            111 => self.step_term(&children[0]),
+            112 => self.string_term(&children[0]),
+            113 => self.struct_term(&children[0]),
+            114 => self.switch_term(&children[0]),
+            115 => self.tri_term(&children[0]),
+            116 => self.true_term(&children[0]),
+            117 => self.type_term(&children[0]),
+            118 => self.u8_term(&children[0]),
+            119 => self.u16_term(&children[0]),
+            120 => self.u32_term(&children[0]),
+            121 => self.u64_term(&children[0]),
+            122 => self.union_term(&children[0]),
+            123 => self.unsafe_term(&children[0]),
+            124 => self.var_term(&children[0]),
+            125 => self.dollar_identifier_term(&children[0]),
+            126 => self.identifier_term(&children[0]),
+            127 => self.any_term(&children[0]),
+            128 => self.comments(&children[0]),
+            129 => self.comments_opt_0(&children[0]),
+            130 => self.comments_opt_1(),
+            131 => self.start_token(&children[0]),
+            132 => self.string_literal_token(&children[0], &children[1]),
+            133 => self.exponent_token(&children[0], &children[1]),
+            134 => self.fixed_point_token(&children[0], &children[1]),
+            135 => self.based_token(&children[0], &children[1]),
+            136 => self.base_less_token(&children[0], &children[1]),
+            137 => self.all_bit_token(&children[0], &children[1]),
+            138 => self.assignment_operator_token(&children[0], &children[1]),
+            139 => self.implication_token(&children[0], &children[1]),
+            140 => self.diamond_operator_token(&children[0], &children[1]),
+            141 => self.operator02_token(&children[0], &children[1]),
+            142 => self.operator03_token(&children[0], &children[1]),
+            143 => self.operator04_token(&children[0], &children[1]),
+            144 => self.operator05_token(&children[0], &children[1]),
+            145 => self.operator06_token(&children[0], &children[1]),
+            146 => self.operator07_token(&children[0], &children[1]),
+            147 => self.operator08_token(&children[0], &children[1]),
+            148 => self.operator09_token(&children[0], &children[1]),
+            149 => self.operator10_token(&children[0], &children[1]),
+            150 => self.operator11_token(&children[0], &children[1]),
+            151 => self.operator12_token(&children[0], &children[1]),
+            152 => self.unary_operator_token(&children[0], &children[1]),
+            153 => self.colon_token(&children[0], &children[1]),
+            154 => self.colon_colon_l_angle_token(&children[0], &children[1]),
+            155 => self.colon_colon_token(&children[0], &children[1]),
+            156 => self.comma_token(&children[0], &children[1]),
+            157 => self.dot_dot_token(&children[0], &children[1]),
+            158 => self.dot_dot_equ_token(&children[0], &children[1]),
+            159 => self.dot_token(&children[0], &children[1]),
+            160 => self.equ_token(&children[0], &children[1]),
+            161 => self.hash_token(&children[0], &children[1]),
+            162 => self.question_token(&children[0], &children[1]),
+            163 => self.quote_token(&children[0], &children[1]),
+            164 => self.quote_l_brace_token(&children[0], &children[1]),
+            165 => self.l_angle_token(&children[0], &children[1]),
+            166 => self.l_brace_token(&children[0], &children[1]),
+            167 => self.l_bracket_token(&children[0], &children[1]),
+            168 => self.l_paren_token(&children[0], &children[1]),
+            169 => self.minus_colon_token(&children[0], &children[1]),
+            170 => self.minus_g_t_token(&children[0], &children[1]),
+            171 => self.plus_colon_token(&children[0], &children[1]),
+            172 => self.r_angle_token(&children[0], &children[1]),
+            173 => self.r_brace_token(&children[0], &children[1]),
+            174 => self.r_bracket_token(&children[0], &children[1]),
+            175 => self.r_paren_token(&children[0], &children[1]),
+            176 => self.semicolon_token(&children[0], &children[1]),
+            177 => self.star_token(&children[0], &children[1]),
+            178 => self.alias_token(&children[0], &children[1]),
+            179 => self.always_comb_token(&children[0], &children[1]),
+            180 => self.always_ff_token(&children[0], &children[1]),
+            181 => self.as_token(&children[0], &children[1]),
+            182 => self.assert_token(&children[0], &children[1]),
+            183 => self.assign_token(&children[0], &children[1]),
+            184 => self.assume_token(&children[0], &children[1]),
+            185 => self.bit_token(&children[0], &children[1]),
+            186 => self.bool_token(&children[0], &children[1]),
+            187 => self.case_token(&children[0], &children[1]),
+            188 => self.clock_token(&children[0], &children[1]),
+            189 => self.clock_posedge_token(&children[0], &children[1]),
+            190 => self.clock_negedge_token(&children[0], &children[1]),
+            191 => self.connect_token(&children[0], &children[1]),
+            192 => self.const_token(&children[0], &children[1]),
+            193 => self.converse_token(&children[0], &children[1]),
+            194 => self.cover_token(&children[0], &children[1]),
+            195 => self.default_token(&children[0], &children[1]),
+            196 => self.else_token(&children[0], &children[1]),
+            197 => self.embed_token(&children[0], &children[1]),
+            198 => self.enum_token(&children[0], &children[1]),
+            199 => self.f32_token(&children[0], &children[1]),
+            200 => self.f64_token(&children[0], &children[1]),
+            201 => self.false_token(&children[0], &children[1]),
+            202 => self.final_token(&children[0], &children[1]),
+            203 => self.for_token(&children[0], &children[1]),
+            204 => self.function_token(&children[0], &children[1]),
+            205 => self.i8_token(&children[0], &children[1]),
+            206 => self.i16_token(&children[0], &children[1]),
+            207 => self.i32_token(&children[0], &children[1]),
+            208 => self.i64_token(&children[0], &children[1]),
+            209 => self.if_reset_token(&children[0], &children[1]),
+            210 => self.if_token(&children[0], &children[1]),
+            211 => self.import_token(&children[0], &children[1]),
+            212 => self.include_token(&children[0], &children[1]),
+            213 => self.initial_token(&children[0], &children[1]),
+            214 => self.inout_token(&children[0], &children[1]),
+            215 => self.input_token(&children[0], &children[1]),
+            216 => self.inside_token(&children[0], &children[1]),
+            217 => self.inst_token(&children[0], &children[1]),
+            218 => self.interface_token(&children[0], &children[1]),
+            219 => self.in_token(&children[0], &children[1]),
+            220 => self.let_token(&children[0], &children[1]),
+            221 => self.logic_token(&children[0], &children[1]),
+            222 => self.lsb_token(&children[0], &children[1]),
+            223 => self.modport_token(&children[0], &children[1]),
+            224 => self.module_token(&children[0], &children[1]),
+            225 => self.msb_token(&children[0], &children[1]),
+            226 => self.output_token(&children[0], &children[1]),
+            227 => self.outside_token(&children[0], &children[1]),
+            228 => self.package_token(&children[0], &children[1]),
+            229 => self.param_token(&children[0], &children[1]),
+            230 => self.proto_token(&children[0], &children[1]),
+            231 => self.pub_token(&children[0], &children[1]),
+            232 => self.repeat_token(&children[0], &children[1]),
+            233 => self.reset_token(&children[0], &children[1]),
+            234 => self.reset_async_high_token(&children[0], &children[1]),
+            235 => self.reset_async_low_token(&children[0], &children[1]),
+            236 => self.reset_sync_high_token(&children[0], &children[1]),
+            237 => self.reset_sync_low_token(&children[0], &children[1]),
+            238 => self.return_token(&children[0], &children[1]),
+            239 => self.break_token(&children[0], &children[1]),
+            240 => self.same_token(&children[0], &children[1]),
+            241 => self.signed_token(&children[0], &children[1]),
+            242 => self.step_token(&children[0], &children[1]),
+            243 => self.string_token(&children[0], &children[1]),
+            244 => self.struct_token(&children[0], &children[1]),
+            245 => self.switch_token(&children[0], &children[1]),
+            246 => self.tri_token(&children[0], &children[1]),
+            247 => self.true_token(&children[0], &children[1]),
+            248 => self.type_token(&children[0], &children[1]),
+            249 => self.u8_token(&children[0], &children[1]),
+            250 => self.u16_token(&children[0], &children[1]),
+            251 => self.u32_token(&children[0], &children[1]),
+            252 => self.u64_token(&children[0], &children[1]),
+            253 => self.union_token(&children[0], &children[1]),
+            254 => self.unsafe_token(&children[0], &children[1]),
+            255 => self.var_token(&children[0], &children[1]),
+            256 => self.dollar_identifier_token(&children[0], &children[1]),
+            257 => self.identifier_token(&children[0], &children[1]),
+            258 => self.start(&children[0]),
+            259 => self.string_literal(&children[0]),
+            260 => self.exponent(&children[0]),
+            261 => self.fixed_point(&children[0]),
+            262 => self.based(&children[0]),
+            263 => self.base_less(&children[0]),
+            264 => self.all_bit(&children[0]),
+            265 => self.assignment_operator(&children[0]),
+            266 => self.implication(&children[0]),
+            267 => self.diamond_operator(&children[0]),
+            268 => self.operator02(&children[0]),
+            269 => self.operator03(&children[0]),
+            270 => self.operator04(&children[0]),
+            271 => self.operator05(&children[0]),
+            272 => self.operator06(&children[0]),
+            273 => self.operator07(&children[0]),
+            274 => self.operator08(&children[0]),
+            275 => self.operator09(&children[0]),
+            276 => self.operator10(&children[0]),
+            277 => self.operator11(&children[0]),
+            278 => self.operator12(&children[0]),
+            279 => self.unary_operator(&children[0]),
+            280 => self.colon(&children[0]),
+            281 => self.colon_colon_l_angle(&children[0]),
+            282 => self.colon_colon(&children[0]),
+            283 => self.comma(&children[0]),
+            284 => self.dot_dot(&children[0]),
+            285 => self.dot_dot_equ(&children[0]),
+            286 => self.dot(&children[0]),
+            287 => self.equ(&children[0]),
+            288 => self.hash(&children[0]),
+            289 => self.question(&children[0]),
+            290 => self.quote(&children[0]),
+            291 => self.quote_l_brace(&children[0]),
+            292 => self.l_angle(&children[0]),
+            293 => self.l_brace(&children[0]),
+            294 => self.l_bracket(&children[0]),
+            295 => self.l_paren(&children[0]),
+            296 => self.minus_colon(&children[0]),
+            297 => self.minus_g_t(&children[0]),
+            298 => self.plus_colon(&children[0]),
+            299 => self.r_angle(&children[0]),
+            300 => self.r_brace(&children[0]),
+            301 => self.r_bracket(&children[0]),
+            302 => self.r_paren(&children[0]),
+            303 => self.semicolon(&children[0]),
+            304 => self.star(&children[0]),
+            305 => self.alias(&children[0]),
+            306 => self.always_comb(&children[0]),
+            307 => self.always_ff(&children[0]),
+            308 => self.r#as(&children[0]),
+            309 => self.assert(&children[0]),
+            310 => self.assign(&children[0]),
+            311 => self.assume(&children[0]),
+            312 => self.bit(&children[0]),
+            313 => self.bool(&children[0]),
+            314 => self.r#break(&children[0]),
+            315 => self.case(&children[0]),
+            316 => self.clock(&children[0]),
+            317 => self.clock_posedge(&children[0]),
+            318 => self.clock_negedge(&children[0]),
+            319 => self.connect(&children[0]),
+            320 => self.r#const(&children[0]),
+            321 => self.converse(&children[0]),
+            322 => self.cover(&children[0]),
+            323 => self.defaul(&children[0]),
+            324 => self.r#else(&children[0]),
+            325 => self.embed(&children[0]),
+            326 => self.r#enum(&children[0]),
+            327 => self.f32(&children[0]),
+            328 => self.f64(&children[0]),
+            329 => self.r#false(&children[0]),
+            330 => self.r#final(&children[0]),
+            331 => self.r#for(&children[0]),
+            332 => self.function(&children[0]),
+            333 => self.i8(&children[0]),
+            334 => self.i16(&children[0]),
+            335 => self.i32(&children[0]),
+            336 => self.i64(&children[0]),
+            337 => self.r#if(&children[0]),
+            338 => self.if_reset(&children[0]),
+            339 => self.import(&children[0]),
+            340 => self.r#in(&children[0]),
+            341 => self.include(&children[0]),
+            342 => self.initial(&children[0]),
+            343 => self.inout(&children[0]),
+            344 => self.input(&children[0]),
+            345 => self.inside(&children[0]),
+            346 => self.inst(&children[0]),
+            347 => self.interface(&children[0]),
+            348 => self.r#let(&children[0]),
+            349 => self.logic(&children[0]),
+            350 => self.lsb(&children[0]),
+            351 => self.modport(&children[0]),
+            352 => self.module(&children[0]),
+            353 => self.msb(&children[0]),
+            354 => self.output(&children[0]),
+            355 => self.outside(&children[0]),
+            356 => self.package(&children[0]),
+            357 => self.param(&children[0]),
+            358 => self.proto(&children[0]),
+            359 => self.r#pub(&children[0]),
+            360 => self.repeat(&children[0]),
+            361 => self.reset(&children[0]),
+            362 => self.reset_async_high(&children[0]),
+            363 => self.reset_async_low(&children[0]),
+            364 => self.reset_sync_high(&children[0]),
+            365 => self.reset_sync_low(&children[0]),
+            366 => self.r#return(&children[0]),
+            367 => self.same(&children[0]),
+            368 => self.signed(&children[0]),
+            369 => self.step(&children[0]),
+            370 => self.strin(&children[0]),
+            371 => self.r#struct(&children[0]),
+            372 => self.switch(&children[0]),
+            373 => self.tri(&children[0]),
+            374 => self.r#true(&children[0]),
+            375 => self.r#type(&children[0]),
+            376 => self.u8(&children[0]),
+            377 => self.u16(&children[0]),
+            378 => self.u32(&children[0]),
+            379 => self.u64(&children[0]),
+            380 => self.r#union(&children[0]),
+            381 => self.r#unsafe(&children[0]),
+            382 => self.var(&children[0]),
+            383 => self.dollar_identifier(&children[0]),
+            384 => self.identifier(&children[0]),
+            385 => self.number_0(&children[0]),
+            386 => self.number_1(&children[0]),
+            387 => self.integral_number_0(&children[0]),
+            388 => self.integral_number_1(&children[0]),
+            389 => self.integral_number_2(&children[0]),
+            390 => self.real_number_0(&children[0]),
+            391 => self.real_number_1(&children[0]),
+            392 => self.hierarchical_identifier(&children[0], &children[1], &children[2]),
+            393 => self.hierarchical_identifier_list0_0(
                 &children[0],
                 &children[1],
                 &children[2],
                 &children[3],
             ),
-            382 => self.hierarchical_identifier_list0_list_0(&children[0], &children[1]),
-            383 => self.hierarchical_identifier_list0_list_1(),
-            384 => self.hierarchical_identifier_list0_1(),
-            385 => self.hierarchical_identifier_list_0(&children[0], &children[1]),
-            386 => self.hierarchical_identifier_list_1(),
-            387 => self.scoped_identifier(&children[0], &children[1]),
-            388 => self.scoped_identifier_group_0(&children[0]),
-            389 => self.scoped_identifier_group_1(&children[0], &children[1]),
-            390 => self.scoped_identifier_list_0(
+            394 => self.hierarchical_identifier_list0_list_0(&children[0], &children[1]),
+            395 => self.hierarchical_identifier_list0_list_1(),
+            396 => self.hierarchical_identifier_list0_1(),
+            397 => self.hierarchical_identifier_list_0(&children[0], &children[1]),
+            398 => self.hierarchical_identifier_list_1(),
+            399 => self.scoped_identifier(&children[0], &children[1]),
+            400 => self.scoped_identifier_group_0(&children[0]),
+            401 => self.scoped_identifier_group_1(&children[0], &children[1]),
+            402 => self.scoped_identifier_list_0(
                 &children[0],
                 &children[1],
                 &children[2],
                 &children[3],
             ),
-            391 => self.scoped_identifier_list_1(),
-            392 => self.scoped_identifier_opt0_0(&children[0]),
-            393 => self.scoped_identifier_opt0_1(),
-            394 => self.scoped_identifier_opt_0(&children[0]),
-            395 => self.scoped_identifier_opt_1(),
-            396 => {
+            403 => self.scoped_identifier_list_1(),
+            404 => self.scoped_identifier_opt0_0(&children[0]),
+            405 => self.scoped_identifier_opt0_1(),
+            406 => self.scoped_identifier_opt_0(&children[0]),
+            407 => self.scoped_identifier_opt_1(),
+            408 => {
                 self.expression_identifier(&children[0], &children[1], &children[2], &children[3])
             }
-            397 => self.expression_identifier_list0_0(
+            409 => self.expression_identifier_list0_0(
                 &children[0],
                 &children[1],
                 &children[2],
                 &children[3],
             ),
-            398 => self.expression_identifier_list0_list_0(&children[0], &children[1]),
-            399 => self.expression_identifier_list0_list_1(),
-            400 => self.expression_identifier_list0_1(),
-            401 => self.expression_identifier_list_0(&children[0], &children[1]),
-            402 => self.expression_identifier_list_1(),
-            403 => self.expression_identifier_opt_0(&children[0]),
-            404 => self.expression_identifier_opt_1(),
-            405 => self.expression(&children[0]),
-            406 => self.if_expression(&children[0], &children[1]),
-            407 => self.if_expression_list_0(
+            410 => self.expression_identifier_list0_list_0(&children[0], &children[1]),
+            411 => self.expression_identifier_list0_list_1(),
+            412 => self.expression_identifier_list0_1(),
+            413 => self.expression_identifier_list_0(&children[0], &children[1]),
+            414 => self.expression_identifier_list_1(),
+            415 => self.expression_identifier_opt_0(&children[0]),
+            416 => self.expression_identifier_opt_1(),
+            417 => self.expression(&children[0]),
+            418 => self.if_expression(&children[0], &children[1]),
+            419 => self.if_expression_list_0(
                 &children[0],
                 &children[1],
                 &children[2],
@@ -40326,125 +41767,125 @@ impl<'t> UserActionsTrait<'t> for VerylGrammarAuto<'t, '_> {
                 &children[4],
                 &children[5],
             ),
-            408 => self.if_expression_list_1(),
-            409 => self.expression01(&children[0], &children[1]),
-            410 => self.expression01_list_0(&children[0], &children[1], &children[2]),
-            411 => self.expression01_list_1(),
-            412 => self.expression02(&children[0], &children[1]),
-            413 => self.expression02_list_0(&children[0], &children[1], &children[2]),
-            414 => self.expression02_list_1(),
-            415 => self.expression03(&children[0], &children[1]),
-            416 => self.expression03_list_0(&children[0], &children[1], &children[2]),
-            417 => self.expression03_list_1(),
-            418 => self.expression04(&children[0], &children[1]),
-            419 => self.expression04_list_0(&children[0], &children[1], &children[2]),
-            420 => self.expression04_list_1(),
-            421 => self.expression05(&children[0], &children[1]),
-            422 => self.expression05_list_0(&children[0], &children[1], &children[2]),
-            423 => self.expression05_list_1(),
-            424 => self.expression06(&children[0], &children[1]),
-            425 => self.expression06_list_0(&children[0], &children[1], &children[2]),
-            426 => self.expression06_list_1(),
-            427 => self.expression07(&children[0], &children[1]),
-            428 => self.expression07_list_0(&children[0], &children[1], &children[2]),
-            429 => self.expression07_list_1(),
-            430 => self.expression08(&children[0], &children[1]),
-            431 => self.expression08_list_0(&children[0], &children[1], &children[2]),
-            432 => self.expression08_list_1(),
-            433 => self.expression09(&children[0], &children[1]),
-            434 => self.expression09_list_0(&children[0], &children[1], &children[2]),
-            435 => self.expression09_list_1(),
-            436 => self.expression10(&children[0], &children[1]),
-            437 => self.expression10_list_0(&children[0], &children[1], &children[2]),
-            438 => self.expression10_list_group_0(&children[0]),
-            439 => self.expression10_list_group_1(&children[0]),
-            440 => self.expression10_list_1(),
-            441 => self.expression11(&children[0], &children[1]),
-            442 => self.expression11_list_0(&children[0], &children[1], &children[2]),
-            443 => self.expression11_list_1(),
-            444 => self.expression12(&children[0], &children[1]),
-            445 => self.expression12_opt_0(&children[0], &children[1]),
-            446 => self.expression12_opt_1(),
-            447 => self.expression13(&children[0], &children[1]),
-            448 => self.expression13_list_0(&children[0], &children[1]),
-            449 => self.expression13_list_group_0(&children[0]),
-            450 => self.expression13_list_group_1(&children[0]),
-            451 => self.expression13_list_group_2(&children[0]),
-            452 => self.expression13_list_group_3(&children[0]),
-            453 => self.expression13_list_group_4(&children[0]),
-            454 => self.expression13_list_1(),
-            455 => self.factor_0(&children[0]),
-            456 => self.factor_1(&children[0]),
-            457 => self.factor_2(&children[0]),
-            458 => self.factor_3(&children[0], &children[1], &children[2]),
-            459 => self.factor_4(&children[0], &children[1], &children[2]),
-            460 => self.factor_5(&children[0], &children[1], &children[2]),
-            461 => self.factor_6(&children[0]),
-            462 => self.factor_7(&children[0]),
-            463 => self.factor_8(&children[0]),
-            464 => self.factor_9(&children[0]),
-            465 => self.factor_group_0(&children[0]),
-            466 => self.factor_group_1(&children[0]),
-            467 => self.factor_10(&children[0]),
-            468 => self.factor_11(&children[0]),
-            469 => self.factor_12(&children[0]),
-            470 => self.factor_13(&children[0]),
-            471 => self.boolean_literal_0(&children[0]),
-            472 => self.boolean_literal_1(&children[0]),
-            473 => self.identifier_factor(&children[0], &children[1]),
-            474 => self.identifier_factor_opt_0(&children[0]),
-            475 => self.identifier_factor_opt_group_0(&children[0]),
-            476 => self.identifier_factor_opt_group_1(&children[0]),
-            477 => self.identifier_factor_opt_1(),
-            478 => self.factor_type_factor(&children[0], &children[1]),
-            479 => self.factor_type_factor_list_0(&children[0], &children[1]),
-            480 => self.factor_type_factor_list_1(),
-            481 => self.function_call(&children[0], &children[1], &children[2]),
-            482 => self.function_call_opt_0(&children[0]),
-            483 => self.function_call_opt_1(),
-            484 => self.argument_list(&children[0], &children[1], &children[2]),
-            485 => self.argument_list_list_0(&children[0], &children[1], &children[2]),
-            486 => self.argument_list_list_1(),
-            487 => self.argument_list_opt_0(&children[0]),
-            488 => self.argument_list_opt_1(),
-            489 => self.argument_item(&children[0], &children[1]),
-            490 => self.argument_item_opt_0(&children[0], &children[1]),
-            491 => self.argument_item_opt_1(),
-            492 => self.argument_expression(&children[0]),
-            493 => self.struct_constructor(&children[0], &children[1], &children[2], &children[3]),
-            494 => self.struct_constructor_opt_0(
+            420 => self.if_expression_list_1(),
+            421 => self.expression01(&children[0], &children[1]),
+            422 => self.expression01_list_0(&children[0], &children[1], &children[2]),
+            423 => self.expression01_list_1(),
+            424 => self.expression02(&children[0], &children[1]),
+            425 => self.expression02_list_0(&children[0], &children[1], &children[2]),
+            426 => self.expression02_list_1(),
+            427 => self.expression03(&children[0], &children[1]),
+            428 => self.expression03_list_0(&children[0], &children[1], &children[2]),
+            429 => self.expression03_list_1(),
+            430 => self.expression04(&children[0], &children[1]),
+            431 => self.expression04_list_0(&children[0], &children[1], &children[2]),
+            432 => self.expression04_list_1(),
+            433 => self.expression05(&children[0], &children[1]),
+            434 => self.expression05_list_0(&children[0], &children[1], &children[2]),
+            435 => self.expression05_list_1(),
+            436 => self.expression06(&children[0], &children[1]),
+            437 => self.expression06_list_0(&children[0], &children[1], &children[2]),
+            438 => self.expression06_list_1(),
+            439 => self.expression07(&children[0], &children[1]),
+            440 => self.expression07_list_0(&children[0], &children[1], &children[2]),
+            441 => self.expression07_list_1(),
+            442 => self.expression08(&children[0], &children[1]),
+            443 => self.expression08_list_0(&children[0], &children[1], &children[2]),
+            444 => self.expression08_list_1(),
+            445 => self.expression09(&children[0], &children[1]),
+            446 => self.expression09_list_0(&children[0], &children[1], &children[2]),
+            447 => self.expression09_list_1(),
+            448 => self.expression10(&children[0], &children[1]),
+            449 => self.expression10_list_0(&children[0], &children[1], &children[2]),
+            450 => self.expression10_list_group_0(&children[0]),
+            451 => self.expression10_list_group_1(&children[0]),
+            452 => self.expression10_list_1(),
+            453 => self.expression11(&children[0], &children[1]),
+            454 => self.expression11_list_0(&children[0], &children[1], &children[2]),
+            455 => self.expression11_list_1(),
+            456 => self.expression12(&children[0], &children[1]),
+            457 => self.expression12_opt_0(&children[0], &children[1]),
+            458 => self.expression12_opt_1(),
+            459 => self.expression13(&children[0], &children[1]),
+            460 => self.expression13_list_0(&children[0], &children[1]),
+            461 => self.expression13_list_group_0(&children[0]),
+            462 => self.expression13_list_group_1(&children[0]),
+            463 => self.expression13_list_group_2(&children[0]),
+            464 => self.expression13_list_group_3(&children[0]),
+            465 => self.expression13_list_group_4(&children[0]),
+            466 => self.expression13_list_1(),
+            467 => self.factor_0(&children[0]),
+            468 => self.factor_1(&children[0]),
+            469 => self.factor_2(&children[0]),
+            470 => self.factor_3(&children[0], &children[1], &children[2]),
+            471 => self.factor_4(&children[0], &children[1], &children[2]),
+            472 => self.factor_5(&children[0], &children[1], &children[2]),
+            473 => self.factor_6(&children[0]),
+            474 => self.factor_7(&children[0]),
+            475 => self.factor_8(&children[0]),
+            476 => self.factor_9(&children[0]),
+            477 => self.factor_group_0(&children[0]),
+            478 => self.factor_group_1(&children[0]),
+            479 => self.factor_10(&children[0]),
+            480 => self.factor_11(&children[0]),
+            481 => self.factor_12(&children[0]),
+            482 => self.factor_13(&children[0]),
+            483 => self.boolean_literal_0(&children[0]),
+            484 => self.boolean_literal_1(&children[0]),
+            485 => self.identifier_factor(&children[0], &children[1]),
+            486 => self.identifier_factor_opt_0(&children[0]),
+            487 => self.identifier_factor_opt_group_0(&children[0]),
+            488 => self.identifier_factor_opt_group_1(&children[0]),
+            489 => self.identifier_factor_opt_1(),
+            490 => self.factor_type_factor(&children[0], &children[1]),
+            491 => self.factor_type_factor_list_0(&children[0], &children[1]),
+            492 => self.factor_type_factor_list_1(),
+            493 => self.function_call(&children[0], &children[1], &children[2]),
+            494 => self.function_call_opt_0(&children[0]),
+            495 => self.function_call_opt_1(),
+            496 => self.argument_list(&children[0], &children[1], &children[2]),
+            497 => self.argument_list_list_0(&children[0], &children[1], &children[2]),
+            498 => self.argument_list_list_1(),
+            499 => self.argument_list_opt_0(&children[0]),
+            500 => self.argument_list_opt_1(),
+            501 => self.argument_item(&children[0], &children[1]),
+            502 => self.argument_item_opt_0(&children[0], &children[1]),
+            503 => self.argument_item_opt_1(),
+            504 => self.argument_expression(&children[0]),
+            505 => self.struct_constructor(&children[0], &children[1], &children[2], &children[3]),
+            506 => self.struct_constructor_opt_0(
                 &children[0],
                 &children[1],
                 &children[2],
                 &children[3],
                 &children[4],
             ),
-            495 => self.struct_constructor_opt_1(),
-            496 => self.struct_constructor_list(&children[0], &children[1], &children[2]),
-            497 => self.struct_constructor_list_list_0(&children[0], &children[1], &children[2]),
-            498 => self.struct_constructor_list_list_1(),
-            499 => self.struct_constructor_list_opt_0(&children[0]),
-            500 => self.struct_constructor_list_opt_1(),
-            501 => self.struct_constructor_item(&children[0], &children[1], &children[2]),
-            502 => self.concatenation_list(&children[0], &children[1], &children[2]),
-            503 => self.concatenation_list_list_0(&children[0], &children[1], &children[2]),
-            504 => self.concatenation_list_list_1(),
-            505 => self.concatenation_list_opt_0(&children[0]),
-            506 => self.concatenation_list_opt_1(),
-            507 => self.concatenation_item(&children[0], &children[1]),
-            508 => self.concatenation_item_opt_0(&children[0], &children[1]),
-            509 => self.concatenation_item_opt_1(),
-            510 => self.array_literal_list(&children[0], &children[1], &children[2]),
-            511 => self.array_literal_list_list_0(&children[0], &children[1], &children[2]),
-            512 => self.array_literal_list_list_1(),
-            513 => self.array_literal_list_opt_0(&children[0]),
-            514 => self.array_literal_list_opt_1(),
-            515 => self.array_literal_item(&children[0]),
-            516 => self.array_literal_item_group_0(&children[0], &children[1]),
-            517 => self.array_literal_item_group_1(&children[0], &children[1], &children[2]),
-            518 => self.array_literal_item_opt_0(&children[0], &children[1]),
-            519 => self.array_literal_item_opt_1(),
-            520 => self.case_expression(
+            507 => self.struct_constructor_opt_1(),
+            508 => self.struct_constructor_list(&children[0], &children[1], &children[2]),
+            509 => self.struct_constructor_list_list_0(&children[0], &children[1], &children[2]),
+            510 => self.struct_constructor_list_list_1(),
+            511 => self.struct_constructor_list_opt_0(&children[0]),
+            512 => self.struct_constructor_list_opt_1(),
+            513 => self.struct_constructor_item(&children[0], &children[1], &children[2]),
+            514 => self.concatenation_list(&children[0], &children[1], &children[2]),
+            515 => self.concatenation_list_list_0(&children[0], &children[1], &children[2]),
+            516 => self.concatenation_list_list_1(),
+            517 => self.concatenation_list_opt_0(&children[0]),
+            518 => self.concatenation_list_opt_1(),
+            519 => self.concatenation_item(&children[0], &children[1]),
+            520 => self.concatenation_item_opt_0(&children[0], &children[1]),
+            521 => self.concatenation_item_opt_1(),
+            522 => self.array_literal_list(&children[0], &children[1], &children[2]),
+            523 => self.array_literal_list_list_0(&children[0], &children[1], &children[2]),
+            524 => self.array_literal_list_list_1(),
+            525 => self.array_literal_list_opt_0(&children[0]),
+            526 => self.array_literal_list_opt_1(),
+            527 => self.array_literal_item(&children[0]),
+            528 => self.array_literal_item_group_0(&children[0], &children[1]),
+            529 => self.array_literal_item_group_1(&children[0], &children[1], &children[2]),
+            530 => self.array_literal_item_opt_0(&children[0], &children[1]),
+            531 => self.array_literal_item_opt_1(),
+            532 => self.case_expression(
                 &children[0],
                 &children[1],
                 &children[2],
@@ -40459,17 +41900,17 @@ impl<'t> UserActionsTrait<'t> for VerylGrammarAuto<'t, '_> {
                 &children[11],
                 &children[12],
             ),
-            521 => self.case_expression_list_0(
+            533 => self.case_expression_list_0(
                 &children[0],
                 &children[1],
                 &children[2],
                 &children[3],
                 &children[4],
             ),
-            522 => self.case_expression_list_1(),
-            523 => self.case_expression_opt_0(&children[0]),
-            524 => self.case_expression_opt_1(),
-            525 => self.switch_expression(
+            534 => self.case_expression_list_1(),
+            535 => self.case_expression_opt_0(&children[0]),
+            536 => self.case_expression_opt_1(),
+            537 => self.switch_expression(
                 &children[0],
                 &children[1],
                 &children[2],
@@ -40483,142 +41924,143 @@ impl<'t> UserActionsTrait<'t> for VerylGrammarAuto<'t, '_> {
                 &children[10],
                 &children[11],
             ),
-            526 => self.switch_expression_list_0(
+            538 => self.switch_expression_list_0(
                 &children[0],
                 &children[1],
                 &children[2],
                 &children[3],
                 &children[4],
             ),
-            527 => self.switch_expression_list_1(),
-            528 => self.switch_expression_opt_0(&children[0]),
-            529 => self.switch_expression_opt_1(),
-            530 => self.type_expression(&children[0], &children[1], &children[2], &children[3]),
-            531 => self.inside_expression(
+            539 => self.switch_expression_list_1(),
+            540 => self.switch_expression_opt_0(&children[0]),
+            541 => self.switch_expression_opt_1(),
+            542 => self.type_expression(&children[0], &children[1], &children[2], &children[3]),
+            543 => self.inside_expression(
                 &children[0],
                 &children[1],
                 &children[2],
                 &children[3],
                 &children[4],
             ),
-            532 => self.outside_expression(
+            544 => self.outside_expression(
                 &children[0],
                 &children[1],
                 &children[2],
                 &children[3],
                 &children[4],
             ),
-            533 => self.range_list(&children[0], &children[1], &children[2]),
-            534 => self.range_list_list_0(&children[0], &children[1], &children[2]),
-            535 => self.range_list_list_1(),
-            536 => self.range_list_opt_0(&children[0]),
-            537 => self.range_list_opt_1(),
-            538 => self.range_item(&children[0]),
-            539 => self.select(&children[0], &children[1], &children[2], &children[3]),
-            540 => self.select_opt_0(&children[0], &children[1]),
-            541 => self.select_opt_1(),
-            542 => self.select_operator_0(&children[0]),
-            543 => self.select_operator_1(&children[0]),
-            544 => self.select_operator_2(&children[0]),
-            545 => self.select_operator_3(&children[0]),
-            546 => self.width(&children[0], &children[1], &children[2], &children[3]),
-            547 => self.width_list_0(&children[0], &children[1], &children[2]),
-            548 => self.width_list_1(),
-            549 => self.array(&children[0], &children[1], &children[2], &children[3]),
-            550 => self.array_list_0(&children[0], &children[1], &children[2]),
-            551 => self.array_list_1(),
-            552 => self.range(&children[0], &children[1]),
-            553 => self.range_opt_0(&children[0], &children[1]),
-            554 => self.range_opt_1(),
-            555 => self.range_operator_0(&children[0]),
-            556 => self.range_operator_1(&children[0]),
-            557 => self.fixed_type_0(&children[0]),
-            558 => self.fixed_type_1(&children[0]),
-            559 => self.fixed_type_2(&children[0]),
-            560 => self.fixed_type_3(&children[0]),
-            561 => self.fixed_type_4(&children[0]),
-            562 => self.fixed_type_5(&children[0]),
-            563 => self.fixed_type_6(&children[0]),
-            564 => self.fixed_type_7(&children[0]),
-            565 => self.fixed_type_8(&children[0]),
-            566 => self.fixed_type_9(&children[0]),
-            567 => self.fixed_type_10(&children[0]),
-            568 => self.fixed_type_11(&children[0]),
-            569 => self.variable_type_0(&children[0]),
-            570 => self.variable_type_1(&children[0]),
-            571 => self.variable_type_2(&children[0]),
-            572 => self.variable_type_3(&children[0]),
-            573 => self.variable_type_4(&children[0]),
-            574 => self.variable_type_5(&children[0]),
-            575 => self.variable_type_6(&children[0]),
-            576 => self.variable_type_7(&children[0]),
-            577 => self.variable_type_8(&children[0]),
-            578 => self.variable_type_9(&children[0]),
-            579 => self.user_defined_type(&children[0]),
-            580 => self.type_modifier_0(&children[0]),
-            581 => self.type_modifier_1(&children[0]),
-            582 => self.type_modifier_2(&children[0]),
-            583 => self.factor_type(&children[0]),
-            584 => self.factor_type_group_0(&children[0], &children[1]),
-            585 => self.factor_type_group_1(&children[0]),
-            586 => self.factor_type_opt_0(&children[0]),
-            587 => self.factor_type_opt_1(),
-            588 => self.scalar_type(&children[0], &children[1]),
-            589 => self.scalar_type_group_0(&children[0], &children[1]),
-            590 => self.scalar_type_group_1(&children[0]),
-            591 => self.scalar_type_list_0(&children[0], &children[1]),
-            592 => self.scalar_type_list_1(),
-            593 => self.scalar_type_opt_0(&children[0]),
-            594 => self.scalar_type_opt_1(),
-            595 => self.array_type(&children[0], &children[1]),
-            596 => self.array_type_opt_0(&children[0]),
-            597 => self.array_type_opt_1(),
-            598 => self.casting_type_0(&children[0]),
-            599 => self.casting_type_1(&children[0]),
-            600 => self.casting_type_2(&children[0]),
-            601 => self.casting_type_3(&children[0]),
-            602 => self.casting_type_4(&children[0]),
-            603 => self.casting_type_5(&children[0]),
-            604 => self.casting_type_6(&children[0]),
-            605 => self.casting_type_7(&children[0]),
-            606 => self.casting_type_8(&children[0]),
-            607 => self.casting_type_9(&children[0]),
-            608 => self.casting_type_10(&children[0]),
-            609 => self.casting_type_11(&children[0]),
-            610 => self.casting_type_12(&children[0]),
-            611 => self.casting_type_13(&children[0]),
-            612 => self.casting_type_14(&children[0]),
-            613 => self.casting_type_15(&children[0]),
-            614 => self.casting_type_16(&children[0]),
-            615 => self.casting_type_17(&children[0]),
-            616 => self.casting_type_18(&children[0]),
-            617 => self.casting_type_19(&children[0]),
-            618 => self.casting_type_20(&children[0]),
-            619 => self.casting_type_21(&children[0]),
-            620 => self.clock_domain(&children[0], &children[1]),
-            621 => self.statement_block(&children[0], &children[1], &children[2]),
-            622 => self.statement_block_list_0(&children[0], &children[1]),
-            623 => self.statement_block_list_1(),
-            624 => self.statement_block_group(&children[0], &children[1]),
-            625 => self.statement_block_group_group_0(&children[0], &children[1], &children[2]),
-            626 => self.statement_block_group_group_list_0(&children[0], &children[1]),
-            627 => self.statement_block_group_group_list_1(),
-            628 => self.statement_block_group_group_1(&children[0]),
-            629 => self.statement_block_group_list_0(&children[0], &children[1]),
-            630 => self.statement_block_group_list_1(),
-            631 => self.statement_block_item_0(&children[0]),
-            632 => self.statement_block_item_1(&children[0]),
-            633 => self.statement_block_item_2(&children[0]),
-            634 => self.statement_block_item_3(&children[0]),
-            635 => self.statement_0(&children[0]),
-            636 => self.statement_1(&children[0]),
-            637 => self.statement_2(&children[0]),
-            638 => self.statement_3(&children[0]),
-            639 => self.statement_4(&children[0]),
-            640 => self.statement_5(&children[0]),
-            641 => self.statement_6(&children[0]),
-            642 => self.statement_7(&children[0]),
-            643 => self.let_statement(
+            545 => self.range_list(&children[0], &children[1], &children[2]),
+            546 => self.range_list_list_0(&children[0], &children[1], &children[2]),
+            547 => self.range_list_list_1(),
+            548 => self.range_list_opt_0(&children[0]),
+            549 => self.range_list_opt_1(),
+            550 => self.range_item(&children[0]),
+            551 => self.select(&children[0], &children[1], &children[2], &children[3]),
+            552 => self.select_opt_0(&children[0], &children[1]),
+            553 => self.select_opt_1(),
+            554 => self.select_operator_0(&children[0]),
+            555 => self.select_operator_1(&children[0]),
+            556 => self.select_operator_2(&children[0]),
+            557 => self.select_operator_3(&children[0]),
+            558 => self.width(&children[0], &children[1], &children[2], &children[3]),
+            559 => self.width_list_0(&children[0], &children[1], &children[2]),
+            560 => self.width_list_1(),
+            561 => self.array(&children[0], &children[1], &children[2], &children[3]),
+            562 => self.array_list_0(&children[0], &children[1], &children[2]),
+            563 => self.array_list_1(),
+            564 => self.range(&children[0], &children[1]),
+            565 => self.range_opt_0(&children[0], &children[1]),
+            566 => self.range_opt_1(),
+            567 => self.range_operator_0(&children[0]),
+            568 => self.range_operator_1(&children[0]),
+            569 => self.fixed_type_0(&children[0]),
+            570 => self.fixed_type_1(&children[0]),
+            571 => self.fixed_type_2(&children[0]),
+            572 => self.fixed_type_3(&children[0]),
+            573 => self.fixed_type_4(&children[0]),
+            574 => self.fixed_type_5(&children[0]),
+            575 => self.fixed_type_6(&children[0]),
+            576 => self.fixed_type_7(&children[0]),
+            577 => self.fixed_type_8(&children[0]),
+            578 => self.fixed_type_9(&children[0]),
+            579 => self.fixed_type_10(&children[0]),
+            580 => self.fixed_type_11(&children[0]),
+            581 => self.variable_type_0(&children[0]),
+            582 => self.variable_type_1(&children[0]),
+            583 => self.variable_type_2(&children[0]),
+            584 => self.variable_type_3(&children[0]),
+            585 => self.variable_type_4(&children[0]),
+            586 => self.variable_type_5(&children[0]),
+            587 => self.variable_type_6(&children[0]),
+            588 => self.variable_type_7(&children[0]),
+            589 => self.variable_type_8(&children[0]),
+            590 => self.variable_type_9(&children[0]),
+            591 => self.user_defined_type(&children[0]),
+            592 => self.type_modifier_0(&children[0]),
+            593 => self.type_modifier_1(&children[0]),
+            594 => self.type_modifier_2(&children[0]),
+            595 => self.factor_type(&children[0]),
+            596 => self.factor_type_group_0(&children[0], &children[1]),
+            597 => self.factor_type_group_1(&children[0]),
+            598 => self.factor_type_opt_0(&children[0]),
+            599 => self.factor_type_opt_1(),
+            600 => self.scalar_type(&children[0], &children[1]),
+            601 => self.scalar_type_group_0(&children[0], &children[1]),
+            602 => self.scalar_type_group_1(&children[0]),
+            603 => self.scalar_type_list_0(&children[0], &children[1]),
+            604 => self.scalar_type_list_1(),
+            605 => self.scalar_type_opt_0(&children[0]),
+            606 => self.scalar_type_opt_1(),
+            607 => self.array_type(&children[0], &children[1]),
+            608 => self.array_type_opt_0(&children[0]),
+            609 => self.array_type_opt_1(),
+            610 => self.casting_type_0(&children[0]),
+            611 => self.casting_type_1(&children[0]),
+            612 => self.casting_type_2(&children[0]),
+            613 => self.casting_type_3(&children[0]),
+            614 => self.casting_type_4(&children[0]),
+            615 => self.casting_type_5(&children[0]),
+            616 => self.casting_type_6(&children[0]),
+            617 => self.casting_type_7(&children[0]),
+            618 => self.casting_type_8(&children[0]),
+            619 => self.casting_type_9(&children[0]),
+            620 => self.casting_type_10(&children[0]),
+            621 => self.casting_type_11(&children[0]),
+            622 => self.casting_type_12(&children[0]),
+            623 => self.casting_type_13(&children[0]),
+            624 => self.casting_type_14(&children[0]),
+            625 => self.casting_type_15(&children[0]),
+            626 => self.casting_type_16(&children[0]),
+            627 => self.casting_type_17(&children[0]),
+            628 => self.casting_type_18(&children[0]),
+            629 => self.casting_type_19(&children[0]),
+            630 => self.casting_type_20(&children[0]),
+            631 => self.casting_type_21(&children[0]),
+            632 => self.clock_domain(&children[0], &children[1]),
+            633 => self.statement_block(&children[0], &children[1], &children[2]),
+            634 => self.statement_block_list_0(&children[0], &children[1]),
+            635 => self.statement_block_list_1(),
+            636 => self.statement_block_group(&children[0], &children[1]),
+            637 => self.statement_block_group_group_0(&children[0], &children[1], &children[2]),
+            638 => self.statement_block_group_group_list_0(&children[0], &children[1]),
+            639 => self.statement_block_group_group_list_1(),
+            640 => self.statement_block_group_group_1(&children[0]),
+            641 => self.statement_block_group_list_0(&children[0], &children[1]),
+            642 => self.statement_block_group_list_1(),
+            643 => self.statement_block_item_0(&children[0]),
+            644 => self.statement_block_item_1(&children[0]),
+            645 => self.statement_block_item_2(&children[0]),
+            646 => self.statement_block_item_3(&children[0]),
+            647 => self.statement_0(&children[0]),
+            648 => self.statement_1(&children[0]),
+            649 => self.statement_2(&children[0]),
+            650 => self.statement_3(&children[0]),
+            651 => self.statement_4(&children[0]),
+            652 => self.statement_5(&children[0]),
+            653 => self.statement_6(&children[0]),
+            654 => self.statement_7(&children[0]),
+            655 => self.statement_8(&children[0]),
+            656 => self.let_statement(
                 &children[0],
                 &children[1],
                 &children[2],
@@ -40628,46 +42070,46 @@ impl<'t> UserActionsTrait<'t> for VerylGrammarAuto<'t, '_> {
                 &children[6],
                 &children[7],
             ),
-            644 => self.let_statement_opt_0(&children[0]),
-            645 => self.let_statement_opt_1(),
-            646 => self.identifier_statement(&children[0], &children[1], &children[2]),
-            647 => self.identifier_statement_group_0(&children[0]),
-            648 => self.identifier_statement_group_1(&children[0]),
-            649 => self.assignment(&children[0], &children[1]),
-            650 => self.assignment_group_0(&children[0]),
-            651 => self.assignment_group_1(&children[0]),
-            652 => self.assignment_group_2(&children[0]),
-            653 => self.if_statement(
+            657 => self.let_statement_opt_0(&children[0]),
+            658 => self.let_statement_opt_1(),
+            659 => self.identifier_statement(&children[0], &children[1], &children[2]),
+            660 => self.identifier_statement_group_0(&children[0]),
+            661 => self.identifier_statement_group_1(&children[0]),
+            662 => self.assignment(&children[0], &children[1]),
+            663 => self.assignment_group_0(&children[0]),
+            664 => self.assignment_group_1(&children[0]),
+            665 => self.assignment_group_2(&children[0]),
+            666 => self.if_statement(
                 &children[0],
                 &children[1],
                 &children[2],
                 &children[3],
                 &children[4],
             ),
-            654 => self.if_statement_list_0(
+            667 => self.if_statement_list_0(
                 &children[0],
                 &children[1],
                 &children[2],
                 &children[3],
                 &children[4],
             ),
-            655 => self.if_statement_list_1(),
-            656 => self.if_statement_opt_0(&children[0], &children[1]),
-            657 => self.if_statement_opt_1(),
-            658 => self.if_reset_statement(&children[0], &children[1], &children[2], &children[3]),
-            659 => self.if_reset_statement_list_0(
+            668 => self.if_statement_list_1(),
+            669 => self.if_statement_opt_0(&children[0], &children[1]),
+            670 => self.if_statement_opt_1(),
+            671 => self.if_reset_statement(&children[0], &children[1], &children[2], &children[3]),
+            672 => self.if_reset_statement_list_0(
                 &children[0],
                 &children[1],
                 &children[2],
                 &children[3],
                 &children[4],
             ),
-            660 => self.if_reset_statement_list_1(),
-            661 => self.if_reset_statement_opt_0(&children[0], &children[1]),
-            662 => self.if_reset_statement_opt_1(),
-            663 => self.return_statement(&children[0], &children[1], &children[2]),
-            664 => self.break_statement(&children[0], &children[1]),
-            665 => self.for_statement(
+            673 => self.if_reset_statement_list_1(),
+            674 => self.if_reset_statement_opt_0(&children[0], &children[1]),
+            675 => self.if_reset_statement_opt_1(),
+            676 => self.return_statement(&children[0], &children[1], &children[2]),
+            677 => self.break_statement(&children[0], &children[1]),
+            678 => self.for_statement(
                 &children[0],
                 &children[1],
                 &children[2],
@@ -40677,53 +42119,71 @@ impl<'t> UserActionsTrait<'t> for VerylGrammarAuto<'t, '_> {
                 &children[6],
                 &children[7],
             ),
-            666 => self.for_statement_opt_0(&children[0], &children[1], &children[2]),
-            667 => self.for_statement_opt_1(),
-            668 => self.case_statement(
+            679 => self.for_statement_opt_0(&children[0], &children[1], &children[2]),
+            680 => self.for_statement_opt_1(),
+            681 => self.case_statement(
                 &children[0],
                 &children[1],
                 &children[2],
                 &children[3],
                 &children[4],
             ),
-            669 => self.case_statement_list_0(&children[0], &children[1]),
-            670 => self.case_statement_list_1(),
-            671 => self.case_item(&children[0], &children[1], &children[2]),
-            672 => self.case_item_group0_0(&children[0]),
-            673 => self.case_item_group0_1(&children[0]),
-            674 => self.case_item_group_0(&children[0]),
-            675 => self.case_item_group_1(&children[0]),
-            676 => self.case_condition(&children[0], &children[1]),
-            677 => self.case_condition_list_0(&children[0], &children[1], &children[2]),
-            678 => self.case_condition_list_1(),
-            679 => self.switch_statement(&children[0], &children[1], &children[2], &children[3]),
-            680 => self.switch_statement_list_0(&children[0], &children[1]),
-            681 => self.switch_statement_list_1(),
-            682 => self.switch_item(&children[0], &children[1], &children[2]),
-            683 => self.switch_item_group0_0(&children[0]),
-            684 => self.switch_item_group0_1(&children[0]),
-            685 => self.switch_item_group_0(&children[0]),
-            686 => self.switch_item_group_1(&children[0]),
-            687 => self.switch_condition(&children[0], &children[1]),
-            688 => self.switch_condition_list_0(&children[0], &children[1], &children[2]),
-            689 => self.switch_condition_list_1(),
-            690 => self.attribute(
+            682 => self.case_statement_list_0(&children[0], &children[1]),
+            683 => self.case_statement_list_1(),
+            684 => self.case_item(&children[0], &children[1], &children[2]),
+            685 => self.case_item_group0_0(&children[0]),
+            686 => self.case_item_group0_1(&children[0]),
+            687 => self.case_item_group_0(&children[0]),
+            688 => self.case_item_group_1(&children[0]),
+            689 => self.case_condition(&children[0], &children[1]),
+            690 => self.case_condition_list_0(&children[0], &children[1], &children[2]),
+            691 => self.case_condition_list_1(),
+            692 => self.switch_statement(&children[0], &children[1], &children[2], &children[3]),
+            693 => self.switch_statement_list_0(&children[0], &children[1]),
+            694 => self.switch_statement_list_1(),
+            695 => self.switch_item(&children[0], &children[1], &children[2]),
+            696 => self.switch_item_group0_0(&children[0]),
+            697 => self.switch_item_group0_1(&children[0]),
+            698 => self.switch_item_group_0(&children[0]),
+            699 => self.switch_item_group_1(&children[0]),
+            700 => self.switch_condition(&children[0], &children[1]),
+            701 => self.switch_condition_list_0(&children[0], &children[1], &children[2]),
+            702 => self.switch_condition_list_1(),
+            703 => self.assertion_statement(&children[0], &children[1], &children[2]),
+            704 => self.assertion_keyword_0(&children[0]),
+            705 => self.assertion_keyword_1(&children[0]),
+            706 => self.assertion_keyword_2(&children[0]),
+            707 => self.attribute(
                 &children[0],
                 &children[1],
                 &children[2],
                 &children[3],
                 &children[4],
             ),
-            691 => self.attribute_opt_0(&children[0], &children[1], &children[2]),
-            692 => self.attribute_opt_1(),
-            693 => self.attribute_list(&children[0], &children[1], &children[2]),
-            694 => self.attribute_list_list_0(&children[0], &children[1], &children[2]),
-            695 => self.attribute_list_list_1(),
-            696 => self.attribute_list_opt_0(&children[0]),
-            697 => self.attribute_list_opt_1(),
-            698 => self.attribute_item_0(&children[0]),
-            699 => self.attribute_item_1(&children[0]),
-            700 => self.let_declaration(
+            708 => self.attribute_opt_0(&children[0], &children[1], &children[2]),
+            709 => self.attribute_opt_1(),
+            710 => self.attribute_list(&children[0], &children[1], &children[2]),
+            711 => self.attribute_list_list_0(&children[0], &children[1], &children[2]),
+            712 => self.attribute_list_list_1(),
+            713 => self.attribute_list_opt_0(&children[0]),
+            714 => self.attribute_list_opt_1(),
+            715 => self.attribute_item_0(&children[0]),
+            716 => self.attribute_item_1(&children[0]),
+            717 => self.attribute_item_2(&children[0]),
+            718 => self.attribute_item_3(&children[0]),
+            719 => self.attribute_assignment(&children[0], &children[1], &children[2]),
+            720 => self.attribute_call(&children[0], &children[1], &children[2], &children[3]),
+            721 => self.attribute_value_0(&children[0]),
+            722 => self.attribute_value_1(&children[0]),
+            723 => self.attribute_value_2(&children[0]),
+            724 => self.attribute_value_3(&children[0]),
+            725 => self.attribute_array(&children[0], &children[1], &children[2]),
+            726 => self.attribute_value_list(&children[0], &children[1], &children[2]),
+            727 => self.attribute_value_list_list_0(&children[0], &children[1], &children[2]),
+            728 => self.attribute_value_list_list_1(),
+            729 => self.attribute_value_list_opt_0(&children[0]),
+            730 => self.attribute_value_list_opt_1(),
+            731 => self.let_declaration(
                 &children[0],
                 &children[1],
                 &children[2],
@@ -40733,9 +42193,9 @@ impl<'t> UserActionsTrait<'t> for VerylGrammarAuto<'t, '_> {
                 &children[6],
                 &children[7],
             ),
-            701 => self.let_declaration_opt_0(&children[0]),
-            702 => self.let_declaration_opt_1(),
-            703 => self.var_declaration(
+            732 => self.let_declaration_opt_0(&children[0]),
+            733 => self.let_declaration_opt_1(),
+            734 => self.var_declaration(
                 &children[0],
                 &children[1],
                 &children[2],
@@ -40743,9 +42203,9 @@ impl<'t> UserActionsTrait<'t> for VerylGrammarAuto<'t, '_> {
                 &children[4],
                 &children[5],
             ),
-            704 => self.var_declaration_opt_0(&children[0]),
-            705 => self.var_declaration_opt_1(),
-            706 => self.const_declaration(
+            735 => self.var_declaration_opt_0(&children[0]),
+            736 => self.var_declaration_opt_1(),
+            737 => self.const_declaration(
                 &children[0],
                 &children[1],
                 &children[2],
@@ -40754,49 +42214,64 @@ impl<'t> UserActionsTrait<'t> for VerylGrammarAuto<'t, '_> {
                 &children[5],
                 &children[6],
             ),
-            707 => self.const_declaration_group_0(&children[0]),
-            708 => self.const_declaration_group_1(&children[0]),
-            709 => self.type_def_declaration(
+            738 => self.const_declaration_group_0(&children[0]),
+            739 => self.const_declaration_group_1(&children[0]),
+            740 => self.type_def_declaration(
                 &children[0],
                 &children[1],
                 &children[2],
                 &children[3],
                 &children[4],
             ),
-            710 => self.always_ff_declaration(&children[0], &children[1], &children[2]),
-            711 => self.always_ff_declaration_opt_0(&children[0]),
-            712 => self.always_ff_declaration_opt_1(),
-            713 => {
+            741 => self.always_ff_declaration(&children[0], &children[1], &children[2]),
+            742 => self.always_ff_declaration_opt_0(&children[0]),
+            743 => self.always_ff_declaration_opt_1(),
+            744 => {
                 self.always_ff_event_list(&children[0], &children[1], &children[2], &children[3])
             }
-            714 => self.always_ff_event_list_opt_0(&children[0], &children[1]),
-            715 => self.always_ff_event_list_opt_1(),
-            716 => self.always_ff_clock(&children[0]),
-            717 => self.always_ff_reset(&children[0]),
-            718 => self.always_comb_declaration(&children[0], &children[1]),
-            719 => self.assign_declaration(
+            745 => self.always_ff_event_list_opt_0(&children[0], &children[1]),
+            746 => self.always_ff_event_list_opt_1(),
+            747 => self.always_ff_clock(&children[0]),
+            748 => self.always_ff_reset(&children[0]),
+            749 => self.always_comb_declaration(&children[0], &children[1]),
+            750 => self.assertion_declaration(
+                &children[0],
+                &children[1],
+                &children[2],
+                &children[3],
+                &children[4],
+                &children[5],
+                &children[6],
+            ),
+            751 => self.assertion_declaration_opt1_0(&children[0], &children[1]),
+            752 => self.assertion_declaration_opt1_1(),
+            753 => self.assertion_declaration_opt0_0(&children[0]),
+            754 => self.assertion_declaration_opt0_1(),
+            755 => self.assertion_declaration_opt_0(&children[0]),
+            756 => self.assertion_declaration_opt_1(),
+            757 => self.assign_declaration(
                 &children[0],
                 &children[1],
                 &children[2],
                 &children[3],
                 &children[4],
             ),
-            720 => self.assign_destination_0(&children[0]),
-            721 => self.assign_destination_1(&children[0], &children[1], &children[2]),
-            722 => self.assign_concatenation_list(&children[0], &children[1], &children[2]),
-            723 => self.assign_concatenation_list_list_0(&children[0], &children[1], &children[2]),
-            724 => self.assign_concatenation_list_list_1(),
-            725 => self.assign_concatenation_list_opt_0(&children[0]),
-            726 => self.assign_concatenation_list_opt_1(),
-            727 => self.assign_concatenation_item(&children[0]),
-            728 => self.connect_declaration(
+            758 => self.assign_destination_0(&children[0]),
+            759 => self.assign_destination_1(&children[0], &children[1], &children[2]),
+            760 => self.assign_concatenation_list(&children[0], &children[1], &children[2]),
+            761 => self.assign_concatenation_list_list_0(&children[0], &children[1], &children[2]),
+            762 => self.assign_concatenation_list_list_1(),
+            763 => self.assign_concatenation_list_opt_0(&children[0]),
+            764 => self.assign_concatenation_list_opt_1(),
+            765 => self.assign_concatenation_item(&children[0]),
+            766 => self.connect_declaration(
                 &children[0],
                 &children[1],
                 &children[2],
                 &children[3],
                 &children[4],
             ),
-            729 => self.modport_declaration(
+            767 => self.modport_declaration(
                 &children[0],
                 &children[1],
                 &children[2],
@@ -40804,26 +42279,26 @@ impl<'t> UserActionsTrait<'t> for VerylGrammarAuto<'t, '_> {
                 &children[4],
                 &children[5],
             ),
-            730 => self.modport_declaration_opt0_0(&children[0], &children[1]),
-            731 => self.modport_declaration_opt0_1(),
-            732 => self.modport_declaration_opt_0(&children[0]),
-            733 => self.modport_declaration_opt_1(),
-            734 => self.modport_list(&children[0], &children[1], &children[2]),
-            735 => self.modport_list_list_0(&children[0], &children[1], &children[2]),
-            736 => self.modport_list_list_1(),
-            737 => self.modport_list_opt_0(&children[0]),
-            738 => self.modport_list_opt_1(),
-            739 => self.modport_group(&children[0], &children[1]),
-            740 => self.modport_group_group_0(&children[0], &children[1], &children[2]),
-            741 => self.modport_group_group_1(&children[0]),
-            742 => self.modport_group_list_0(&children[0], &children[1]),
-            743 => self.modport_group_list_1(),
-            744 => self.modport_item(&children[0], &children[1], &children[2]),
-            745 => self.modport_default_0(&children[0]),
-            746 => self.modport_default_1(&children[0]),
-            747 => self.modport_default_2(&children[0], &children[1], &children[2], &children[3]),
-            748 => self.modport_default_3(&children[0], &children[1], &children[2], &children[3]),
-            749 => self.enum_declaration(
+            768 => self.modport_declaration_opt0_0(&children[0], &children[1]),
+            769 => self.modport_declaration_opt0_1(),
+            770 => self.modport_declaration_opt_0(&children[0]),
+            771 => self.modport_declaration_opt_1(),
+            772 => self.modport_list(&children[0], &children[1], &children[2]),
+            773 => self.modport_list_list_0(&children[0], &children[1], &children[2]),
+            774 => self.modport_list_list_1(),
+            775 => self.modport_list_opt_0(&children[0]),
+            776 => self.modport_list_opt_1(),
+            777 => self.modport_group(&children[0], &children[1]),
+            778 => self.modport_group_group_0(&children[0], &children[1], &children[2]),
+            779 => self.modport_group_group_1(&children[0]),
+            780 => self.modport_group_list_0(&children[0], &children[1]),
+            781 => self.modport_group_list_1(),
+            782 => self.modport_item(&children[0], &children[1], &children[2]),
+            783 => self.modport_default_0(&children[0]),
+            784 => self.modport_default_1(&children[0]),
+            785 => self.modport_default_2(&children[0], &children[1], &children[2], &children[3]),
+            786 => self.modport_default_3(&children[0], &children[1], &children[2], &children[3]),
+            787 => self.enum_declaration(
                 &children[0],
                 &children[1],
                 &children[2],
@@ -40831,24 +42306,24 @@ impl<'t> UserActionsTrait<'t> for VerylGrammarAuto<'t, '_> {
                 &children[4],
                 &children[5],
             ),
-            750 => self.enum_declaration_opt_0(&children[0], &children[1]),
-            751 => self.enum_declaration_opt_1(),
-            752 => self.enum_list(&children[0], &children[1], &children[2]),
-            753 => self.enum_list_list_0(&children[0], &children[1], &children[2]),
-            754 => self.enum_list_list_1(),
-            755 => self.enum_list_opt_0(&children[0]),
-            756 => self.enum_list_opt_1(),
-            757 => self.enum_group(&children[0], &children[1]),
-            758 => self.enum_group_group_0(&children[0], &children[1], &children[2]),
-            759 => self.enum_group_group_1(&children[0]),
-            760 => self.enum_group_list_0(&children[0], &children[1]),
-            761 => self.enum_group_list_1(),
-            762 => self.enum_item(&children[0], &children[1]),
-            763 => self.enum_item_opt_0(&children[0], &children[1]),
-            764 => self.enum_item_opt_1(),
-            765 => self.struct_union_0(&children[0]),
-            766 => self.struct_union_1(&children[0]),
-            767 => self.struct_union_declaration(
+            788 => self.enum_declaration_opt_0(&children[0], &children[1]),
+            789 => self.enum_declaration_opt_1(),
+            790 => self.enum_list(&children[0], &children[1], &children[2]),
+            791 => self.enum_list_list_0(&children[0], &children[1], &children[2]),
+            792 => self.enum_list_list_1(),
+            793 => self.enum_list_opt_0(&children[0]),
+            794 => self.enum_list_opt_1(),
+            795 => self.enum_group(&children[0], &children[1]),
+            796 => self.enum_group_group_0(&children[0], &children[1], &children[2]),
+            797 => self.enum_group_group_1(&children[0]),
+            798 => self.enum_group_list_0(&children[0], &children[1]),
+            799 => self.enum_group_list_1(),
+            800 => self.enum_item(&children[0], &children[1]),
+            801 => self.enum_item_opt_0(&children[0], &children[1]),
+            802 => self.enum_item_opt_1(),
+            803 => self.struct_union_0(&children[0]),
+            804 => self.struct_union_1(&children[0]),
+            805 => self.struct_union_declaration(
                 &children[0],
                 &children[1],
                 &children[2],
@@ -40856,22 +42331,22 @@ impl<'t> UserActionsTrait<'t> for VerylGrammarAuto<'t, '_> {
                 &children[4],
                 &children[5],
             ),
-            768 => self.struct_union_declaration_opt_0(&children[0]),
-            769 => self.struct_union_declaration_opt_1(),
-            770 => self.struct_union_list(&children[0], &children[1], &children[2]),
-            771 => self.struct_union_list_list_0(&children[0], &children[1], &children[2]),
-            772 => self.struct_union_list_list_1(),
-            773 => self.struct_union_list_opt_0(&children[0]),
-            774 => self.struct_union_list_opt_1(),
-            775 => self.struct_union_group(&children[0], &children[1]),
-            776 => self.struct_union_group_group_0(&children[0], &children[1], &children[2]),
-            777 => self.struct_union_group_group_1(&children[0]),
-            778 => self.struct_union_group_list_0(&children[0], &children[1]),
-            779 => self.struct_union_group_list_1(),
-            780 => self.struct_union_item(&children[0], &children[1], &children[2]),
-            781 => self.initial_declaration(&children[0], &children[1]),
-            782 => self.final_declaration(&children[0], &children[1]),
-            783 => self.inst_declaration(
+            806 => self.struct_union_declaration_opt_0(&children[0]),
+            807 => self.struct_union_declaration_opt_1(),
+            808 => self.struct_union_list(&children[0], &children[1], &children[2]),
+            809 => self.struct_union_list_list_0(&children[0], &children[1], &children[2]),
+            810 => self.struct_union_list_list_1(),
+            811 => self.struct_union_list_opt_0(&children[0]),
+            812 => self.struct_union_list_opt_1(),
+            813 => self.struct_union_group(&children[0], &children[1]),
+            814 => self.struct_union_group_group_0(&children[0], &children[1], &children[2]),
+            815 => self.struct_union_group_group_1(&children[0]),
+            816 => self.struct_union_group_list_0(&children[0], &children[1]),
+            817 => self.struct_union_group_list_1(),
+            818 => self.struct_union_item(&children[0], &children[1], &children[2]),
+            819 => self.initial_declaration(&children[0], &children[1]),
+            820 => self.final_declaration(&children[0], &children[1]),
+            821 => self.inst_declaration(
                 &children[0],
                 &children[1],
                 &children[2],
@@ -40882,59 +42357,59 @@ impl<'t> UserActionsTrait<'t> for VerylGrammarAuto<'t, '_> {
                 &children[7],
                 &children[8],
             ),
-            784 => self.inst_declaration_opt2_0(&children[0], &children[1], &children[2]),
-            785 => self.inst_declaration_opt3_0(&children[0]),
-            786 => self.inst_declaration_opt3_1(),
-            787 => self.inst_declaration_opt2_1(),
-            788 => self.inst_declaration_opt1_0(&children[0]),
-            789 => self.inst_declaration_opt1_1(),
-            790 => self.inst_declaration_opt0_0(&children[0]),
-            791 => self.inst_declaration_opt0_1(),
-            792 => self.inst_declaration_opt_0(&children[0]),
-            793 => self.inst_declaration_opt_1(),
-            794 => self.inst_parameter(&children[0], &children[1], &children[2], &children[3]),
-            795 => self.inst_parameter_opt_0(&children[0]),
-            796 => self.inst_parameter_opt_1(),
-            797 => self.inst_parameter_list(&children[0], &children[1], &children[2]),
-            798 => self.inst_parameter_list_list_0(&children[0], &children[1], &children[2]),
-            799 => self.inst_parameter_list_list_1(),
-            800 => self.inst_parameter_list_opt_0(&children[0]),
-            801 => self.inst_parameter_list_opt_1(),
-            802 => self.inst_parameter_group(&children[0], &children[1]),
-            803 => self.inst_parameter_group_group_0(&children[0], &children[1], &children[2]),
-            804 => self.inst_parameter_group_group_1(&children[0]),
-            805 => self.inst_parameter_group_list_0(&children[0], &children[1]),
-            806 => self.inst_parameter_group_list_1(),
-            807 => self.inst_parameter_item(&children[0], &children[1]),
-            808 => self.inst_parameter_item_opt_0(&children[0], &children[1]),
-            809 => self.inst_parameter_item_opt_1(),
-            810 => self.inst_port_list(&children[0], &children[1], &children[2]),
-            811 => self.inst_port_list_list_0(&children[0], &children[1], &children[2]),
-            812 => self.inst_port_list_list_1(),
-            813 => self.inst_port_list_opt_0(&children[0]),
-            814 => self.inst_port_list_opt_1(),
-            815 => self.inst_port_group(&children[0], &children[1]),
-            816 => self.inst_port_group_group_0(&children[0], &children[1], &children[2]),
-            817 => self.inst_port_group_group_1(&children[0]),
-            818 => self.inst_port_group_list_0(&children[0], &children[1]),
-            819 => self.inst_port_group_list_1(),
-            820 => self.inst_port_item(&children[0], &children[1]),
-            821 => self.inst_port_item_opt_0(&children[0], &children[1]),
-            822 => self.inst_port_item_opt_1(),
-            823 => self.with_parameter(&children[0], &children[1], &children[2], &children[3]),
-            824 => self.with_parameter_opt_0(&children[0]),
-            825 => self.with_parameter_opt_1(),
-            826 => self.with_parameter_list(&children[0], &children[1], &children[2]),
-            827 => self.with_parameter_list_list_0(&children[0], &children[1], &children[2]),
-            828 => self.with_parameter_list_list_1(),
-            829 => self.with_parameter_list_opt_0(&children[0]),
-            830 => self.with_parameter_list_opt_1(),
-            831 => self.with_parameter_group(&children[0], &children[1]),
-            832 => self.with_parameter_group_group_0(&children[0], &children[1], &children[2]),
-            833 => self.with_parameter_group_group_1(&children[0]),
-            834 => self.with_parameter_group_list_0(&children[0], &children[1]),
-            835 => self.with_parameter_group_list_1(),
-            836 => self.with_parameter_item(
+            822 => self.inst_declaration_opt2_0(&children[0], &children[1], &children[2]),
+            823 => self.inst_declaration_opt3_0(&children[0]),
+            824 => self.inst_declaration_opt3_1(),
+            825 => self.inst_declaration_opt2_1(),
+            826 => self.inst_declaration_opt1_0(&children[0]),
+            827 => self.inst_declaration_opt1_1(),
+            828 => self.inst_declaration_opt0_0(&children[0]),
+            829 => self.inst_declaration_opt0_1(),
+            830 => self.inst_declaration_opt_0(&children[0]),
+            831 => self.inst_declaration_opt_1(),
+            832 => self.inst_parameter(&children[0], &children[1], &children[2], &children[3]),
+            833 => self.inst_parameter_opt_0(&children[0]),
+            834 => self.inst_parameter_opt_1(),
+            835 => self.inst_parameter_list(&children[0], &children[1], &children[2]),
+            836 => self.inst_parameter_list_list_0(&children[0], &children[1], &children[2]),
+            837 => self.inst_parameter_list_list_1(),
+            838 => self.inst_parameter_list_opt_0(&children[0]),
+            839 => self.inst_parameter_list_opt_1(),
+            840 => self.inst_parameter_group(&children[0], &children[1]),
+            841 => self.inst_parameter_group_group_0(&children[0], &children[1], &children[2]),
+            842 => self.inst_parameter_group_group_1(&children[0]),
+            843 => self.inst_parameter_group_list_0(&children[0], &children[1]),
+            844 => self.inst_parameter_group_list_1(),
+            845 => self.inst_parameter_item(&children[0], &children[1]),
+            846 => self.inst_parameter_item_opt_0(&children[0], &children[1]),
+            847 => self.inst_parameter_item_opt_1(),
+            848 => self.inst_port_list(&children[0], &children[1], &children[2]),
+            849 => self.inst_port_list_list_0(&children[0], &children[1], &children[2]),
+            850 => self.inst_port_list_list_1(),
+            851 => self.inst_port_list_opt_0(&children[0]),
+            852 => self.inst_port_list_opt_1(),
+            853 => self.inst_port_group(&children[0], &children[1]),
+            854 => self.inst_port_group_group_0(&children[0], &children[1], &children[2]),
+            855 => self.inst_port_group_group_1(&children[0]),
+            856 => self.inst_port_group_list_0(&children[0], &children[1]),
+            857 => self.inst_port_group_list_1(),
+            858 => self.inst_port_item(&children[0], &children[1]),
+            859 => self.inst_port_item_opt_0(&children[0], &children[1]),
+            860 => self.inst_port_item_opt_1(),
+            861 => self.with_parameter(&children[0], &children[1], &children[2], &children[3]),
+            862 => self.with_parameter_opt_0(&children[0]),
+            863 => self.with_parameter_opt_1(),
+            864 => self.with_parameter_list(&children[0], &children[1], &children[2]),
+            865 => self.with_parameter_list_list_0(&children[0], &children[1], &children[2]),
+            866 => self.with_parameter_list_list_1(),
+            867 => self.with_parameter_list_opt_0(&children[0]),
+            868 => self.with_parameter_list_opt_1(),
+            869 => self.with_parameter_group(&children[0], &children[1]),
+            870 => self.with_parameter_group_group_0(&children[0], &children[1], &children[2]),
+            871 => self.with_parameter_group_group_1(&children[0]),
+            872 => self.with_parameter_group_list_0(&children[0], &children[1]),
+            873 => self.with_parameter_group_list_1(),
+            874 => self.with_parameter_item(
                 &children[0],
                 &children[1],
                 &children[2],
@@ -40942,78 +42417,78 @@ impl<'t> UserActionsTrait<'t> for VerylGrammarAuto<'t, '_> {
                 &children[4],
                 &children[5],
             ),
-            837 => self.with_parameter_item_group0_0(&children[0]),
-            838 => self.with_parameter_item_group0_1(&children[0]),
-            839 => self.with_parameter_item_group_0(&children[0]),
-            840 => self.with_parameter_item_group_1(&children[0]),
-            841 => self.generic_bound_0(&children[0]),
-            842 => self.generic_bound_1(&children[0], &children[1]),
-            843 => self.generic_bound_2(&children[0]),
-            844 => self.with_generic_parameter(&children[0], &children[1], &children[2]),
-            845 => self.with_generic_parameter_list(&children[0], &children[1], &children[2]),
-            846 => {
+            875 => self.with_parameter_item_group0_0(&children[0]),
+            876 => self.with_parameter_item_group0_1(&children[0]),
+            877 => self.with_parameter_item_group_0(&children[0]),
+            878 => self.with_parameter_item_group_1(&children[0]),
+            879 => self.generic_bound_0(&children[0]),
+            880 => self.generic_bound_1(&children[0], &children[1]),
+            881 => self.generic_bound_2(&children[0]),
+            882 => self.with_generic_parameter(&children[0], &children[1], &children[2]),
+            883 => self.with_generic_parameter_list(&children[0], &children[1], &children[2]),
+            884 => {
                 self.with_generic_parameter_list_list_0(&children[0], &children[1], &children[2])
             }
-            847 => self.with_generic_parameter_list_list_1(),
-            848 => self.with_generic_parameter_list_opt_0(&children[0]),
-            849 => self.with_generic_parameter_list_opt_1(),
-            850 => self.with_generic_parameter_item(
+            885 => self.with_generic_parameter_list_list_1(),
+            886 => self.with_generic_parameter_list_opt_0(&children[0]),
+            887 => self.with_generic_parameter_list_opt_1(),
+            888 => self.with_generic_parameter_item(
                 &children[0],
                 &children[1],
                 &children[2],
                 &children[3],
             ),
-            851 => self.with_generic_parameter_item_opt_0(&children[0], &children[1]),
-            852 => self.with_generic_parameter_item_opt_1(),
-            853 => self.generic_proto_bound_0(&children[0]),
-            854 => self.generic_proto_bound_1(&children[0]),
-            855 => self.with_generic_argument(&children[0], &children[1], &children[2]),
-            856 => self.with_generic_argument_opt_0(&children[0]),
-            857 => self.with_generic_argument_opt_1(),
-            858 => self.with_generic_argument_list(&children[0], &children[1], &children[2]),
-            859 => self.with_generic_argument_list_list_0(&children[0], &children[1], &children[2]),
-            860 => self.with_generic_argument_list_list_1(),
-            861 => self.with_generic_argument_list_opt_0(&children[0]),
-            862 => self.with_generic_argument_list_opt_1(),
-            863 => self.with_generic_argument_item_0(&children[0]),
-            864 => self.with_generic_argument_item_1(&children[0]),
-            865 => self.with_generic_argument_item_2(&children[0]),
-            866 => self.with_generic_argument_item_3(&children[0]),
-            867 => self.port_declaration(&children[0], &children[1], &children[2]),
-            868 => self.port_declaration_opt_0(&children[0]),
-            869 => self.port_declaration_opt_1(),
-            870 => self.port_declaration_list(&children[0], &children[1], &children[2]),
-            871 => self.port_declaration_list_list_0(&children[0], &children[1], &children[2]),
-            872 => self.port_declaration_list_list_1(),
-            873 => self.port_declaration_list_opt_0(&children[0]),
-            874 => self.port_declaration_list_opt_1(),
-            875 => self.port_declaration_group(&children[0], &children[1]),
-            876 => self.port_declaration_group_group_0(&children[0], &children[1], &children[2]),
-            877 => self.port_declaration_group_group_1(&children[0]),
-            878 => self.port_declaration_group_list_0(&children[0], &children[1]),
-            879 => self.port_declaration_group_list_1(),
-            880 => self.port_declaration_item(&children[0], &children[1], &children[2]),
-            881 => self.port_declaration_item_group_0(&children[0]),
-            882 => self.port_declaration_item_group_1(&children[0]),
-            883 => self.port_type_concrete(&children[0], &children[1], &children[2], &children[3]),
-            884 => self.port_type_concrete_opt0_0(&children[0], &children[1]),
-            885 => self.port_type_concrete_opt0_1(),
-            886 => self.port_type_concrete_opt_0(&children[0]),
-            887 => self.port_type_concrete_opt_1(),
-            888 => self.port_default_value(&children[0]),
-            889 => self.port_type_abstract(&children[0], &children[1], &children[2], &children[3]),
-            890 => self.port_type_abstract_opt1_0(&children[0]),
-            891 => self.port_type_abstract_opt1_1(),
-            892 => self.port_type_abstract_opt0_0(&children[0], &children[1]),
-            893 => self.port_type_abstract_opt0_1(),
-            894 => self.port_type_abstract_opt_0(&children[0]),
-            895 => self.port_type_abstract_opt_1(),
-            896 => self.direction_0(&children[0]),
-            897 => self.direction_1(&children[0]),
-            898 => self.direction_2(&children[0]),
-            899 => self.direction_3(&children[0]),
-            900 => self.direction_4(&children[0]),
-            901 => self.function_declaration(
+            889 => self.with_generic_parameter_item_opt_0(&children[0], &children[1]),
+            890 => self.with_generic_parameter_item_opt_1(),
+            891 => self.generic_proto_bound_0(&children[0]),
+            892 => self.generic_proto_bound_1(&children[0]),
+            893 => self.with_generic_argument(&children[0], &children[1], &children[2]),
+            894 => self.with_generic_argument_opt_0(&children[0]),
+            895 => self.with_generic_argument_opt_1(),
+            896 => self.with_generic_argument_list(&children[0], &children[1], &children[2]),
+            897 => self.with_generic_argument_list_list_0(&children[0], &children[1], &children[2]),
+            898 => self.with_generic_argument_list_list_1(),
+            899 => self.with_generic_argument_list_opt_0(&children[0]),
+            900 => self.with_generic_argument_list_opt_1(),
+            901 => self.with_generic_argument_item_0(&children[0]),
+            902 => self.with_generic_argument_item_1(&children[0]),
+            903 => self.with_generic_argument_item_2(&children[0]),
+            904 => self.with_generic_argument_item_3(&children[0]),
+            905 => self.port_declaration(&children[0], &children[1], &children[2]),
+            906 => self.port_declaration_opt_0(&children[0]),
+            907 => self.port_declaration_opt_1(),
+            908 => self.port_declaration_list(&children[0], &children[1], &children[2]),
+            909 => self.port_declaration_list_list_0(&children[0], &children[1], &children[2]),
+            910 => self.port_declaration_list_list_1(),
+            911 => self.port_declaration_list_opt_0(&children[0]),
+            912 => self.port_declaration_list_opt_1(),
+            913 => self.port_declaration_group(&children[0], &children[1]),
+            914 => self.port_declaration_group_group_0(&children[0], &children[1], &children[2]),
+            915 => self.port_declaration_group_group_1(&children[0]),
+            916 => self.port_declaration_group_list_0(&children[0], &children[1]),
+            917 => self.port_declaration_group_list_1(),
+            918 => self.port_declaration_item(&children[0], &children[1], &children[2]),
+            919 => self.port_declaration_item_group_0(&children[0]),
+            920 => self.port_declaration_item_group_1(&children[0]),
+            921 => self.port_type_concrete(&children[0], &children[1], &children[2], &children[3]),
+            922 => self.port_type_concrete_opt0_0(&children[0], &children[1]),
+            923 => self.port_type_concrete_opt0_1(),
+            924 => self.port_type_concrete_opt_0(&children[0]),
+            925 => self.port_type_concrete_opt_1(),
+            926 => self.port_default_value(&children[0]),
+            927 => self.port_type_abstract(&children[0], &children[1], &children[2], &children[3]),
+            928 => self.port_type_abstract_opt1_0(&children[0]),
+            929 => self.port_type_abstract_opt1_1(),
+            930 => self.port_type_abstract_opt0_0(&children[0], &children[1]),
+            931 => self.port_type_abstract_opt0_1(),
+            932 => self.port_type_abstract_opt_0(&children[0]),
+            933 => self.port_type_abstract_opt_1(),
+            934 => self.direction_0(&children[0]),
+            935 => self.direction_1(&children[0]),
+            936 => self.direction_2(&children[0]),
+            937 => self.direction_3(&children[0]),
+            938 => self.direction_4(&children[0]),
+            939 => self.function_declaration(
                 &children[0],
                 &children[1],
                 &children[2],
@@ -41021,16 +42496,16 @@ impl<'t> UserActionsTrait<'t> for VerylGrammarAuto<'t, '_> {
                 &children[4],
                 &children[5],
             ),
-            902 => self.function_declaration_opt1_0(&children[0], &children[1]),
-            903 => self.function_declaration_opt1_1(),
-            904 => self.function_declaration_opt0_0(&children[0]),
-            905 => self.function_declaration_opt0_1(),
-            906 => self.function_declaration_opt_0(&children[0]),
-            907 => self.function_declaration_opt_1(),
-            908 => self.import_declaration(&children[0], &children[1], &children[2], &children[3]),
-            909 => self.import_declaration_opt_0(&children[0], &children[1]),
-            910 => self.import_declaration_opt_1(),
-            911 => self.unsafe_block(
+            940 => self.function_declaration_opt1_0(&children[0], &children[1]),
+            941 => self.function_declaration_opt1_1(),
+            942 => self.function_declaration_opt0_0(&children[0]),
+            943 => self.function_declaration_opt0_1(),
+            944 => self.function_declaration_opt_0(&children[0]),
+            945 => self.function_declaration_opt_1(),
+            946 => self.import_declaration(&children[0], &children[1], &children[2], &children[3]),
+            947 => self.import_declaration_opt_0(&children[0], &children[1]),
+            948 => self.import_declaration_opt_1(),
+            949 => self.unsafe_block(
                 &children[0],
                 &children[1],
                 &children[2],
@@ -41039,9 +42514,9 @@ impl<'t> UserActionsTrait<'t> for VerylGrammarAuto<'t, '_> {
                 &children[5],
                 &children[6],
             ),
-            912 => self.unsafe_block_list_0(&children[0], &children[1]),
-            913 => self.unsafe_block_list_1(),
-            914 => self.module_declaration(
+            950 => self.unsafe_block_list_0(&children[0], &children[1]),
+            951 => self.unsafe_block_list_1(),
+            952 => self.module_declaration(
                 &children[0],
                 &children[1],
                 &children[2],
@@ -41052,25 +42527,25 @@ impl<'t> UserActionsTrait<'t> for VerylGrammarAuto<'t, '_> {
                 &children[7],
                 &children[8],
             ),
-            915 => self.module_declaration_list_0(&children[0], &children[1]),
-            916 => self.module_declaration_list_1(),
-            917 => self.module_declaration_opt2_0(&children[0]),
-            918 => self.module_declaration_opt2_1(),
-            919 => self.module_declaration_opt1_0(&children[0]),
-            920 => self.module_declaration_opt1_1(),
-            921 => self.module_declaration_opt0_0(&children[0], &children[1]),
-            922 => self.module_declaration_opt0_1(),
-            923 => self.module_declaration_opt_0(&children[0]),
-            924 => self.module_declaration_opt_1(),
-            925 => self.module_group(&children[0], &children[1]),
-            926 => self.module_group_group_0(&children[0], &children[1], &children[2]),
-            927 => self.module_group_group_list_0(&children[0], &children[1]),
-            928 => self.module_group_group_list_1(),
-            929 => self.module_group_group_1(&children[0]),
-            930 => self.module_group_list_0(&children[0], &children[1]),
-            931 => self.module_group_list_1(),
-            932 => self.module_item(&children[0]),
-            933 => self.interface_declaration(
+            953 => self.module_declaration_list_0(&children[0], &children[1]),
+            954 => self.module_declaration_list_1(),
+            955 => self.module_declaration_opt2_0(&children[0]),
+            956 => self.module_declaration_opt2_1(),
+            957 => self.module_declaration_opt1_0(&children[0]),
+            958 => self.module_declaration_opt1_1(),
+            959 => self.module_declaration_opt0_0(&children[0], &children[1]),
+            960 => self.module_declaration_opt0_1(),
+            961 => self.module_declaration_opt_0(&children[0]),
+            962 => self.module_declaration_opt_1(),
+            963 => self.module_group(&children[0], &children[1]),
+            964 => self.module_group_group_0(&children[0], &children[1], &children[2]),
+            965 => self.module_group_group_list_0(&children[0], &children[1]),
+            966 => self.module_group_group_list_1(),
+            967 => self.module_group_group_1(&children[0]),
+            968 => self.module_group_list_0(&children[0], &children[1]),
+            969 => self.module_group_list_1(),
+            970 => self.module_item(&children[0]),
+            971 => self.interface_declaration(
                 &children[0],
                 &children[1],
                 &children[2],
@@ -41080,41 +42555,41 @@ impl<'t> UserActionsTrait<'t> for VerylGrammarAuto<'t, '_> {
                 &children[6],
                 &children[7],
             ),
-            934 => self.interface_declaration_list_0(&children[0], &children[1]),
-            935 => self.interface_declaration_list_1(),
-            936 => self.interface_declaration_opt1_0(&children[0]),
-            937 => self.interface_declaration_opt1_1(),
-            938 => self.interface_declaration_opt0_0(&children[0], &children[1]),
-            939 => self.interface_declaration_opt0_1(),
-            940 => self.interface_declaration_opt_0(&children[0]),
-            941 => self.interface_declaration_opt_1(),
-            942 => self.interface_group(&children[0], &children[1]),
-            943 => self.interface_group_group_0(&children[0], &children[1], &children[2]),
-            944 => self.interface_group_group_list_0(&children[0], &children[1]),
-            945 => self.interface_group_group_list_1(),
-            946 => self.interface_group_group_1(&children[0]),
-            947 => self.interface_group_list_0(&children[0], &children[1]),
-            948 => self.interface_group_list_1(),
-            949 => self.interface_item_0(&children[0]),
-            950 => self.interface_item_1(&children[0]),
-            951 => self.generate_if_declaration(
+            972 => self.interface_declaration_list_0(&children[0], &children[1]),
+            973 => self.interface_declaration_list_1(),
+            974 => self.interface_declaration_opt1_0(&children[0]),
+            975 => self.interface_declaration_opt1_1(),
+            976 => self.interface_declaration_opt0_0(&children[0], &children[1]),
+            977 => self.interface_declaration_opt0_1(),
+            978 => self.interface_declaration_opt_0(&children[0]),
+            979 => self.interface_declaration_opt_1(),
+            980 => self.interface_group(&children[0], &children[1]),
+            981 => self.interface_group_group_0(&children[0], &children[1], &children[2]),
+            982 => self.interface_group_group_list_0(&children[0], &children[1]),
+            983 => self.interface_group_group_list_1(),
+            984 => self.interface_group_group_1(&children[0]),
+            985 => self.interface_group_list_0(&children[0], &children[1]),
+            986 => self.interface_group_list_1(),
+            987 => self.interface_item_0(&children[0]),
+            988 => self.interface_item_1(&children[0]),
+            989 => self.generate_if_declaration(
                 &children[0],
                 &children[1],
                 &children[2],
                 &children[3],
                 &children[4],
             ),
-            952 => self.generate_if_declaration_list_0(
+            990 => self.generate_if_declaration_list_0(
                 &children[0],
                 &children[1],
                 &children[2],
                 &children[3],
                 &children[4],
             ),
-            953 => self.generate_if_declaration_list_1(),
-            954 => self.generate_if_declaration_opt_0(&children[0], &children[1]),
-            955 => self.generate_if_declaration_opt_1(),
-            956 => self.generate_for_declaration(
+            991 => self.generate_if_declaration_list_1(),
+            992 => self.generate_if_declaration_opt_0(&children[0], &children[1]),
+            993 => self.generate_if_declaration_opt_1(),
+            994 => self.generate_for_declaration(
                 &children[0],
                 &children[1],
                 &children[2],
@@ -41122,57 +42597,58 @@ impl<'t> UserActionsTrait<'t> for VerylGrammarAuto<'t, '_> {
                 &children[4],
                 &children[5],
             ),
-            957 => self.generate_for_declaration_opt_0(&children[0], &children[1], &children[2]),
-            958 => self.generate_for_declaration_opt_1(),
-            959 => self.generate_block_declaration(&children[0]),
-            960 => self.generate_named_block(
+            995 => self.generate_for_declaration_opt_0(&children[0], &children[1], &children[2]),
+            996 => self.generate_for_declaration_opt_1(),
+            997 => self.generate_block_declaration(&children[0]),
+            998 => self.generate_named_block(
                 &children[0],
                 &children[1],
                 &children[2],
                 &children[3],
                 &children[4],
             ),
-            961 => self.generate_named_block_list_0(&children[0], &children[1]),
-            962 => self.generate_named_block_list_1(),
-            963 => self.generate_optional_named_block(
+            999 => self.generate_named_block_list_0(&children[0], &children[1]),
+            1000 => self.generate_named_block_list_1(),
+            1001 => self.generate_optional_named_block(
                 &children[0],
                 &children[1],
                 &children[2],
                 &children[3],
             ),
-            964 => self.generate_optional_named_block_list_0(&children[0], &children[1]),
-            965 => self.generate_optional_named_block_list_1(),
-            966 => self.generate_optional_named_block_opt_0(&children[0], &children[1]),
-            967 => self.generate_optional_named_block_opt_1(),
-            968 => self.generate_group(&children[0], &children[1]),
-            969 => self.generate_group_group_0(&children[0], &children[1], &children[2]),
-            970 => self.generate_group_group_list_0(&children[0], &children[1]),
-            971 => self.generate_group_group_list_1(),
-            972 => self.generate_group_group_1(&children[0]),
-            973 => self.generate_group_list_0(&children[0], &children[1]),
-            974 => self.generate_group_list_1(),
-            975 => self.generate_item_0(&children[0]),
-            976 => self.generate_item_1(&children[0]),
-            977 => self.generate_item_2(&children[0]),
-            978 => self.generate_item_3(&children[0]),
-            979 => self.generate_item_4(&children[0]),
-            980 => self.generate_item_5(&children[0]),
-            981 => self.generate_item_6(&children[0]),
-            982 => self.generate_item_7(&children[0]),
-            983 => self.generate_item_8(&children[0]),
-            984 => self.generate_item_9(&children[0]),
-            985 => self.generate_item_10(&children[0]),
-            986 => self.generate_item_11(&children[0]),
-            987 => self.generate_item_12(&children[0]),
-            988 => self.generate_item_13(&children[0]),
-            989 => self.generate_item_14(&children[0]),
-            990 => self.generate_item_15(&children[0]),
-            991 => self.generate_item_16(&children[0]),
-            992 => self.generate_item_17(&children[0]),
-            993 => self.generate_item_18(&children[0]),
-            994 => self.generate_item_19(&children[0]),
-            995 => self.generate_item_20(&children[0]),
-            996 => self.package_declaration(
+            1002 => self.generate_optional_named_block_list_0(&children[0], &children[1]),
+            1003 => self.generate_optional_named_block_list_1(),
+            1004 => self.generate_optional_named_block_opt_0(&children[0], &children[1]),
+            1005 => self.generate_optional_named_block_opt_1(),
+            1006 => self.generate_group(&children[0], &children[1]),
+            1007 => self.generate_group_group_0(&children[0], &children[1], &children[2]),
+            1008 => self.generate_group_group_list_0(&children[0], &children[1]),
+            1009 => self.generate_group_group_list_1(),
+            1010 => self.generate_group_group_1(&children[0]),
+            1011 => self.generate_group_list_0(&children[0], &children[1]),
+            1012 => self.generate_group_list_1(),
+            1013 => self.generate_item_0(&children[0]),
+            1014 => self.generate_item_1(&children[0]),
+            1015 => self.generate_item_2(&children[0]),
+            1016 => self.generate_item_3(&children[0]),
+            1017 => self.generate_item_4(&children[0]),
+            1018 => self.generate_item_5(&children[0]),
+            1019 => self.generate_item_6(&children[0]),
+            1020 => self.generate_item_7(&children[0]),
+            1021 => self.generate_item_8(&children[0]),
+            1022 => self.generate_item_9(&children[0]),
+            1023 => self.generate_item_10(&children[0]),
+            1024 => self.generate_item_11(&children[0]),
+            1025 => self.generate_item_12(&children[0]),
+            1026 => self.generate_item_13(&children[0]),
+            1027 => self.generate_item_14(&children[0]),
+            1028 => self.generate_item_15(&children[0]),
+            1029 => self.generate_item_16(&children[0]),
+            1030 => self.generate_item_17(&children[0]),
+            1031 => self.generate_item_18(&children[0]),
+            1032 => self.generate_item_19(&children[0]),
+            1033 => self.generate_item_20(&children[0]),
+            1034 => self.generate_item_21(&children[0]),
+            1035 => self.package_declaration(
                 &children[0],
                 &children[1],
                 &children[2],
@@ -41181,28 +42657,28 @@ impl<'t> UserActionsTrait<'t> for VerylGrammarAuto<'t, '_> {
                 &children[5],
                 &children[6],
             ),
-            997 => self.package_declaration_list_0(&children[0], &children[1]),
-            998 => self.package_declaration_list_1(),
-            999 => self.package_declaration_opt0_0(&children[0], &children[1]),
-            1000 => self.package_declaration_opt0_1(),
-            1001 => self.package_declaration_opt_0(&children[0]),
-            1002 => self.package_declaration_opt_1(),
-            1003 => self.package_group(&children[0], &children[1]),
-            1004 => self.package_group_group_0(&children[0], &children[1], &children[2]),
-            1005 => self.package_group_group_list_0(&children[0], &children[1]),
-            1006 => self.package_group_group_list_1(),
-            1007 => self.package_group_group_1(&children[0]),
-            1008 => self.package_group_list_0(&children[0], &children[1]),
-            1009 => self.package_group_list_1(),
-            1010 => self.package_item_0(&children[0]),
-            1011 => self.package_item_1(&children[0]),
-            1012 => self.package_item_2(&children[0]),
-            1013 => self.package_item_3(&children[0]),
-            1014 => self.package_item_4(&children[0]),
-            1015 => self.package_item_5(&children[0]),
-            1016 => self.package_item_6(&children[0]),
-            1017 => self.package_item_7(&children[0]),
-            1018 => self.alias_declaration(
+            1036 => self.package_declaration_list_0(&children[0], &children[1]),
+            1037 => self.package_declaration_list_1(),
+            1038 => self.package_declaration_opt0_0(&children[0], &children[1]),
+            1039 => self.package_declaration_opt0_1(),
+            1040 => self.package_declaration_opt_0(&children[0]),
+            1041 => self.package_declaration_opt_1(),
+            1042 => self.package_group(&children[0], &children[1]),
+            1043 => self.package_group_group_0(&children[0], &children[1], &children[2]),
+            1044 => self.package_group_group_list_0(&children[0], &children[1]),
+            1045 => self.package_group_group_list_1(),
+            1046 => self.package_group_group_1(&children[0]),
+            1047 => self.package_group_list_0(&children[0], &children[1]),
+            1048 => self.package_group_list_1(),
+            1049 => self.package_item_0(&children[0]),
+            1050 => self.package_item_1(&children[0]),
+            1051 => self.package_item_2(&children[0]),
+            1052 => self.package_item_3(&children[0]),
+            1053 => self.package_item_4(&children[0]),
+            1054 => self.package_item_5(&children[0]),
+            1055 => self.package_item_6(&children[0]),
+            1056 => self.package_item_7(&children[0]),
+            1057 => self.alias_declaration(
                 &children[0],
                 &children[1],
                 &children[2],
@@ -41210,25 +42686,25 @@ impl<'t> UserActionsTrait<'t> for VerylGrammarAuto<'t, '_> {
                 &children[4],
                 &children[5],
             ),
-            1019 => self.alias_declaration_group_0(&children[0]),
-            1020 => self.alias_declaration_group_1(&children[0]),
-            1021 => self.alias_declaration_group_2(&children[0]),
-            1022 => self.proto_declaration(&children[0], &children[1]),
-            1023 => self.proto_declaration_group_0(&children[0]),
-            1024 => self.proto_declaration_group_1(&children[0]),
-            1025 => self.proto_declaration_group_2(&children[0]),
-            1026 => self.proto_module_declaration(
+            1058 => self.alias_declaration_group_0(&children[0]),
+            1059 => self.alias_declaration_group_1(&children[0]),
+            1060 => self.alias_declaration_group_2(&children[0]),
+            1061 => self.proto_declaration(&children[0], &children[1]),
+            1062 => self.proto_declaration_group_0(&children[0]),
+            1063 => self.proto_declaration_group_1(&children[0]),
+            1064 => self.proto_declaration_group_2(&children[0]),
+            1065 => self.proto_module_declaration(
                 &children[0],
                 &children[1],
                 &children[2],
                 &children[3],
                 &children[4],
             ),
-            1027 => self.proto_module_declaration_opt0_0(&children[0]),
-            1028 => self.proto_module_declaration_opt0_1(),
-            1029 => self.proto_module_declaration_opt_0(&children[0]),
-            1030 => self.proto_module_declaration_opt_1(),
-            1031 => self.proto_interface_declaration(
+            1066 => self.proto_module_declaration_opt0_0(&children[0]),
+            1067 => self.proto_module_declaration_opt0_1(),
+            1068 => self.proto_module_declaration_opt_0(&children[0]),
+            1069 => self.proto_module_declaration_opt_1(),
+            1070 => self.proto_interface_declaration(
                 &children[0],
                 &children[1],
                 &children[2],
@@ -41236,51 +42712,51 @@ impl<'t> UserActionsTrait<'t> for VerylGrammarAuto<'t, '_> {
                 &children[4],
                 &children[5],
             ),
-            1032 => self.proto_interface_declaration_list_0(&children[0], &children[1]),
-            1033 => self.proto_interface_declaration_list_1(),
-            1034 => self.proto_interface_declaration_opt_0(&children[0]),
-            1035 => self.proto_interface_declaration_opt_1(),
-            1036 => self.proto_interface_item_0(&children[0]),
-            1037 => self.proto_interface_item_1(&children[0]),
-            1038 => self.proto_interface_item_2(&children[0]),
-            1039 => self.proto_interface_item_3(&children[0]),
-            1040 => self.proto_interface_item_4(&children[0]),
-            1041 => self.proto_interface_item_5(&children[0]),
-            1042 => self.proto_interface_item_6(&children[0]),
-            1043 => self.proto_package_declaration(
+            1071 => self.proto_interface_declaration_list_0(&children[0], &children[1]),
+            1072 => self.proto_interface_declaration_list_1(),
+            1073 => self.proto_interface_declaration_opt_0(&children[0]),
+            1074 => self.proto_interface_declaration_opt_1(),
+            1075 => self.proto_interface_item_0(&children[0]),
+            1076 => self.proto_interface_item_1(&children[0]),
+            1077 => self.proto_interface_item_2(&children[0]),
+            1078 => self.proto_interface_item_3(&children[0]),
+            1079 => self.proto_interface_item_4(&children[0]),
+            1080 => self.proto_interface_item_5(&children[0]),
+            1081 => self.proto_interface_item_6(&children[0]),
+            1082 => self.proto_package_declaration(
                 &children[0],
                 &children[1],
                 &children[2],
                 &children[3],
                 &children[4],
             ),
-            1044 => self.proto_package_declaration_list_0(&children[0], &children[1]),
-            1045 => self.proto_package_declaration_list_1(),
-            1046 => self.proto_pacakge_item_0(&children[0]),
-            1047 => self.proto_pacakge_item_1(&children[0]),
-            1048 => self.proto_pacakge_item_2(&children[0]),
-            1049 => self.proto_pacakge_item_3(&children[0]),
-            1050 => self.proto_pacakge_item_4(&children[0]),
-            1051 => self.proto_pacakge_item_5(&children[0]),
-            1052 => self.proto_pacakge_item_6(&children[0]),
-            1053 => self.proto_const_declaration(
+            1083 => self.proto_package_declaration_list_0(&children[0], &children[1]),
+            1084 => self.proto_package_declaration_list_1(),
+            1085 => self.proto_pacakge_item_0(&children[0]),
+            1086 => self.proto_pacakge_item_1(&children[0]),
+            1087 => self.proto_pacakge_item_2(&children[0]),
+            1088 => self.proto_pacakge_item_3(&children[0]),
+            1089 => self.proto_pacakge_item_4(&children[0]),
+            1090 => self.proto_pacakge_item_5(&children[0]),
+            1091 => self.proto_pacakge_item_6(&children[0]),
+            1092 => self.proto_const_declaration(
                 &children[0],
                 &children[1],
                 &children[2],
                 &children[3],
                 &children[4],
             ),
-            1054 => self.proto_const_declaration_group_0(&children[0]),
-            1055 => self.proto_const_declaration_group_1(&children[0]),
-            1056 => self.proto_type_def_declaration(
+            1093 => self.proto_const_declaration_group_0(&children[0]),
+            1094 => self.proto_const_declaration_group_1(&children[0]),
+            1095 => self.proto_type_def_declaration(
                 &children[0],
                 &children[1],
                 &children[2],
                 &children[3],
             ),
-            1057 => self.proto_type_def_declaration_opt_0(&children[0], &children[1]),
-            1058 => self.proto_type_def_declaration_opt_1(),
-            1059 => self.proto_function_declaration(
+            1096 => self.proto_type_def_declaration_opt_0(&children[0], &children[1]),
+            1097 => self.proto_type_def_declaration_opt_1(),
+            1098 => self.proto_function_declaration(
                 &children[0],
                 &children[1],
                 &children[2],
@@ -41288,13 +42764,13 @@ impl<'t> UserActionsTrait<'t> for VerylGrammarAuto<'t, '_> {
                 &children[4],
                 &children[5],
             ),
-            1060 => self.proto_function_declaration_opt1_0(&children[0], &children[1]),
-            1061 => self.proto_function_declaration_opt1_1(),
-            1062 => self.proto_function_declaration_opt0_0(&children[0]),
-            1063 => self.proto_function_declaration_opt0_1(),
-            1064 => self.proto_function_declaration_opt_0(&children[0]),
-            1065 => self.proto_function_declaration_opt_1(),
-            1066 => self.proto_alias_declaration(
+            1099 => self.proto_function_declaration_opt1_0(&children[0], &children[1]),
+            1100 => self.proto_function_declaration_opt1_1(),
+            1101 => self.proto_function_declaration_opt0_0(&children[0]),
+            1102 => self.proto_function_declaration_opt0_1(),
+            1103 => self.proto_function_declaration_opt_0(&children[0]),
+            1104 => self.proto_function_declaration_opt_1(),
+            1105 => self.proto_alias_declaration(
                 &children[0],
                 &children[1],
                 &children[2],
@@ -41302,10 +42778,10 @@ impl<'t> UserActionsTrait<'t> for VerylGrammarAuto<'t, '_> {
                 &children[4],
                 &children[5],
             ),
-            1067 => self.proto_alias_declaration_group_0(&children[0]),
-            1068 => self.proto_alias_declaration_group_1(&children[0]),
-            1069 => self.proto_alias_declaration_group_2(&children[0]),
-            1070 => self.embed_declaration(
+            1106 => self.proto_alias_declaration_group_0(&children[0]),
+            1107 => self.proto_alias_declaration_group_1(&children[0]),
+            1108 => self.proto_alias_declaration_group_2(&children[0]),
+            1109 => self.embed_declaration(
                 &children[0],
                 &children[1],
                 &children[2],
@@ -41313,8 +42789,8 @@ impl<'t> UserActionsTrait<'t> for VerylGrammarAuto<'t, '_> {
                 &children[4],
                 &children[5],
             ),
-            1071 => self.embed_content(&children[0]),
-            1072 => self.embed_content_token(
+            1110 => self.embed_content(&children[0]),
+            1111 => self.embed_content_token(
                 &children[0],
                 &children[1],
                 &children[2],
@@ -41324,13 +42800,13 @@ impl<'t> UserActionsTrait<'t> for VerylGrammarAuto<'t, '_> {
                 &children[6],
                 &children[7],
             ),
-            1073 => self.embed_content_token_list_0(&children[0], &children[1]),
-            1074 => self.embed_content_token_list_1(),
-            1075 => self.embed_item_0(&children[0], &children[1], &children[2]),
-            1076 => self.embed_item_list_0(&children[0], &children[1]),
-            1077 => self.embed_item_list_1(),
-            1078 => self.embed_item_1(&children[0]),
-            1079 => self.include_declaration(
+            1112 => self.embed_content_token_list_0(&children[0], &children[1]),
+            1113 => self.embed_content_token_list_1(),
+            1114 => self.embed_item_0(&children[0], &children[1], &children[2]),
+            1115 => self.embed_item_list_0(&children[0], &children[1]),
+            1116 => self.embed_item_list_1(),
+            1117 => self.embed_item_1(&children[0]),
+            1118 => self.include_declaration(
                 &children[0],
                 &children[1],
                 &children[2],
@@ -41339,27 +42815,27 @@ impl<'t> UserActionsTrait<'t> for VerylGrammarAuto<'t, '_> {
                 &children[5],
                 &children[6],
             ),
-            1080 => self.description_group(&children[0], &children[1]),
-            1081 => self.description_group_group_0(&children[0], &children[1], &children[2]),
-            1082 => self.description_group_group_list_0(&children[0], &children[1]),
-            1083 => self.description_group_group_list_1(),
-            1084 => self.description_group_group_1(&children[0]),
-            1085 => self.description_group_list_0(&children[0], &children[1]),
-            1086 => self.description_group_list_1(),
-            1087 => self.description_item_0(&children[0], &children[1]),
-            1088 => self.description_item_1(&children[0]),
-            1089 => self.description_item_2(&children[0]),
-            1090 => self.description_item_3(&children[0]),
-            1091 => self.description_item_opt_0(&children[0]),
-            1092 => self.description_item_opt_1(),
-            1093 => self.public_description_item_0(&children[0]),
-            1094 => self.public_description_item_1(&children[0]),
-            1095 => self.public_description_item_2(&children[0]),
-            1096 => self.public_description_item_3(&children[0]),
-            1097 => self.public_description_item_4(&children[0]),
-            1098 => self.veryl(&children[0], &children[1]),
-            1099 => self.veryl_list_0(&children[0], &children[1]),
-            1100 => self.veryl_list_1(),
+            1119 => self.description_group(&children[0], &children[1]),
+            1120 => self.description_group_group_0(&children[0], &children[1], &children[2]),
+            1121 => self.description_group_group_list_0(&children[0], &children[1]),
+            1122 => self.description_group_group_list_1(),
+            1123 => self.description_group_group_1(&children[0]),
+            1124 => self.description_group_list_0(&children[0], &children[1]),
+            1125 => self.description_group_list_1(),
+            1126 => self.description_item_0(&children[0], &children[1]),
+            1127 => self.description_item_1(&children[0]),
+            1128 => self.description_item_2(&children[0]),
+            1129 => self.description_item_3(&children[0]),
+            1130 => self.description_item_opt_0(&children[0]),
+            1131 => self.description_item_opt_1(),
+            1132 => self.public_description_item_0(&children[0]),
+            1133 => self.public_description_item_1(&children[0]),
+            1134 => self.public_description_item_2(&children[0]),
+            1135 => self.public_description_item_3(&children[0]),
+            1136 => self.public_description_item_4(&children[0]),
+            1137 => self.veryl(&children[0], &children[1]),
+            1138 => self.veryl_list_0(&children[0], &children[1]),
+            1139 => self.veryl_list_1(),
             _ => Err(ParserError::InternalError(format!(
                 "Unhandled production number: {}",
                 prod_num
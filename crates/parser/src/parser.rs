@@ -5,6 +5,7 @@ use crate::veryl_grammar::VerylGrammar;
 use crate::veryl_grammar_trait::Veryl;
 use crate::veryl_parser::parse;
 use anyhow::anyhow;
+use std::ops::Range;
 use std::path::Path;
 
 #[derive(Debug)]
@@ -29,4 +30,256 @@ impl Parser {
 
         Ok(Parser { veryl })
     }
+
+    /// Parses with error recovery.
+    ///
+    /// On each syntax error, the erroneous statement or item is removed by synchronizing at `;`, `}`
+    /// or item keywords, and parsing is retried.
+    /// This returns the partial tree of the remaining part and all syntax errors.
+    ///
+    /// Retries stop when the total size of reparsed text exceeds `RECOVERY_FACTOR` times of the input
+    /// (or `MIN_RECOVERY_BUDGET` for small inputs), so the cost doesn't grow with the number of errors.
+    pub fn parse_with_recovery<T: AsRef<Path>>(
+        input: &str,
+        file: &T,
+    ) -> (Option<Self>, Vec<ParserError>) {
+        let budget = input
+            .len()
+            .saturating_mul(RECOVERY_FACTOR)
+            .max(MIN_RECOVERY_BUDGET);
+        Self::parse_with_recovery_budget(input, file, budget)
+    }
+
+    pub(crate) fn parse_with_recovery_budget<T: AsRef<Path>>(
+        input: &str,
+        file: &T,
+        budget: usize,
+    ) -> (Option<Self>, Vec<ParserError>) {
+        let mut errors = Vec::new();
+        let mut text = input.to_string();
+        let mut parsed = 0;
+
+        loop {
+            parsed += text.len();
+            let mut err = match Self::parse(&text, file) {
+                Ok(x) => {
+                    Self::restore_text(input, file);
+                    return (Some(x), errors);
+                }
+                Err(x) => x,
+            };
+
+            let region = if let ParserError::SyntaxError(x) = &mut err {
+                // Show the original source instead of the blanked one
+                x.set_source(input);
+                recovery_region(&text, x.error_location.offset(), x.error_location.len())
+            } else {
+                None
+            };
+            errors.push(err);
+
+            match region {
+                Some(region) if errors.len() < MAX_RECOVERY && parsed + text.len() <= budget => {
+                    blank(&mut text, region)
+                }
+                _ => {
+                    Self::restore_text(input, file);
+                    return (None, errors);
+                }
+            }
+        }
+    }
+
+    fn restore_text<T: AsRef<Path>>(input: &str, file: &T) {
+        // Token positions are unchanged by blanking, so the original text can be used
+        let path = resource_table::insert_path(file.as_ref());
+        let text = TextInfo {
+            path,
+            text: input.to_string(),
+        };
+        text_table::set_current_text(text);
+    }
+}
+
+const MAX_RECOVERY: usize = 100;
+
+/// Upper bound of the total text size parsed by `parse_with_recovery` relative to the input size
+const RECOVERY_FACTOR: usize = 16;
+
+/// Lower bound of the recovery budget not to limit the number of errors in small files
+const MIN_RECOVERY_BUDGET: usize = 1024 * 1024;
+
+const SYNC_KEYWORDS: &[&str] = &[
+    "alias",
+    "always_comb",
+    "always_ff",
+    "assert",
+    "assign",
+    "assume",
+    "connect",
+    "const",
+    "cover",
+    "embed",
+    "enum",
+    "final",
+    "function",
+    "import",
+    "include",
+    "initial",
+    "inst",
+    "interface",
+    "let",
+    "modport",
+    "module",
+    "package",
+    "param",
+    "proto",
+    "pub",
+    "struct",
+    "type",
+    "union",
+    "var",
+];
+
+#[derive(Clone, Copy, PartialEq)]
+enum SyncPoint {
+    Semicolon,
+    LBrace,
+    RBrace,
+    Keyword,
+}
+
+/// Collects synchronization points except inside comments and string literals
+fn sync_points(text: &str) -> Vec<(usize, SyncPoint)> {
+    let bytes = text.as_bytes();
+    let is_ident = |x: u8| x.is_ascii_alphanumeric() || x == b'_' || x == b'$';
+
+    let mut ret = Vec::new();
+    let mut i = 0;
+    while i < bytes.len() {
+        match bytes[i] {
+            b'/' if bytes.get(i + 1) == Some(&b'/') => {
+                while i < bytes.len() && bytes[i] != b'\n' {
+                    i += 1;
+                }
+            }
+            b'/' if bytes.get(i + 1) == Some(&b'*') => {
+                i += 2;
+                while i < bytes.len() && !(bytes[i] == b'*' && bytes.get(i + 1) == Some(&b'/')) {
+                    i += 1;
+                }
+                i += 2;
+            }
+            b'"' => {
+                i += 1;
+                while i < bytes.len() && bytes[i] != b'"' {
+                    if bytes[i] == b'\\' {
+                        i += 1;
+                    }
+                    i += 1;
+                }
+                i += 1;
+            }
+            b';' => {
+                ret.push((i, SyncPoint::Semicolon));
+                i += 1;
+            }
+            b'{' => {
+                ret.push((i, SyncPoint::LBrace));
+                i += 1;
+            }
+            b'}' => {
+                ret.push((i, SyncPoint::RBrace));
+                i += 1;
+            }
+            x if is_ident(x) => {
+                let beg = i;
+                while i < bytes.len() && is_ident(bytes[i]) {
+                    i += 1;
+                }
+                if SYNC_KEYWORDS.contains(&&text[beg..i]) {
+                    ret.push((beg, SyncPoint::Keyword));
+                }
+            }
+            _ => i += 1,
+        }
+    }
+    ret
+}
+
+/// Determines the region which should be removed to recover from the error at `offset`
+fn recovery_region(text: &str, offset: usize, len: usize) -> Option<Range<usize>> {
+    let points = sync_points(text);
+
+    let beg = points
+        .iter()
+        .rev()
+        .find(|(x, _)| *x < offset)
+        .map(|(x, kind)| {
+            if *kind == SyncPoint::Keyword {
+                *x
+            } else {
+                x + 1
+            }
+        })
+        .unwrap_or(0);
+
+    let end = points
+        .iter()
+        .find(|(x, kind)| match kind {
+            SyncPoint::Semicolon => *x >= offset,
+            _ => *x > offset,
+        })
+        .map(|(x, kind)| {
+            if *kind == SyncPoint::Semicolon {
+                x + 1
+            } else {
+                *x
+            }
+        })
+        .unwrap_or(text.len());
+
+    let is_blank = |x: &Range<usize>| text.get(x.clone()).is_none_or(|x| x.trim().is_empty());
+
+    let region = beg..end;
+    if !is_blank(&region) {
+        return Some(region);
+    }
+
+    // Remove the unexpected token itself
+    let region = offset..(offset + len.max(1)).min(text.len());
+    if !region.is_empty() && !is_blank(&region) {
+        return Some(region);
+    }
+
+    // Unexpected end of input: remove the last incomplete top-level item
+    let mut depth = 0;
+    let mut beg = None;
+    for (x, kind) in points.iter().filter(|(x, _)| *x < offset) {
+        match kind {
+            SyncPoint::LBrace => depth += 1,
+            SyncPoint::RBrace => depth -= 1,
+            SyncPoint::Keyword if depth <= 0 => beg = Some(*x),
+            _ => (),
+        }
+    }
+    Some(beg?..text.len())
+}
+
+/// Replaces the region by whitespaces with keeping byte offsets and line breaks
+fn blank(text: &mut String, region: Range<usize>) {
+    let Some(target) = text.get(region.clone()) else {
+        return;
+    };
+    let blanked: String = target
+        .chars()
+        .map(|x| {
+            if x == '\n' || x == '\r' {
+                x.to_string()
+            } else {
+                " ".repeat(x.len_utf8())
+            }
+        })
+        .collect();
+    text.replace_range(region, &blanked);
 }
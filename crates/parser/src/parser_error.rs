@@ -51,6 +51,18 @@ impl std::fmt::Display for SyntaxError {
     }
 }
 
+impl SyntaxError {
+    /// Replaces the source code shown by diagnostics
+    pub(crate) fn set_source(&mut self, input: &str) {
+        let file_name = self.input.inner().0.file_name.clone();
+        let file_source = FileSource(parol_runtime::FileSource {
+            file_name,
+            input: input.to_string(),
+        });
+        self.input = file_source.into();
+    }
+}
+
 impl From<parol_runtime::SyntaxError> for SyntaxError {
     fn from(value: parol_runtime::SyntaxError) -> Self {
         Self {
@@ -86,6 +98,7 @@ pub enum TokenType {
     MinusGT,
     PlusColon,
     AssignmentOperator,
+    Implication,
     Operator11,
     Operator10,
     Operator09,
@@ -119,12 +132,15 @@ pub enum TokenType {
     Star,
     AlwaysComb,
     AlwaysFf,
+    Assert,
     Assign,
+    Assume,
     AsyncHigh,
     AsyncLow,
     As,
     Bit,
     Case,
+    Cover,
     Default,
     Else,
     Enum,
@@ -193,6 +209,7 @@ impl From<&str> for TokenType {
             "MinusGTTerm" => TokenType::MinusGT,
             "PlusColonTerm" => TokenType::PlusColon,
             "AssignmentOperatorTerm" => TokenType::AssignmentOperator,
+            "ImplicationTerm" => TokenType::Implication,
             "Operator11Term" => TokenType::Operator11,
             "Operator10Term" => TokenType::Operator10,
             "Operator09Term" => TokenType::Operator09,
@@ -226,12 +243,15 @@ impl From<&str> for TokenType {
             "StarTerm" => TokenType::Star,
             "AlwaysCombTerm" => TokenType::AlwaysComb,
             "AlwaysFfTerm" => TokenType::AlwaysFf,
+            "AssertTerm" => TokenType::Assert,
             "AssignTerm" => TokenType::Assign,
+            "AssumeTerm" => TokenType::Assume,
             "AsyncHighTerm" => TokenType::AsyncHigh,
             "AsyncLowTerm" => TokenType::AsyncLow,
             "AsTerm" => TokenType::As,
             "BitTerm" => TokenType::Bit,
             "CaseTerm" => TokenType::Case,
+            "CoverTerm" => TokenType::Cover,
             "DefaultTerm" => TokenType::Default,
             "ElseTerm" => TokenType::Else,
             "EnumTerm" => TokenType::Enum,
@@ -302,6 +322,7 @@ impl std::fmt::Display for TokenType {
             TokenType::MinusGT => "->",
             TokenType::PlusColon => "+:",
             TokenType::AssignmentOperator => "assignment operator",
+            TokenType::Implication => "implication operator",
             TokenType::Operator11 => "operator",
             TokenType::Operator10 => "operator",
             TokenType::Operator09 => "operator",
@@ -335,12 +356,15 @@ impl std::fmt::Display for TokenType {
             TokenType::Star => "*",
             TokenType::AlwaysComb => "always_comb",
             TokenType::AlwaysFf => "always_ff",
+            TokenType::Assert => "assert",
             TokenType::Assign => "assign",
+            TokenType::Assume => "assume",
             TokenType::AsyncHigh => "async_high",
             TokenType::AsyncLow => "async_low",
             TokenType::As => "as",
             TokenType::Bit => "bit",
             TokenType::Case => "case",
+            TokenType::Cover => "cover",
             TokenType::Default => "default",
             TokenType::Else => "else",
             TokenType::Enum => "enum",
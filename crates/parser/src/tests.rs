@@ -150,3 +150,51 @@ fn assignment_statement() {
     success("always_comb { a <<<= 1; }");
     success("always_comb { a >>>= 1; }");
 }
+
+#[test]
+fn error_recovery() {
+    let code = r#"module A {
+    var a: logic
+    var b: logic;
+    assign b = ;
+    always_comb {
+        a = 1;
+    }
+}
+module B {}
+"#;
+    let (parser, errors) = Parser::parse_with_recovery(code, &"");
+    assert_eq!(errors.len(), 2);
+    let parser = parser.unwrap();
+    assert_eq!(parser.veryl.veryl_list.len(), 2);
+
+    let code = "module A { var a: logic; ";
+    let (parser, errors) = Parser::parse_with_recovery(code, &"");
+    assert_eq!(errors.len(), 1);
+    assert!(parser.unwrap().veryl.veryl_list.is_empty());
+
+    let code = "module A { var a: logic; }";
+    let (parser, errors) = Parser::parse_with_recovery(code, &"");
+    assert!(errors.is_empty());
+    assert_eq!(parser.unwrap().veryl.veryl_list.len(), 1);
+
+    // Recovery stops when the reparsed text exceeds the budget
+    let code = "module A { var a: logic }\n".repeat(10);
+    let (parser, errors) = Parser::parse_with_recovery_budget(&code, &"", code.len() * 4);
+    assert_eq!(errors.len(), 4);
+    assert!(parser.is_none());
+
+    // Large inputs are recovered more than once
+    let mut code = format!("// {}\n", "x".repeat(1024)).repeat(512);
+    code.push_str(
+        r#"module A {
+    var a: logic
+    var b: logic;
+    assign b = ;
+}
+"#,
+    );
+    let (parser, errors) = Parser::parse_with_recovery(&code, &"");
+    assert_eq!(errors.len(), 2);
+    assert_eq!(parser.unwrap().veryl.veryl_list.len(), 1);
+}
@@ -269,6 +269,7 @@ impl_token_range_singular!(AllBit);
 
 // Operator
 impl_token_range_singular!(AssignmentOperator);
+impl_token_range_singular!(Implication);
 impl_token_range_singular!(DiamondOperator);
 impl_token_range_singular!(Operator02);
 impl_token_range_singular!(Operator03);
@@ -315,7 +316,9 @@ impl_token_range_singular!(Alias);
 impl_token_range_singular!(AlwaysComb);
 impl_token_range_singular!(AlwaysFf);
 impl_token_range_singular!(As);
+impl_token_range_singular!(Assert);
 impl_token_range_singular!(Assign);
+impl_token_range_singular!(Assume);
 impl_token_range_singular!(Bit);
 impl_token_range_singular!(Bool);
 impl_token_range_singular!(Break);
@@ -326,6 +329,7 @@ impl_token_range_singular!(ClockNegedge);
 impl_token_range_singular!(Connect);
 impl_token_range_singular!(Const);
 impl_token_range_singular!(Converse);
+impl_token_range_singular!(Cover);
 
 impl From<&Defaul> for TokenRange {
     fn from(value: &Defaul) -> Self {
@@ -823,7 +827,8 @@ impl_token_range_enum!(
     break_statement,
     for_statement,
     case_statement,
-    switch_statement
+    switch_statement,
+    assertion_statement
 );
 impl_token_range!(LetStatement, r#let, semicolon);
 impl_token_range!(IdentifierStatement, expression_identifier, semicolon);
@@ -938,13 +943,33 @@ impl From<&SwitchCondition> for TokenRange {
 }
 impl_token_ext!(SwitchCondition);
 
+impl_token_range!(AssertionStatement, assertion_keyword, semicolon);
+impl_token_range_enum!(AssertionKeyword, assert, assume, cover);
+
 // ----------------------------------------------------------------------------
 // Attribute
 // ----------------------------------------------------------------------------
 
 impl_token_range!(Attribute, hash, r_bracket);
 impl_token_range_list!(AttributeList, AttributeItem);
-impl_token_range_enum!(AttributeItem, identifier, string_literal);
+impl_token_range_enum!(
+    AttributeItem,
+    identifier,
+    string_literal,
+    attribute_assignment,
+    attribute_call
+);
+impl_token_range!(AttributeAssignment, identifier, attribute_value);
+impl_token_range!(AttributeCall, identifier, r_paren);
+impl_token_range_enum!(
+    AttributeValue,
+    integral_number,
+    string_literal,
+    identifier,
+    attribute_array
+);
+impl_token_range!(AttributeArray, l_bracket, r_bracket);
+impl_token_range_list!(AttributeValueList, AttributeValue);
 
 // ----------------------------------------------------------------------------
 // Declaration
@@ -959,6 +984,7 @@ impl_token_range!(AlwaysFfEventList, l_paren, r_paren);
 impl_token_range!(AlwaysFfClock, hierarchical_identifier);
 impl_token_range!(AlwaysFfReset, hierarchical_identifier);
 impl_token_range!(AlwaysCombDeclaration, always_comb, statement_block);
+impl_token_range!(AssertionDeclaration, assertion_keyword, r_brace);
 impl_token_range!(AssignDeclaration, assign, semicolon);
 
 impl From<&AssignDestination> for TokenRange {
@@ -1264,6 +1290,7 @@ impl_token_range_enum!(
     always_ff_declaration,
     always_comb_declaration,
     assign_declaration,
+    assertion_declaration,
     connect_declaration,
     function_declaration,
     generate_if_declaration,
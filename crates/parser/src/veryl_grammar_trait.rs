@@ -616,3 +616,37 @@ impl AlwaysFfDeclaration {
         None
     }
 }
+
+impl AssertionDeclaration {
+    pub fn has_explicit_clock(&self) -> bool {
+        self.assertion_declaration_opt0.is_some()
+    }
+
+    pub fn get_explicit_clock(&self) -> Option<HierarchicalIdentifier> {
+        self.assertion_declaration_opt0.as_ref().map(|x| {
+            x.always_ff_event_list
+                .always_ff_clock
+                .hierarchical_identifier
+                .as_ref()
+                .clone()
+        })
+    }
+
+    pub fn has_explicit_reset(&self) -> bool {
+        if let Some(x) = &self.assertion_declaration_opt0 {
+            return x.always_ff_event_list.always_ff_event_list_opt.is_some();
+        }
+
+        false
+    }
+
+    pub fn get_explicit_reset(&self) -> Option<HierarchicalIdentifier> {
+        if let Some(x) = &self.assertion_declaration_opt0
+            && let Some(x) = &x.always_ff_event_list.always_ff_event_list_opt
+        {
+            return Some(x.always_ff_reset.hierarchical_identifier.as_ref().clone());
+        }
+
+        None
+    }
+}
@@ -371,6 +371,7 @@ token_with_comments!(Semicolon);
 token_with_comments!(Star);
 
 token_with_comments!(AssignmentOperator);
+token_with_comments!(Implication);
 token_with_comments!(DiamondOperator);
 token_with_comments!(Operator02);
 token_with_comments!(Operator03);
@@ -389,7 +390,9 @@ token_with_comments!(Alias);
 token_with_comments!(AlwaysComb);
 token_with_comments!(AlwaysFf);
 token_with_comments!(As);
+token_with_comments!(Assert);
 token_with_comments!(Assign);
+token_with_comments!(Assume);
 token_with_comments!(Bit);
 token_with_comments!(Bool);
 token_with_comments!(Break);
@@ -400,6 +403,7 @@ token_with_comments!(ClockNegedge);
 token_with_comments!(Connect);
 token_with_comments!(Const);
 token_with_comments!(Converse);
+token_with_comments!(Cover);
 token_with_comments!(Default);
 token_with_comments!(Else);
 token_with_comments!(Embed);
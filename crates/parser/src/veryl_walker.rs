@@ -87,6 +87,13 @@ pub trait VerylWalker {
         after!(self, assignment_operator, arg);
     }
 
+    /// Semantic action for non-terminal 'Implication'
+    fn implication(&mut self, arg: &Implication) {
+        before!(self, implication, arg);
+        self.veryl_token(&arg.implication_token);
+        after!(self, implication, arg);
+    }
+
     /// Semantic action for non-terminal 'DiamondOperator'
     fn diamond_operator(&mut self, arg: &DiamondOperator) {
         before!(self, diamond_operator, arg);
@@ -381,6 +388,13 @@ pub trait VerylWalker {
         after!(self, r#as, arg);
     }
 
+    /// Semantic action for non-terminal 'Assert'
+    fn assert(&mut self, arg: &Assert) {
+        before!(self, assert, arg);
+        self.veryl_token(&arg.assert_token);
+        after!(self, assert, arg);
+    }
+
     /// Semantic action for non-terminal 'Assign'
     fn assign(&mut self, arg: &Assign) {
         before!(self, assign, arg);
@@ -388,6 +402,13 @@ pub trait VerylWalker {
         after!(self, assign, arg);
     }
 
+    /// Semantic action for non-terminal 'Assume'
+    fn assume(&mut self, arg: &Assume) {
+        before!(self, assume, arg);
+        self.veryl_token(&arg.assume_token);
+        after!(self, assume, arg);
+    }
+
     /// Semantic action for non-terminal 'Bit'
     fn bit(&mut self, arg: &Bit) {
         before!(self, bit, arg);
@@ -451,6 +472,13 @@ pub trait VerylWalker {
         after!(self, converse, arg);
     }
 
+    /// Semantic action for non-terminal 'Cover'
+    fn cover(&mut self, arg: &Cover) {
+        before!(self, cover, arg);
+        self.veryl_token(&arg.cover_token);
+        after!(self, cover, arg);
+    }
+
     /// Semantic action for non-terminal 'Defaul'
     fn defaul(&mut self, arg: &Defaul) {
         before!(self, defaul, arg);
@@ -1756,6 +1784,7 @@ pub trait VerylWalker {
             Statement::ForStatement(x) => self.for_statement(&x.for_statement),
             Statement::CaseStatement(x) => self.case_statement(&x.case_statement),
             Statement::SwitchStatement(x) => self.switch_statement(&x.switch_statement),
+            Statement::AssertionStatement(x) => self.assertion_statement(&x.assertion_statement),
         };
         after!(self, statement, arg);
     }
@@ -1957,6 +1986,26 @@ pub trait VerylWalker {
         after!(self, switch_condition, arg);
     }
 
+    /// Semantic action for non-terminal 'AssertionStatement'
+    fn assertion_statement(&mut self, arg: &AssertionStatement) {
+        before!(self, assertion_statement, arg);
+        self.assertion_keyword(&arg.assertion_keyword);
+        self.expression(&arg.expression);
+        self.semicolon(&arg.semicolon);
+        after!(self, assertion_statement, arg);
+    }
+
+    /// Semantic action for non-terminal 'AssertionKeyword'
+    fn assertion_keyword(&mut self, arg: &AssertionKeyword) {
+        before!(self, assertion_keyword, arg);
+        match arg {
+            AssertionKeyword::Assert(x) => self.assert(&x.assert),
+            AssertionKeyword::Assume(x) => self.assume(&x.assume),
+            AssertionKeyword::Cover(x) => self.cover(&x.cover),
+        };
+        after!(self, assertion_keyword, arg);
+    }
+
     /// Semantic action for non-terminal 'Attribute'
     fn attribute(&mut self, arg: &Attribute) {
         before!(self, attribute, arg);
@@ -1992,10 +2041,68 @@ pub trait VerylWalker {
         match arg {
             AttributeItem::Identifier(x) => self.identifier(&x.identifier),
             AttributeItem::StringLiteral(x) => self.string_literal(&x.string_literal),
+            AttributeItem::AttributeAssignment(x) => {
+                self.attribute_assignment(&x.attribute_assignment)
+            }
+            AttributeItem::AttributeCall(x) => self.attribute_call(&x.attribute_call),
         }
         after!(self, attribute_item, arg);
     }
 
+    /// Semantic action for non-terminal 'AttributeAssignment'
+    fn attribute_assignment(&mut self, arg: &AttributeAssignment) {
+        before!(self, attribute_assignment, arg);
+        self.identifier(&arg.identifier);
+        self.equ(&arg.equ);
+        self.attribute_value(&arg.attribute_value);
+        after!(self, attribute_assignment, arg);
+    }
+
+    /// Semantic action for non-terminal 'AttributeCall'
+    fn attribute_call(&mut self, arg: &AttributeCall) {
+        before!(self, attribute_call, arg);
+        self.identifier(&arg.identifier);
+        self.l_paren(&arg.l_paren);
+        self.attribute_list(&arg.attribute_list);
+        self.r_paren(&arg.r_paren);
+        after!(self, attribute_call, arg);
+    }
+
+    /// Semantic action for non-terminal 'AttributeValue'
+    fn attribute_value(&mut self, arg: &AttributeValue) {
+        before!(self, attribute_value, arg);
+        match arg {
+            AttributeValue::IntegralNumber(x) => self.integral_number(&x.integral_number),
+            AttributeValue::StringLiteral(x) => self.string_literal(&x.string_literal),
+            AttributeValue::Identifier(x) => self.identifier(&x.identifier),
+            AttributeValue::AttributeArray(x) => self.attribute_array(&x.attribute_array),
+        }
+        after!(self, attribute_value, arg);
+    }
+
+    /// Semantic action for non-terminal 'AttributeArray'
+    fn attribute_array(&mut self, arg: &AttributeArray) {
+        before!(self, attribute_array, arg);
+        self.l_bracket(&arg.l_bracket);
+        self.attribute_value_list(&arg.attribute_value_list);
+        self.r_bracket(&arg.r_bracket);
+        after!(self, attribute_array, arg);
+    }
+
+    /// Semantic action for non-terminal 'AttributeValueList'
+    fn attribute_value_list(&mut self, arg: &AttributeValueList) {
+        before!(self, attribute_value_list, arg);
+        self.attribute_value(&arg.attribute_value);
+        for x in &arg.attribute_value_list_list {
+            self.comma(&x.comma);
+            self.attribute_value(&x.attribute_value);
+        }
+        if let Some(ref x) = arg.attribute_value_list_opt {
+            self.comma(&x.comma);
+        }
+        after!(self, attribute_value_list, arg);
+    }
+
     /// Semantic action for non-terminal 'LetDeclaration'
     fn let_declaration(&mut self, arg: &LetDeclaration) {
         before!(self, let_declaration, arg);
@@ -2103,6 +2210,26 @@ pub trait VerylWalker {
         after!(self, always_comb_declaration, arg);
     }
 
+    /// Semantic action for non-terminal 'AssertionDeclaration'
+    fn assertion_declaration(&mut self, arg: &AssertionDeclaration) {
+        before!(self, assertion_declaration, arg);
+        self.assertion_keyword(&arg.assertion_keyword);
+        if let Some(ref x) = arg.assertion_declaration_opt {
+            self.identifier(&x.identifier);
+        }
+        if let Some(ref x) = arg.assertion_declaration_opt0 {
+            self.always_ff_event_list(&x.always_ff_event_list);
+        }
+        self.l_brace(&arg.l_brace);
+        self.expression(&arg.expression);
+        if let Some(ref x) = arg.assertion_declaration_opt1 {
+            self.implication(&x.implication);
+            self.expression(&x.expression);
+        }
+        self.r_brace(&arg.r_brace);
+        after!(self, assertion_declaration, arg);
+    }
+
     /// Semantic action for non-terminal 'AssignDeclaration'
     fn assign_declaration(&mut self, arg: &AssignDeclaration) {
         before!(self, assign_declaration, arg);
@@ -3051,6 +3178,9 @@ pub trait VerylWalker {
                 self.always_comb_declaration(&x.always_comb_declaration)
             }
             GenerateItem::AssignDeclaration(x) => self.assign_declaration(&x.assign_declaration),
+            GenerateItem::AssertionDeclaration(x) => {
+                self.assertion_declaration(&x.assertion_declaration)
+            }
             GenerateItem::ConnectDeclaration(x) => self.connect_declaration(&x.connect_declaration),
             GenerateItem::FunctionDeclaration(x) => {
                 self.function_declaration(&x.function_declaration)
@@ -1,4 +1,6 @@
 mod sourcemap;
 mod sourcemap_error;
-pub use sourcemap::SourceMap;
+pub use sourcemap::{SourceMap, SourceMapRange};
 pub use sourcemap_error::SourceMapError;
+#[cfg(test)]
+mod tests;
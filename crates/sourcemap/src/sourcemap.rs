@@ -2,9 +2,21 @@ use crate::SourceMapError;
 use relative_path::PathExt;
 use sourcemap::SourceMapBuilder;
 use std::fs;
+use std::ops::Range;
 use std::path::{Path, PathBuf};
 
 const LINK_HEADER: &str = "//# sourceMappingURL=";
+const VHDL_LINK_HEADER: &str = "--# sourceMappingURL=";
+
+/// Range of the generated code corresponding to a source position.
+/// Line and column are 1-based, and the end is exclusive.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct SourceMapRange {
+    pub start_line: u32,
+    pub start_column: u32,
+    pub end_line: u32,
+    pub end_column: u32,
+}
 
 pub struct SourceMap {
     pub src_path: PathBuf,
@@ -47,34 +59,38 @@ impl SourceMap {
         let src = fs::read_to_string(src_path)?;
 
         if let Some(line) = src.lines().last() {
-            if line.starts_with(LINK_HEADER) {
-                let map_path = line.strip_prefix(LINK_HEADER).unwrap();
+            if let Some(map_path) = line
+                .strip_prefix(LINK_HEADER)
+                .or_else(|| line.strip_prefix(VHDL_LINK_HEADER))
+            {
                 let map_path = src_path.parent().unwrap().join(map_path);
-                let text = fs::read(&map_path)?;
-
-                let src_path = src_path.to_path_buf();
-                let dst_path = PathBuf::new();
-                let src_path_from_map = String::new();
-                let map_path_from_dst = String::new();
-                let builder =
-                    SourceMapBuilder::new(Some(&map_path.file_name().unwrap().to_string_lossy()));
-                let source_map = Some(sourcemap::SourceMap::from_reader(text.as_slice())?);
-
-                return Ok(Self {
-                    src_path,
-                    dst_path,
-                    map_path,
-                    src_path_from_map,
-                    map_path_from_dst,
-                    builder,
-                    source_map,
-                });
+                let mut ret = Self::from_map(&map_path)?;
+                ret.src_path = src_path.to_path_buf();
+                return Ok(ret);
             }
         }
 
         Err(SourceMapError::NotFound)
     }
 
+    pub fn from_map(map_path: &Path) -> Result<Self, SourceMapError> {
+        let text = fs::read(map_path)?;
+
+        let map_path = map_path.to_path_buf();
+        let builder = SourceMapBuilder::new(Some(&map_path.file_name().unwrap().to_string_lossy()));
+        let source_map = Some(sourcemap::SourceMap::from_reader(text.as_slice())?);
+
+        Ok(Self {
+            src_path: PathBuf::new(),
+            dst_path: PathBuf::new(),
+            map_path,
+            src_path_from_map: String::new(),
+            map_path_from_dst: String::new(),
+            builder,
+            source_map,
+        })
+    }
+
     pub fn add(
         &mut self,
         dst_line: u32,
@@ -112,10 +128,42 @@ impl SourceMap {
         self.source_map = Some(builder.into_sourcemap());
     }
 
+    /// Returns the source map of the generated lines moved into another file.
+    /// `lines` is 1-based and the end is exclusive, and the first of them is moved to `dst_line`.
+    pub fn split(
+        &self,
+        dst_path: &Path,
+        map_path: &Path,
+        lines: Range<u32>,
+        dst_line: u32,
+    ) -> SourceMap {
+        let mut ret = SourceMap::new(&self.src_path, dst_path, map_path);
+        if let Some(ref x) = self.source_map {
+            for token in x.tokens() {
+                let line = token.get_dst_line() + 1;
+                if lines.contains(&line) {
+                    ret.add(
+                        line - lines.start + dst_line,
+                        token.get_dst_col() + 1,
+                        token.get_src_line() + 1,
+                        token.get_src_col() + 1,
+                        token.get_name().unwrap_or(""),
+                    );
+                }
+            }
+        }
+        ret.build();
+        ret
+    }
+
     pub fn get_link(&self) -> String {
         format!("{}{}", LINK_HEADER, self.map_path_from_dst)
     }
 
+    pub fn get_vhdl_link(&self) -> String {
+        format!("{}{}", VHDL_LINK_HEADER, self.map_path_from_dst)
+    }
+
     pub fn to_bytes(&self) -> Result<Vec<u8>, SourceMapError> {
         if let Some(ref x) = self.source_map {
             let mut ret = Vec::new();
@@ -148,4 +196,73 @@ impl SourceMap {
             None
         }
     }
+
+    /// Returns ranges of the generated code corresponding to the source line.
+    /// If column is specified, only the source token containing it is looked up.
+    pub fn lookup_reverse(&self, line: u32, column: Option<u32>) -> Vec<SourceMapRange> {
+        let Some(ref x) = self.source_map else {
+            return Vec::new();
+        };
+
+        // Line and column of sourcemap crate is 0-based
+        let Some(line) = line.checked_sub(1) else {
+            return Vec::new();
+        };
+        let tokens: Vec<_> = x
+            .tokens()
+            .enumerate()
+            .filter(|(_, x)| x.get_src_line() == line)
+            .collect();
+
+        // The token containing the column is the last one starting at or before it
+        let column = if let Some(column) = column {
+            let column = tokens
+                .iter()
+                .map(|(_, x)| x.get_src_col())
+                .filter(|x| *x < column)
+                .max();
+            if column.is_none() {
+                return Vec::new();
+            }
+            column
+        } else {
+            None
+        };
+
+        let mut ret: Vec<SourceMapRange> = Vec::new();
+        let mut last_index = None;
+        for (index, token) in &tokens {
+            if column.is_some_and(|x| x != token.get_src_col()) {
+                continue;
+            }
+
+            let start_line = token.get_dst_line() + 1;
+            let start_column = token.get_dst_col() + 1;
+            let name = token.get_name().unwrap_or("");
+            let (end_line, end_column) = if let Some(x) = name.rfind('\n') {
+                let lines = name.matches('\n').count() as u32;
+                (start_line + lines, (name.len() - x) as u32)
+            } else {
+                (start_line, start_column + name.len() as u32)
+            };
+
+            // Tokens emitted consecutively are merged into a range
+            if last_index.is_some_and(|x| x + 1 == *index)
+                && let Some(last) = ret.last_mut()
+            {
+                last.end_line = end_line;
+                last.end_column = end_column;
+            } else {
+                ret.push(SourceMapRange {
+                    start_line,
+                    start_column,
+                    end_line,
+                    end_column,
+                });
+            }
+            last_index = Some(*index);
+        }
+
+        ret
+    }
 }
@@ -0,0 +1,71 @@
+use crate::{SourceMap, SourceMapRange};
+use std::path::Path;
+
+fn source_map() -> SourceMap {
+    let mut ret = SourceMap::new(
+        Path::new("/prj/src/a.veryl"),
+        Path::new("/prj/target/a.sv"),
+        Path::new("/prj/target/a.sv.map"),
+    );
+    ret.add(1, 1, 1, 1, "module");
+    ret.add(1, 8, 1, 8, "A");
+    ret.add(2, 5, 2, 5, "a");
+    ret.add(3, 1, 3, 1, "b\n  c");
+    ret.add(5, 5, 2, 5, "a");
+    ret.build();
+    ret
+}
+
+fn range(start_line: u32, start_column: u32, end_line: u32, end_column: u32) -> SourceMapRange {
+    SourceMapRange {
+        start_line,
+        start_column,
+        end_line,
+        end_column,
+    }
+}
+
+#[test]
+fn lookup_reverse_line() {
+    let map = source_map();
+
+    assert_eq!(map.lookup_reverse(1, None), vec![range(1, 1, 1, 9)]);
+    assert_eq!(
+        map.lookup_reverse(2, None),
+        vec![range(2, 5, 2, 6), range(5, 5, 5, 6)]
+    );
+    assert_eq!(map.lookup_reverse(3, None), vec![range(3, 1, 4, 4)]);
+    assert_eq!(map.lookup_reverse(9, None), vec![]);
+}
+
+#[test]
+fn lookup_reverse_column() {
+    let map = source_map();
+
+    assert_eq!(map.lookup_reverse(1, Some(3)), vec![range(1, 1, 1, 7)]);
+    assert_eq!(map.lookup_reverse(1, Some(8)), vec![range(1, 8, 1, 9)]);
+    assert_eq!(map.lookup_reverse(2, Some(4)), vec![]);
+}
+
+#[test]
+fn lookup_reverse_zero() {
+    let map = source_map();
+
+    assert_eq!(map.lookup_reverse(0, None), vec![]);
+    assert_eq!(map.lookup_reverse(1, Some(0)), vec![]);
+}
+
+#[test]
+fn split() {
+    let map = source_map().split(
+        Path::new("/prj/target/unit/A.sv"),
+        Path::new("/prj/target/unit/A.sv.map"),
+        2..4,
+        10,
+    );
+
+    assert_eq!(map.lookup_reverse(1, None), vec![]);
+    assert_eq!(map.lookup_reverse(2, None), vec![range(10, 5, 10, 6)]);
+    assert_eq!(map.lookup_reverse(3, None), vec![range(11, 1, 12, 4)]);
+    assert_eq!(map.get_link(), "//# sourceMappingURL=A.sv.map");
+}
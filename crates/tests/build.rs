@@ -42,4 +42,40 @@ fn main() {
         let _ = writeln!(out_test, "    r\"{testcase}\",");
     }
     let _ = writeln!(out_test, "];");
+
+    let out_vhdl_test = Path::new(&out_dir).join("vhdl_test.rs");
+    let mut out_vhdl_test = File::create(out_vhdl_test).unwrap();
+
+    for entry in WalkDir::new("../../testcases/vhdl") {
+        let entry = entry.unwrap();
+        if entry.file_type().is_file() {
+            if let Some(x) = entry.path().extension() {
+                if x == "vhd" {
+                    let file = entry.path().file_stem().unwrap().to_string_lossy();
+                    let _ = writeln!(out_vhdl_test, "#[test]");
+                    let _ = writeln!(out_vhdl_test, "fn test_{file}() {{");
+                    let _ = writeln!(out_vhdl_test, "    test(\"{file}\");");
+                    let _ = writeln!(out_vhdl_test, "}}");
+                }
+            }
+        }
+    }
+
+    let out_circt_test = Path::new(&out_dir).join("circt_test.rs");
+    let mut out_circt_test = File::create(out_circt_test).unwrap();
+
+    for entry in WalkDir::new("../../testcases/circt") {
+        let entry = entry.unwrap();
+        if entry.file_type().is_file() {
+            if let Some(x) = entry.path().extension() {
+                if x == "mlir" {
+                    let file = entry.path().file_stem().unwrap().to_string_lossy();
+                    let _ = writeln!(out_circt_test, "#[test]");
+                    let _ = writeln!(out_circt_test, "fn test_{file}() {{");
+                    let _ = writeln!(out_circt_test, "    test(\"{file}\");");
+                    let _ = writeln!(out_circt_test, "}}");
+                }
+            }
+        }
+    }
 }
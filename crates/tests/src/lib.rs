@@ -169,6 +169,95 @@ mod emitter {
     include!(concat!(env!("OUT_DIR"), "/test.rs"));
 }
 
+#[cfg(test)]
+mod vhdl_emitter {
+    use std::fs;
+    use std::path::PathBuf;
+    use veryl_analyzer::Analyzer;
+    use veryl_emitter::VhdlEmitter;
+    use veryl_metadata::{Language, Metadata};
+    use veryl_parser::Parser;
+
+    fn test(name: &str) {
+        let metadata_path = Metadata::search_from_current().unwrap();
+        let mut metadata = Metadata::load(&metadata_path).unwrap();
+        metadata.build.language = Language::Vhdl2008;
+
+        let src_path = PathBuf::from(format!("../../testcases/veryl/{}.veryl", name));
+        let dst_path = PathBuf::from(format!("../../testcases/vhdl/{}.vhd", name));
+        let map_path = PathBuf::from(format!("../../testcases/map/{}.vhd.map", name));
+
+        let input = fs::read_to_string(&src_path).unwrap();
+        let ret = Parser::parse(&input, &src_path).unwrap();
+        let prj = &metadata.project.name;
+        let analyzer = Analyzer::new(&metadata);
+        let mut errors = analyzer.analyze_pass1(&prj, &src_path, &ret.veryl);
+        errors.append(&mut Analyzer::analyze_post_pass1());
+        errors.append(&mut analyzer.analyze_pass2(&prj, &src_path, &ret.veryl));
+        assert!(errors.is_empty());
+
+        let mut emitter = VhdlEmitter::new(&metadata, &src_path, &dst_path, &map_path);
+        emitter.emit(&prj, &ret.veryl);
+
+        let out_code = emitter.as_str();
+        let ref_code = fs::read_to_string(&dst_path).unwrap();
+
+        assert_eq!(ref_code, out_code);
+
+        let out_map = String::from_utf8(emitter.source_map().to_bytes().unwrap()).unwrap();
+        let ref_map = if cfg!(target_os = "windows") {
+            fs::read_to_string(&map_path)
+                .unwrap()
+                .replace("\\n", "\\r\\n")
+        } else {
+            fs::read_to_string(&map_path).unwrap()
+        };
+
+        assert_eq!(ref_map, out_map);
+    }
+
+    include!(concat!(env!("OUT_DIR"), "/vhdl_test.rs"));
+}
+
+#[cfg(test)]
+mod circt_emitter {
+    use std::fs;
+    use std::path::PathBuf;
+    use veryl_analyzer::Analyzer;
+    use veryl_emitter::CirctEmitter;
+    use veryl_metadata::{Language, Metadata};
+    use veryl_parser::Parser;
+
+    fn test(name: &str) {
+        let metadata_path = Metadata::search_from_current().unwrap();
+        let mut metadata = Metadata::load(&metadata_path).unwrap();
+        metadata.build.language = Language::Circt;
+
+        let src_path = PathBuf::from(format!("../../testcases/veryl/{}.veryl", name));
+        let dst_path = PathBuf::from(format!("../../testcases/circt/{}.mlir", name));
+        let map_path = PathBuf::from(format!("../../testcases/map/{}.mlir.map", name));
+
+        let input = fs::read_to_string(&src_path).unwrap();
+        let ret = Parser::parse(&input, &src_path).unwrap();
+        let prj = &metadata.project.name;
+        let analyzer = Analyzer::new(&metadata);
+        let mut errors = analyzer.analyze_pass1(&prj, &src_path, &ret.veryl);
+        errors.append(&mut Analyzer::analyze_post_pass1());
+        errors.append(&mut analyzer.analyze_pass2(&prj, &src_path, &ret.veryl));
+        assert!(errors.is_empty());
+
+        let mut emitter = CirctEmitter::new(&metadata, &src_path, &dst_path, &map_path);
+        emitter.emit(&prj, &ret.veryl);
+
+        let out_code = emitter.as_str();
+        let ref_code = fs::read_to_string(&dst_path).unwrap();
+
+        assert_eq!(ref_code, out_code);
+    }
+
+    include!(concat!(env!("OUT_DIR"), "/circt_test.rs"));
+}
+
 #[cfg(test)]
 mod path {
     use std::path::PathBuf;
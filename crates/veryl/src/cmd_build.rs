@@ -5,17 +5,20 @@ use crate::diff::print_diff;
 use crate::utils;
 use log::{debug, info};
 use miette::{IntoDiagnostic, Result, WrapErr};
-use std::collections::HashMap;
+use serde::Serialize;
+use std::collections::{BTreeMap, HashMap};
 use std::fs;
 use std::path::{Path, PathBuf};
 use tempfile::TempDir;
 use veryl_analyzer::namespace::Namespace;
 use veryl_analyzer::symbol::SymbolKind;
 use veryl_analyzer::{Analyzer, symbol_table, type_dag};
-use veryl_emitter::Emitter;
-use veryl_metadata::{FilelistType, Metadata, SourceMapTarget, Target};
+use veryl_emitter::{CirctEmitter, EmittedUnit, Emitter, Exporter, IpXactGenerator, VhdlEmitter};
+use veryl_metadata::{FilelistType, Language, Metadata, SourceMapTarget, Target};
+use veryl_parser::veryl_grammar_trait::Veryl;
 use veryl_parser::{Parser, resource_table, veryl_token::TokenSource};
 use veryl_path::PathSet;
+use veryl_sourcemap::SourceMap;
 
 pub struct CmdBuild {
     opt: OptBuild,
@@ -79,14 +82,41 @@ impl CmdBuild {
 
         debug!("Executed analyze_pass3 ({} milliseconds)", stopwatch.lap());
 
+        let sources: Vec<_> = contexts
+            .iter()
+            .map(|(path, input, parser, _)| (*path, input.as_str(), &parser.veryl))
+            .collect();
+        let all_pass = self.emit(metadata, &paths, &sources, include_tests, quiet)?;
+
+        let _ = check_error.check_err()?;
+        Ok(all_pass)
+    }
+
+    /// Emits the target codes of the analyzed sources, and generates the related files like filelist
+    pub fn emit(
+        &self,
+        metadata: &mut Metadata,
+        paths: &[PathSet],
+        sources: &[(&PathSet, &str, &Veryl)],
+        include_tests: bool,
+        quiet: bool,
+    ) -> Result<bool> {
         let temp_dir = if let Target::Bundle { .. } = &metadata.build.target {
             Some(TempDir::new().into_diagnostic()?)
         } else {
             None
         };
 
+        let unit_path = if let Target::Unit { path } = &metadata.build.target {
+            Some(metadata.project_path().join(path))
+        } else {
+            None
+        };
+
         let mut all_pass = true;
-        for (path, input, parser, _) in &contexts {
+        let mut mangled_names = BTreeMap::new();
+        let mut units = HashMap::new();
+        for (path, input, veryl) in sources {
             let (dst, map) = if let Some(ref temp_dir) = temp_dir {
                 let dst_temp = temp_dir.path().join(
                     path.dst
@@ -103,8 +133,42 @@ impl CmdBuild {
                 (path.dst.clone(), path.map.clone())
             };
 
-            let mut emitter = Emitter::new(metadata, &path.src, &dst, &map);
-            emitter.emit(&path.prj, &parser.veryl);
+            let mut emitter = LanguageEmitter::new(metadata, &path.src, &dst, &map);
+            emitter.emit(&path.prj, veryl);
+
+            if let Some(x) = emitter.mangled_names() {
+                mangled_names.extend(x.clone());
+            }
+
+            if let Some(ref unit_path) = unit_path {
+                let Some(emitted) = emitter.units() else {
+                    miette::bail!(
+                        "Unit target is not supported by {:?}",
+                        metadata.build.language
+                    );
+                };
+                let source_map = if metadata.build.sourcemap_target != SourceMapTarget::None {
+                    emitter.source_map()
+                } else {
+                    None
+                };
+                let emitted: Vec<_> = emitted
+                    .into_iter()
+                    .map(|mut unit| {
+                        let source_map = source_map.as_ref().map(|x| {
+                            let dst = unit_file(metadata, unit_path, &unit);
+                            let map = unit_map_file(metadata, &dst);
+                            let source_map = x.split(&dst, &map, unit.lines.clone(), unit.line);
+                            unit.text.push_str(&source_map.get_link());
+                            unit.text.push('\n');
+                            source_map
+                        });
+                        (unit, source_map)
+                    })
+                    .collect();
+                units.insert(path.src.clone(), emitted);
+                continue;
+            }
 
             let dst_dir = dst.parent().unwrap();
             if !dst_dir.exists() {
@@ -120,41 +184,74 @@ impl CmdBuild {
                     all_pass = false;
                 }
             } else {
-                let written = utils::write_file_if_changed(&dst, emitter.as_str().as_bytes())?;
-                if written {
-                    debug!("Output file ({})", dst.to_string_lossy());
-                }
-
-                metadata.build_info.generated_files.insert(dst);
+                self.write_output(metadata, &dst, emitter.as_str().as_bytes())?;
 
-                if metadata.build.sourcemap_target != SourceMapTarget::None {
-                    let source_map = emitter.source_map();
+                if metadata.build.sourcemap_target != SourceMapTarget::None
+                    && let Some(source_map) = emitter.source_map()
+                {
                     source_map.set_source_content(input);
-                    let source_map = source_map.to_bytes().into_diagnostic()?;
-
-                    let map_dir = map.parent().unwrap();
-                    if !map_dir.exists() {
-                        std::fs::create_dir_all(map.parent().unwrap()).into_diagnostic()?;
-                    }
+                    self.write_source_map(metadata, source_map, &map)?;
+                }
+            }
+        }
 
-                    let written = utils::write_file_if_changed(&map, &source_map)?;
-                    if written {
-                        debug!("Output map ({})", map.to_string_lossy());
+        // Only units in the filelist are written, so others are not checked
+        if let Some(ref unit_path) = unit_path
+            && self.opt.check
+        {
+            for path in Self::sort_filelist(metadata, paths, include_tests) {
+                for (unit, _) in units.get(&path.src).into_iter().flatten() {
+                    let dst = unit_file(metadata, unit_path, unit);
+                    let output = fs::read_to_string(&dst).unwrap_or_default();
+                    if output != unit.text {
+                        if !quiet {
+                            print_diff(&path.src, &output, &unit.text);
+                        }
+                        all_pass = false;
                     }
-
-                    metadata.build_info.generated_files.insert(map);
                 }
             }
         }
 
         if !self.opt.check {
-            self.gen_filelist(metadata, &paths, temp_dir, include_tests)?;
+            let files = self.gen_filelist(metadata, paths, temp_dir, units, include_tests)?;
+            self.gen_export(metadata)?;
+            self.gen_ipxact(metadata, &files)?;
+            self.gen_mangled_name_map(metadata, &mangled_names)?;
         }
 
-        let _ = check_error.check_err()?;
         Ok(all_pass)
     }
 
+    fn write_source_map(
+        &self,
+        metadata: &mut Metadata,
+        source_map: &SourceMap,
+        map: &Path,
+    ) -> Result<()> {
+        let source_map = source_map.to_bytes().into_diagnostic()?;
+        self.write_output(metadata, map, &source_map)
+    }
+
+    /// Writes a generated file if the content is changed, and records it to the build info
+    fn write_output(&self, metadata: &mut Metadata, path: &Path, text: &[u8]) -> Result<()> {
+        let dir = path.parent().unwrap();
+        if !dir.exists() {
+            std::fs::create_dir_all(dir).into_diagnostic()?;
+        }
+
+        let written = utils::write_file_if_changed(path, text)?;
+        if written {
+            debug!("Output file ({})", path.to_string_lossy());
+        }
+
+        metadata
+            .build_info
+            .generated_files
+            .insert(path.to_path_buf());
+        Ok(())
+    }
+
     fn gen_filelist_line(&self, metadata: &Metadata, path: &Path) -> Result<String> {
         let base_path = metadata.project_path();
         let path = path.canonicalize().into_diagnostic()?;
@@ -173,13 +270,15 @@ impl CmdBuild {
         metadata: &mut Metadata,
         paths: &[PathSet],
         temp_dir: Option<TempDir>,
+        mut units: HashMap<PathBuf, Vec<(EmittedUnit, Option<SourceMap>)>>,
         include_tests: bool,
-    ) -> Result<()> {
+    ) -> Result<Vec<PathBuf>> {
         let filelist_path = metadata.filelist_path();
         let base_path = metadata.project_path();
 
         let paths = Self::sort_filelist(metadata, paths, include_tests);
 
+        let mut files = Vec::new();
         let text = if let Target::Bundle { path } = &metadata.build.target {
             let temp_dir = temp_dir.unwrap();
             let mut text = String::new();
@@ -193,22 +292,56 @@ impl CmdBuild {
                 text.push_str(&fs::read_to_string(&dst).into_diagnostic()?);
             }
 
-            let written = utils::write_file_if_changed(&target_path, text.as_bytes())?;
-            if written {
-                debug!("Output file ({})", target_path.to_string_lossy());
+            self.write_output(metadata, &target_path, text.as_bytes())?;
+
+            let line = self.gen_filelist_line(metadata, &target_path)?;
+            files.push(target_path);
+            line
+        } else if let Target::Unit { path } = &metadata.build.target {
+            let target_path = base_path.join(path);
+            if !target_path.exists() {
+                std::fs::create_dir_all(&target_path).into_diagnostic()?;
             }
 
-            metadata
-                .build_info
-                .generated_files
-                .insert(target_path.clone());
+            let mut manifest = Vec::new();
+            for path in paths {
+                for (unit, source_map) in units.remove(&path.src).unwrap_or_default() {
+                    let dst = unit_file(metadata, &target_path, &unit);
+                    let written = utils::write_file_if_changed(&dst, unit.text.as_bytes())?;
+                    if written {
+                        debug!("Output file ({})", dst.to_string_lossy());
+                    }
+                    if let Some(source_map) = source_map {
+                        self.write_source_map(metadata, &source_map, &source_map.map_path)?;
+                    }
+                    manifest.push((unit, path.src.clone(), dst));
+                }
+            }
+
+            // Units are sorted by the type dependency because a file may contain several units
+            let order: HashMap<_, _> = type_dag::toposort()
+                .iter()
+                .enumerate()
+                .map(|(i, x)| (x.id, i))
+                .collect();
+            manifest
+                .sort_by_key(|(unit, _, _)| order.get(&unit.symbol).copied().unwrap_or(usize::MAX));
+
+            self.gen_unit_manifest(metadata, &target_path, &manifest)?;
 
-            self.gen_filelist_line(metadata, &target_path)?
+            let mut text = String::new();
+            for (_, _, dst) in manifest {
+                text.push_str(&self.gen_filelist_line(metadata, &dst)?);
+                metadata.build_info.generated_files.insert(dst.clone());
+                files.push(dst);
+            }
+            text
         } else {
             let mut text = String::new();
             for path in paths {
                 let line = self.gen_filelist_line(metadata, &path.dst)?;
                 text.push_str(&line);
+                files.push(path.dst);
             }
             text
         };
@@ -218,6 +351,110 @@ impl CmdBuild {
         info!("Output filelist ({})", filelist_path.to_string_lossy());
         metadata.build_info.generated_files.insert(filelist_path);
 
+        Ok(files)
+    }
+
+    fn gen_unit_manifest(
+        &self,
+        metadata: &mut Metadata,
+        target_path: &Path,
+        units: &[(EmittedUnit, PathBuf, PathBuf)],
+    ) -> Result<()> {
+        let base_path = metadata.project_path();
+
+        let mut names: HashMap<_, Vec<_>> = HashMap::new();
+        for (unit, _, _) in units {
+            names
+                .entry(unit.symbol)
+                .or_default()
+                .push(unit.name.clone());
+        }
+
+        let mut manifest = Vec::new();
+        for (order, (unit, src, dst)) in units.iter().enumerate() {
+            let kind = match symbol_table::get(unit.symbol).map(|x| x.kind) {
+                Some(SymbolKind::Interface(_)) => "interface",
+                Some(SymbolKind::Package(_)) => "package",
+                _ => "module",
+            };
+
+            let mut dependencies: Vec<_> = type_dag::dependencies(unit.symbol)
+                .iter()
+                .filter_map(|x| names.get(&x.id))
+                .flatten()
+                .cloned()
+                .collect();
+            dependencies.sort();
+            dependencies.dedup();
+
+            manifest.push(UnitManifest {
+                name: unit.name.clone(),
+                kind,
+                source: src.strip_prefix(&base_path).unwrap_or(src).to_path_buf(),
+                file: dst.strip_prefix(&base_path).unwrap_or(dst).to_path_buf(),
+                dependencies,
+                order,
+            });
+        }
+
+        let manifest_path = target_path.join("manifest.json");
+        let text = serde_json::to_string_pretty(&manifest).into_diagnostic()?;
+        self.write_output(metadata, &manifest_path, text.as_bytes())?;
+
+        info!("Output unit manifest ({})", manifest_path.to_string_lossy());
+
+        Ok(())
+    }
+
+    fn gen_export(&self, metadata: &mut Metadata) -> Result<()> {
+        if metadata.export.languages.is_empty() {
+            return Ok(());
+        }
+
+        let export_path = metadata.export_path();
+        let files = Exporter::new(metadata).export();
+        for file in files {
+            let path = export_path.join(&file.name);
+            self.write_output(metadata, &path, file.text.as_bytes())?;
+        }
+
+        info!("Output export ({})", export_path.to_string_lossy());
+
+        Ok(())
+    }
+
+    fn gen_ipxact(&self, metadata: &mut Metadata, files: &[PathBuf]) -> Result<()> {
+        if !metadata.ipxact.enable {
+            return Ok(());
+        }
+
+        let ipxact_path = metadata.ipxact_path();
+        let files = IpXactGenerator::new(metadata).generate(files);
+        for file in files {
+            let path = ipxact_path.join(&file.name);
+            self.write_output(metadata, &path, file.text.as_bytes())?;
+        }
+
+        info!("Output IP-XACT ({})", ipxact_path.to_string_lossy());
+
+        Ok(())
+    }
+
+    fn gen_mangled_name_map(
+        &self,
+        metadata: &mut Metadata,
+        mangled_names: &BTreeMap<String, String>,
+    ) -> Result<()> {
+        if !metadata.build.hashed_mangled_name {
+            return Ok(());
+        }
+
+        let map_path = metadata.mangled_name_map_path();
+        let text = serde_json::to_string_pretty(mangled_names).into_diagnostic()?;
+        self.write_output(metadata, &map_path, text.as_bytes())?;
+
+        info!("Output mangled name map ({})", map_path.to_string_lossy());
+
         Ok(())
     }
 
@@ -280,3 +517,99 @@ impl CmdBuild {
         ret
     }
 }
+
+/// Entry of the manifest written for `Target::Unit`
+#[derive(Serialize)]
+struct UnitManifest {
+    name: String,
+    kind: &'static str,
+    source: PathBuf,
+    file: PathBuf,
+    dependencies: Vec<String>,
+    /// Position in the dependency-sorted filelist
+    order: usize,
+}
+
+fn unit_file(metadata: &Metadata, target_path: &Path, unit: &EmittedUnit) -> PathBuf {
+    target_path.join(format!(
+        "{}.{}",
+        unit.name,
+        metadata.build.language.extension()
+    ))
+}
+
+/// Source map of the unit file, which is placed like ones of the other targets
+fn unit_map_file(metadata: &Metadata, dst: &Path) -> PathBuf {
+    let map_ext = format!("{}.map", metadata.build.language.extension());
+    if let SourceMapTarget::Directory { path } = &metadata.build.sourcemap_target {
+        let base_path = metadata.project_path();
+        let dst = dst.strip_prefix(&base_path).unwrap_or(dst);
+        base_path.join(path.join(dst.with_extension(&map_ext)))
+    } else {
+        dst.with_extension(&map_ext)
+    }
+}
+
+/// Code generator selected by `build.language`
+enum LanguageEmitter {
+    Verilog(Box<Emitter>),
+    Vhdl(Box<VhdlEmitter>),
+    Circt(Box<CirctEmitter>),
+}
+
+impl LanguageEmitter {
+    fn new(metadata: &Metadata, src_path: &Path, dst_path: &Path, map_path: &Path) -> Self {
+        match metadata.build.language {
+            Language::Vhdl2008 => Self::Vhdl(Box::new(VhdlEmitter::new(
+                metadata, src_path, dst_path, map_path,
+            ))),
+            Language::Circt => Self::Circt(Box::new(CirctEmitter::new(
+                metadata, src_path, dst_path, map_path,
+            ))),
+            _ => Self::Verilog(Box::new(Emitter::new(
+                metadata, src_path, dst_path, map_path,
+            ))),
+        }
+    }
+
+    fn emit(&mut self, project_name: &str, input: &Veryl) {
+        match self {
+            Self::Verilog(x) => x.emit(project_name, input),
+            Self::Vhdl(x) => x.emit(project_name, input),
+            Self::Circt(x) => x.emit(project_name, input),
+        }
+    }
+
+    fn as_str(&self) -> &str {
+        match self {
+            Self::Verilog(x) => x.as_str(),
+            Self::Vhdl(x) => x.as_str(),
+            Self::Circt(x) => x.as_str(),
+        }
+    }
+
+    /// Hashed names of generic instances, which are emitted only for SystemVerilog
+    fn mangled_names(&self) -> Option<&BTreeMap<String, String>> {
+        match self {
+            Self::Verilog(x) => Some(x.mangled_names()),
+            _ => None,
+        }
+    }
+
+    /// Top-level units of the output, which are separated only for SystemVerilog/Verilog
+    fn units(&self) -> Option<Vec<EmittedUnit>> {
+        match self {
+            Self::Verilog(x) => Some(x.units()),
+            _ => None,
+        }
+    }
+
+    /// Source map of the output, which is not generated for CIRCT because each operation has its location
+    fn source_map(&mut self) -> Option<&mut SourceMap> {
+        match self {
+            Self::Verilog(x) => Some(x.source_map()),
+            Self::Vhdl(x) => Some(x.source_map()),
+            Self::Circt(_) => None,
+        }
+    }
+}
@@ -18,21 +18,39 @@ pub struct CheckError {
     pub related: Vec<AnalyzerError>,
 }
 
+#[derive(Error, Diagnostic, Debug, Default)]
+#[error("veryl check failed")]
+pub struct SyntaxErrors {
+    #[related]
+    pub related: Vec<Box<dyn Diagnostic + Send + Sync>>,
+}
+
+impl SyntaxErrors {
+    pub fn append<T: Diagnostic + Send + Sync + 'static>(&mut self, x: Vec<T>) {
+        self.related.extend(
+            x.into_iter()
+                .map(|x| Box::new(x) as Box<dyn Diagnostic + Send + Sync>),
+        );
+    }
+}
+
 impl CheckError {
     pub fn append(mut self, x: &mut Vec<AnalyzerError>) -> Self {
         self.related.append(x);
         self
     }
 
-    pub fn check_err(self) -> Result<Self> {
-        if self
-            .related
+    pub fn has_error(&self) -> bool {
+        self.related
             .iter()
-            .all(|x| !matches!(x.severity(), Some(Severity::Error) | None))
-        {
-            Ok(self)
-        } else {
+            .any(|x| matches!(x.severity(), Some(Severity::Error) | None))
+    }
+
+    pub fn check_err(self) -> Result<Self> {
+        if self.has_error() {
             Err(self.into())
+        } else {
+            Ok(self)
         }
     }
 
@@ -54,6 +72,7 @@ impl CmdCheck {
         let paths = metadata.paths(&self.opt.files, true)?;
 
         let mut check_error = CheckError::default();
+        let mut syntax_errors = SyntaxErrors::default();
         let mut contexts = Vec::new();
 
         for path in &paths {
@@ -62,28 +81,64 @@ impl CmdCheck {
             let input = fs::read_to_string(&path.src)
                 .into_diagnostic()
                 .wrap_err("")?;
-            let parser = Parser::parse(&input, &path.src)?;
+            // Report all syntax errors of all files at once
+            let (parser, errors) = Parser::parse_with_recovery(&input, &path.src);
+            syntax_errors.append(errors);
+            let Some(parser) = parser else {
+                continue;
+            };
 
             let analyzer = Analyzer::new(metadata);
             let mut errors = analyzer.analyze_pass1(&path.prj, &path.src, &parser.veryl);
-            check_error = check_error.append(&mut errors).check_err()?;
+            check_error = check_error.append(&mut errors);
 
             contexts.push((path, input, parser, analyzer));
         }
 
+        // Semantic errors are reported with syntax errors if the partial trees can be analyzed
+        let recovered = !syntax_errors.related.is_empty();
+        if !recovered {
+            check_error = check_error.check_err()?;
+        }
+
         let mut errors = Analyzer::analyze_post_pass1();
-        check_error = check_error.append(&mut errors).check_err()?;
+        check_error = check_error.append(&mut errors);
+        if !recovered {
+            check_error = check_error.check_err()?;
+        }
 
         for (path, _, parser, analyzer) in &contexts {
             let mut errors = analyzer.analyze_pass2(&path.prj, &path.src, &parser.veryl);
-            check_error = check_error.append(&mut errors).check_err()?;
+            check_error = check_error.append(&mut errors);
+            if !recovered {
+                check_error = check_error.check_err()?;
+            }
         }
 
         let info = Analyzer::analyze_post_pass2();
 
         for (path, _, parser, analyzer) in &contexts {
             let mut errors = analyzer.analyze_pass3(&path.prj, &path.src, &parser.veryl, &info);
-            check_error = check_error.append(&mut errors).check_err()?;
+            check_error = check_error.append(&mut errors);
+            if !recovered {
+                check_error = check_error.check_err()?;
+            }
+        }
+
+        if recovered {
+            // Errors caused by declarations and statements removed by the recovery are filtered
+            check_error.related.retain(|x| {
+                !matches!(
+                    x,
+                    AnalyzerError::UndefinedIdentifier { .. }
+                        | AnalyzerError::UnknownMember { .. }
+                        | AnalyzerError::UnassignVariable { .. }
+                        | AnalyzerError::UnusedVariable { .. }
+                        | AnalyzerError::AnonymousIdentifierUsage { .. }
+                )
+            });
+            syntax_errors.append(check_error.related);
+            return Err(syntax_errors.into());
         }
 
         let _ = check_error.check_all()?;
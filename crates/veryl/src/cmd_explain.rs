@@ -0,0 +1,29 @@
+use crate::OptExplain;
+use miette::{Result, bail};
+use veryl_analyzer::explanation;
+
+pub struct CmdExplain {
+    opt: OptExplain,
+}
+
+impl CmdExplain {
+    pub fn new(opt: OptExplain) -> Self {
+        Self { opt }
+    }
+
+    pub fn exec(&self) -> Result<bool> {
+        let Some(code) = &self.opt.code else {
+            for code in explanation::codes() {
+                println!("{code}");
+            }
+            return Ok(true);
+        };
+
+        let Some(text) = explanation::get(code) else {
+            bail!("\"{code}\" is not a valid diagnostic code");
+        };
+
+        print!("{text}");
+        Ok(true)
+    }
+}
@@ -0,0 +1,175 @@
+use crate::OptFix;
+use crate::diff::print_diff;
+use crate::utils;
+use log::{debug, info};
+use miette::{IntoDiagnostic, Result, WrapErr};
+use std::collections::{HashMap, HashSet};
+use std::fs;
+use veryl_analyzer::Analyzer;
+use veryl_analyzer::suggestion::Edit;
+use veryl_formatter::Formatter;
+use veryl_metadata::Metadata;
+use veryl_parser::Parser;
+use veryl_parser::resource_table::{self, PathId};
+
+pub struct CmdFix {
+    opt: OptFix,
+}
+
+impl CmdFix {
+    pub fn new(opt: OptFix) -> Self {
+        Self { opt }
+    }
+
+    pub fn exec(&self, metadata: &mut Metadata, quiet: bool) -> Result<bool> {
+        let paths = metadata.paths(&self.opt.files, true)?;
+
+        let mut contexts = Vec::new();
+        let mut errors = Vec::new();
+
+        for path in &paths {
+            info!("Processing file ({})", path.src.to_string_lossy());
+
+            let input = fs::read_to_string(&path.src)
+                .into_diagnostic()
+                .wrap_err("")?;
+            let parser = Parser::parse(&input, &path.src)?;
+
+            let analyzer = Analyzer::new(metadata);
+            errors.append(&mut analyzer.analyze_pass1(&path.prj, &path.src, &parser.veryl));
+
+            contexts.push((path, input, parser, analyzer));
+        }
+
+        errors.append(&mut Analyzer::analyze_post_pass1());
+
+        for (path, _, parser, analyzer) in &contexts {
+            errors.append(&mut analyzer.analyze_pass2(&path.prj, &path.src, &parser.veryl));
+        }
+
+        let info = Analyzer::analyze_post_pass2();
+
+        for (path, _, parser, analyzer) in &contexts {
+            errors.append(&mut analyzer.analyze_pass3(&path.prj, &path.src, &parser.veryl, &info));
+        }
+
+        // Only files of the current project can be fixed
+        let fixable: Vec<PathId> = contexts
+            .iter()
+            .filter(|(path, ..)| path.prj == metadata.project.name)
+            .map(|(path, ..)| resource_table::insert_path(&path.src))
+            .collect();
+
+        // Suggestions are applied as a whole, and ones overlapping with already accepted edits are skipped
+        let mut accepted: HashMap<PathId, Vec<Edit>> = HashMap::new();
+        let mut renamed = HashSet::new();
+        let mut fixed = 0;
+        for error in &errors {
+            let Some(suggestion) = error.suggestion() else {
+                continue;
+            };
+            let edits = suggestion.edits();
+            if edits.is_empty() || edits.iter().any(|x| !fixable.contains(&x.path)) {
+                continue;
+            }
+
+            let conflict = edits.iter().any(|x| {
+                accepted.get(&x.path).is_some_and(|accepted| {
+                    accepted
+                        .iter()
+                        .any(|y| x != y && x.beg < y.end && y.beg < x.end)
+                })
+            });
+            // Two identifiers can't be renamed to the same one in a scope
+            let target = suggestion.rename_target();
+            if conflict || target.as_ref().is_some_and(|x| renamed.contains(x)) {
+                debug!("Skipped conflicting suggestion ({error})");
+                continue;
+            }
+            if let Some(x) = target {
+                renamed.insert(x);
+            }
+
+            for edit in edits {
+                let accepted = accepted.entry(edit.path).or_default();
+                if !accepted.contains(&edit) {
+                    accepted.push(edit);
+                }
+            }
+            fixed += 1;
+        }
+
+        // All fixed files are parsed before writing any of them,
+        // so a file is not left half-fixed by errors of the others
+        let mut fixed_files = Vec::new();
+        for (path, input, _, _) in &contexts {
+            let path_id = resource_table::insert_path(&path.src);
+            let Some(edits) = accepted.get_mut(&path_id) else {
+                continue;
+            };
+
+            let mut text = input.clone();
+            edits.sort_by_key(|x| std::cmp::Reverse(x.beg));
+            for edit in edits.iter() {
+                let (beg, end) = if edit.text.is_empty() {
+                    expand_to_line(&text, edit.beg, edit.end)
+                } else {
+                    (edit.beg, edit.end)
+                };
+                text.replace_range(beg..end, &edit.text);
+            }
+
+            let parser = Parser::parse(&text, &path.src)?;
+            fixed_files.push((path, input, path_id, parser));
+        }
+
+        // Attributes of the fixed files are required by formatter
+        let mut outputs = Vec::new();
+        for (path, input, path_id, parser) in &fixed_files {
+            Analyzer::drop_file(*path_id);
+            let analyzer = Analyzer::new(metadata);
+            let _ = analyzer.analyze_pass1(&path.prj, &path.src, &parser.veryl);
+
+            let mut formatter = Formatter::new(metadata);
+            formatter.format(&parser.veryl);
+            outputs.push((path, input, formatter.as_str().to_string()));
+        }
+
+        for (path, input, output) in outputs {
+            if self.opt.dry_run {
+                if !quiet {
+                    print_diff(&path.src, input.as_str(), &output);
+                }
+            } else {
+                let written = utils::write_file_if_changed(&path.src, output.as_bytes())?;
+                if written {
+                    debug!("Overwritten file ({})", path.src.to_string_lossy());
+                }
+            }
+        }
+
+        if self.opt.dry_run {
+            info!("Found {fixed} fixable diagnostics");
+        } else {
+            info!("Fixed {fixed} diagnostics");
+        }
+
+        Ok(true)
+    }
+}
+
+/// Expands the removed range to the whole line if nothing else remains in the line
+fn expand_to_line(text: &str, beg: usize, end: usize) -> (usize, usize) {
+    let line_beg = text[..beg].rfind('\n').map(|x| x + 1).unwrap_or(0);
+    let line_end = text[end..]
+        .find('\n')
+        .map(|x| end + x + 1)
+        .unwrap_or(text.len());
+
+    let blank = |x: &str| x.trim().is_empty();
+    if blank(&text[line_beg..beg]) && blank(&text[end..line_end]) {
+        (line_beg, line_end)
+    } else {
+        (beg, end)
+    }
+}
@@ -0,0 +1,492 @@
+use crate::{GraphFormat, GraphKind, OptGraph};
+use log::{info, warn};
+use miette::{IntoDiagnostic, Result, WrapErr, bail};
+use serde::Serialize;
+use std::collections::hash_map::Entry;
+use std::collections::{HashMap, HashSet, VecDeque};
+use std::fs;
+use veryl_analyzer::Analyzer;
+use veryl_analyzer::namespace::Namespace;
+use veryl_analyzer::symbol::{Symbol, SymbolKind};
+use veryl_analyzer::symbol_table;
+use veryl_metadata::Metadata;
+use veryl_parser::Parser;
+use veryl_parser::resource_table::{self, StrId};
+use veryl_parser::veryl_token::TokenSource;
+
+pub struct CmdGraph {
+    opt: OptGraph,
+}
+
+#[derive(Debug, Default, Serialize)]
+struct Graph {
+    nodes: Vec<String>,
+    edges: Vec<Edge>,
+}
+
+#[derive(Debug, Serialize)]
+struct Edge {
+    from: usize,
+    to: usize,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    label: Option<String>,
+}
+
+impl Graph {
+    fn node(&mut self, name: &str) -> usize {
+        if let Some(x) = self.nodes.iter().position(|x| x == name) {
+            x
+        } else {
+            self.nodes.push(name.to_string());
+            self.nodes.len() - 1
+        }
+    }
+
+    fn edge(&mut self, from: &str, to: &str, label: Option<String>) {
+        let from = self.node(from);
+        let to = self.node(to);
+        if !self
+            .edges
+            .iter()
+            .any(|x| x.from == from && x.to == to && x.label == label)
+        {
+            self.edges.push(Edge { from, to, label });
+        }
+    }
+
+    /// Extracts the subgraph reachable from the root within the depth.
+    /// Nodes without incoming edges are regarded as roots if the root is not specified,
+    /// and all nodes are if there is no such node.
+    fn filter(self, root: Option<&str>, depth: Option<usize>) -> Result<Graph> {
+        if root.is_none() && depth.is_none() {
+            return Ok(self);
+        }
+
+        let roots: Vec<_> = if let Some(root) = root {
+            let Some(x) = self.nodes.iter().position(|x| x == root) else {
+                bail!("\"{root}\" is not found in the graph");
+            };
+            vec![x]
+        } else {
+            let roots: Vec<_> = (0..self.nodes.len())
+                .filter(|x| !self.edges.iter().any(|y| y.to == *x))
+                .collect();
+            // All nodes are regarded as roots if every node is in a cycle
+            if roots.is_empty() {
+                (0..self.nodes.len()).collect()
+            } else {
+                roots
+            }
+        };
+
+        let mut levels = HashMap::new();
+        let mut queue = VecDeque::new();
+        for root in roots {
+            levels.insert(root, 0);
+            queue.push_back(root);
+        }
+        while let Some(x) = queue.pop_front() {
+            let level = levels[&x];
+            if depth.is_some_and(|depth| level >= depth) {
+                continue;
+            }
+            for edge in self.edges.iter().filter(|y| y.from == x) {
+                if let Entry::Vacant(entry) = levels.entry(edge.to) {
+                    entry.insert(level + 1);
+                    queue.push_back(edge.to);
+                }
+            }
+        }
+
+        let mut ret = Graph::default();
+        for (i, name) in self.nodes.iter().enumerate() {
+            if levels.contains_key(&i) {
+                ret.node(name);
+            }
+        }
+        for edge in &self.edges {
+            let included = levels.get(&edge.from).is_some_and(|level| {
+                levels.contains_key(&edge.to) && depth.is_none_or(|depth| *level < depth)
+            });
+            if included {
+                let from = &self.nodes[edge.from];
+                let to = &self.nodes[edge.to];
+                ret.edge(from, to, edge.label.clone());
+            }
+        }
+        Ok(ret)
+    }
+
+    fn find_cycle(&self) -> Option<Vec<usize>> {
+        fn visit(
+            graph: &Graph,
+            node: usize,
+            stack: &mut Vec<usize>,
+            done: &mut HashSet<usize>,
+        ) -> Option<Vec<usize>> {
+            if let Some(pos) = stack.iter().position(|x| *x == node) {
+                let mut cycle = stack[pos..].to_vec();
+                cycle.push(node);
+                return Some(cycle);
+            }
+            if !done.insert(node) {
+                return None;
+            }
+
+            stack.push(node);
+            for edge in graph.edges.iter().filter(|x| x.from == node) {
+                if let Some(x) = visit(graph, edge.to, stack, done) {
+                    return Some(x);
+                }
+            }
+            stack.pop();
+            None
+        }
+
+        let mut done = HashSet::new();
+        (0..self.nodes.len()).find_map(|x| visit(self, x, &mut vec![], &mut done))
+    }
+
+    fn to_dot(&self, name: &str) -> String {
+        let mut ret = format!("digraph {name} {{\n");
+        for node in &self.nodes {
+            ret.push_str(&format!("    \"{node}\";\n"));
+        }
+        for edge in &self.edges {
+            let from = &self.nodes[edge.from];
+            let to = &self.nodes[edge.to];
+            if let Some(label) = &edge.label {
+                ret.push_str(&format!(
+                    "    \"{from}\" -> \"{to}\" [label=\"{label}\"];\n"
+                ));
+            } else {
+                ret.push_str(&format!("    \"{from}\" -> \"{to}\";\n"));
+            }
+        }
+        ret.push('}');
+        ret
+    }
+
+    fn to_mermaid(&self) -> String {
+        let mut ret = "graph TD\n".to_string();
+        for (i, node) in self.nodes.iter().enumerate() {
+            ret.push_str(&format!("    n{i}[\"{node}\"]\n"));
+        }
+        for edge in &self.edges {
+            if let Some(label) = &edge.label {
+                ret.push_str(&format!("    n{} -->|{label}| n{}\n", edge.from, edge.to));
+            } else {
+                ret.push_str(&format!("    n{} --> n{}\n", edge.from, edge.to));
+            }
+        }
+        ret.pop();
+        ret
+    }
+}
+
+impl CmdGraph {
+    pub fn new(opt: OptGraph) -> Self {
+        Self { opt }
+    }
+
+    pub fn exec(&self, metadata: &mut Metadata) -> Result<bool> {
+        let (graph, name) = match self.opt.kind {
+            GraphKind::Hierarchy => {
+                self.analyze(metadata)?;
+                (hierarchy_graph(metadata), "hierarchy")
+            }
+            GraphKind::Import => {
+                self.analyze(metadata)?;
+                (import_graph(metadata), "import")
+            }
+            GraphKind::Dependency => {
+                metadata.update_lockfile()?;
+                (dependency_graph(metadata), "dependency")
+            }
+        };
+
+        let graph = graph.filter(self.opt.root.as_deref(), self.opt.depth)?;
+
+        if let Some(cycle) = graph.find_cycle() {
+            let cycle: Vec<_> = cycle.iter().map(|x| graph.nodes[*x].as_str()).collect();
+            warn!("Cycle is detected ({})", cycle.join(" -> "));
+        }
+
+        let text = match self.opt.format {
+            GraphFormat::Dot => graph.to_dot(name),
+            GraphFormat::Mermaid => graph.to_mermaid(),
+            GraphFormat::Json => serde_json::to_string(&graph).into_diagnostic()?,
+        };
+
+        println!("{text}");
+
+        Ok(true)
+    }
+
+    /// Builds the symbol table. Diagnostics are ignored because the graph is useful to
+    /// investigate errors like recursive instances.
+    fn analyze(&self, metadata: &mut Metadata) -> Result<()> {
+        let paths = metadata.paths::<&str>(&[], true)?;
+
+        let mut contexts = Vec::new();
+
+        for path in &paths {
+            info!("Processing file ({})", path.src.to_string_lossy());
+
+            let input = fs::read_to_string(&path.src)
+                .into_diagnostic()
+                .wrap_err("")?;
+            let parser = Parser::parse(&input, &path.src)?;
+            let analyzer = Analyzer::new(metadata);
+            analyzer.analyze_pass1(&path.prj, &path.src, &parser.veryl);
+
+            contexts.push((path, input, parser, analyzer));
+        }
+
+        Analyzer::analyze_post_pass1();
+
+        for (path, _, parser, analyzer) in &contexts {
+            analyzer.analyze_pass2(&path.prj, &path.src, &parser.veryl);
+        }
+
+        Ok(())
+    }
+}
+
+/// Returns the name of the symbol qualified by the project name if it belongs to other projects
+fn qualified_name(project: StrId, namespace: &Namespace, name: StrId) -> String {
+    let name = resource_table::get_str_value(name).unwrap();
+    match namespace.paths.first() {
+        Some(x) if *x != project => {
+            format!("{}::{name}", resource_table::get_str_value(*x).unwrap())
+        }
+        _ => name,
+    }
+}
+
+fn design_unit(symbol: &Symbol, project: StrId) -> Option<String> {
+    let name = symbol.namespace.paths.get(1)?;
+    let namespace = Namespace {
+        paths: symbol.namespace.paths[..1].into(),
+        ..Default::default()
+    };
+    Some(qualified_name(project, &namespace, *name))
+}
+
+fn hierarchy_graph(metadata: &Metadata) -> Graph {
+    let project = resource_table::insert_str(&metadata.project.name);
+    let mut symbols = symbol_table::get_all();
+    symbols.sort_by_key(|x| x.id);
+
+    let mut graph = Graph::default();
+    for symbol in &symbols {
+        if symbol.namespace.paths.first() == Some(&project)
+            && matches!(
+                symbol.kind,
+                SymbolKind::Module(_) | SymbolKind::Interface(_)
+            )
+        {
+            graph.node(&symbol.token.to_string());
+        }
+    }
+
+    // Instances of all projects are gathered to follow the hierarchy into dependencies
+    let mut instances: HashMap<String, Vec<(String, String)>> = HashMap::new();
+    for symbol in &symbols {
+        let SymbolKind::Instance(x) = &symbol.kind else {
+            continue;
+        };
+        let Ok(found) = symbol_table::resolve((&x.type_name.mangled_path(), &symbol.namespace))
+        else {
+            continue;
+        };
+        let child = match &found.found.kind {
+            SymbolKind::GenericInstance(x) => symbol_table::get(x.base),
+            _ => Some(found.found),
+        };
+        if let (Some(parent), Some(child)) = (design_unit(symbol, project), child) {
+            let child = qualified_name(project, &child.namespace, child.token.text);
+            instances
+                .entry(parent)
+                .or_default()
+                .push((symbol.token.to_string(), child));
+        }
+    }
+
+    let mut queue: VecDeque<_> = graph.nodes.clone().into();
+    let mut visited: HashSet<_> = queue.iter().cloned().collect();
+    while let Some(parent) = queue.pop_front() {
+        for (name, child) in instances.get(&parent).into_iter().flatten() {
+            graph.edge(&parent, child, Some(name.clone()));
+            if visited.insert(child.clone()) {
+                queue.push_back(child.clone());
+            }
+        }
+    }
+
+    graph
+}
+
+fn import_graph(metadata: &Metadata) -> Graph {
+    let project = resource_table::insert_str(&metadata.project.name);
+
+    let mut symbols = symbol_table::get_all();
+    symbols.sort_by_key(|x| x.id);
+
+    // File scope imports may be recorded without the importing unit,
+    // so they are attributed to all units declared in the same file
+    let mut units: HashMap<TokenSource, Vec<String>> = HashMap::new();
+    for symbol in &symbols {
+        if symbol.namespace.paths.len() == 1
+            && symbol.namespace.paths[0] == project
+            && matches!(
+                symbol.kind,
+                SymbolKind::Module(_) | SymbolKind::Interface(_) | SymbolKind::Package(_)
+            )
+        {
+            units
+                .entry(symbol.token.source)
+                .or_default()
+                .push(symbol.token.to_string());
+        }
+    }
+
+    let mut graph = Graph::default();
+    for symbol in symbols {
+        for (path, namespace) in &symbol.imported {
+            // Imports are recorded to each member of the imported package
+            let package = if let SymbolKind::Package(_) = symbol.kind {
+                Some(qualified_name(
+                    project,
+                    &symbol.namespace,
+                    symbol.token.text,
+                ))
+            } else {
+                design_unit(&symbol, project)
+            };
+            let importers = if let Some(x) = namespace.paths.get(1) {
+                vec![qualified_name(project, namespace, *x)]
+            } else {
+                units
+                    .get(&path.range.beg.source)
+                    .cloned()
+                    .unwrap_or_default()
+            };
+
+            let Some(package) = package else {
+                continue;
+            };
+            if namespace.paths.first() != Some(&project) {
+                continue;
+            }
+            for importer in importers {
+                if importer != package {
+                    graph.edge(&importer, &package, None);
+                }
+            }
+        }
+    }
+
+    graph
+}
+
+fn dependency_graph(metadata: &Metadata) -> Graph {
+    let mut graph = Graph::default();
+    let root = metadata.project.name.clone();
+    graph.node(&root);
+
+    let mut locks: Vec<_> = metadata.lockfile.lock_table.values().flatten().collect();
+    locks.sort_by(|a, b| a.name.cmp(&b.name));
+
+    // Dependencies are referred by project local names, so they are converted to the locked names
+    let names: HashMap<_, _> = locks.iter().map(|x| (&x.source, &x.name)).collect();
+
+    for lock in &locks {
+        if metadata.dependencies.contains_key(&lock.name) {
+            graph.edge(&root, &lock.name, None);
+        }
+        for dep in &lock.dependencies {
+            let to = names.get(&dep.source).copied().unwrap_or(&dep.name);
+            graph.edge(&lock.name, to, None);
+        }
+    }
+
+    graph
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn graph(edges: &[(&str, &str)]) -> Graph {
+        let mut graph = Graph::default();
+        for (from, to) in edges {
+            graph.edge(from, to, None);
+        }
+        graph
+    }
+
+    fn edges(graph: &Graph) -> Vec<(&str, &str)> {
+        graph
+            .edges
+            .iter()
+            .map(|x| (graph.nodes[x.from].as_str(), graph.nodes[x.to].as_str()))
+            .collect()
+    }
+
+    #[test]
+    fn filter_root() {
+        let graph = graph(&[("a", "b"), ("b", "c"), ("d", "c")]);
+        let graph = graph.filter(Some("b"), None).unwrap();
+        assert_eq!(graph.nodes, ["b", "c"]);
+        assert_eq!(edges(&graph), [("b", "c")]);
+    }
+
+    #[test]
+    fn filter_unknown_root() {
+        let graph = graph(&[("a", "b")]);
+        assert!(graph.filter(Some("x"), None).is_err());
+    }
+
+    #[test]
+    fn filter_depth() {
+        let graph = graph(&[("a", "b"), ("b", "c"), ("c", "d"), ("e", "b")]);
+        let graph = graph.filter(None, Some(1)).unwrap();
+        assert_eq!(graph.nodes, ["a", "b", "e"]);
+        assert_eq!(edges(&graph), [("a", "b"), ("e", "b")]);
+    }
+
+    #[test]
+    fn filter_root_depth() {
+        let graph = graph(&[("a", "b"), ("b", "c"), ("c", "d")]);
+        let graph = graph.filter(Some("b"), Some(1)).unwrap();
+        assert_eq!(graph.nodes, ["b", "c"]);
+        assert_eq!(edges(&graph), [("b", "c")]);
+
+        let graph = graph.filter(Some("b"), Some(0)).unwrap();
+        assert_eq!(graph.nodes, ["b"]);
+        assert!(graph.edges.is_empty());
+    }
+
+    #[test]
+    fn filter_depth_all_in_cycle() {
+        let graph = graph(&[("a", "b"), ("b", "a")]);
+        let graph = graph.filter(None, Some(0)).unwrap();
+        assert_eq!(graph.nodes, ["a", "b"]);
+        assert!(graph.edges.is_empty());
+    }
+
+    #[test]
+    fn find_cycle() {
+        let acyclic = graph(&[("a", "b"), ("a", "c"), ("b", "d"), ("c", "d")]);
+        assert_eq!(acyclic.find_cycle(), None);
+
+        let cyclic = graph(&[("a", "b"), ("b", "c"), ("c", "b")]);
+        let cycle = cyclic.find_cycle().unwrap();
+        let cycle: Vec<_> = cycle.iter().map(|x| cyclic.nodes[*x].as_str()).collect();
+        assert_eq!(cycle, ["b", "c", "b"]);
+
+        let self_loop = graph(&[("a", "a")]);
+        assert_eq!(self_loop.find_cycle(), Some(vec![0, 0]));
+    }
+}
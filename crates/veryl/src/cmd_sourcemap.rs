@@ -0,0 +1,220 @@
+use crate::OptSourcemap;
+use crate::runner::MangledNameMap;
+use miette::{IntoDiagnostic, Result, miette};
+use once_cell::sync::Lazy;
+use regex::{Captures, Regex};
+use std::collections::HashMap;
+use std::fs;
+use std::io::{BufRead, Write};
+use std::path::{Path, PathBuf};
+use veryl_metadata::{Metadata, Target};
+use veryl_sourcemap::SourceMap;
+
+pub struct CmdSourcemap {
+    opt: OptSourcemap,
+}
+
+impl CmdSourcemap {
+    pub fn new(opt: OptSourcemap) -> Self {
+        Self { opt }
+    }
+
+    pub fn exec(&self, metadata: &mut Metadata) -> Result<bool> {
+        if let Some(ref position) = self.opt.reverse {
+            self.reverse(metadata, position)
+        } else {
+            self.rewrite(metadata)
+        }
+    }
+
+    /// Rewrites references to the generated code in stdin to the Veryl sources
+    fn rewrite(&self, metadata: &Metadata) -> Result<bool> {
+        static RE: Lazy<Regex> = Lazy::new(|| {
+            Regex::new(
+                r"(?<path>[^\s:'\x22()\[\]]+\.(?:sv|v|vhd)):(?<line>[0-9]+)(?::(?<column>[0-9]+))?",
+            )
+            .unwrap()
+        });
+
+        let mangled_names = MangledNameMap::load(metadata);
+        let mut source_maps: HashMap<String, Option<SourceMap>> = HashMap::new();
+
+        let stdin = std::io::stdin();
+        let mut stdout = std::io::stdout().lock();
+        for line in stdin.lock().lines() {
+            let line = line.into_diagnostic()?;
+            let line = RE.replace_all(&line, |caps: &Captures| {
+                let path = &caps["path"];
+                let source_map = source_maps
+                    .entry(path.to_string())
+                    .or_insert_with(|| SourceMap::from_src(&PathBuf::from(path)).ok());
+
+                let line = caps["line"].parse::<u32>().unwrap();
+                let column = caps
+                    .name("column")
+                    .map(|x| x.as_str().parse::<u32>().unwrap());
+
+                if let Some(source_map) = source_map
+                    && let Some((path, line, column)) = source_map.lookup(line, column.unwrap_or(1))
+                {
+                    let path = relative_path(&path);
+                    format!("{}:{line}:{column}", path.to_string_lossy())
+                } else {
+                    caps[0].to_string()
+                }
+            });
+            let line = mangled_names.demangle(&line);
+            writeln!(stdout, "{line}").into_diagnostic()?;
+        }
+
+        Ok(true)
+    }
+
+    /// Prints ranges of the generated code corresponding to the Veryl source position
+    fn reverse(&self, metadata: &mut Metadata, position: &str) -> Result<bool> {
+        let (path, line, column) = parse_position(position)
+            .ok_or_else(|| miette!("Invalid position \"{position}\" (FILE:LINE[:COLUMN])"))?;
+
+        let paths = metadata.paths(&[&path], false)?;
+        let Some(path) = paths
+            .iter()
+            .find(|x| Some(&x.src) == fs::canonicalize(&path).ok().as_ref())
+        else {
+            return Err(miette!(
+                "{} is not a source of the project",
+                path.to_string_lossy()
+            ));
+        };
+
+        let source_maps = if let Target::Unit { path: unit_path } = &metadata.build.target {
+            unit_source_maps(metadata, unit_path, &path.src)
+        } else {
+            SourceMap::from_map(&path.map)
+                .map(|x| vec![(path.dst.clone(), x)])
+                .unwrap_or_default()
+        };
+        if source_maps.is_empty() {
+            return Err(miette!(
+                "Source map of {} is not found",
+                path.src.to_string_lossy()
+            ));
+        }
+
+        let mut ranges = Vec::new();
+        for (dst, source_map) in &source_maps {
+            let dst = relative_path(dst);
+            for x in source_map.lookup_reverse(line, column) {
+                println!(
+                    "{}:{}:{}-{}:{}",
+                    dst.to_string_lossy(),
+                    x.start_line,
+                    x.start_column,
+                    x.end_line,
+                    x.end_column
+                );
+                ranges.push(x);
+            }
+        }
+
+        Ok(!ranges.is_empty())
+    }
+}
+
+fn parse_position(position: &str) -> Option<(PathBuf, u32, Option<u32>)> {
+    let mut items = position.rsplitn(3, ':');
+    let last = items.next()?.parse::<u32>().ok().filter(|x| *x != 0)?;
+    let middle = items.next()?;
+    if let Ok(line) = middle.parse::<u32>()
+        && line != 0
+        && let Some(path) = items.next()
+    {
+        Some((PathBuf::from(path), line, Some(last)))
+    } else {
+        let path = if let Some(x) = items.next() {
+            format!("{x}:{middle}")
+        } else {
+            middle.to_string()
+        };
+        Some((PathBuf::from(path), last, None))
+    }
+}
+
+/// Returns source maps of units generated from the source, which are listed in the unit manifest
+fn unit_source_maps(
+    metadata: &Metadata,
+    unit_path: &Path,
+    src: &Path,
+) -> Vec<(PathBuf, SourceMap)> {
+    let base_path = metadata.project_path();
+    let manifest = base_path.join(unit_path).join("manifest.json");
+    let Ok(text) = fs::read_to_string(manifest) else {
+        return Vec::new();
+    };
+    let Ok(serde_json::Value::Array(units)) = serde_json::from_str(&text) else {
+        return Vec::new();
+    };
+
+    units
+        .iter()
+        .filter(|x| {
+            x["source"]
+                .as_str()
+                .is_some_and(|x| fs::canonicalize(base_path.join(x)).ok().as_deref() == Some(src))
+        })
+        .filter_map(|x| {
+            let dst = base_path.join(x["file"].as_str()?);
+            let source_map = SourceMap::from_src(&dst).ok()?;
+            Some((dst, source_map))
+        })
+        .collect()
+}
+
+fn relative_path(path: &Path) -> PathBuf {
+    if let Ok(current_dir) = std::env::current_dir().and_then(fs::canonicalize)
+        && let Ok(x) = path.strip_prefix(current_dir)
+    {
+        x.to_path_buf()
+    } else {
+        path.to_path_buf()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_position_line() {
+        assert_eq!(
+            parse_position("src/a.veryl:3"),
+            Some((PathBuf::from("src/a.veryl"), 3, None))
+        );
+    }
+
+    #[test]
+    fn parse_position_column() {
+        assert_eq!(
+            parse_position("src/a.veryl:3:5"),
+            Some((PathBuf::from("src/a.veryl"), 3, Some(5)))
+        );
+    }
+
+    #[test]
+    fn parse_position_drive() {
+        assert_eq!(
+            parse_position("C:/src/a.veryl:3"),
+            Some((PathBuf::from("C:/src/a.veryl"), 3, None))
+        );
+        assert_eq!(
+            parse_position("C:/src/a.veryl:3:5"),
+            Some((PathBuf::from("C:/src/a.veryl"), 3, Some(5)))
+        );
+    }
+
+    #[test]
+    fn parse_position_invalid() {
+        assert_eq!(parse_position("src/a.veryl"), None);
+        assert_eq!(parse_position("src/a.veryl:0"), None);
+        assert_eq!(parse_position("src/a.veryl:x"), None);
+    }
+}
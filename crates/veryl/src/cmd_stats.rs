@@ -0,0 +1,493 @@
+use crate::cmd_check::CheckError;
+use crate::{Format, OptStats};
+use log::info;
+use miette::{IntoDiagnostic, Result, WrapErr};
+use serde::Serialize;
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::fs;
+use veryl_analyzer::Analyzer;
+use veryl_analyzer::symbol::{Direction, Symbol, SymbolId, SymbolKind};
+use veryl_analyzer::symbol_table;
+use veryl_analyzer::var_ref::{AssignDeclarationType, AssignPositionType};
+use veryl_emitter::Emitter;
+use veryl_metadata::Metadata;
+use veryl_parser::Parser;
+use veryl_parser::resource_table::{self, StrId};
+
+pub struct CmdStats {
+    opt: OptStats,
+}
+
+#[derive(Debug, Default, Serialize)]
+struct Stats {
+    files: usize,
+    veryl_lines: usize,
+    sv_lines: usize,
+    modules: usize,
+    interfaces: usize,
+    packages: usize,
+    deepest_hierarchy: Vec<String>,
+    module_stats: Vec<ModuleStats>,
+}
+
+#[derive(Debug, Default, Serialize)]
+struct ModuleStats {
+    name: String,
+    input_ports: usize,
+    output_ports: usize,
+    inout_ports: usize,
+    interface_ports: usize,
+    input_bits: usize,
+    output_bits: usize,
+    /// Input ports whose width can't be determined, like ones of generic type
+    unresolved_inputs: usize,
+    /// Output ports whose width can't be determined, like ones of generic type
+    unresolved_outputs: usize,
+    register_bits: usize,
+    /// Registers whose width can't be determined, like ones of struct type
+    unresolved_registers: usize,
+    instances: usize,
+}
+
+impl CmdStats {
+    pub fn new(opt: OptStats) -> Self {
+        Self { opt }
+    }
+
+    pub fn exec(&self, metadata: &mut Metadata) -> Result<bool> {
+        let paths = metadata.paths::<&str>(&[], true)?;
+
+        let mut check_error = CheckError::default();
+        let mut contexts = Vec::new();
+
+        for path in &paths {
+            info!("Processing file ({})", path.src.to_string_lossy());
+
+            let input = fs::read_to_string(&path.src)
+                .into_diagnostic()
+                .wrap_err("")?;
+            let parser = Parser::parse(&input, &path.src)?;
+
+            let analyzer = Analyzer::new(metadata);
+            let mut errors = analyzer.analyze_pass1(&path.prj, &path.src, &parser.veryl);
+            check_error = check_error.append(&mut errors).check_err()?;
+
+            contexts.push((path, input, parser, analyzer));
+        }
+
+        let mut errors = Analyzer::analyze_post_pass1();
+        check_error = check_error.append(&mut errors).check_err()?;
+
+        for (path, _, parser, analyzer) in &contexts {
+            let mut errors = analyzer.analyze_pass2(&path.prj, &path.src, &parser.veryl);
+            check_error = check_error.append(&mut errors).check_err()?;
+        }
+
+        let info = Analyzer::analyze_post_pass2();
+
+        for (path, _, parser, analyzer) in &contexts {
+            let mut errors = analyzer.analyze_pass3(&path.prj, &path.src, &parser.veryl, &info);
+            check_error = check_error.append(&mut errors).check_err()?;
+        }
+
+        let mut stats = Stats::default();
+
+        for (path, input, parser, _) in &contexts {
+            if path.prj != metadata.project.name {
+                continue;
+            }
+
+            let mut emitter = Emitter::new(metadata, &path.src, &path.dst, &path.map);
+            emitter.emit(&path.prj, &parser.veryl);
+
+            stats.files += 1;
+            stats.veryl_lines += input.lines().count();
+            stats.sv_lines += emitter.as_str().lines().count();
+        }
+
+        collect_modules(&mut stats, &metadata.project.name);
+
+        match self.opt.format {
+            Format::Json => {
+                let text = serde_json::to_string(&stats).into_diagnostic()?;
+                println!("{text}");
+            }
+            Format::Pretty => print_table(&stats),
+        }
+
+        let _ = check_error.check_err()?;
+        Ok(true)
+    }
+}
+
+/// Collects statistics of modules, interfaces and packages of the analyzed project
+fn collect_modules(stats: &mut Stats, project_name: &str) {
+    let project = resource_table::insert_str(project_name);
+    let mut symbols: Vec<_> = symbol_table::get_all()
+        .into_iter()
+        .filter(|x| x.namespace.paths.first() == Some(&project))
+        .collect();
+    // Instances are visited in the declaration order to report the deepest hierarchy stably
+    symbols.sort_by_key(|x| x.id);
+
+    let mut modules = BTreeMap::new();
+    for symbol in &symbols {
+        match &symbol.kind {
+            SymbolKind::Module(x) => {
+                stats.modules += 1;
+
+                let mut module = ModuleStats {
+                    name: symbol.token.to_string(),
+                    ..Default::default()
+                };
+                for port in &x.ports {
+                    let (input, output) = match port.property().direction {
+                        Direction::Input => {
+                            module.input_ports += 1;
+                            (true, false)
+                        }
+                        Direction::Output => {
+                            module.output_ports += 1;
+                            (false, true)
+                        }
+                        Direction::Inout => {
+                            module.inout_ports += 1;
+                            (true, true)
+                        }
+                        _ => {
+                            module.interface_ports += 1;
+                            continue;
+                        }
+                    };
+                    if let Some(bits) = total_width(&port.symbol()) {
+                        module.input_bits += if input { bits } else { 0 };
+                        module.output_bits += if output { bits } else { 0 };
+                    } else {
+                        module.unresolved_inputs += input as usize;
+                        module.unresolved_outputs += output as usize;
+                    }
+                }
+                modules.insert(symbol.token.text, (symbol.id, module));
+            }
+            SymbolKind::Interface(_) => stats.interfaces += 1,
+            SymbolKind::Package(_) => stats.packages += 1,
+            _ => (),
+        }
+    }
+
+    // Register bits are gathered from targets assigned in `always_ff`
+    let mut registers = HashSet::new();
+    for assign in symbol_table::get_assign_list() {
+        let in_always_ff = assign.position.0.iter().any(|x| {
+            matches!(
+                x,
+                AssignPositionType::Declaration {
+                    r#type: AssignDeclarationType::AlwaysFF,
+                    ..
+                }
+            )
+        });
+        if in_always_ff && let Some(id) = assign.path.full_path().first() {
+            registers.insert(*id);
+        }
+    }
+    for id in registers {
+        if let Some(symbol) = symbol_table::get(id)
+            && let Some((_, module)) = owner_module(&symbol, project, &mut modules)
+        {
+            if let Some(bits) = total_width(&symbol) {
+                module.register_bits += bits;
+            } else {
+                module.unresolved_registers += 1;
+            }
+        }
+    }
+
+    let mut hierarchy: HashMap<SymbolId, Vec<(String, SymbolId)>> = HashMap::new();
+    for symbol in &symbols {
+        if let SymbolKind::Instance(x) = &symbol.kind {
+            let child = symbol_table::resolve((&x.type_name.mangled_path(), &symbol.namespace))
+                .ok()
+                .map(|x| match &x.found.kind {
+                    SymbolKind::GenericInstance(x) => x.base,
+                    _ => x.found.id,
+                });
+            if let Some((parent, module)) = owner_module(symbol, project, &mut modules) {
+                module.instances += 1;
+                if let Some(child) = child {
+                    hierarchy
+                        .entry(*parent)
+                        .or_default()
+                        .push((symbol.token.to_string(), child));
+                }
+            }
+        }
+    }
+
+    let mut memo = HashMap::new();
+    for (id, module) in modules.values() {
+        let mut path = deepest_path(*id, &hierarchy, &mut memo, &mut HashSet::new());
+        path.insert(0, module.name.clone());
+        if path.len() > stats.deepest_hierarchy.len() {
+            stats.deepest_hierarchy = path;
+        }
+    }
+
+    stats.module_stats = modules.into_values().map(|(_, x)| x).collect();
+}
+
+fn total_width(symbol: &Symbol) -> Option<usize> {
+    let evaluated = symbol.evaluate();
+    let width: usize = evaluated.get_width()?.iter().product();
+    let array: usize = evaluated.get_array()?.iter().product();
+    if width == 0 {
+        None
+    } else {
+        Some(width * array)
+    }
+}
+
+fn owner_module<'a>(
+    symbol: &Symbol,
+    project: StrId,
+    modules: &'a mut BTreeMap<StrId, (SymbolId, ModuleStats)>,
+) -> Option<&'a mut (SymbolId, ModuleStats)> {
+    if symbol.namespace.paths.first() != Some(&project) {
+        return None;
+    }
+    modules.get_mut(symbol.namespace.paths.get(1)?)
+}
+
+/// Returns instance names of the deepest hierarchy under the module
+fn deepest_path(
+    id: SymbolId,
+    hierarchy: &HashMap<SymbolId, Vec<(String, SymbolId)>>,
+    memo: &mut HashMap<SymbolId, Vec<String>>,
+    visiting: &mut HashSet<SymbolId>,
+) -> Vec<String> {
+    if let Some(x) = memo.get(&id) {
+        return x.clone();
+    }
+    // Recursive instances are reported by analyzer, so they are just cut here
+    if !visiting.insert(id) {
+        return vec![];
+    }
+
+    let mut ret = vec![];
+    if let Some(children) = hierarchy.get(&id) {
+        for (name, child) in children {
+            let mut path = deepest_path(*child, hierarchy, memo, visiting);
+            if path.len() + 1 > ret.len() {
+                path.insert(0, name.clone());
+                ret = path;
+            }
+        }
+    }
+
+    visiting.remove(&id);
+    memo.insert(id, ret.clone());
+    ret
+}
+
+fn print_table(stats: &Stats) {
+    println!("Files             : {}", stats.files);
+    println!("Lines (Veryl)     : {}", stats.veryl_lines);
+    println!("Lines (SV)        : {}", stats.sv_lines);
+    println!("Modules           : {}", stats.modules);
+    println!("Interfaces        : {}", stats.interfaces);
+    println!("Packages          : {}", stats.packages);
+    if !stats.deepest_hierarchy.is_empty() {
+        println!(
+            "Deepest hierarchy : {} (depth {})",
+            stats.deepest_hierarchy.join("."),
+            stats.deepest_hierarchy.len()
+        );
+    }
+
+    if stats.module_stats.is_empty() {
+        return;
+    }
+
+    let header = [
+        "Module",
+        "Input",
+        "Output",
+        "Inout",
+        "Interface",
+        "Input bits",
+        "Output bits",
+        "Register bits",
+        "Instances",
+    ];
+    let rows: Vec<[String; 9]> = stats
+        .module_stats
+        .iter()
+        .map(|x| {
+            let bits = |bits: usize, unresolved: usize| {
+                if unresolved == 0 {
+                    bits.to_string()
+                } else {
+                    format!("{bits} (+{unresolved} unknown)")
+                }
+            };
+            [
+                x.name.clone(),
+                x.input_ports.to_string(),
+                x.output_ports.to_string(),
+                x.inout_ports.to_string(),
+                x.interface_ports.to_string(),
+                bits(x.input_bits, x.unresolved_inputs),
+                bits(x.output_bits, x.unresolved_outputs),
+                bits(x.register_bits, x.unresolved_registers),
+                x.instances.to_string(),
+            ]
+        })
+        .collect();
+
+    let mut widths = header.map(|x| x.len());
+    for row in &rows {
+        for (width, cell) in widths.iter_mut().zip(row) {
+            *width = (*width).max(cell.len());
+        }
+    }
+
+    let line = |cells: Vec<&str>| {
+        let cells: Vec<_> = cells
+            .iter()
+            .zip(widths)
+            .enumerate()
+            .map(|(i, (cell, width))| {
+                if i == 0 {
+                    format!("{cell:<width$}")
+                } else {
+                    format!("{cell:>width$}")
+                }
+            })
+            .collect();
+        println!("{}", cells.join(" | ").trim_end());
+    };
+
+    println!();
+    line(header.to_vec());
+    println!(
+        "{}",
+        widths
+            .iter()
+            .map(|x| "-".repeat(*x))
+            .collect::<Vec<_>>()
+            .join("-+-")
+    );
+    for row in &rows {
+        line(row.iter().map(|x| x.as_str()).collect());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use veryl_analyzer::attribute_table;
+
+    fn stats(code: &str) -> Stats {
+        symbol_table::clear();
+        attribute_table::clear();
+
+        let metadata: Metadata = Metadata::create_default_toml("prj")
+            .unwrap()
+            .parse()
+            .unwrap();
+        let parser = Parser::parse(code, &"").unwrap();
+        let analyzer = Analyzer::new(&metadata);
+
+        analyzer.analyze_pass1("prj", "", &parser.veryl);
+        Analyzer::analyze_post_pass1();
+        analyzer.analyze_pass2("prj", "", &parser.veryl);
+        let info = Analyzer::analyze_post_pass2();
+        analyzer.analyze_pass3("prj", "", &parser.veryl, &info);
+
+        let mut ret = Stats::default();
+        collect_modules(&mut ret, "prj");
+        ret
+    }
+
+    fn module<'a>(stats: &'a Stats, name: &str) -> &'a ModuleStats {
+        stats.module_stats.iter().find(|x| x.name == name).unwrap()
+    }
+
+    #[test]
+    fn deepest_hierarchy() {
+        let code = r#"
+module Top {
+    inst u0: Mid;
+    inst u1: Leaf;
+}
+module Mid {
+    inst u2: Leaf;
+    inst u3: Leaf;
+}
+module Leaf {}
+"#;
+        let stats = stats(code);
+        assert_eq!(stats.modules, 3);
+        assert_eq!(stats.deepest_hierarchy, vec!["Top", "u0", "u2"]);
+        assert_eq!(module(&stats, "Top").instances, 2);
+        assert_eq!(module(&stats, "Mid").instances, 2);
+    }
+
+    #[test]
+    fn deepest_path_recursive() {
+        let mut hierarchy = HashMap::new();
+        hierarchy.insert(SymbolId(1), vec![("u0".to_string(), SymbolId(2))]);
+        hierarchy.insert(SymbolId(2), vec![("u1".to_string(), SymbolId(1))]);
+
+        let path = deepest_path(
+            SymbolId(1),
+            &hierarchy,
+            &mut HashMap::new(),
+            &mut HashSet::new(),
+        );
+        assert_eq!(path, vec!["u0", "u1"]);
+    }
+
+    #[test]
+    fn register_and_port_bits() {
+        let code = r#"
+package Pkg {
+    struct S {
+        a: logic<2>,
+    }
+}
+module Top (
+    i_clk: input  clock    ,
+    i_rst: input  reset    ,
+    i_a  : input  logic<8> ,
+    i_s  : input  Pkg::S   ,
+    o_b  : output logic<4> ,
+    o_s  : output Pkg::S   ,
+) {
+    var r: logic<4>;
+    var s: Pkg::S;
+    always_ff {
+        if_reset {
+            r = 0;
+            s = 0;
+        } else {
+            r = i_a[3:0];
+            s = i_s;
+        }
+    }
+    assign o_b = r;
+    assign o_s = s;
+}
+"#;
+        let stats = stats(code);
+        let top = module(&stats, "Top");
+        assert_eq!(top.input_ports, 4);
+        assert_eq!(top.output_ports, 2);
+        assert_eq!(top.input_bits, 10);
+        assert_eq!(top.output_bits, 4);
+        assert_eq!(top.unresolved_inputs, 1);
+        assert_eq!(top.unresolved_outputs, 1);
+        assert_eq!(top.register_bits, 4);
+        assert_eq!(top.unresolved_registers, 1);
+    }
+}
@@ -1,9 +1,20 @@
 use crate::cmd_build::CmdBuild;
-use crate::runner::{Cocotb, CocotbSource, Vcs, Verilator, Vivado};
-use crate::{OptBuild, OptTest};
+use crate::runner::{
+    Cocotb, CocotbSource, Custom, Icarus, RunnerMessage, TestArgs, Vcs, Verilator, Vivado,
+};
+use crate::utils;
+use crate::{MessageFormat, OptBuild, OptTest};
+use glob::Pattern;
 use log::{error, info};
-use miette::Result;
-use veryl_analyzer::symbol::{SymbolKind, TestType};
+use miette::{IntoDiagnostic, Result};
+use serde::Serialize;
+use std::fmt::Write as _;
+use std::io::Write as _;
+use std::path::PathBuf;
+use std::sync::Mutex;
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+use std::time::Instant;
+use veryl_analyzer::symbol::{SymbolKind, TestProperty, TestType};
 use veryl_analyzer::symbol_table;
 use veryl_metadata::{FilelistType, Metadata, SimType};
 
@@ -11,6 +22,48 @@ pub struct CmdTest {
     opt: OptTest,
 }
 
+/// Test run collected from the symbol table, which can be executed at any thread
+struct TestCase {
+    /// Name of the run including seed and parameters
+    name: String,
+    /// Name of the test module
+    test: String,
+    top: Option<String>,
+    args: TestArgs,
+    path: PathBuf,
+    cocotb: Option<CocotbSource>,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+enum TestStatus {
+    Passed,
+    Failed,
+    Timeout,
+    Skipped,
+}
+
+#[derive(Serialize)]
+struct TestResult {
+    name: String,
+    status: TestStatus,
+    /// Elapsed time in seconds
+    duration: f64,
+    /// Messages printed by the runner
+    messages: Vec<RunnerMessage>,
+    log: Vec<String>,
+}
+
+#[derive(Serialize)]
+struct TestReport<'a> {
+    project: &'a str,
+    passed: usize,
+    failed: usize,
+    skipped: usize,
+    duration: f64,
+    tests: &'a [TestResult],
+}
+
 impl CmdTest {
     pub fn new(opt: OptTest) -> Self {
         Self { opt }
@@ -19,19 +72,39 @@ impl CmdTest {
     pub fn exec(&self, metadata: &mut Metadata) -> Result<bool> {
         // force filelist_type to absolute which can be refered from temporary directory
         metadata.build.filelist_type = FilelistType::Absolute;
+        if let Some(x) = self.opt.timeout {
+            metadata.test.timeout = Some(x);
+        }
+
+        // positional arguments which are not Veryl sources are test name patterns
+        let (files, names): (Vec<_>, Vec<_>) = self
+            .opt
+            .files
+            .iter()
+            .partition(|x| x.extension().is_some_and(|x| x == "veryl") || x.exists());
+        let mut patterns = Vec::new();
+        for x in names {
+            patterns.push(Pattern::new(&x.to_string_lossy()).into_diagnostic()?);
+        }
 
         let build = CmdBuild::new(OptBuild {
-            files: self.opt.files.clone(),
+            files: files.into_iter().cloned().collect(),
             check: false,
+            message_format: MessageFormat::Human,
         });
         build.exec(metadata, true, false)?;
 
-        let tests: Vec<_> = symbol_table::get_all()
+        self.run(metadata, &patterns)
+    }
+
+    /// Runs tests matched with the patterns in the analyzed and built project
+    pub fn run(&self, metadata: &Metadata, patterns: &[Pattern]) -> Result<bool> {
+        let mut tests: Vec<_> = symbol_table::get_all()
             .into_iter()
             .filter_map(|symbol| {
                 if symbol.namespace.to_string() == metadata.project.name {
                     if let SymbolKind::Test(x) = symbol.kind {
-                        Some((symbol.token.text, x))
+                        Some((symbol.token.to_string(), x))
                     } else {
                         None
                     }
@@ -40,6 +113,13 @@ impl CmdTest {
                 }
             })
             .collect();
+        tests.sort_by(|a, b| a.0.cmp(&b.0));
+
+        let tests: Vec<_> = tests
+            .into_iter()
+            .flat_map(|(test, property)| expand_test(&test, &property))
+            .filter(|case| case.matches(patterns))
+            .collect();
 
         let sim_type = if let Some(x) = self.opt.sim {
             x.into()
@@ -47,32 +127,391 @@ impl CmdTest {
             metadata.test.simulator
         };
 
-        let mut success = 0;
-        let mut failure = 0;
-        for (test, property) in &tests {
-            let mut runner = match property.r#type {
-                TestType::Inline => match sim_type {
-                    SimType::Verilator => Verilator::new().runner(),
-                    SimType::Vcs => Vcs::new().runner(),
-                    SimType::Vivado => Vivado::new().runner(),
-                },
-                TestType::CocotbEmbed(x) => Cocotb::new(CocotbSource::Embed(x)).runner(),
-                TestType::CocotbInclude(x) => Cocotb::new(CocotbSource::Include(x)).runner(),
-            };
-
-            if runner.run(metadata, *test, property.top, property.path, self.opt.wave)? {
-                success += 1;
-            } else {
-                failure += 1;
-            }
+        let start = Instant::now();
+        let results = self.run_tests(metadata, &tests, sim_type)?;
+        let duration = start.elapsed().as_secs_f64();
+
+        let count = |status: &[TestStatus]| {
+            results
+                .iter()
+                .filter(|x| status.contains(&x.status))
+                .count()
+        };
+        let report = TestReport {
+            project: &metadata.project.name,
+            passed: count(&[TestStatus::Passed]),
+            failed: count(&[TestStatus::Failed, TestStatus::Timeout]),
+            skipped: count(&[TestStatus::Skipped]),
+            duration,
+            tests: &results,
+        };
+
+        if let Some(path) = &self.opt.junit {
+            utils::write_file_if_changed(path, junit(&report).as_bytes())?;
+            info!("Output JUnit report ({})", path.to_string_lossy());
+        }
+        if let Some(path) = &self.opt.json {
+            let text = serde_json::to_string_pretty(&report).into_diagnostic()?;
+            utils::write_file_if_changed(path, text.as_bytes())?;
+            info!("Output JSON report ({})", path.to_string_lossy());
         }
 
-        if failure == 0 {
-            info!("Completed tests : {success} passed, {failure} failed");
+        let skipped = if report.skipped == 0 {
+            String::new()
+        } else {
+            format!(", {} skipped", report.skipped)
+        };
+        if report.failed == 0 {
+            info!(
+                "Completed tests : {} passed, {} failed{skipped}",
+                report.passed, report.failed
+            );
             Ok(true)
         } else {
-            error!("Completed tests : {success} passed, {failure} failed");
+            error!(
+                "Completed tests : {} passed, {} failed{skipped}",
+                report.passed, report.failed
+            );
             Ok(false)
         }
     }
+
+    /// Runs tests by `--jobs` worker threads.
+    /// The remaining tests are skipped after a failure if `--fail-fast` is specified.
+    fn run_tests(
+        &self,
+        metadata: &Metadata,
+        tests: &[TestCase],
+        sim_type: SimType,
+    ) -> Result<Vec<TestResult>> {
+        let jobs = match self.opt.jobs {
+            0 => std::thread::available_parallelism().map_or(1, |x| x.get()),
+            x => x,
+        };
+        let buffered = jobs > 1;
+
+        let next = AtomicUsize::new(0);
+        let stop = AtomicBool::new(false);
+        let results: Mutex<Vec<Option<TestResult>>> =
+            Mutex::new(tests.iter().map(|_| None).collect());
+        let error = Mutex::new(None);
+
+        std::thread::scope(|s| {
+            for _ in 0..jobs.min(tests.len()) {
+                s.spawn(|| {
+                    while !stop.load(Ordering::Relaxed) {
+                        let i = next.fetch_add(1, Ordering::Relaxed);
+                        let Some(test) = tests.get(i) else {
+                            break;
+                        };
+                        match self.run_test(metadata, test, sim_type, buffered) {
+                            Ok(result) => {
+                                // Messages of a test are printed together when it finishes
+                                if buffered {
+                                    let mut stdout = std::io::stdout().lock();
+                                    for x in &result.messages {
+                                        let _ = writeln!(stdout, "{}", x.styled);
+                                    }
+                                }
+                                if result.status != TestStatus::Passed && self.opt.fail_fast {
+                                    stop.store(true, Ordering::Relaxed);
+                                }
+                                results.lock().unwrap()[i] = Some(result);
+                            }
+                            Err(x) => {
+                                stop.store(true, Ordering::Relaxed);
+                                error.lock().unwrap().get_or_insert(x);
+                            }
+                        }
+                    }
+                });
+            }
+        });
+
+        if let Some(x) = error.into_inner().unwrap() {
+            return Err(x);
+        }
+
+        let results = results
+            .into_inner()
+            .unwrap()
+            .into_iter()
+            .zip(tests)
+            .map(|(result, test)| {
+                result.unwrap_or_else(|| TestResult {
+                    name: test.name.clone(),
+                    status: TestStatus::Skipped,
+                    duration: 0.0,
+                    messages: Vec::new(),
+                    log: Vec::new(),
+                })
+            })
+            .collect();
+        Ok(results)
+    }
+
+    fn run_test(
+        &self,
+        metadata: &Metadata,
+        test: &TestCase,
+        sim_type: SimType,
+        buffered: bool,
+    ) -> Result<TestResult> {
+        let mut runner = match &test.cocotb {
+            None => match sim_type {
+                SimType::Verilator => Verilator::new().runner(),
+                SimType::Vcs => Vcs::new().runner(),
+                SimType::Vivado => Vivado::new().runner(),
+                SimType::Icarus => Icarus::new().runner(),
+                SimType::Custom => Custom::new().runner(),
+            },
+            Some(x) => Cocotb::new(x.clone()).runner(),
+        };
+        runner.output().buffered = buffered;
+
+        let start = Instant::now();
+        let success = runner.run(
+            metadata,
+            &test.test,
+            test.top.as_deref(),
+            &test.args,
+            &test.path,
+            self.opt.wave,
+        )?;
+        let duration = start.elapsed().as_secs_f64();
+
+        let output = std::mem::take(runner.output());
+
+        let status = if success {
+            TestStatus::Passed
+        } else if output.timeout {
+            TestStatus::Timeout
+        } else {
+            TestStatus::Failed
+        };
+
+        Ok(TestResult {
+            name: test.name.clone(),
+            status,
+            duration,
+            messages: output.messages,
+            log: output.log,
+        })
+    }
+}
+
+impl TestCase {
+    /// Returns whether the test or the run is matched with any of the patterns
+    fn matches(&self, patterns: &[Pattern]) -> bool {
+        patterns.is_empty()
+            || patterns
+                .iter()
+                .any(|x| x.matches(&self.test) || x.matches(&self.name))
+    }
+}
+
+/// Expands a test into runs of all combinations of parameters and seeds
+fn expand_test(test: &str, property: &TestProperty) -> Vec<TestCase> {
+    let mut runs = vec![TestArgs::default()];
+    for (name, values) in &property.params {
+        runs = runs
+            .iter()
+            .flat_map(|x| {
+                values.iter().map(|value| {
+                    let mut x = x.clone();
+                    x.params.push((name.to_string(), value.to_string()));
+                    x
+                })
+            })
+            .collect();
+    }
+    if let Some(seeds) = property.seeds
+        && seeds > 0
+    {
+        runs = runs
+            .iter()
+            .flat_map(|x| {
+                (1..=seeds).map(|seed| {
+                    let mut x = x.clone();
+                    x.seed = Some(seed);
+                    x
+                })
+            })
+            .collect();
+    }
+
+    let cocotb = match property.r#type {
+        TestType::Inline => None,
+        TestType::CocotbEmbed(x) => Some(CocotbSource::Embed(x.to_string())),
+        TestType::CocotbInclude(x) => Some(CocotbSource::Include(x.to_string())),
+    };
+
+    runs.into_iter()
+        .map(|args| TestCase {
+            name: args.name(test),
+            test: test.to_string(),
+            top: property.top.map(|x| x.to_string()),
+            args,
+            path: PathBuf::from(property.path.to_string()),
+            cocotb: cocotb.clone(),
+        })
+        .collect()
+}
+
+fn junit(report: &TestReport) -> String {
+    let mut ret = String::new();
+    let tests = report.tests.len();
+    let project = escape(report.project);
+    let attrs = format!(
+        r#"tests="{tests}" failures="{}" skipped="{}" time="{:.3}""#,
+        report.failed, report.skipped, report.duration
+    );
+
+    let _ = writeln!(ret, r#"<?xml version="1.0" encoding="UTF-8"?>"#);
+    let _ = writeln!(ret, r#"<testsuites name="{project}" {attrs}>"#);
+    let _ = writeln!(ret, r#"  <testsuite name="{project}" {attrs}>"#);
+    for x in report.tests {
+        let _ = writeln!(
+            ret,
+            r#"    <testcase name="{}" classname="{project}" time="{:.3}">"#,
+            escape(&x.name),
+            x.duration
+        );
+        match x.status {
+            TestStatus::Passed => (),
+            TestStatus::Failed => {
+                let _ = writeln!(ret, r#"      <failure message="failed"/>"#);
+            }
+            TestStatus::Timeout => {
+                let _ = writeln!(ret, r#"      <failure message="timed out"/>"#);
+            }
+            TestStatus::Skipped => {
+                let _ = writeln!(ret, r#"      <skipped/>"#);
+            }
+        }
+        if !x.log.is_empty() {
+            let _ = writeln!(
+                ret,
+                "      <system-out>{}</system-out>",
+                escape(&x.log.join("\n"))
+            );
+        }
+        let _ = writeln!(ret, "    </testcase>");
+    }
+    let _ = writeln!(ret, "  </testsuite>");
+    let _ = writeln!(ret, "</testsuites>");
+    ret
+}
+
+fn escape(text: &str) -> String {
+    // control characters like escape sequences of simulators are not allowed in XML
+    text.replace(
+        |x: char| x.is_control() && !matches!(x, '\t' | '\n' | '\r'),
+        "",
+    )
+    .replace('&', "&amp;")
+    .replace('<', "&lt;")
+    .replace('>', "&gt;")
+    .replace('"', "&quot;")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn case(test: &str, args: TestArgs) -> TestCase {
+        TestCase {
+            name: args.name(test),
+            test: test.to_string(),
+            top: None,
+            args,
+            path: PathBuf::new(),
+            cocotb: None,
+        }
+    }
+
+    fn result(name: &str, status: TestStatus, log: &[&str]) -> TestResult {
+        TestResult {
+            name: name.to_string(),
+            status,
+            duration: 0.5,
+            messages: Vec::new(),
+            log: log.iter().map(|x| x.to_string()).collect(),
+        }
+    }
+
+    #[test]
+    fn escape_xml() {
+        assert_eq!(escape(r#"a<b>&"c""#), "a&lt;b&gt;&amp;&quot;c&quot;");
+    }
+
+    #[test]
+    fn escape_control() {
+        assert_eq!(escape("\x1b[31mred\x1b[0m\tx\ny"), "[31mred[0m\tx\ny");
+    }
+
+    #[test]
+    fn junit_report() {
+        let tests = [
+            result("a", TestStatus::Passed, &[]),
+            result("b", TestStatus::Failed, &["x < y"]),
+            result("c", TestStatus::Timeout, &[]),
+            result("d", TestStatus::Skipped, &[]),
+        ];
+        let report = TestReport {
+            project: "prj",
+            passed: 1,
+            failed: 2,
+            skipped: 1,
+            duration: 2.0,
+            tests: &tests,
+        };
+        let expect = r#"<?xml version="1.0" encoding="UTF-8"?>
+<testsuites name="prj" tests="4" failures="2" skipped="1" time="2.000">
+  <testsuite name="prj" tests="4" failures="2" skipped="1" time="2.000">
+    <testcase name="a" classname="prj" time="0.500">
+    </testcase>
+    <testcase name="b" classname="prj" time="0.500">
+      <failure message="failed"/>
+      <system-out>x &lt; y</system-out>
+    </testcase>
+    <testcase name="c" classname="prj" time="0.500">
+      <failure message="timed out"/>
+    </testcase>
+    <testcase name="d" classname="prj" time="0.500">
+      <skipped/>
+    </testcase>
+  </testsuite>
+</testsuites>
+"#;
+        assert_eq!(junit(&report), expect);
+    }
+
+    #[test]
+    fn filter_empty() {
+        assert!(case("test_a", TestArgs::default()).matches(&[]));
+    }
+
+    #[test]
+    fn filter_test_name() {
+        let patterns = [Pattern::new("test_*").unwrap()];
+        assert!(case("test_a", TestArgs::default()).matches(&patterns));
+        assert!(!case("other", TestArgs::default()).matches(&patterns));
+    }
+
+    #[test]
+    fn filter_run_name() {
+        let args = TestArgs {
+            seed: Some(2),
+            params: Vec::new(),
+        };
+        let patterns = [Pattern::new("test_a[[]seed=2[]]").unwrap()];
+        assert!(case("test_a", args).matches(&patterns));
+
+        let args = TestArgs {
+            seed: Some(1),
+            params: Vec::new(),
+        };
+        assert!(!case("test_a", args).matches(&patterns));
+    }
 }
@@ -0,0 +1,385 @@
+use crate::cmd_build::CmdBuild;
+use crate::cmd_check::{CheckError, SyntaxErrors};
+use crate::cmd_test::CmdTest;
+use crate::{MessageFormat, OptBuild, OptTest, OptWatch};
+use glob::Pattern;
+use log::{info, warn};
+use miette::{IntoDiagnostic, Result};
+use notify::{Event, EventKind, RecursiveMode, Watcher};
+use std::collections::{BTreeMap, BTreeSet, HashSet};
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::mpsc;
+use std::time::Duration;
+use veryl_analyzer::Analyzer;
+use veryl_analyzer::dependency_graph::{self, DependencyGraph};
+use veryl_analyzer::symbol_table;
+use veryl_metadata::{FilelistType, Metadata};
+use veryl_parser::Parser;
+use veryl_parser::resource_table;
+use veryl_path::PathSet;
+
+const DEBOUNCE_TIME: Duration = Duration::from_millis(200);
+
+pub struct CmdWatch {
+    opt: OptWatch,
+}
+
+/// Parsed source which is kept between runs
+struct Source {
+    path: PathSet,
+    input: String,
+    parser: Parser,
+}
+
+/// Analysis state which is kept between runs
+#[derive(Default)]
+struct State {
+    sources: BTreeMap<PathBuf, Source>,
+    /// Files which should be analyzed again at the next run because of errors
+    pending: HashSet<PathBuf>,
+    graph: Option<DependencyGraph>,
+}
+
+impl CmdWatch {
+    pub fn new(opt: OptWatch) -> Self {
+        Self { opt }
+    }
+
+    pub fn exec(&self, metadata: &mut Metadata, quiet: bool) -> Result<bool> {
+        let mut patterns = Vec::new();
+        if self.opt.test {
+            // force filelist_type to absolute which can be refered from temporary directory
+            metadata.build.filelist_type = FilelistType::Absolute;
+
+            if self.opt.tests.is_empty() {
+                patterns.push(Pattern::new("*").into_diagnostic()?);
+            }
+            for x in &self.opt.tests {
+                patterns.push(Pattern::new(x).into_diagnostic()?);
+            }
+        }
+
+        let paths = metadata.paths(&[] as &[PathBuf], true)?;
+
+        let (tx, rx) = mpsc::channel();
+        let mut watcher = notify::recommended_watcher(tx).into_diagnostic()?;
+        for (dir, mode) in watch_dirs(metadata, &paths) {
+            watcher.watch(&dir, mode).into_diagnostic()?;
+        }
+
+        let mut state = State::default();
+        self.run(metadata, &mut state, &HashSet::new(), &patterns, quiet);
+
+        while let Ok(event) = rx.recv() {
+            // Gather events until a while passes without any event because saving a file
+            // may cause several events
+            let mut changed = HashSet::new();
+            let mut event = Some(event);
+            while let Some(x) = event {
+                match x {
+                    Ok(x) => changed.extend(changed_files(x)),
+                    Err(x) => warn!("Failed to watch files ({x})"),
+                }
+                event = rx.recv_timeout(DEBOUNCE_TIME).ok();
+            }
+
+            if !changed.is_empty() {
+                self.run(metadata, &mut state, &changed, &patterns, quiet);
+            }
+        }
+
+        Ok(true)
+    }
+
+    fn run(
+        &self,
+        metadata: &mut Metadata,
+        state: &mut State,
+        changed: &HashSet<PathBuf>,
+        patterns: &[Pattern],
+        quiet: bool,
+    ) {
+        if let Err(x) = self.run_once(metadata, state, changed, patterns, quiet) {
+            eprintln!("{x:?}");
+        }
+        info!("Waiting for changes");
+    }
+
+    fn run_once(
+        &self,
+        metadata: &mut Metadata,
+        state: &mut State,
+        changed: &HashSet<PathBuf>,
+        patterns: &[Pattern],
+        quiet: bool,
+    ) -> Result<()> {
+        let paths = metadata.paths(&[] as &[PathBuf], true)?;
+        let Some(check_error) = self.analyze(metadata, state, &paths, changed)? else {
+            return Ok(());
+        };
+
+        if self.opt.build || !patterns.is_empty() {
+            let build = CmdBuild::new(OptBuild {
+                files: Vec::new(),
+                check: false,
+                message_format: MessageFormat::Human,
+            });
+            let sources: Vec<_> = paths
+                .iter()
+                .filter_map(|x| state.sources.get(&x.src))
+                .map(|x| (&x.path, x.input.as_str(), &x.parser.veryl))
+                .collect();
+            let ret = build.emit(metadata, &paths, &sources, !patterns.is_empty(), quiet);
+            metadata.save_build_info()?;
+            ret?;
+        }
+
+        if !patterns.is_empty() {
+            let test = CmdTest::new(OptTest {
+                files: Vec::new(),
+                sim: self.opt.sim,
+                wave: false,
+                jobs: self.opt.jobs,
+                fail_fast: false,
+                timeout: None,
+                junit: None,
+                json: None,
+            });
+            test.run(metadata, patterns)?;
+        }
+
+        // Report warnings after build and test
+        let _ = check_error.check_all()?;
+        Ok(())
+    }
+
+    /// Analyzes changed files and files depending on them, and returns `None` if no file is changed.
+    /// Unchanged files are not parsed again, and their results of pass1 are reused.
+    fn analyze(
+        &self,
+        metadata: &Metadata,
+        state: &mut State,
+        paths: &[PathSet],
+        changed: &HashSet<PathBuf>,
+    ) -> Result<Option<CheckError>> {
+        let mut dirty = BTreeSet::new();
+
+        // Removed files
+        let current: HashSet<_> = paths.iter().map(|x| &x.src).collect();
+        let removed: Vec<_> = state
+            .sources
+            .keys()
+            .filter(|x| !current.contains(x))
+            .cloned()
+            .collect();
+        let is_removed = !removed.is_empty();
+        for src in removed {
+            state.sources.remove(&src);
+            state.pending.remove(&src);
+            if let Some(path_id) = resource_table::get_path_id(src) {
+                if let Some(graph) = &mut state.graph {
+                    dirty.extend(graph.dependents(path_id));
+                    graph.remove(path_id);
+                }
+                Analyzer::drop_file(path_id);
+            }
+        }
+
+        // Parse new and changed files
+        let mut syntax_errors = SyntaxErrors::default();
+        let mut reparsed = Vec::new();
+        for path in paths {
+            let current = state.sources.get(&path.src);
+            if current.is_some()
+                && !changed.contains(&path.src)
+                && !state.pending.contains(&path.src)
+            {
+                continue;
+            }
+
+            let input = fs::read_to_string(&path.src).into_diagnostic()?;
+            if current.is_some_and(|x| x.input == input) && !state.pending.contains(&path.src) {
+                continue;
+            }
+
+            info!("Processing file ({})", path.src.to_string_lossy());
+
+            let (parser, errors) = Parser::parse_with_recovery(&input, &path.src);
+            if let (Some(parser), true) = (parser, errors.is_empty()) {
+                state.pending.remove(&path.src);
+                reparsed.push(Source {
+                    path: path.clone(),
+                    input,
+                    parser,
+                });
+            } else {
+                state.pending.insert(path.src.clone());
+                syntax_errors.append(errors);
+            }
+        }
+
+        if !syntax_errors.related.is_empty() {
+            state
+                .pending
+                .extend(reparsed.into_iter().map(|x| x.path.src));
+            return Err(syntax_errors.into());
+        }
+
+        // Nothing to do if only timestamps of files are changed
+        if reparsed.is_empty() && !is_removed && state.graph.is_some() {
+            return Ok(None);
+        }
+
+        let analyzer = Analyzer::new(metadata);
+        let mut check_error = CheckError::default();
+
+        // pass1 of reparsed files
+        let mut snapshots = Vec::new();
+        for mut source in reparsed {
+            // Nothing has been analyzed at the first run
+            if state.graph.is_some()
+                && let Some(path_id) = resource_table::get_path_id(source.path.src.clone())
+            {
+                let signature = dependency_graph::file_signature(path_id);
+                let references = dependency_graph::external_references(path_id);
+                Analyzer::drop_file(path_id);
+                snapshots.push((path_id, signature, references));
+
+                // The text of the file is dropped too, so it should be registered again
+                source.parser = Parser::parse(&source.input, &source.path.src)?;
+            }
+
+            let path = &source.path;
+            let mut errors = analyzer.analyze_pass1(&path.prj, &path.src, &source.parser.veryl);
+            check_error = check_error.append(&mut errors);
+            state.sources.insert(path.src.clone(), source);
+        }
+
+        // Errors of post_pass1 are ignored if pass1 failed because they may be duplicated
+        let mut errors = Analyzer::analyze_post_pass1();
+        if !check_error.has_error() {
+            check_error = check_error.append(&mut errors);
+        }
+
+        let graph = state.graph.get_or_insert_with(|| {
+            let mut graph = DependencyGraph::new();
+            graph.rebuild();
+            graph
+        });
+        for (path_id, signature, references) in &snapshots {
+            dependency_graph::restore_references(*path_id, references.clone());
+            graph.update(*path_id);
+            if *signature != dependency_graph::file_signature(*path_id) {
+                dirty.extend(graph.dependents(*path_id));
+            }
+        }
+
+        // pass1 of files depending on the changed symbols
+        let mut snapshots_dependents = Vec::new();
+        for path_id in dirty {
+            if snapshots.iter().any(|x| x.0 == path_id) {
+                continue;
+            }
+            let Some(source) =
+                resource_table::get_path_value(path_id).and_then(|x| state.sources.get_mut(&x))
+            else {
+                continue;
+            };
+
+            let references = dependency_graph::external_references(path_id);
+            Analyzer::drop_file(path_id);
+            snapshots_dependents.push((path_id, references));
+            source.parser = Parser::parse(&source.input, &source.path.src)?;
+
+            let path = &source.path;
+            let mut errors = analyzer.analyze_pass1(&path.prj, &path.src, &source.parser.veryl);
+            check_error = check_error.append(&mut errors);
+        }
+
+        if !snapshots_dependents.is_empty() {
+            let mut errors = Analyzer::analyze_post_pass1();
+            if !check_error.has_error() {
+                check_error = check_error.append(&mut errors);
+            }
+
+            for (path_id, references) in snapshots_dependents {
+                dependency_graph::restore_references(path_id, references);
+                graph.update(path_id);
+            }
+        }
+
+        check_error = match check_error.check_err() {
+            Ok(x) => x,
+            Err(x) => {
+                state.pending.extend(
+                    snapshots
+                        .iter()
+                        .filter_map(|x| resource_table::get_path_value(x.0)),
+                );
+                return Err(x);
+            }
+        };
+
+        // pass2 and pass3 of all files because they may refer changed symbols indirectly
+        symbol_table::clear_var_ref_list();
+        let sources: Vec<_> = paths
+            .iter()
+            .filter_map(|x| state.sources.get(&x.src))
+            .collect();
+
+        for source in &sources {
+            let path = &source.path;
+            let mut errors = analyzer.analyze_pass2(&path.prj, &path.src, &source.parser.veryl);
+            check_error = check_error.append(&mut errors).check_err()?;
+        }
+
+        let info = Analyzer::analyze_post_pass2();
+
+        for source in &sources {
+            let path = &source.path;
+            let mut errors =
+                analyzer.analyze_pass3(&path.prj, &path.src, &source.parser.veryl, &info);
+            check_error = check_error.append(&mut errors).check_err()?;
+        }
+
+        Ok(Some(check_error))
+    }
+}
+
+fn changed_files(event: Event) -> Vec<PathBuf> {
+    if matches!(
+        event.kind,
+        EventKind::Create(_) | EventKind::Modify(_) | EventKind::Remove(_)
+    ) {
+        event
+            .paths
+            .into_iter()
+            .filter(|x| x.extension().is_some_and(|x| x == "veryl"))
+            .collect()
+    } else {
+        Vec::new()
+    }
+}
+
+/// Directories to be watched: the source directory of the project and directories of dependencies
+fn watch_dirs(metadata: &Metadata, paths: &[PathSet]) -> Vec<(PathBuf, RecursiveMode)> {
+    let src_base = metadata.project_path().join(&metadata.build.source);
+
+    let mut dirs = BTreeSet::new();
+    for path in paths {
+        if !path.src.starts_with(&src_base)
+            && let Some(x) = path.src.parent()
+        {
+            dirs.insert(x.to_path_buf());
+        }
+    }
+
+    let mut ret = vec![(src_base, RecursiveMode::Recursive)];
+    ret.extend(
+        dirs.into_iter()
+            .filter(|x| Path::exists(x))
+            .map(|x| (x, RecursiveMode::NonRecursive)),
+    );
+    ret
+}
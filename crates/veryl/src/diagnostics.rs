@@ -0,0 +1,228 @@
+use crate::MessageFormat;
+use miette::{Diagnostic, LabeledSpan, Report, Result, Severity, SourceCode, SourceSpan};
+use serde::Serialize;
+use serde_json::json;
+use std::collections::BTreeMap;
+use std::path::Path;
+
+/// Diagnostic converted for machine-readable outputs
+#[derive(Serialize)]
+pub struct Record {
+    pub code: Option<String>,
+    pub severity: &'static str,
+    pub message: String,
+    pub help: Option<String>,
+    pub url: Option<String>,
+    pub span: Option<Span>,
+    pub secondary_spans: Vec<Span>,
+}
+
+/// Source position which lines and columns are 1-based
+#[derive(Serialize)]
+pub struct Span {
+    pub file: String,
+    pub line: usize,
+    pub column: usize,
+    pub end_line: usize,
+    pub end_column: usize,
+    pub label: Option<String>,
+}
+
+/// Prints diagnostics of the result by the specified format.
+/// Errors are printed as records instead of being returned if the format is machine-readable.
+pub fn emit(ret: Result<bool>, format: MessageFormat) -> Result<bool> {
+    let (ret, records) = match (format, ret) {
+        (MessageFormat::Human, ret) => return ret,
+        (_, Ok(x)) => (x, Vec::new()),
+        (_, Err(x)) => (false, records(&x)),
+    };
+
+    if let MessageFormat::Sarif = format {
+        let sarif = serde_json::to_string_pretty(&sarif(&records)).unwrap();
+        println!("{sarif}");
+    } else {
+        for x in &records {
+            println!("{}", serde_json::to_string(x).unwrap());
+        }
+    }
+
+    Ok(ret)
+}
+
+/// Flattens diagnostics in the report like `CheckError` which has related diagnostics
+pub fn records(report: &Report) -> Vec<Record> {
+    let mut ret = Vec::new();
+    collect(report.as_ref(), &mut ret);
+    ret
+}
+
+fn collect(diag: &dyn Diagnostic, records: &mut Vec<Record>) {
+    if let Some(related) = diag.related() {
+        let len = records.len();
+        for x in related {
+            collect(x, records);
+        }
+        if records.len() != len {
+            return;
+        }
+    }
+    records.push(record(diag));
+}
+
+fn record(diag: &dyn Diagnostic) -> Record {
+    let severity = match diag.severity() {
+        Some(Severity::Advice) => "advice",
+        Some(Severity::Warning) => "warning",
+        Some(Severity::Error) | None => "error",
+    };
+
+    let non_empty = |x: String| if x.is_empty() { None } else { Some(x) };
+
+    let mut spans = Vec::new();
+    if let (Some(source), Some(labels)) = (diag.source_code(), diag.labels()) {
+        for label in labels {
+            if let Some(x) = span(source, &label) {
+                spans.push(x);
+            }
+        }
+    }
+    let span = if spans.is_empty() {
+        None
+    } else {
+        Some(spans.remove(0))
+    };
+
+    Record {
+        code: diag.code().map(|x| x.to_string()),
+        severity,
+        message: diag.to_string(),
+        help: diag.help().map(|x| x.to_string()).and_then(non_empty),
+        url: diag.url().map(|x| x.to_string()).and_then(non_empty),
+        span,
+        secondary_spans: spans,
+    }
+}
+
+fn span(source: &dyn SourceCode, label: &LabeledSpan) -> Option<Span> {
+    let (file, line, column) = position(source, label.offset())?;
+
+    // The end is exclusive, and the offset just after the span may be out of the source
+    let (end_line, end_column) = if label.is_empty() {
+        (line, column)
+    } else {
+        let (_, line, column) = position(source, label.offset() + label.len() - 1)?;
+        (line, column + 1)
+    };
+
+    Some(Span {
+        file,
+        line,
+        column,
+        end_line,
+        end_column,
+        label: label.label().map(|x| x.to_string()),
+    })
+}
+
+fn position(source: &dyn SourceCode, offset: usize) -> Option<(String, usize, usize)> {
+    let contents = source
+        .read_span(&SourceSpan::new(offset.into(), 0), 0, 0)
+        .ok()?;
+    let file = contents.name().unwrap_or_default().to_string();
+    Some((file, contents.line() + 1, contents.column() + 1))
+}
+
+/// Static Analysis Results Interchange Format (SARIF) 2.1.0
+fn sarif(records: &[Record]) -> serde_json::Value {
+    let mut rules = BTreeMap::new();
+    for x in records {
+        if let Some(code) = &x.code {
+            rules.entry(code.clone()).or_insert_with(|| {
+                let mut rule = json!({ "id": code });
+                if let Some(url) = &x.url {
+                    rule["helpUri"] = json!(url);
+                }
+                rule
+            });
+        }
+    }
+
+    let location = |x: &Span| {
+        json!({
+            "physicalLocation": {
+                "artifactLocation": { "uri": uri(&x.file) },
+                "region": {
+                    "startLine": x.line,
+                    "startColumn": x.column,
+                    "endLine": x.end_line,
+                    "endColumn": x.end_column,
+                }
+            }
+        })
+    };
+
+    let results: Vec<_> = records
+        .iter()
+        .map(|x| {
+            let level = match x.severity {
+                "error" => "error",
+                "warning" => "warning",
+                _ => "note",
+            };
+            let mut result = json!({
+                "level": level,
+                "message": { "text": x.message },
+                "locations": x.span.iter().map(location).collect::<Vec<_>>(),
+                "relatedLocations": x
+                    .secondary_spans
+                    .iter()
+                    .enumerate()
+                    .map(|(i, span)| {
+                        let mut ret = location(span);
+                        ret["id"] = json!(i);
+                        if let Some(label) = &span.label {
+                            ret["message"] = json!({ "text": label });
+                        }
+                        ret
+                    })
+                    .collect::<Vec<_>>(),
+            });
+            if let Some(code) = &x.code {
+                result["ruleId"] = json!(code);
+            }
+            if let Some(help) = &x.help {
+                result["properties"] = json!({ "help": help });
+            }
+            result
+        })
+        .collect();
+
+    json!({
+        "$schema": "https://json.schemastore.org/sarif-2.1.0.json",
+        "version": "2.1.0",
+        "runs": [{
+            "tool": {
+                "driver": {
+                    "name": "veryl",
+                    "version": env!("CARGO_PKG_VERSION"),
+                    "informationUri": "https://veryl-lang.org/",
+                    "rules": rules.into_values().collect::<Vec<_>>(),
+                }
+            },
+            "results": results,
+        }]
+    })
+}
+
+/// Paths under the current directory are relative because code scanning services
+/// resolve them from the root of the repository
+fn uri(file: &str) -> String {
+    let path = Path::new(file);
+    if let Ok(dir) = std::env::current_dir()
+        && let Ok(x) = path.strip_prefix(dir)
+    {
+        x.to_string_lossy().replace('\\', "/")
+    } else {
+        file.replace('\\', "/")
+    }
+}
@@ -6,14 +6,21 @@ pub mod cmd_check;
 pub mod cmd_clean;
 pub mod cmd_doc;
 pub mod cmd_dump;
+pub mod cmd_explain;
+pub mod cmd_fix;
 pub mod cmd_fmt;
+pub mod cmd_graph;
 pub mod cmd_init;
 pub mod cmd_metadata;
 pub mod cmd_migrate;
 pub mod cmd_new;
 pub mod cmd_publish;
+pub mod cmd_sourcemap;
+pub mod cmd_stats;
 pub mod cmd_test;
 pub mod cmd_update;
+pub mod cmd_watch;
+pub mod diagnostics;
 pub mod diff;
 pub mod doc;
 pub mod runner;
@@ -66,6 +73,7 @@ pub enum Commands {
     New(OptNew),
     Init(OptInit),
     Fmt(OptFmt),
+    Fix(OptFix),
     Check(OptCheck),
     Build(OptBuild),
     Clean(OptClean),
@@ -76,6 +84,11 @@ pub enum Commands {
     Metadata(OptMetadata),
     Dump(OptDump),
     Test(OptTest),
+    Sourcemap(OptSourcemap),
+    Watch(OptWatch),
+    Explain(OptExplain),
+    Stats(OptStats),
+    Graph(OptGraph),
 }
 
 /// Create a new project
@@ -102,11 +115,26 @@ pub struct OptFmt {
     pub check: bool,
 }
 
+/// Apply machine-applicable suggestions of diagnostics to the current project
+#[derive(Args)]
+pub struct OptFix {
+    /// Target files
+    pub files: Vec<PathBuf>,
+
+    /// Show the diff of fixes without writing files
+    #[arg(long)]
+    pub dry_run: bool,
+}
+
 /// Analyze the current project
 #[derive(Args)]
 pub struct OptCheck {
     /// Target files
     pub files: Vec<PathBuf>,
+
+    /// Output format of diagnostics
+    #[arg(long, value_enum, default_value_t)]
+    pub message_format: MessageFormat,
 }
 
 /// Build the target codes corresponding to the current project
@@ -118,6 +146,21 @@ pub struct OptBuild {
     /// Run build in check mode
     #[arg(long)]
     pub check: bool,
+
+    /// Output format of diagnostics
+    #[arg(long, value_enum, default_value_t)]
+    pub message_format: MessageFormat,
+}
+
+#[derive(Clone, Copy, Default, Debug, ValueEnum)]
+pub enum MessageFormat {
+    /// Rendered diagnostics for humans
+    #[default]
+    Human,
+    /// A JSON object per diagnostic
+    Json,
+    /// SARIF 2.1.0 log
+    Sarif,
 }
 
 /// Clean-up the current project
@@ -157,7 +200,7 @@ pub struct OptDoc {
 /// Execute tests
 #[derive(Args)]
 pub struct OptTest {
-    /// Target files
+    /// Target files or patterns of test names (e.g. `fifo_*`)
     pub files: Vec<PathBuf>,
 
     /// Simulator
@@ -167,6 +210,26 @@ pub struct OptTest {
     /// Dump waveform
     #[arg(long)]
     pub wave: bool,
+
+    /// Number of tests executed in parallel (0 means the number of CPUs)
+    #[arg(long, short, default_value_t = 1)]
+    pub jobs: usize,
+
+    /// Stop after the first failed test
+    #[arg(long)]
+    pub fail_fast: bool,
+
+    /// Timeout of each test in seconds
+    #[arg(long, value_name = "SECONDS")]
+    pub timeout: Option<u64>,
+
+    /// Write the results as JUnit XML
+    #[arg(long, value_name = "PATH")]
+    pub junit: Option<PathBuf>,
+
+    /// Write the results as JSON
+    #[arg(long, value_name = "PATH")]
+    pub json: Option<PathBuf>,
 }
 
 #[derive(Clone, Copy, Debug, ValueEnum)]
@@ -177,6 +240,11 @@ pub enum SimType {
     Vcs,
     /// AMD Vivado Simulator
     Vivado,
+    /// Icarus Verilog
+    #[value(name = "iverilog")]
+    Icarus,
+    /// Simulator configured by [test.custom]
+    Custom,
 }
 
 impl From<SimType> for veryl_metadata::SimType {
@@ -185,6 +253,8 @@ impl From<SimType> for veryl_metadata::SimType {
             SimType::Verilator => veryl_metadata::SimType::Verilator,
             SimType::Vcs => veryl_metadata::SimType::Vcs,
             SimType::Vivado => veryl_metadata::SimType::Vivado,
+            SimType::Icarus => veryl_metadata::SimType::Icarus,
+            SimType::Custom => veryl_metadata::SimType::Custom,
         }
     }
 }
@@ -225,6 +295,92 @@ pub enum Format {
     Json,
 }
 
+/// Rewrite references to the generated code in stdin to the Veryl sources
+#[derive(Args)]
+pub struct OptSourcemap {
+    /// Print ranges of the generated code corresponding to the Veryl source position instead
+    #[arg(long, value_name = "FILE:LINE[:COLUMN]")]
+    pub reverse: Option<String>,
+}
+
+/// Watch changes of the current project, and check, build or test it continuously
+#[derive(Args)]
+pub struct OptWatch {
+    /// Build the target codes after check
+    #[arg(long)]
+    pub build: bool,
+
+    /// Run tests after build
+    #[arg(long)]
+    pub test: bool,
+
+    /// Patterns of test names run by `--test` (e.g. `fifo_*`), or all tests are run if omitted
+    #[arg(requires = "test")]
+    pub tests: Vec<String>,
+
+    /// Simulator
+    #[arg(long, value_enum)]
+    pub sim: Option<SimType>,
+
+    /// Number of tests executed in parallel (0 means the number of CPUs)
+    #[arg(long, short, default_value_t = 1)]
+    pub jobs: usize,
+}
+
+/// Show the detailed explanation of a diagnostic code
+#[derive(Args)]
+pub struct OptExplain {
+    /// Diagnostic code (e.g. `multiple_assignment`), or all codes are listed if omitted
+    pub code: Option<String>,
+}
+
+/// Show the design statistics of the current project
+#[derive(Args)]
+pub struct OptStats {
+    /// output format
+    #[arg(long, value_enum, default_value_t)]
+    pub format: Format,
+}
+
+/// Output the instance or dependency graph of the current project
+#[derive(Args)]
+pub struct OptGraph {
+    /// Kind of graph
+    #[arg(value_enum, default_value_t)]
+    pub kind: GraphKind,
+
+    /// Output format
+    #[arg(long, value_enum, default_value_t)]
+    pub format: GraphFormat,
+
+    /// Root node of the graph
+    #[arg(long)]
+    pub root: Option<String>,
+
+    /// Maximum depth from the root
+    #[arg(long)]
+    pub depth: Option<usize>,
+}
+
+#[derive(Clone, Copy, Default, Debug, ValueEnum)]
+pub enum GraphKind {
+    /// Module instantiation hierarchy
+    #[default]
+    Hierarchy,
+    /// Package import graph
+    Import,
+    /// Project dependency graph from the lockfile
+    Dependency,
+}
+
+#[derive(Clone, Copy, Default, Debug, ValueEnum)]
+pub enum GraphFormat {
+    #[default]
+    Dot,
+    Mermaid,
+    Json,
+}
+
 /// Dump debug info
 #[derive(Args)]
 pub struct OptDump {
@@ -73,7 +73,7 @@ fn main() -> Result<ExitCode> {
         .into_diagnostic()?;
 
     let (mut metadata, dot_build_lock) = match opt.command {
-        Commands::New(_) | Commands::Init(_) => {
+        Commands::New(_) | Commands::Init(_) | Commands::Explain(_) => {
             // dummy metadata
             let metadata = Metadata::create_default_toml("dummy").unwrap();
             (Metadata::from_str(&metadata)?, None)
@@ -94,11 +94,17 @@ fn main() -> Result<ExitCode> {
         Commands::New(x) => cmd_new::CmdNew::new(x).exec()?,
         Commands::Init(x) => cmd_init::CmdInit::new(x).exec()?,
         Commands::Fmt(x) => cmd_fmt::CmdFmt::new(x).exec(&mut metadata, opt.quiet)?,
-        Commands::Check(x) => cmd_check::CmdCheck::new(x).exec(&mut metadata)?,
+        Commands::Fix(x) => cmd_fix::CmdFix::new(x).exec(&mut metadata, opt.quiet)?,
+        Commands::Check(x) => {
+            let format = x.message_format;
+            let ret = cmd_check::CmdCheck::new(x).exec(&mut metadata);
+            diagnostics::emit(ret, format)?
+        }
         Commands::Build(x) => {
+            let format = x.message_format;
             let ret = cmd_build::CmdBuild::new(x).exec(&mut metadata, false, opt.quiet);
             metadata.save_build_info()?;
-            ret?
+            diagnostics::emit(ret, format)?
         }
         Commands::Clean(x) => cmd_clean::CmdClean::new(x).exec(&mut metadata)?,
         Commands::Update(x) => cmd_update::CmdUpdate::new(x).exec(&mut metadata)?,
@@ -108,6 +114,11 @@ fn main() -> Result<ExitCode> {
         Commands::Metadata(x) => cmd_metadata::CmdMetadata::new(x).exec(&metadata)?,
         Commands::Dump(x) => cmd_dump::CmdDump::new(x).exec(&mut metadata)?,
         Commands::Test(x) => cmd_test::CmdTest::new(x).exec(&mut metadata)?,
+        Commands::Sourcemap(x) => cmd_sourcemap::CmdSourcemap::new(x).exec(&mut metadata)?,
+        Commands::Watch(x) => cmd_watch::CmdWatch::new(x).exec(&mut metadata, opt.quiet)?,
+        Commands::Explain(x) => cmd_explain::CmdExplain::new(x).exec()?,
+        Commands::Stats(x) => cmd_stats::CmdStats::new(x).exec(&mut metadata)?,
+        Commands::Graph(x) => cmd_graph::CmdGraph::new(x).exec(&mut metadata)?,
     };
 
     if let Some(dot_build_lock) = dot_build_lock {
@@ -1,30 +1,116 @@
 use anstyle::{AnsiColor, Style};
-use log::{Level, debug, log_enabled};
+use log::{Level, debug, error, log_enabled};
 use miette::{IntoDiagnostic, Result};
 use once_cell::sync::Lazy;
-use regex::Regex;
+use regex::{Captures, Regex};
+use serde::Serialize;
+use std::collections::HashMap;
 use std::fs;
 use std::path::{Path, PathBuf};
+use std::time::Duration;
+use tokio::time::Instant;
 use veryl_metadata::{Metadata, WaveFormTarget};
-use veryl_parser::resource_table::{PathId, StrId};
 use veryl_sourcemap::SourceMap;
 
 mod cocotb;
+mod custom;
+mod icarus;
 mod vcs;
 mod verilator;
 mod vivado;
 pub use cocotb::*;
+pub use custom::*;
+pub use icarus::*;
 pub use vcs::*;
 pub use verilator::*;
 pub use vivado::*;
 
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum MessageLevel {
+    Info,
+    Warning,
+    Error,
+}
+
+/// Message of a test run, which is reported with its level
+#[derive(Clone, Debug, Serialize)]
+pub struct RunnerMessage {
+    pub level: MessageLevel,
+    pub text: String,
+    /// Text decorated by the style for terminals
+    #[serde(skip)]
+    pub styled: String,
+}
+
+/// Messages and logs of a test run
+#[derive(Default)]
+pub struct RunnerOutput {
+    /// Messages are kept until the test finishes instead of being printed immediately
+    pub buffered: bool,
+    pub messages: Vec<RunnerMessage>,
+    /// Raw outputs of the simulator
+    pub log: Vec<String>,
+    pub timeout: bool,
+}
+
+/// Seed and parameter overrides of a test run expanded by `seeds` and `params` of `#[test]`
+#[derive(Clone, Debug, Default)]
+pub struct TestArgs {
+    pub seed: Option<usize>,
+    pub params: Vec<(String, String)>,
+}
+
+impl TestArgs {
+    fn labels(&self) -> Vec<String> {
+        let mut ret: Vec<_> = self
+            .params
+            .iter()
+            .map(|(name, value)| format!("{name}={value}"))
+            .collect();
+        if let Some(x) = self.seed {
+            ret.push(format!("seed={x}"));
+        }
+        ret
+    }
+
+    /// Name of the run reported in logs and results like `test[WIDTH=8,seed=1]`
+    pub fn name(&self, test: &str) -> String {
+        let labels = self.labels();
+        if labels.is_empty() {
+            test.to_string()
+        } else {
+            format!("{test}[{}]", labels.join(","))
+        }
+    }
+
+    /// Parameter overrides with hierarchical paths from the top module like `top.WIDTH`
+    pub fn param_overrides(&self, top: &str) -> Vec<(String, String)> {
+        self.params
+            .iter()
+            .map(|(name, value)| (format!("{top}.{name}"), value.clone()))
+            .collect()
+    }
+
+    /// Name of the waveform file which is unique for each run
+    pub fn wave_name(&self, test: &str) -> String {
+        let mut ret = test.to_string();
+        for x in self.labels() {
+            ret.push('_');
+            ret.push_str(&x.replace(|x: char| !x.is_ascii_alphanumeric() && x != '=', "_"));
+        }
+        ret
+    }
+}
+
 pub trait Runner {
     fn run(
         &mut self,
         metadata: &Metadata,
-        test: StrId,
-        top: Option<StrId>,
-        path: PathId,
+        test: &str,
+        top: Option<&str>,
+        args: &TestArgs,
+        path: &Path,
         wave: bool,
     ) -> Result<bool>;
 
@@ -32,17 +118,33 @@ pub trait Runner {
 
     fn failure(&mut self);
 
-    fn debug(&self, line: &str) {
+    fn output(&mut self) -> &mut RunnerOutput;
+
+    fn print(&mut self, line: &str, style: &Style, level: MessageLevel) {
+        let styled = format!("{}{}{}", style.render(), line, style.render_reset());
+        let output = self.output();
+        if !output.buffered {
+            println!("{styled}");
+        }
+        output.messages.push(RunnerMessage {
+            level,
+            text: line.to_string(),
+            styled,
+        });
+    }
+
+    fn debug(&mut self, line: &str) {
         if log_enabled!(Level::Debug) {
             debug!("{} : {}", self.name(), line);
         }
+        self.output().log.push(line.to_string());
     }
 
-    fn info(&self, line: &str) {
+    fn info(&mut self, line: &str) {
         static STYLE: Lazy<Style> =
             Lazy::new(|| Style::new().fg_color(Some(AnsiColor::Green.into())));
         if !log_enabled!(Level::Debug) {
-            println!("{}{}{}", STYLE.render(), line, STYLE.render_reset());
+            self.print(line, &STYLE, MessageLevel::Info);
         }
     }
 
@@ -50,7 +152,7 @@ pub trait Runner {
         static STYLE: Lazy<Style> =
             Lazy::new(|| Style::new().fg_color(Some(AnsiColor::Yellow.into())));
         if !log_enabled!(Level::Debug) {
-            println!("{}{}{}", STYLE.render(), line, STYLE.render_reset());
+            self.print(line, &STYLE, MessageLevel::Warning);
         }
     }
 
@@ -58,7 +160,7 @@ pub trait Runner {
         static STYLE: Lazy<Style> =
             Lazy::new(|| Style::new().fg_color(Some(AnsiColor::Red.into())));
         if !log_enabled!(Level::Debug) {
-            println!("{}{}{}", STYLE.render(), line, STYLE.render_reset());
+            self.print(line, &STYLE, MessageLevel::Error);
         }
         self.failure();
     }
@@ -67,17 +169,103 @@ pub trait Runner {
         static STYLE: Lazy<Style> =
             Lazy::new(|| Style::new().fg_color(Some(AnsiColor::Red.into())).bold());
         if !log_enabled!(Level::Debug) {
-            println!("{}{}{}", STYLE.render(), line, STYLE.render_reset());
+            self.print(line, &STYLE, MessageLevel::Error);
         }
         self.failure();
     }
+
+    /// Marks the test as timed out, and returns the result of the test
+    fn timeout(&mut self, test: &str) -> bool {
+        self.output().timeout = true;
+        self.failure();
+        error!("Timed out test ({test})");
+        false
+    }
+}
+
+/// Name of the top module of the test in the generated code.
+/// The top module specified by `#[test]` is a Veryl module emitted with the project prefix,
+/// and the module of an inline test is embedded as is.
+pub fn top_module(metadata: &Metadata, test: &str, top: Option<&str>) -> String {
+    match top {
+        Some(x) if metadata.build.omit_project_prefix => x.to_string(),
+        Some(x) => format!("{}_{x}", metadata.project.name),
+        None => test.to_string(),
+    }
 }
 
-pub fn remap_msg_by_regex(line: &str, re: &Regex) -> String {
-    let mut ret = line.to_string();
+/// Deadline of the test started now
+pub fn deadline(metadata: &Metadata) -> Option<Instant> {
+    metadata
+        .test
+        .timeout
+        .map(|x| Instant::now() + Duration::from_secs(x))
+}
+
+/// Waits the future until the deadline, and returns `false` if the deadline has passed.
+/// Child processes should be spawned with `kill_on_drop` to be killed at the timeout.
+pub async fn wait_until(
+    deadline: Option<Instant>,
+    future: impl Future<Output = Result<()>>,
+) -> Result<bool> {
+    if let Some(deadline) = deadline {
+        match tokio::time::timeout_at(deadline, future).await {
+            Ok(x) => x.map(|_| true),
+            Err(_) => Ok(false),
+        }
+    } else {
+        future.await.map(|_| true)
+    }
+}
+
+/// Map from hashed names of generic instances to their Veryl paths
+#[derive(Default)]
+pub struct MangledNameMap {
+    re: Option<Regex>,
+    map: HashMap<String, String>,
+}
+
+impl MangledNameMap {
+    pub fn load(metadata: &Metadata) -> Self {
+        if !metadata.build.hashed_mangled_name {
+            return Self::default();
+        }
+
+        let Ok(text) = fs::read_to_string(metadata.mangled_name_map_path()) else {
+            return Self::default();
+        };
+        let Ok(map) = serde_json::from_str::<HashMap<String, String>>(&text) else {
+            return Self::default();
+        };
+        if map.is_empty() {
+            return Self::default();
+        }
+
+        // Longer names are matched first because a name may be a prefix of another one
+        let mut names: Vec<_> = map.keys().map(|x| regex::escape(x)).collect();
+        names.sort_by_key(|x| std::cmp::Reverse(x.len()));
+        let re = Regex::new(&format!(r"\b(?:{})\b", names.join("|"))).ok();
+
+        Self { re, map }
+    }
+
+    pub fn demangle(&self, line: &str) -> String {
+        if let Some(re) = &self.re {
+            re.replace_all(line, |caps: &Captures| self.map[&caps[0]].clone())
+                .into_owned()
+        } else {
+            line.to_string()
+        }
+    }
+}
+
+pub fn remap_msg_by_regex(line: &str, re: &Regex, mangled_names: &MangledNameMap) -> String {
+    let mut ret = mangled_names.demangle(line);
 
     if let Some(caps) = re.captures(line) {
+        // The caret is placed at the location in the demangled message
         let start = caps.get(0).unwrap().start();
+        let start = mangled_names.demangle(&line[..start]).chars().count();
         let path = caps.name("path").unwrap().as_str().to_string();
         let line = caps.name("line").unwrap().as_str().parse::<u32>().unwrap();
         let column = caps
@@ -101,8 +289,9 @@ pub fn remap_msg_by_regex(line: &str, re: &Regex) -> String {
 }
 
 pub fn copy_wave(
-    test_name: StrId,
-    test_path: PathId,
+    test_name: &str,
+    args: &TestArgs,
+    test_path: &Path,
     metadata: &Metadata,
     work_path: &Path,
 ) -> Result<()> {
@@ -112,15 +301,12 @@ pub fn copy_wave(
     // but let's rename the target file to the correct extension, based on the selected format
     let target_name = format!(
         "{}.{}",
-        test_name,
+        args.wave_name(test_name),
         metadata.test.waveform_format.extension()
     );
 
     let wave_dst_path = match &metadata.test.waveform_target {
-        WaveFormTarget::Target => PathBuf::from(test_path.to_string())
-            .parent()
-            .unwrap()
-            .join(target_name),
+        WaveFormTarget::Target => test_path.parent().unwrap().join(target_name),
         WaveFormTarget::Directory { path } => path.join(target_name),
     };
 
@@ -132,3 +318,127 @@ pub fn copy_wave(
     fs::copy(wave_src_path, wave_dst_path).into_diagnostic()?;
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use veryl_analyzer::{Analyzer, attribute_table, symbol_table};
+    use veryl_emitter::Emitter;
+    use veryl_parser::Parser;
+
+    fn emit(metadata: &Metadata, code: &str) -> String {
+        symbol_table::clear();
+        attribute_table::clear();
+
+        let parser = Parser::parse(code, &"").unwrap();
+        let analyzer = Analyzer::new(metadata);
+
+        analyzer.analyze_pass1("prj", "", &parser.veryl);
+        Analyzer::analyze_post_pass1();
+        analyzer.analyze_pass2("prj", "", &parser.veryl);
+
+        let mut emitter = Emitter::new(
+            metadata,
+            &PathBuf::from("test.veryl"),
+            &PathBuf::from("test.sv"),
+            &PathBuf::from("test.sv.map"),
+        );
+        emitter.emit("prj", &parser.veryl);
+        emitter.as_str().to_string()
+    }
+
+    #[test]
+    fn remap_msg_demangled() {
+        let dir = tempfile::tempdir().unwrap();
+        let src_path = dir.path().join("test.veryl");
+        let dst_path = dir.path().join("test.sv");
+        let map_path = dir.path().join("test.sv.map");
+
+        let mut source_map = SourceMap::new(&src_path, &dst_path, &map_path);
+        source_map.add(3, 1, 5, 9, "a");
+        source_map.build();
+        fs::write(&src_path, "").unwrap();
+        fs::write(&map_path, source_map.to_bytes().unwrap()).unwrap();
+        fs::write(&dst_path, format!("\n\n\n{}\n", source_map.get_link())).unwrap();
+
+        let mangled_names = MangledNameMap {
+            re: Some(Regex::new(r"\b__prj_Module__0123abcd\b").unwrap()),
+            map: HashMap::from([(
+                "__prj_Module__0123abcd".to_string(),
+                "prj::Module::<prj::Package::<8>>".to_string(),
+            )]),
+        };
+        let re = Regex::new(r"(?<path>[^: ]+):(?<line>[0-9]+):").unwrap();
+        let line = format!(
+            "%Error: __prj_Module__0123abcd {}:3: error",
+            dst_path.to_string_lossy()
+        );
+
+        let ret = remap_msg_by_regex(&line, &re, &mangled_names);
+        let (message, from) = ret.split_once('\n').unwrap();
+        assert_eq!(
+            message,
+            format!(
+                "%Error: prj::Module::<prj::Package::<8>> {}:3: error",
+                dst_path.to_string_lossy()
+            )
+        );
+
+        let column = from.find('^').unwrap();
+        assert_eq!(Some(column), message.find(&*dst_path.to_string_lossy()));
+        let src_path = fs::canonicalize(&src_path).unwrap();
+        assert!(from.ends_with(&format!("^ from: {}:5:9", src_path.to_string_lossy())));
+    }
+
+    fn module_names(text: &str) -> Vec<&str> {
+        text.lines()
+            .filter_map(|x| x.trim().strip_prefix("module "))
+            .filter_map(|x| {
+                x.split(|x: char| !x.is_ascii_alphanumeric() && x != '_')
+                    .next()
+            })
+            .collect()
+    }
+
+    #[test]
+    fn param_override_path() {
+        let code = r#"module Top #(
+    param WIDTH: u32 = 1,
+) {}
+
+#[test(test_top, Top, params(WIDTH = [8]))]
+embed (inline) sv{{{
+module test_top;
+endmodule
+}}}
+
+#[test(test_inline, params(WIDTH = [8]))]
+embed (inline) sv{{{
+module test_inline #(parameter int WIDTH = 1);
+endmodule
+}}}
+"#;
+
+        let args = TestArgs {
+            seed: None,
+            params: vec![("WIDTH".to_string(), "8".to_string())],
+        };
+
+        for omit_project_prefix in [false, true] {
+            let mut metadata: Metadata = Metadata::create_default_toml("prj")
+                .unwrap()
+                .parse()
+                .unwrap();
+            metadata.build.omit_project_prefix = omit_project_prefix;
+            let text = emit(&metadata, code);
+            let modules = module_names(&text);
+
+            for (test, top) in [("test_top", Some("Top")), ("test_inline", None)] {
+                let top = top_module(&metadata, test, top);
+                let overrides = args.param_overrides(&top);
+                assert_eq!(overrides, vec![(format!("{top}.WIDTH"), "8".to_string())]);
+                assert!(modules.contains(&top.as_str()), "{top} in {modules:?}");
+            }
+        }
+    }
+}
@@ -1,15 +1,15 @@
-use crate::runner::{Runner, copy_wave};
+use crate::runner::{Runner, RunnerOutput, TestArgs, copy_wave, deadline, top_module, wait_until};
 use futures::prelude::*;
 use log::{error, info};
 use miette::{IntoDiagnostic, Result, WrapErr};
 use std::fs::{self, OpenOptions};
 use std::io::Write;
+use std::path::Path;
 use std::process::Stdio;
 use tokio::process::{Child, Command};
 use tokio::runtime::Runtime;
 use tokio_util::codec::{FramedRead, LinesCodec};
 use veryl_metadata::{Metadata, WaveFormFormat};
-use veryl_parser::resource_table::{self, PathId, StrId};
 
 #[derive(Copy, Clone, Debug, PartialEq, Eq)]
 enum State {
@@ -20,15 +20,17 @@ enum State {
     Fatal,
 }
 
+#[derive(Clone)]
 pub enum CocotbSource {
-    Embed(StrId),
-    Include(StrId),
+    Embed(String),
+    Include(String),
 }
 
 pub struct Cocotb {
     source: CocotbSource,
     state: State,
     success: bool,
+    output: RunnerOutput,
 }
 
 impl Cocotb {
@@ -37,6 +39,7 @@ impl Cocotb {
             source,
             state: State::Idle,
             success: true,
+            output: RunnerOutput::default(),
         }
     }
 
@@ -116,16 +119,18 @@ impl Runner for Cocotb {
     fn run(
         &mut self,
         metadata: &Metadata,
-        test: StrId,
-        top: Option<StrId>,
-        path: PathId,
+        test: &str,
+        top: Option<&str>,
+        args: &TestArgs,
+        path: &Path,
         wave: bool,
     ) -> Result<bool> {
         self.success = true;
+        let name = args.name(test);
 
         let temp_dir = tempfile::tempdir().into_diagnostic()?;
 
-        info!("Executing test ({test})");
+        info!("Executing test ({name})");
 
         let src_path = temp_dir.path().join(format!("{test}.py"));
 
@@ -151,9 +156,9 @@ impl Runner for Cocotb {
             }
         }
 
-        match self.source {
+        match &self.source {
             CocotbSource::Embed(x) => {
-                let src_text = x.to_string();
+                let src_text = x.as_str();
                 let src_text = src_text.strip_prefix("{{{").unwrap();
                 let src_text = src_text.strip_suffix("}}}").unwrap();
 
@@ -167,11 +172,7 @@ impl Runner for Cocotb {
                 file.flush().into_diagnostic()?;
             }
             CocotbSource::Include(x) => {
-                let include_path = resource_table::get_path_value(path).unwrap();
-                let include_path = include_path
-                    .parent()
-                    .unwrap()
-                    .join(x.to_string().trim_matches('"'));
+                let include_path = path.parent().unwrap().join(x.trim_matches('"'));
                 fs::copy(include_path, src_path).into_diagnostic()?;
             }
         }
@@ -183,9 +184,9 @@ impl Runner for Cocotb {
         }
         sources = format!("[{}]", sources.strip_suffix(',').unwrap());
 
-        let module = format!("{}_{}", metadata.project.name, top.unwrap());
+        let module = top_module(metadata, test, top);
 
-        let (py_waves, args) = match wave.then_some(metadata.test.waveform_format) {
+        let (py_waves, build_args) = match wave.then_some(metadata.test.waveform_format) {
             Some(WaveFormFormat::Vcd) => ("True", "['--trace']"),
             Some(WaveFormFormat::Fst) => (
                 "True",
@@ -193,6 +194,23 @@ impl Runner for Cocotb {
             ),
             None => ("False", "[]"),
         };
+        // Rust debug strings are also valid Python string literals
+        let params: Vec<_> = args
+            .params
+            .iter()
+            .map(|(name, value)| format!("{name:?}: {value:?}"))
+            .collect();
+        let params = format!("{{{}}}", params.join(", "));
+        let (defines, seed, plusargs) = if let Some(x) = args.seed {
+            (
+                format!("{{\"__veryl_seed__\": {x}}}"),
+                x.to_string(),
+                format!("[\"+veryl_seed={x}\"]"),
+            )
+        } else {
+            ("{}".to_string(), "None".to_string(), "[]".to_string())
+        };
+
         let runner_path = temp_dir.path().join("runner.py");
         let runner_text = format!(
             r#"
@@ -207,13 +225,17 @@ runner.build(
     hdl_toplevel="{module}",
     always=True,
     waves={py_waves},
-    build_args={args},
+    build_args={build_args},
+    parameters={params},
+    defines={defines},
 )
 
 runner.test(
     hdl_toplevel="{module}",
     test_module="{test},",
     waves={py_waves},
+    seed={seed},
+    plusargs={plusargs},
 )
 "#
         );
@@ -228,19 +250,24 @@ runner.test(
         file.flush().into_diagnostic()?;
 
         let rt = Runtime::new().unwrap();
+        let deadline = deadline(metadata);
 
-        rt.block_on(async {
+        let finished = rt.block_on(async {
             let compile = Command::new("python3")
                 .arg("runner.py")
                 .current_dir(temp_dir.path())
                 .stdout(Stdio::piped())
                 .stderr(Stdio::piped())
+                .kill_on_drop(true)
                 .spawn()
                 .into_diagnostic()
                 .wrap_err("Failed to run \"python3\"")?;
 
-            self.parse(compile).await
+            wait_until(deadline, self.parse(compile)).await
         })?;
+        if !finished {
+            return Ok(self.timeout(&name));
+        }
 
         if wave {
             // `copy_wave` expects the waveform at a certain position and format
@@ -250,17 +277,17 @@ runner.test(
                     .join("sim_build")
                     .join("dump")
                     .with_extension(metadata.test.waveform_format.extension()),
-                temp_dir.path().join(test.to_string()).with_extension("vcd"),
+                temp_dir.path().join(test).with_extension("vcd"),
             )
             .into_diagnostic()?;
-            copy_wave(test, path, metadata, temp_dir.path())?;
+            copy_wave(test, args, path, metadata, temp_dir.path())?;
         }
 
         if self.success {
-            info!("Succeeded test ({test})");
+            info!("Succeeded test ({name})");
             Ok(true)
         } else {
-            error!("Failed test ({test})");
+            error!("Failed test ({name})");
             Ok(false)
         }
     }
@@ -272,4 +299,8 @@ runner.test(
     fn failure(&mut self) {
         self.success = false;
     }
+
+    fn output(&mut self) -> &mut RunnerOutput {
+        &mut self.output
+    }
 }
@@ -0,0 +1,365 @@
+use crate::runner::{
+    MangledNameMap, Runner, RunnerOutput, TestArgs, copy_wave, deadline, remap_msg_by_regex,
+    top_module, wait_until,
+};
+use futures::prelude::*;
+use log::{error, info};
+use miette::{IntoDiagnostic, Result, WrapErr};
+use once_cell::sync::Lazy;
+use regex::Regex;
+use std::path::Path;
+use std::process::Stdio;
+use tokio::process::{Child, Command};
+use tokio::runtime::Runtime;
+use tokio_util::codec::{FramedRead, LinesCodec};
+use veryl_metadata::{CustomProperty, Metadata};
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+enum State {
+    Compile,
+    Elaborate,
+    Simulate,
+}
+
+impl State {
+    fn name(self) -> &'static str {
+        match self {
+            State::Compile => "compile",
+            State::Elaborate => "elaborate",
+            State::Simulate => "simulate",
+        }
+    }
+}
+
+pub struct Custom {
+    state: State,
+    success: bool,
+    passed: bool,
+    property: CustomProperty,
+    mangled_names: MangledNameMap,
+    output: RunnerOutput,
+}
+
+/// Values of placeholders in command templates
+struct Placeholder {
+    filelist: String,
+    /// Top module in the generated code, which has the project prefix if it is a Veryl module
+    top: String,
+    test: String,
+    project: String,
+    defines: Vec<String>,
+    params: Vec<String>,
+    plusargs: Vec<String>,
+    seed: String,
+    wave: String,
+    work: String,
+}
+
+impl Placeholder {
+    fn expand(&self, template: &str) -> Result<Vec<String>> {
+        let Some(args) = shlex::split(template) else {
+            miette::bail!("Failed to parse command \"{template}\"");
+        };
+
+        let mut ret = Vec::new();
+        for arg in args {
+            match arg.as_str() {
+                "{defines}" => ret.extend(self.defines.iter().cloned()),
+                "{params}" => ret.extend(self.params.iter().cloned()),
+                "{plusargs}" => ret.extend(self.plusargs.iter().cloned()),
+                _ => {
+                    let expanded = arg
+                        .replace("{filelist}", &self.filelist)
+                        .replace("{top}", &self.top)
+                        .replace("{test}", &self.test)
+                        .replace("{project}", &self.project)
+                        .replace("{defines}", &self.defines.join(" "))
+                        .replace("{params}", &self.params.join(" "))
+                        .replace("{plusargs}", &self.plusargs.join(" "))
+                        .replace("{seed}", &self.seed)
+                        .replace("{wave}", &self.wave)
+                        .replace("{work}", &self.work);
+
+                    // e.g. `{seed}` is empty if the test is not expanded by seeds
+                    if !expanded.is_empty() || arg.is_empty() {
+                        ret.push(expanded);
+                    }
+                }
+            }
+        }
+        Ok(ret)
+    }
+}
+
+impl Custom {
+    pub fn new() -> Self {
+        Self {
+            state: State::Compile,
+            success: true,
+            passed: false,
+            property: CustomProperty::default(),
+            mangled_names: MangledNameMap::default(),
+            output: RunnerOutput::default(),
+        }
+    }
+
+    pub fn runner(self) -> Box<dyn Runner> {
+        Box::new(self) as Box<dyn Runner>
+    }
+
+    fn remap_msg(&self, line: &str) -> String {
+        static RE: Lazy<Regex> = Lazy::new(|| {
+            Regex::new(r"(?<path>[^\s:'\x22()]+\.s?v):(?<line>[0-9]+)(?::(?<column>[0-9]+))?")
+                .unwrap()
+        });
+
+        let re = self.property.re_location.as_ref().unwrap_or(&RE);
+        remap_msg_by_regex(line, re, &self.mangled_names)
+    }
+
+    fn parse_line(&mut self, line: &str) {
+        self.debug(line);
+
+        let is_match = |re: &Option<Regex>| re.as_ref().is_some_and(|x| x.is_match(line));
+
+        if self.state == State::Simulate {
+            if is_match(&self.property.re_pass) {
+                self.passed = true;
+            }
+            if is_match(&self.property.re_fail) {
+                self.error(&self.remap_msg(line));
+                return;
+            }
+        }
+
+        if is_match(&self.property.re_fatal) {
+            self.fatal(&self.remap_msg(line));
+        } else if is_match(&self.property.re_error) {
+            self.error(&self.remap_msg(line));
+        } else if is_match(&self.property.re_warning) {
+            self.warning(&self.remap_msg(line));
+        } else if is_match(&self.property.re_info) {
+            self.info(&self.remap_msg(line));
+        }
+    }
+
+    async fn parse(&mut self, mut child: Child) -> Result<()> {
+        let stdout = child.stdout.take().unwrap();
+        let mut reader = FramedRead::new(stdout, LinesCodec::new());
+        while let Some(line) = reader.next().await {
+            let line = line.into_diagnostic()?;
+            self.parse_line(&line);
+        }
+
+        let stderr = child.stderr.take().unwrap();
+        let mut reader = FramedRead::new(stderr, LinesCodec::new());
+        while let Some(line) = reader.next().await {
+            let line = line.into_diagnostic()?;
+            self.parse_line(&line);
+        }
+
+        // Unlike the builtin simulators, exit status is the only reliable result
+        // if no regex is specified.
+        let status = child.wait().await.into_diagnostic()?;
+        if !status.success() {
+            self.error(&format!(
+                "{} command exited with {status}",
+                self.state.name()
+            ));
+        }
+        Ok(())
+    }
+
+    /// Executes a command template of the current state.
+    /// Returns `false` if the deadline is exceeded.
+    fn execute(
+        &mut self,
+        rt: &Runtime,
+        template: &str,
+        placeholder: &Placeholder,
+        work_path: &Path,
+        deadline: Option<tokio::time::Instant>,
+    ) -> Result<bool> {
+        let args = placeholder.expand(template)?;
+        let Some((program, args)) = args.split_first() else {
+            miette::bail!("Empty {} command in [test.custom]", self.state.name());
+        };
+
+        rt.block_on(async {
+            let child = Command::new(program)
+                .args(args)
+                .current_dir(work_path)
+                .stdout(Stdio::piped())
+                .stderr(Stdio::piped())
+                .kill_on_drop(true)
+                .spawn()
+                .into_diagnostic()
+                .wrap_err(format!("Failed to run \"{program}\""))?;
+
+            wait_until(deadline, self.parse(child)).await
+        })
+    }
+}
+
+impl Default for Custom {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Runner for Custom {
+    fn run(
+        &mut self,
+        metadata: &Metadata,
+        test: &str,
+        top: Option<&str>,
+        args: &TestArgs,
+        path: &Path,
+        wave: bool,
+    ) -> Result<bool> {
+        self.success = true;
+        let name = args.name(test);
+        self.passed = false;
+        self.mangled_names = MangledNameMap::load(metadata);
+
+        let property = metadata.test.custom.clone();
+        self.property = property.clone();
+        if property.compile.is_none() && property.elaborate.is_none() && property.simulate.is_none()
+        {
+            miette::bail!("No command is specified in [test.custom]");
+        }
+
+        let temp_dir = tempfile::tempdir().into_diagnostic()?;
+
+        for include_file in &metadata.test.include_files {
+            if include_file.is_dir() {
+                miette::bail!("Including directories currently unsupported");
+            } else if let Some(file_name) = include_file.iter().next_back() {
+                let target_path = temp_dir.path().join(file_name);
+                if std::fs::copy(include_file, &target_path).is_err() {
+                    miette::bail!(
+                        "Failed to copy include {:?} to {:?}",
+                        include_file,
+                        target_path
+                    )
+                }
+            } else {
+                miette::bail!("Failed to get include file name {:?}", include_file);
+            }
+        }
+
+        let define_format = property
+            .define_format
+            .as_deref()
+            .unwrap_or("+define+{define}");
+
+        let mut defines = vec![format!("__veryl_test_{}_{}__", metadata.project.name, test)];
+        if wave {
+            defines.push(format!(
+                "__veryl_wavedump_{}_{}__",
+                metadata.project.name, test
+            ));
+        }
+
+        let mut plusargs = vec![];
+        if let Some(x) = args.seed {
+            defines.push(format!("__veryl_seed__={x}"));
+            plusargs.push(format!("+veryl_seed={x}"));
+        }
+
+        let param_format = property
+            .param_format
+            .as_deref()
+            .unwrap_or("-G{name}={value}");
+
+        let placeholder = Placeholder {
+            filelist: metadata.filelist_path().to_string_lossy().to_string(),
+            top: top_module(metadata, test, top),
+            test: test.to_string(),
+            project: metadata.project.name.clone(),
+            defines: defines
+                .iter()
+                .map(|x| define_format.replace("{define}", x))
+                .collect(),
+            params: args
+                .params
+                .iter()
+                .map(|(name, value)| {
+                    param_format
+                        .replace("{name}", name)
+                        .replace("{value}", value)
+                })
+                .collect(),
+            plusargs,
+            seed: args.seed.map(|x| x.to_string()).unwrap_or_default(),
+            // `$dumpfile` in the generated code always uses this name
+            wave: format!("{test}.vcd"),
+            work: temp_dir.path().to_string_lossy().to_string(),
+        };
+
+        let rt = Runtime::new().unwrap();
+        let deadline = deadline(metadata);
+
+        let stages = [
+            (State::Compile, &property.compile),
+            (State::Elaborate, &property.elaborate),
+            (State::Simulate, &property.simulate),
+        ];
+
+        for (state, template) in stages {
+            let Some(template) = template else {
+                continue;
+            };
+
+            self.state = state;
+            match state {
+                State::Compile => info!("Compiling test ({name})"),
+                State::Elaborate => info!("Elaborating test ({name})"),
+                State::Simulate => info!("Executing test ({name})"),
+            }
+
+            let finished = self.execute(&rt, template, &placeholder, temp_dir.path(), deadline)?;
+            if !finished {
+                return Ok(self.timeout(&name));
+            }
+
+            if !self.success {
+                match state {
+                    State::Compile => error!("Failed compile ({name})"),
+                    State::Elaborate => error!("Failed elaborate ({name})"),
+                    State::Simulate => break,
+                }
+                return Ok(false);
+            }
+        }
+
+        // A failed simulation may be terminated before the waveform is dumped
+        if wave && (self.success || temp_dir.path().join(&placeholder.wave).exists()) {
+            copy_wave(test, args, path, metadata, temp_dir.path())?;
+        }
+
+        if property.re_pass.is_some() && !self.passed {
+            self.failure();
+        }
+
+        if self.success {
+            info!("Succeeded test ({name})");
+            Ok(true)
+        } else {
+            error!("Failed test ({name})");
+            Ok(false)
+        }
+    }
+
+    fn name(&self) -> &'static str {
+        "Custom"
+    }
+
+    fn failure(&mut self) {
+        self.success = false;
+    }
+
+    fn output(&mut self) -> &mut RunnerOutput {
+        &mut self.output
+    }
+}
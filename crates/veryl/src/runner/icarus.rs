@@ -0,0 +1,259 @@
+use crate::runner::{
+    MangledNameMap, Runner, RunnerOutput, TestArgs, copy_wave, deadline, remap_msg_by_regex,
+    top_module, wait_until,
+};
+use futures::prelude::*;
+use log::{error, info};
+use miette::{IntoDiagnostic, Result, WrapErr};
+use once_cell::sync::Lazy;
+use regex::Regex;
+use std::path::Path;
+use std::process::Stdio;
+use tokio::process::{Child, Command};
+use tokio::runtime::Runtime;
+use tokio_util::codec::{FramedRead, LinesCodec};
+use veryl_metadata::{Metadata, WaveFormFormat};
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+enum State {
+    Compile,
+    Simulate,
+}
+
+pub struct Icarus {
+    state: State,
+    success: bool,
+    mangled_names: MangledNameMap,
+    output: RunnerOutput,
+}
+
+fn remap_msg(line: &str, mangled_names: &MangledNameMap) -> String {
+    static RE: Lazy<Regex> = Lazy::new(|| Regex::new(r"(?<path>[^: ]+):(?<line>[0-9]+):").unwrap());
+
+    remap_msg_by_regex(line, &RE, mangled_names)
+}
+
+impl Icarus {
+    pub fn new() -> Self {
+        Self {
+            state: State::Compile,
+            success: true,
+            mangled_names: MangledNameMap::default(),
+            output: RunnerOutput::default(),
+        }
+    }
+
+    pub fn runner(self) -> Box<dyn Runner> {
+        Box::new(self) as Box<dyn Runner>
+    }
+
+    fn parse_line(&mut self, line: &str) {
+        self.debug(line);
+
+        match self.state {
+            State::Compile => {
+                if line.contains(": warning: ") {
+                    self.warning(&remap_msg(line, &self.mangled_names));
+                } else if line.contains(": error: ")
+                    || line.contains(": sorry: ")
+                    || line.contains(": syntax error")
+                    || line.ends_with("during elaboration.")
+                    || line.ends_with("I give up.")
+                {
+                    self.error(&remap_msg(line, &self.mangled_names));
+                }
+            }
+            State::Simulate => {
+                if let Some(x) = line.strip_prefix("INFO: ") {
+                    self.info(&remap_msg(x, &self.mangled_names));
+                } else if let Some(x) = line.strip_prefix("WARNING: ") {
+                    self.warning(&remap_msg(x, &self.mangled_names));
+                } else if let Some(x) = line.strip_prefix("ERROR: ") {
+                    self.error(&remap_msg(x, &self.mangled_names));
+                } else if let Some(x) = line.strip_prefix("FATAL: ") {
+                    self.fatal(&remap_msg(x, &self.mangled_names));
+                }
+            }
+        }
+    }
+
+    async fn parse(&mut self, mut child: Child) -> Result<()> {
+        let stdout = child.stdout.take().unwrap();
+        let mut reader = FramedRead::new(stdout, LinesCodec::new());
+        while let Some(line) = reader.next().await {
+            let line = line.into_diagnostic()?;
+            self.parse_line(&line);
+        }
+
+        let stderr = child.stderr.take().unwrap();
+        let mut reader = FramedRead::new(stderr, LinesCodec::new());
+        while let Some(line) = reader.next().await {
+            let line = line.into_diagnostic()?;
+            self.parse_line(&line);
+        }
+        Ok(())
+    }
+}
+
+impl Default for Icarus {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Runner for Icarus {
+    fn run(
+        &mut self,
+        metadata: &Metadata,
+        test: &str,
+        top: Option<&str>,
+        args: &TestArgs,
+        path: &Path,
+        wave: bool,
+    ) -> Result<bool> {
+        self.success = true;
+        let name = args.name(test);
+        self.mangled_names = MangledNameMap::load(metadata);
+
+        let temp_dir = tempfile::tempdir().into_diagnostic()?;
+
+        for include_file in &metadata.test.include_files {
+            if include_file.is_dir() {
+                miette::bail!("Including directories currently unsupported");
+            } else if let Some(file_name) = include_file.iter().next_back() {
+                let target_path = temp_dir.path().join(file_name);
+                if std::fs::copy(include_file, &target_path).is_err() {
+                    miette::bail!(
+                        "Failed to copy include {:?} to {:?}",
+                        include_file,
+                        target_path
+                    )
+                }
+            } else {
+                miette::bail!("Failed to get include file name {:?}", include_file);
+            }
+        }
+
+        info!("Compiling test ({name})");
+
+        let mut defines = vec![format!(
+            "-D__veryl_test_{}_{}__",
+            metadata.project.name, test
+        )];
+
+        // Only the test module and the waveform dumper are elaborated as top modules
+        let top_name = top_module(metadata, test, top);
+        let mut top = vec!["-s".to_string(), top_name.clone()];
+        let mut opt = vec![];
+
+        if wave {
+            defines.push(format!(
+                "-D__veryl_wavedump_{}_{}__",
+                metadata.project.name, test
+            ));
+            top.push("-s".to_string());
+            top.push("__veryl_wavedump".to_string());
+
+            // `$dumpfile` writes FST instead of VCD with the same file name
+            if metadata.test.waveform_format == WaveFormFormat::Fst {
+                opt.push("-fst");
+            }
+        }
+
+        let mut plusargs = vec![];
+        if let Some(x) = args.seed {
+            defines.push(format!("-D__veryl_seed__={x}"));
+            plusargs.push(format!("+veryl_seed={x}"));
+        }
+
+        let params: Vec<_> = args
+            .param_overrides(&top_name)
+            .iter()
+            .map(|(path, value)| format!("-P{path}={value}"))
+            .collect();
+
+        let rt = Runtime::new().unwrap();
+        let deadline = deadline(metadata);
+
+        self.state = State::Compile;
+        let finished = rt.block_on(async {
+            let compile = Command::new("iverilog")
+                .arg("-g2012")
+                .arg("-I")
+                .arg(temp_dir.path())
+                .arg("-c")
+                .arg(metadata.filelist_path())
+                .arg("-o")
+                .arg("simv")
+                .args(&top)
+                .args(&defines)
+                .args(&params)
+                .args(&metadata.test.iverilog.compile_args)
+                .current_dir(temp_dir.path())
+                .stdout(Stdio::piped())
+                .stderr(Stdio::piped())
+                .kill_on_drop(true)
+                .spawn()
+                .into_diagnostic()
+                .wrap_err("Failed to run \"iverilog\"")?;
+
+            wait_until(deadline, self.parse(compile)).await
+        })?;
+        if !finished {
+            return Ok(self.timeout(&name));
+        }
+
+        if !self.success {
+            error!("Failed compile ({name})");
+            return Ok(false);
+        }
+
+        info!("Executing test ({name})");
+
+        self.state = State::Simulate;
+        let finished = rt.block_on(async {
+            let simulate = Command::new("vvp")
+                .arg("-n")
+                .arg("simv")
+                .args(&opt)
+                .args(&plusargs)
+                .args(&metadata.test.iverilog.simulate_args)
+                .current_dir(temp_dir.path())
+                .stdout(Stdio::piped())
+                .stderr(Stdio::piped())
+                .kill_on_drop(true)
+                .spawn()
+                .into_diagnostic()
+                .wrap_err("Failed to run \"vvp\"")?;
+
+            wait_until(deadline, self.parse(simulate)).await
+        })?;
+        if !finished {
+            return Ok(self.timeout(&name));
+        }
+
+        if wave {
+            copy_wave(test, args, path, metadata, temp_dir.path())?;
+        }
+
+        if self.success {
+            info!("Succeeded test ({name})");
+            Ok(true)
+        } else {
+            error!("Failed test ({name})");
+            Ok(false)
+        }
+    }
+
+    fn name(&self) -> &'static str {
+        "Icarus"
+    }
+
+    fn failure(&mut self) {
+        self.success = false;
+    }
+
+    fn output(&mut self) -> &mut RunnerOutput {
+        &mut self.output
+    }
+}
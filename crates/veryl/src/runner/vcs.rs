@@ -1,15 +1,18 @@
-use crate::runner::{Runner, copy_wave, remap_msg_by_regex};
+use crate::runner::{
+    MangledNameMap, Runner, RunnerOutput, TestArgs, copy_wave, deadline, remap_msg_by_regex,
+    top_module, wait_until,
+};
 use futures::prelude::*;
 use log::{error, info, warn};
 use miette::{IntoDiagnostic, Result, WrapErr};
 use once_cell::sync::Lazy;
 use regex::Regex;
+use std::path::Path;
 use std::process::Stdio;
 use tokio::process::{Child, Command};
 use tokio::runtime::Runtime;
 use tokio_util::codec::{FramedRead, LinesCodec};
 use veryl_metadata::{Metadata, WaveFormFormat};
-use veryl_parser::resource_table::{PathId, StrId};
 
 #[derive(Copy, Clone, Debug, PartialEq, Eq)]
 enum State {
@@ -25,13 +28,15 @@ enum State {
 pub struct Vcs {
     state: State,
     success: bool,
+    mangled_names: MangledNameMap,
+    output: RunnerOutput,
 }
 
-fn remap_msg(line: &str) -> String {
+fn remap_msg(line: &str, mangled_names: &MangledNameMap) -> String {
     static RE: Lazy<Regex> =
         Lazy::new(|| Regex::new(r###""?(?<path>[^: "]+)"?, (?<line>[0-9]+)"###).unwrap());
 
-    remap_msg_by_regex(line, &RE)
+    remap_msg_by_regex(line, &RE, mangled_names)
 }
 
 impl Vcs {
@@ -39,6 +44,8 @@ impl Vcs {
         Self {
             state: State::Idle,
             success: true,
+            mangled_names: MangledNameMap::default(),
+            output: RunnerOutput::default(),
         }
     }
 
@@ -61,10 +68,10 @@ impl Vcs {
                     self.state = State::SimulateFatal;
                 } else if line.starts_with("Warning-") {
                     self.state = State::CompileWarning;
-                    self.warning(&remap_msg(line));
+                    self.warning(&remap_msg(line, &self.mangled_names));
                 } else if line.starts_with("Error-") {
                     self.state = State::CompileError;
-                    self.error(&remap_msg(line));
+                    self.error(&remap_msg(line, &self.mangled_names));
                 }
             }
             State::SimulateInfo => {
@@ -87,14 +94,14 @@ impl Vcs {
                 if line.is_empty() {
                     self.state = State::Idle;
                 } else {
-                    self.warning(&remap_msg(line));
+                    self.warning(&remap_msg(line, &self.mangled_names));
                 }
             }
             State::CompileError => {
                 if line.is_empty() {
                     self.state = State::Idle;
                 } else {
-                    self.error(&remap_msg(line));
+                    self.error(&remap_msg(line, &self.mangled_names));
                 }
             }
         }
@@ -121,12 +128,15 @@ impl Runner for Vcs {
     fn run(
         &mut self,
         metadata: &Metadata,
-        test: StrId,
-        _top: Option<StrId>,
-        path: PathId,
+        test: &str,
+        top: Option<&str>,
+        args: &TestArgs,
+        path: &Path,
         mut wave: bool,
     ) -> Result<bool> {
         self.success = true;
+        let name = args.name(test);
+        self.mangled_names = MangledNameMap::load(metadata);
 
         let temp_dir = tempfile::tempdir().into_diagnostic()?;
 
@@ -135,7 +145,7 @@ impl Runner for Vcs {
             warn!("Including files is unimplemented for this backend!");
         }
 
-        info!("Compiling test ({test})");
+        info!("Compiling test ({name})");
 
         let mut defines = vec![format!(
             "+define+__veryl_test_{}_{}__",
@@ -153,54 +163,78 @@ impl Runner for Vcs {
             }
         }
 
+        let mut plusargs = vec![];
+        if let Some(x) = args.seed {
+            defines.push(format!("+define+__veryl_seed__={x}"));
+            plusargs.push(format!("+ntb_random_seed={x}"));
+            plusargs.push(format!("+veryl_seed={x}"));
+        }
+
+        let params: Vec<_> = args
+            .param_overrides(&top_module(metadata, test, top))
+            .iter()
+            .map(|(path, value)| format!("-pvalue+{path}={value}"))
+            .collect();
+
         let rt = Runtime::new().unwrap();
+        let deadline = deadline(metadata);
 
-        rt.block_on(async {
+        let finished = rt.block_on(async {
             let compile = Command::new("vcs")
                 .arg("-sverilog")
                 .arg("-f")
                 .arg(metadata.filelist_path())
                 .args(&defines)
+                .args(&params)
                 .args(&metadata.test.vcs.compile_args)
                 .current_dir(temp_dir.path())
                 .stdout(Stdio::piped())
                 .stderr(Stdio::null())
+                .kill_on_drop(true)
                 .spawn()
                 .into_diagnostic()
                 .wrap_err("Failed to run \"vcs\"")?;
 
-            self.parse(compile).await
+            wait_until(deadline, self.parse(compile)).await
         })?;
+        if !finished {
+            return Ok(self.timeout(&name));
+        }
 
         if !self.success {
-            error!("Failed compile ({test})");
+            error!("Failed compile ({name})");
             return Ok(false);
         }
 
-        info!("Executing test ({test})");
+        info!("Executing test ({name})");
 
-        rt.block_on(async {
+        let finished = rt.block_on(async {
             let simulate = Command::new("./simv")
+                .args(&plusargs)
                 .args(&metadata.test.vcs.simulate_args)
                 .current_dir(temp_dir.path())
                 .stdout(Stdio::piped())
                 .stderr(Stdio::null())
+                .kill_on_drop(true)
                 .spawn()
                 .into_diagnostic()
                 .wrap_err("Failed to run simulation binary")?;
 
-            self.parse(simulate).await
+            wait_until(deadline, self.parse(simulate)).await
         })?;
+        if !finished {
+            return Ok(self.timeout(&name));
+        }
 
         if wave {
-            copy_wave(test, path, metadata, temp_dir.path())?;
+            copy_wave(test, args, path, metadata, temp_dir.path())?;
         }
 
         if self.success {
-            info!("Succeeded test ({test})");
+            info!("Succeeded test ({name})");
             Ok(true)
         } else {
-            error!("Failed test ({test})");
+            error!("Failed test ({name})");
             Ok(false)
         }
     }
@@ -212,4 +246,8 @@ impl Runner for Vcs {
     fn failure(&mut self) {
         self.success = false;
     }
+
+    fn output(&mut self) -> &mut RunnerOutput {
+        &mut self.output
+    }
 }
@@ -1,15 +1,18 @@
-use crate::runner::{Runner, copy_wave, remap_msg_by_regex};
+use crate::runner::{
+    MangledNameMap, Runner, RunnerOutput, TestArgs, copy_wave, deadline, remap_msg_by_regex,
+    wait_until,
+};
 use futures::prelude::*;
 use log::{error, info};
 use miette::{IntoDiagnostic, Result, WrapErr};
 use once_cell::sync::Lazy;
 use regex::Regex;
+use std::path::Path;
 use std::process::Stdio;
 use tokio::process::{Child, Command};
 use tokio::runtime::Runtime;
 use tokio_util::codec::{FramedRead, LinesCodec};
 use veryl_metadata::{Metadata, WaveFormFormat};
-use veryl_parser::resource_table::{PathId, StrId};
 
 #[derive(Copy, Clone, Debug, PartialEq, Eq)]
 enum State {
@@ -21,6 +24,8 @@ enum State {
 pub struct Verilator {
     state: State,
     success: bool,
+    mangled_names: MangledNameMap,
+    output: RunnerOutput,
 }
 
 fn parse_msg(line: &str) -> String {
@@ -33,12 +38,12 @@ fn parse_msg(line: &str) -> String {
     }
 }
 
-fn remap_msg(line: &str) -> String {
+fn remap_msg(line: &str, mangled_names: &MangledNameMap) -> String {
     static RE: Lazy<Regex> = Lazy::new(|| {
         Regex::new(r"(?<path>[^: ]+):(?<line>[0-9]+)(?::(?<column>[0-9]+))?").unwrap()
     });
 
-    remap_msg_by_regex(line, &RE)
+    remap_msg_by_regex(line, &RE, mangled_names)
 }
 
 impl Verilator {
@@ -46,6 +51,8 @@ impl Verilator {
         Self {
             state: State::Idle,
             success: true,
+            mangled_names: MangledNameMap::default(),
+            output: RunnerOutput::default(),
         }
     }
 
@@ -68,34 +75,34 @@ impl Verilator {
                     self.fatal(&parse_msg(line));
                 } else if line.starts_with("%Warning:") {
                     self.state = State::CompileWarning;
-                    self.warning(&remap_msg(line));
+                    self.warning(&remap_msg(line, &self.mangled_names));
                 } else if line.starts_with("%Error:") {
                     self.state = State::CompileError;
-                    self.error(&remap_msg(line));
+                    self.error(&remap_msg(line, &self.mangled_names));
                 }
             }
             State::CompileWarning => {
                 if line.starts_with(' ') {
-                    self.warning(&remap_msg(line));
+                    self.warning(&remap_msg(line, &self.mangled_names));
                 } else if line.starts_with("%Warning") {
                     self.state = State::CompileWarning;
-                    self.warning(&remap_msg(line));
+                    self.warning(&remap_msg(line, &self.mangled_names));
                 } else if line.starts_with("%Error") {
                     self.state = State::CompileError;
-                    self.error(&remap_msg(line));
+                    self.error(&remap_msg(line, &self.mangled_names));
                 } else {
                     self.state = State::Idle;
                 }
             }
             State::CompileError => {
                 if line.starts_with(' ') {
-                    self.error(&remap_msg(line));
+                    self.error(&remap_msg(line, &self.mangled_names));
                 } else if line.starts_with("%Warning") {
                     self.state = State::CompileWarning;
-                    self.warning(&remap_msg(line));
+                    self.warning(&remap_msg(line, &self.mangled_names));
                 } else if line.starts_with("%Error") {
                     self.state = State::CompileError;
-                    self.error(&remap_msg(line));
+                    self.error(&remap_msg(line, &self.mangled_names));
                 } else {
                     self.state = State::Idle;
                 }
@@ -131,12 +138,15 @@ impl Runner for Verilator {
     fn run(
         &mut self,
         metadata: &Metadata,
-        test: StrId,
-        _top: Option<StrId>,
-        path: PathId,
+        test: &str,
+        _top: Option<&str>,
+        args: &TestArgs,
+        path: &Path,
         wave: bool,
     ) -> Result<bool> {
         self.success = true;
+        let name = args.name(test);
+        self.mangled_names = MangledNameMap::load(metadata);
 
         let temp_dir = tempfile::tempdir().into_diagnostic()?;
 
@@ -157,7 +167,7 @@ impl Runner for Verilator {
             }
         }
 
-        info!("Compiling test ({test})");
+        info!("Compiling test ({name})");
 
         let mut defines = vec![format!(
             "+define+__veryl_test_{}_{}__",
@@ -165,6 +175,19 @@ impl Runner for Verilator {
         )];
 
         let mut opt = vec!["--assert", "--binary", "-Wno-MULTITOP"];
+        let mut plusargs = vec![];
+
+        if let Some(x) = args.seed {
+            defines.push(format!("+define+__veryl_seed__={x}"));
+            plusargs.push(format!("+verilator+seed+{x}"));
+            plusargs.push(format!("+veryl_seed={x}"));
+        }
+
+        let params: Vec<_> = args
+            .params
+            .iter()
+            .map(|(name, value)| format!("-G{name}={value}"))
+            .collect();
 
         if wave {
             defines.push(format!(
@@ -185,8 +208,9 @@ impl Runner for Verilator {
         }
 
         let rt = Runtime::new().unwrap();
+        let deadline = deadline(metadata);
 
-        rt.block_on(async {
+        let finished = rt.block_on(async {
             let compile = Command::new("verilator")
                 .args(&opt)
                 .arg("-f")
@@ -194,46 +218,56 @@ impl Runner for Verilator {
                 .arg("-o")
                 .arg("simv")
                 .args(&defines)
+                .args(&params)
                 .args(&metadata.test.verilator.compile_args)
                 .current_dir(temp_dir.path())
                 .stdout(Stdio::piped())
                 .stderr(Stdio::piped())
+                .kill_on_drop(true)
                 .spawn()
                 .into_diagnostic()
                 .wrap_err("Failed to run \"verilator\"")?;
 
-            self.parse(compile).await
+            wait_until(deadline, self.parse(compile)).await
         })?;
+        if !finished {
+            return Ok(self.timeout(&name));
+        }
 
         if !self.success {
-            error!("Failed compile ({test})");
+            error!("Failed compile ({name})");
             return Ok(false);
         }
 
-        info!("Executing test ({test})");
+        info!("Executing test ({name})");
 
-        rt.block_on(async {
+        let finished = rt.block_on(async {
             let simulate = Command::new("./obj_dir/simv")
+                .args(&plusargs)
                 .args(&metadata.test.verilator.simulate_args)
                 .current_dir(temp_dir.path())
                 .stdout(Stdio::piped())
                 .stderr(Stdio::piped())
+                .kill_on_drop(true)
                 .spawn()
                 .into_diagnostic()
                 .wrap_err("Failed to run simulation binary")?;
 
-            self.parse(simulate).await
+            wait_until(deadline, self.parse(simulate)).await
         })?;
+        if !finished {
+            return Ok(self.timeout(&name));
+        }
 
         if wave {
-            copy_wave(test, path, metadata, temp_dir.path())?;
+            copy_wave(test, args, path, metadata, temp_dir.path())?;
         }
 
         if self.success {
-            info!("Succeeded test ({test})");
+            info!("Succeeded test ({name})");
             Ok(true)
         } else {
-            error!("Failed test ({test})");
+            error!("Failed test ({name})");
             Ok(false)
         }
     }
@@ -245,4 +279,8 @@ impl Runner for Verilator {
     fn failure(&mut self) {
         self.success = false;
     }
+
+    fn output(&mut self) -> &mut RunnerOutput {
+        &mut self.output
+    }
 }
@@ -1,15 +1,18 @@
-use crate::runner::{Runner, copy_wave, remap_msg_by_regex};
+use crate::runner::{
+    MangledNameMap, Runner, RunnerOutput, TestArgs, copy_wave, deadline, remap_msg_by_regex,
+    top_module, wait_until,
+};
 use futures::prelude::*;
 use log::{error, info, warn};
 use miette::{IntoDiagnostic, Result, WrapErr};
 use once_cell::sync::Lazy;
 use regex::Regex;
+use std::path::Path;
 use std::process::Stdio;
 use tokio::process::{Child, Command};
 use tokio::runtime::Runtime;
 use tokio_util::codec::{FramedRead, LinesCodec};
 use veryl_metadata::{Metadata, WaveFormFormat};
-use veryl_parser::resource_table::{PathId, StrId};
 
 #[derive(Copy, Clone, Debug, PartialEq, Eq)]
 enum State {
@@ -23,14 +26,16 @@ enum State {
 pub struct Vivado {
     state: State,
     success: bool,
+    mangled_names: MangledNameMap,
+    output: RunnerOutput,
 }
 
-fn remap_msg(line: &str) -> String {
+fn remap_msg(line: &str, mangled_names: &MangledNameMap) -> String {
     static RE: Lazy<Regex> = Lazy::new(|| {
         Regex::new(r"(?<path>[^: \[\]]+):(?<line>[0-9]+)(?::(?<column>[0-9]+))?").unwrap()
     });
 
-    remap_msg_by_regex(line, &RE)
+    remap_msg_by_regex(line, &RE, mangled_names)
 }
 
 impl Vivado {
@@ -38,6 +43,8 @@ impl Vivado {
         Self {
             state: State::Idle,
             success: true,
+            mangled_names: MangledNameMap::default(),
+            output: RunnerOutput::default(),
         }
     }
 
@@ -63,9 +70,9 @@ impl Vivado {
                     self.state = State::SimulateFatal;
                     self.fatal(line.strip_prefix("Fatal: ").unwrap());
                 } else if line.starts_with("WARNING:") {
-                    self.warning(&remap_msg(line));
+                    self.warning(&remap_msg(line, &self.mangled_names));
                 } else if line.starts_with("ERROR:") {
-                    self.error(&remap_msg(line));
+                    self.error(&remap_msg(line, &self.mangled_names));
                 }
             }
             State::SimulateInfo => {
@@ -104,12 +111,15 @@ impl Runner for Vivado {
     fn run(
         &mut self,
         metadata: &Metadata,
-        test: StrId,
-        _top: Option<StrId>,
-        path: PathId,
+        test: &str,
+        top: Option<&str>,
+        args: &TestArgs,
+        path: &Path,
         mut wave: bool,
     ) -> Result<bool> {
         self.success = true;
+        let name = args.name(test);
+        self.mangled_names = MangledNameMap::load(metadata);
 
         let temp_dir = tempfile::tempdir().into_diagnostic()?;
 
@@ -121,7 +131,6 @@ impl Runner for Vivado {
                 let target_path = temp_dir.path().join(file_name);
                 includes.push("-i".to_string());
                 includes.push(target_path.to_string_lossy().to_string());
-                println!("{target_path:?}");
                 if std::fs::copy(include_file, &target_path).is_err() {
                     miette::bail!(
                         "Failed to copy include {:?} to {:?}",
@@ -134,7 +143,7 @@ impl Runner for Vivado {
             }
         }
 
-        info!("Compiling test ({test})");
+        info!("Compiling test ({name})");
 
         let mut defines = vec![
             "-d".to_string(),
@@ -154,9 +163,26 @@ impl Runner for Vivado {
             }
         }
 
+        let mut seed = vec![];
+        if let Some(x) = args.seed {
+            defines.push("-d".to_string());
+            defines.push(format!("__veryl_seed__={x}"));
+            seed.push("-sv_seed".to_string());
+            seed.push(x.to_string());
+            seed.push("--testplusarg".to_string());
+            seed.push(format!("veryl_seed={x}"));
+        }
+
+        let mut params = vec![];
+        for (name, value) in &args.params {
+            params.push("--generic_top".to_string());
+            params.push(format!("{name}={value}"));
+        }
+
         let rt = Runtime::new().unwrap();
+        let deadline = deadline(metadata);
 
-        rt.block_on(async {
+        let finished = rt.block_on(async {
             let compile = Command::new("xvlog")
                 .arg("--sv")
                 .arg("-f")
@@ -166,21 +192,25 @@ impl Runner for Vivado {
                 .current_dir(temp_dir.path())
                 .stdout(Stdio::piped())
                 .stderr(Stdio::null())
+                .kill_on_drop(true)
                 .spawn()
                 .into_diagnostic()
                 .wrap_err("Failed to run \"xvlog\"")?;
 
-            self.parse(compile).await
+            wait_until(deadline, self.parse(compile)).await
         })?;
+        if !finished {
+            return Ok(self.timeout(&name));
+        }
 
         if !self.success {
-            error!("Failed compile ({test})");
+            error!("Failed compile ({name})");
             return Ok(false);
         }
 
-        info!("Elaborating test ({test})");
+        info!("Elaborating test ({name})");
 
-        let mut top = vec![test.to_string()];
+        let mut top = vec![top_module(metadata, test, top)];
         let opt = if wave && WaveFormFormat::Vcd == metadata.test.waveform_format {
             top.push("__veryl_wavedump".to_string());
             vec!["-debug", "all"]
@@ -188,54 +218,64 @@ impl Runner for Vivado {
             vec![]
         };
 
-        rt.block_on(async {
+        let finished = rt.block_on(async {
             let elaborate = Command::new("xelab")
                 .args(top)
                 .args(opt)
                 .arg("-s")
                 .arg("simv")
+                .args(&params)
                 .args(&metadata.test.vivado.elaborate_args)
                 .current_dir(temp_dir.path())
                 .stdout(Stdio::piped())
                 .stderr(Stdio::null())
+                .kill_on_drop(true)
                 .spawn()
                 .into_diagnostic()
                 .wrap_err("Failed to run \"xelab\"")?;
 
-            self.parse(elaborate).await
+            wait_until(deadline, self.parse(elaborate)).await
         })?;
+        if !finished {
+            return Ok(self.timeout(&name));
+        }
 
         if !self.success {
-            error!("Failed elaborate ({test})");
+            error!("Failed elaborate ({name})");
             return Ok(false);
         }
 
-        info!("Executing test ({test})");
+        info!("Executing test ({name})");
 
-        rt.block_on(async {
+        let finished = rt.block_on(async {
             let simulate = Command::new("xsim")
                 .arg("simv")
                 .arg("--runall")
+                .args(&seed)
                 .args(&metadata.test.vivado.simulate_args)
                 .current_dir(temp_dir.path())
                 .stdout(Stdio::piped())
                 .stderr(Stdio::null())
+                .kill_on_drop(true)
                 .spawn()
                 .into_diagnostic()
                 .wrap_err("Failed to run \"xsim\"")?;
 
-            self.parse(simulate).await
+            wait_until(deadline, self.parse(simulate)).await
         })?;
+        if !finished {
+            return Ok(self.timeout(&name));
+        }
 
         if wave {
-            copy_wave(test, path, metadata, temp_dir.path())?;
+            copy_wave(test, args, path, metadata, temp_dir.path())?;
         }
 
         if self.success {
-            info!("Succeeded test ({test})");
+            info!("Succeeded test ({name})");
             Ok(true)
         } else {
-            error!("Failed test ({test})");
+            error!("Failed test ({name})");
             Ok(false)
         }
     }
@@ -247,4 +287,8 @@ impl Runner for Vivado {
     fn failure(&mut self) {
         self.success = false;
     }
+
+    fn output(&mut self) -> &mut RunnerOutput {
+        &mut self.output
+    }
 }
@@ -48,6 +48,13 @@ function startServer(context: vscode.ExtensionContext) {
 		clientOptions
 	);
 
+	// Show output of tests executed by code lens
+	let testOutput = window.createOutputChannel('Veryl Test');
+	client.onNotification('veryl/testOutput', (params: { test: string, line: string }) => {
+		testOutput.show(true);
+		testOutput.appendLine(params.line);
+	});
+
 	// Start the client. This will also launch the server
 	client.start();
 }
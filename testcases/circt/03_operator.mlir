@@ -0,0 +1,43 @@
+hw.module @veryl_testcase_Module03() {
+  %0 = hw.constant true loc("../veryl/03_operator.veryl":3:9)
+  %_a = hw.wire %0 : i1 loc("../veryl/03_operator.veryl":3:9)
+  %_aa = hw.wire %0 : i1 loc("../veryl/03_operator.veryl":4:9)
+  %1 = hw.constant false loc("../veryl/03_operator.veryl":7:9)
+  %_b = hw.wire %1 : i1 loc("../veryl/03_operator.veryl":7:9)
+  %_bb = hw.wire %1 : i1 loc("../veryl/03_operator.veryl":8:9)
+  %_c = hw.wire %1 : i1 loc("../veryl/03_operator.veryl":11:9)
+  %_cc = hw.wire %0 : i1 loc("../veryl/03_operator.veryl":12:9)
+  %_ccc = hw.wire %0 : i1 loc("../veryl/03_operator.veryl":13:9)
+  %_cccc = hw.wire %0 : i1 loc("../veryl/03_operator.veryl":14:9)
+  %_ccccc = hw.wire %1 : i1 loc("../veryl/03_operator.veryl":15:9)
+  %_cccccc = hw.wire %1 : i1 loc("../veryl/03_operator.veryl":16:9)
+  %_ccccccc = hw.wire %1 : i1 loc("../veryl/03_operator.veryl":17:9)
+  %_d = hw.wire %0 : i1 loc("../veryl/03_operator.veryl":20:9)
+  %_dd = hw.wire %0 : i1 loc("../veryl/03_operator.veryl":21:9)
+  %_ddd = hw.wire %0 : i1 loc("../veryl/03_operator.veryl":22:9)
+  %_dddd = hw.wire %1 : i1 loc("../veryl/03_operator.veryl":23:9)
+  %_ddddd = hw.wire %1 : i1 loc("../veryl/03_operator.veryl":24:9)
+  %_dddddd = hw.wire %1 : i1 loc("../veryl/03_operator.veryl":25:9)
+  %_e = hw.wire %1 : i1 loc("../veryl/03_operator.veryl":28:9)
+  %_ee = hw.wire %1 : i1 loc("../veryl/03_operator.veryl":29:9)
+  %_eee = hw.wire %1 : i1 loc("../veryl/03_operator.veryl":30:9)
+  %_eeee = hw.wire %1 : i1 loc("../veryl/03_operator.veryl":31:9)
+  %_f = hw.wire %1 : i1 loc("../veryl/03_operator.veryl":34:9)
+  %_ff = hw.wire %0 : i1 loc("../veryl/03_operator.veryl":35:9)
+  %_fff = hw.wire %1 : i1 loc("../veryl/03_operator.veryl":36:9)
+  %_ffff = hw.wire %0 : i1 loc("../veryl/03_operator.veryl":37:9)
+  %_fffff = hw.wire %0 : i1 loc("../veryl/03_operator.veryl":38:9)
+  %_ffffff = hw.wire %1 : i1 loc("../veryl/03_operator.veryl":39:9)
+  %_fffffff = hw.wire %0 : i1 loc("../veryl/03_operator.veryl":40:9)
+  %_ffffffff = hw.wire %1 : i1 loc("../veryl/03_operator.veryl":41:9)
+  %_fffffffff = hw.wire %0 : i1 loc("../veryl/03_operator.veryl":42:9)
+  %_ffffffffff = hw.wire %1 : i1 loc("../veryl/03_operator.veryl":43:9)
+  %_g = hw.wire %0 : i1 loc("../veryl/03_operator.veryl":46:9)
+  %_gg = hw.wire %1 : i1 loc("../veryl/03_operator.veryl":47:9)
+  %_ggg = hw.wire %0 : i1 loc("../veryl/03_operator.veryl":48:9)
+  %_gggg = hw.wire %0 : i1 loc("../veryl/03_operator.veryl":49:9)
+  %_ggggg = hw.wire %0 : i1 loc("../veryl/03_operator.veryl":50:9)
+  %_h = hw.wire %0 : i1 loc("../veryl/03_operator.veryl":53:9)
+  %_hh = hw.wire %0 : i1 loc("../veryl/03_operator.veryl":54:9)
+  hw.output loc("../veryl/03_operator.veryl":55:1)
+} loc("../veryl/03_operator.veryl":1:1)
@@ -0,0 +1,23 @@
+hw.module @veryl_testcase_Module08() {
+  %0 = seq.to_clock %i_clk loc("../veryl/08_generate_declaration.veryl":9:9)
+  %1 = hw.constant true loc("../veryl/08_generate_declaration.veryl":9:9)
+  %label.a = seq.firreg %1 clock %0 : i1 loc("../veryl/08_generate_declaration.veryl":9:9)
+  %3 = hw.constant false loc("../veryl/08_generate_declaration.veryl":32:9)
+  %label2_0.a = seq.firreg %3 clock %0 : i1 loc("../veryl/08_generate_declaration.veryl":32:9)
+  %label2_1.a = seq.firreg %1 clock %0 : i1 loc("../veryl/08_generate_declaration.veryl":32:9)
+  %label2_2.a = seq.firreg %3 clock %0 : i1 loc("../veryl/08_generate_declaration.veryl":32:9)
+  %label2_3.a = seq.firreg %1 clock %0 : i1 loc("../veryl/08_generate_declaration.veryl":32:9)
+  %label2_4.a = seq.firreg %3 clock %0 : i1 loc("../veryl/08_generate_declaration.veryl":32:9)
+  %label2_5.a = seq.firreg %1 clock %0 : i1 loc("../veryl/08_generate_declaration.veryl":32:9)
+  %label2_6.a = seq.firreg %3 clock %0 : i1 loc("../veryl/08_generate_declaration.veryl":32:9)
+  %label2_7.a = seq.firreg %1 clock %0 : i1 loc("../veryl/08_generate_declaration.veryl":32:9)
+  %label2_8.a = seq.firreg %3 clock %0 : i1 loc("../veryl/08_generate_declaration.veryl":32:9)
+  %label2_9.a = seq.firreg %1 clock %0 : i1 loc("../veryl/08_generate_declaration.veryl":32:9)
+  %label3_0.a = seq.firreg %3 clock %0 : i1 loc("../veryl/08_generate_declaration.veryl":40:9)
+  %label3_2.a = seq.firreg %3 clock %0 : i1 loc("../veryl/08_generate_declaration.veryl":40:9)
+  %label3_4.a = seq.firreg %3 clock %0 : i1 loc("../veryl/08_generate_declaration.veryl":40:9)
+  %label3_6.a = seq.firreg %3 clock %0 : i1 loc("../veryl/08_generate_declaration.veryl":40:9)
+  %label3_8.a = seq.firreg %3 clock %0 : i1 loc("../veryl/08_generate_declaration.veryl":40:9)
+  %i_clk = hw.wire %1 : i1 loc("../veryl/08_generate_declaration.veryl":4:9)
+  hw.output loc("../veryl/08_generate_declaration.veryl":44:1)
+} loc("../veryl/08_generate_declaration.veryl":1:1)
@@ -0,0 +1,20 @@
+hw.module @veryl_testcase_Module09() {
+  %0 = comb.extract %a from 42 : (i52) -> i10 loc("../veryl/09_struct_enum.veryl":73:5)
+  %1 = comb.extract %0 from 0 : (i10) -> i1 loc("../veryl/09_struct_enum.veryl":73:5)
+  %2 = hw.constant 4402341478401 : i52 loc("../veryl/09_struct_enum.veryl":53:9)
+  %a = hw.wire %2 : i52 loc("../veryl/09_struct_enum.veryl":53:9)
+  %3 = hw.constant 1 : i2 loc("../veryl/09_struct_enum.veryl":54:9)
+  %b = hw.wire %3 : i2 loc("../veryl/09_struct_enum.veryl":54:9)
+  %4 = hw.constant 2 : i3 loc("../veryl/09_struct_enum.veryl":55:9)
+  %c = hw.wire %4 : i3 loc("../veryl/09_struct_enum.veryl":55:9)
+  %5 = hw.constant false loc("../veryl/09_struct_enum.veryl":56:9)
+  %d = hw.wire %5 : i1 loc("../veryl/09_struct_enum.veryl":56:9)
+  %6 = hw.constant 0 : i2 loc("../veryl/09_struct_enum.veryl":57:9)
+  %e = hw.wire %6 : i2 loc("../veryl/09_struct_enum.veryl":57:9)
+  %7 = hw.constant 1 : i3 loc("../veryl/09_struct_enum.veryl":58:9)
+  %f = hw.wire %7 : i3 loc("../veryl/09_struct_enum.veryl":58:9)
+  %g = hw.wire %6 : i2 loc("../veryl/09_struct_enum.veryl":59:9)
+  %h = hw.wire %5 : i1 loc("../veryl/09_struct_enum.veryl":60:9)
+  %i = hw.wire %1 : i1 loc("../veryl/09_struct_enum.veryl":61:9)
+  hw.output loc("../veryl/09_struct_enum.veryl":74:1)
+} loc("../veryl/09_struct_enum.veryl":1:1)
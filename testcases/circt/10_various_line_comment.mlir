@@ -0,0 +1,27 @@
+hw.module @veryl_testcase_Module10(in %i_clk : !seq.clock, in %i_rst_n : i1, in %i_up : i1, in %i_down : i1, out o_count : i8) {
+  %0 = hw.constant false loc("../veryl/10_various_line_comment.veryl":29:10)
+  %1 = comb.concat %0, %i_up : i1, i1 loc("../veryl/10_various_line_comment.veryl":29:10)
+  %2 = hw.constant 1 : i2 loc("../veryl/10_various_line_comment.veryl":29:10)
+  %3 = comb.shl %1, %2 : i2 loc("../veryl/10_various_line_comment.veryl":29:10)
+  %4 = comb.concat %0, %i_down : i1, i1 loc("../veryl/10_various_line_comment.veryl":29:10)
+  %5 = comb.or %3, %4 : i2 loc("../veryl/10_various_line_comment.veryl":31:10)
+  %6 = hw.constant true loc("../veryl/10_various_line_comment.veryl":34:5)
+  %7 = comb.xor %i_rst_n, %6 : i1 loc("../veryl/10_various_line_comment.veryl":34:5)
+  %8 = hw.constant -2 : i2 loc("../veryl/10_various_line_comment.veryl":47:10)
+  %9 = comb.icmp eq %up_down, %8 : i2 loc("../veryl/10_various_line_comment.veryl":47:10)
+  %10 = comb.icmp eq %up_down, %2 : i2 loc("../veryl/10_various_line_comment.veryl":55:18)
+  %11 = hw.constant 0 : i24 loc("../veryl/10_various_line_comment.veryl":50:14)
+  %12 = comb.concat %11, %count : i24, i8 loc("../veryl/10_various_line_comment.veryl":50:14)
+  %13 = hw.constant 1 : i32 loc("../veryl/10_various_line_comment.veryl":50:14)
+  %14 = comb.add %12, %13 : i32 loc("../veryl/10_various_line_comment.veryl":50:14)
+  %15 = comb.extract %14 from 0 : (i32) -> i8 loc("../veryl/10_various_line_comment.veryl":50:14)
+  %16 = comb.sub %12, %13 : i32 loc("../veryl/10_various_line_comment.veryl":57:22)
+  %17 = comb.extract %16 from 0 : (i32) -> i8 loc("../veryl/10_various_line_comment.veryl":57:22)
+  %18 = comb.mux %10, %17, %count : i8 loc("../veryl/10_various_line_comment.veryl":57:22)
+  %19 = comb.mux %9, %15, %18 : i8 loc("../veryl/10_various_line_comment.veryl":57:22)
+  %20 = hw.constant 0 : i8 loc("../veryl/10_various_line_comment.veryl":34:5)
+  %count = seq.firreg %19 clock %i_clk reset async %7, %20 : i8 loc("../veryl/10_various_line_comment.veryl":34:5)
+  %o_count = hw.wire %count : i8 loc("../veryl/10_various_line_comment.veryl":13:5)
+  %up_down = hw.wire %5 : i2 loc("../veryl/10_various_line_comment.veryl":21:9)
+  hw.output %o_count : i8 loc("../veryl/10_various_line_comment.veryl":61:1)
+} loc("../veryl/10_various_line_comment.veryl":1:1)
@@ -0,0 +1,10 @@
+hw.module @veryl_testcase_Module11() {
+  %0 = hw.constant true loc("../veryl/11_let.veryl":3:9)
+  %b = hw.wire %0 : i1 loc("../veryl/11_let.veryl":3:9)
+  %1 = hw.constant 1 : i10 loc("../veryl/11_let.veryl":4:9)
+  %bb = hw.wire %1 : i10 loc("../veryl/11_let.veryl":4:9)
+  %2 = hw.constant 1 : i100 loc("../veryl/11_let.veryl":5:9)
+  %_bbb = hw.wire %2 : i100 loc("../veryl/11_let.veryl":5:9)
+  %_c = hw.wire %1 : i10 loc("../veryl/11_let.veryl":8:9)
+  hw.output loc("../veryl/11_let.veryl":13:1)
+} loc("../veryl/11_let.veryl":1:1)
@@ -0,0 +1,17 @@
+hw.module @veryl_testcase_Module13() {
+  %0 = comb.extract %X from 0 : (i10) -> i1 loc("../veryl/13_range_operator.veryl":10:5)
+  %1 = comb.extract %X from 0 : (i10) -> i2 loc("../veryl/13_range_operator.veryl":13:5)
+  %2 = comb.extract %1 from 0 : (i2) -> i1 loc("../veryl/13_range_operator.veryl":13:5)
+  %3 = comb.extract %X from 1 : (i10) -> i2 loc("../veryl/13_range_operator.veryl":16:5)
+  %4 = comb.extract %3 from 0 : (i2) -> i1 loc("../veryl/13_range_operator.veryl":16:5)
+  %5 = comb.extract %X from 2 : (i10) -> i2 loc("../veryl/13_range_operator.veryl":20:5)
+  %6 = comb.extract %5 from 0 : (i2) -> i1 loc("../veryl/13_range_operator.veryl":20:5)
+  %a = hw.wire %0 : i1 loc("../veryl/13_range_operator.veryl":2:9)
+  %b = hw.wire %2 : i1 loc("../veryl/13_range_operator.veryl":3:9)
+  %c = hw.wire %4 : i1 loc("../veryl/13_range_operator.veryl":4:9)
+  %d = hw.wire %2 : i1 loc("../veryl/13_range_operator.veryl":5:9)
+  %e = hw.wire %6 : i1 loc("../veryl/13_range_operator.veryl":6:9)
+  %7 = hw.constant 1 : i10 loc("../veryl/13_range_operator.veryl":7:9)
+  %X = hw.wire %7 : i10 loc("../veryl/13_range_operator.veryl":7:9)
+  hw.output loc("../veryl/13_range_operator.veryl":21:1)
+} loc("../veryl/13_range_operator.veryl":1:1)
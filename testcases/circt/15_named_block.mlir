@@ -0,0 +1,17 @@
+hw.module @veryl_testcase_Module15() {
+  %0 = hw.constant true loc("../veryl/15_named_block.veryl":2:9)
+  %_a = hw.wire %0 : i1 loc("../veryl/15_named_block.veryl":2:9)
+  %label._a = hw.wire %0 : i1 loc("../veryl/15_named_block.veryl":5:13)
+  %label1._a = hw.wire %0 : i1 loc("../veryl/15_named_block.veryl":9:13)
+  %label2_0.label._a = hw.wire %0 : i1 loc("../veryl/15_named_block.veryl":14:17)
+  %label2_1.label._a = hw.wire %0 : i1 loc("../veryl/15_named_block.veryl":14:17)
+  %label2_2.label._a = hw.wire %0 : i1 loc("../veryl/15_named_block.veryl":14:17)
+  %label2_3.label._a = hw.wire %0 : i1 loc("../veryl/15_named_block.veryl":14:17)
+  %label2_4.label._a = hw.wire %0 : i1 loc("../veryl/15_named_block.veryl":14:17)
+  %label2_5.label._a = hw.wire %0 : i1 loc("../veryl/15_named_block.veryl":14:17)
+  %label2_6.label._a = hw.wire %0 : i1 loc("../veryl/15_named_block.veryl":14:17)
+  %label2_7.label._a = hw.wire %0 : i1 loc("../veryl/15_named_block.veryl":14:17)
+  %label2_8.label._a = hw.wire %0 : i1 loc("../veryl/15_named_block.veryl":14:17)
+  %label2_9.label._a = hw.wire %0 : i1 loc("../veryl/15_named_block.veryl":14:17)
+  hw.output loc("../veryl/15_named_block.veryl":17:1)
+} loc("../veryl/15_named_block.veryl":1:1)
@@ -0,0 +1,84 @@
+hw.module @veryl_testcase_Module16() {
+  %0 = hw.constant 0 : i31 loc("../veryl/16_case_switch.veryl":10:9)
+  %1 = comb.concat %0, %x : i31, i1 loc("../veryl/16_case_switch.veryl":10:9)
+  %2 = hw.constant 0 : i32 loc("../veryl/16_case_switch.veryl":10:9)
+  %3 = comb.icmp eq %1, %2 : i32 loc("../veryl/16_case_switch.veryl":10:9)
+  %4 = hw.constant 1 : i32 loc("../veryl/16_case_switch.veryl":10:9)
+  %5 = comb.icmp eq %1, %4 : i32 loc("../veryl/16_case_switch.veryl":10:9)
+  %6 = hw.constant 2 : i32 loc("../veryl/16_case_switch.veryl":10:9)
+  %7 = comb.icmp eq %1, %6 : i32 loc("../veryl/16_case_switch.veryl":10:9)
+  %8 = hw.constant 3 : i32 loc("../veryl/16_case_switch.veryl":10:9)
+  %9 = comb.icmp eq %1, %8 : i32 loc("../veryl/16_case_switch.veryl":10:9)
+  %10 = hw.constant 4 : i32 loc("../veryl/16_case_switch.veryl":10:9)
+  %11 = comb.icmp eq %1, %10 : i32 loc("../veryl/16_case_switch.veryl":10:9)
+  %12 = comb.or %9, %11 : i1 loc("../veryl/16_case_switch.veryl":10:9)
+  %13 = hw.constant 5 : i32 loc("../veryl/16_case_switch.veryl":10:9)
+  %14 = comb.icmp uge %1, %13 : i32 loc("../veryl/16_case_switch.veryl":10:9)
+  %15 = hw.constant 7 : i32 loc("../veryl/16_case_switch.veryl":10:9)
+  %16 = comb.icmp ule %1, %15 : i32 loc("../veryl/16_case_switch.veryl":10:9)
+  %17 = comb.and %14, %16 : i1 loc("../veryl/16_case_switch.veryl":10:9)
+  %18 = hw.constant 10 : i32 loc("../veryl/16_case_switch.veryl":10:9)
+  %19 = comb.icmp eq %1, %18 : i32 loc("../veryl/16_case_switch.veryl":10:9)
+  %20 = hw.constant 11 : i32 loc("../veryl/16_case_switch.veryl":10:9)
+  %21 = comb.icmp eq %1, %20 : i32 loc("../veryl/16_case_switch.veryl":10:9)
+  %22 = comb.or %19, %21 : i1 loc("../veryl/16_case_switch.veryl":10:9)
+  %23 = hw.constant 12 : i32 loc("../veryl/16_case_switch.veryl":10:9)
+  %24 = comb.icmp eq %1, %23 : i32 loc("../veryl/16_case_switch.veryl":10:9)
+  %25 = comb.or %22, %24 : i1 loc("../veryl/16_case_switch.veryl":10:9)
+  %26 = hw.constant 13 : i32 loc("../veryl/16_case_switch.veryl":10:9)
+  %27 = comb.icmp eq %1, %26 : i32 loc("../veryl/16_case_switch.veryl":10:9)
+  %28 = comb.or %25, %27 : i1 loc("../veryl/16_case_switch.veryl":10:9)
+  %29 = hw.constant 14 : i32 loc("../veryl/16_case_switch.veryl":10:9)
+  %30 = comb.icmp eq %1, %29 : i32 loc("../veryl/16_case_switch.veryl":10:9)
+  %31 = comb.or %28, %30 : i1 loc("../veryl/16_case_switch.veryl":10:9)
+  %32 = hw.constant 15 : i32 loc("../veryl/16_case_switch.veryl":10:9)
+  %33 = comb.icmp eq %1, %32 : i32 loc("../veryl/16_case_switch.veryl":10:9)
+  %34 = comb.or %31, %33 : i1 loc("../veryl/16_case_switch.veryl":10:9)
+  %35 = hw.constant 16 : i32 loc("../veryl/16_case_switch.veryl":10:9)
+  %36 = comb.icmp eq %1, %35 : i32 loc("../veryl/16_case_switch.veryl":10:9)
+  %37 = comb.or %34, %36 : i1 loc("../veryl/16_case_switch.veryl":10:9)
+  %38 = hw.constant 17 : i32 loc("../veryl/16_case_switch.veryl":10:9)
+  %39 = comb.icmp eq %1, %38 : i32 loc("../veryl/16_case_switch.veryl":10:9)
+  %40 = comb.or %37, %39 : i1 loc("../veryl/16_case_switch.veryl":10:9)
+  %41 = hw.constant 0 : i29 loc("../veryl/16_case_switch.veryl":29:15)
+  %42 = comb.concat %41, %z : i29, i3 loc("../veryl/16_case_switch.veryl":29:15)
+  %43 = comb.icmp eq %42, %2 : i32 loc("../veryl/16_case_switch.veryl":29:15)
+  %44 = comb.icmp eq %42, %4 : i32 loc("../veryl/16_case_switch.veryl":30:15)
+  %45 = comb.icmp eq %42, %6 : i32 loc("../veryl/16_case_switch.veryl":31:15)
+  %46 = comb.icmp eq %42, %8 : i32 loc("../veryl/16_case_switch.veryl":36:15)
+  %47 = comb.icmp eq %42, %10 : i32 loc("../veryl/16_case_switch.veryl":36:23)
+  %48 = comb.or %46, %47 : i1 loc("../veryl/16_case_switch.veryl":36:23)
+  %49 = comb.icmp eq %42, %13 : i32 loc("../veryl/16_case_switch.veryl":37:15)
+  %50 = hw.constant 6 : i32 loc("../veryl/16_case_switch.veryl":37:26)
+  %51 = comb.icmp eq %42, %50 : i32 loc("../veryl/16_case_switch.veryl":37:26)
+  %52 = comb.or %49, %51 : i1 loc("../veryl/16_case_switch.veryl":37:26)
+  %53 = comb.icmp eq %42, %15 : i32 loc("../veryl/16_case_switch.veryl":37:37)
+  %54 = comb.or %52, %53 : i1 loc("../veryl/16_case_switch.veryl":37:37)
+  %55 = hw.constant 8 : i32 loc("../veryl/16_case_switch.veryl":37:48)
+  %56 = comb.icmp eq %42, %55 : i32 loc("../veryl/16_case_switch.veryl":37:48)
+  %57 = comb.or %54, %56 : i1 loc("../veryl/16_case_switch.veryl":37:48)
+  %58 = hw.constant 9 : i32 loc("../veryl/16_case_switch.veryl":38:15)
+  %59 = comb.icmp eq %42, %58 : i32 loc("../veryl/16_case_switch.veryl":38:15)
+  %60 = comb.or %57, %59 : i1 loc("../veryl/16_case_switch.veryl":38:15)
+  %61 = comb.icmp eq %42, %18 : i32 loc("../veryl/16_case_switch.veryl":38:26)
+  %62 = comb.or %60, %61 : i1 loc("../veryl/16_case_switch.veryl":38:26)
+  %63 = comb.icmp eq %42, %20 : i32 loc("../veryl/16_case_switch.veryl":38:37)
+  %64 = comb.or %62, %63 : i1 loc("../veryl/16_case_switch.veryl":38:37)
+  %65 = comb.icmp eq %42, %23 : i32 loc("../veryl/16_case_switch.veryl":38:48)
+  %66 = comb.or %64, %65 : i1 loc("../veryl/16_case_switch.veryl":38:48)
+  %67 = comb.icmp eq %42, %26 : i32 loc("../veryl/16_case_switch.veryl":39:15)
+  %68 = comb.or %66, %67 : i1 loc("../veryl/16_case_switch.veryl":39:15)
+  %69 = comb.icmp eq %42, %29 : i32 loc("../veryl/16_case_switch.veryl":39:26)
+  %70 = comb.or %68, %69 : i1 loc("../veryl/16_case_switch.veryl":39:26)
+  %71 = comb.icmp eq %42, %32 : i32 loc("../veryl/16_case_switch.veryl":39:37)
+  %72 = comb.or %70, %71 : i1 loc("../veryl/16_case_switch.veryl":39:37)
+  %73 = comb.icmp eq %42, %35 : i32 loc("../veryl/16_case_switch.veryl":39:48)
+  %74 = comb.or %72, %73 : i1 loc("../veryl/16_case_switch.veryl":39:48)
+  %75 = hw.constant true loc("../veryl/16_case_switch.veryl":4:9)
+  %a = hw.wire %75 : i1 loc("../veryl/16_case_switch.veryl":4:9)
+  %b = hw.wire %75 : i1 loc("../veryl/16_case_switch.veryl":5:9)
+  %x = hw.wire %75 : i1 loc("../veryl/16_case_switch.veryl":6:9)
+  %76 = hw.constant 1 : i3 loc("../veryl/16_case_switch.veryl":7:9)
+  %z = hw.wire %76 : i3 loc("../veryl/16_case_switch.veryl":7:9)
+  hw.output loc("../veryl/16_case_switch.veryl":43:1)
+} loc("../veryl/16_case_switch.veryl":1:1)
@@ -0,0 +1,32 @@
+hw.module @veryl_testcase_Module18() {
+  %0 = comb.extract %a from 0 : (i20) -> i11 loc("../veryl/18_concatenation.veryl":6:5)
+  %1 = comb.concat %0, %c : i11, i1 loc("../veryl/18_concatenation.veryl":6:5)
+  %2 = hw.constant 0 : i8 loc("../veryl/18_concatenation.veryl":6:5)
+  %3 = comb.concat %2, %1 : i8, i12 loc("../veryl/18_concatenation.veryl":6:5)
+  %4 = comb.replicate %0 : (i11) -> i110 loc("../veryl/18_concatenation.veryl":7:5)
+  %5 = comb.replicate %c : (i1) -> i4 loc("../veryl/18_concatenation.veryl":7:5)
+  %6 = comb.concat %4, %5 : i110, i4 loc("../veryl/18_concatenation.veryl":7:5)
+  %7 = comb.extract %6 from 0 : (i114) -> i1 loc("../veryl/18_concatenation.veryl":7:5)
+  %8 = comb.replicate %a : (i20) -> i160 loc("../veryl/18_concatenation.veryl":10:9)
+  %9 = comb.replicate %b : (i1) -> i8 loc("../veryl/18_concatenation.veryl":10:9)
+  %10 = comb.replicate %c : (i1) -> i8 loc("../veryl/18_concatenation.veryl":10:9)
+  %11 = comb.concat %8, %9, %10, %8, %9, %10, %8, %9, %10, %8, %9, %10, %8, %9, %10, %8, %9, %10, %8, %9, %10 : i160, i8, i8, i160, i8, i8, i160, i8, i8, i160, i8, i8, i160, i8, i8, i160, i8, i8, i160, i8, i8 loc("../veryl/18_concatenation.veryl":10:9)
+  %12 = comb.extract %11 from 0 : (i1232) -> i1 loc("../veryl/18_concatenation.veryl":10:9)
+  %13 = hw.constant false loc("../veryl/18_concatenation.veryl":22:5)
+  %14 = comb.concat %13, %a : i1, i20 loc("../veryl/18_concatenation.veryl":22:5)
+  %15 = comb.extract %14 from 1 : (i21) -> i20 loc("../veryl/18_concatenation.veryl":22:5)
+  %16 = comb.extract %14 from 0 : (i21) -> i1 loc("../veryl/18_concatenation.veryl":22:5)
+  %17 = hw.constant true loc("../veryl/18_concatenation.veryl":25:9)
+  hw.instance "u" @veryl_testcase_Module18A(a: %17: i1, b: %13: i1) -> () loc("../veryl/18_concatenation.veryl":24:10)
+  %a = hw.wire %3 : i20 loc("../veryl/18_concatenation.veryl":2:9)
+  %b = hw.wire %7 : i1 loc("../veryl/18_concatenation.veryl":3:9)
+  %c = hw.wire %17 : i1 loc("../veryl/18_concatenation.veryl":4:9)
+  %_d = hw.wire %12 : i1 loc("../veryl/18_concatenation.veryl":10:9)
+  %d = hw.wire %15 : i20 loc("../veryl/18_concatenation.veryl":20:9)
+  %e = hw.wire %16 : i1 loc("../veryl/18_concatenation.veryl":21:9)
+  hw.output loc("../veryl/18_concatenation.veryl":32:1)
+} loc("../veryl/18_concatenation.veryl":1:1)
+
+hw.module @veryl_testcase_Module18A(in %a : i1, in %b : i1) {
+  hw.output loc("../veryl/18_concatenation.veryl":37:4)
+} loc("../veryl/18_concatenation.veryl":34:1)
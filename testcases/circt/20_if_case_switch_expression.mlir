@@ -0,0 +1,38 @@
+hw.module @veryl_testcase_Module20() {
+  %0 = hw.constant 2 : i32 loc("../veryl/20_if_case_switch_expression.veryl":8:5)
+  %1 = hw.constant 3 : i32 loc("../veryl/20_if_case_switch_expression.veryl":8:5)
+  %2 = comb.mux %y, %0, %1 : i32 loc("../veryl/20_if_case_switch_expression.veryl":8:5)
+  %3 = hw.constant 1 : i32 loc("../veryl/20_if_case_switch_expression.veryl":8:5)
+  %4 = comb.mux %x, %3, %2 : i32 loc("../veryl/20_if_case_switch_expression.veryl":8:5)
+  %5 = comb.extract %4 from 0 : (i32) -> i1 loc("../veryl/20_if_case_switch_expression.veryl":8:5)
+  %6 = hw.constant 0 : i31 loc("../veryl/20_if_case_switch_expression.veryl":10:5)
+  %7 = comb.concat %6, %a : i31, i1 loc("../veryl/20_if_case_switch_expression.veryl":10:5)
+  %8 = comb.icmp eq %7, %3 : i32 loc("../veryl/20_if_case_switch_expression.veryl":10:16)
+  %9 = comb.icmp eq %7, %0 : i32 loc("../veryl/20_if_case_switch_expression.veryl":10:16)
+  %10 = comb.icmp eq %7, %1 : i32 loc("../veryl/20_if_case_switch_expression.veryl":10:16)
+  %11 = hw.constant 4 : i32 loc("../veryl/20_if_case_switch_expression.veryl":10:16)
+  %12 = comb.icmp eq %7, %11 : i32 loc("../veryl/20_if_case_switch_expression.veryl":10:16)
+  %13 = comb.or %10, %12 : i1 loc("../veryl/20_if_case_switch_expression.veryl":10:16)
+  %14 = hw.constant 5 : i32 loc("../veryl/20_if_case_switch_expression.veryl":10:16)
+  %15 = comb.icmp uge %7, %14 : i32 loc("../veryl/20_if_case_switch_expression.veryl":10:16)
+  %16 = hw.constant 7 : i32 loc("../veryl/20_if_case_switch_expression.veryl":10:16)
+  %17 = comb.icmp ule %7, %16 : i32 loc("../veryl/20_if_case_switch_expression.veryl":10:16)
+  %18 = comb.and %15, %17 : i1 loc("../veryl/20_if_case_switch_expression.veryl":10:16)
+  %19 = comb.mux %18, %1, %11 : i32 loc("../veryl/20_if_case_switch_expression.veryl":10:16)
+  %20 = comb.mux %13, %0, %19 : i32 loc("../veryl/20_if_case_switch_expression.veryl":10:16)
+  %21 = comb.mux %9, %3, %20 : i32 loc("../veryl/20_if_case_switch_expression.veryl":10:16)
+  %22 = hw.constant 0 : i32 loc("../veryl/20_if_case_switch_expression.veryl":10:16)
+  %23 = comb.mux %8, %22, %21 : i32 loc("../veryl/20_if_case_switch_expression.veryl":10:16)
+  %24 = comb.extract %23 from 0 : (i32) -> i1 loc("../veryl/20_if_case_switch_expression.veryl":10:16)
+  %25 = comb.mux %13, %0, %1 : i32 loc("../veryl/20_if_case_switch_expression.veryl":18:16)
+  %26 = comb.mux %9, %3, %25 : i32 loc("../veryl/20_if_case_switch_expression.veryl":18:16)
+  %27 = comb.mux %8, %22, %26 : i32 loc("../veryl/20_if_case_switch_expression.veryl":18:16)
+  %28 = comb.extract %27 from 0 : (i32) -> i1 loc("../veryl/20_if_case_switch_expression.veryl":18:16)
+  %a = hw.wire %5 : i1 loc("../veryl/20_if_case_switch_expression.veryl":2:9)
+  %b = hw.wire %24 : i1 loc("../veryl/20_if_case_switch_expression.veryl":3:9)
+  %c = hw.wire %28 : i1 loc("../veryl/20_if_case_switch_expression.veryl":4:9)
+  %29 = hw.constant true loc("../veryl/20_if_case_switch_expression.veryl":5:9)
+  %x = hw.wire %29 : i1 loc("../veryl/20_if_case_switch_expression.veryl":5:9)
+  %y = hw.wire %29 : i1 loc("../veryl/20_if_case_switch_expression.veryl":6:9)
+  hw.output loc("../veryl/20_if_case_switch_expression.veryl":24:1)
+} loc("../veryl/20_if_case_switch_expression.veryl":1:1)
@@ -0,0 +1,8 @@
+hw.module @veryl_testcase_Module22() {
+  %0 = hw.constant 1 : i10 loc("../veryl/22_type_modifier.veryl":2:9)
+  %_a = hw.wire %0 : i10 loc("../veryl/22_type_modifier.veryl":2:9)
+  %_b = hw.wire %0 : i10 loc("../veryl/22_type_modifier.veryl":3:9)
+  %_c = hw.wire %0 : i10 loc("../veryl/22_type_modifier.veryl":4:9)
+  %_d = hw.wire %0 : i10 loc("../veryl/22_type_modifier.veryl":5:9)
+  hw.output loc("../veryl/22_type_modifier.veryl":6:1)
+} loc("../veryl/22_type_modifier.veryl":1:1)
@@ -0,0 +1,6 @@
+hw.module @veryl_testcase_Module24() {
+  %0 = hw.constant true loc("../veryl/24_sv_attribute.veryl":3:9)
+  %_a = hw.wire %0 : i1 loc("../veryl/24_sv_attribute.veryl":3:9)
+  %_b = hw.wire %0 : i1 loc("../veryl/24_sv_attribute.veryl":5:9)
+  hw.output loc("../veryl/24_sv_attribute.veryl":6:1)
+} loc("../veryl/24_sv_attribute.veryl":1:1)
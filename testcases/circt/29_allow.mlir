@@ -0,0 +1,15 @@
+hw.module @veryl_testcase_Module29(in %clk : !seq.clock, in %rst_n : i1) {
+  %0 = hw.constant true loc("../veryl/29_allow.veryl":11:5)
+  %1 = comb.xor %rst_n, %0 : i1 loc("../veryl/29_allow.veryl":11:5)
+  %2 = hw.constant false loc("../veryl/29_allow.veryl":11:5)
+  %a = seq.firreg %2 clock %clk reset async %1, %2 : i1 loc("../veryl/29_allow.veryl":11:5)
+  %b = seq.firreg %2 clock %clk : i1 loc("../veryl/29_allow.veryl":11:5)
+  %5 = seq.to_clock %2 loc("../veryl/29_allow.veryl":21:10)
+  hw.instance "u0" @veryl_testcase_Module29A(clk: %5: !seq.clock, rst_n: %2: i1) -> () loc("../veryl/29_allow.veryl":21:10)
+  %c = hw.wire %0 : i1 loc("../veryl/29_allow.veryl":8:9)
+  hw.output loc("../veryl/29_allow.veryl":22:1)
+} loc("../veryl/29_allow.veryl":1:1)
+
+hw.module @veryl_testcase_Module29A(in %clk : !seq.clock, in %rst_n : i1) {
+  hw.output loc("../veryl/29_allow.veryl":27:4)
+} loc("../veryl/29_allow.veryl":24:1)
@@ -0,0 +1,7 @@
+hw.module @veryl_testcase_Module32() {
+  %0 = hw.constant true loc("../veryl/32_inside_outside.veryl":2:9)
+  %a = hw.wire %0 : i1 loc("../veryl/32_inside_outside.veryl":2:9)
+  %1 = hw.constant false loc("../veryl/32_inside_outside.veryl":3:9)
+  %b = hw.wire %1 : i1 loc("../veryl/32_inside_outside.veryl":3:9)
+  hw.output loc("../veryl/32_inside_outside.veryl":7:1)
+} loc("../veryl/32_inside_outside.veryl":1:1)
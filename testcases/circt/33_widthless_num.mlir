@@ -0,0 +1,3 @@
+hw.module @veryl_testcase_Module33() {
+  hw.output loc("../veryl/33_widthless_num.veryl":11:1)
+} loc("../veryl/33_widthless_num.veryl":1:1)
@@ -0,0 +1,15 @@
+hw.module @veryl_testcase_Module35() {
+  %0 = hw.constant 0 : i31 loc("../veryl/35_unconnected_port.veryl":5:9)
+  %1 = comb.concat %0, %aa : i31, i1 loc("../veryl/35_unconnected_port.veryl":5:9)
+  %xx.bb, %xx.bbbb = hw.instance "xx" @veryl_testcase_Module35B(aa: %1: i32) -> (bb: i32, bbbb: i32) loc("../veryl/35_unconnected_port.veryl":4:10)
+  %2 = hw.constant true loc("../veryl/35_unconnected_port.veryl":2:9)
+  %aa = hw.wire %2 : i1 loc("../veryl/35_unconnected_port.veryl":2:9)
+  hw.output loc("../veryl/35_unconnected_port.veryl":9:1)
+} loc("../veryl/35_unconnected_port.veryl":1:1)
+
+hw.module @veryl_testcase_Module35B(in %aa : i32, out bb : i32, out bbbb : i32) {
+  %0 = hw.constant 0 : i32 loc("../veryl/35_unconnected_port.veryl":13:5)
+  %bb = hw.wire %0 : i32 loc("../veryl/35_unconnected_port.veryl":13:5)
+  %bbbb = hw.wire %0 : i32 loc("../veryl/35_unconnected_port.veryl":14:5)
+  hw.output %bb, %bbbb : i32, i32 loc("../veryl/35_unconnected_port.veryl":20:1)
+} loc("../veryl/35_unconnected_port.veryl":11:1)
@@ -0,0 +1,5 @@
+hw.module @veryl_testcase_Module40() {
+  %0 = hw.constant 0 : i2 loc("../veryl/40_enum_resolve.veryl":10:9)
+  %a = hw.wire %0 : i2 loc("../veryl/40_enum_resolve.veryl":10:9)
+  hw.output loc("../veryl/40_enum_resolve.veryl":18:1)
+} loc("../veryl/40_enum_resolve.veryl":1:1)
@@ -0,0 +1,7 @@
+hw.module @veryl_testcase_Module41() {
+  %0 = hw.constant false loc("../veryl/41_union.veryl":12:9)
+  %foo = hw.wire %0 : i1 loc("../veryl/41_union.veryl":12:9)
+  %1 = hw.constant true loc("../veryl/41_union.veryl":15:9)
+  %bar = hw.wire %1 : i1 loc("../veryl/41_union.veryl":15:9)
+  hw.output loc("../veryl/41_union.veryl":17:1)
+} loc("../veryl/41_union.veryl":1:1)
@@ -0,0 +1,7 @@
+hw.module @veryl_testcase_Module44() {
+  %0 = hw.constant 0 : i10 loc("../veryl/44_import_resolve.veryl":10:9)
+  %a = hw.wire %0 : i10 loc("../veryl/44_import_resolve.veryl":10:9)
+  %b = hw.wire %0 : i10 loc("../veryl/44_import_resolve.veryl":11:9)
+  %c = hw.wire %0 : i10 loc("../veryl/44_import_resolve.veryl":12:9)
+  hw.output loc("../veryl/44_import_resolve.veryl":20:1)
+} loc("../veryl/44_import_resolve.veryl":9:1)
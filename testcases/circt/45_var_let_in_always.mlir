@@ -0,0 +1,9 @@
+hw.module @veryl_testcase_Module45() {
+  %0 = seq.to_clock %a loc("../veryl/45_var_let_in_always.veryl":6:5)
+  %1 = hw.constant 1 : i10 loc("../veryl/45_var_let_in_always.veryl":6:5)
+  %b = seq.firreg %1 clock %0 : i10 loc("../veryl/45_var_let_in_always.veryl":6:5)
+  %3 = hw.constant true loc("../veryl/45_var_let_in_always.veryl":2:9)
+  %a = hw.wire %3 : i1 loc("../veryl/45_var_let_in_always.veryl":2:9)
+  %c = hw.wire %1 : i10 loc("../veryl/45_var_let_in_always.veryl":4:9)
+  hw.output loc("../veryl/45_var_let_in_always.veryl":16:1)
+} loc("../veryl/45_var_let_in_always.veryl":1:1)
@@ -0,0 +1,11 @@
+hw.module @veryl_testcase_Module46() {
+  %0 = seq.to_clock %a loc("../veryl/46_var_let_anywhere.veryl":8:5)
+  %1 = hw.constant 1 : i10 loc("../veryl/46_var_let_anywhere.veryl":8:5)
+  %b = seq.firreg %1 clock %0 : i10 loc("../veryl/46_var_let_anywhere.veryl":8:5)
+  %d = seq.firreg %1 clock %0 : i10 loc("../veryl/46_var_let_anywhere.veryl":8:5)
+  %4 = hw.constant true loc("../veryl/46_var_let_anywhere.veryl":2:9)
+  %a = hw.wire %4 : i1 loc("../veryl/46_var_let_anywhere.veryl":2:9)
+  %c = hw.wire %1 : i10 loc("../veryl/46_var_let_anywhere.veryl":4:9)
+  %e = hw.wire %1 : i10 loc("../veryl/46_var_let_anywhere.veryl":6:9)
+  hw.output loc("../veryl/46_var_let_anywhere.veryl":39:1)
+} loc("../veryl/46_var_let_anywhere.veryl":1:1)
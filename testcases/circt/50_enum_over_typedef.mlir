@@ -0,0 +1,7 @@
+hw.module @veryl_testcase_Module50() {
+  %0 = hw.constant false loc("../veryl/50_enum_over_typedef.veryl":11:9)
+  %_a = hw.wire %0 : i1 loc("../veryl/50_enum_over_typedef.veryl":11:9)
+  %1 = hw.constant true loc("../veryl/50_enum_over_typedef.veryl":12:9)
+  %_b = hw.wire %1 : i1 loc("../veryl/50_enum_over_typedef.veryl":12:9)
+  hw.output loc("../veryl/50_enum_over_typedef.veryl":13:1)
+} loc("../veryl/50_enum_over_typedef.veryl":8:1)
@@ -0,0 +1,5 @@
+hw.module @veryl_testcase_Module53() {
+  %0 = hw.constant false loc("../veryl/53_multiline_comment_case.veryl":6:9)
+  %_a = hw.wire %0 : i1 loc("../veryl/53_multiline_comment_case.veryl":6:9)
+  hw.output loc("../veryl/53_multiline_comment_case.veryl":15:1)
+} loc("../veryl/53_multiline_comment_case.veryl":1:1)
@@ -0,0 +1,16 @@
+hw.module @veryl_testcase_Module60A(in %i_clk_a : !seq.clock, in %i_rst_a_n : i1, in %i_dat_a : i1, out o_dat_a : i1, in %i_clk_b : !seq.clock, in %i_rst_b_n : i1, in %i_dat_b : i1, out o_dat_b : i1) {
+  %o_dat_a = hw.wire %i_dat_a : i1 loc("../veryl/60_clock_domain.veryl":5:5)
+  %o_dat_b = hw.wire %i_dat_b : i1 loc("../veryl/60_clock_domain.veryl":9:5)
+  hw.output %o_dat_a, %o_dat_b : i1, i1 loc("../veryl/60_clock_domain.veryl":13:1)
+} loc("../veryl/60_clock_domain.veryl":1:5)
+
+hw.module @veryl_testcase_Module60B(in %i_clk : !seq.clock, in %i_clk_x2 : !seq.clock, in %i_dat : i1, out o_dat : i1) {
+  %o_dat = hw.wire %i_dat : i1 loc("../veryl/60_clock_domain.veryl":19:5)
+  hw.output %o_dat : i1 loc("../veryl/60_clock_domain.veryl":22:1)
+} loc("../veryl/60_clock_domain.veryl":15:5)
+
+hw.module @veryl_testcase_Module60C(in %i_clk : !seq.clock, in %i_dat : i1, out o_dat : i1, in %i_thr : i1, out o_thr : i1) {
+  %o_dat = hw.wire %i_dat : i1 loc("../veryl/60_clock_domain.veryl":27:5)
+  %o_thr = hw.wire %i_thr : i1 loc("../veryl/60_clock_domain.veryl":29:5)
+  hw.output %o_dat, %o_thr : i1, i1 loc("../veryl/60_clock_domain.veryl":33:1)
+} loc("../veryl/60_clock_domain.veryl":24:5)
@@ -0,0 +1,9 @@
+hw.module @veryl_testcase_Module62() {
+  %0 = hw.constant true loc("../veryl/62_raw_identifier.veryl":2:9)
+  %clock = hw.wire %0 : i1 loc("../veryl/62_raw_identifier.veryl":2:9)
+  %reset = hw.wire %0 : i1 loc("../veryl/62_raw_identifier.veryl":3:9)
+  %1 = hw.constant false loc("../veryl/62_raw_identifier.veryl":4:9)
+  %in = hw.wire %1 : i1 loc("../veryl/62_raw_identifier.veryl":4:9)
+  %out = hw.wire %in : i1 loc("../veryl/62_raw_identifier.veryl":5:9)
+  hw.output loc("../veryl/62_raw_identifier.veryl":9:1)
+} loc("../veryl/62_raw_identifier.veryl":1:1)
@@ -0,0 +1,13 @@
+hw.module @veryl_testcase_Module63(in %i_clk : !seq.clock, in %i_rst_n : i1, in %i_data_a : i1) {
+  hw.instance "u" @veryl_testcase_Module63A(i_clk: %i_clk: !seq.clock, i_rst_n: %i_rst_n: i1, i_data_a: %i_data_a: i1) -> () loc("../veryl/63_prefix_suffix.veryl":6:10)
+  %0 = hw.constant true loc("../veryl/63_prefix_suffix.veryl":14:5)
+  %1 = comb.xor %i_rst_n, %0 : i1 loc("../veryl/63_prefix_suffix.veryl":14:5)
+  %2 = hw.constant false loc("../veryl/63_prefix_suffix.veryl":14:5)
+  %a = seq.firreg %0 clock %i_clk reset async %1, %2 : i1 loc("../veryl/63_prefix_suffix.veryl":14:5)
+  %_b = hw.wire %i_rst_n : i1 loc("../veryl/63_prefix_suffix.veryl":22:9)
+  hw.output loc("../veryl/63_prefix_suffix.veryl":23:1)
+} loc("../veryl/63_prefix_suffix.veryl":1:1)
+
+hw.module @veryl_testcase_Module63A(in %i_clk : !seq.clock, in %i_rst_n : i1, in %i_data_a : i1) {
+  hw.output loc("../veryl/63_prefix_suffix.veryl":29:4)
+} loc("../veryl/63_prefix_suffix.veryl":25:1)
@@ -0,0 +1,20 @@
+hw.module @veryl_testcase_Module65() {
+  %0 = hw.constant true loc("../veryl/65_cast_to_clock_reset.veryl":2:9)
+  %a = hw.wire %0 : i1 loc("../veryl/65_cast_to_clock_reset.veryl":2:9)
+  %b_n = hw.wire %0 : i1 loc("../veryl/65_cast_to_clock_reset.veryl":3:9)
+  %c = hw.wire %0 : i1 loc("../veryl/65_cast_to_clock_reset.veryl":4:9)
+  %d = hw.wire %0 : i1 loc("../veryl/65_cast_to_clock_reset.veryl":5:9)
+  %e = hw.wire %0 : i1 loc("../veryl/65_cast_to_clock_reset.veryl":6:9)
+  %f = hw.wire %0 : i1 loc("../veryl/65_cast_to_clock_reset.veryl":7:9)
+  %_x0 = hw.wire %a : i1 loc("../veryl/65_cast_to_clock_reset.veryl":9:9)
+  %_x1 = hw.wire %a : i1 loc("../veryl/65_cast_to_clock_reset.veryl":10:9)
+  %_x2 = hw.wire %b_n : i1 loc("../veryl/65_cast_to_clock_reset.veryl":11:9)
+  %_x3 = hw.wire %b_n : i1 loc("../veryl/65_cast_to_clock_reset.veryl":12:9)
+  %_x4 = hw.wire %b_n : i1 loc("../veryl/65_cast_to_clock_reset.veryl":13:9)
+  %_x5 = hw.wire %b_n : i1 loc("../veryl/65_cast_to_clock_reset.veryl":14:9)
+  %_x6_n = hw.wire %c : i1 loc("../veryl/65_cast_to_clock_reset.veryl":15:9)
+  %_x7_n = hw.wire %d : i1 loc("../veryl/65_cast_to_clock_reset.veryl":16:9)
+  %_x8_n = hw.wire %e : i1 loc("../veryl/65_cast_to_clock_reset.veryl":17:9)
+  %_x9_n = hw.wire %f : i1 loc("../veryl/65_cast_to_clock_reset.veryl":18:9)
+  hw.output loc("../veryl/65_cast_to_clock_reset.veryl":19:1)
+} loc("../veryl/65_cast_to_clock_reset.veryl":1:1)
@@ -0,0 +1,27 @@
+hw.module @veryl_testcase_Module72(in %i_clk : !seq.clock, in %i_rst_n : i1) {
+  %0 = hw.constant 0 : i31 loc("../veryl/72_cond_type.veryl":18:9)
+  %1 = comb.concat %0, %x : i31, i1 loc("../veryl/72_cond_type.veryl":18:9)
+  %2 = hw.constant 0 : i32 loc("../veryl/72_cond_type.veryl":18:9)
+  %3 = comb.icmp eq %1, %2 : i32 loc("../veryl/72_cond_type.veryl":18:9)
+  %4 = hw.constant 1 : i32 loc("../veryl/72_cond_type.veryl":18:9)
+  %5 = comb.icmp eq %1, %4 : i32 loc("../veryl/72_cond_type.veryl":18:9)
+  %6 = hw.constant true loc("../veryl/72_cond_type.veryl":20:18)
+  %7 = hw.constant false loc("../veryl/72_cond_type.veryl":20:18)
+  %8 = comb.mux %5, %6, %7 : i1 loc("../veryl/72_cond_type.veryl":20:18)
+  %9 = comb.mux %3, %6, %8 : i1 loc("../veryl/72_cond_type.veryl":20:18)
+  %10 = comb.xor %i_rst_n, %6 : i1 loc("../veryl/72_cond_type.veryl":55:5)
+  %11 = comb.mux %5, %6, %g : i1 loc("../veryl/72_cond_type.veryl":60:15)
+  %g = seq.firreg %11 clock %i_clk reset async %10, %6 : i1 loc("../veryl/72_cond_type.veryl":55:5)
+  %13 = comb.mux %5, %6, %h : i1 loc("../veryl/72_cond_type.veryl":68:15)
+  %h = seq.firreg %13 clock %i_clk reset async %10, %6 : i1 loc("../veryl/72_cond_type.veryl":63:5)
+  %15 = comb.mux %5, %6, %i : i1 loc("../veryl/72_cond_type.veryl":76:15)
+  %i = seq.firreg %15 clock %i_clk reset async %10, %6 : i1 loc("../veryl/72_cond_type.veryl":71:5)
+  %x = hw.wire %6 : i1 loc("../veryl/72_cond_type.veryl":5:9)
+  %a = hw.wire %9 : i1 loc("../veryl/72_cond_type.veryl":6:9)
+  %b = hw.wire %9 : i1 loc("../veryl/72_cond_type.veryl":7:9)
+  %c = hw.wire %9 : i1 loc("../veryl/72_cond_type.veryl":8:9)
+  %d = hw.wire %9 : i1 loc("../veryl/72_cond_type.veryl":9:9)
+  %e = hw.wire %9 : i1 loc("../veryl/72_cond_type.veryl":10:9)
+  %f = hw.wire %9 : i1 loc("../veryl/72_cond_type.veryl":11:9)
+  hw.output loc("../veryl/72_cond_type.veryl":79:1)
+} loc("../veryl/72_cond_type.veryl":1:1)
@@ -0,0 +1,31 @@
+hw.module @veryl_testcase_Module74() {
+  %0 = comb.extract %a from 0 : (i32) -> i1 loc("../veryl/74_align.veryl":6:9)
+  %1 = comb.extract %aa from 1 : (i32) -> i1 loc("../veryl/74_align.veryl":6:9)
+  %2 = comb.replicate %1 : (i1) -> i8 loc("../veryl/74_align.veryl":6:9)
+  %3 = comb.extract %aaa from 2 : (i32) -> i1 loc("../veryl/74_align.veryl":6:9)
+  %4 = comb.replicate %3 : (i1) -> i16 loc("../veryl/74_align.veryl":6:9)
+  %5 = comb.extract %a from 3 : (i32) -> i1 loc("../veryl/74_align.veryl":6:9)
+  %6 = comb.extract %aa from 4 : (i32) -> i1 loc("../veryl/74_align.veryl":6:9)
+  %7 = comb.replicate %6 : (i1) -> i8 loc("../veryl/74_align.veryl":6:9)
+  %8 = comb.extract %aaa from 5 : (i32) -> i1 loc("../veryl/74_align.veryl":6:9)
+  %9 = comb.replicate %8 : (i1) -> i16 loc("../veryl/74_align.veryl":6:9)
+  %10 = comb.extract %a from 6 : (i32) -> i1 loc("../veryl/74_align.veryl":6:9)
+  %11 = comb.extract %aa from 7 : (i32) -> i1 loc("../veryl/74_align.veryl":6:9)
+  %12 = comb.replicate %11 : (i1) -> i8 loc("../veryl/74_align.veryl":6:9)
+  %13 = comb.extract %aaa from 8 : (i32) -> i1 loc("../veryl/74_align.veryl":6:9)
+  %14 = comb.replicate %13 : (i1) -> i16 loc("../veryl/74_align.veryl":6:9)
+  %15 = comb.extract %a from 9 : (i32) -> i1 loc("../veryl/74_align.veryl":6:9)
+  %16 = comb.extract %aa from 10 : (i32) -> i1 loc("../veryl/74_align.veryl":6:9)
+  %17 = comb.replicate %16 : (i1) -> i8 loc("../veryl/74_align.veryl":6:9)
+  %18 = comb.extract %aaa from 11 : (i32) -> i1 loc("../veryl/74_align.veryl":6:9)
+  %19 = comb.replicate %18 : (i1) -> i16 loc("../veryl/74_align.veryl":6:9)
+  %20 = comb.concat %0, %0, %2, %2, %4, %4, %5, %5, %7, %7, %9, %9, %10, %10, %12, %12, %14, %14, %15, %15, %17, %17, %19, %19 : i1, i1, i8, i8, i16, i16, i1, i1, i8, i8, i16, i16, i1, i1, i8, i8, i16, i16, i1, i1, i8, i8, i16, i16 loc("../veryl/74_align.veryl":6:9)
+  %21 = comb.extract %20 from 0 : (i200) -> i1 loc("../veryl/74_align.veryl":6:9)
+  %22 = hw.constant 1 : i32 loc("../veryl/74_align.veryl":2:9)
+  %a = hw.wire %22 : i32 loc("../veryl/74_align.veryl":2:9)
+  %aa = hw.wire %22 : i32 loc("../veryl/74_align.veryl":3:9)
+  %aaa = hw.wire %22 : i32 loc("../veryl/74_align.veryl":4:9)
+  %_b = hw.wire %21 : i1 loc("../veryl/74_align.veryl":6:9)
+  %_c = hw.wire %21 : i1 loc("../veryl/74_align.veryl":22:9)
+  hw.output loc("../veryl/74_align.veryl":36:1)
+} loc("../veryl/74_align.veryl":1:1)
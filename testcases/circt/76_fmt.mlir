@@ -0,0 +1,38 @@
+hw.module @veryl_testcase_Module76() {
+  %0 = hw.constant true loc("../veryl/76_fmt.veryl":3:42)
+  %u0.y = hw.instance "u0" @veryl_testcase_Module76A__veryl_testcase_Module76_0(x: %0: i1) -> (y: i1) loc("../veryl/76_fmt.veryl":3:10)
+  %u1.y = hw.instance "u1" @veryl_testcase_Module76A__veryl_testcase_Module76_0(x: %0: i1) -> (y: i1) loc("../veryl/76_fmt.veryl":7:14)
+  %u2.y = hw.instance "u2" @veryl_testcase_Module76A__veryl_testcase_Module76_0(x: %0: i1) -> (y: i1) loc("../veryl/76_fmt.veryl":8:14)
+  %u3.y = hw.instance "u3" @veryl_testcase_Module76A__veryl_testcase_Module76_0(x: %0: i1) -> (y: i1) loc("../veryl/76_fmt.veryl":9:14)
+  %u4.y = hw.instance "u4" @veryl_testcase_Module76A__veryl_testcase_Module76_0(x: %0: i1) -> (y: i1) loc("../veryl/76_fmt.veryl":10:14)
+  %1 = hw.constant 0 : i31 loc("../veryl/76_fmt.veryl":14:26)
+  %2 = comb.concat %1, %a : i31, i1 loc("../veryl/76_fmt.veryl":14:26)
+  %3 = hw.constant 1 : i32 loc("../veryl/76_fmt.veryl":14:26)
+  %4 = comb.icmp eq %2, %3 : i32 loc("../veryl/76_fmt.veryl":14:26)
+  %5 = hw.constant 2 : i32 loc("../veryl/76_fmt.veryl":16:12)
+  %6 = comb.icmp eq %2, %5 : i32 loc("../veryl/76_fmt.veryl":16:12)
+  %7 = hw.constant 3 : i32 loc("../veryl/76_fmt.veryl":18:12)
+  %8 = comb.icmp eq %2, %7 : i32 loc("../veryl/76_fmt.veryl":18:12)
+  %9 = hw.constant 241785163922925834941235 : i128 loc("../veryl/76_fmt.veryl":18:12)
+  %10 = hw.constant 322380218563901113254980 : i128 loc("../veryl/76_fmt.veryl":18:12)
+  %11 = comb.mux %8, %9, %10 : i128 loc("../veryl/76_fmt.veryl":18:12)
+  %12 = hw.constant 161190109281950556627490 : i128 loc("../veryl/76_fmt.veryl":18:12)
+  %13 = comb.mux %6, %12, %11 : i128 loc("../veryl/76_fmt.veryl":18:12)
+  %14 = hw.constant 80595054640975278313745 : i128 loc("../veryl/76_fmt.veryl":18:12)
+  %15 = comb.mux %4, %14, %13 : i128 loc("../veryl/76_fmt.veryl":18:12)
+  %16 = comb.extract %15 from 0 : (i128) -> i1 loc("../veryl/76_fmt.veryl":18:12)
+  %a = hw.wire %0 : i1 loc("../veryl/76_fmt.veryl":13:9)
+  %_b = hw.wire %16 : i1 loc("../veryl/76_fmt.veryl":14:9)
+  %_c = hw.wire %16 : i1 loc("../veryl/76_fmt.veryl":24:9)
+  hw.output loc("../veryl/76_fmt.veryl":25:1)
+} loc("../veryl/76_fmt.veryl":1:1)
+
+hw.module @veryl_testcase_Module76A__veryl_testcase_Module76_0(in %x : i1, out y : i1) {
+  %y = hw.wire %x : i1 loc("../veryl/76_fmt.veryl":32:5)
+  hw.output %y : i1 loc("../veryl/76_fmt.veryl":35:1)
+} loc("../veryl/76_fmt.veryl":27:1)
+
+hw.module @veryl_testcase_Module76A(in %x : i1, out y : i1) {
+  %y = hw.wire %x : i1 loc("../veryl/76_fmt.veryl":32:5)
+  hw.output %y : i1 loc("../veryl/76_fmt.veryl":35:1)
+} loc("../veryl/76_fmt.veryl":27:1)
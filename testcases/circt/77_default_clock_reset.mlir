@@ -0,0 +1,7 @@
+hw.module @veryl_testcase_Module77(in %i_clk_a : !seq.clock, in %i_rst_a_n : i1, in %i_clk_b : !seq.clock, in %i_rst_b_n : i1, in %i_d : i1, out o_d : i1) {
+  %0 = hw.constant true loc("../veryl/77_default_clock_reset.veryl":9:5)
+  %1 = comb.xor %i_rst_a_n, %0 : i1 loc("../veryl/77_default_clock_reset.veryl":9:5)
+  %2 = hw.constant false loc("../veryl/77_default_clock_reset.veryl":9:5)
+  %o_d = seq.firreg %i_d clock %i_clk_a reset async %1, %2 : i1 loc("../veryl/77_default_clock_reset.veryl":9:5)
+  hw.output %o_d : i1 loc("../veryl/77_default_clock_reset.veryl":16:1)
+} loc("../veryl/77_default_clock_reset.veryl":1:1)
@@ -74,4 +74,23 @@ module veryl_testcase_test4;
     end
 endmodule
 `endif
+
+`ifdef __veryl_test_veryl_testcase_test5__
+    `ifdef __veryl_wavedump_veryl_testcase_test5__
+        module __veryl_wavedump;
+            initial begin
+                $dumpfile("test5.vcd");
+                $dumpvars();
+            end
+        endmodule
+    `endif
+`ifndef SYNTHESIS
+module test5 #(parameter int WIDTH = 1);
+   initial begin
+       $display("%0d %0d", WIDTH, `__veryl_seed__);
+       $finish();
+   end
+endmodule
+`endif
+`endif
 //# sourceMappingURL=../map/48_test.sv.map
@@ -0,0 +1,41 @@
+module veryl_testcase_Module82 (
+    input  var logic         i_clk  ,
+    input  var logic         i_rst_n,
+    input  var logic         i_req  ,
+    input  var logic [8-1:0] i_data ,
+    output var logic         o_ack  ,
+    output var logic [8-1:0] o_data 
+);
+    logic         r_ack ;
+    logic [8-1:0] r_data;
+
+    always_ff @ (posedge i_clk, negedge i_rst_n) begin
+        if (!i_rst_n) begin
+            r_ack  <= 0;
+            r_data <= 0;
+        end else begin
+            r_ack <= i_req;
+            if (i_req) begin
+                r_data <= i_data;
+            end
+            `ifndef SYNTHESIS
+            assert (r_data <= 8'hff);
+            `endif
+        end
+    end
+
+    always_comb o_ack  = r_ack;
+    always_comb o_data = r_data;
+
+    // request is acknowledged in the next cycle
+    `ifndef SYNTHESIS
+    req_ack: assert property (@(posedge i_clk) disable iff (!i_rst_n) i_req |=> o_ack);
+    `endif
+    `ifndef SYNTHESIS
+    assume property (@(posedge i_clk) disable iff (!i_rst_n) !$isunknown(i_req));
+    `endif
+    `ifndef SYNTHESIS
+    cover property (@(posedge i_clk) disable iff (!i_rst_n) i_req && o_ack);
+    `endif
+endmodule
+//# sourceMappingURL=../map/82_assertion.sv.map
@@ -0,0 +1,60 @@
+library ieee;
+use ieee.std_logic_1164.all;
+use ieee.numeric_std.all;
+use ieee.numeric_std_unsigned.all;
+use ieee.math_real.all;
+use work.all;
+
+entity veryl_testcase_Module01 is
+end entity veryl_testcase_Module01;
+
+architecture rtl of veryl_testcase_Module01 is
+    -- integer
+    constant a : natural := 0123456789;
+    constant aa : natural := 01234_56789;
+
+    -- binary
+    constant b : std_logic_vector(31 downto 0) := 32b"01XZXZ";
+    constant bb : std_logic_vector(31 downto 0) := 32b"01_XZ_XZ";
+    constant bbb : std_logic_vector(31 downto 0) := 32sb"01_XZ_XZ";
+
+    -- octal
+    constant c : std_logic_vector(31 downto 0) := 32o"01234567XZXZ";
+    constant cc : std_logic_vector(31 downto 0) := 32o"01234_567XZXZ";
+    constant ccc : std_logic_vector(31 downto 0) := 32so"01234_567XZXZ";
+
+    -- decimal
+    constant d : natural := 0123456789;
+    constant dd : natural := 01234_56789;
+    constant ddd : natural := 01234_56789;
+
+    -- hex
+    constant e : std_logic_vector(127 downto 0) := 128x"0123456789ABCDEFXZABCDEFXZ";
+    constant ee : std_logic_vector(127 downto 0) := 128x"01234_5678_9ABC_DEFXZ_ABCD_EFXZ";
+    constant eee : std_logic_vector(127 downto 0) := 128sx"01234_5678_9ABC_DEFXZ_ABCD_EFXZ";
+
+    -- all0, all1, allx, allz
+    constant f : std_logic_vector(31 downto 0) := (others => '0');
+    constant ff : std_logic_vector(31 downto 0) := (others => '1');
+    constant fff : std_logic_vector(31 downto 0) := (others => 'X');
+    constant ffff : std_logic_vector(31 downto 0) := (others => 'X');
+    constant fffff : std_logic_vector(31 downto 0) := (others => 'Z');
+    constant ffffff : std_logic_vector(31 downto 0) := (others => 'Z');
+
+    -- floating point
+    constant g : natural := integer(0123456789.0123456789);
+    constant gg : natural := integer(0123456789.0123456789e+0123456789);
+    constant ggg : natural := integer(0123456789.0123456789e-0123456789);
+    constant gggg : natural := integer(0123456789.0123456789E+0123456789);
+    constant ggggg : natural := integer(0123456789.0123456789E-0123456789);
+
+    -- width-less based
+    constant h : std_logic_vector(31 downto 0) := to_slv(2#0#, 32);
+    constant hh : std_logic_vector(31 downto 0) := to_slv(2#0_0#, 32);
+    constant hhh : std_logic_vector(31 downto 0) := to_slv(8#0#, 32);
+    constant hhhh : std_logic_vector(31 downto 0) := to_slv(8#0_0#, 32);
+    constant hhhhh : std_logic_vector(31 downto 0) := to_slv(16#0#, 32);
+    constant hhhhhh : std_logic_vector(31 downto 0) := to_slv(16#0_0#, 32);
+begin
+end architecture rtl;
+--# sourceMappingURL=../map/01_number.vhd.map
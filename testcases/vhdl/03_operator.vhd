@@ -0,0 +1,112 @@
+library ieee;
+use ieee.std_logic_1164.all;
+use ieee.numeric_std.all;
+use ieee.numeric_std_unsigned.all;
+use ieee.math_real.all;
+use work.all;
+
+entity veryl_testcase_Module03 is
+end entity veryl_testcase_Module03;
+
+architecture rtl of veryl_testcase_Module03 is
+    -- unary arithmetic
+    signal \_a\ : std_logic;
+    signal \_aa\ : std_logic;
+
+    -- unary logical
+    signal \_b\ : std_logic;
+    signal \_bb\ : std_logic;
+
+    -- unary reduce
+    signal \_c\ : std_logic;
+    signal \_cc\ : std_logic;
+    signal \_ccc\ : std_logic;
+    signal \_cccc\ : std_logic;
+    signal \_ccccc\ : std_logic;
+    signal \_cccccc\ : std_logic;
+    signal \_ccccccc\ : std_logic;
+
+    -- binary arithmetic
+    signal \_d\ : std_logic;
+    signal \_dd\ : std_logic;
+    signal \_ddd\ : std_logic;
+    signal \_dddd\ : std_logic;
+    signal \_ddddd\ : std_logic;
+    signal \_dddddd\ : std_logic;
+
+    -- binary shift
+    signal \_e\ : std_logic;
+    signal \_ee\ : std_logic;
+    signal \_eee\ : std_logic;
+    signal \_eeee\ : std_logic;
+
+    -- binary compare
+    signal \_f\ : std_logic;
+    signal \_ff\ : std_logic;
+    signal \_fff\ : std_logic;
+    signal \_ffff\ : std_logic;
+    signal \_fffff\ : std_logic;
+    signal \_ffffff\ : std_logic;
+    signal \_fffffff\ : std_logic;
+    signal \_ffffffff\ : std_logic;
+    signal \_fffffffff\ : std_logic;
+    signal \_ffffffffff\ : std_logic;
+
+    -- binary bitwise
+    signal \_g\ : std_logic;
+    signal \_gg\ : std_logic;
+    signal \_ggg\ : std_logic;
+    signal \_gggg\ : std_logic;
+    signal \_ggggg\ : std_logic;
+
+    -- binary logical
+    signal \_h\ : std_logic;
+    signal \_hh\ : std_logic;
+begin
+    \_a\ <= to_slv(+1, 1)(0);
+    \_aa\ <= '1';
+
+    \_b\ <= not '1';
+    \_bb\ <= not '1';
+
+    \_c\ <= '1';
+    \_cc\ <= '1';
+    \_ccc\ <= '1';
+    \_cccc\ <= not '1';
+    \_ccccc\ <= not '1';
+    \_cccccc\ <= not '1';
+    \_ccccccc\ <= not '1';
+
+    \_d\ <= to_slv(1 ** 1, 1)(0);
+    \_dd\ <= to_slv(1 * 1, 1)(0);
+    \_ddd\ <= to_slv(1 / 1, 1)(0);
+    \_dddd\ <= to_slv(1 mod 1, 1)(0);
+    \_ddddd\ <= to_slv(1 + 1, 1)(0);
+    \_dddddd\ <= to_slv(1 - 1, 1)(0);
+
+    \_e\ <= to_slv(1 * 2 ** 1, 1)(0);
+    \_ee\ <= to_slv(1 / 2 ** 1, 1)(0);
+    \_eee\ <= to_slv(1 * 2 ** 1, 1)(0);
+    \_eeee\ <= to_slv(1 / 2 ** 1, 1)(0);
+
+    \_f\ <= '1' when 1 < 1 else '0';
+    \_ff\ <= '1' when 1 <= 1 else '0';
+    \_fff\ <= '1' when 1 > 1 else '0';
+    \_ffff\ <= '1' when 1 >= 1 else '0';
+    \_fffff\ <= '1' when 1 = 1 else '0';
+    \_ffffff\ <= '1' when 1 /= 1 else '0';
+    \_fffffff\ <= '1' when 1 = 1 else '0';
+    \_ffffffff\ <= '1' when 1 /= 1 else '0';
+    \_fffffffff\ <= '1' when 1 = 1 else '0';
+    \_ffffffffff\ <= '1' when 1 /= 1 else '0';
+
+    \_g\ <= '1' and '1';
+    \_gg\ <= '1' xor '1';
+    \_ggg\ <= '1' xnor '1';
+    \_gggg\ <= '1' xnor '1';
+    \_ggggg\ <= '1' or '1';
+
+    \_h\ <= '1' when 1 /= 0 and 1 /= 0 else '0';
+    \_hh\ <= '1' when 1 /= 0 or 1 /= 0 else '0';
+end architecture rtl;
+--# sourceMappingURL=../map/03_operator.vhd.map
@@ -0,0 +1,74 @@
+library ieee;
+use ieee.std_logic_1164.all;
+use ieee.numeric_std.all;
+use ieee.numeric_std_unsigned.all;
+use ieee.math_real.all;
+use work.all;
+
+entity veryl_testcase_Module09 is
+end entity veryl_testcase_Module09;
+
+architecture rtl of veryl_testcase_Module09 is
+    -- struct declaration
+    type \A\ is record
+        \a\ : std_logic_vector(9 downto 0);
+        aa  : std_logic_vector(9 downto 0);
+        aaa : std_logic_vector(31 downto 0);
+    end record \A\;
+
+    -- enum declaration
+    subtype \B\ is std_logic_vector(1 downto 0);
+    constant B_X : \B\ := 2d"1";
+    constant B_Y : \B\ := 2d"2";
+    constant B_Z : \B\ := 2d"3";
+
+    subtype \C\ is std_logic_vector(2 downto 0);
+    constant C_X : \C\ := 3d"2";
+    constant C_Y : \C\ := 3d"3";
+    constant C_Z : \C\ := 3d"4";
+
+    subtype \D\ is std_logic_vector(0 downto 0);
+    constant D_X : \D\ := 1d"0";
+
+    subtype \E\ is std_logic_vector(1 downto 0);
+    constant E_X : \E\ := 2d"0";
+    constant E_Y : \E\ := 2d"1";
+    constant E_Z : \E\ := 2d"2";
+
+    subtype \F\ is std_logic_vector(2 downto 0);
+    constant F_X : \F\ := 3d"1";
+    constant F_Y : \F\ := 3d"2";
+    constant F_Z : \F\ := 3d"4";
+
+    subtype \G\ is std_logic_vector(1 downto 0);
+    constant G_X : \G\ := 2d"0";
+    constant G_Y : \G\ := 2d"1";
+    constant G_Z : \G\ := 2d"3";
+
+    subtype \H\ is std_logic;
+    constant FOO_H_0 : \H\ := '0';
+    constant FOO_H_1 : \H\ := '1';
+
+    signal \a\ : \A\;
+    signal \b\ : \B\;
+    signal \c\ : \C\;
+    signal \d\ : \D\;
+    signal \e\ : \E\;
+    signal \f\ : \F\;
+    signal \g\ : \G\;
+    signal \h\ : \H\;
+    signal i : std_logic;
+begin
+    \a\.\a\ <= to_slv(1, \a\.\a\'length);
+    \a\.aa <= to_slv(1, \a\.aa'length);
+    \a\.aaa <= to_slv(1, \a\.aaa'length);
+    \b\ <= B_X;
+    \c\ <= C_X;
+    \d\ <= D_X;
+    \e\ <= E_X;
+    \f\ <= F_X;
+    \g\ <= G_X;
+    \h\ <= FOO_H_0;
+    i <= \a\.\a\(0);
+end architecture rtl;
+--# sourceMappingURL=../map/09_struct_enum.vhd.map
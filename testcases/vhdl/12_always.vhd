@@ -0,0 +1,186 @@
+library ieee;
+use ieee.std_logic_1164.all;
+use ieee.numeric_std.all;
+use ieee.numeric_std_unsigned.all;
+use ieee.math_real.all;
+use work.all;
+
+entity veryl_testcase_Module12_1 is
+    port (
+        i_clk   : in    std_logic;
+        i_rst_n : in    std_logic
+    );
+end entity veryl_testcase_Module12_1;
+
+architecture rtl of veryl_testcase_Module12_1 is
+    signal a : std_logic;
+    signal b : std_logic;
+    signal c : std_logic;
+begin
+    process (i_clk, i_rst_n) is
+    begin
+        if i_rst_n = '0' then
+            c <= '0';
+        elsif rising_edge(i_clk) then
+            c <= not a;
+        end if;
+    end process;
+
+    process (i_clk, i_rst_n) is
+    begin
+        if i_rst_n = '0' then
+            a <= '0';
+        elsif rising_edge(i_clk) then
+            a <= not a;
+        end if;
+    end process;
+
+    process (i_clk) is
+    begin
+        if rising_edge(i_clk) then
+            b <= a;
+        end if;
+    end process;
+end architecture rtl;
+
+library ieee;
+use ieee.std_logic_1164.all;
+use ieee.numeric_std.all;
+use ieee.numeric_std_unsigned.all;
+use ieee.math_real.all;
+use work.all;
+
+entity veryl_testcase_Module12_2 is
+    port (
+        i_clk    : in    std_logic;
+        i_clk_p  : in    std_logic;
+        i_clk_n  : in    std_logic;
+        i_rst_n  : in    std_logic;
+        i_rst_ah : in    std_logic;
+        i_rst_al : in    std_logic;
+        i_rst_sh : in    std_logic;
+        i_rst_sl : in    std_logic
+    );
+end entity veryl_testcase_Module12_2;
+
+architecture rtl of veryl_testcase_Module12_2 is
+    signal a : std_logic;
+    signal aa : std_logic;
+    signal aaa : std_logic;
+    signal b : std_logic;
+    signal c : std_logic_vector(9 downto 0);
+
+    -- if_reset with loop
+    signal d : std_logic_vector(9 downto 0);
+
+    -- if_reset with loop
+    signal e : std_logic_vector(9 downto 0);
+
+    -- if_reset by reset value initialized by function
+    constant X : std_logic := to_slv(integer(sin(real(1))), 1)(0);
+    signal f : std_logic;
+begin
+    b <= '1';
+    c <= to_slv(1, c'length);
+
+    -- always_ff declaration with default polarity
+    process (i_clk, i_rst_n) is
+    begin
+        if i_rst_n = '0' then
+            a <= '0';
+        elsif rising_edge(i_clk) then
+            if a then
+                a <= b;
+            else
+                a <= c(5 downto 0)(0);
+            end if;
+        end if;
+    end process;
+
+    -- always_ff declaration without reset
+    process (i_clk) is
+    begin
+        if rising_edge(i_clk) then
+            if a then
+                a <= b;
+            else
+                a <= c(5 downto 0)(0);
+            end if;
+        end if;
+    end process;
+
+    -- always_ff declaration with specified polarity
+    process (i_clk_p, i_rst_ah) is
+    begin
+        if i_rst_ah = '1' then
+            a <= '0';
+        elsif rising_edge(i_clk_p) then
+            a <= c(5 downto 0)(0);
+        end if;
+    end process;
+    process (i_clk_n, i_rst_al) is
+    begin
+        if i_rst_al = '0' then
+            a <= '0';
+        elsif falling_edge(i_clk_n) then
+            a <= c(5 downto 0)(0);
+        end if;
+    end process;
+    process (i_clk_p) is
+    begin
+        if rising_edge(i_clk_p) then
+            if i_rst_sh = '1' then
+                a <= '0';
+            else
+                a <= c(5 downto 0)(0);
+            end if;
+        end if;
+    end process;
+    process (i_clk_n) is
+    begin
+        if falling_edge(i_clk_n) then
+            if i_rst_sl = '0' then
+                a <= '0';
+            else
+                a <= c(5 downto 0)(0);
+            end if;
+        end if;
+    end process;
+    g: for i in 0 to 9 generate
+        process (i_clk, i_rst_n) is
+        begin
+            if i_rst_n = '0' then
+                d(i) <= to_slv(i, 1)(0);
+            elsif rising_edge(i_clk) then
+            end if;
+        end process;
+    end generate g;
+    process (i_clk, i_rst_n) is
+    begin
+        if i_rst_n = '0' then
+            for i in 0 to 9 loop
+                e(i) <= to_slv(i, 1)(0);
+            end loop;
+        elsif rising_edge(i_clk) then
+        end if;
+    end process;
+    process (i_clk, i_rst_n) is
+    begin
+        if i_rst_n = '0' then
+            f <= X;
+        elsif rising_edge(i_clk) then
+        end if;
+    end process;
+
+    -- always_comb declaration
+    process (all) is
+    begin
+        a <= '0';
+        aa <= '0';
+        aaa <= 'Z';
+
+        a <= to_slv(10 + 10, 1)(0);
+        aa <= resize(std_logic_vector'(10 + 16x"FFFF" * (3 / 4)), 1)(0);
+    end process;
+end architecture rtl;
+--# sourceMappingURL=../map/12_always.vhd.map
@@ -0,0 +1,34 @@
+library ieee;
+use ieee.std_logic_1164.all;
+use ieee.numeric_std.all;
+use ieee.numeric_std_unsigned.all;
+use ieee.math_real.all;
+use work.all;
+
+entity veryl_testcase_Module13 is
+end entity veryl_testcase_Module13;
+
+architecture rtl of veryl_testcase_Module13 is
+    signal a : std_logic;
+    signal b : std_logic;
+    signal c : std_logic;
+    signal d : std_logic;
+    signal e : std_logic;
+    signal X : std_logic_vector(9 downto 0);
+begin
+    X <= to_slv(1, X'length);
+
+    -- bit select
+    a <= X(0);
+
+    -- range select
+    b <= X(1 downto 0)(0);
+
+    -- position and width
+    c <= X(1 + 2 - 1 downto 1)(0);
+    d <= X(1 downto 1 - 2 + 1)(0);
+
+    -- index by step
+    e <= X((1 + 1) * 2 - 1 downto 1 * 2)(0);
+end architecture rtl;
+--# sourceMappingURL=../map/13_range_operator.vhd.map
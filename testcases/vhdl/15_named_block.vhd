@@ -0,0 +1,36 @@
+library ieee;
+use ieee.std_logic_1164.all;
+use ieee.numeric_std.all;
+use ieee.numeric_std_unsigned.all;
+use ieee.math_real.all;
+use work.all;
+
+entity veryl_testcase_Module15 is
+end entity veryl_testcase_Module15;
+
+architecture rtl of veryl_testcase_Module15 is
+    signal \_a\ : std_logic;
+begin
+    \_a\ <= '1';
+
+    \label\: block is
+        signal \_a\ : std_logic;
+    begin
+        \_a\ <= '1';
+    end block \label\;
+
+    label1: block is
+        signal \_a\ : std_logic;
+    begin
+        \_a\ <= '1';
+    end block label1;
+
+    label2: for i in 0 to 9 generate
+        \label\: block is
+            signal \_a\ : std_logic;
+        begin
+            \_a\ <= '1';
+        end block \label\;
+    end generate label2;
+end architecture rtl;
+--# sourceMappingURL=../map/15_named_block.vhd.map
@@ -0,0 +1,22 @@
+library ieee;
+use ieee.std_logic_1164.all;
+use ieee.numeric_std.all;
+use ieee.numeric_std_unsigned.all;
+use ieee.math_real.all;
+use work.all;
+
+entity veryl_testcase_Module22 is
+end entity veryl_testcase_Module22;
+
+architecture rtl of veryl_testcase_Module22 is
+    signal \_a\ : signed(9 downto 0);
+    signal \_b\ : signed(9 downto 0);
+    signal \_c\ : signed(9 downto 0);
+    signal \_d\ : std_logic_vector(9 downto 0);
+begin
+    \_a\ <= to_signed(1, \_a\'length);
+    \_b\ <= to_signed(1, \_b\'length);
+    \_c\ <= to_signed(1, \_c\'length);
+    \_d\ <= to_slv(1, \_d\'length);
+end architecture rtl;
+--# sourceMappingURL=../map/22_type_modifier.vhd.map
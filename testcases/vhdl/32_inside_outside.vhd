@@ -0,0 +1,18 @@
+library ieee;
+use ieee.std_logic_1164.all;
+use ieee.numeric_std.all;
+use ieee.numeric_std_unsigned.all;
+use ieee.math_real.all;
+use work.all;
+
+entity veryl_testcase_Module32 is
+end entity veryl_testcase_Module32;
+
+architecture rtl of veryl_testcase_Module32 is
+    signal a : std_logic;
+    signal b : std_logic;
+begin
+    a <= '1' when (1 + 2 / 3 = 0 or (1 + 2 / 3 >= 0 and 1 + 2 / 3 < 10) or (1 + 2 / 3 >= 1 and 1 + 2 / 3 <= 10)) else '0';
+    b <= '1' when not (1 * 2 - 1 = 0 or (1 * 2 - 1 >= 0 and 1 * 2 - 1 < 10) or (1 * 2 - 1 >= 1 and 1 * 2 - 1 <= 10)) else '0';
+end architecture rtl;
+--# sourceMappingURL=../map/32_inside_outside.vhd.map
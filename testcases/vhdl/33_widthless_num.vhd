@@ -0,0 +1,23 @@
+library ieee;
+use ieee.std_logic_1164.all;
+use ieee.numeric_std.all;
+use ieee.numeric_std_unsigned.all;
+use ieee.math_real.all;
+use work.all;
+
+entity veryl_testcase_Module33 is
+end entity veryl_testcase_Module33;
+
+architecture rtl of veryl_testcase_Module33 is
+    constant a0 : natural := 2#1#;
+    constant a1 : natural := 2#1010#;
+    constant a2 : natural := 8#1#;
+    constant a3 : natural := 8#1234#;
+    constant a4 : natural := 1;
+    constant a5 : natural := 12345678;
+    constant a6 : natural := 16#1#;
+    constant a7 : natural := 16#FFFFFFFF#;
+    constant a8 : natural := 16#FFFF_FFFF_FFFF_FFFF_FFFF_FFFF_FFFF_FFFF_FFFF_FFFF_FFFF_FFFF_FFFF#;
+begin
+end architecture rtl;
+--# sourceMappingURL=../map/33_widthless_num.vhd.map
@@ -0,0 +1,30 @@
+library ieee;
+use ieee.std_logic_1164.all;
+use ieee.numeric_std.all;
+use ieee.numeric_std_unsigned.all;
+use ieee.math_real.all;
+use work.all;
+
+entity veryl_testcase_Module34 is
+end entity veryl_testcase_Module34;
+
+architecture rtl of veryl_testcase_Module34 is
+    signal \_a0\ : std_logic_vector(31 downto 0);
+    signal \_a1\ : std_logic_vector(31 downto 0);
+    signal \_a2\ : std_logic_vector(31 downto 0);
+    signal \_a3\ : std_logic_vector(31 downto 0);
+    signal \_a4\ : std_logic_vector(31 downto 0);
+    signal \_a5\ : std_logic_vector(31 downto 0);
+    signal \_a6\ : std_logic_vector(31 downto 0);
+    signal \_a7\ : std_logic_vector(31 downto 0);
+begin
+    \_a0\ <= (others => '0');
+    \_a1\ <= (others => '1');
+    \_a2\ <= (others => 'X');
+    \_a3\ <= (others => 'Z');
+    \_a4\ <= (others => '0');
+    \_a5\ <= (others => '1');
+    \_a6\ <= (others => 'X');
+    \_a7\ <= (others => 'Z');
+end architecture rtl;
+--# sourceMappingURL=../map/34_width_all_set.vhd.map
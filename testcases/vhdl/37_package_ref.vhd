@@ -0,0 +1,43 @@
+library ieee;
+use ieee.std_logic_1164.all;
+use ieee.numeric_std.all;
+use ieee.numeric_std_unsigned.all;
+use ieee.math_real.all;
+use work.all;
+
+package veryl_testcase_Package37 is
+    constant A : natural := 1;
+
+    subtype B is std_logic;
+    constant B_C : B := '0';
+
+    function X return std_logic_vector;
+end package veryl_testcase_Package37;
+
+package body veryl_testcase_Package37 is
+    function X return std_logic_vector is
+    begin
+        return to_slv(0, 32);
+    end function X;
+end package body veryl_testcase_Package37;
+
+library ieee;
+use ieee.std_logic_1164.all;
+use ieee.numeric_std.all;
+use ieee.numeric_std_unsigned.all;
+use ieee.math_real.all;
+use work.all;
+
+entity veryl_testcase_Module37 is
+end entity veryl_testcase_Module37;
+
+architecture rtl of veryl_testcase_Module37 is
+    signal \_a\ : std_logic_vector(31 downto 0);
+    signal \_b\ : std_logic_vector(31 downto 0);
+    signal \_c\ : std_logic_vector(31 downto 0);
+begin
+    \_a\ <= to_slv(veryl_testcase_Package37.A, \_a\'length);
+    \_b\ <= (0 => veryl_testcase_Package37.B_C, others => '0');
+    \_c\ <= veryl_testcase_Package37.X;
+end architecture rtl;
+--# sourceMappingURL=../map/37_package_ref.vhd.map
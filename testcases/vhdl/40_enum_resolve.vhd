@@ -0,0 +1,27 @@
+library ieee;
+use ieee.std_logic_1164.all;
+use ieee.numeric_std.all;
+use ieee.numeric_std_unsigned.all;
+use ieee.math_real.all;
+use work.all;
+
+entity veryl_testcase_Module40 is
+end entity veryl_testcase_Module40;
+
+architecture rtl of veryl_testcase_Module40 is
+    subtype EnumA is std_logic_vector(1 downto 0);
+    constant EnumA_member_a : EnumA := 2d"0";
+    constant EnumA_member_b : EnumA := 2d"1";
+    constant EnumA_member_c : EnumA := 2d"3";
+
+    signal a : EnumA;
+    function is_a (
+        e : EnumA
+    ) return std_logic is
+    begin
+        return e ?= EnumA_member_a;
+    end function is_a;
+begin
+    a <= EnumA_member_a;
+end architecture rtl;
+--# sourceMappingURL=../map/40_enum_resolve.vhd.map
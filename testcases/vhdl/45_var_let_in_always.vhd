@@ -0,0 +1,34 @@
+library ieee;
+use ieee.std_logic_1164.all;
+use ieee.numeric_std.all;
+use ieee.numeric_std_unsigned.all;
+use ieee.math_real.all;
+use work.all;
+
+entity veryl_testcase_Module45 is
+end entity veryl_testcase_Module45;
+
+architecture rtl of veryl_testcase_Module45 is
+    signal a : std_logic;
+    signal b : std_logic_vector(9 downto 0);
+    signal c : std_logic_vector(9 downto 0);
+begin
+    a <= '1';
+
+    process (a) is
+        variable x : std_logic_vector(9 downto 0);
+    begin
+        if rising_edge(a) then
+            x := to_slv(1, x'length);
+            b <= resize(x * 1, b'length);
+        end if;
+    end process;
+
+    process (all) is
+        variable y : std_logic_vector(9 downto 0);
+    begin
+        y := to_slv(1, y'length);
+        c <= resize(y * 1, c'length);
+    end process;
+end architecture rtl;
+--# sourceMappingURL=../map/45_var_let_in_always.vhd.map
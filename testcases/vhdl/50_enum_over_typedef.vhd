@@ -0,0 +1,33 @@
+library ieee;
+use ieee.std_logic_1164.all;
+use ieee.numeric_std.all;
+use ieee.numeric_std_unsigned.all;
+use ieee.math_real.all;
+use work.all;
+
+package veryl_testcase_Package50 is
+    subtype EnumA is std_logic_vector(0 downto 0);
+    constant EnumA_memberA : EnumA := 1d"0";
+    constant EnumA_memberB : EnumA := 1d"1";
+end package veryl_testcase_Package50;
+
+library ieee;
+use ieee.std_logic_1164.all;
+use ieee.numeric_std.all;
+use ieee.numeric_std_unsigned.all;
+use ieee.math_real.all;
+use work.all;
+
+entity veryl_testcase_Module50 is
+end entity veryl_testcase_Module50;
+
+architecture rtl of veryl_testcase_Module50 is
+    subtype EnumB is veryl_testcase_Package50.EnumA;
+
+    signal \_a\ : veryl_testcase_Package50.EnumA;
+    signal \_b\ : EnumB;
+begin
+    \_a\ <= veryl_testcase_Package50.EnumA_memberA;
+    \_b\ <= veryl_testcase_Package50.EnumA_memberB;
+end architecture rtl;
+--# sourceMappingURL=../map/50_enum_over_typedef.vhd.map
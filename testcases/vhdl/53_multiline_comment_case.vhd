@@ -0,0 +1,27 @@
+library ieee;
+use ieee.std_logic_1164.all;
+use ieee.numeric_std.all;
+use ieee.numeric_std_unsigned.all;
+use ieee.math_real.all;
+use work.all;
+
+entity veryl_testcase_Module53 is
+end entity veryl_testcase_Module53;
+
+architecture rtl of veryl_testcase_Module53 is
+    subtype EnumA is std_logic;
+    constant EnumA_A : EnumA := '0';
+
+    signal \_a\ : std_logic;
+begin
+    process (all) is
+    begin
+        case EnumA_A is
+            when EnumA_A =>
+                \_a\ <= '0';
+            when others =>
+                \_a\ <= '1';
+        end case;
+    end process;
+end architecture rtl;
+--# sourceMappingURL=../map/53_multiline_comment_case.vhd.map
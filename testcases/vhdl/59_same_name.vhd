@@ -0,0 +1,22 @@
+library ieee;
+use ieee.std_logic_1164.all;
+use ieee.numeric_std.all;
+use ieee.numeric_std_unsigned.all;
+use ieee.math_real.all;
+use work.all;
+
+package veryl_testcase_Package59A is
+    constant XLEN : natural := 32;
+end package veryl_testcase_Package59A;
+
+library ieee;
+use ieee.std_logic_1164.all;
+use ieee.numeric_std.all;
+use ieee.numeric_std_unsigned.all;
+use ieee.math_real.all;
+use work.all;
+
+package veryl_testcase_Package59B is
+    constant XLEN : natural := veryl_testcase_Package59A.XLEN;
+end package veryl_testcase_Package59B;
+--# sourceMappingURL=../map/59_same_name.vhd.map
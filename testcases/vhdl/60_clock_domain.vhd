@@ -0,0 +1,70 @@
+library ieee;
+use ieee.std_logic_1164.all;
+use ieee.numeric_std.all;
+use ieee.numeric_std_unsigned.all;
+use ieee.math_real.all;
+use work.all;
+
+entity veryl_testcase_Module60A is
+    port (
+        i_clk_a   : in    std_logic;
+        i_rst_a_n : in    std_logic;
+        i_dat_a   : in    std_logic;
+        o_dat_a   : out   std_logic;
+        i_clk_b   : in    std_logic;
+        i_rst_b_n : in    std_logic;
+        i_dat_b   : in    std_logic;
+        o_dat_b   : out   std_logic
+    );
+end entity veryl_testcase_Module60A;
+
+architecture rtl of veryl_testcase_Module60A is
+begin
+    o_dat_a <= i_dat_a;
+    o_dat_b <= i_dat_b;
+end architecture rtl;
+
+library ieee;
+use ieee.std_logic_1164.all;
+use ieee.numeric_std.all;
+use ieee.numeric_std_unsigned.all;
+use ieee.math_real.all;
+use work.all;
+
+entity veryl_testcase_Module60B is
+    port (
+        i_clk    : in    std_logic;
+        i_clk_x2 : in    std_logic;
+        i_dat    : in    std_logic;
+        o_dat    : out   std_logic
+    );
+end entity veryl_testcase_Module60B;
+
+architecture rtl of veryl_testcase_Module60B is
+begin
+    o_dat <= i_dat;
+end architecture rtl;
+
+library ieee;
+use ieee.std_logic_1164.all;
+use ieee.numeric_std.all;
+use ieee.numeric_std_unsigned.all;
+use ieee.math_real.all;
+use work.all;
+
+entity veryl_testcase_Module60C is
+    port (
+        i_clk : in    std_logic;
+        i_dat : in    std_logic;
+        o_dat : out   std_logic;
+        i_thr : in    std_logic;
+        o_thr : out   std_logic
+    );
+end entity veryl_testcase_Module60C;
+
+architecture rtl of veryl_testcase_Module60C is
+begin
+    o_dat <= i_dat;
+    o_thr <= i_thr;
+end architecture rtl;
+--# sourceMappingURL=../map/60_clock_domain.vhd.map
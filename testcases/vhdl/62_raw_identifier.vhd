@@ -0,0 +1,22 @@
+library ieee;
+use ieee.std_logic_1164.all;
+use ieee.numeric_std.all;
+use ieee.numeric_std_unsigned.all;
+use ieee.math_real.all;
+use work.all;
+
+entity veryl_testcase_Module62 is
+end entity veryl_testcase_Module62;
+
+architecture rtl of veryl_testcase_Module62 is
+    signal clock : std_logic;
+    signal reset : std_logic;
+    signal \in\ : std_logic;
+    signal \out\ : std_logic;
+begin
+    \in\ <= '0';
+    clock <= '1';
+    reset <= '1';
+    \out\ <= \in\;
+end architecture rtl;
+--# sourceMappingURL=../map/62_raw_identifier.vhd.map
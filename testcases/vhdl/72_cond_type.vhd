@@ -0,0 +1,111 @@
+library ieee;
+use ieee.std_logic_1164.all;
+use ieee.numeric_std.all;
+use ieee.numeric_std_unsigned.all;
+use ieee.math_real.all;
+use work.all;
+
+entity veryl_testcase_Module72 is
+    port (
+        i_clk   : in    std_logic;
+        i_rst_n : in    std_logic
+    );
+end entity veryl_testcase_Module72;
+
+architecture rtl of veryl_testcase_Module72 is
+    signal x : std_logic;
+    signal a : std_logic;
+    signal b : std_logic;
+    signal c : std_logic;
+    signal d : std_logic;
+    signal e : std_logic;
+    signal f : std_logic;
+    signal g : std_logic;
+    signal h : std_logic;
+    signal i : std_logic;
+begin
+    x <= '1';
+
+    process (all) is
+    begin
+        case x is
+            when '0' =>
+                a <= '1';
+            when '1' =>
+                a <= '1';
+            when others =>
+                null;
+        end case;
+
+        case x is
+            when '0' =>
+                b <= '1';
+            when '1' =>
+                b <= '1';
+            when others =>
+                null;
+        end case;
+
+        case x is
+            when '0' =>
+                c <= '1';
+            when '1' =>
+                c <= '1';
+            when others =>
+                null;
+        end case;
+    end process;
+
+    process (all) is
+    begin
+        if x ?= '0' then
+            d <= '1';
+        elsif x ?= '1' then
+            d <= '1';
+        end if;
+
+        if x ?= '0' then
+            e <= '1';
+        elsif x ?= '1' then
+            e <= '1';
+        end if;
+
+        if x ?= '0' then
+            f <= '1';
+        elsif x ?= '1' then
+            f <= '1';
+        end if;
+    end process;
+
+    process (i_clk, i_rst_n) is
+    begin
+        if i_rst_n = '0' then
+            g <= '1';
+        elsif rising_edge(i_clk) then
+            if x ?= '1' then
+                g <= '1';
+            end if;
+        end if;
+    end process;
+    process (i_clk, i_rst_n) is
+    begin
+        if i_rst_n = '0' then
+            h <= '1';
+        elsif rising_edge(i_clk) then
+            if x ?= '1' then
+                h <= '1';
+            end if;
+        end if;
+    end process;
+    process (i_clk, i_rst_n) is
+    begin
+        if i_rst_n = '0' then
+            i <= '1';
+        elsif rising_edge(i_clk) then
+            if x ?= '1' then
+                i <= '1';
+            end if;
+        end if;
+    end process;
+end architecture rtl;
+--# sourceMappingURL=../map/72_cond_type.vhd.map
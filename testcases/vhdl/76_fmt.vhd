@@ -0,0 +1,92 @@
+library ieee;
+use ieee.std_logic_1164.all;
+use ieee.numeric_std.all;
+use ieee.numeric_std_unsigned.all;
+use ieee.math_real.all;
+use work.all;
+
+entity veryl_testcase_Module76 is
+end entity veryl_testcase_Module76;
+
+architecture rtl of veryl_testcase_Module76 is
+    signal a : std_logic;
+    signal \_b\ : std_logic;
+
+    signal \_c\ : std_logic;
+begin
+    u0: entity work.veryl_testcase_Module76A
+        generic map (
+            A => 1,
+            B => 2
+        )
+        port map (
+            x => '1',
+            y => open
+        );
+
+    u1: entity work.veryl_testcase_Module76A
+        generic map (
+            A => 1,
+            B => 2
+        )
+        port map (
+            x => '1',
+            y => open
+        );
+    u2: entity work.veryl_testcase_Module76A
+        generic map (
+            A => 1,
+            B => 2
+        )
+        port map (
+            x => '1',
+            y => open
+        );
+    u3: entity work.veryl_testcase_Module76A
+        generic map (
+            A => 1,
+            B => 2
+        )
+        port map (
+            x => '1',
+            y => open
+        );
+    u4: entity work.veryl_testcase_Module76A
+        generic map (
+            A => 1,
+            B => 2
+        )
+        port map (
+            x => '1',
+            y => open
+        );
+
+    a <= '1';
+    \_b\ <= '1' when a ?= '1' else '0' when a ?= '0' else '1' when a ?= '1' else '0';
+
+    \_c\ <= '1' when a ?= '1' else '0' when a ?= '0' else '1' when a ?= '1' else '0';
+end architecture rtl;
+
+library ieee;
+use ieee.std_logic_1164.all;
+use ieee.numeric_std.all;
+use ieee.numeric_std_unsigned.all;
+use ieee.math_real.all;
+use work.all;
+
+entity veryl_testcase_Module76A is
+    generic (
+        A : natural := 1;
+        B : natural := 1
+    );
+    port (
+        x : in    std_logic;
+        y : out   std_logic
+    );
+end entity veryl_testcase_Module76A;
+
+architecture rtl of veryl_testcase_Module76A is
+begin
+    y <= x;
+end architecture rtl;
+--# sourceMappingURL=../map/76_fmt.vhd.map
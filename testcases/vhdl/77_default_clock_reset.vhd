@@ -0,0 +1,30 @@
+library ieee;
+use ieee.std_logic_1164.all;
+use ieee.numeric_std.all;
+use ieee.numeric_std_unsigned.all;
+use ieee.math_real.all;
+use work.all;
+
+entity veryl_testcase_Module77 is
+    port (
+        i_clk_a   : in    std_logic;
+        i_rst_a_n : in    std_logic;
+        i_clk_b   : in    std_logic;
+        i_rst_b_n : in    std_logic;
+        i_d       : in    std_logic;
+        o_d       : out   std_logic
+    );
+end entity veryl_testcase_Module77;
+
+architecture rtl of veryl_testcase_Module77 is
+begin
+    process (i_clk_a, i_rst_a_n) is
+    begin
+        if i_rst_a_n = '0' then
+            o_d <= '0';
+        elsif rising_edge(i_clk_a) then
+            o_d <= i_d;
+        end if;
+    end process;
+end architecture rtl;
+--# sourceMappingURL=../map/77_default_clock_reset.vhd.map
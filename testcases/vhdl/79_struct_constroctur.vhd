@@ -0,0 +1,29 @@
+library ieee;
+use ieee.std_logic_1164.all;
+use ieee.numeric_std.all;
+use ieee.numeric_std_unsigned.all;
+use ieee.math_real.all;
+use work.all;
+
+entity veryl_testcase_Module79 is
+end entity veryl_testcase_Module79;
+
+architecture rtl of veryl_testcase_Module79 is
+    type StructA is record
+        a  : std_logic_vector(9 downto 0);
+        bb : std_logic_vector(9 downto 0);
+    end record StructA;
+
+    signal \_a\ : StructA;
+
+    signal \_b\ : StructA;
+
+    signal \_c\ : StructA;
+begin
+    \_a\ <= (a => to_slv(10000, 10), bb => to_slv(10, 10));
+
+    \_b\ <= (a => to_slv(10, 10), bb => to_slv(0, 10));
+
+    \_c\ <= (a => to_slv(10, 10), bb => to_slv(10, 10));
+end architecture rtl;
+--# sourceMappingURL=../map/79_struct_constroctur.vhd.map